@@ -6,14 +6,17 @@
 //! # Metrics
 //! - `policy/eval_v2_with_args`: v0.2 API with tool arguments (target: < 100µs)
 //! - `policy/reload`: Policy hot-reload performance (target: < 100ms)
+//! - `policy/scaling/{N}`: Evaluation latency with N loaded policies; p50/p99
+//!   for each N are printed after the criterion run
 //!
 //! # Usage
 //! ```bash
 //! cargo bench --bench policy_eval
 //! ```
 
-use criterion::{Criterion, criterion_group, criterion_main};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use serde_json::json;
+use std::time::{Duration, Instant};
 use thoughtgate::policy::{
     Principal,
     engine::CedarEngine,
@@ -56,6 +59,7 @@ fn bench_policy_eval_v2(c: &mut Criterion) {
                     "category": "payment"
                 }
             }),
+            attributes: Default::default(),
         },
         context: CedarContext {
             policy_id: "financial_transfer".to_string(),
@@ -79,5 +83,116 @@ fn bench_policy_reload(c: &mut Criterion) {
     c.bench_function("policy/reload", |b| b.iter(|| engine.reload().ok()));
 }
 
-criterion_group!(benches, bench_policy_eval_v2, bench_policy_reload,);
+/// Policy counts to measure evaluation latency at.
+const POLICY_COUNTS: [usize; 4] = [1, 10, 100, 1000];
+
+/// Samples taken for the p50/p99 report at each policy count.
+const PERCENTILE_SAMPLES: usize = 10_000;
+
+/// Generate `count` permit policies, each for a different tool.
+///
+/// The benchmark request matches the last one, so every policy is checked.
+fn generate_policies(count: usize) -> String {
+    (0..count)
+        .map(|i| {
+            format!(
+                "@id(\"tool-{i}\")\n\
+                 permit(principal, action == ThoughtGate::Action::\"tools/call\", resource)\n\
+                 when {{ resource.name == \"tool_{i}\" && resource.server == \"mcp-server\" }};\n"
+            )
+        })
+        .collect()
+}
+
+/// Load an engine with `count` generated policies, warmed up.
+fn engine_with_policies(count: usize) -> CedarEngine {
+    // SAFETY: benchmarks run single-threaded while the engine loads.
+    unsafe { std::env::set_var("THOUGHTGATE_POLICIES", generate_policies(count)) };
+    let engine = CedarEngine::new();
+    unsafe { std::env::remove_var("THOUGHTGATE_POLICIES") };
+    let engine = engine.expect("Failed to create Cedar engine");
+    engine.warmup();
+    engine
+}
+
+/// A request permitted by the last of `count` generated policies.
+fn scaling_request(count: usize) -> CedarRequest {
+    CedarRequest {
+        principal: create_test_principal(),
+        resource: CedarResource::ToolCall {
+            name: format!("tool_{}", count - 1),
+            server: "mcp-server".to_string(),
+            arguments: json!({ "amount": 5000 }),
+            attributes: Default::default(),
+        },
+        context: CedarContext {
+            policy_id: "scaling".to_string(),
+            source_id: "test-source".to_string(),
+            time: TimeContext::now(),
+        },
+    }
+}
+
+/// The `pct` percentile of sorted samples (nearest rank).
+fn percentile(sorted: &[Duration], pct: f64) -> Duration {
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Benchmark evaluation latency as the number of loaded policies grows.
+///
+/// Criterion reports the mean per policy count; p50/p99 are measured
+/// separately over individually timed evaluations and printed.
+///
+/// # Traceability
+/// - Implements: REQ-OBS-001 M-POL-001 (Policy eval performance)
+fn bench_policy_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("policy/scaling");
+    let mut report = Vec::new();
+
+    for count in POLICY_COUNTS {
+        let engine = engine_with_policies(count);
+        let request = scaling_request(count);
+        assert!(
+            engine.evaluate_v2(&request).is_permit(),
+            "benchmark request must be permitted"
+        );
+
+        group.bench_with_input(BenchmarkId::from_parameter(count), &request, |b, req| {
+            b.iter(|| engine.evaluate_v2(req))
+        });
+
+        let mut samples: Vec<Duration> = (0..PERCENTILE_SAMPLES)
+            .map(|_| {
+                let start = Instant::now();
+                std::hint::black_box(engine.evaluate_v2(&request));
+                start.elapsed()
+            })
+            .collect();
+        samples.sort_unstable();
+        report.push((
+            count,
+            percentile(&samples, 50.0),
+            percentile(&samples, 99.0),
+        ));
+    }
+    group.finish();
+
+    println!("\npolicy/scaling latency ({PERCENTILE_SAMPLES} samples each):");
+    println!("{:>10} {:>12} {:>12}", "policies", "p50", "p99");
+    for (count, p50, p99) in report {
+        println!(
+            "{count:>10} {:>12} {:>12}",
+            format!("{p50:.1?}"),
+            format!("{p99:.1?}")
+        );
+    }
+}
+
+criterion_group!(
+    benches,
+    bench_policy_eval_v2,
+    bench_policy_reload,
+    bench_policy_scaling,
+);
 criterion_main!(benches);
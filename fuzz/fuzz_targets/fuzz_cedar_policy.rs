@@ -168,13 +168,18 @@ fn build_resource(input: &FuzzResource) -> CedarResource {
                 name,
                 server,
                 arguments,
+                attributes: Default::default(),
             }
         }
         FuzzResource::McpMethod { method, server } => {
             let method = sanitize_string(method, 256);
             let server = sanitize_string(server, 256);
 
-            CedarResource::McpMethod { method, server }
+            CedarResource::McpMethod {
+                method,
+                server,
+                attributes: Default::default(),
+            }
         }
     }
 }
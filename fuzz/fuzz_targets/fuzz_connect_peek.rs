@@ -0,0 +1,72 @@
+#![no_main]
+
+//! Fuzz target for REQ-CORE-002 CONNECT detection on peeked connection bytes
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-002 (Conditional Termination - CONNECT rejection)
+//!
+//! # Goal
+//! Exercise the same `is_connect_preface` the accept loop calls on the bytes
+//! it peeks from each new connection, and verify that:
+//! - Arbitrary or truncated peeks never panic
+//! - Only the first `CONNECT_PEEK_LEN` bytes influence the decision, so
+//!   detection needs a fixed-size buffer regardless of input size
+//! - A short peek is never classified as CONNECT
+//! - The rejection response is well-formed HTTP/1.1
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+use thoughtgate::proxy_service::{
+    connect_rejection_response, is_connect_preface, CONNECT_PEEK_LEN,
+};
+
+/// Fuzz input for CONNECT peeking
+#[derive(Arbitrary, Debug)]
+struct FuzzPeekInput {
+    /// Bytes the client sent first
+    data: Vec<u8>,
+    /// How many bytes the peek returned (a peek may return fewer than asked)
+    peeked: u8,
+    /// Bytes appended after the peek window, which must not matter
+    trailing: Vec<u8>,
+}
+
+fuzz_target!(|input: FuzzPeekInput| {
+    // Mirror handle_connection: peek into a fixed buffer, possibly short
+    let mut peek_buf = [0u8; CONNECT_PEEK_LEN];
+    let n = (input.peeked as usize)
+        .min(CONNECT_PEEK_LEN)
+        .min(input.data.len());
+    peek_buf[..n].copy_from_slice(&input.data[..n]);
+
+    let is_connect = is_connect_preface(&peek_buf[..n]);
+
+    // Invariant: short peeks are never CONNECT
+    if n < CONNECT_PEEK_LEN {
+        assert!(!is_connect, "short peek of {n} bytes classified as CONNECT");
+    } else {
+        assert_eq!(is_connect, &peek_buf[..] == b"CONNECT");
+    }
+
+    // Invariant: bytes beyond the peek window never change the decision
+    if input.data.len() >= CONNECT_PEEK_LEN {
+        let mut extended = input.data.clone();
+        extended.extend(input.trailing.iter().take(64 * 1024));
+        assert_eq!(
+            is_connect_preface(&input.data[..CONNECT_PEEK_LEN]),
+            is_connect_preface(&extended),
+            "bytes beyond the peek window changed the decision"
+        );
+    }
+
+    // Invariant: the rejection response is well-formed
+    if is_connect {
+        let response = connect_rejection_response();
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .expect("response missing header terminator");
+        assert!(head.starts_with("HTTP/1.1 405 "));
+        assert!(head.contains(&format!("Content-Length: {}", body.len())));
+    }
+});
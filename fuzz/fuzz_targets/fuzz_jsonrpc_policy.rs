@@ -0,0 +1,232 @@
+#![no_main]
+
+//! Fuzz target for JSON-RPC classification into Cedar policy requests
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-003/F-001 (JSON-RPC Parsing)
+//! - Implements: REQ-POL-001/§6.1 (Policy Evaluation Request)
+//! - Attack surface: Malicious tool arguments reaching Cedar evaluation
+//!
+//! # Goal
+//! Drive bytes through the same path as Gate 3: `parse_jsonrpc`, then
+//! `build_cedar_request`, then `CedarEngine::evaluate_v2`, and verify:
+//! - No panics anywhere along the path
+//! - Bodies over the server's size limit are never parsed
+//! - Arguments handed to Cedar never nest deeper than the parser allows
+//! - Arguments handed to Cedar are not amplified beyond the body size
+//! - The resource name always matches the request's `name`/`uri`
+//!
+//! The fuzz input is used directly as a request body, so the JSON seeds in
+//! `fuzz/seeds/fuzz_jsonrpc_policy/` are exercised as-is. The same bytes
+//! also drive a structured, always-valid request for deeper coverage.
+
+use arbitrary::{Arbitrary, Unstructured};
+use libfuzzer_sys::fuzz_target;
+use serde_json::Value;
+use std::sync::OnceLock;
+
+use thoughtgate::policy::engine::CedarEngine;
+use thoughtgate::policy::{CedarResource, Principal};
+use thoughtgate::transport::jsonrpc::{parse_jsonrpc, BatchItem, McpRequest, ParsedRequests};
+use thoughtgate::transport::server::build_cedar_request;
+
+/// Default `McpServerConfig::max_body_size` (1MB); larger bodies are
+/// rejected before parsing.
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// serde_json's recursion limit; deeper documents fail to parse.
+const MAX_NESTING_DEPTH: usize = 128;
+
+/// Upper bound on re-serialized argument size relative to the body.
+/// Number normalization (e.g. `1e5` -> `100000.0`) can expand a few bytes.
+const MAX_AMPLIFICATION: usize = 8;
+
+/// Structured tools/call with fuzzed arguments
+#[derive(Arbitrary, Debug)]
+struct ToolCallInput {
+    /// Governable method to use
+    method: GovernableMethod,
+    /// Tool name or resource URI
+    target: String,
+    /// Top-level arguments
+    arguments: Vec<(String, ArgValue)>,
+    /// Extra nesting applied around the arguments
+    nesting: u8,
+}
+
+#[derive(Arbitrary, Debug)]
+enum GovernableMethod {
+    ToolsCall,
+    ResourcesRead,
+    ResourcesSubscribe,
+    PromptsGet,
+}
+
+#[derive(Arbitrary, Debug)]
+enum ArgValue {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<i64>),
+}
+
+fuzz_target!(|data: &[u8]| {
+    fuzz_jsonrpc_policy(data);
+});
+
+fn engine() -> Option<&'static CedarEngine> {
+    static ENGINE: OnceLock<Option<CedarEngine>> = OnceLock::new();
+    ENGINE.get_or_init(|| CedarEngine::new().ok()).as_ref()
+}
+
+fn principal() -> Principal {
+    Principal {
+        app_name: "fuzz-agent".to_string(),
+        namespace: "default".to_string(),
+        service_account: "default".to_string(),
+        roles: vec![],
+    }
+}
+
+fn fuzz_jsonrpc_policy(data: &[u8]) {
+    // Test 1: Raw bytes along the Gate 3 path
+    classify_body(data);
+
+    // Test 2: Well-formed governable requests with hostile arguments
+    if let Ok(tool_call) = ToolCallInput::arbitrary(&mut Unstructured::new(data)) {
+        let body = build_request(&tool_call);
+        classify_body(body.as_bytes());
+    }
+}
+
+/// Parse a body and build and evaluate a policy request for each request.
+fn classify_body(body: &[u8]) {
+    // Invariant: the server rejects oversized bodies before parsing
+    if body.len() > MAX_BODY_SIZE {
+        return;
+    }
+
+    let requests = match parse_jsonrpc(body) {
+        Ok(ParsedRequests::Single(request)) => vec![request],
+        Ok(ParsedRequests::Batch(items)) => items
+            .into_iter()
+            .filter_map(|item| match item {
+                BatchItem::Valid(request) => Some(request),
+                BatchItem::Invalid { .. } => None,
+            })
+            .collect(),
+        Err(_) => return,
+    };
+
+    for request in &requests {
+        classify_request(request, body.len());
+    }
+}
+
+fn classify_request(request: &McpRequest, body_len: usize) {
+    let Ok((resource_name, cedar_request)) =
+        build_cedar_request(request, principal(), "default", "upstream")
+    else {
+        return;
+    };
+
+    let params = request.params.as_ref();
+    match &cedar_request.resource {
+        CedarResource::ToolCall {
+            name, arguments, ..
+        } => {
+            // Invariant: name comes from params.name
+            let expected = params.and_then(|p| p.get("name")).and_then(Value::as_str);
+            assert_eq!(expected, Some(name.as_str()));
+            assert_eq!(&resource_name, name);
+
+            // Invariant: nesting stays within the parser's limit
+            let depth = nesting_depth(arguments);
+            assert!(depth <= MAX_NESTING_DEPTH, "arguments nested {depth} deep");
+
+            // Invariant: no unbounded amplification of the body
+            let size = serde_json::to_vec(arguments).map(|v| v.len()).unwrap_or(0);
+            assert!(
+                size <= body_len * MAX_AMPLIFICATION + 64,
+                "arguments re-serialized to {size} bytes from a {body_len} byte body"
+            );
+        }
+        CedarResource::McpMethod { method, .. } => {
+            assert_eq!(method, &request.method);
+            let key = if method.starts_with("resources/") {
+                "uri"
+            } else {
+                "name"
+            };
+            let expected = params.and_then(|p| p.get(key)).and_then(Value::as_str);
+            assert_eq!(expected, Some(resource_name.as_str()));
+        }
+    }
+
+    // Evaluation must never panic, whatever the arguments contain
+    if let Some(engine) = engine() {
+        let _ = engine.evaluate_v2(&cedar_request);
+    }
+}
+
+fn nesting_depth(value: &Value) -> usize {
+    match value {
+        Value::Array(items) => 1 + items.iter().map(nesting_depth).max().unwrap_or(0),
+        Value::Object(fields) => 1 + fields.values().map(nesting_depth).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Build a JSON-RPC body for a governable method from structured input.
+fn build_request(input: &ToolCallInput) -> String {
+    let mut arguments = Value::Object(
+        input
+            .arguments
+            .iter()
+            .take(32)
+            .map(|(k, v)| (k.chars().take(64).collect(), arg_value(v)))
+            .collect(),
+    );
+    for _ in 0..input.nesting {
+        arguments = serde_json::json!({ "nested": arguments });
+    }
+
+    let target: String = input.target.chars().take(256).collect();
+    let (method, params) = match input.method {
+        GovernableMethod::ToolsCall => (
+            "tools/call",
+            serde_json::json!({ "name": target, "arguments": arguments }),
+        ),
+        GovernableMethod::ResourcesRead => ("resources/read", serde_json::json!({ "uri": target })),
+        GovernableMethod::ResourcesSubscribe => {
+            ("resources/subscribe", serde_json::json!({ "uri": target }))
+        }
+        GovernableMethod::PromptsGet => (
+            "prompts/get",
+            serde_json::json!({ "name": target, "arguments": arguments }),
+        ),
+    };
+
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params,
+    })
+    .to_string()
+}
+
+fn arg_value(value: &ArgValue) -> Value {
+    match value {
+        ArgValue::Null => Value::Null,
+        ArgValue::Bool(b) => Value::Bool(*b),
+        ArgValue::Integer(n) => Value::from(*n),
+        ArgValue::Float(f) => serde_json::Number::from_f64(*f)
+            .map(Value::Number)
+            .unwrap_or(Value::Null),
+        ArgValue::String(s) => Value::String(s.chars().take(1024).collect()),
+        ArgValue::Array(items) => items.iter().take(64).copied().map(Value::from).collect(),
+    }
+}
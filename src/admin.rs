@@ -8,20 +8,38 @@
 //! The admin server runs on a dedicated port (default: 7469) and provides:
 //!
 //! - **Health Endpoints**: `/health` (liveness) and `/ready` (readiness)
+//! - **Probe Endpoints**: `/healthz` and `/readyz` with detailed checks
 //! - **Metrics Endpoint**: `/metrics` (Prometheus format)
+//! - **Debug Endpoint**: `POST /debug/explain` (policy decision explanations,
+//!   only when `THOUGHTGATE_EXPLAIN_DECISIONS=true`)
 //!
 //! This is separate from the main proxy port to allow:
 //! - Independent health monitoring
 //! - Security isolation (admin endpoints not exposed to proxy clients)
 //! - Dedicated resource allocation
-
-use axum::{Router, extract::State, http::StatusCode, response::IntoResponse, routing::get};
+//!
+//! The traffic ports never serve these paths. To keep them off the traffic
+//! network entirely, bind the admin server to the monitoring interface with
+//! `THOUGHTGATE_ADMIN_BIND`. With `THOUGHTGATE_ADMIN_TOKEN` set, `/metrics`
+//! and `/debug/*` also require `Authorization: Bearer <token>`; the health
+//! probes stay open so the kubelet can reach them.
+
+use axum::{
+    Json, Router,
+    extract::{Request, State},
+    http::{StatusCode, header},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
 use std::sync::Arc;
 use tokio::net::TcpListener;
 use tokio_util::sync::CancellationToken;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-use crate::lifecycle::LifecycleManager;
+use crate::governance::approval::signature::constant_time_eq;
+use crate::lifecycle::{LifecycleManager, probe_router};
+use crate::policy::explain::{ExplainQuery, explain, get_explain_engine};
 use crate::ports::admin_port;
 
 /// Admin server configuration.
@@ -29,15 +47,24 @@ use crate::ports::admin_port;
 pub struct AdminServerConfig {
     /// Port to listen on (default: 7469)
     pub port: u16,
-    /// Bind address (default: 0.0.0.0)
+    /// Bind address (default: `THOUGHTGATE_ADMIN_BIND`, or 0.0.0.0)
     pub bind_addr: String,
+    /// Bearer token required for `/metrics` and `/debug/*` (default:
+    /// `THOUGHTGATE_ADMIN_TOKEN`, or none)
+    pub token: Option<String>,
 }
 
 impl Default for AdminServerConfig {
     fn default() -> Self {
         Self {
             port: admin_port(),
-            bind_addr: "0.0.0.0".to_string(),
+            bind_addr: std::env::var("THOUGHTGATE_ADMIN_BIND")
+                .ok()
+                .filter(|addr| !addr.is_empty())
+                .unwrap_or_else(|| "0.0.0.0".to_string()),
+            token: std::env::var("THOUGHTGATE_ADMIN_TOKEN")
+                .ok()
+                .filter(|token| !token.is_empty()),
         }
     }
 }
@@ -115,15 +142,34 @@ impl AdminServer {
     ///
     /// - `GET /health` - Liveness probe (always returns 200 if server is running)
     /// - `GET /ready` - Readiness probe (returns 200 if ready, 503 if not)
+    /// - `GET /healthz` - Liveness probe (503 if stopped or event loop stalled)
+    /// - `GET /readyz` - Readiness probe over all checks, including the last
+    ///   policy load and upstream health
     /// - `GET /metrics` - Prometheus metrics
+    /// - `POST /debug/explain` - Explain a policy decision (404 unless
+    ///   explanations are enabled)
+    ///
+    /// With a token configured, `/metrics` and `/debug/explain` answer 401
+    /// without it.
     ///
     /// # Traceability
     /// - Implements: REQ-CORE-005/F-001 (Health Endpoints)
     pub fn router(&self) -> Router {
+        let mut protected = Router::new()
+            .route("/metrics", get(metrics_handler))
+            .route("/debug/explain", post(explain_handler));
+        if let Some(token) = &self.config.token {
+            protected = protected.route_layer(middleware::from_fn_with_state(
+                Arc::<str>::from(token.as_str()),
+                require_token,
+            ));
+        }
+
         Router::new()
             .route("/health", get(health_handler))
             .route("/ready", get(readiness_handler))
-            .route("/metrics", get(metrics_handler))
+            .merge(protected)
+            .merge(probe_router(self.state.lifecycle.clone()))
             .with_state(self.state.clone())
     }
 
@@ -149,8 +195,25 @@ impl AdminServer {
         let bind_addr = self.config.bind_string();
         let listener = TcpListener::bind(&bind_addr).await?;
 
-        info!(addr = %bind_addr, "Admin server listening");
+        info!(
+            addr = %bind_addr,
+            token_required = self.config.token.is_some(),
+            "Admin server listening"
+        );
+        self.serve(listener, shutdown).await
+    }
 
+    /// Serve requests on an already bound `listener` until `shutdown` is
+    /// cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serving fails.
+    pub async fn serve(
+        self,
+        listener: TcpListener,
+        shutdown: CancellationToken,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         axum::serve(listener, self.router())
             .with_graceful_shutdown(async move {
                 shutdown.cancelled().await;
@@ -162,6 +225,31 @@ impl AdminServer {
     }
 }
 
+/// Reject requests without `Authorization: Bearer <token>`.
+///
+/// The token is compared in constant time.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-005/§5.1 (Admin Server)
+async fn require_token(State(token): State<Arc<str>>, request: Request, next: Next) -> Response {
+    let authorized = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|presented| constant_time_eq(presented.as_bytes(), token.as_bytes()));
+    if !authorized {
+        warn!(path = %request.uri().path(), "Admin request refused: missing or invalid token");
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "missing or invalid bearer token",
+        )
+            .into_response();
+    }
+    next.run(request).await
+}
+
 /// Health check handler (liveness probe).
 ///
 /// This always returns 200 OK if the server is running.
@@ -220,6 +308,24 @@ async fn metrics_handler() -> impl IntoResponse {
         .into_response()
 }
 
+/// Policy decision explanation handler.
+///
+/// Evaluates the query against the live policies and returns the action
+/// with its [`Explanation`](crate::policy::Explanation). Served on the
+/// admin port only, and 404 unless explanations are enabled.
+///
+/// # Traceability
+/// - Implements: REQ-POL-001/§6.3 (Decision Explanation)
+async fn explain_handler(Json(query): Json<ExplainQuery>) -> impl IntoResponse {
+    let Some(engine) = get_explain_engine() else {
+        return (StatusCode::NOT_FOUND, "Decision explanations are disabled").into_response();
+    };
+    match explain(&engine, &query) {
+        Ok(outcome) => Json(outcome).into_response(),
+        Err(message) => (StatusCode::BAD_REQUEST, message).into_response(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,6 +414,23 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_explain_endpoint_disabled_by_default() {
+        let state = create_test_state();
+        let admin = AdminServer::with_config(state.lifecycle.clone(), AdminServerConfig::default());
+        let router = admin.router();
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/debug/explain")
+            .header("content-type", "application/json")
+            .body(Body::from(r#"{"principal":"app","tool":"delete_user"}"#))
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
     #[test]
     fn test_admin_config_default() {
         let config = AdminServerConfig::default();
@@ -322,4 +445,26 @@ mod tests {
         assert_eq!(config.port, 9000);
         assert_eq!(config.bind_string(), "0.0.0.0:9000");
     }
+
+    #[tokio::test]
+    async fn test_probe_endpoints() {
+        let state = create_test_state();
+        let admin = AdminServer::with_config(state.lifecycle.clone(), AdminServerConfig::default());
+
+        let request = Request::builder()
+            .uri("/healthz")
+            .body(Body::empty())
+            .unwrap();
+        let response = admin.router().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // Ready state alone is not enough: no policy or config loaded yet
+        state.lifecycle.mark_ready();
+        let request = Request::builder()
+            .uri("/readyz")
+            .body(Body::empty())
+            .unwrap();
+        let response = admin.router().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
 }
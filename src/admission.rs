@@ -0,0 +1,321 @@
+//! Admission control for concurrently handled requests.
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-001 Section 3.2 (Concurrency Limit)
+//!
+//! The connection limit (`max_concurrent_streams`) bounds connections, but
+//! one HTTP/2 connection can carry many requests. This bounds the requests
+//! themselves: each needs a permit from its upstream's limit and one from
+//! the global limit, held until its response body has been sent.
+//!
+//! A request that finds no permit free may wait in a short queue. Once the
+//! queue is full, or the wait runs out, it is shed with 503 and
+//! `Retry-After` instead of slowing down every request already in flight.
+//!
+//! # Configuration
+//!
+//! - `THOUGHTGATE_MAX_IN_FLIGHT_REQUESTS` (default: unlimited)
+//! - `THOUGHTGATE_MAX_IN_FLIGHT_PER_UPSTREAM` (default: unlimited)
+//! - `THOUGHTGATE_ADMISSION_QUEUE_DEPTH` (default: 0, shed immediately)
+//! - `THOUGHTGATE_ADMISSION_QUEUE_WAIT_MS` (default: 100)
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use dashmap::DashMap;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::warn;
+
+use crate::error::{ProxyError, ProxyResult};
+use crate::metrics::get_admission_metrics;
+use crate::proxy_config::ProxyConfig;
+
+/// Seconds a shed client is asked to wait before retrying.
+pub const OVERLOAD_RETRY_AFTER_SECS: u64 = 1;
+
+/// One concurrency limit and the requests queued for it.
+struct Limit {
+    permits: Arc<Semaphore>,
+    max: usize,
+    queued: AtomicUsize,
+}
+
+impl Limit {
+    fn new(max: usize) -> Self {
+        let max = max.min(Semaphore::MAX_PERMITS);
+        Self {
+            permits: Arc::new(Semaphore::new(max)),
+            max,
+            queued: AtomicUsize::new(0),
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        self.permits.available_permits() == self.max && self.queued.load(Ordering::SeqCst) == 0
+    }
+}
+
+/// Frees a queue slot when the waiting request is admitted, shed or
+/// cancelled.
+struct QueueSlot<'a>(&'a AtomicUsize);
+
+impl Drop for QueueSlot<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Global and per-upstream limits on requests in flight.
+///
+/// Implements: REQ-CORE-001 Section 3.2 (Concurrency Limit)
+pub struct AdmissionControl {
+    global: Option<Limit>,
+    per_upstream: Option<usize>,
+    upstreams: Arc<DashMap<String, Arc<Limit>>>,
+    queue_depth: usize,
+    queue_wait: Duration,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl AdmissionControl {
+    /// Create the limits configured in `config`.
+    ///
+    /// Returns `None` when neither limit is set.
+    pub fn from_config(config: &ProxyConfig) -> Option<Self> {
+        if config.max_in_flight_requests.is_none() && config.max_in_flight_per_upstream.is_none() {
+            return None;
+        }
+        Some(Self {
+            global: config.max_in_flight_requests.map(Limit::new),
+            per_upstream: config.max_in_flight_per_upstream,
+            upstreams: Arc::new(DashMap::new()),
+            queue_depth: config.admission_queue_depth,
+            queue_wait: config.admission_queue_wait,
+            in_flight: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// Requests currently admitted.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Admit a request to `upstream`, waiting in the queue if allowed.
+    ///
+    /// The request counts against the limits until the returned
+    /// [`Admission`] is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProxyError::Overloaded` (503) if no permit became free.
+    pub async fn admit(&self, upstream: &str) -> ProxyResult<Admission> {
+        // The upstream permit is taken first, so a request queued behind a
+        // slow upstream does not hold a global permit while it waits.
+        let upstream_permit = match self.per_upstream {
+            Some(max) => {
+                let limit = self
+                    .upstreams
+                    .entry(upstream.to_string())
+                    .or_insert_with(|| Arc::new(Limit::new(max)))
+                    .clone();
+                let permit = self.acquire(&limit).await;
+                drop(limit);
+                match permit {
+                    Some(permit) => Some(UpstreamPermit {
+                        upstream: upstream.to_string(),
+                        permit: Some(permit),
+                        upstreams: self.upstreams.clone(),
+                    }),
+                    None => {
+                        forget_idle(&self.upstreams, upstream);
+                        return Err(shed("upstream", upstream));
+                    }
+                }
+            }
+            None => None,
+        };
+        let global_permit = match &self.global {
+            Some(limit) => match self.acquire(limit).await {
+                Some(permit) => Some(permit),
+                None => return Err(shed("global", upstream)),
+            },
+            None => None,
+        };
+
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        if let Some(metrics) = get_admission_metrics() {
+            metrics.add_in_flight(1);
+        }
+        Ok(Admission {
+            _global: global_permit,
+            _upstream: upstream_permit,
+            in_flight: self.in_flight.clone(),
+        })
+    }
+
+    /// Take a permit from `limit`, queueing for up to `queue_wait` if the
+    /// queue has room.
+    async fn acquire(&self, limit: &Limit) -> Option<OwnedSemaphorePermit> {
+        if let Ok(permit) = limit.permits.clone().try_acquire_owned() {
+            return Some(permit);
+        }
+        if self.queue_wait.is_zero() {
+            return None;
+        }
+        limit
+            .queued
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |queued| {
+                (queued < self.queue_depth).then_some(queued + 1)
+            })
+            .ok()?;
+        let _slot = QueueSlot(&limit.queued);
+        tokio::time::timeout(self.queue_wait, limit.permits.clone().acquire_owned())
+            .await
+            .ok()?
+            .ok()
+    }
+}
+
+/// Forget `upstream`'s limit if nothing is using it, so the map only holds
+/// upstreams with requests in flight or queued.
+fn forget_idle(upstreams: &DashMap<String, Arc<Limit>>, upstream: &str) {
+    upstreams.remove_if(upstream, |_, limit| {
+        Arc::strong_count(limit) == 1 && limit.is_idle()
+    });
+}
+
+fn shed(scope: &str, upstream: &str) -> ProxyError {
+    warn!(scope, upstream, "Request shed: too many requests in flight");
+    if let Some(metrics) = get_admission_metrics() {
+        metrics.record_overloaded(scope);
+    }
+    ProxyError::Overloaded(scope.to_string())
+}
+
+/// A permit from one upstream's limit, forgetting the limit once it is
+/// idle.
+struct UpstreamPermit {
+    upstream: String,
+    permit: Option<OwnedSemaphorePermit>,
+    upstreams: Arc<DashMap<String, Arc<Limit>>>,
+}
+
+impl Drop for UpstreamPermit {
+    fn drop(&mut self) {
+        self.permit.take();
+        forget_idle(&self.upstreams, &self.upstream);
+    }
+}
+
+/// A request's place within the limits, given up when dropped.
+pub struct Admission {
+    _global: Option<OwnedSemaphorePermit>,
+    _upstream: Option<UpstreamPermit>,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl Drop for Admission {
+    fn drop(&mut self) {
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        if let Some(metrics) = get_admission_metrics() {
+            metrics.add_in_flight(-1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn control(
+        global: Option<usize>,
+        per_upstream: Option<usize>,
+        queue: usize,
+    ) -> AdmissionControl {
+        AdmissionControl::from_config(&ProxyConfig {
+            max_in_flight_requests: global,
+            max_in_flight_per_upstream: per_upstream,
+            admission_queue_depth: queue,
+            admission_queue_wait: Duration::from_millis(200),
+            ..ProxyConfig::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_disabled_without_limits() {
+        assert!(AdmissionControl::from_config(&ProxyConfig::default()).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_global_limit_sheds_excess() {
+        let control = control(Some(2), None, 0);
+
+        let first = control.admit("a").await.unwrap();
+        let _second = control.admit("b").await.unwrap();
+        assert_eq!(control.in_flight(), 2);
+        assert!(matches!(
+            control.admit("c").await,
+            Err(ProxyError::Overloaded(scope)) if scope == "global"
+        ));
+
+        drop(first);
+        assert_eq!(control.in_flight(), 1);
+        assert!(control.admit("c").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_per_upstream_limit_is_independent() {
+        let control = control(None, Some(1), 0);
+
+        let _a = control.admit("a").await.unwrap();
+        assert!(matches!(
+            control.admit("a").await,
+            Err(ProxyError::Overloaded(scope)) if scope == "upstream"
+        ));
+        let b = control.admit("b").await.unwrap();
+        assert_eq!(control.upstreams.len(), 2);
+
+        // An upstream with nothing in flight is forgotten
+        drop(b);
+        assert_eq!(control.upstreams.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_queued_request_admitted_when_permit_frees() {
+        let control = Arc::new(control(Some(1), None, 1));
+
+        let held = control.admit("a").await.unwrap();
+        let waiter = tokio::spawn({
+            let control = control.clone();
+            async move { control.admit("a").await.map(drop) }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        // The queue holds one request; the next is shed
+        assert!(control.admit("a").await.is_err());
+
+        drop(held);
+        assert!(waiter.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_queue_wait_expires() {
+        let control = control(Some(1), None, 4);
+
+        let _held = control.admit("a").await.unwrap();
+        let started = std::time::Instant::now();
+        assert!(control.admit("a").await.is_err());
+        assert!(started.elapsed() >= Duration::from_millis(200));
+        assert_eq!(
+            control
+                .global
+                .as_ref()
+                .unwrap()
+                .queued
+                .load(Ordering::SeqCst),
+            0
+        );
+    }
+}
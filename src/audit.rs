@@ -0,0 +1,803 @@
+//! Audit trail of governance decisions.
+//!
+//! Every finalized decision — forwarded, denied, approved, rejected or
+//! expired — is written as one newline-delimited JSON [`AuditRecord`] to a
+//! dedicated sink, separate from operational logs and never subject to
+//! level filtering or sampling. Calls that were allowed but could not be
+//! forwarded because the upstream's TLS certificate was rejected are
+//! recorded too, naming the upstream.
+//!
+//! # Configuration
+//!
+//! - `THOUGHTGATE_AUDIT_LOG`: `stdout`, or a file path opened in append
+//!   mode. Unset disables the audit trail.
+//! - `THOUGHTGATE_AUDIT_HMAC_KEY`: optional key; when set, every record
+//!   carries an HMAC-SHA256 over its contents.
+//! - `THOUGHTGATE_AUDIT_QUEUE_SIZE`: records waiting to be written before
+//!   new ones are dropped (default 1024).
+//!
+//! # Tamper Evidence
+//!
+//! Records form a hash chain: each record's `prev_hash` is the SHA-256 of
+//! the previous line as written (the first record uses [`GENESIS_HASH`]).
+//! Editing, inserting or deleting a line breaks the link to the next one,
+//! which [`verify_audit_chain`] reports. With a key, the `hmac` field also
+//! pins each record to its writer, so a rewritten chain does not verify
+//! under [`verify_audit_chain_with_key`].
+//!
+//! When a file sink is reopened, the chain continues from its last line.
+//!
+//! # Hot Path
+//!
+//! Decisions only queue the record on a bounded channel; chaining, signing
+//! and writing happen on a dedicated thread. A record that finds the queue
+//! full is dropped, logged and counted in [`AuditLog::dropped`].
+//!
+//! # Durability
+//!
+//! Each record is written with a single `write` call and flushed once the
+//! writer thread reaches it. Records still queued when the process crashes
+//! are lost; [`AuditLog::flush`] waits for the queue to drain and is called
+//! on shutdown. Records are not fsynced, so a host crash may lose the most
+//! recent ones.
+//!
+//! # Traceability
+//! - Implements: REQ-OBS-002 (Audit Trail)
+
+use crate::governance::approval::signature::{constant_time_eq, hmac_sha256};
+use crate::policy::Explanation;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use tracing::error;
+
+/// Version of the [`AuditRecord`] schema.
+///
+/// Bumped on any change that is not a new optional field.
+///
+/// - 1: initial schema
+/// - 2: `prev_hash` chain and optional `hmac`
+/// - 3: `client_disconnected` decision
+/// - 4: `upstream_tls_failure` decision and `upstream` gate
+/// - 5: `would_reject` and `would_approve` decisions
+pub const AUDIT_SCHEMA_VERSION: u32 = 5;
+
+/// Default for `THOUGHTGATE_AUDIT_QUEUE_SIZE`.
+const DEFAULT_QUEUE_SIZE: usize = 1024;
+
+/// `prev_hash` of the first record in a chain.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Outcome recorded for a governance decision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditDecision {
+    /// Forwarded to upstream without approval.
+    Forward,
+    /// Refused by a gate.
+    Deny,
+    /// Approved by a human (or auto-approved).
+    Approved,
+    /// Rejected by a human.
+    Rejected,
+    /// Approval not decided before the task expired.
+    Expired,
+    /// Client disconnected while waiting for the approval decision.
+    ClientDisconnected,
+    /// Not forwarded: the TLS handshake with the upstream failed.
+    UpstreamTlsFailure,
+    /// Would have been refused by a gate; forwarded in monitor mode.
+    WouldReject,
+    /// Would have been held for approval; forwarded in monitor mode.
+    WouldApprove,
+}
+
+/// Gate that made the decision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditGate {
+    /// Gate 1: visibility.
+    Visibility,
+    /// Gate 2: YAML governance rules.
+    Governance,
+    /// Gate 3: Cedar policy.
+    Policy,
+    /// Gate 4: approval workflow.
+    Approval,
+    /// Upstream connection, after all gates passed.
+    Upstream,
+}
+
+/// One audit record (one line of the audit log).
+///
+/// # Traceability
+/// - Implements: REQ-OBS-002 (Audit Trail)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// Schema version ([`AUDIT_SCHEMA_VERSION`]).
+    pub version: u32,
+    /// SHA-256 (hex) of the previous line. Set by the sink when written.
+    #[serde(default)]
+    pub prev_hash: String,
+    /// When the decision was made.
+    pub timestamp: DateTime<Utc>,
+    /// Correlation ID of the request, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Application the request was made on behalf of.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal: Option<String>,
+    /// MCP method (e.g. `tools/call`).
+    pub method: String,
+    /// Governed resource (tool name, resource URI, prompt name or path).
+    pub resource: String,
+    /// The decision.
+    pub decision: AuditDecision,
+    /// Gate that decided.
+    pub gate: AuditGate,
+    /// Matched governance rule or Cedar policy ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+    /// Who approved or rejected (Gate 4 only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approver: Option<String>,
+    /// Approval task ID (Gate 4 only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    /// Human-readable reason for a refusal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Machine-readable code for a refusal, as sent in the
+    /// `ThoughtGate-Reject-Code` response header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reject_code: Option<String>,
+    /// Which policies decided (Gate 3 only, when explanations are enabled).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<Explanation>,
+    /// Upstream the call was sent to (upstream failures only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upstream: Option<String>,
+    /// HMAC-SHA256 (hex) of the line without this field. Set by the sink
+    /// when a key is configured.
+    ///
+    /// Must stay the last field: verification strips it from the line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hmac: Option<String>,
+}
+
+impl AuditRecord {
+    /// Create a record timestamped now, tagged with the current request ID.
+    pub fn new(
+        method: impl Into<String>,
+        resource: impl Into<String>,
+        decision: AuditDecision,
+        gate: AuditGate,
+    ) -> Self {
+        Self {
+            version: AUDIT_SCHEMA_VERSION,
+            prev_hash: String::new(),
+            timestamp: Utc::now(),
+            request_id: crate::logging_layer::current_request_id().map(|id| id.to_string()),
+            principal: None,
+            method: method.into(),
+            resource: resource.into(),
+            decision,
+            gate,
+            rule: None,
+            approver: None,
+            task_id: None,
+            reason: None,
+            reject_code: None,
+            explanation: None,
+            upstream: None,
+            hmac: None,
+        }
+    }
+
+    /// Set the principal.
+    #[must_use]
+    pub fn with_principal(mut self, principal: impl Into<String>) -> Self {
+        self.principal = Some(principal.into());
+        self
+    }
+
+    /// Set the matched rule or policy ID.
+    #[must_use]
+    pub fn with_rule(mut self, rule: Option<String>) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Set the approver.
+    #[must_use]
+    pub fn with_approver(mut self, approver: impl Into<String>) -> Self {
+        self.approver = Some(approver.into());
+        self
+    }
+
+    /// Set the approval task ID.
+    #[must_use]
+    pub fn with_task_id(mut self, task_id: impl Into<String>) -> Self {
+        self.task_id = Some(task_id.into());
+        self
+    }
+
+    /// Set the reason.
+    #[must_use]
+    pub fn with_reason(mut self, reason: Option<String>) -> Self {
+        self.reason = reason;
+        self
+    }
+
+    /// Set the refusal code.
+    #[must_use]
+    pub fn with_reject_code(mut self, code: Option<&str>) -> Self {
+        self.reject_code = code.map(str::to_string);
+        self
+    }
+
+    /// Set the policy decision explanation.
+    #[must_use]
+    pub fn with_explanation(mut self, explanation: Option<Explanation>) -> Self {
+        self.explanation = explanation;
+        self
+    }
+
+    /// Set the upstream.
+    #[must_use]
+    pub fn with_upstream(mut self, upstream: impl Into<String>) -> Self {
+        self.upstream = Some(upstream.into());
+        self
+    }
+}
+
+/// Newline-delimited JSON audit sink.
+///
+/// # Traceability
+/// - Implements: REQ-OBS-002 (Audit Trail)
+pub struct AuditLog {
+    queue: SyncSender<Command>,
+    dropped: AtomicU64,
+}
+
+/// Work for the writer thread.
+enum Command {
+    /// Sign records written from now on with this key.
+    Key(Vec<u8>),
+    /// Chain and write one record.
+    Record(Box<AuditRecord>),
+    /// Acknowledge once everything queued before has been written.
+    Flush(SyncSender<()>),
+}
+
+/// Writer plus the hash of the last line written to it. Owned by the
+/// writer thread, so records are chained in the order they are written.
+struct ChainState {
+    writer: Box<dyn Write + Send>,
+    prev_hash: String,
+    hmac_key: Option<Vec<u8>>,
+}
+
+impl std::fmt::Debug for AuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditLog").finish_non_exhaustive()
+    }
+}
+
+impl AuditLog {
+    /// Write records to any writer from a dedicated thread, starting a new
+    /// chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the writer thread cannot start.
+    pub fn from_writer(writer: impl Write + Send + 'static) -> std::io::Result<Self> {
+        Self::chained(writer, GENESIS_HASH.to_string(), queue_size())
+    }
+
+    /// Start the writer thread. It exits once the log is dropped and its
+    /// queue drained.
+    fn chained(
+        writer: impl Write + Send + 'static,
+        prev_hash: String,
+        queue_size: usize,
+    ) -> std::io::Result<Self> {
+        let (queue, commands) = mpsc::sync_channel::<Command>(queue_size.max(1));
+        let mut state = ChainState {
+            writer: Box::new(writer),
+            prev_hash,
+            hmac_key: None,
+        };
+        std::thread::Builder::new()
+            .name("thoughtgate-audit".to_string())
+            .spawn(move || {
+                for command in commands {
+                    match command {
+                        Command::Key(key) => state.hmac_key = Some(key),
+                        Command::Record(record) => state.write(*record),
+                        // The caller may have stopped waiting
+                        Command::Flush(done) => {
+                            let _ = done.send(());
+                        }
+                    }
+                }
+            })?;
+        Ok(Self {
+            queue,
+            dropped: AtomicU64::new(0),
+        })
+    }
+
+    /// Sign every record with HMAC-SHA256 under `key`.
+    #[must_use]
+    pub fn with_hmac_key(self, key: impl Into<Vec<u8>>) -> Self {
+        // Nothing is queued yet, so this only waits if the thread died
+        let _ = self.queue.send(Command::Key(key.into()));
+        self
+    }
+
+    /// Write records to stdout.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the writer thread cannot start.
+    pub fn stdout() -> std::io::Result<Self> {
+        Self::from_writer(std::io::stdout())
+    }
+
+    /// Append records to a file, creating it if needed.
+    ///
+    /// The chain continues from the file's last line.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the file cannot be opened or read, or the
+    /// writer thread cannot start.
+    pub fn file(path: &Path) -> std::io::Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .read(true)
+            .open(path)?;
+
+        let mut prev_hash = GENESIS_HASH.to_string();
+        for line in BufReader::new(&file).lines() {
+            let line = line?;
+            if !line.is_empty() {
+                prev_hash = line_hash(&line);
+            }
+        }
+        Self::chained(file, prev_hash, queue_size())
+    }
+
+    /// Build the sink named by `THOUGHTGATE_AUDIT_LOG`, if set, signed with
+    /// `THOUGHTGATE_AUDIT_HMAC_KEY` if that is set too.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the audit file cannot be opened or the
+    /// writer thread cannot start.
+    pub fn from_env() -> std::io::Result<Option<Self>> {
+        let log = match std::env::var("THOUGHTGATE_AUDIT_LOG").as_deref() {
+            Err(_) | Ok("") => return Ok(None),
+            Ok("stdout") => Self::stdout()?,
+            Ok(path) => Self::file(Path::new(path))?,
+        };
+        Ok(Some(match std::env::var("THOUGHTGATE_AUDIT_HMAC_KEY") {
+            Ok(key) if !key.is_empty() => log.with_hmac_key(key),
+            _ => log,
+        }))
+    }
+
+    /// Queue one record for writing.
+    ///
+    /// Never blocks. Failures are logged; the decision itself is not
+    /// affected.
+    pub fn record(&self, record: &AuditRecord) {
+        match self
+            .queue
+            .try_send(Command::Record(Box::new(record.clone())))
+        {
+            Ok(()) => {}
+            Err(TrySendError::Full(_) | TrySendError::Disconnected(_)) => {
+                // Disconnected only if the writer thread died; either way it is lost
+                error!(
+                    method = %record.method,
+                    resource = %record.resource,
+                    "Audit record dropped"
+                );
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Records dropped because the queue was full.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Block until every record queued so far has been written.
+    ///
+    /// Returns immediately if the writer thread has died.
+    pub fn flush(&self) {
+        let (done, flushed) = mpsc::sync_channel(1);
+        if self.queue.send(Command::Flush(done)).is_ok() {
+            let _ = flushed.recv();
+        }
+    }
+}
+
+impl ChainState {
+    /// Chain, sign and write one record.
+    fn write(&mut self, mut record: AuditRecord) {
+        record.prev_hash = self.prev_hash.clone();
+        record.hmac = None;
+        let mut line = match serde_json::to_string(&record) {
+            Ok(line) => line,
+            Err(e) => {
+                error!(error = %e, "Failed to serialize audit record");
+                return;
+            }
+        };
+        if let Some(key) = &self.hmac_key {
+            let mac = hex::encode(hmac_sha256(key, line.as_bytes()));
+            line.pop();
+            line.push_str(&format!(r#","hmac":"{mac}"}}"#));
+        }
+
+        let hash = line_hash(&line);
+        line.push('\n');
+        match self
+            .writer
+            .write_all(line.as_bytes())
+            .and_then(|()| self.writer.flush())
+        {
+            Ok(()) => self.prev_hash = hash,
+            Err(e) => error!(error = %e, "Failed to write audit record"),
+        }
+    }
+}
+
+/// `THOUGHTGATE_AUDIT_QUEUE_SIZE`, or the default.
+fn queue_size() -> usize {
+    std::env::var("THOUGHTGATE_AUDIT_QUEUE_SIZE")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|s| *s > 0)
+        .unwrap_or(DEFAULT_QUEUE_SIZE)
+}
+
+/// SHA-256 (hex) of one line as written, without its newline.
+fn line_hash(line: &str) -> String {
+    hex::encode(Sha256::digest(line.as_bytes()))
+}
+
+/// Verify the hash chain of an audit log.
+///
+/// Implements: REQ-OBS-002 (Audit Trail)
+///
+/// # Errors
+///
+/// Returns the zero-based index of the first record whose `prev_hash` does
+/// not match the line before it (or that cannot be read or parsed). A
+/// modified record is reported at the index of the record after it; a
+/// truncated tail is not detectable from the chain alone.
+pub fn verify_audit_chain(reader: impl std::io::Read) -> Result<(), usize> {
+    verify(reader, None)
+}
+
+/// Verify the hash chain and every record's HMAC under `key`.
+///
+/// # Errors
+///
+/// Returns the zero-based index of the first record with a broken link or a
+/// missing or wrong HMAC, so a modified record is reported at its own index.
+pub fn verify_audit_chain_with_key(reader: impl std::io::Read, key: &[u8]) -> Result<(), usize> {
+    verify(reader, Some(key))
+}
+
+fn verify(reader: impl std::io::Read, key: Option<&[u8]>) -> Result<(), usize> {
+    #[derive(Deserialize)]
+    struct Link {
+        prev_hash: String,
+    }
+
+    let mut prev_hash = GENESIS_HASH.to_string();
+    let lines = BufReader::new(reader)
+        .lines()
+        .filter(|line| !matches!(line, Ok(l) if l.is_empty()));
+    for (index, line) in lines.enumerate() {
+        let line = line.map_err(|_| index)?;
+        let link: Link = serde_json::from_str(&line).map_err(|_| index)?;
+        if link.prev_hash != prev_hash {
+            return Err(index);
+        }
+        if let Some(key) = key
+            && !hmac_matches(&line, key)
+        {
+            return Err(index);
+        }
+        prev_hash = line_hash(&line);
+    }
+    Ok(())
+}
+
+/// Check the trailing `"hmac"` field against the rest of the line.
+fn hmac_matches(line: &str, key: &[u8]) -> bool {
+    const PREFIX: &[u8] = br#","hmac":""#;
+    const SUFFIX: &[u8] = br#""}"#;
+    const MAC_HEX_LEN: usize = 64;
+
+    let line = line.as_bytes();
+    let Some(split) = line
+        .len()
+        .checked_sub(PREFIX.len() + MAC_HEX_LEN + SUFFIX.len())
+    else {
+        return false;
+    };
+    let (body, tail) = line.split_at(split);
+    let Some(provided) = tail
+        .strip_prefix(PREFIX)
+        .and_then(|t| t.strip_suffix(SUFFIX))
+        .and_then(|hex_mac| hex::decode(hex_mac).ok())
+    else {
+        return false;
+    };
+
+    let mut signed = body.to_vec();
+    signed.push(b'}');
+    constant_time_eq(&hmac_sha256(key, &signed), &provided)
+}
+
+/// Global audit sink.
+static AUDIT_LOG: once_cell::sync::OnceCell<Arc<AuditLog>> = once_cell::sync::OnceCell::new();
+
+/// Install the global audit sink (first call wins).
+pub fn init_audit(log: AuditLog) {
+    let _ = AUDIT_LOG.set(Arc::new(log));
+}
+
+/// Get the global audit sink, if installed.
+pub fn get_audit_log() -> Option<Arc<AuditLog>> {
+    AUDIT_LOG.get().cloned()
+}
+
+/// Whether an audit sink is installed.
+///
+/// Lets callers skip building records (e.g. inferring the principal) when
+/// auditing is off.
+pub fn is_enabled() -> bool {
+    AUDIT_LOG.get().is_some()
+}
+
+/// Write a record to the global audit sink, if installed.
+pub fn record(record: AuditRecord) {
+    if let Some(log) = AUDIT_LOG.get() {
+        log.record(&record);
+    }
+}
+
+/// Wait for the global audit sink, if installed, to write every queued
+/// record.
+pub fn flush() {
+    if let Some(log) = AUDIT_LOG.get() {
+        log.flush();
+    }
+}
+
+/// Test support: a shared in-memory sink installed as the global sink.
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    static CAPTURED: once_cell::sync::Lazy<Captured> = once_cell::sync::Lazy::new(|| {
+        let captured = Captured::default();
+        init_audit(AuditLog::from_writer(captured.clone()).unwrap());
+        captured
+    });
+
+    /// Install the capturing sink (idempotent).
+    pub(crate) fn install() {
+        once_cell::sync::Lazy::force(&CAPTURED);
+    }
+
+    /// Records captured so far for `resource`.
+    ///
+    /// Tests share the global sink, so each should use a unique resource.
+    pub(crate) fn records_for(resource: &str) -> Vec<AuditRecord> {
+        flush();
+        let bytes = CAPTURED.0.lock().unwrap().clone();
+        String::from_utf8(bytes)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<AuditRecord>(line).unwrap())
+            .filter(|r| r.resource == resource)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_log(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "thoughtgate_test_audit_{}_{name}.ndjson",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn write_records(log: &AuditLog, count: usize) {
+        for i in 0..count {
+            log.record(&AuditRecord::new(
+                "tools/call",
+                format!("tool_{i}"),
+                AuditDecision::Forward,
+                AuditGate::Governance,
+            ));
+        }
+        log.flush();
+    }
+
+    /// Replace `tool_1` with `tool_x` in the second line.
+    fn tamper_middle(contents: &str) -> String {
+        let mut lines: Vec<String> = contents.lines().map(String::from).collect();
+        lines[1] = lines[1].replace("tool_1", "tool_x");
+        lines.join("\n") + "\n"
+    }
+
+    #[test]
+    fn test_record_is_versioned_ndjson() {
+        let path = temp_log("ndjson");
+
+        let log = AuditLog::file(&path).unwrap();
+        log.record(
+            &AuditRecord::new(
+                "tools/call",
+                "delete_user",
+                AuditDecision::Deny,
+                AuditGate::Governance,
+            )
+            .with_principal("app")
+            .with_rule(Some("delete_*".to_string())),
+        );
+        log.record(&AuditRecord::new(
+            "tools/call",
+            "get_user",
+            AuditDecision::Forward,
+            AuditGate::Governance,
+        ));
+        log.flush();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let lines: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["version"], AUDIT_SCHEMA_VERSION);
+        assert_eq!(lines[0]["decision"], "deny");
+        assert_eq!(lines[0]["gate"], "governance");
+        assert_eq!(lines[0]["rule"], "delete_*");
+        assert_eq!(lines[0]["principal"], "app");
+        assert_eq!(lines[1]["decision"], "forward");
+        // Unset optional fields are omitted
+        assert!(lines[1].get("approver").is_none());
+    }
+
+    #[test]
+    fn test_full_queue_drops_records() {
+        /// Blocks every write until released.
+        struct Gated(mpsc::Receiver<()>);
+
+        impl Write for Gated {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                let _ = self.0.recv();
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let (release, gate) = mpsc::channel();
+        let log = AuditLog::chained(Gated(gate), GENESIS_HASH.to_string(), 1).unwrap();
+
+        // At most one record is being written and one is queued
+        for i in 0..3 {
+            log.record(&AuditRecord::new(
+                "tools/call",
+                format!("tool_{i}"),
+                AuditDecision::Forward,
+                AuditGate::Governance,
+            ));
+        }
+        assert!(log.dropped() >= 1);
+
+        drop(release);
+        log.flush();
+    }
+
+    #[test]
+    fn test_intact_chain_verifies() {
+        let path = temp_log("intact");
+        write_records(&AuditLog::file(&path).unwrap(), 3);
+        // Reopening continues the chain from the last line
+        write_records(&AuditLog::file(&path).unwrap(), 2);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(contents.lines().count(), 5);
+        assert!(contents.starts_with(&format!(
+            r#"{{"version":{AUDIT_SCHEMA_VERSION},"prev_hash":"{GENESIS_HASH}""#
+        )));
+        assert_eq!(verify_audit_chain(contents.as_bytes()), Ok(()));
+    }
+
+    #[test]
+    fn test_modified_middle_record_detected() {
+        let path = temp_log("tampered");
+        write_records(&AuditLog::file(&path).unwrap(), 3);
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        // The edit breaks the link from the record after it
+        let tampered = tamper_middle(&contents);
+        assert_eq!(verify_audit_chain(tampered.as_bytes()), Err(2));
+
+        // Deleting the middle record is caught the same way
+        let mut lines: Vec<&str> = contents.lines().collect();
+        lines.remove(1);
+        assert_eq!(verify_audit_chain(lines.join("\n").as_bytes()), Err(1));
+    }
+
+    #[test]
+    fn test_hmac_pinpoints_modified_record() {
+        let path = temp_log("hmac");
+        write_records(&AuditLog::file(&path).unwrap().with_hmac_key("secret"), 3);
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert!(contents.lines().all(|l| l.contains(r#""hmac":""#)));
+        assert_eq!(
+            verify_audit_chain_with_key(contents.as_bytes(), b"secret"),
+            Ok(())
+        );
+        assert_eq!(
+            verify_audit_chain_with_key(contents.as_bytes(), b"wrong"),
+            Err(0)
+        );
+
+        let tampered = tamper_middle(&contents);
+        assert_eq!(
+            verify_audit_chain_with_key(tampered.as_bytes(), b"secret"),
+            Err(1)
+        );
+    }
+}
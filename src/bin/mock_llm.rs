@@ -0,0 +1,521 @@
+//! Mock LLM Server for streaming tests of the ThoughtGate proxy.
+//!
+//! Serves an OpenAI-style streaming chat completion as Server-Sent Events:
+//! one `data:` chunk per token, then `data: [DONE]`. Timing is configurable
+//! so tests can drive slow-drip, fast-burst and stalled streams.
+//!
+//! A request body with `"stream": false` gets a single non-streaming
+//! `chat.completion` JSON object instead. A missing body or `stream` flag
+//! streams.
+//!
+//! # Environment Variables
+//!
+//! - `MOCK_LLM_PORT`: Listen port (default: 9998)
+//! - `MOCK_LLM_TOKENS`: Tokens per response (default: 50)
+//! - `MOCK_LLM_TTFB_MS`: Delay before the response starts (default: 500)
+//! - `MOCK_LLM_INTERVAL_MS`: Delay between tokens (default: 10)
+//! - `MOCK_LLM_STALL_AFTER`: Stall after this many tokens (default: never)
+//! - `MOCK_LLM_STALL_MS`: How long to stall (default: forever)
+//!
+//! The listener honours the proxy's socket settings: `THOUGHTGATE_TCP_NODELAY`,
+//! `THOUGHTGATE_TCP_REUSEADDR` and `THOUGHTGATE_LISTEN_BACKLOG`.
+//!
+//! Each setting can be overridden per request with a query parameter of the
+//! same name in lowercase without the prefix, e.g.
+//! `?tokens=200&ttfb_ms=2000&interval_ms=5&stall_after=10`.
+//!
+//! A stall holds the connection open without sending data, which trips the
+//! proxy's per-chunk timeout (`TimeoutBody`).
+//!
+//! # Failure Injection
+//!
+//! - `MOCK_LLM_FAIL` / `fail`: failure mode, one of:
+//!   - `status`: respond immediately with HTTP `fail_status` and a JSON
+//!     error body.
+//!   - `drop`: send `fail_after` tokens, then abort the connection without
+//!     finishing the response.
+//!   - `malformed`: replace token `fail_after` with an SSE frame whose data
+//!     is not valid JSON, then finish normally. In non-streaming mode the
+//!     JSON body is truncated.
+//! - `MOCK_LLM_FAIL_STATUS` / `fail_status`: status for `status` mode
+//!   (default: 503)
+//! - `MOCK_LLM_FAIL_AFTER` / `fail_after`: token at which `drop` and
+//!   `malformed` fail (default: half of `tokens`)
+//! - `MOCK_LLM_FAIL_EVERY` / `fail_every`: fail only every Nth request,
+//!   counted per server (default: 1, every request)
+//!
+//! ```bash
+//! # Every third request gets a 503
+//! MOCK_LLM_FAIL=status MOCK_LLM_FAIL_EVERY=3 cargo run --bin mock_llm --features mock
+//!
+//! # Abort the stream after 5 tokens
+//! curl -N -X POST 'http://localhost:9998/v1/chat/completions?fail=drop&fail_after=5'
+//! ```
+//!
+//! # Usage
+//!
+//! ```bash
+//! # Start with defaults
+//! cargo run --bin mock_llm --features mock
+//!
+//! # Slow drip: 5 tokens, one per second
+//! curl -N -X POST 'http://localhost:9998/v1/chat/completions?tokens=5&interval_ms=1000'
+//!
+//! # Stall after 10 tokens to trigger a chunk timeout
+//! curl -N -X POST 'http://localhost:9998/v1/chat/completions?stall_after=10'
+//! ```
+
+use axum::serve::ListenerExt;
+use axum::{
+    Json, Router,
+    body::{Body, Bytes},
+    extract::{Query, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use futures_util::stream;
+use serde::Deserialize;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use thoughtgate::connection::bind_listener;
+use thoughtgate::proxy_config::ProxyConfig;
+use tokio::time::sleep;
+
+/// Stream timing, from the environment and optionally per request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct StreamSettings {
+    tokens: u32,
+    ttfb_ms: u64,
+    interval_ms: u64,
+    stall_after: Option<u32>,
+    /// `None` stalls forever.
+    stall_ms: Option<u64>,
+    fail: Option<FailureMode>,
+    fail_status: u16,
+    /// `None` fails halfway through.
+    fail_after: Option<u32>,
+    fail_every: u64,
+}
+
+/// How an injected failure manifests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum FailureMode {
+    Status,
+    Drop,
+    Malformed,
+}
+
+impl std::str::FromStr for FailureMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "status" => Ok(Self::Status),
+            "drop" => Ok(Self::Drop),
+            "malformed" => Ok(Self::Malformed),
+            other => Err(format!("unknown failure mode: {other}")),
+        }
+    }
+}
+
+impl Default for StreamSettings {
+    fn default() -> Self {
+        Self {
+            tokens: 50,
+            ttfb_ms: 500,
+            interval_ms: 10,
+            stall_after: None,
+            stall_ms: None,
+            fail: None,
+            fail_status: 503,
+            fail_after: None,
+            fail_every: 1,
+        }
+    }
+}
+
+/// Per-request overrides from the query string.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StreamQuery {
+    tokens: Option<u32>,
+    ttfb_ms: Option<u64>,
+    interval_ms: Option<u64>,
+    stall_after: Option<u32>,
+    stall_ms: Option<u64>,
+    fail: Option<FailureMode>,
+    fail_status: Option<u16>,
+    fail_after: Option<u32>,
+    fail_every: Option<u64>,
+}
+
+fn env_var<T: std::str::FromStr>(name: &str) -> Option<T> {
+    std::env::var(name).ok().and_then(|s| s.parse().ok())
+}
+
+impl StreamSettings {
+    /// Defaults overridden by `MOCK_LLM_*` environment variables.
+    fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            tokens: env_var("MOCK_LLM_TOKENS").unwrap_or(defaults.tokens),
+            ttfb_ms: env_var("MOCK_LLM_TTFB_MS").unwrap_or(defaults.ttfb_ms),
+            interval_ms: env_var("MOCK_LLM_INTERVAL_MS").unwrap_or(defaults.interval_ms),
+            stall_after: env_var("MOCK_LLM_STALL_AFTER"),
+            stall_ms: env_var("MOCK_LLM_STALL_MS"),
+            fail: env_var("MOCK_LLM_FAIL"),
+            fail_status: env_var("MOCK_LLM_FAIL_STATUS").unwrap_or(defaults.fail_status),
+            fail_after: env_var("MOCK_LLM_FAIL_AFTER"),
+            fail_every: env_var("MOCK_LLM_FAIL_EVERY").unwrap_or(defaults.fail_every),
+        }
+    }
+
+    /// Apply query parameter overrides.
+    fn with_query(self, query: StreamQuery) -> Self {
+        Self {
+            tokens: query.tokens.unwrap_or(self.tokens),
+            ttfb_ms: query.ttfb_ms.unwrap_or(self.ttfb_ms),
+            interval_ms: query.interval_ms.unwrap_or(self.interval_ms),
+            stall_after: query.stall_after.or(self.stall_after),
+            stall_ms: query.stall_ms.or(self.stall_ms),
+            fail: query.fail.or(self.fail),
+            fail_status: query.fail_status.unwrap_or(self.fail_status),
+            fail_after: query.fail_after.or(self.fail_after),
+            fail_every: query.fail_every.unwrap_or(self.fail_every),
+        }
+    }
+
+    /// The failure to inject into the `nth` request (1-based), if any.
+    fn failure_for(&self, nth: u64) -> Option<FailureMode> {
+        self.fail
+            .filter(|_| self.fail_every <= 1 || nth.is_multiple_of(self.fail_every))
+    }
+
+    /// Token index at which `drop` and `malformed` fail.
+    fn fail_index(&self) -> u32 {
+        self.fail_after.unwrap_or(self.tokens / 2).min(self.tokens)
+    }
+}
+
+/// Number of completion requests served, for `fail_every`.
+type RequestCounter = Arc<AtomicU64>;
+
+/// SSE frame whose data is not valid JSON.
+const MALFORMED_FRAME: &str = "data: {\"id\":\"chatcmpl-mock\",\"choices\":[\n\n";
+
+fn aborted() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::ConnectionAborted,
+        "injected connection drop",
+    )
+}
+
+/// The parts of the request body the mock looks at.
+#[derive(Debug, Deserialize)]
+struct CompletionRequest {
+    #[serde(default = "default_stream")]
+    stream: bool,
+    #[serde(default)]
+    messages: Vec<serde_json::Value>,
+}
+
+fn default_stream() -> bool {
+    true
+}
+
+impl CompletionRequest {
+    /// Parse the body, treating an empty or unparseable body as defaults.
+    fn parse(body: &[u8]) -> Self {
+        serde_json::from_slice(body).unwrap_or(Self {
+            stream: true,
+            messages: Vec::new(),
+        })
+    }
+
+    /// Synthetic prompt token count: whitespace-separated words in messages.
+    fn prompt_tokens(&self) -> usize {
+        self.messages
+            .iter()
+            .filter_map(|m| m.get("content").and_then(serde_json::Value::as_str))
+            .map(|c| c.split_whitespace().count())
+            .sum()
+    }
+}
+
+/// Content of token `index`.
+fn token_text(index: u32) -> String {
+    format!("token{index} ")
+}
+
+/// A complete non-streaming `chat.completion` object.
+fn completion_json(settings: &StreamSettings, prompt_tokens: usize) -> serde_json::Value {
+    let content: String = (0..settings.tokens).map(token_text).collect();
+    let completion_tokens = settings.tokens as usize;
+    serde_json::json!({
+        "id": "chatcmpl-mock",
+        "object": "chat.completion",
+        "model": "mock",
+        "choices": [{
+            "index": 0,
+            "message": { "role": "assistant", "content": content },
+            "finish_reason": "stop"
+        }],
+        "usage": {
+            "prompt_tokens": prompt_tokens,
+            "completion_tokens": completion_tokens,
+            "total_tokens": prompt_tokens + completion_tokens
+        }
+    })
+}
+
+/// One SSE event carrying token `index`.
+fn token_event(index: u32) -> String {
+    let chunk = serde_json::json!({
+        "id": "chatcmpl-mock",
+        "object": "chat.completion.chunk",
+        "choices": [{
+            "index": 0,
+            "delta": { "content": token_text(index) },
+            "finish_reason": null
+        }]
+    });
+    format!("data: {chunk}\n\n")
+}
+
+/// Handle chat completion requests, streaming unless `stream` is false.
+async fn handle_completion(
+    State(requests): State<RequestCounter>,
+    Query(query): Query<StreamQuery>,
+    body: Bytes,
+) -> Response {
+    let settings = StreamSettings::from_env().with_query(query);
+    let request = CompletionRequest::parse(&body);
+    let failure = settings.failure_for(requests.fetch_add(1, Ordering::Relaxed) + 1);
+
+    if failure == Some(FailureMode::Status) {
+        let status =
+            StatusCode::from_u16(settings.fail_status).unwrap_or(StatusCode::SERVICE_UNAVAILABLE);
+        let error = serde_json::json!({
+            "error": { "message": "injected failure", "type": "mock_error" }
+        });
+        return (status, Json(error)).into_response();
+    }
+
+    if settings.ttfb_ms > 0 {
+        sleep(Duration::from_millis(settings.ttfb_ms)).await;
+    }
+
+    if !request.stream {
+        let body = completion_json(&settings, request.prompt_tokens()).to_string();
+        let half = body[..body.len() / 2].to_string();
+        let body = match failure {
+            Some(FailureMode::Malformed) => Body::from(half),
+            Some(FailureMode::Drop) => Body::from_stream(stream::iter([Ok(half), Err(aborted())])),
+            _ => Body::from(body),
+        };
+        return Response::builder()
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .unwrap_or_default();
+    }
+
+    let fail_index = failure.map(|mode| (mode, settings.fail_index()));
+    let events = stream::unfold(0u32, move |index| async move {
+        if index > settings.tokens {
+            return None;
+        }
+        if index == settings.tokens {
+            return Some((Ok("data: [DONE]\n\n".to_string()), index + 1));
+        }
+        match fail_index {
+            Some((FailureMode::Drop, at)) if at == index => {
+                return Some((Err(aborted()), settings.tokens + 1));
+            }
+            Some((FailureMode::Malformed, at)) if at == index => {
+                return Some((Ok(MALFORMED_FRAME.to_string()), index + 1));
+            }
+            _ => {}
+        }
+        if settings.stall_after == Some(index) {
+            match settings.stall_ms {
+                Some(ms) => sleep(Duration::from_millis(ms)).await,
+                None => std::future::pending::<()>().await,
+            }
+        } else if index > 0 && settings.interval_ms > 0 {
+            sleep(Duration::from_millis(settings.interval_ms)).await;
+        }
+        Some((Ok(token_event(index)), index + 1))
+    });
+
+    Response::builder()
+        .header(header::CONTENT_TYPE, "text/event-stream")
+        .header(header::CACHE_CONTROL, "no-cache")
+        .body(Body::from_stream(events))
+        .unwrap_or_default()
+}
+
+/// Health check endpoint.
+async fn health() -> &'static str {
+    "OK"
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/v1/chat/completions", post(handle_completion))
+        .route("/health", get(health))
+        .with_state(RequestCounter::default())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let port: u16 = env_var("MOCK_LLM_PORT").unwrap_or(9998);
+
+    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+    println!(
+        "Mock LLM server listening on http://{} ({:?})",
+        addr,
+        StreamSettings::from_env()
+    );
+
+    let socket_config = ProxyConfig::from_env();
+    let nodelay = socket_config.tcp_nodelay;
+    let listener = bind_listener(addr, &socket_config)
+        .await?
+        .tap_io(move |tcp| {
+            let _ = tcp.set_nodelay(nodelay);
+        });
+    axum::serve(listener, app()).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_overrides_settings() {
+        let query = StreamQuery {
+            tokens: Some(200),
+            ttfb_ms: Some(2000),
+            stall_after: Some(10),
+            ..Default::default()
+        };
+        let settings = StreamSettings::default().with_query(query);
+
+        assert_eq!(
+            settings,
+            StreamSettings {
+                tokens: 200,
+                ttfb_ms: 2000,
+                interval_ms: 10,
+                stall_after: Some(10),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_failure_every_nth_request() {
+        let settings = StreamSettings {
+            fail: Some(FailureMode::Drop),
+            fail_every: 3,
+            ..Default::default()
+        };
+        let failed: Vec<u64> = (1..=9)
+            .filter(|&n| settings.failure_for(n).is_some())
+            .collect();
+        assert_eq!(failed, [3, 6, 9]);
+        assert_eq!(settings.fail_index(), 25);
+        assert!(StreamSettings::default().failure_for(1).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_injected_failures() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app()).await });
+
+        let client = reqwest::Client::new();
+        let base = format!("http://{addr}/v1/chat/completions?tokens=4&ttfb_ms=0&interval_ms=0");
+
+        let status = client
+            .post(format!("{base}&fail=status&fail_status=429"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(status.status(), 429);
+
+        let malformed = client
+            .post(format!("{base}&fail=malformed&fail_after=1"))
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert!(malformed.contains(MALFORMED_FRAME));
+        assert!(malformed.ends_with("data: [DONE]\n\n"));
+
+        // Depending on timing the abort surfaces on the headers or the body
+        let dropped = async {
+            client
+                .post(format!("{base}&fail=drop&fail_after=2"))
+                .send()
+                .await?
+                .text()
+                .await
+        }
+        .await;
+        assert!(dropped.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_stream_flag_selects_content_type() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app()).await });
+
+        let url = format!("http://{addr}/v1/chat/completions?tokens=3&ttfb_ms=0&interval_ms=0");
+        let client = reqwest::Client::new();
+        let send = |stream: bool| {
+            client
+                .post(&url)
+                .json(&serde_json::json!({
+                    "stream": stream,
+                    "messages": [{ "role": "user", "content": "hello there" }]
+                }))
+                .send()
+        };
+
+        let json = send(false).await.unwrap();
+        assert_eq!(json.headers()[header::CONTENT_TYPE], "application/json");
+        let body: serde_json::Value = json.json().await.unwrap();
+        assert_eq!(body["object"], "chat.completion");
+        assert_eq!(body["usage"]["prompt_tokens"], 2);
+        assert_eq!(body["usage"]["completion_tokens"], 3);
+
+        let sse = send(true).await.unwrap();
+        assert_eq!(sse.headers()[header::CONTENT_TYPE], "text/event-stream");
+        assert!(sse.text().await.unwrap().ends_with("data: [DONE]\n\n"));
+    }
+
+    #[test]
+    fn test_missing_body_streams() {
+        assert!(CompletionRequest::parse(b"").stream);
+        assert!(CompletionRequest::parse(b"{}").stream);
+        assert!(!CompletionRequest::parse(br#"{"stream":false}"#).stream);
+    }
+
+    #[test]
+    fn test_token_event_is_sse() {
+        let event = token_event(3);
+        assert!(event.starts_with("data: {"));
+        assert!(event.ends_with("\n\n"));
+        assert!(event.contains("token3"));
+    }
+}
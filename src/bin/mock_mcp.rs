@@ -1,21 +1,50 @@
-//! Mock MCP Server for benchmarking ThoughtGate proxy.
+//! Mock MCP Server for benchmarking and integration testing ThoughtGate.
 //!
-//! A minimal MCP JSON-RPC server that responds to requests with minimal latency.
-//! Used for benchmarking proxy overhead without upstream processing delays.
+//! A minimal MCP JSON-RPC server that answers `tools/list`, `tools/call` and
+//! `resources/read` with canned results. Any other method gets a generic
+//! result. Per-method scenarios can delay the response, return a JSON-RPC
+//! error, or pad the result to a given size to exercise proxy timeouts and
+//! buffer limits.
 //!
 //! # Environment Variables
 //!
 //! - `MOCK_MCP_PORT`: Listen port (default: 9999)
 //! - `MOCK_MCP_DELAY_MS`: Response delay in milliseconds (default: 0)
+//! - `MOCK_MCP_CONFIG`: Path to a JSON scenario file (optional)
+//!
+//! The listener honours the proxy's socket settings: `THOUGHTGATE_TCP_NODELAY`,
+//! `THOUGHTGATE_TCP_REUSEADDR` and `THOUGHTGATE_LISTEN_BACKLOG`.
+//!
+//! # Scenario File
+//!
+//! ```json
+//! {
+//!   "delay_ms": 0,
+//!   "tools": [{ "name": "echo", "description": "Echo the arguments" }],
+//!   "scenarios": {
+//!     "tools/call:slow_tool": { "delay_ms": 5000 },
+//!     "tools/call:broken_tool": { "error": { "code": -32000, "message": "boom" } },
+//!     "resources/read:file:///big": { "payload_bytes": 10485760 },
+//!     "tools/list": { "result": { "tools": [] } }
+//!   }
+//! }
+//! ```
+//!
+//! Scenarios are looked up first by `<method>:<target>` (the tool name for
+//! `tools/call`, the URI for `resources/read`), then by `<method>`. A
+//! scenario's `delay_ms` replaces the global delay.
 //!
 //! # Usage
 //!
 //! ```bash
 //! # Start with defaults (port 9999, no delay)
-//! cargo run --bin mock_mcp
+//! cargo run --bin mock_mcp --features mock
 //!
 //! # Start with custom port and delay
-//! MOCK_MCP_PORT=8888 MOCK_MCP_DELAY_MS=10 cargo run --bin mock_mcp
+//! MOCK_MCP_PORT=8888 MOCK_MCP_DELAY_MS=10 cargo run --bin mock_mcp --features mock
+//!
+//! # Start with a scenario file
+//! MOCK_MCP_CONFIG=scenarios.json cargo run --bin mock_mcp --features mock
 //!
 //! # Test with curl
 //! curl -X POST http://localhost:9999/mcp/v1 \
@@ -23,13 +52,20 @@
 //!   -d '{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"test"}}'
 //! ```
 
+use axum::serve::ListenerExt;
 use axum::{
     Json, Router,
+    extract::State,
     routing::{get, post},
 };
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
 use std::net::SocketAddr;
+use std::sync::Arc;
 use std::time::Duration;
+use thoughtgate::connection::bind_listener;
+use thoughtgate::proxy_config::ProxyConfig;
 use tokio::time::sleep;
 
 /// JSON-RPC 2.0 request structure.
@@ -37,44 +73,214 @@ use tokio::time::sleep;
 #[allow(dead_code)] // Fields are parsed for validation but not all used in response
 struct JsonRpcRequest {
     jsonrpc: String,
-    id: serde_json::Value,
+    id: Value,
     method: String,
     #[serde(default)]
-    params: serde_json::Value,
+    params: Value,
 }
 
 /// JSON-RPC 2.0 response structure.
 #[derive(Debug, Serialize)]
 struct JsonRpcResponse {
     jsonrpc: String,
-    id: serde_json::Value,
-    result: serde_json::Value,
+    id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<ErrorSpec>,
+}
+
+/// JSON-RPC error returned by a scenario.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ErrorSpec {
+    code: i64,
+    message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    data: Option<Value>,
+}
+
+/// Tool advertised by `tools/list`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ToolSpec {
+    name: String,
+    #[serde(default)]
+    description: String,
+}
+
+/// Scripted behavior for one method or method/target pair.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Scenario {
+    /// Delay before responding, replacing the global delay.
+    delay_ms: Option<u64>,
+    /// Respond with this JSON-RPC error instead of a result.
+    error: Option<ErrorSpec>,
+    /// Respond with this result instead of the canned one.
+    result: Option<Value>,
+    /// Pad the text content of the result to this many bytes.
+    payload_bytes: Option<usize>,
+}
+
+/// Scenario file contents.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MockConfig {
+    #[serde(default)]
+    delay_ms: u64,
+    #[serde(default = "default_tools")]
+    tools: Vec<ToolSpec>,
+    #[serde(default)]
+    scenarios: HashMap<String, Scenario>,
+}
+
+impl Default for MockConfig {
+    fn default() -> Self {
+        Self {
+            delay_ms: 0,
+            tools: default_tools(),
+            scenarios: HashMap::new(),
+        }
+    }
+}
+
+fn default_tools() -> Vec<ToolSpec> {
+    vec![ToolSpec {
+        name: "echo".to_string(),
+        description: "Echo the call arguments".to_string(),
+    }]
+}
+
+impl MockConfig {
+    /// Load from `MOCK_MCP_CONFIG` if set, then apply `MOCK_MCP_DELAY_MS`.
+    fn from_env() -> Result<Self, Box<dyn std::error::Error>> {
+        let mut config = match std::env::var("MOCK_MCP_CONFIG") {
+            Ok(path) => {
+                let raw = std::fs::read_to_string(&path)
+                    .map_err(|e| format!("failed to read {path}: {e}"))?;
+                serde_json::from_str(&raw).map_err(|e| format!("invalid {path}: {e}"))?
+            }
+            Err(_) => Self::default(),
+        };
+        if let Some(delay) = std::env::var("MOCK_MCP_DELAY_MS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+        {
+            config.delay_ms = delay;
+        }
+        Ok(config)
+    }
+
+    /// Find the scenario for a request, most specific key first.
+    fn scenario(&self, req: &JsonRpcRequest) -> Option<&Scenario> {
+        let target = match req.method.as_str() {
+            "tools/call" => req.params.get("name"),
+            "resources/read" => req.params.get("uri"),
+            _ => None,
+        }
+        .and_then(Value::as_str);
+
+        target
+            .and_then(|t| self.scenarios.get(&format!("{}:{t}", req.method)))
+            .or_else(|| self.scenarios.get(&req.method))
+    }
+
+    /// Canned result for a method.
+    fn canned_result(&self, req: &JsonRpcRequest) -> Value {
+        match req.method.as_str() {
+            "tools/list" => json!({ "tools": self.tools.iter().map(|t| json!({
+                "name": t.name,
+                "description": t.description,
+                "inputSchema": { "type": "object" },
+            })).collect::<Vec<_>>() }),
+            "tools/call" => {
+                let name = req.params.get("name").and_then(Value::as_str).unwrap_or("");
+                let args = req.params.get("arguments").cloned().unwrap_or(Value::Null);
+                json!({
+                    "content": [{
+                        "type": "text",
+                        "text": format!("mock response for tool: {name} {args}")
+                    }]
+                })
+            }
+            "resources/read" => {
+                let uri = req.params.get("uri").and_then(Value::as_str).unwrap_or("");
+                json!({
+                    "contents": [{
+                        "uri": uri,
+                        "mimeType": "text/plain",
+                        "text": format!("mock contents of {uri}")
+                    }]
+                })
+            }
+            method => json!({
+                "content": [{
+                    "type": "text",
+                    "text": format!("mock response for method: {method}")
+                }]
+            }),
+        }
+    }
+}
+
+/// Replace the text of the first content item with `size` bytes of filler.
+fn pad_result(result: &mut Value, size: usize) {
+    let filler = Value::String("x".repeat(size));
+    let key = if result.get("contents").is_some() {
+        "contents"
+    } else {
+        "content"
+    };
+    if let Some(item) = result
+        .get_mut(key)
+        .and_then(|items| items.get_mut(0))
+        .and_then(Value::as_object_mut)
+    {
+        item.insert("text".to_string(), filler);
+    } else if let Some(obj) = result.as_object_mut() {
+        obj.insert("padding".to_string(), filler);
+    } else {
+        *result = json!({ "padding": filler });
+    }
 }
 
 /// Handle MCP JSON-RPC requests.
 ///
-/// Returns a mock response with configurable delay for benchmarking.
-async fn handle_mcp(Json(req): Json<JsonRpcRequest>) -> Json<JsonRpcResponse> {
-    // Apply configurable delay (default: 0ms for benchmarking)
-    let delay_ms: u64 = std::env::var("MOCK_MCP_DELAY_MS")
-        .ok()
-        .and_then(|s| s.parse().ok())
-        .unwrap_or(0);
+/// Applies the matching scenario, falling back to the canned result and the
+/// global delay (default: 0ms for benchmarking).
+async fn handle_mcp(
+    State(config): State<Arc<MockConfig>>,
+    Json(req): Json<JsonRpcRequest>,
+) -> Json<JsonRpcResponse> {
+    let scenario = config.scenario(&req).cloned().unwrap_or_default();
 
+    let delay_ms = scenario.delay_ms.unwrap_or(config.delay_ms);
     if delay_ms > 0 {
         sleep(Duration::from_millis(delay_ms)).await;
     }
 
-    // Return a valid MCP-style response
+    if let Some(error) = scenario.error {
+        return Json(JsonRpcResponse {
+            jsonrpc: "2.0".to_string(),
+            id: req.id,
+            result: None,
+            error: Some(error),
+        });
+    }
+
+    let mut result = scenario
+        .result
+        .unwrap_or_else(|| config.canned_result(&req));
+    if let Some(size) = scenario.payload_bytes {
+        pad_result(&mut result, size);
+    }
+
     Json(JsonRpcResponse {
         jsonrpc: "2.0".to_string(),
         id: req.id,
-        result: serde_json::json!({
-            "content": [{
-                "type": "text",
-                "text": format!("mock response for method: {}", req.method)
-            }]
-        }),
+        result: Some(result),
+        error: None,
     })
 }
 
@@ -91,17 +297,83 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .and_then(|s| s.parse().ok())
         .unwrap_or(9999);
 
+    let config = Arc::new(MockConfig::from_env()?);
+
     // Build router with MCP and health endpoints
     let app = Router::new()
         .route("/mcp/v1", post(handle_mcp))
         .route("/", post(handle_mcp)) // Root path also valid for MCP
-        .route("/health", get(health));
+        .route("/health", get(health))
+        .with_state(config.clone());
 
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
-    println!("Mock MCP server listening on http://{}", addr);
+    println!(
+        "Mock MCP server listening on http://{} ({} scenarios)",
+        addr,
+        config.scenarios.len()
+    );
 
-    let listener = tokio::net::TcpListener::bind(addr).await?;
+    let socket_config = ProxyConfig::from_env();
+    let nodelay = socket_config.tcp_nodelay;
+    let listener = bind_listener(addr, &socket_config)
+        .await?
+        .tap_io(move |tcp| {
+            let _ = tcp.set_nodelay(nodelay);
+        });
     axum::serve(listener, app).await?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, params: Value) -> JsonRpcRequest {
+        JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: json!(1),
+            method: method.to_string(),
+            params,
+        }
+    }
+
+    #[test]
+    fn test_scenario_lookup_prefers_target() {
+        let config: MockConfig = serde_json::from_value(json!({
+            "scenarios": {
+                "tools/call": { "delay_ms": 1 },
+                "tools/call:slow": { "delay_ms": 2 },
+                "resources/read:file:///big": { "payload_bytes": 4 }
+            }
+        }))
+        .unwrap();
+
+        let slow = request("tools/call", json!({ "name": "slow" }));
+        let other = request("tools/call", json!({ "name": "other" }));
+        let big = request("resources/read", json!({ "uri": "file:///big" }));
+        let list = request("tools/list", Value::Null);
+
+        assert_eq!(config.scenario(&slow).unwrap().delay_ms, Some(2));
+        assert_eq!(config.scenario(&other).unwrap().delay_ms, Some(1));
+        assert_eq!(config.scenario(&big).unwrap().payload_bytes, Some(4));
+        assert!(config.scenario(&list).is_none());
+    }
+
+    #[test]
+    fn test_pad_result() {
+        let config = MockConfig::default();
+        let mut result =
+            config.canned_result(&request("resources/read", json!({ "uri": "file:///a" })));
+        pad_result(&mut result, 16);
+        assert_eq!(result["contents"][0]["text"].as_str().unwrap().len(), 16);
+    }
+
+    #[test]
+    fn test_unknown_scenario_field_rejected() {
+        let err = serde_json::from_value::<MockConfig>(json!({
+            "scenarios": { "tools/list": { "delay": 5 } }
+        }));
+        assert!(err.is_err());
+    }
+}
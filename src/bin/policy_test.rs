@@ -0,0 +1,75 @@
+//! Run policy test fixtures against ThoughtGate's Cedar policies.
+//!
+//! Each fixture file is a YAML or JSON list of requests and the decision
+//! expected for them (see `thoughtgate::policy::testkit`). Policies are
+//! loaded exactly as the proxy loads them, so the same environment applies:
+//!
+//! - `THOUGHTGATE_POLICY_FILE`: Cedar policy file
+//!   (default: /etc/thoughtgate/policies.cedar)
+//! - `THOUGHTGATE_POLICIES`: Inline policies, if the file does not exist
+//! - `THOUGHTGATE_POLICY_LINKS_FILE`, `THOUGHTGATE_POLICY_NAMESPACE_DIR`
+//!   and `THOUGHTGATE_POLICY_FAST_PATH_FILE`: template links, namespace
+//!   policies and allow/deny lists
+//!
+//! Failed cases are printed with the policies that decided them. The exit
+//! status is 0 when every case passes, 1 when any fails, and 2 when the
+//! policies or a fixture cannot be loaded.
+//!
+//! # Usage
+//!
+//! ```bash
+//! THOUGHTGATE_POLICY_FILE=policies.cedar cargo run --bin policy_test -- tests/policies/*.yaml
+//! ```
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+use thoughtgate::policy::engine::CedarEngine;
+use thoughtgate::policy::testkit::{self, PolicyTestCase};
+
+/// Run policy test fixtures.
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// Fixture files to run
+    #[arg(required = true)]
+    fixtures: Vec<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let mut fixtures: Vec<(PathBuf, Vec<PolicyTestCase>)> = Vec::new();
+    for path in args.fixtures {
+        match testkit::load_cases(&path) {
+            Ok(cases) => fixtures.push((path, cases)),
+            Err(e) => {
+                eprintln!("{}: {e}", path.display());
+                return ExitCode::from(2);
+            }
+        }
+    }
+
+    let attributes = testkit::attribute_types(fixtures.iter().flat_map(|(_, cases)| cases));
+    let engine = match CedarEngine::new_with_attributes(&attributes) {
+        Ok(engine) => engine,
+        Err(e) => {
+            eprintln!("Failed to load policies: {e}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut passed = true;
+    for (path, cases) in &fixtures {
+        let report = testkit::run(&engine, cases);
+        println!("{}\n{report}", path.display());
+        passed &= report.passed();
+    }
+
+    if passed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
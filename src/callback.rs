@@ -0,0 +1,202 @@
+//! Approval callback server.
+//!
+//! # Traceability
+//! - Implements: REQ-GOV-003/F-003 (Decisions via callback)
+//!
+//! # Overview
+//!
+//! Serves `POST /approval/callback` on the inbound port (default: 7468).
+//! Approval channels that push decisions instead of being polled (the
+//! generic webhook, Slack buttons) deliver them here. The active
+//! [`ApprovalAdapter`](crate::governance::ApprovalAdapter) verifies each
+//! callback's signature before the decision is applied.
+//!
+//! | Outcome | Status |
+//! |---------|--------|
+//! | Decision applied | 200 `{"ok": true, "task_id": "..."}` |
+//! | Malformed payload | 400 |
+//! | Bad or stale signature | 401 |
+//! | Adapter takes no callbacks, or task not pending | 404 |
+
+use axum::{
+    Json, Router,
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::post,
+};
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::governance::{ApprovalEngine, CallbackError};
+
+/// Path decisions are POSTed to.
+pub const CALLBACK_PATH: &str = "/approval/callback";
+
+/// Approval callback server.
+///
+/// # Traceability
+/// - Implements: REQ-GOV-003/F-003 (Decisions via callback)
+pub struct CallbackServer {
+    engine: Arc<ApprovalEngine>,
+}
+
+impl CallbackServer {
+    /// Create a callback server applying decisions to `engine`.
+    pub fn new(engine: Arc<ApprovalEngine>) -> Self {
+        Self { engine }
+    }
+
+    /// Create the Axum router for the callback server.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route(CALLBACK_PATH, post(callback_handler))
+            .with_state(self.engine.clone())
+    }
+
+    /// Serve callbacks on `listener` until `shutdown` is cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server fails to serve.
+    pub async fn run(
+        self,
+        listener: TcpListener,
+        shutdown: CancellationToken,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        info!(addr = ?listener.local_addr().ok(), "Approval callback server listening");
+
+        axum::serve(listener, self.router())
+            .with_graceful_shutdown(async move {
+                shutdown.cancelled().await;
+                info!("Approval callback server shutting down");
+            })
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Verify and apply one decision callback.
+///
+/// # Traceability
+/// - Implements: REQ-GOV-003/F-003 (Decisions via callback)
+async fn callback_handler(
+    State(engine): State<Arc<ApprovalEngine>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
+    match engine.handle_callback(&headers, &body).await {
+        Ok(task_id) => (
+            StatusCode::OK,
+            Json(serde_json::json!({ "ok": true, "task_id": task_id })),
+        )
+            .into_response(),
+        Err(e) => {
+            let status = match e {
+                CallbackError::Signature(_) => StatusCode::UNAUTHORIZED,
+                CallbackError::InvalidPayload { .. } => StatusCode::BAD_REQUEST,
+                CallbackError::Unsupported { .. } | CallbackError::UnknownTask { .. } => {
+                    StatusCode::NOT_FOUND
+                }
+                // Retriable: the sender should deliver the decision again
+                CallbackError::Store(_) => StatusCode::SERVICE_UNAVAILABLE,
+            };
+            warn!(error = %e, status = %status, "Rejected approval callback");
+            (
+                status,
+                Json(serde_json::json!({ "ok": false, "error": e.to_string() })),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ThoughtGateError;
+    use crate::governance::ApprovalEngineConfig;
+    use crate::governance::TaskStore;
+    use crate::governance::approval::{WebhookAdapter, WebhookConfig, sign_webhook};
+    use crate::transport::{JsonRpcResponse, McpRequest, UpstreamForwarder};
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    struct NoUpstream;
+
+    #[async_trait::async_trait]
+    impl UpstreamForwarder for NoUpstream {
+        async fn forward(
+            &self,
+            _request: &McpRequest,
+        ) -> Result<JsonRpcResponse, ThoughtGateError> {
+            unreachable!("callbacks never forward")
+        }
+
+        async fn forward_batch(
+            &self,
+            _requests: &[McpRequest],
+        ) -> Result<Vec<JsonRpcResponse>, ThoughtGateError> {
+            unreachable!("callbacks never forward")
+        }
+    }
+
+    fn server() -> CallbackServer {
+        let adapter = WebhookAdapter::new(WebhookConfig::new("http://unused", "secret")).unwrap();
+        let engine = ApprovalEngine::new(
+            Arc::new(TaskStore::with_defaults()),
+            Arc::new(adapter),
+            Arc::new(NoUpstream),
+            ApprovalEngineConfig::default(),
+            CancellationToken::new(),
+        )
+        .unwrap();
+        CallbackServer::new(Arc::new(engine))
+    }
+
+    fn callback(secret: &str, body: &'static str) -> Request<Body> {
+        let timestamp = chrono::Utc::now().timestamp();
+        Request::builder()
+            .method("POST")
+            .uri(CALLBACK_PATH)
+            .header("x-thoughtgate-timestamp", timestamp.to_string())
+            .header(
+                "x-thoughtgate-signature",
+                sign_webhook(secret, timestamp, body.as_bytes()),
+            )
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_callback_status_codes() {
+        let server = server();
+        let decision = r#"{"task_id":"tg_unknown","decision":"approve","decided_by":"alice"}"#;
+
+        let response = server
+            .router()
+            .oneshot(callback("wrong", decision))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = server
+            .router()
+            .oneshot(callback("secret", r#"{"decision":"approve"}"#))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = server
+            .router()
+            .oneshot(callback("secret", decision))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,179 @@
+//! Time source for time-based logic.
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+//! - Implements: REQ-GOV-001/F-008 (TTL Enforcement)
+//!
+//! Rate limiters, stream timeouts, task expiry and approval validity all
+//! read the time through a [`Clock`] rather than directly, so tests can
+//! substitute a [`MockClock`] and move time forward by hand instead of
+//! sleeping. Components use [`SystemClock`] unless given another clock.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use tokio::sync::Notify;
+
+/// A future that completes once a [`Clock`] has advanced by a duration.
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send + Sync>>;
+
+/// A source of wall-clock time, monotonic time and timers.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// The current wall-clock time.
+    fn now(&self) -> DateTime<Utc>;
+
+    /// The current monotonic time.
+    fn instant(&self) -> Instant;
+
+    /// A future that completes once `duration` has passed on this clock.
+    fn sleep(&self, duration: Duration) -> Sleep;
+}
+
+/// The system clock, backed by `chrono` and tokio timers.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// The system clock as a shared [`Clock`].
+    #[must_use]
+    pub fn shared() -> Arc<dyn Clock> {
+        Arc::new(Self)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn instant(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// A clock that only moves when [`MockClock::advance`] is called.
+///
+/// Clones share the same time, so a test can keep one handle and give
+/// another to the component under test. Sleeps complete as soon as the
+/// clock has been advanced past them.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    state: Arc<MockState>,
+}
+
+#[derive(Debug)]
+struct MockState {
+    /// Wall-clock time when the mock was created
+    wall: DateTime<Utc>,
+    /// Monotonic time when the mock was created
+    start: Instant,
+    /// How far the mock has been advanced, in nanoseconds
+    elapsed: AtomicU64,
+    /// Wakes sleeps when the clock advances
+    advanced: Notify,
+}
+
+impl MockState {
+    fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed.load(Ordering::Acquire))
+    }
+}
+
+impl MockClock {
+    /// Create a mock clock starting at the current system time.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(MockState {
+                wall: Utc::now(),
+                start: Instant::now(),
+                elapsed: AtomicU64::new(0),
+                advanced: Notify::new(),
+            }),
+        }
+    }
+
+    /// Move the clock forward by `duration`, waking any sleeps that are due.
+    pub fn advance(&self, duration: Duration) {
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        // The closure never returns `None`, so the update always succeeds
+        let _ = self
+            .state
+            .elapsed
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |elapsed| {
+                Some(elapsed.saturating_add(nanos))
+            });
+        self.state.advanced.notify_waiters();
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.state.wall
+            + chrono::Duration::from_std(self.state.elapsed()).unwrap_or(chrono::Duration::MAX)
+    }
+
+    fn instant(&self) -> Instant {
+        self.state.start + self.state.elapsed()
+    }
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        let state = self.state.clone();
+        let until = state.elapsed() + duration;
+        Box::pin(async move {
+            loop {
+                let advanced = state.advanced.notified();
+                tokio::pin!(advanced);
+                // Register before checking so no advance is missed
+                advanced.as_mut().enable();
+                if state.elapsed() >= until {
+                    return;
+                }
+                advanced.await;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mock_clock_sleeps_until_advanced() {
+        let clock = MockClock::new();
+        let start = (clock.now(), clock.instant());
+
+        let mut sleep = clock.sleep(Duration::from_secs(10));
+        assert!(futures::poll!(sleep.as_mut()).is_pending());
+
+        clock.advance(Duration::from_secs(9));
+        assert!(futures::poll!(sleep.as_mut()).is_pending());
+
+        clock.advance(Duration::from_secs(1));
+        assert!(futures::poll!(sleep.as_mut()).is_ready());
+        assert_eq!(clock.now() - start.0, chrono::Duration::seconds(10));
+        assert_eq!(clock.instant() - start.1, Duration::from_secs(10));
+    }
+}
@@ -0,0 +1,436 @@
+//! Tool-metadata catalog for attribute-based Cedar policies.
+//!
+//! Implements: REQ-CFG-001 Section 7.7 (Tool Metadata Catalog)
+//!
+//! The catalog is a separate YAML (or JSON) file, referenced by the
+//! `catalog:` key of the main config, that maps `(server, tool)` patterns to
+//! attribute sets. Before Gate 3 runs, the matching attributes are attached
+//! to the Cedar resource so policies can use `resource.<key>`.
+//!
+//! ```yaml
+//! default_risk: high
+//! tools:
+//!   - match: "transfer_*"
+//!     attributes:
+//!       risk: high
+//!       team: payments
+//!   - server: "analytics-*"
+//!     match: "get_*"
+//!     attributes:
+//!       risk: low
+//! ```
+//!
+//! Unlike the rest of the config, the catalog is hot-reloadable through
+//! [`LiveCatalog`].
+//!
+//! # Traceability
+//! - Implements: REQ-CFG-001/7.7 (Tool Metadata Catalog)
+
+use arc_swap::ArcSwap;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use super::error::ConfigError;
+use crate::policy::{AttrType, AttrValue, CedarResource};
+
+/// Resource attribute keys set by the engine itself.
+pub const RESERVED_ATTRIBUTES: &[&str] = &["name", "server", "arguments", "method"];
+
+/// Attribute that always carries the tool's risk tier.
+pub const RISK_ATTRIBUTE: &str = "risk";
+
+/// Tool-metadata catalog.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 7.7 (Tool Metadata Catalog)
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolCatalog {
+    /// Risk tier for tools whose entry has no `risk` (or that have no entry).
+    ///
+    /// Defaults to `high` so uncatalogued tools fail safe.
+    #[serde(default = "default_risk")]
+    pub default_risk: String,
+
+    /// Ordered catalog entries (first match wins).
+    #[serde(default)]
+    pub tools: Vec<ToolMetadata>,
+}
+
+fn default_risk() -> String {
+    "high".to_string()
+}
+
+impl Default for ToolCatalog {
+    fn default() -> Self {
+        Self {
+            default_risk: default_risk(),
+            tools: Vec::new(),
+        }
+    }
+}
+
+/// Catalog entry attaching attributes to matching tools.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 7.7 (Tool Metadata Catalog)
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolMetadata {
+    /// Glob pattern for the source (server) ID; matches any server by default.
+    #[serde(default = "any_server")]
+    pub server: String,
+
+    /// Glob pattern for tool name matching.
+    #[serde(rename = "match")]
+    pub pattern: String,
+
+    /// Attributes exposed to Cedar as `resource.<key>`.
+    #[serde(default)]
+    pub attributes: BTreeMap<String, AttrValue>,
+}
+
+fn any_server() -> String {
+    "*".to_string()
+}
+
+impl ToolMetadata {
+    /// Check whether this entry applies to a tool on a server.
+    pub fn matches(&self, server: &str, tool: &str) -> bool {
+        let glob_matches = |pattern: &str, value: &str| {
+            glob::Pattern::new(pattern).is_ok_and(|p| p.matches(value))
+        };
+        glob_matches(&self.server, server) && glob_matches(&self.pattern, tool)
+    }
+}
+
+impl ToolCatalog {
+    /// Load and validate a catalog from a YAML or JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError` if the file cannot be read, parsed, or fails
+    /// [`ToolCatalog::validate`].
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path)?;
+        if contents.trim().is_empty() {
+            return Err(ConfigError::EmptyConfigFile);
+        }
+        // YAML is a superset of JSON, so one parser handles both
+        let catalog: ToolCatalog = serde_saphyr::from_str(&contents)?;
+        let errors = catalog.validate();
+        if !errors.is_empty() {
+            return Err(ConfigError::from_errors(errors));
+        }
+        Ok(catalog)
+    }
+
+    /// Check patterns, reserved keys, and that each key has a single type.
+    pub fn validate(&self) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+        let mut types = BTreeMap::from([(RISK_ATTRIBUTE, AttrType::String)]);
+
+        for (i, entry) in self.tools.iter().enumerate() {
+            for pattern in [&entry.server, &entry.pattern] {
+                if let Err(e) = glob::Pattern::new(pattern) {
+                    errors.push(ConfigError::InvalidGlobPattern {
+                        pattern: pattern.clone(),
+                        message: format!("invalid catalog pattern: {e}"),
+                    });
+                }
+            }
+            for (key, value) in &entry.attributes {
+                let field = format!("tools[{i}].attributes.{key}");
+                if RESERVED_ATTRIBUTES.contains(&key.as_str()) {
+                    errors.push(ConfigError::OutOfRange {
+                        field,
+                        message: "is reserved for built-in resource attributes".to_string(),
+                    });
+                    continue;
+                }
+                let expected = *types
+                    .entry(key.as_str())
+                    .or_insert_with(|| value.attr_type());
+                if expected != value.attr_type() {
+                    errors.push(ConfigError::OutOfRange {
+                        field,
+                        message: format!("must be {expected}, got {}", value.attr_type()),
+                    });
+                }
+            }
+        }
+        errors
+    }
+
+    /// Attributes for a tool (first matching entry wins).
+    ///
+    /// `risk` is always present, falling back to `default_risk`.
+    pub fn lookup(&self, server: &str, tool: &str) -> BTreeMap<String, AttrValue> {
+        let mut attributes = self
+            .tools
+            .iter()
+            .find(|entry| entry.matches(server, tool))
+            .map(|entry| entry.attributes.clone())
+            .unwrap_or_default();
+        attributes
+            .entry(RISK_ATTRIBUTE.to_string())
+            .or_insert_with(|| AttrValue::String(self.default_risk.clone()));
+        attributes
+    }
+
+    /// Cedar type of every attribute key the catalog can produce.
+    ///
+    /// The engine declares these as optional resource attributes in its
+    /// schema. Conflicting types are rejected by validation; here the first
+    /// occurrence wins.
+    pub fn attribute_types(&self) -> BTreeMap<String, AttrType> {
+        let mut types = BTreeMap::from([(RISK_ATTRIBUTE.to_string(), AttrType::String)]);
+        for entry in &self.tools {
+            for (key, value) in &entry.attributes {
+                types
+                    .entry(key.clone())
+                    .or_insert_with(|| value.attr_type());
+            }
+        }
+        types
+    }
+
+    /// Attach catalog attributes to a Cedar resource.
+    ///
+    /// Attributes already set on the resource are kept.
+    pub fn enrich(&self, resource: &mut CedarResource) {
+        let mut attributes = self.lookup(resource.server(), resource.name());
+        attributes.extend(resource.attributes().clone());
+        resource.set_attributes(attributes);
+    }
+}
+
+/// Hot-reloadable holder for the catalog loaded from a file.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001/7.7 (Tool Metadata Catalog)
+pub struct LiveCatalog {
+    current: ArcSwap<ToolCatalog>,
+    path: PathBuf,
+    /// Attribute types declared in the Cedar schema at startup.
+    declared: BTreeMap<String, AttrType>,
+}
+
+impl std::fmt::Debug for LiveCatalog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LiveCatalog")
+            .field("path", &self.path)
+            .field("entries", &self.current.load().tools.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl LiveCatalog {
+    /// Load the catalog at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError` if [`ToolCatalog::load`] fails.
+    pub fn load(path: PathBuf) -> Result<Self, ConfigError> {
+        let catalog = ToolCatalog::load(&path)?;
+        info!(
+            path = %path.display(),
+            entries = catalog.tools.len(),
+            "Tool catalog loaded"
+        );
+        Ok(Self {
+            declared: catalog.attribute_types(),
+            current: ArcSwap::from_pointee(catalog),
+            path,
+        })
+    }
+
+    /// Path the catalog is loaded from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Current catalog snapshot.
+    pub fn catalog(&self) -> Arc<ToolCatalog> {
+        self.current.load_full()
+    }
+
+    /// Attribute types from the catalog loaded at startup.
+    pub fn declared_types(&self) -> &BTreeMap<String, AttrType> {
+        &self.declared
+    }
+
+    /// Re-read the file and swap in the new catalog.
+    ///
+    /// Attributes whose type is not the one declared at startup still reach
+    /// Cedar, but policies using them are only type-checked after a restart.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError` if the file fails to load; the current catalog
+    /// stays in effect.
+    pub fn reload(&self) -> Result<(), ConfigError> {
+        let catalog = ToolCatalog::load(&self.path)?;
+        let undeclared: Vec<_> = catalog
+            .attribute_types()
+            .into_iter()
+            .filter(|(key, ty)| self.declared.get(key) != Some(ty))
+            .map(|(key, _)| key)
+            .collect();
+        if !undeclared.is_empty() {
+            warn!(
+                attributes = %undeclared.join(", "),
+                "Catalog attributes not declared in the Cedar schema; restart to type-check policies"
+            );
+        }
+        info!(entries = catalog.tools.len(), "Tool catalog reloaded");
+        self.current.store(Arc::new(catalog));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CATALOG: &str = r#"
+default_risk: critical
+tools:
+  - server: upstream
+    match: "transfer_small"
+    attributes:
+      risk: low
+      team: payments
+  - match: "transfer_*"
+    attributes:
+      team: payments
+      pii: true
+  - server: "analytics-*"
+    match: "*"
+    attributes:
+      risk: low
+      tier: 3
+"#;
+
+    fn catalog() -> ToolCatalog {
+        serde_saphyr::from_str(CATALOG).unwrap()
+    }
+
+    fn string(s: &str) -> AttrValue {
+        AttrValue::String(s.to_string())
+    }
+
+    #[test]
+    fn test_lookup_known_tool() {
+        let attrs = catalog().lookup("upstream", "transfer_small");
+        assert_eq!(attrs.get("risk"), Some(&string("low")));
+        assert_eq!(attrs.get("team"), Some(&string("payments")));
+        assert!(!attrs.contains_key("pii"));
+
+        // Entry without a risk gets the default
+        let attrs = catalog().lookup("upstream", "transfer_large");
+        assert_eq!(attrs.get("risk"), Some(&string("critical")));
+        assert_eq!(attrs.get("pii"), Some(&AttrValue::Bool(true)));
+    }
+
+    #[test]
+    fn test_lookup_unknown_tool_gets_default_risk() {
+        let attrs = catalog().lookup("upstream", "delete_user");
+        assert_eq!(
+            attrs,
+            BTreeMap::from([("risk".to_string(), string("critical"))])
+        );
+
+        let attrs = ToolCatalog::default().lookup("upstream", "delete_user");
+        assert_eq!(attrs.get("risk"), Some(&string("high")));
+    }
+
+    #[test]
+    fn test_lookup_server_patterns() {
+        // Server-scoped entry does not apply to other servers
+        let attrs = catalog().lookup("other", "transfer_small");
+        assert_eq!(attrs.get("risk"), Some(&string("critical")));
+
+        // Wildcard server entry
+        let attrs = catalog().lookup("analytics-eu", "get_report");
+        assert_eq!(attrs.get("risk"), Some(&string("low")));
+        assert_eq!(attrs.get("tier"), Some(&AttrValue::Long(3)));
+        assert_eq!(catalog().lookup("analytics", "get_report").len(), 1);
+    }
+
+    #[test]
+    fn test_enrich_keeps_existing_attributes() {
+        let mut resource = CedarResource::ToolCall {
+            name: "transfer_small".to_string(),
+            server: "upstream".to_string(),
+            arguments: serde_json::json!({}),
+            attributes: BTreeMap::from([("risk".to_string(), string("medium"))]),
+        };
+        catalog().enrich(&mut resource);
+
+        assert_eq!(resource.attributes().get("risk"), Some(&string("medium")));
+        assert_eq!(resource.attributes().get("team"), Some(&string("payments")));
+    }
+
+    #[test]
+    fn test_validate() {
+        let catalog: ToolCatalog = serde_saphyr::from_str(
+            r#"
+tools:
+  - match: "transfer_*"
+    attributes:
+      tier: 1
+  - match: "get_*"
+    attributes:
+      tier: low
+      name: spoofed
+      risk: 2
+  - match: "[bad"
+"#,
+        )
+        .unwrap();
+        let errors: Vec<_> = catalog.validate().iter().map(|e| e.to_string()).collect();
+        assert_eq!(errors.len(), 4, "{errors:?}");
+        assert!(errors[0].contains("tools[1].attributes.name"));
+        assert!(errors[1].contains("tools[1].attributes.risk"));
+        assert!(errors[2].contains("tools[1].attributes.tier"));
+        assert!(errors[3].contains("[bad"));
+
+        let types = self::catalog().attribute_types();
+        assert_eq!(types.get("risk"), Some(&AttrType::String));
+        assert_eq!(types.get("pii"), Some(&AttrType::Bool));
+        assert_eq!(types.get("tier"), Some(&AttrType::Long));
+    }
+
+    #[test]
+    fn test_live_catalog_reload() {
+        let path = std::env::temp_dir().join(format!(
+            "thoughtgate_test_catalog_{}.json",
+            std::process::id()
+        ));
+        std::fs::write(
+            &path,
+            r#"{"tools": [{"match": "get_*", "attributes": {"risk": "low"}}]}"#,
+        )
+        .unwrap();
+
+        let live = LiveCatalog::load(path.clone()).unwrap();
+        let attrs = live.catalog().lookup("upstream", "get_user");
+        assert_eq!(attrs.get("risk"), Some(&string("low")));
+
+        std::fs::write(&path, "default_risk: medium\n").unwrap();
+        live.reload().unwrap();
+        let attrs = live.catalog().lookup("upstream", "get_user");
+        assert_eq!(attrs.get("risk"), Some(&string("medium")));
+
+        // Invalid file keeps the current catalog
+        std::fs::write(&path, "tools:\n  - match: x\n    attributes: {name: y}\n").unwrap();
+        assert!(live.reload().is_err());
+        assert_eq!(live.catalog().default_risk, "medium");
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -15,16 +15,12 @@ use thiserror::Error;
 #[derive(Debug, Error)]
 pub enum ConfigError {
     // ─────────────────────────────────────────────────────────────────────────
-    // Source validation errors (V-001, V-002, V-003, V-010, V-011, V-012)
+    // Source validation errors (V-001, V-002, V-003, V-010, V-012)
     // ─────────────────────────────────────────────────────────────────────────
     /// V-010: No sources defined in configuration.
     #[error("no sources defined in configuration")]
     NoSourcesDefined,
 
-    /// V-011: v0.2 only supports a single source.
-    #[error("v0.2 supports only a single source, found {count}")]
-    V02SingleSourceOnly { count: usize },
-
     /// V-012: v0.2 only supports kind: mcp.
     #[error("v0.2 supports only kind: mcp, found '{kind}'")]
     V02McpOnly { kind: String },
@@ -71,10 +67,26 @@ pub enum ConfigError {
     #[error("policy file not found: {path}")]
     PolicyFileNotFound { path: PathBuf },
 
+    /// Tool-metadata catalog file not found.
+    #[error("catalog file not found: {path}")]
+    CatalogFileNotFound { path: PathBuf },
+
     /// V-014: Required environment variable not set.
     #[error("environment variable '{var}' not set (required for field '{field}')")]
     MissingEnvVar { var: String, field: String },
 
+    /// Value outside its allowed range, or inconsistent with a related field.
+    #[error("invalid value for '{field}': {message}")]
+    OutOfRange { field: String, message: String },
+
+    /// Unknown key or unparseable value in a proxy setting override.
+    #[error("invalid setting '{key}': {message}")]
+    InvalidSetting { key: String, message: String },
+
+    /// Several validation errors reported together.
+    #[error("{}", format_errors(.errors))]
+    Invalid { errors: Vec<ConfigError> },
+
     // ─────────────────────────────────────────────────────────────────────────
     // Schema validation errors
     // ─────────────────────────────────────────────────────────────────────────
@@ -106,6 +118,30 @@ pub enum ConfigError {
     EmptyConfigFile,
 }
 
+impl ConfigError {
+    /// Collapse a list of validation errors into one error.
+    ///
+    /// A single error is returned unchanged; several are wrapped in
+    /// [`ConfigError::Invalid`] so all of them are reported.
+    pub fn from_errors(mut errors: Vec<ConfigError>) -> Self {
+        if errors.len() == 1 {
+            if let Some(error) = errors.pop() {
+                return error;
+            }
+        }
+        Self::Invalid { errors }
+    }
+}
+
+fn format_errors(errors: &[ConfigError]) -> String {
+    let mut out = format!("{} configuration errors:", errors.len());
+    for error in errors {
+        out.push_str("\n  - ");
+        out.push_str(&error.to_string());
+    }
+    out
+}
+
 /// Validation warnings (non-fatal).
 ///
 /// # Traceability
@@ -126,6 +162,12 @@ pub enum ValidationWarning {
         feature: String,
         min_version: String,
     },
+
+    /// Workflow escalates no earlier than it times out.
+    EscalationAfterTimeout { workflow: String },
+
+    /// synthetic_response on a rule whose action never denies.
+    SyntheticResponseNeverSent { pattern: String },
 }
 
 impl std::fmt::Display for ValidationWarning {
@@ -152,6 +194,18 @@ impl std::fmt::Display for ValidationWarning {
                     "feature '{feature}' requires version {min_version} or later"
                 )
             }
+            Self::EscalationAfterTimeout { workflow } => {
+                write!(
+                    f,
+                    "workflow '{workflow}' escalate_after is not shorter than its timeout; escalation will never fire"
+                )
+            }
+            Self::SyntheticResponseNeverSent { pattern } => {
+                write!(
+                    f,
+                    "rule '{pattern}' has synthetic_response but action is neither 'deny' nor 'policy'"
+                )
+            }
         }
     }
 }
@@ -194,6 +248,24 @@ mod tests {
         assert_eq!(err.to_string(), "duplicate source ID: 'upstream'");
     }
 
+    #[test]
+    fn test_from_errors_lists_all() {
+        let err = ConfigError::from_errors(vec![
+            ConfigError::NoSourcesDefined,
+            ConfigError::OutOfRange {
+                field: "approval.default.timeout".to_string(),
+                message: "must be greater than 0".to_string(),
+            },
+        ]);
+        assert_eq!(
+            err.to_string(),
+            "2 configuration errors:\n  - no sources defined in configuration\n  - invalid value for 'approval.default.timeout': must be greater than 0"
+        );
+
+        let single = ConfigError::from_errors(vec![ConfigError::NoSourcesDefined]);
+        assert!(matches!(single, ConfigError::NoSourcesDefined));
+    }
+
     #[test]
     fn test_validation_warning_display() {
         let warn = ValidationWarning::PolicyIdWithoutPolicyAction {
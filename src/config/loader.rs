@@ -12,9 +12,13 @@ use regex::Regex;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
+use std::time::Duration;
 
+use super::defaults::ThoughtGateDefaults;
 use super::error::{ConfigError, ValidationResult, ValidationWarning};
-use super::schema::{Action, Config, Source};
+use super::schema::{Action, ClassifierConfig, Config, FailoverStrategy, RuntimeSettings, Source};
+use crate::header_rules::HeaderRules;
+use crate::proxy_config::{ProxyConfig, ProxyConfigLayer};
 
 /// Semantic version for feature gating.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -111,7 +115,7 @@ pub fn load_and_validate(
     version: Version,
 ) -> Result<(Config, ValidationResult), ConfigError> {
     let config = load_config(path)?;
-    let result = validate(&config, version)?;
+    let result = validate(&config, version).map_err(ConfigError::from_errors)?;
     Ok((config, result))
 }
 
@@ -180,36 +184,55 @@ pub fn substitute_env_vars(content: &str) -> Result<String, ConfigError> {
 // Validation
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 
+/// Resolve proxy settings from every configuration layer.
+///
+/// Precedence, highest first: CLI overrides (`cli`), `THOUGHTGATE_<KEY>`
+/// environment variables, the `proxy:` section of the config file, then
+/// built-in defaults. Each layer only overrides the keys it sets, so
+/// raising one timeout leaves the others at their lower-layer values.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 6.3 (Configuration Loading Interface)
+pub fn load(file: Option<&Config>, cli: &ProxyConfigLayer) -> ProxyConfig {
+    let file = file.and_then(|c| c.proxy.clone()).unwrap_or_default();
+    ProxyConfig::from_layers(&[&file, &ProxyConfigLayer::from_env(), cli])
+}
+
 /// Validate a configuration.
 ///
+/// Every rule is checked and all violations are returned together, so a
+/// config with several mistakes can be fixed in one pass.
+///
+/// # Errors
+///
+/// Returns every [`ConfigError`] found, in rule order.
+///
 /// # Traceability
 /// - Implements: REQ-CFG-001 Section 8 (Validation Rules)
-pub fn validate(config: &Config, version: Version) -> Result<ValidationResult, ConfigError> {
+pub fn validate(config: &Config, version: Version) -> Result<ValidationResult, Vec<ConfigError>> {
     let mut warnings = Vec::new();
+    let mut errors = Vec::new();
 
     // Schema version validation
     if config.schema != 1 {
-        return Err(ConfigError::UnsupportedSchemaVersion {
+        errors.push(ConfigError::UnsupportedSchemaVersion {
             version: config.schema,
         });
     }
 
     // V-010: At least one source
     if config.sources.is_empty() {
-        return Err(ConfigError::NoSourcesDefined);
+        errors.push(ConfigError::NoSourcesDefined);
     }
 
-    // V-011, V-012: v0.2 restrictions
+    // V-012: v0.2 restrictions
     if version.major == 0 && version.minor == 2 {
-        if config.sources.len() != 1 {
-            return Err(ConfigError::V02SingleSourceOnly {
-                count: config.sources.len(),
-            });
-        }
-        if !matches!(config.sources[0], Source::Mcp { .. }) {
-            return Err(ConfigError::V02McpOnly {
-                kind: config.sources[0].kind().to_string(),
-            });
+        for source in &config.sources {
+            if !matches!(source, Source::Mcp { .. }) {
+                errors.push(ConfigError::V02McpOnly {
+                    kind: source.kind().to_string(),
+                });
+            }
         }
     }
 
@@ -217,7 +240,7 @@ pub fn validate(config: &Config, version: Version) -> Result<ValidationResult, C
     let mut seen_ids = HashSet::new();
     for source in &config.sources {
         if !seen_ids.insert(source.id()) {
-            return Err(ConfigError::DuplicateSourceId {
+            errors.push(ConfigError::DuplicateSourceId {
                 id: source.id().to_string(),
             });
         }
@@ -226,7 +249,7 @@ pub fn validate(config: &Config, version: Version) -> Result<ValidationResult, C
     // V-002: Reserved prefix
     for source in &config.sources {
         if source.id().starts_with('_') {
-            return Err(ConfigError::ReservedPrefix {
+            errors.push(ConfigError::ReservedPrefix {
                 id: source.id().to_string(),
             });
         }
@@ -237,7 +260,7 @@ pub fn validate(config: &Config, version: Version) -> Result<ValidationResult, C
     for source in &config.sources {
         if let Some(prefix) = source.prefix() {
             if !seen_prefixes.insert(prefix) {
-                return Err(ConfigError::DuplicatePrefix {
+                errors.push(ConfigError::DuplicatePrefix {
                     prefix: prefix.to_string(),
                 });
             }
@@ -246,12 +269,63 @@ pub fn validate(config: &Config, version: Version) -> Result<ValidationResult, C
 
     // V-008: Validate source URLs
     for source in &config.sources {
-        let url = source.url();
-        if url::Url::parse(url).is_err() {
-            return Err(ConfigError::InvalidUrl {
-                url: url.to_string(),
-                message: "invalid URL format".to_string(),
-            });
+        let failover = source.failover().map(|f| f.upstreams.as_slice());
+        let urls = std::iter::once(source.url())
+            .chain(failover.unwrap_or_default().iter().map(|u| u.url.as_str()));
+        for url in urls {
+            if url::Url::parse(url).is_err() {
+                errors.push(ConfigError::InvalidUrl {
+                    url: url.to_string(),
+                    message: "invalid URL format".to_string(),
+                });
+            }
+        }
+    }
+
+    // Weighted groups need every member to take a share
+    for source in &config.sources {
+        if let Some(failover) = source.failover() {
+            let zero_weight =
+                failover.weight == 0 || failover.upstreams.iter().any(|u| u.weight == 0);
+            if failover.strategy == FailoverStrategy::Weighted && zero_weight {
+                errors.push(ConfigError::OutOfRange {
+                    field: format!("sources.{}.failover", source.id()),
+                    message: "weights must be at least 1".to_string(),
+                });
+            }
+            if failover.failure_threshold == Some(0) {
+                errors.push(ConfigError::OutOfRange {
+                    field: format!("sources.{}.failover.failure_threshold", source.id()),
+                    message: "must be at least 1".to_string(),
+                });
+            }
+        }
+    }
+
+    // Upstream client identity needs both halves
+    for source in &config.sources {
+        if let Some(tls) = source.tls() {
+            if tls.client_cert.is_some() != tls.client_key.is_some() {
+                errors.push(ConfigError::OutOfRange {
+                    field: format!("sources.{}.tls", source.id()),
+                    message: "client_cert and client_key must be set together".to_string(),
+                });
+            }
+            if tls.pin_ca && tls.ca_file.is_none() {
+                errors.push(ConfigError::OutOfRange {
+                    field: format!("sources.{}.tls.pin_ca", source.id()),
+                    message: "requires ca_file".to_string(),
+                });
+            }
+        }
+    }
+
+    // Header rules must name valid headers
+    for source in &config.sources {
+        if let Some(headers) = source.headers()
+            && let Err(e) = HeaderRules::from_config(source.id(), headers)
+        {
+            errors.push(e);
         }
     }
 
@@ -259,14 +333,14 @@ pub fn validate(config: &Config, version: Version) -> Result<ValidationResult, C
     let workflow_names: HashSet<&str> = config
         .approval
         .as_ref()
-        .map(|a| a.keys().map(|s| s.as_str()).collect())
+        .map(|a| a.workflows.keys().map(|s| s.as_str()).collect())
         .unwrap_or_default();
 
     // Validate governance rules
     for rule in &config.governance.rules {
         // V-005: action: policy requires policy_id
         if rule.action == Action::Policy && rule.policy_id.is_none() {
-            return Err(ConfigError::MissingPolicyId {
+            errors.push(ConfigError::MissingPolicyId {
                 pattern: rule.pattern.clone(),
             });
         }
@@ -282,7 +356,7 @@ pub fn validate(config: &Config, version: Version) -> Result<ValidationResult, C
         if rule.action == Action::Approve {
             if let Some(ref workflow) = rule.approval {
                 if !workflow_names.contains(workflow.as_str()) {
-                    return Err(ConfigError::UndefinedWorkflow {
+                    errors.push(ConfigError::UndefinedWorkflow {
                         workflow: workflow.clone(),
                         pattern: rule.pattern.clone(),
                     });
@@ -292,11 +366,86 @@ pub fn validate(config: &Config, version: Version) -> Result<ValidationResult, C
 
         // V-009: Valid glob pattern
         if let Err(e) = glob::Pattern::new(&rule.pattern) {
-            return Err(ConfigError::InvalidGlobPattern {
+            errors.push(ConfigError::InvalidGlobPattern {
                 pattern: rule.pattern.clone(),
                 message: e.to_string(),
             });
         }
+
+        // A synthetic response must be a result the agent can parse, and is
+        // only ever sent by a rule that can deny
+        if let Some(ref response) = rule.synthetic_response {
+            if let Err(message) = check_synthetic_response(response) {
+                errors.push(ConfigError::OutOfRange {
+                    field: format!("governance.rules.{}.synthetic_response", rule.pattern),
+                    message,
+                });
+            }
+            if !matches!(rule.action, Action::Deny | Action::Policy) {
+                warnings.push(ValidationWarning::SyntheticResponseNeverSent {
+                    pattern: rule.pattern.clone(),
+                });
+            }
+        }
+    }
+
+    // Workflow value ranges and cross-field constraints
+    if let Some(ref approval) = config.approval {
+        if let Some(timeout) = approval.default_timeout {
+            errors.extend(check_approval_timeout(
+                "approval.default_timeout".to_string(),
+                timeout,
+            ));
+        }
+
+        // Sorted so errors are reported in a stable order
+        let mut overrides: Vec<_> = approval
+            .overrides
+            .iter()
+            .flat_map(|(server, tools)| {
+                tools
+                    .iter()
+                    .map(move |(tool, timeout)| (server.as_str(), tool.as_str(), *timeout))
+            })
+            .collect();
+        overrides.sort();
+        for (server, tool, timeout) in overrides {
+            errors.extend(check_approval_timeout(
+                format!("approval.overrides.{server}.{tool}"),
+                timeout,
+            ));
+        }
+
+        let mut workflows: Vec<_> = approval.workflows.iter().collect();
+        workflows.sort_by_key(|(name, _)| name.as_str());
+
+        for (name, workflow) in workflows {
+            if let Some(timeout) = workflow.timeout {
+                errors.extend(check_approval_timeout(
+                    format!("approval.{name}.timeout"),
+                    timeout,
+                ));
+            }
+            if workflow.min_approvals == Some(0) {
+                errors.push(ConfigError::OutOfRange {
+                    field: format!("approval.{name}.min_approvals"),
+                    message: "must be at least 1".to_string(),
+                });
+            }
+            if let Some(ref escalation) = workflow.escalation {
+                if escalation.escalate_after.is_zero() {
+                    errors.push(ConfigError::OutOfRange {
+                        field: format!("approval.{name}.escalation.escalate_after"),
+                        message: "must be greater than 0".to_string(),
+                    });
+                } else if escalation.escalate_after >= workflow.timeout_or_default() {
+                    // Escalation must fire before the workflow times out (warning)
+                    warnings.push(ValidationWarning::EscalationAfterTimeout {
+                        workflow: name.clone(),
+                    });
+                }
+            }
+        }
     }
 
     // V-014: Valid expose config glob patterns
@@ -304,7 +453,7 @@ pub fn validate(config: &Config, version: Version) -> Result<ValidationResult, C
         if let Some(patterns) = source.expose().patterns() {
             for pattern in patterns {
                 if let Err(e) = glob::Pattern::new(pattern) {
-                    return Err(ConfigError::InvalidGlobPattern {
+                    errors.push(ConfigError::InvalidGlobPattern {
                         pattern: pattern.clone(),
                         message: format!(
                             "invalid expose pattern for source '{}': {}",
@@ -317,16 +466,140 @@ pub fn validate(config: &Config, version: Version) -> Result<ValidationResult, C
         }
     }
 
+    // Hot-reloadable settings
+    if let Some(ref runtime) = config.runtime {
+        errors.extend(validate_runtime(runtime));
+    }
+
     // V-013: Cedar policy files exist
     if let Some(ref cedar) = config.cedar {
         for path in &cedar.policies {
             if !path.exists() {
-                return Err(ConfigError::PolicyFileNotFound { path: path.clone() });
+                errors.push(ConfigError::PolicyFileNotFound { path: path.clone() });
             }
         }
     }
 
-    Ok(ValidationResult::with_warnings(warnings))
+    // Classifier patterns compile, so a bad pattern cannot disable one
+    for (index, classifier) in config.classifiers.iter().enumerate() {
+        let ClassifierConfig::Regex { patterns, .. } = classifier;
+        let field = format!("classifiers.{index}.patterns");
+        if patterns.is_empty() {
+            errors.push(ConfigError::OutOfRange {
+                field,
+                message: "must list at least one pattern".to_string(),
+            });
+        } else if let Err(e) = regex::bytes::RegexSet::new(patterns) {
+            errors.push(ConfigError::OutOfRange {
+                field,
+                message: e.to_string(),
+            });
+        }
+    }
+
+    // Tool-metadata catalog file exists (contents are validated on load)
+    if let Some(ref path) = config.catalog {
+        if !path.exists() {
+            errors.push(ConfigError::CatalogFileNotFound { path: path.clone() });
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(ValidationResult::with_warnings(warnings))
+    } else {
+        Err(errors)
+    }
+}
+
+/// Check an approval timeout is positive and no longer than a task may live.
+///
+/// An approval that outlives its task could never be acted on.
+fn check_approval_timeout(field: String, timeout: Duration) -> Option<ConfigError> {
+    let max = ThoughtGateDefaults::default().max_task_ttl;
+    if timeout.is_zero() {
+        Some(ConfigError::OutOfRange {
+            field,
+            message: "must be greater than 0".to_string(),
+        })
+    } else if timeout > max {
+        Some(ConfigError::OutOfRange {
+            field,
+            message: format!("must be at most {}", humantime::format_duration(max)),
+        })
+    } else {
+        None
+    }
+}
+
+/// Check a synthetic response is a well-formed MCP result.
+///
+/// It becomes the `result` of a JSON-RPC response, which MCP always makes
+/// an object; a tool result's `content` is a list of typed items.
+fn check_synthetic_response(response: &serde_json::Value) -> Result<(), String> {
+    let Some(result) = response.as_object() else {
+        return Err("must be an object".to_string());
+    };
+    if result.contains_key("jsonrpc") || result.contains_key("error") {
+        return Err("must be the result alone, not a JSON-RPC message".to_string());
+    }
+    if let Some(content) = result.get("content") {
+        let typed = content.as_array().is_some_and(|items| {
+            items
+                .iter()
+                .all(|item| item.get("type").is_some_and(serde_json::Value::is_string))
+        });
+        if !typed {
+            return Err("content must be a list of items with a string type".to_string());
+        }
+    }
+    if result.get("isError").is_some_and(|v| !v.is_boolean()) {
+        return Err("isError must be a boolean".to_string());
+    }
+    Ok(())
+}
+
+/// Check the ranges of hot-reloadable settings.
+fn validate_runtime(runtime: &RuntimeSettings) -> Vec<ConfigError> {
+    let mut errors = Vec::new();
+
+    if let Some(ref level) = runtime.log_level {
+        if let Err(e) = tracing_subscriber::EnvFilter::try_new(level) {
+            errors.push(ConfigError::OutOfRange {
+                field: "runtime.log_level".to_string(),
+                message: e.to_string(),
+            });
+        }
+    }
+    if let Some(rate) = runtime.log_sample_rate {
+        if !(0.0..=1.0).contains(&rate) {
+            errors.push(ConfigError::OutOfRange {
+                field: "runtime.log_sample_rate".to_string(),
+                message: "must be between 0.0 and 1.0".to_string(),
+            });
+        }
+    }
+    for (field, value) in [
+        (
+            "runtime.max_pending_per_principal",
+            runtime.max_pending_per_principal,
+        ),
+        ("runtime.max_pending_global", runtime.max_pending_global),
+    ] {
+        if value == Some(0) {
+            errors.push(ConfigError::OutOfRange {
+                field: field.to_string(),
+                message: "must be greater than 0".to_string(),
+            });
+        }
+    }
+    if runtime.default_task_ttl.is_some_and(|t| t.is_zero()) {
+        errors.push(ConfigError::OutOfRange {
+            field: "runtime.default_task_ttl".to_string(),
+            message: "must be greater than 0".to_string(),
+        });
+    }
+
+    errors
 }
 
 #[cfg(test)]
@@ -371,14 +644,137 @@ governance:
     action: forward
 "#;
         let config: Config = serde_saphyr::from_str(yaml).unwrap();
-        let result = validate(&config, Version::V0_2);
-        assert!(matches!(result, Err(ConfigError::NoSourcesDefined)));
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        assert!(matches!(errors.as_slice(), [ConfigError::NoSourcesDefined]));
     }
 
     #[test]
     fn test_validate_duplicate_source_id() {
-        // This would require v0.3+ to test multiple sources
-        // For v0.2, the V02SingleSourceOnly error would trigger first
+        let yaml = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://localhost:8080
+  - id: upstream
+    kind: mcp
+    url: http://localhost:8081
+governance:
+  defaults:
+    action: forward
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [ConfigError::DuplicateSourceId { .. }]
+        ));
+    }
+
+    #[test]
+    fn test_validate_multiple_sources() {
+        let yaml = r#"
+schema: 1
+sources:
+  - id: billing
+    kind: mcp
+    url: https://billing:8443
+    timeout: 10s
+    tls:
+      ca_file: /etc/thoughtgate/billing-ca.pem
+  - id: search
+    kind: mcp
+    url: http://search:8080
+    connect_timeout: 2s
+    headers:
+      request:
+        remove: [x-internal-auth]
+      principal_header: x-thoughtgate-principal
+governance:
+  defaults:
+    action: forward
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        assert!(validate(&config, Version::V0_2).is_ok());
+        let billing = config.get_source("billing").unwrap();
+        assert_eq!(billing.timeout(), Some(std::time::Duration::from_secs(10)));
+        assert!(billing.tls().unwrap().ca_file.is_some());
+        let search = config.get_source("search").unwrap().headers().unwrap();
+        assert_eq!(search.request.remove, ["x-internal-auth"]);
+        assert!(search.forwarded);
+    }
+
+    #[test]
+    fn test_validate_failover_weights() {
+        let yaml = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://mcp-a:8080
+    failover:
+      strategy: weighted
+      upstreams:
+        - url: http://mcp-b:8080
+          weight: 0
+        - url: not a url
+governance:
+  defaults:
+    action: forward
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [
+                ConfigError::InvalidUrl { .. },
+                ConfigError::OutOfRange { .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_validate_source_tls_requires_key() {
+        let yaml = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: https://localhost:8443
+    tls:
+      client_cert: /etc/thoughtgate/client.pem
+governance:
+  defaults:
+    action: forward
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [ConfigError::OutOfRange { .. }]
+        ));
+    }
+
+    #[test]
+    fn test_validate_source_tls_pin_requires_ca() {
+        let yaml = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: https://localhost:8443
+    tls:
+      pin_ca: true
+governance:
+  defaults:
+    action: forward
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [ConfigError::OutOfRange { field, .. }] if field == "sources.upstream.tls.pin_ca"
+        ));
     }
 
     #[test]
@@ -394,8 +790,11 @@ governance:
     action: forward
 "#;
         let config: Config = serde_saphyr::from_str(yaml).unwrap();
-        let result = validate(&config, Version::V0_2);
-        assert!(matches!(result, Err(ConfigError::ReservedPrefix { .. })));
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [ConfigError::ReservedPrefix { .. }]
+        ));
     }
 
     #[test]
@@ -414,8 +813,11 @@ governance:
       action: policy
 "#;
         let config: Config = serde_saphyr::from_str(yaml).unwrap();
-        let result = validate(&config, Version::V0_2);
-        assert!(matches!(result, Err(ConfigError::MissingPolicyId { .. })));
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [ConfigError::MissingPolicyId { .. }]
+        ));
     }
 
     #[test]
@@ -435,8 +837,11 @@ governance:
       approval: nonexistent
 "#;
         let config: Config = serde_saphyr::from_str(yaml).unwrap();
-        let result = validate(&config, Version::V0_2);
-        assert!(matches!(result, Err(ConfigError::UndefinedWorkflow { .. })));
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [ConfigError::UndefinedWorkflow { .. }]
+        ));
     }
 
     /// Regression test: "default" workflow name is NOT special-cased.
@@ -459,12 +864,12 @@ governance:
       approval: default
 "#;
         let config: Config = serde_saphyr::from_str(yaml).unwrap();
-        let result = validate(&config, Version::V0_2);
+        let errors = validate(&config, Version::V0_2).unwrap_err();
         // Should fail because "default" workflow is not defined in approval section
         assert!(
-            matches!(result, Err(ConfigError::UndefinedWorkflow { ref workflow, .. }) if workflow == "default"),
+            matches!(errors.as_slice(), [ConfigError::UndefinedWorkflow { workflow, .. }] if workflow == "default"),
             "Expected UndefinedWorkflow error for 'default', got: {:?}",
-            result
+            errors
         );
     }
 
@@ -484,13 +889,41 @@ governance:
       action: deny
 "#;
         let config: Config = serde_saphyr::from_str(yaml).unwrap();
-        let result = validate(&config, Version::V0_2);
+        let errors = validate(&config, Version::V0_2).unwrap_err();
         assert!(matches!(
-            result,
-            Err(ConfigError::InvalidGlobPattern { .. })
+            errors.as_slice(),
+            [ConfigError::InvalidGlobPattern { .. }]
         ));
     }
 
+    #[test]
+    fn test_validate_classifier_patterns() {
+        let yaml = format!(
+            r#"{MINIMAL_CONFIG}
+classifiers:
+  - type: regex
+    action: reject
+    patterns: ['\b\d{{3}}-\d{{2}}-\d{{4}}\b']
+  - type: regex
+    action: approve
+    patterns: ["(unclosed"]
+  - type: regex
+    action: approve
+    patterns: []
+"#
+        );
+        let config: Config = serde_saphyr::from_str(&yaml).unwrap();
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        let fields: Vec<_> = errors
+            .iter()
+            .filter_map(|e| match e {
+                ConfigError::OutOfRange { field, .. } => Some(field.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fields, ["classifiers.1.patterns", "classifiers.2.patterns"]);
+    }
+
     #[test]
     fn test_validate_invalid_expose_pattern() {
         let yaml = r#"
@@ -508,10 +941,10 @@ governance:
     action: forward
 "#;
         let config: Config = serde_saphyr::from_str(yaml).unwrap();
-        let result = validate(&config, Version::V0_2);
+        let errors = validate(&config, Version::V0_2).unwrap_err();
         assert!(matches!(
-            result,
-            Err(ConfigError::InvalidGlobPattern { .. })
+            errors.as_slice(),
+            [ConfigError::InvalidGlobPattern { .. }]
         ));
     }
 
@@ -528,8 +961,11 @@ governance:
     action: forward
 "#;
         let config: Config = serde_saphyr::from_str(yaml).unwrap();
-        let result = validate(&config, Version::V0_2);
-        assert!(matches!(result, Err(ConfigError::InvalidUrl { .. })));
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [ConfigError::InvalidUrl { .. }]
+        ));
     }
 
     #[test]
@@ -557,6 +993,133 @@ governance:
         ));
     }
 
+    #[test]
+    fn test_validate_synthetic_response() {
+        let yaml = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://localhost:8080
+governance:
+  defaults:
+    action: forward
+  rules:
+    - match: "search"
+      action: deny
+      synthetic_response:
+        content: []
+        structuredContent: {results: []}
+    - match: "lookup"
+      action: forward
+      synthetic_response: {content: []}
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let result = validate(&config, Version::V0_2).unwrap();
+        assert!(matches!(
+            result.warnings.as_slice(),
+            [ValidationWarning::SyntheticResponseNeverSent { pattern }] if pattern == "lookup"
+        ));
+
+        for bad in [
+            "[]",
+            "{jsonrpc: '2.0', id: 1, result: {}}",
+            "{content: 'none'}",
+            "{content: [{text: hi}]}",
+            "{content: [], isError: 'yes'}",
+        ] {
+            let yaml = yaml.replace(
+                "synthetic_response: {content: []}",
+                &format!("synthetic_response: {bad}"),
+            );
+            let config: Config = serde_saphyr::from_str(&yaml).unwrap();
+            let errors = validate(&config, Version::V0_2).unwrap_err();
+            assert!(
+                matches!(
+                    errors.as_slice(),
+                    [ConfigError::OutOfRange { field, .. }]
+                        if field == "governance.rules.lookup.synthetic_response"
+                ),
+                "{bad}: {errors:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_escalation_after_timeout_warning() {
+        let yaml = r##"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://localhost:8080
+governance:
+  defaults:
+    action: approve
+approval:
+  default:
+    destination:
+      type: slack
+      channel: "#approvals"
+    timeout: 5m
+    escalation:
+      escalate_after: 5m
+      escalate_to:
+        channel: "#oncall"
+"##;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let result = validate(&config, Version::V0_2).unwrap();
+        assert_eq!(
+            result.warnings,
+            vec![ValidationWarning::EscalationAfterTimeout {
+                workflow: "default".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_approval_timeout_bounds() {
+        let yaml = r##"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://localhost:8080
+governance:
+  defaults:
+    action: approve
+approval:
+  default_timeout: 0s
+  overrides:
+    upstream:
+      deploy: 2d
+      restart: 30m
+  default:
+    destination:
+      type: slack
+      channel: "#approvals"
+    timeout: 25h
+"##;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        let fields: Vec<_> = errors
+            .iter()
+            .map(|e| match e {
+                ConfigError::OutOfRange { field, .. } => field.as_str(),
+                e => panic!("unexpected error: {e}"),
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                "approval.default_timeout",
+                "approval.overrides.upstream.deploy",
+                "approval.default.timeout"
+            ]
+        );
+        assert!(errors[1].to_string().contains("must be at most 1day"));
+    }
+
     #[test]
     fn test_env_var_substitution_required() {
         unsafe {
@@ -603,11 +1166,179 @@ governance:
     action: forward
 "#;
         let config: Config = serde_saphyr::from_str(yaml).unwrap();
-        let result = validate(&config, Version::V0_2);
+        let errors = validate(&config, Version::V0_2).unwrap_err();
         assert!(matches!(
-            result,
-            Err(ConfigError::UnsupportedSchemaVersion { version: 2 })
+            errors.as_slice(),
+            [ConfigError::UnsupportedSchemaVersion { version: 2 }]
+        ));
+    }
+
+    #[test]
+    fn test_validate_reports_all_errors() {
+        let yaml = r#"
+schema: 2
+sources:
+  - id: _internal
+    kind: mcp
+    url: "not a url"
+governance:
+  defaults:
+    action: forward
+  rules:
+    - match: "[invalid"
+      action: policy
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        assert!(
+            matches!(
+                errors.as_slice(),
+                [
+                    ConfigError::UnsupportedSchemaVersion { version: 2 },
+                    ConfigError::ReservedPrefix { .. },
+                    ConfigError::InvalidUrl { .. },
+                    ConfigError::MissingPolicyId { .. },
+                    ConfigError::InvalidGlobPattern { .. },
+                ]
+            ),
+            "got: {errors:?}"
+        );
+    }
+
+    #[test]
+    fn test_validate_workflow_ranges() {
+        let yaml = r##"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://localhost:8080
+governance:
+  defaults:
+    action: approve
+approval:
+  default:
+    destination:
+      type: slack
+      channel: "#approvals"
+    timeout: 0s
+    min_approvals: 0
+    escalation:
+      escalate_after: 0s
+      escalate_to:
+        channel: "#oncall"
+"##;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let errors = validate(&config, Version::V0_2).unwrap_err();
+        let fields: Vec<_> = errors
+            .iter()
+            .map(|e| match e {
+                ConfigError::OutOfRange { field, .. } => field.as_str(),
+                other => panic!("unexpected error: {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                "approval.default.timeout",
+                "approval.default.min_approvals",
+                "approval.default.escalation.escalate_after",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_and_validate_aggregates_errors() {
+        let path = std::env::temp_dir().join(format!(
+            "thoughtgate_test_config_{}.yaml",
+            std::process::id()
         ));
+        std::fs::write(
+            &path,
+            r#"
+schema: 2
+sources: []
+governance:
+  defaults:
+    action: forward
+"#,
+        )
+        .unwrap();
+
+        let err = load_and_validate(&path, Version::V0_2).unwrap_err();
+        let _ = std::fs::remove_file(&path);
+        let ConfigError::Invalid { errors } = err else {
+            panic!("expected aggregated error, got: {err:?}");
+        };
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn test_unknown_fields_rejected() {
+        let typo_top_level = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://localhost:8080
+governance:
+  defaults:
+    action: forward
+telemetry: true
+"#;
+        assert!(serde_saphyr::from_str::<Config>(typo_top_level).is_err());
+
+        let typo_in_rule = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://localhost:8080
+governance:
+  defaults:
+    action: forward
+  rules:
+    - match: "delete_*"
+      action: deny
+      polcy_id: typo
+"#;
+        assert!(serde_saphyr::from_str::<Config>(typo_in_rule).is_err());
+
+        let typo_in_source = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    ulr: http://localhost:8080
+    url: http://localhost:8080
+governance:
+  defaults:
+    action: forward
+"#;
+        assert!(serde_saphyr::from_str::<Config>(typo_in_source).is_err());
+    }
+
+    #[test]
+    fn test_load_applies_file_proxy_section() {
+        let yaml = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://localhost:8080
+governance:
+  defaults:
+    action: forward
+proxy:
+  req_buffer_max: 4096
+  resp_buffer_max: 8192
+"#;
+        let config: Config = serde_saphyr::from_str(yaml).unwrap();
+        let cli = ProxyConfigLayer::from_overrides(&["resp_buffer_max=16384"]).unwrap();
+
+        let proxy = load(Some(&config), &cli);
+        assert_eq!(proxy.req_buffer_max, 4096);
+        assert_eq!(proxy.resp_buffer_max, 16384);
     }
 
     #[test]
@@ -681,8 +1412,8 @@ cedar:
         assert_eq!(config.governance.rules[0].action, Action::Approve);
 
         let approval = config.approval.as_ref().unwrap();
-        assert!(approval.contains_key("default"));
-        assert!(approval.contains_key("finance"));
+        assert!(approval.workflows.contains_key("default"));
+        assert!(approval.workflows.contains_key("finance"));
 
         assert!(config.cedar.is_some());
     }
@@ -9,6 +9,8 @@
 //! - Governance rule matching
 //! - Tool exposure filtering
 //! - Centralized default values
+//! - Live reload of runtime settings
+//! - Tool-metadata catalog for Cedar resource attributes
 //!
 //! # Example
 //!
@@ -33,22 +35,31 @@
 //! # Traceability
 //! - Implements: REQ-CFG-001 (Configuration Schema)
 
+mod catalog;
 mod defaults;
 mod duration_format;
 mod error;
 mod loader;
+mod reload;
 mod schema;
 
 // Re-export public API
+pub use catalog::{LiveCatalog, RESERVED_ATTRIBUTES, RISK_ATTRIBUTE, ToolCatalog, ToolMetadata};
 pub use defaults::ThoughtGateDefaults;
+pub(crate) use duration_format::parse_duration;
 pub use error::{ConfigError, ValidationResult, ValidationWarning};
 pub use loader::{
-    Version, default_config_paths, find_config_file, load_and_validate, load_config,
+    Version, default_config_paths, find_config_file, load, load_and_validate, load_config,
     substitute_env_vars, validate,
 };
+pub use reload::{ConfigWatcher, LiveConfig, ReloadHook, reload_from_file, structural_changes};
 pub use schema::{
-    Action, ApprovalDestination, CedarConfig, Config, ExposeConfig, Governance, GovernanceDefaults,
-    HumanWorkflow, MatchResult, Rule, Source, SourceFilter, TimeoutAction, WebhookAuth,
+    Action, ApprovalConfig, ApprovalDestination, ApprovalMode, ApproverRoute, CedarConfig,
+    ClassifierAction, ClassifierConfig, Config, DEFAULT_REJECT_MESSAGE, Enforcement, Escalation,
+    ExposeConfig, FailoverConfig, FailoverStrategy, FailoverUpstream, Governance,
+    GovernanceDefaults, HeaderMutations, HumanWorkflow, MatchResult, MultiCallMode,
+    PolicyErrorMode, RejectCode, Rule, RuntimeSettings, SelfTestConfig, SelfTestFailureMode,
+    Source, SourceFilter, SourceHeaders, SourceTls, TimeoutAction, WebhookAuth,
 };
 
 #[cfg(test)]
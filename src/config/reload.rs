@@ -0,0 +1,587 @@
+//! Live reload of hot-reloadable settings.
+//!
+//! Implements: REQ-CFG-001 Section 9.1 (Configuration Loading Flow)
+//!
+//! Only the `runtime:` section ([`RuntimeSettings`]) is reloadable: log
+//! level, log sample rate, task rate limits and the default task TTL. Every
+//! other section is structural — sources, governance, approval, cedar,
+//! classifiers and proxy (listen address, stream tuning) — and only takes
+//! effect on restart. A reload that changes a structural section logs a
+//! warning and keeps running with the old values.
+//!
+//! The tool-metadata catalog file referenced by `catalog:` is watched too,
+//! and its contents are swapped in whenever it changes.
+//!
+//! Every successful reload also clears the classifier verdict cache, so a
+//! reload never leaves verdicts from before it in use.
+//!
+//! # Traceability
+//! - Implements: REQ-CFG-001/9.1 (Configuration Loading Flow)
+
+use arc_swap::ArcSwap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::Notify;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
+
+use super::catalog::LiveCatalog;
+use super::error::ConfigError;
+use super::loader::{Version, load_and_validate};
+use super::schema::{Config, RuntimeSettings};
+use crate::inspector::ClassifierPipeline;
+
+/// Callback run with the new settings after a reload changes them.
+pub type ReloadHook = Box<dyn Fn(&RuntimeSettings) + Send + Sync>;
+
+/// Callback run with the outcome of every reload attempt.
+pub type ReloadResultHook = Box<dyn Fn(Result<(), &ConfigError>) + Send + Sync>;
+
+/// Atomically swappable holder for the hot-reloadable settings.
+///
+/// Readers call [`LiveConfig::load`] on each use and always see a complete,
+/// consistent snapshot. Components that cannot read on each use (e.g. the
+/// tracing filter) register a hook with [`LiveConfig::on_reload`].
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001/9.1 (Configuration Loading Flow)
+pub struct LiveConfig {
+    current: ArcSwap<RuntimeSettings>,
+    hooks: ArcSwap<Vec<Arc<ReloadHook>>>,
+}
+
+impl std::fmt::Debug for LiveConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LiveConfig")
+            .field("current", &self.current.load())
+            .finish_non_exhaustive()
+    }
+}
+
+impl LiveConfig {
+    /// Create a holder with the startup settings.
+    pub fn new(settings: RuntimeSettings) -> Self {
+        Self {
+            current: ArcSwap::from_pointee(settings),
+            hooks: ArcSwap::from_pointee(Vec::new()),
+        }
+    }
+
+    /// Current settings snapshot.
+    pub fn load(&self) -> Arc<RuntimeSettings> {
+        self.current.load_full()
+    }
+
+    /// Register a hook run after every reload that changes a setting.
+    pub fn on_reload(&self, hook: impl Fn(&RuntimeSettings) + Send + Sync + 'static) {
+        let hook: Arc<ReloadHook> = Arc::new(Box::new(hook));
+        self.hooks.rcu(|hooks| {
+            let mut hooks = Vec::clone(hooks);
+            hooks.push(Arc::clone(&hook));
+            hooks
+        });
+    }
+
+    /// Swap in new settings and return the names of those that changed.
+    ///
+    /// Hooks run only if something changed.
+    pub fn update(&self, settings: RuntimeSettings) -> Vec<&'static str> {
+        let previous = self.current.swap(Arc::new(settings.clone()));
+        let changed = changed_settings(&previous, &settings);
+        if !changed.is_empty() {
+            for hook in self.hooks.load().iter() {
+                hook(&settings);
+            }
+        }
+        changed
+    }
+}
+
+/// Names of the runtime settings that differ between `old` and `new`.
+fn changed_settings(old: &RuntimeSettings, new: &RuntimeSettings) -> Vec<&'static str> {
+    let mut changed = Vec::new();
+    if old.log_level != new.log_level {
+        changed.push("log_level");
+    }
+    if old.log_sample_rate != new.log_sample_rate {
+        changed.push("log_sample_rate");
+    }
+    if old.max_pending_per_principal != new.max_pending_per_principal {
+        changed.push("max_pending_per_principal");
+    }
+    if old.max_pending_global != new.max_pending_global {
+        changed.push("max_pending_global");
+    }
+    if old.default_task_ttl != new.default_task_ttl {
+        changed.push("default_task_ttl");
+    }
+    changed
+}
+
+/// Names of the structural sections that differ between `old` and `new`.
+pub fn structural_changes(old: &Config, new: &Config) -> Vec<&'static str> {
+    fn differs<T: serde::Serialize>(a: &T, b: &T) -> bool {
+        serde_json::to_value(a).ok() != serde_json::to_value(b).ok()
+    }
+
+    let mut changed = Vec::new();
+    if old.schema != new.schema {
+        changed.push("schema");
+    }
+    if differs(&old.sources, &new.sources) {
+        changed.push("sources");
+    }
+    if differs(&old.governance, &new.governance) {
+        changed.push("governance");
+    }
+    if differs(&old.approval, &new.approval) {
+        changed.push("approval");
+    }
+    if differs(&old.cedar, &new.cedar) {
+        changed.push("cedar");
+    }
+    if old.classifiers != new.classifiers {
+        changed.push("classifiers");
+    }
+    if differs(&old.proxy, &new.proxy) {
+        changed.push("proxy");
+    }
+    if old.catalog != new.catalog {
+        changed.push("catalog");
+    }
+    if old.self_test != new.self_test {
+        changed.push("self_test");
+    }
+    if old.enforcement != new.enforcement {
+        changed.push("enforcement");
+    }
+    changed
+}
+
+/// Re-read `path` and apply its `runtime:` section to `live`.
+///
+/// `running` is the config the process started with; structural sections
+/// that differ from it are logged as requiring a restart.
+///
+/// # Errors
+///
+/// Returns `ConfigError` if the file cannot be loaded or fails validation.
+/// The current settings are left untouched.
+pub fn reload_from_file(
+    live: &LiveConfig,
+    path: &Path,
+    running: &Config,
+) -> Result<Vec<&'static str>, ConfigError> {
+    let (config, result) = load_and_validate(path, Version::V0_2)?;
+    for warning in &result.warnings {
+        warn!(warning = %warning, "Configuration warning");
+    }
+
+    let structural = structural_changes(running, &config);
+    if !structural.is_empty() {
+        warn!(
+            sections = %structural.join(", "),
+            "Structural configuration changed; restart required to apply"
+        );
+    }
+
+    let changed = live.update(config.runtime.unwrap_or_default());
+    if changed.is_empty() {
+        info!("Configuration reloaded, no runtime settings changed");
+    } else {
+        info!(changed = %changed.join(", "), "Configuration reloaded");
+    }
+    Ok(changed)
+}
+
+/// Watches the config file and reloads runtime settings when it changes.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001/9.1 (Configuration Loading Flow)
+pub struct ConfigWatcher {
+    live: Arc<LiveConfig>,
+    path: PathBuf,
+    running: Config,
+    poll_interval: Duration,
+    on_result: Option<ReloadResultHook>,
+    catalog: Option<Arc<LiveCatalog>>,
+    classifiers: Option<ClassifierPipeline>,
+}
+
+impl ConfigWatcher {
+    /// Create a watcher for `path`, which `running` was loaded from.
+    pub fn new(live: Arc<LiveConfig>, path: PathBuf, running: Config) -> Self {
+        Self {
+            live,
+            path,
+            running,
+            poll_interval: Duration::from_secs(5),
+            on_result: None,
+            catalog: None,
+            classifiers: None,
+        }
+    }
+
+    /// Also reload `catalog` when its file changes (or on `trigger`).
+    pub fn with_catalog(mut self, catalog: Arc<LiveCatalog>) -> Self {
+        self.catalog = Some(catalog);
+        self
+    }
+
+    /// Clear the verdict cache of `classifiers` after every successful
+    /// reload.
+    pub fn with_classifiers(mut self, classifiers: ClassifierPipeline) -> Self {
+        self.classifiers = Some(classifiers);
+        self
+    }
+
+    /// Run `hook` with the outcome of every reload attempt, e.g. to report
+    /// a failed reload through readiness.
+    pub fn on_result(
+        mut self,
+        hook: impl Fn(Result<(), &ConfigError>) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_result = Some(Box::new(hook));
+        self
+    }
+
+    /// Set how often the file's modification time is checked.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Reload whenever the file's modification time changes or `trigger`
+    /// is notified (e.g. on SIGHUP), until `shutdown` is cancelled.
+    ///
+    /// A failed reload is logged and the previous settings stay in effect.
+    pub async fn run(self, trigger: Arc<Notify>, shutdown: CancellationToken) {
+        let catalog_path = self.catalog.as_ref().map(|c| c.path().to_path_buf());
+        let mut last_modified = modified(&self.path);
+        let mut catalog_modified = catalog_path.as_deref().and_then(modified);
+        let mut ticker = tokio::time::interval(self.poll_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            let (config_changed, catalog_changed) = tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = trigger.notified() => {
+                    last_modified = modified(&self.path);
+                    catalog_modified = catalog_path.as_deref().and_then(modified);
+                    (true, catalog_path.is_some())
+                }
+                _ = ticker.tick() => {
+                    let current = modified(&self.path);
+                    let catalog_current = catalog_path.as_deref().and_then(modified);
+                    let changed = (current != last_modified, catalog_current != catalog_modified);
+                    last_modified = current;
+                    catalog_modified = catalog_current;
+                    changed
+                }
+            };
+
+            if config_changed {
+                let result = reload_from_file(&self.live, &self.path, &self.running);
+                match result {
+                    Ok(_) => {
+                        if let Some(ref classifiers) = self.classifiers {
+                            classifiers.invalidate_cache();
+                        }
+                    }
+                    Err(ref e) => error!(
+                        path = %self.path.display(),
+                        error = %e,
+                        "Configuration reload failed, keeping previous settings"
+                    ),
+                }
+                self.report(result.as_ref().map(|_| ()));
+            }
+
+            if let Some(catalog) = self.catalog.as_ref().filter(|_| catalog_changed) {
+                let result = catalog.reload();
+                if let Err(ref e) = result {
+                    error!(
+                        path = %catalog.path().display(),
+                        error = %e,
+                        "Tool catalog reload failed, keeping previous catalog"
+                    );
+                }
+                self.report(result.as_ref().map(|_| ()));
+            }
+        }
+    }
+
+    fn report(&self, result: Result<(), &ConfigError>) {
+        if let Some(ref hook) = self.on_result {
+            hook(result);
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const BASE: &str = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://localhost:8080
+governance:
+  defaults:
+    action: forward
+"#;
+
+    fn write_config(name: &str, runtime: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "thoughtgate_test_reload_{name}_{}.yaml",
+            std::process::id()
+        ));
+        std::fs::write(&path, format!("{BASE}{runtime}")).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_update_reports_changes_and_runs_hooks() {
+        let live = LiveConfig::new(RuntimeSettings::default());
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        live.on_reload(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let settings = RuntimeSettings {
+            log_level: Some("debug".to_string()),
+            max_pending_global: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(
+            live.update(settings.clone()),
+            ["log_level", "max_pending_global"]
+        );
+        assert_eq!(live.load().max_pending_global, Some(5));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Same settings again: nothing changed, hooks not run
+        assert!(live.update(settings).is_empty());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_reload_from_file_applies_runtime_section() {
+        let path = write_config("apply", "runtime:\n  max_pending_per_principal: 3\n");
+        let running: Config = serde_saphyr::from_str(BASE).unwrap();
+        let live = LiveConfig::new(RuntimeSettings::default());
+
+        let changed = reload_from_file(&live, &path, &running).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(changed, ["max_pending_per_principal"]);
+        assert_eq!(live.load().max_pending_per_principal, Some(3));
+    }
+
+    #[test]
+    fn test_reload_from_file_keeps_settings_on_error() {
+        let path = write_config("invalid", "runtime:\n  log_sample_rate: 2.0\n");
+        let running: Config = serde_saphyr::from_str(BASE).unwrap();
+        let initial = RuntimeSettings {
+            log_sample_rate: Some(0.5),
+            ..Default::default()
+        };
+        let live = LiveConfig::new(initial.clone());
+
+        let result = reload_from_file(&live, &path, &running);
+        let _ = std::fs::remove_file(&path);
+
+        assert!(matches!(result, Err(ConfigError::OutOfRange { .. })));
+        assert_eq!(*live.load(), initial);
+    }
+
+    #[test]
+    fn test_structural_changes() {
+        let old: Config = serde_saphyr::from_str(BASE).unwrap();
+        let new: Config = serde_saphyr::from_str(
+            &BASE
+                .replace("localhost:8080", "localhost:9090")
+                .replace("action: forward", "action: deny"),
+        )
+        .unwrap();
+
+        assert!(structural_changes(&old, &old.clone()).is_empty());
+        assert_eq!(structural_changes(&old, &new), ["sources", "governance"]);
+    }
+
+    #[tokio::test]
+    async fn test_watcher_reports_reload_results() {
+        let path = write_config(
+            "watch",
+            "runtime:
+  log_sample_rate: 2.0
+",
+        );
+        let running: Config = serde_saphyr::from_str(BASE).unwrap();
+        let live = Arc::new(LiveConfig::new(RuntimeSettings::default()));
+        let results = Arc::new(Mutex::new(Vec::new()));
+        let recorded = results.clone();
+
+        let watcher = ConfigWatcher::new(live, path.clone(), running)
+            .with_poll_interval(Duration::from_secs(3600))
+            .on_result(move |result| recorded.lock().unwrap().push(result.is_ok()));
+        let trigger = Arc::new(Notify::new());
+        let shutdown = CancellationToken::new();
+        let task = tokio::spawn(watcher.run(trigger.clone(), shutdown.clone()));
+
+        let wait_for = |n: usize| {
+            let results = results.clone();
+            async move {
+                while results.lock().unwrap().len() < n {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+            }
+        };
+
+        trigger.notify_one();
+        tokio::time::timeout(Duration::from_secs(5), wait_for(1))
+            .await
+            .unwrap();
+
+        std::fs::write(&path, BASE).unwrap();
+        trigger.notify_one();
+        tokio::time::timeout(Duration::from_secs(5), wait_for(2))
+            .await
+            .unwrap();
+
+        shutdown.cancel();
+        task.await.unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(*results.lock().unwrap(), [false, true]);
+    }
+
+    #[tokio::test]
+    async fn test_watcher_reloads_catalog() {
+        let path = write_config("catalog_base", "");
+        let catalog_path = std::env::temp_dir().join(format!(
+            "thoughtgate_test_reload_catalog_{}.yaml",
+            std::process::id()
+        ));
+        std::fs::write(&catalog_path, "default_risk: high\n").unwrap();
+
+        let running: Config = serde_saphyr::from_str(BASE).unwrap();
+        let live = Arc::new(LiveConfig::new(RuntimeSettings::default()));
+        let catalog = Arc::new(LiveCatalog::load(catalog_path.clone()).unwrap());
+
+        let watcher = ConfigWatcher::new(live, path.clone(), running)
+            .with_poll_interval(Duration::from_secs(3600))
+            .with_catalog(catalog.clone());
+        let trigger = Arc::new(Notify::new());
+        let shutdown = CancellationToken::new();
+        let task = tokio::spawn(watcher.run(trigger.clone(), shutdown.clone()));
+
+        std::fs::write(&catalog_path, "default_risk: medium\n").unwrap();
+        trigger.notify_one();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while catalog.catalog().default_risk != "medium" {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        shutdown.cancel();
+        task.await.unwrap();
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&catalog_path);
+    }
+
+    /// Classifier counting how often it runs.
+    struct Counting(Arc<AtomicUsize>);
+
+    #[async_trait::async_trait]
+    impl crate::inspector::Classifier for Counting {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        async fn classify(
+            &self,
+            _: &crate::policy::PolicyRequest,
+            _: &[u8],
+        ) -> crate::inspector::ClassificationOutcome {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            crate::inspector::ClassificationOutcome::Clean
+        }
+    }
+
+    #[tokio::test]
+    async fn test_watcher_invalidates_classifier_cache() {
+        let path = write_config("classifiers", "");
+        let running: Config = serde_saphyr::from_str(BASE).unwrap();
+        let live = Arc::new(LiveConfig::new(RuntimeSettings::default()));
+        let runs = Arc::new(AtomicUsize::new(0));
+        let classifiers = ClassifierPipeline::new()
+            .with_classifier(Arc::new(Counting(runs.clone())))
+            .with_cache(crate::inspector::ClassificationCache::new(
+                Duration::from_secs(3600),
+                16,
+            ));
+        let request = crate::policy::PolicyRequest {
+            principal: crate::policy::Principal {
+                app_name: "agent".to_string(),
+                namespace: "default".to_string(),
+                service_account: "default".to_string(),
+                roles: vec![],
+            },
+            resource: crate::policy::Resource::ToolCall {
+                name: "read_file".to_string(),
+                server: "upstream".to_string(),
+                attributes: Default::default(),
+            },
+            context: None,
+        };
+        let reloads = Arc::new(AtomicUsize::new(0));
+        let counter = reloads.clone();
+
+        let watcher = ConfigWatcher::new(live, path.clone(), running)
+            .with_poll_interval(Duration::from_secs(3600))
+            .with_classifiers(classifiers.clone())
+            .on_result(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        let trigger = Arc::new(Notify::new());
+        let shutdown = CancellationToken::new();
+        let task = tokio::spawn(watcher.run(trigger.clone(), shutdown.clone()));
+
+        classifiers.classify(&request, b"{}").await;
+        classifiers.classify(&request, b"{}").await;
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        trigger.notify_one();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while reloads.load(Ordering::SeqCst) == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        classifiers.classify(&request, b"{}").await;
+        assert_eq!(
+            runs.load(Ordering::SeqCst),
+            2,
+            "reload should clear verdicts"
+        );
+
+        shutdown.cancel();
+        task.await.unwrap();
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -12,6 +12,7 @@ use std::time::Duration;
 
 use super::defaults::ThoughtGateDefaults;
 use super::duration_format;
+use crate::proxy_config::ProxyConfigLayer;
 
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 // 7.1 Top-Level Schema
@@ -36,6 +37,7 @@ use super::duration_format;
 ///     action: forward
 /// ```
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct Config {
     /// Schema version (must be 1).
     pub schema: u32,
@@ -46,13 +48,83 @@ pub struct Config {
     /// Governance rules.
     pub governance: Governance,
 
-    /// Human approval workflows.
+    /// Human approval workflows and timeout defaults.
     #[serde(default)]
-    pub approval: Option<HashMap<String, HumanWorkflow>>,
+    pub approval: Option<ApprovalConfig>,
 
     /// Cedar policy configuration.
     #[serde(default)]
     pub cedar: Option<CedarConfig>,
+
+    /// Content classifiers run on requests Cedar permits (Gate 3).
+    ///
+    /// Structural: changes require a restart.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub classifiers: Vec<ClassifierConfig>,
+
+    /// Proxy setting overrides (lowest-precedence layer, see [`super::load`]).
+    ///
+    /// Structural: changes require a restart.
+    #[serde(default)]
+    pub proxy: Option<ProxyConfigLayer>,
+
+    /// Settings that can change without a restart (see [`super::LiveConfig`]).
+    #[serde(default)]
+    pub runtime: Option<RuntimeSettings>,
+
+    /// Tool-metadata catalog file (see [`super::ToolCatalog`]).
+    ///
+    /// The path is structural; the file's contents are hot-reloadable.
+    #[serde(default)]
+    pub catalog: Option<PathBuf>,
+
+    /// Checks run before serving (see [`crate::startup::self_test`]).
+    ///
+    /// Structural: only read at startup.
+    #[serde(default)]
+    pub self_test: Option<SelfTestConfig>,
+
+    /// Whether gate decisions are enforced or only recorded.
+    ///
+    /// Structural: changes require a restart.
+    #[serde(default)]
+    pub enforcement: Enforcement,
+}
+
+/// Hot-reloadable settings.
+///
+/// Every field is optional; an unset field keeps the value the component
+/// was started with. All other sections of [`Config`] are structural and
+/// only take effect on restart.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 9.1 (Configuration Loading Flow)
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuntimeSettings {
+    /// Log filter directive (e.g. `info`, `thoughtgate=debug`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<String>,
+
+    /// Fraction (0.0–1.0) of Green-path requests logged at INFO and below.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_sample_rate: Option<f64>,
+
+    /// Maximum pending tasks per principal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_pending_per_principal: Option<usize>,
+
+    /// Maximum pending tasks globally.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_pending_global: Option<usize>,
+
+    /// TTL for tasks created without an explicit TTL.
+    #[serde(
+        default,
+        deserialize_with = "duration_format::deserialize_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default_task_ttl: Option<Duration>,
 }
 
 impl Config {
@@ -61,14 +133,36 @@ impl Config {
         self.sources.iter().find(|s| s.id() == id)
     }
 
-    /// Get the first (and in v0.2, only) source.
+    /// Get the first source.
     pub fn primary_source(&self) -> Option<&Source> {
         self.sources.first()
     }
 
     /// Get an approval workflow by name.
     pub fn get_workflow(&self, name: &str) -> Option<&HumanWorkflow> {
-        self.approval.as_ref()?.get(name)
+        self.approval.as_ref()?.workflows.get(name)
+    }
+
+    /// Resolve the approval timeout for `tool` on source `server`.
+    ///
+    /// Implements: REQ-CFG-001 Section 7.5 (Approval Configuration)
+    ///
+    /// Precedence: the timeout of the `workflow` the policy selected, then
+    /// `approval.overrides`, then `approval.default_timeout`. A workflow
+    /// without any of these falls back to the built-in 10 minutes; `None`
+    /// leaves the choice to the approval engine.
+    pub fn approval_timeout(
+        &self,
+        workflow: Option<&HumanWorkflow>,
+        server: &str,
+        tool: &str,
+    ) -> Option<Duration> {
+        let approval = self.approval.as_ref();
+        workflow
+            .and_then(|w| w.timeout)
+            .or_else(|| approval?.overrides.get(server)?.get(tool).copied())
+            .or_else(|| approval?.default_timeout)
+            .or_else(|| workflow.map(HumanWorkflow::timeout_or_default))
     }
 
     /// Check if this configuration requires an approval engine.
@@ -107,7 +201,7 @@ impl Config {
 /// # Traceability
 /// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
 #[derive(Debug, Clone, Deserialize, Serialize)]
-#[serde(tag = "kind")]
+#[serde(tag = "kind", deny_unknown_fields)]
 pub enum Source {
     /// Static MCP server (v0.2).
     #[serde(rename = "mcp")]
@@ -133,6 +227,34 @@ pub enum Source {
         /// Human-readable description.
         #[serde(default)]
         description: Option<String>,
+
+        /// Request timeout for this upstream (defaults to the global timeout).
+        #[serde(
+            default,
+            deserialize_with = "duration_format::deserialize_option",
+            skip_serializing_if = "Option::is_none"
+        )]
+        timeout: Option<Duration>,
+
+        /// Connect timeout for this upstream (defaults to the global timeout).
+        #[serde(
+            default,
+            deserialize_with = "duration_format::deserialize_option",
+            skip_serializing_if = "Option::is_none"
+        )]
+        connect_timeout: Option<Duration>,
+
+        /// TLS settings for connecting to this upstream.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tls: Option<SourceTls>,
+
+        /// Secondary upstreams that take over when `url` is unreachable.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        failover: Option<FailoverConfig>,
+
+        /// Header mutations for traffic to and from this upstream.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        headers: Option<SourceHeaders>,
     },
     // v0.3+: A2a
     // v0.4+: McpDiscovery, OpenApi, A2aDiscovery
@@ -142,6 +264,149 @@ fn default_true() -> bool {
     true
 }
 
+/// Upstream group for a source: the primary `url` plus secondaries.
+///
+/// Secondaries share the source's timeouts and TLS settings. Each member has
+/// its own circuit breaker; open members are skipped until `cooldown` passes.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FailoverConfig {
+    /// How the first member is chosen for each request.
+    #[serde(default)]
+    pub strategy: FailoverStrategy,
+
+    /// Weight of the primary `url` (only used by `weighted`).
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+
+    /// Secondary upstreams, in failover order.
+    pub upstreams: Vec<FailoverUpstream>,
+
+    /// Consecutive failures that open a member's circuit (defaults to 3).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_threshold: Option<u32>,
+
+    /// How long an open circuit skips its member (defaults to 30s).
+    #[serde(
+        default,
+        deserialize_with = "duration_format::deserialize_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cooldown: Option<Duration>,
+}
+
+/// Member selection strategy for an upstream group.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailoverStrategy {
+    /// Always start with the first healthy member in order.
+    #[default]
+    Priority,
+    /// Spread requests across healthy members by weight.
+    Weighted,
+}
+
+/// A secondary upstream in a [`FailoverConfig`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FailoverUpstream {
+    /// Base URL of the secondary MCP server.
+    pub url: String,
+
+    /// Relative share of requests (only used by `weighted`).
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+/// TLS settings for a source's upstream connection.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SourceTls {
+    /// PEM bundle of extra CA certificates trusted for this upstream.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_file: Option<PathBuf>,
+
+    /// Trust only `ca_file` for this upstream, not the system roots.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pin_ca: bool,
+
+    /// PEM client certificate presented to the upstream (requires `client_key`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_cert: Option<PathBuf>,
+
+    /// PEM private key for `client_cert`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_key: Option<PathBuf>,
+}
+
+/// Header mutations for a source's traffic.
+///
+/// Connection-specific headers are always removed, whatever the rules say.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SourceHeaders {
+    /// Changes to requests sent to the upstream.
+    #[serde(default)]
+    pub request: HeaderMutations,
+
+    /// Changes to responses returned to the client.
+    #[serde(default)]
+    pub response: HeaderMutations,
+
+    /// Add `X-Forwarded-For` and `Via` (defaults to true).
+    #[serde(default = "default_true")]
+    pub forwarded: bool,
+
+    /// Header carrying the caller's principal to the upstream. Any value
+    /// sent by the client is replaced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub principal_header: Option<String>,
+}
+
+impl Default for SourceHeaders {
+    fn default() -> Self {
+        Self {
+            request: HeaderMutations::default(),
+            response: HeaderMutations::default(),
+            forwarded: true,
+            principal_header: None,
+        }
+    }
+}
+
+/// Header changes for one direction, applied as remove, then set, then add.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct HeaderMutations {
+    /// Headers to drop.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<String>,
+
+    /// Headers to set, replacing any existing values.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub set: HashMap<String, String>,
+
+    /// Headers to add, keeping any existing values.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub add: HashMap<String, String>,
+}
+
 impl Source {
     /// Get the source ID.
     pub fn id(&self) -> &str {
@@ -178,6 +443,43 @@ impl Source {
         }
     }
 
+    /// Get the per-source request timeout, if overridden.
+    pub fn timeout(&self) -> Option<Duration> {
+        match self {
+            Source::Mcp { timeout, .. } => *timeout,
+        }
+    }
+
+    /// Get the per-source connect timeout, if overridden.
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        match self {
+            Source::Mcp {
+                connect_timeout, ..
+            } => *connect_timeout,
+        }
+    }
+
+    /// Get the upstream TLS settings, if any.
+    pub fn tls(&self) -> Option<&SourceTls> {
+        match self {
+            Source::Mcp { tls, .. } => tls.as_ref(),
+        }
+    }
+
+    /// Get the failover group settings, if any.
+    pub fn failover(&self) -> Option<&FailoverConfig> {
+        match self {
+            Source::Mcp { failover, .. } => failover.as_ref(),
+        }
+    }
+
+    /// Get the header mutation rules, if any.
+    pub fn headers(&self) -> Option<&SourceHeaders> {
+        match self {
+            Source::Mcp { headers, .. } => headers.as_ref(),
+        }
+    }
+
     /// Get the source kind as a string.
     pub fn kind(&self) -> &'static str {
         match self {
@@ -196,7 +498,7 @@ impl Source {
 /// - Implements: REQ-CFG-001 Section 7.3 (Exposure Configuration)
 /// - Implements: REQ-CFG-001 Section 9.3 (Exposure Filtering)
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
-#[serde(tag = "mode")]
+#[serde(tag = "mode", deny_unknown_fields)]
 pub enum ExposeConfig {
     /// All tools visible (default).
     #[default]
@@ -254,6 +556,7 @@ impl ExposeConfig {
 /// # Traceability
 /// - Implements: REQ-CFG-001 Section 7.4 (Governance Configuration)
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct Governance {
     /// Default action when no rule matches.
     pub defaults: GovernanceDefaults,
@@ -265,9 +568,108 @@ pub struct Governance {
 
 /// Default governance settings.
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct GovernanceDefaults {
     /// Default action to take when no rule matches.
     pub action: Action,
+
+    /// Whether a Cedar engine error forwards or rejects the request.
+    #[serde(default)]
+    pub on_policy_error: PolicyErrorMode,
+
+    /// Policy denials whose detailed reason is returned to the client.
+    ///
+    /// Any other denial only tells the client the request was not
+    /// permitted; the reason goes to the logs and audit trail.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disclose_reasons: Vec<RejectCode>,
+
+    /// How a `tools/call` carrying several calls is handled when only
+    /// some of them are permitted.
+    #[serde(default)]
+    pub multi_call: MultiCallMode,
+}
+
+impl GovernanceDefaults {
+    /// The message a client gets for a denial with `code` and `reason`.
+    ///
+    /// Implements: REQ-CORE-004/NFR-002 (Security - No Data Leaks)
+    pub fn client_message(&self, code: RejectCode, reason: &str) -> String {
+        if self.disclose_reasons.contains(&code) {
+            reason.to_string()
+        } else {
+            DEFAULT_REJECT_MESSAGE.to_string()
+        }
+    }
+}
+
+/// What a client is told about a denial whose reason is not disclosed.
+pub const DEFAULT_REJECT_MESSAGE: &str = "Request not permitted";
+
+/// Why the Cedar policy engine denied a request.
+///
+/// # Traceability
+/// - Implements: REQ-POL-001/§6.2 (Policy Action output)
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectCode {
+    /// A `forbid` policy matched.
+    Forbidden,
+    /// No policy permits the request.
+    DefaultDeny,
+    /// The tool is on the policy denylist.
+    Denylist,
+    /// Policies failed to evaluate and errors fail closed.
+    PolicyError,
+    /// A content classifier refused a request Cedar permitted.
+    Classifier,
+}
+
+impl RejectCode {
+    /// The code as written in configuration.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Forbidden => "forbidden",
+            Self::DefaultDeny => "default_deny",
+            Self::Denylist => "denylist",
+            Self::PolicyError => "policy_error",
+            Self::Classifier => "classifier",
+        }
+    }
+}
+
+/// Behavior when the Cedar engine fails to evaluate a request.
+///
+/// Only applies to engine-internal errors; an explicit Forbid is always
+/// honored.
+///
+/// # Traceability
+/// - Implements: REQ-POL-001/F-001 (Policy Evaluation)
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyErrorMode {
+    /// Treat the error as a Permit. Trades safety for availability.
+    FailOpen,
+    /// Treat the error as a Forbid.
+    #[default]
+    FailClosed,
+}
+
+/// How a request carrying several tool calls is governed when only some
+/// of its calls are permitted.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-003/F-007 (Multi-Call Requests)
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MultiCallMode {
+    /// Refuse the whole request if any call is refused.
+    #[default]
+    RejectAny,
+    /// Forward the permitted calls and drop the rest.
+    ///
+    /// The request is still refused if no call is permitted.
+    Split,
 }
 
 /// Actions that can be taken for a tool call.
@@ -303,6 +705,7 @@ impl std::fmt::Display for Action {
 /// # Traceability
 /// - Implements: REQ-CFG-001 Section 7.4 (Rule)
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct Rule {
     /// Glob pattern for tool name matching.
     #[serde(rename = "match")]
@@ -327,6 +730,12 @@ pub struct Rule {
     #[serde(default)]
     pub description: Option<String>,
 
+    /// JSON-RPC `result` returned to the agent when this rule denies a
+    /// request (`action: deny`, or a Cedar forbid under `action: policy`),
+    /// in place of a policy error.
+    #[serde(default)]
+    pub synthetic_response: Option<serde_json::Value>,
+
     // ───────────────────────────────────────────────────────────────────────
     // Future slots (v0.3+) - Parsed but ignored in v0.2
     // ───────────────────────────────────────────────────────────────────────
@@ -370,6 +779,8 @@ pub struct MatchResult {
     pub approval_workflow: Option<String>,
     /// The pattern that matched (None if default).
     pub matched_rule: Option<String>,
+    /// Result to return instead of an error if the request is denied.
+    pub synthetic_response: Option<serde_json::Value>,
 }
 
 impl Governance {
@@ -394,6 +805,7 @@ impl Governance {
                         policy_id: rule.policy_id.clone(),
                         approval_workflow: rule.approval.clone(),
                         matched_rule: Some(rule.pattern.clone()),
+                        synthetic_response: rule.synthetic_response.clone(),
                     };
                 }
             }
@@ -405,6 +817,7 @@ impl Governance {
             policy_id: None,
             approval_workflow: None,
             matched_rule: None,
+            synthetic_response: None,
         }
     }
 }
@@ -413,11 +826,78 @@ impl Governance {
 // 7.5 Approval Configuration
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 
+/// The `approval` section: named workflows plus timeout defaults.
+///
+/// Workflows are keyed by name alongside `default_timeout` and
+/// `overrides`, so those two names cannot be used for a workflow.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 7.5 (Approval Configuration)
+///
+/// # Example
+/// ```yaml
+/// approval:
+///   default_timeout: 15m
+///   overrides:
+///     github:
+///       merge_pull_request: 1h
+///   default:
+///     destination:
+///       type: slack
+///       channel: "#approvals"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ApprovalConfig {
+    /// Timeout for approvals whose workflow does not set one.
+    #[serde(
+        default,
+        deserialize_with = "duration_format::deserialize_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default_timeout: Option<Duration>,
+
+    /// Timeouts for individual tools, by source ID and then tool name.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_timeout_overrides",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub overrides: HashMap<String, HashMap<String, Duration>>,
+
+    /// Approval workflows by name.
+    #[serde(flatten)]
+    pub workflows: HashMap<String, HumanWorkflow>,
+}
+
+/// Deserialize `approval.overrides`, parsing each timeout as a duration.
+fn deserialize_timeout_overrides<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, HashMap<String, Duration>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let raw: HashMap<String, HashMap<String, String>> = Deserialize::deserialize(deserializer)?;
+    raw.into_iter()
+        .map(|(server, tools)| {
+            let tools = tools
+                .into_iter()
+                .map(|(tool, timeout)| {
+                    duration_format::parse_duration(&timeout)
+                        .map(|timeout| (tool, timeout))
+                        .map_err(serde::de::Error::custom)
+                })
+                .collect::<Result<_, _>>()?;
+            Ok((server, tools))
+        })
+        .collect()
+}
+
 /// Human approval workflow configuration.
 ///
 /// # Traceability
 /// - Implements: REQ-CFG-001 Section 7.5 (Approval Configuration)
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct HumanWorkflow {
     /// Destination for approval requests.
     pub destination: ApprovalDestination,
@@ -433,6 +913,20 @@ pub struct HumanWorkflow {
     /// Action to take on timeout.
     #[serde(default)]
     pub on_timeout: Option<TimeoutAction>,
+
+    /// How the agent receives the decision (defaults to the engine-wide mode).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<ApprovalMode>,
+
+    /// Escalate to a secondary approver group if nobody responds in time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalation: Option<Escalation>,
+
+    /// Distinct approvers required before the call is approved (defaults to 1).
+    ///
+    /// A single rejection still rejects immediately.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_approvals: Option<u8>,
 }
 
 impl HumanWorkflow {
@@ -446,6 +940,11 @@ impl HumanWorkflow {
     pub fn on_timeout_or_default(&self) -> TimeoutAction {
         self.on_timeout.clone().unwrap_or_default()
     }
+
+    /// Get the required number of approvers, at least 1.
+    pub fn min_approvals_or_default(&self) -> u8 {
+        self.min_approvals.unwrap_or(1).max(1)
+    }
 }
 
 /// Destination for approval requests.
@@ -453,7 +952,7 @@ impl HumanWorkflow {
 /// # Traceability
 /// - Implements: REQ-CFG-001 Section 7.5 (ApprovalDestination)
 #[derive(Debug, Clone, Deserialize, Serialize)]
-#[serde(tag = "type")]
+#[serde(tag = "type", deny_unknown_fields)]
 pub enum ApprovalDestination {
     /// Send to Slack channel.
     #[serde(rename = "slack")]
@@ -499,6 +998,65 @@ pub enum ApprovalDestination {
     },
 }
 
+/// Escalation of an unanswered approval to a secondary approver group.
+///
+/// # Traceability
+/// - Implements: REQ-GOV-003/F-007 (Approval Escalation)
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Escalation {
+    /// How long the primary approvers have before escalating (typically
+    /// half the workflow timeout).
+    #[serde(with = "duration_format")]
+    pub escalate_after: Duration,
+
+    /// Where the follow-up message is posted.
+    pub escalate_to: ApproverRoute,
+}
+
+/// A Slack channel and the people to ping there.
+///
+/// # Traceability
+/// - Implements: REQ-GOV-003/F-007
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ApproverRoute {
+    /// Slack channel (e.g., "#oncall-approvals").
+    pub channel: String,
+
+    /// Mentions in Slack syntax (e.g., `<@U123>`, `<!subteam^S456>`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mention: Vec<String>,
+}
+
+/// How an approval-gated request is answered.
+///
+/// # Traceability
+/// - Implements: REQ-GOV-002/F-002.3 (Approval Response Mode)
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalMode {
+    /// Return a SEP-1686 task immediately; the agent polls `tasks/get` and
+    /// fetches the replayed result with `tasks/result`.
+    #[default]
+    Async,
+    /// Hold the connection open until the decision, then return the
+    /// replayed result (or the rejection) directly.
+    Blocking,
+}
+
+impl std::str::FromStr for ApprovalMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "async" => Ok(Self::Async),
+            "blocking" => Ok(Self::Blocking),
+            other => Err(format!("unknown approval mode '{other}'")),
+        }
+    }
+}
+
 /// Action to take when approval times out.
 #[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -511,6 +1069,7 @@ pub enum TimeoutAction {
 
 /// Webhook authentication configuration.
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct WebhookAuth {
     /// Authentication type ("bearer" or "basic").
     #[serde(rename = "type")]
@@ -530,6 +1089,7 @@ pub struct WebhookAuth {
 /// # Traceability
 /// - Implements: REQ-CFG-001 Section 7.6 (Cedar Configuration)
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct CedarConfig {
     /// List of Cedar policy file paths.
     pub policies: Vec<PathBuf>,
@@ -539,10 +1099,217 @@ pub struct CedarConfig {
     pub schema: Option<PathBuf>,
 }
 
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Content Classifiers
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// A content classifier (see [`crate::inspector::Classifier`]).
+///
+/// Classifiers look at the payload of a request Cedar has permitted and
+/// can only make the decision stricter.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-006 (Content Classification)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ClassifierConfig {
+    /// Escalate requests whose JSON body matches any of `patterns`.
+    Regex {
+        /// What a match does.
+        action: ClassifierAction,
+        /// Regular expressions matched against the request body.
+        patterns: Vec<String>,
+    },
+}
+
+/// What a classifier match does to a permitted request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClassifierAction {
+    /// Hold the request for human approval.
+    Approve,
+    /// Refuse the request.
+    Reject,
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Startup Self-Test
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// Startup self-test configuration.
+///
+/// Every check runs unless turned off; sample requests only run when a
+/// fixture is given.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-005/F-001 (Startup Sequencing)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SelfTestConfig {
+    /// Load the Cedar policy set.
+    #[serde(default = "default_true")]
+    pub policies: bool,
+
+    /// Policy test fixture (see [`crate::policy::testkit`]) whose cases must
+    /// pass against the loaded policies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub samples: Option<PathBuf>,
+
+    /// Verify the Slack bot token, when approvals are posted to Slack.
+    #[serde(default = "default_true")]
+    pub slack: bool,
+
+    /// Verify every enabled source accepts connections.
+    #[serde(default = "default_true")]
+    pub upstream: bool,
+
+    /// What a failed check does.
+    #[serde(default)]
+    pub on_failure: SelfTestFailureMode,
+}
+
+impl Default for SelfTestConfig {
+    fn default() -> Self {
+        Self {
+            policies: true,
+            samples: None,
+            slack: true,
+            upstream: true,
+            on_failure: SelfTestFailureMode::default(),
+        }
+    }
+}
+
+/// What a failed self-test check does.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SelfTestFailureMode {
+    /// Refuse to start.
+    #[default]
+    Fail,
+    /// Log the failure at error level and start anyway.
+    Warn,
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Enforcement
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// Whether gate decisions take effect.
+///
+/// In monitor mode every gate still runs and its decision is audited, but
+/// requests that would be refused or held for approval are forwarded, for
+/// trying out a configuration before enforcing it.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 9 (Request Processing)
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Enforcement {
+    /// Refuse, hold for approval, or forward as decided.
+    #[default]
+    Enforce,
+    /// Record the decision, then forward regardless.
+    Monitor,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn approval_config(approval: &str) -> Config {
+        let yaml = format!(
+            r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://localhost:8080
+governance:
+  defaults:
+    action: approve
+approval:
+{approval}
+"#
+        );
+        serde_saphyr::from_str(&yaml).unwrap()
+    }
+
+    const WORKFLOWS: &str = r##"
+  quick:
+    destination:
+      type: slack
+      channel: "#approvals"
+    timeout: 2m
+  plain:
+    destination:
+      type: slack
+      channel: "#approvals"
+"##;
+
+    /// Verifies: REQ-CFG-001 Section 7.5 (Approval Configuration)
+    #[test]
+    fn test_approval_timeout_precedence() {
+        let config = approval_config(&format!(
+            "  default_timeout: 15m\n  overrides:\n    upstream:\n      deploy: 1h\n{WORKFLOWS}"
+        ));
+        let quick = config.get_workflow("quick");
+        let plain = config.get_workflow("plain");
+        let mins = |m: u64| Some(Duration::from_secs(m * 60));
+
+        // Workflow timeout beats the override and the default
+        assert_eq!(
+            config.approval_timeout(quick, "upstream", "deploy"),
+            mins(2)
+        );
+        assert_eq!(config.approval_timeout(quick, "upstream", "other"), mins(2));
+        // Override beats the default, only for its own server and tool
+        assert_eq!(
+            config.approval_timeout(plain, "upstream", "deploy"),
+            mins(60)
+        );
+        assert_eq!(
+            config.approval_timeout(None, "upstream", "deploy"),
+            mins(60)
+        );
+        assert_eq!(config.approval_timeout(plain, "other", "deploy"), mins(15));
+        // Default applies when nothing more specific is set
+        assert_eq!(
+            config.approval_timeout(plain, "upstream", "other"),
+            mins(15)
+        );
+        assert_eq!(config.approval_timeout(None, "upstream", "other"), mins(15));
+    }
+
+    /// Verifies: REQ-CFG-001 Section 7.5 (Approval Configuration)
+    #[test]
+    fn test_approval_timeout_without_defaults() {
+        let config = approval_config(WORKFLOWS);
+        let plain = config.get_workflow("plain");
+
+        assert!(config.approval.as_ref().unwrap().overrides.is_empty());
+        assert_eq!(config.approval.as_ref().unwrap().workflows.len(), 2);
+        // A workflow still gets the built-in default; no workflow defers
+        // to the approval engine
+        assert_eq!(
+            config.approval_timeout(plain, "upstream", "deploy"),
+            Some(ThoughtGateDefaults::default().default_approval_timeout)
+        );
+        assert_eq!(config.approval_timeout(None, "upstream", "deploy"), None);
+    }
+
+    #[test]
+    fn test_enforcement_defaults_to_enforce() {
+        let config = approval_config(WORKFLOWS);
+        assert_eq!(config.enforcement, Enforcement::Enforce);
+
+        let monitor: Config = serde_saphyr::from_str(
+            "schema: 1\nenforcement: monitor\nsources: []\ngovernance:\n  defaults:\n    action: forward\n",
+        )
+        .unwrap();
+        assert_eq!(monitor.enforcement, Enforcement::Monitor);
+    }
+
     #[test]
     fn test_source_accessors() {
         let source = Source::Mcp {
@@ -552,6 +1319,11 @@ mod tests {
             expose: None,
             enabled: true,
             description: Some("Test source".to_string()),
+            timeout: None,
+            connect_timeout: None,
+            tls: None,
+            failover: None,
+            headers: None,
         };
 
         assert_eq!(source.id(), "test");
@@ -603,11 +1375,24 @@ mod tests {
         assert!(!filter.matches("source3"));
     }
 
+    #[test]
+    fn test_on_policy_error_parsing() {
+        let defaults: GovernanceDefaults = serde_saphyr::from_str("action: policy\n").unwrap();
+        assert_eq!(defaults.on_policy_error, PolicyErrorMode::FailClosed);
+
+        let defaults: GovernanceDefaults =
+            serde_saphyr::from_str("action: policy\non_policy_error: fail_open\n").unwrap();
+        assert_eq!(defaults.on_policy_error, PolicyErrorMode::FailOpen);
+    }
+
     #[test]
     fn test_governance_evaluate_first_match() {
         let governance = Governance {
             defaults: GovernanceDefaults {
                 action: Action::Forward,
+                on_policy_error: PolicyErrorMode::default(),
+                disclose_reasons: Vec::new(),
+                multi_call: MultiCallMode::default(),
             },
             rules: vec![
                 Rule {
@@ -617,6 +1402,7 @@ mod tests {
                     policy_id: None,
                     approval: Some("default".to_string()),
                     description: None,
+                    synthetic_response: None,
                     limits: None,
                     inspectors: None,
                 },
@@ -627,6 +1413,7 @@ mod tests {
                     policy_id: None,
                     approval: None,
                     description: None,
+                    synthetic_response: None,
                     limits: None,
                     inspectors: None,
                 },
@@ -643,6 +1430,9 @@ mod tests {
         let governance = Governance {
             defaults: GovernanceDefaults {
                 action: Action::Forward,
+                on_policy_error: PolicyErrorMode::default(),
+                disclose_reasons: Vec::new(),
+                multi_call: MultiCallMode::default(),
             },
             rules: vec![Rule {
                 pattern: "admin_*".to_string(),
@@ -651,6 +1441,7 @@ mod tests {
                 policy_id: None,
                 approval: None,
                 description: None,
+                synthetic_response: None,
                 limits: None,
                 inspectors: None,
             }],
@@ -666,6 +1457,9 @@ mod tests {
         let governance = Governance {
             defaults: GovernanceDefaults {
                 action: Action::Forward,
+                on_policy_error: PolicyErrorMode::default(),
+                disclose_reasons: Vec::new(),
+                multi_call: MultiCallMode::default(),
             },
             rules: vec![Rule {
                 pattern: "*".to_string(),
@@ -674,6 +1468,7 @@ mod tests {
                 policy_id: None,
                 approval: None,
                 description: None,
+                synthetic_response: None,
                 limits: None,
                 inspectors: None,
             }],
@@ -695,4 +1490,67 @@ mod tests {
         assert_eq!(Action::Deny.to_string(), "deny");
         assert_eq!(Action::Policy.to_string(), "policy");
     }
+
+    #[test]
+    fn test_approval_mode_parse() {
+        assert_eq!("async".parse::<ApprovalMode>(), Ok(ApprovalMode::Async));
+        assert_eq!(
+            " Blocking ".parse::<ApprovalMode>(),
+            Ok(ApprovalMode::Blocking)
+        );
+        assert!("later".parse::<ApprovalMode>().is_err());
+        assert_eq!(ApprovalMode::default(), ApprovalMode::Async);
+    }
+
+    #[test]
+    fn test_workflow_mode_deserialize() {
+        let workflow: HumanWorkflow = serde_saphyr::from_str(
+            "destination:\n  type: slack\n  channel: \"#approvals\"\nmode: blocking\n",
+        )
+        .unwrap();
+        assert_eq!(workflow.mode, Some(ApprovalMode::Blocking));
+
+        let workflow: HumanWorkflow =
+            serde_saphyr::from_str("destination:\n  type: cli\n").unwrap();
+        assert_eq!(workflow.mode, None);
+        assert_eq!(workflow.escalation, None);
+    }
+
+    #[test]
+    fn test_workflow_escalation_deserialize() {
+        let yaml = r##"
+destination:
+  type: slack
+  channel: "#approvals"
+timeout: 10m
+escalation:
+  escalate_after: 5m
+  escalate_to:
+    channel: "#oncall"
+    mention: ["<!subteam^S123>"]
+"##;
+        let workflow: HumanWorkflow = serde_saphyr::from_str(yaml).unwrap();
+        let escalation = workflow.escalation.unwrap();
+        assert_eq!(escalation.escalate_after, Duration::from_secs(300));
+        assert_eq!(escalation.escalate_to.channel, "#oncall");
+        assert_eq!(escalation.escalate_to.mention, vec!["<!subteam^S123>"]);
+    }
+
+    #[test]
+    fn test_workflow_min_approvals() {
+        let yaml = r##"
+destination:
+  type: slack
+  channel: "#approvals"
+min_approvals: 2
+"##;
+        let workflow: HumanWorkflow = serde_saphyr::from_str(yaml).unwrap();
+        assert_eq!(workflow.min_approvals_or_default(), 2);
+
+        let workflow = HumanWorkflow {
+            min_approvals: Some(0),
+            ..workflow
+        };
+        assert_eq!(workflow.min_approvals_or_default(), 1);
+    }
 }
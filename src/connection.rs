@@ -0,0 +1,955 @@
+//! Client connection handling for the outbound listener.
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+//! - Implements: REQ-CORE-002 (Conditional Termination - CONNECT rejection)
+//!
+//! # Overview
+//!
+//! Serves HTTP on each accepted connection, closing it when the client
+//! stalls outside a request (classic slowloris protection):
+//!
+//! | Timeout | Running while | Outcome |
+//! |---------|---------------|---------|
+//! | `header_read_timeout` | Waiting for a request's headers | Connection closed |
+//! | `idle_connection_timeout` | No request in flight | Connection closed |
+//! | `response_start_timeout` | Waiting for the service's response | 408, then closed |
+//!
+//! The header timer starts when the connection opens and, between HTTP/1
+//! requests, at the first byte of the next one; until that byte arrives the
+//! connection is idle. HTTP/2 connections only use the header timer for
+//! their first request. A request stays in flight until its response body
+//! is finished or dropped, so long streams are never idle. Every closure is
+//! counted in `connections_timed_out_total`.
+//!
+//! [`TimeoutBody`](crate::timeout::TimeoutBody) protects the bodies
+//! themselves once a request is under way.
+//!
+//! # HTTP/2 Stream Limit
+//!
+//! HTTP/2 connections advertise `http2_max_concurrent_streams` and refuse
+//! streams opened beyond it with RST_STREAM(REFUSED_STREAM), which clients
+//! may safely retry. Refusals happen inside the HTTP/2 codec, so they are
+//! counted from the frames written back to the client, in
+//! `http2_streams_refused_total`.
+//!
+//! # Socket Options
+//!
+//! [`bind_listener`] applies `tcp_reuseaddr` and `listen_backlog` to the
+//! listening socket; [`configure_stream`] applies `tcp_nodelay`, keepalive
+//! and buffer sizes to each accepted connection.
+
+use std::future::Future;
+use std::io;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use http_body_util::BodyExt;
+use hyper::body::Incoming;
+use hyper::{Request, Response, header};
+use hyper_util::rt::TokioIo;
+use hyper_util::server::conn::auto;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::{TcpListener, TcpSocket, TcpStream, ToSocketAddrs};
+use tokio::sync::Notify;
+use tokio::time::Instant;
+use tokio_rustls::TlsAcceptor;
+use tokio_util::sync::CancellationToken;
+use tower::Service;
+use tracing::{debug, error, info, warn};
+
+use crate::error::ProxyError;
+use crate::proxy_config::ProxyConfig;
+use crate::proxy_service::{
+    CONNECT_PEEK_LEN, UnifiedBody, connect_rejection_response, is_connect_preface,
+};
+use crate::transport::tls::ClientCertificate;
+
+/// Address of the client a request came from, attached to every request
+/// as an extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeerAddr(pub SocketAddr);
+
+/// Start of the HTTP/2 connection preface.
+const H2_PREFACE_START: &[u8] = b"PRI * HTTP/2.0";
+
+/// How long a timed-out idle connection gets to close gracefully.
+const IDLE_CLOSE_GRACE: Duration = Duration::from_secs(1);
+
+/// Length of an HTTP/2 frame header.
+const H2_FRAME_HEADER_LEN: usize = 9;
+
+/// HTTP/2 RST_STREAM frame type.
+const H2_RST_STREAM: u8 = 0x3;
+
+/// HTTP/2 REFUSED_STREAM error code.
+const H2_REFUSED_STREAM: u32 = 0x7;
+
+// ============================================================================
+// Socket Options
+// ============================================================================
+
+/// Bind a listener with the configured socket options.
+///
+/// Like [`TcpListener::bind`], every address `addr` resolves to is tried
+/// in turn and the first that binds is used.
+///
+/// # Errors
+///
+/// Returns the last bind error if no address could be bound.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 Section 3.2 (Network Optimization)
+pub async fn bind_listener<A: ToSocketAddrs>(
+    addr: A,
+    config: &ProxyConfig,
+) -> io::Result<TcpListener> {
+    let mut last_err = None;
+    for addr in tokio::net::lookup_host(addr).await? {
+        let socket = if addr.is_ipv4() {
+            TcpSocket::new_v4()?
+        } else {
+            TcpSocket::new_v6()?
+        };
+        // Windows SO_REUSEADDR allows binding over a live listener
+        #[cfg(not(windows))]
+        socket.set_reuseaddr(config.tcp_reuseaddr)?;
+        match socket
+            .bind(addr)
+            .and_then(|()| socket.listen(config.listen_backlog))
+        {
+            Ok(listener) => return Ok(listener),
+            Err(e) => last_err = Some(e),
+        }
+    }
+    Err(last_err.unwrap_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "could not resolve to any address",
+        )
+    }))
+}
+
+/// Configure an accepted connection with optimized socket options.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-001 (TCP_NODELAY enforcement)
+/// - Implements: REQ-CORE-001 Section 3.2 (Network Optimization)
+pub fn configure_stream(stream: &TcpStream, config: &ProxyConfig) -> io::Result<()> {
+    // Set TCP_NODELAY
+    stream.set_nodelay(config.tcp_nodelay)?;
+
+    // Convert to socket2::Socket for advanced options
+    let socket = socket2::SockRef::from(stream);
+
+    // Set TCP keepalive
+    let keepalive =
+        socket2::TcpKeepalive::new().with_time(Duration::from_secs(config.tcp_keepalive_secs));
+    #[cfg(any(
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "windows",
+    ))]
+    let keepalive = match config.tcp_keepalive_interval {
+        Some(interval) => keepalive.with_interval(interval),
+        None => keepalive,
+    };
+    socket.set_tcp_keepalive(&keepalive)?;
+
+    // Set socket buffer sizes
+    socket.set_recv_buffer_size(config.socket_buffer_size)?;
+    socket.set_send_buffer_size(config.socket_buffer_size)?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Timeouts
+// ============================================================================
+
+/// Listener-side timeouts and limits for one connection.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListenerTimeouts {
+    /// Time allowed to receive a request's headers
+    pub header_read: Option<Duration>,
+    /// Time a connection may sit with no request in flight
+    pub idle: Option<Duration>,
+    /// Time allowed for the service to start a response
+    pub request: Option<Duration>,
+    /// Most concurrent streams on an HTTP/2 connection (`None` leaves
+    /// hyper's default)
+    pub http2_max_concurrent_streams: Option<u32>,
+}
+
+impl ListenerTimeouts {
+    /// Take the timeouts and limits from the proxy configuration.
+    pub fn from_config(config: &ProxyConfig) -> Self {
+        Self {
+            header_read: config.header_read_timeout,
+            idle: config.idle_connection_timeout,
+            request: config.response_start_timeout,
+            http2_max_concurrent_streams: Some(config.http2_max_concurrent_streams),
+        }
+    }
+}
+
+/// Which timeout closed a connection.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionTimeout {
+    /// Headers did not arrive in time
+    HeaderRead,
+    /// No request for too long
+    Idle,
+    /// The response did not start in time
+    Request,
+}
+
+impl ConnectionTimeout {
+    /// Metric label for this timeout.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::HeaderRead => "header_read",
+            Self::Idle => "idle",
+            Self::Request => "request",
+        }
+    }
+
+    /// Count and log a connection closed by this timeout.
+    fn record(self) {
+        debug!(
+            timeout = self.as_str(),
+            "Closing client connection on timeout"
+        );
+        if let Some(metrics) = crate::metrics::get_connection_metrics() {
+            metrics.record_timeout(self.as_str());
+        }
+    }
+}
+
+/// `reading_headers_since` while no headers are being read.
+const NOT_READING: u64 = u64::MAX;
+
+/// Shared activity of one connection.
+///
+/// Updated by the I/O wrapper (bytes read) and by each request's
+/// [`InFlight`] guard; [`ConnectionTracker::expired`] watches it. Times are
+/// nanoseconds since the connection opened, held in atomics so reads never
+/// contend on a lock.
+#[derive(Debug)]
+struct ConnectionTracker {
+    timeouts: ListenerTimeouts,
+    /// Whether bytes between requests start the header timer (HTTP/1)
+    restart_header_timer: bool,
+    /// When the connection opened
+    opened_at: Instant,
+    /// Requests whose response has not finished
+    in_flight: AtomicUsize,
+    /// When the headers now being read started arriving, or
+    /// [`NOT_READING`]
+    reading_headers_since: AtomicU64,
+    /// When the last request finished (or the connection opened)
+    idle_since: AtomicU64,
+    changed: Notify,
+}
+
+impl ConnectionTracker {
+    fn new(timeouts: ListenerTimeouts, opened_at: Instant) -> Self {
+        Self {
+            timeouts,
+            restart_header_timer: true,
+            opened_at,
+            in_flight: AtomicUsize::new(0),
+            reading_headers_since: AtomicU64::new(0),
+            idle_since: AtomicU64::new(0),
+            changed: Notify::new(),
+        }
+    }
+
+    /// Nanoseconds from the connection opening to now.
+    fn now(&self) -> u64 {
+        u64::try_from(self.opened_at.elapsed().as_nanos())
+            .unwrap_or(u64::MAX)
+            .min(NOT_READING - 1)
+    }
+
+    /// The instant `nanos` after the connection opened.
+    fn instant(&self, nanos: u64) -> Instant {
+        self.opened_at + Duration::from_nanos(nanos)
+    }
+
+    /// Bytes arrived from the client.
+    fn on_read(&self) {
+        if !self.restart_header_timer || self.in_flight.load(Ordering::Acquire) > 0 {
+            return;
+        }
+        if self
+            .reading_headers_since
+            .compare_exchange(NOT_READING, self.now(), Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            self.changed.notify_waiters();
+        }
+    }
+
+    /// A request's headers were read; it is in flight until the guard drops.
+    fn begin_request(self: &Arc<Self>) -> InFlight {
+        self.in_flight.fetch_add(1, Ordering::AcqRel);
+        self.reading_headers_since
+            .store(NOT_READING, Ordering::Release);
+        self.changed.notify_waiters();
+        InFlight(self.clone())
+    }
+
+    /// The next deadline of the header or idle timer, if one is running.
+    fn next_deadline(&self) -> Option<(Instant, ConnectionTimeout)> {
+        if self.in_flight.load(Ordering::Acquire) > 0 {
+            return None;
+        }
+        match self.reading_headers_since.load(Ordering::Acquire) {
+            NOT_READING => self.timeouts.idle.map(|t| {
+                let idle_since = self.instant(self.idle_since.load(Ordering::Acquire));
+                (idle_since + t, ConnectionTimeout::Idle)
+            }),
+            since => self
+                .timeouts
+                .header_read
+                .map(|t| (self.instant(since) + t, ConnectionTimeout::HeaderRead)),
+        }
+    }
+
+    /// Resolve once the header or idle timer runs out.
+    async fn expired(&self) -> ConnectionTimeout {
+        loop {
+            let changed = self.changed.notified();
+            tokio::pin!(changed);
+            // Register before reading the state so no change is missed
+            changed.as_mut().enable();
+
+            match self.next_deadline() {
+                Some((deadline, kind)) => {
+                    tokio::select! {
+                        _ = tokio::time::sleep_until(deadline) => {
+                            if self.next_deadline() == Some((deadline, kind)) {
+                                return kind;
+                            }
+                        }
+                        _ = &mut changed => {}
+                    }
+                }
+                None => changed.await,
+            }
+        }
+    }
+}
+
+/// Keeps a request in flight until its response body is done.
+struct InFlight(Arc<ConnectionTracker>);
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        let tracker = &self.0;
+        // Set before the count drops, so the timers never see stale times;
+        // both are unused while other requests are in flight
+        tracker
+            .reading_headers_since
+            .store(NOT_READING, Ordering::Release);
+        tracker.idle_since.store(tracker.now(), Ordering::Release);
+        tracker.in_flight.fetch_sub(1, Ordering::AcqRel);
+        tracker.changed.notify_waiters();
+    }
+}
+
+/// Finds RST_STREAM(REFUSED_STREAM) frames in the bytes an HTTP/2
+/// server writes, however the writes split them.
+#[derive(Debug, Default)]
+struct RefusalScanner {
+    /// The current frame's header, plus the error code of an RST_STREAM
+    head: Vec<u8>,
+    /// Payload bytes of the current frame still to skip
+    skip: usize,
+}
+
+impl RefusalScanner {
+    /// How many bytes of the current frame to collect before skipping.
+    fn wanted(&self) -> usize {
+        match self.head.get(..H2_FRAME_HEADER_LEN) {
+            Some(header) if header[3] == H2_RST_STREAM && frame_len(header) == 4 => {
+                H2_FRAME_HEADER_LEN + 4
+            }
+            _ => H2_FRAME_HEADER_LEN,
+        }
+    }
+
+    /// Scan the next bytes written, returning how many streams they refuse.
+    fn scan(&mut self, mut buf: &[u8]) -> usize {
+        let mut refused = 0;
+        while !buf.is_empty() {
+            if self.skip > 0 {
+                let n = self.skip.min(buf.len());
+                self.skip -= n;
+                buf = &buf[n..];
+                continue;
+            }
+            let n = (self.wanted() - self.head.len()).min(buf.len());
+            self.head.extend_from_slice(&buf[..n]);
+            buf = &buf[n..];
+            if self.head.len() < self.wanted() {
+                continue;
+            }
+            if self.head.len() > H2_FRAME_HEADER_LEN
+                && self.head[H2_FRAME_HEADER_LEN..] == H2_REFUSED_STREAM.to_be_bytes()
+            {
+                refused += 1;
+            }
+            self.skip = frame_len(&self.head) - (self.head.len() - H2_FRAME_HEADER_LEN);
+            self.head.clear();
+        }
+        refused
+    }
+}
+
+/// Payload length from an HTTP/2 frame header.
+fn frame_len(header: &[u8]) -> usize {
+    u32::from_be_bytes([0, header[0], header[1], header[2]]) as usize
+}
+
+/// Client I/O that reports reads to the connection's tracker.
+struct TrackedIo<I> {
+    inner: I,
+    tracker: Arc<ConnectionTracker>,
+    /// Counts refused streams on HTTP/2 connections
+    refusals: Option<RefusalScanner>,
+}
+
+impl<I> TrackedIo<I> {
+    /// Count the streams refused by `written`.
+    fn on_write<'a>(&mut self, written: impl IntoIterator<Item = &'a [u8]>) {
+        let Some(scanner) = &mut self.refusals else {
+            return;
+        };
+        let refused: usize = written.into_iter().map(|buf| scanner.scan(buf)).sum();
+        if refused > 0 {
+            debug!(refused, "Refused HTTP/2 streams over the concurrency limit");
+            if let Some(metrics) = crate::metrics::get_connection_metrics() {
+                metrics.record_streams_refused(refused as u64);
+            }
+        }
+    }
+}
+
+impl<I: AsyncRead + Unpin> AsyncRead for TrackedIo<I> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let before = buf.filled().len();
+        let result = Pin::new(&mut self.inner).poll_read(cx, buf);
+        if matches!(result, Poll::Ready(Ok(()))) && buf.filled().len() > before {
+            self.tracker.on_read();
+        }
+        result
+    }
+}
+
+impl<I: AsyncWrite + Unpin> AsyncWrite for TrackedIo<I> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let result = Pin::new(&mut self.inner).poll_write(cx, buf);
+        if let Poll::Ready(Ok(n)) = result {
+            self.on_write([&buf[..n]]);
+        }
+        result
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let result = Pin::new(&mut self.inner).poll_write_vectored(cx, bufs);
+        if let Poll::Ready(Ok(n)) = result {
+            let mut left = n;
+            self.on_write(bufs.iter().map(|buf| {
+                let take = buf.len().min(left);
+                left -= take;
+                &buf[..take]
+            }));
+        }
+        result
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+}
+
+/// Run `fut`, giving up after `timeout` if one is set.
+async fn within<F: Future>(timeout: Option<Duration>, fut: F) -> Option<F::Output> {
+    match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, fut).await.ok(),
+        None => Some(fut.await),
+    }
+}
+
+// ============================================================================
+// Connection Handling
+// ============================================================================
+
+/// Handle a single connection with HTTP protocol.
+///
+/// With `tls_acceptor` set, TLS is terminated first and the client
+/// certificate (if any) is attached to every request on the connection.
+/// The first bytes and the TLS handshake count against the header timeout.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-001 (TCP_NODELAY enforcement)
+/// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+/// - Implements: REQ-CORE-002 (Conditional Termination - CONNECT rejection)
+/// - Implements: REQ-POL-001/F-006.3 (Client Certificate Identity)
+pub async fn handle_connection<S>(
+    mut stream: TcpStream,
+    tls_acceptor: Option<TlsAcceptor>,
+    service: S,
+    timeouts: ListenerTimeouts,
+    shutdown: CancellationToken,
+) -> Result<(), ProxyError>
+where
+    S: Service<Request<Incoming>, Response = Response<UnifiedBody>, Error = ProxyError>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    let mut tracker = ConnectionTracker::new(timeouts, Instant::now());
+    let peer = stream.peer_addr().ok().map(PeerAddr);
+    let header_deadline = timeouts.header_read.map(|t| Instant::now() + t);
+    let remaining = || header_deadline.map(|d| d.saturating_duration_since(Instant::now()));
+
+    // Peek to detect CONNECT method and reject it immediately
+    // PERF(latency): Zero-copy peek avoids buffering overhead
+    let mut peek_buf = [0u8; H2_PREFACE_START.len()];
+    let Some(peeked) = within(remaining(), stream.peek(&mut peek_buf)).await else {
+        ConnectionTimeout::HeaderRead.record();
+        return Ok(());
+    };
+    if let Ok(n) = peeked {
+        if is_connect_preface(&peek_buf[..n.min(CONNECT_PEEK_LEN)]) {
+            use tokio::io::AsyncWriteExt;
+
+            warn!("Rejected CONNECT request - ThoughtGate is a termination proxy");
+
+            let response = connect_rejection_response();
+            let _ = stream.write_all(response.as_bytes()).await;
+            return Ok(());
+        }
+        tracker.restart_header_timer = !peek_buf[..n].starts_with(H2_PREFACE_START);
+    }
+
+    match tls_acceptor {
+        Some(acceptor) => {
+            let Some(accepted) = within(remaining(), acceptor.accept(stream)).await else {
+                ConnectionTimeout::HeaderRead.record();
+                return Ok(());
+            };
+            let stream = accepted?;
+            let connection = stream.get_ref().1;
+            tracker.restart_header_timer = connection.alpn_protocol() != Some(b"h2");
+            let client_cert = ClientCertificate::from_connection(connection);
+            serve_connection(
+                stream,
+                peer,
+                client_cert,
+                service,
+                Arc::new(tracker),
+                shutdown,
+            )
+            .await
+        }
+        None => serve_connection(stream, peer, None, service, Arc::new(tracker), shutdown).await,
+    }
+}
+
+/// Serve HTTP on an accepted (and possibly TLS-terminated) connection.
+async fn serve_connection<I, S>(
+    io: I,
+    peer: Option<PeerAddr>,
+    client_cert: Option<ClientCertificate>,
+    service: S,
+    tracker: Arc<ConnectionTracker>,
+    shutdown: CancellationToken,
+) -> Result<(), ProxyError>
+where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    S: Service<Request<Incoming>, Response = Response<UnifiedBody>, Error = ProxyError>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    let request_timeout = tracker.timeouts.request;
+    let svc_tracker = tracker.clone();
+    let svc_fn = hyper::service::service_fn(move |mut req: Request<Incoming>| {
+        if let Some(peer) = peer {
+            req.extensions_mut().insert(peer);
+        }
+        if let Some(cert) = &client_cert {
+            req.extensions_mut().insert(cert.clone());
+        }
+        let in_flight = svc_tracker.begin_request();
+        let mut svc = service.clone();
+        async move {
+            // Convert ProxyError to proper HTTP response with correct status codes
+            // Implements: REQ-CORE-001 F-002 (Fail-Fast Error Propagation)
+            // Implements: REQ-CORE-002 F-002 (Fail-Closed State)
+            let result: Result<_, std::convert::Infallible> =
+                match within(request_timeout, svc.call(req)).await {
+                    Some(Ok(response)) => {
+                        // UnifiedBody is BoxBody<Bytes, ProxyError>. Convert to BoxBody<Bytes, Box<dyn Error>>
+                        // for hyper compatibility - hyper requires a boxed error type.
+                        // The request stays in flight until the body is done.
+                        Ok(response.map(|body| {
+                            body.map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
+                                Box::new(io::Error::other(e.to_string()))
+                            })
+                            .map_frame(move |frame| {
+                                let _in_flight = &in_flight;
+                                frame
+                            })
+                            .boxed()
+                        }))
+                    }
+                    Some(Err(e)) => {
+                        error!(error = %e, "Service error");
+                        // Use to_response() to map error to proper HTTP status code
+                        // Full<Bytes> has Infallible error - convert using absurd pattern
+                        Ok(e.to_response()
+                            .map(|body| body.map_err(|e| match e {}).boxed()))
+                    }
+                    None => {
+                        ConnectionTimeout::Request.record();
+                        let timeout = request_timeout.unwrap_or_default();
+                        let mut response =
+                            ProxyError::RequestTimeout(format!("no response within {timeout:?}"))
+                                .to_response();
+                        response.headers_mut().insert(
+                            header::CONNECTION,
+                            header::HeaderValue::from_static("close"),
+                        );
+                        Ok(response.map(|body| body.map_err(|e| match e {}).boxed()))
+                    }
+                };
+            result
+        }
+    });
+
+    let io = TokioIo::new(TrackedIo {
+        inner: io,
+        refusals: (!tracker.restart_header_timer).then(RefusalScanner::default),
+        tracker: tracker.clone(),
+    });
+    let executor = hyper_util::rt::TokioExecutor::new();
+    let mut builder = auto::Builder::new(executor);
+    if let Some(max) = tracker.timeouts.http2_max_concurrent_streams {
+        builder.http2().max_concurrent_streams(max);
+    }
+    let conn = builder.serve_connection_with_upgrades(io, svc_fn);
+
+    tokio::pin!(conn);
+
+    tokio::select! {
+        result = &mut conn => {
+            if let Err(e) = result {
+                error!(error = %e, "Connection error");
+            }
+        }
+        timeout = tracker.expired() => {
+            timeout.record();
+            if timeout == ConnectionTimeout::Idle {
+                // Nothing is in flight, so this only tells HTTP/2 clients why
+                conn.as_mut().graceful_shutdown();
+                let _ = tokio::time::timeout(IDLE_CLOSE_GRACE, conn).await;
+            }
+        }
+        _ = shutdown.cancelled() => {
+            info!("Shutdown signal received, gracefully closing connection");
+            conn.as_mut().graceful_shutdown();
+            let _ = tokio::time::timeout(Duration::from_secs(5), conn).await;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http_body_util::Full;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn timeouts(header_read: u64, idle: u64, request: Option<u64>) -> ListenerTimeouts {
+        ListenerTimeouts {
+            header_read: Some(Duration::from_millis(header_read)),
+            idle: Some(Duration::from_millis(idle)),
+            request: request.map(Duration::from_millis),
+            ..ListenerTimeouts::default()
+        }
+    }
+
+    /// Encode an HTTP/2 frame.
+    fn h2_frame(kind: u8, flags: u8, stream: u32, payload: &[u8]) -> Vec<u8> {
+        let mut frame = (payload.len() as u32).to_be_bytes()[1..].to_vec();
+        frame.extend_from_slice(&[kind, flags]);
+        frame.extend_from_slice(&stream.to_be_bytes());
+        frame.extend_from_slice(payload);
+        frame
+    }
+
+    /// Read one HTTP/2 frame: its type, flags, stream and payload.
+    async fn read_h2_frame(stream: &mut TcpStream) -> (u8, u8, u32, Vec<u8>) {
+        let mut header = [0u8; H2_FRAME_HEADER_LEN];
+        stream.read_exact(&mut header).await.unwrap();
+        let mut payload = vec![0u8; frame_len(&header)];
+        stream.read_exact(&mut payload).await.unwrap();
+        let id = u32::from_be_bytes([header[5], header[6], header[7], header[8]]);
+        (header[3], header[4], id & 0x7fff_ffff, payload)
+    }
+
+    /// Serve one connection with a service that answers after `delay`.
+    async fn serve_one(timeouts: ListenerTimeouts, delay: Duration) -> TcpStream {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let service = tower::service_fn(move |_req: Request<Incoming>| async move {
+                tokio::time::sleep(delay).await;
+                let body = Full::new(Bytes::from_static(b"ok"))
+                    .map_err(|e| match e {})
+                    .boxed();
+                Ok::<_, ProxyError>(Response::new(body))
+            });
+            let _ =
+                handle_connection(stream, None, service, timeouts, CancellationToken::new()).await;
+        });
+        TcpStream::connect(addr).await.unwrap()
+    }
+
+    /// Read until the server closes the connection, returning what was read
+    /// and how long the close took.
+    async fn read_until_closed(stream: &mut TcpStream) -> (String, Duration) {
+        let started = Instant::now();
+        let mut received = Vec::new();
+        tokio::time::timeout(Duration::from_secs(5), stream.read_to_end(&mut received))
+            .await
+            .expect("connection was not closed")
+            .unwrap();
+        (
+            String::from_utf8_lossy(&received).into_owned(),
+            started.elapsed(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_socket_options_applied() {
+        let config = ProxyConfig {
+            tcp_keepalive_secs: 45,
+            tcp_keepalive_interval: Some(Duration::from_secs(7)),
+            tcp_reuseaddr: false,
+            listen_backlog: 16,
+            ..ProxyConfig::default()
+        };
+
+        let listener = bind_listener("127.0.0.1:0", &config).await.unwrap();
+        // The backlog is not readable back from the socket
+        #[cfg(not(windows))]
+        assert!(!socket2::SockRef::from(&listener).reuse_address().unwrap());
+
+        let addr = listener.local_addr().unwrap();
+        let _client = TcpStream::connect(addr).await.unwrap();
+        let (stream, _) = listener.accept().await.unwrap();
+        configure_stream(&stream, &config).unwrap();
+
+        let socket = socket2::SockRef::from(&stream);
+        assert!(socket.tcp_nodelay().unwrap());
+        assert!(socket.keepalive().unwrap());
+        #[cfg(target_os = "linux")]
+        {
+            assert_eq!(
+                socket.tcp_keepalive_time().unwrap(),
+                Duration::from_secs(45)
+            );
+            assert_eq!(
+                socket.tcp_keepalive_interval().unwrap(),
+                Duration::from_secs(7)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_listener_reuses_address() {
+        let listener = bind_listener("127.0.0.1:0", &ProxyConfig::default())
+            .await
+            .unwrap();
+        #[cfg(not(windows))]
+        assert!(socket2::SockRef::from(&listener).reuse_address().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_stalled_before_headers_is_closed() {
+        let mut client = serve_one(timeouts(200, 10_000, None), Duration::ZERO).await;
+
+        let (received, elapsed) = read_until_closed(&mut client).await;
+        assert!(received.is_empty());
+        assert!(elapsed >= Duration::from_millis(150), "{elapsed:?}");
+        assert!(elapsed < Duration::from_secs(2), "{elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn test_slow_headers_are_closed() {
+        let mut client = serve_one(timeouts(300, 10_000, None), Duration::ZERO).await;
+
+        // Trickle the request line, then stall mid-headers
+        client.write_all(b"GET / HTTP/1.1\r\n").await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client.write_all(b"Host: x\r\n").await.unwrap();
+
+        let (received, elapsed) = read_until_closed(&mut client).await;
+        assert!(!received.contains("200 OK"), "{received}");
+        assert!(elapsed < Duration::from_secs(2), "{elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn test_idle_keep_alive_connection_is_closed() {
+        let mut client = serve_one(timeouts(5_000, 300, None), Duration::ZERO).await;
+
+        client
+            .write_all(b"GET / HTTP/1.1\r\nHost: x\r\n\r\n")
+            .await
+            .unwrap();
+        let mut buf = [0u8; 256];
+        let n = client.read(&mut buf).await.unwrap();
+        assert!(String::from_utf8_lossy(&buf[..n]).starts_with("HTTP/1.1 200"));
+
+        // Idle, not stalled mid-headers: the shorter idle timeout applies
+        let (_, elapsed) = read_until_closed(&mut client).await;
+        assert!(elapsed >= Duration::from_millis(250), "{elapsed:?}");
+        assert!(elapsed < Duration::from_secs(2), "{elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn test_slow_response_gets_request_timeout() {
+        let mut client =
+            serve_one(timeouts(5_000, 5_000, Some(200)), Duration::from_secs(10)).await;
+
+        client
+            .write_all(b"GET / HTTP/1.1\r\nHost: x\r\n\r\n")
+            .await
+            .unwrap();
+
+        let (received, elapsed) = read_until_closed(&mut client).await;
+        assert!(received.starts_with("HTTP/1.1 408"), "{received}");
+        assert!(received.to_ascii_lowercase().contains("connection: close"));
+        assert!(elapsed < Duration::from_secs(2), "{elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn test_long_request_is_not_idle() {
+        // The service takes longer than the idle timeout; in flight, it stays open
+        let mut client = serve_one(timeouts(5_000, 100, None), Duration::from_millis(400)).await;
+
+        client
+            .write_all(b"GET / HTTP/1.1\r\nHost: x\r\n\r\n")
+            .await
+            .unwrap();
+        let mut buf = [0u8; 256];
+        let n = client.read(&mut buf).await.unwrap();
+        assert!(String::from_utf8_lossy(&buf[..n]).starts_with("HTTP/1.1 200"));
+    }
+
+    #[test]
+    fn test_refusal_scanner_across_writes() {
+        let mut written = h2_frame(0x4, 0, 0, &[0, 3, 0, 0, 0, 1]);
+        // A DATA payload that looks like a refusal is skipped
+        written.extend(h2_frame(
+            0x0,
+            0,
+            1,
+            &h2_frame(H2_RST_STREAM, 0, 1, &[0, 0, 0, 7]),
+        ));
+        written.extend(h2_frame(H2_RST_STREAM, 0, 3, &[0, 0, 0, 7]));
+        written.extend(h2_frame(H2_RST_STREAM, 0, 5, &[0, 0, 0, 8]));
+        written.extend(h2_frame(0x6, 0, 0, &[0; 8]));
+        written.extend(h2_frame(H2_RST_STREAM, 0, 7, &[0, 0, 0, 7]));
+
+        assert_eq!(RefusalScanner::default().scan(&written), 2);
+
+        let mut scanner = RefusalScanner::default();
+        let refused: usize = written.chunks(1).map(|b| scanner.scan(b)).sum();
+        assert_eq!(refused, 2);
+    }
+
+    #[tokio::test]
+    async fn test_http2_streams_over_limit_are_refused() {
+        let limits = ListenerTimeouts {
+            http2_max_concurrent_streams: Some(1),
+            ..ListenerTimeouts::default()
+        };
+        let mut client = serve_one(limits, Duration::from_secs(10)).await;
+
+        // GET / over http, HPACK-indexed; no END_STREAM keeps streams open
+        let headers = [0x82, 0x86, 0x84];
+        let mut opening = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n".to_vec();
+        opening.extend(h2_frame(0x4, 0, 0, &[]));
+        opening.extend(h2_frame(0x1, 0x4, 1, &headers));
+        opening.extend(h2_frame(0x1, 0x4, 3, &headers));
+        client.write_all(&opening).await.unwrap();
+
+        let mut advertised = None;
+        let refused = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                match read_h2_frame(&mut client).await {
+                    (0x4, 0, 0, payload) => {
+                        advertised = payload
+                            .chunks(6)
+                            .find(|setting| setting[..2] == [0, 3])
+                            .map(|setting| u32::from_be_bytes(setting[2..].try_into().unwrap()));
+                    }
+                    (H2_RST_STREAM, _, id, payload) => break (id, payload),
+                    _ => {}
+                }
+            }
+        })
+        .await
+        .expect("no stream was refused");
+
+        assert_eq!(advertised, Some(1));
+        assert_eq!(refused, (3, H2_REFUSED_STREAM.to_be_bytes().to_vec()));
+    }
+}
@@ -0,0 +1,221 @@
+//! Per-request deadlines.
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+//! - Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+//!
+//! A request gets one overall deadline when it arrives, bounding every
+//! phase it goes through: inspection, the approval wait, the upstream call
+//! and the streamed response. The client can ask for a deadline with
+//! `grpc-timeout` or `X-Deadline`; the configured default caps it, so a
+//! client can shorten its budget but not extend it.
+//!
+//! The deadline is carried in a task-local for the duration of the
+//! request, so the phases that wait can bound themselves by
+//! [`remaining`] without it being threaded through every call.
+
+use std::future::Future;
+use std::time::Duration;
+
+use http::HeaderMap;
+use tokio::time::Instant;
+
+use crate::config::parse_duration;
+
+/// gRPC's request timeout header, e.g. `grpc-timeout: 500m`.
+pub const GRPC_TIMEOUT_HEADER: &str = "grpc-timeout";
+
+/// Request timeout header for other clients: a duration such as `30s` or
+/// `PT30S`, or a bare number of milliseconds.
+pub const DEADLINE_HEADER: &str = "x-deadline";
+
+tokio::task_local! {
+    /// Deadline of the request being handled.
+    static REQUEST_DEADLINE: Deadline;
+}
+
+/// The point in time by which a request must be answered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deadline {
+    at: Instant,
+    budget: Duration,
+}
+
+impl Deadline {
+    /// A deadline `budget` from now.
+    pub fn after(budget: Duration) -> Self {
+        Self {
+            at: Instant::now() + budget,
+            budget,
+        }
+    }
+
+    /// The deadline for a request with `headers`, capped by `default`.
+    ///
+    /// Returns `None` when neither the request nor the configuration sets
+    /// one. Unparseable headers are ignored.
+    pub fn for_request(headers: &HeaderMap, default: Option<Duration>) -> Option<Self> {
+        let requested = headers
+            .get(GRPC_TIMEOUT_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_grpc_timeout)
+            .or_else(|| {
+                headers
+                    .get(DEADLINE_HEADER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(parse_deadline_header)
+            });
+        let budget = match (requested, default) {
+            (Some(requested), Some(default)) => requested.min(default),
+            (requested, default) => requested.or(default)?,
+        };
+        Some(Self::after(budget))
+    }
+
+    /// When the deadline falls.
+    pub fn instant(&self) -> Instant {
+        self.at
+    }
+
+    /// The total budget the request was given.
+    pub fn budget(&self) -> Duration {
+        self.budget
+    }
+
+    /// Time left before the deadline; zero once it has passed.
+    pub fn remaining(&self) -> Duration {
+        self.at.saturating_duration_since(Instant::now())
+    }
+}
+
+/// Run `fut` with `deadline` as the request deadline.
+pub async fn with_deadline<F: Future>(deadline: Deadline, fut: F) -> F::Output {
+    REQUEST_DEADLINE.scope(deadline, fut).await
+}
+
+/// The deadline of the request being handled, if it has one.
+pub fn current() -> Option<Deadline> {
+    REQUEST_DEADLINE.try_with(|d| *d).ok()
+}
+
+/// Time left before the current request's deadline, if it has one.
+pub fn remaining() -> Option<Duration> {
+    current().map(|d| d.remaining())
+}
+
+/// Parse a `grpc-timeout` value: up to 8 digits followed by a unit
+/// (`H`, `M`, `S`, `m`, `u` or `n`).
+pub fn parse_grpc_timeout(value: &str) -> Option<Duration> {
+    let (digits, unit) = value.split_at_checked(value.len().checked_sub(1)?)?;
+    if digits.is_empty() || digits.len() > 8 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let n: u64 = digits.parse().ok()?;
+    Some(match unit {
+        "H" => Duration::from_secs(n * 3600),
+        "M" => Duration::from_secs(n * 60),
+        "S" => Duration::from_secs(n),
+        "m" => Duration::from_millis(n),
+        "u" => Duration::from_micros(n),
+        "n" => Duration::from_nanos(n),
+        _ => return None,
+    })
+}
+
+/// Parse an `X-Deadline` value: a duration (`30s`, `PT30S`) or a bare
+/// number of milliseconds.
+pub fn parse_deadline_header(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+        return value.parse().ok().map(Duration::from_millis);
+    }
+    parse_duration(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    #[test]
+    fn test_parse_grpc_timeout() {
+        assert_eq!(parse_grpc_timeout("2H"), Some(Duration::from_secs(7200)));
+        assert_eq!(parse_grpc_timeout("3M"), Some(Duration::from_secs(180)));
+        assert_eq!(parse_grpc_timeout("10S"), Some(Duration::from_secs(10)));
+        assert_eq!(parse_grpc_timeout("500m"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_grpc_timeout("250u"), Some(Duration::from_micros(250)));
+        assert_eq!(parse_grpc_timeout("99n"), Some(Duration::from_nanos(99)));
+
+        for invalid in ["", "S", "10", "10s", "123456789S", "-1S", "1.5S", "10é"] {
+            assert_eq!(parse_grpc_timeout(invalid), None, "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_deadline_header() {
+        assert_eq!(
+            parse_deadline_header("1500"),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(parse_deadline_header("30s"), Some(Duration::from_secs(30)));
+        assert_eq!(
+            parse_deadline_header("PT2M"),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(parse_deadline_header("soon"), None);
+    }
+
+    #[test]
+    fn test_request_deadline_capped_by_default() {
+        let mut headers = HeaderMap::new();
+        let default = Some(Duration::from_secs(30));
+
+        assert_eq!(Deadline::for_request(&headers, None), None);
+        assert_eq!(
+            Deadline::for_request(&headers, default).map(|d| d.budget()),
+            default
+        );
+
+        headers.insert(DEADLINE_HEADER, HeaderValue::from_static("5s"));
+        assert_eq!(
+            Deadline::for_request(&headers, default).map(|d| d.budget()),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            Deadline::for_request(&headers, None).map(|d| d.budget()),
+            Some(Duration::from_secs(5))
+        );
+
+        // A client cannot extend its budget past the default
+        headers.insert(DEADLINE_HEADER, HeaderValue::from_static("1h"));
+        assert_eq!(
+            Deadline::for_request(&headers, default).map(|d| d.budget()),
+            default
+        );
+
+        // grpc-timeout takes precedence; a malformed header is ignored
+        headers.insert(GRPC_TIMEOUT_HEADER, HeaderValue::from_static("100m"));
+        assert_eq!(
+            Deadline::for_request(&headers, default).map(|d| d.budget()),
+            Some(Duration::from_millis(100))
+        );
+        headers.insert(GRPC_TIMEOUT_HEADER, HeaderValue::from_static("soon"));
+        headers.remove(DEADLINE_HEADER);
+        assert_eq!(
+            Deadline::for_request(&headers, default).map(|d| d.budget()),
+            default
+        );
+    }
+
+    #[tokio::test]
+    async fn test_current_deadline_is_scoped() {
+        assert_eq!(current(), None);
+        let deadline = Deadline::after(Duration::from_secs(60));
+        with_deadline(deadline, async {
+            assert_eq!(current(), Some(deadline));
+            assert!(remaining().unwrap() <= Duration::from_secs(60));
+        })
+        .await;
+        assert_eq!(remaining(), None);
+    }
+}
@@ -1,8 +1,31 @@
 //! JSON-RPC 2.0 error response structures.
 //!
 //! Implements: REQ-CORE-004/§6.2 (JSON-RPC Error Response)
+//!
+//! [`jsonrpc_error`] builds a complete error response envelope and echoes
+//! the request `id` back. [`error_response`] fills it from a
+//! [`ThoughtGateError`], and the named constructors below it build their
+//! error and go through it, so codes come only from
+//! [`ThoughtGateError::to_jsonrpc_code`] (or, for proxy errors,
+//! [`super::status::jsonrpc_code`]), which use the constants here.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{ProxyError, ThoughtGateError};
+use crate::config::DEFAULT_REJECT_MESSAGE;
+
+/// Policy denied the request (REQ-CORE-004/§5.2).
+pub const POLICY_DENIED: i64 = -32003;
+
+/// Request payload exceeds the configured size limit.
+pub const PAYLOAD_TOO_LARGE: i64 = -32004;
+
+/// Approval window expired without a decision (REQ-CORE-004/§5.2).
+pub const APPROVAL_TIMEOUT: i64 = -32008;
+
+/// Upstream MCP server cannot be reached (REQ-CORE-004/EC-ERR-005).
+pub const UPSTREAM_UNAVAILABLE: i64 = -32000;
 
 /// JSON-RPC 2.0 error object.
 ///
@@ -54,12 +77,200 @@ pub struct ErrorData {
     /// Suggested retry delay in seconds (for retriable errors)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retry_after: Option<u64>,
+
+    /// Suggested retry delay in milliseconds (for retriable errors)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after_ms: Option<u64>,
+}
+
+/// Build a JSON-RPC 2.0 error response.
+///
+/// Implements: REQ-CORE-004/F-002 (Error Response Formatting)
+///
+/// Per JSON-RPC 2.0 §5 the `id` member is always present: it echoes the
+/// request id, or is `null` when the id is absent or could not be
+/// determined. Ids that are neither strings nor numbers are not valid
+/// request ids and are also reported as `null`.
+#[must_use]
+pub fn jsonrpc_error(id: Option<Value>, code: i64, message: &str, data: Option<Value>) -> Value {
+    let id = match id {
+        Some(id @ (Value::String(_) | Value::Number(_))) => id,
+        _ => Value::Null,
+    };
+
+    let mut error = serde_json::json!({
+        "code": code,
+        "message": message,
+    });
+    if let Some(data) = data {
+        error["data"] = data;
+    }
+
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": error,
+    })
+}
+
+/// Error response for `error`, echoing `id` back.
+///
+/// Implements: REQ-CORE-004/§6.4 (Error Mapping Implementation)
+#[must_use]
+pub fn error_response(id: Option<Value>, error: &ThoughtGateError, correlation_id: &str) -> Value {
+    let JsonRpcError {
+        code,
+        message,
+        data,
+    } = error.to_jsonrpc_error(correlation_id);
+    let data = data.and_then(|data| serde_json::to_value(data).ok());
+    jsonrpc_error(id, code.into(), &message, data)
+}
+
+/// Error response for a policy denial (-32003).
+///
+/// Policy internals are never included; only the tool name is reported.
+#[must_use]
+pub fn policy_denied(id: Option<Value>, tool: &str, correlation_id: &str) -> Value {
+    let error = ThoughtGateError::PolicyDenied {
+        tool: tool.to_string(),
+        policy_id: None,
+        internal_reason: None,
+        client_message: DEFAULT_REJECT_MESSAGE.to_string(),
+        code: None,
+    };
+    error_response(id, &error, correlation_id)
+}
+
+/// Error response for an approval that expired without a decision (-32008).
+#[must_use]
+pub fn approval_timeout(
+    id: Option<Value>,
+    tool: &str,
+    timeout_secs: u64,
+    correlation_id: &str,
+) -> Value {
+    let error = ThoughtGateError::ApprovalTimeout {
+        tool: tool.to_string(),
+        timeout_secs,
+        workflow: None,
+    };
+    error_response(id, &error, correlation_id)
+}
+
+/// Error response for an unreachable upstream MCP server (-32000).
+///
+/// The upstream URL and failure reason are logged, not returned.
+#[must_use]
+pub fn upstream_unavailable(id: Option<Value>, url: &str, correlation_id: &str) -> Value {
+    let error = ThoughtGateError::UpstreamConnectionFailed {
+        url: url.to_string(),
+        reason: String::new(),
+    };
+    error_response(id, &error, correlation_id)
+}
+
+/// Error response for a request body over the size limit (-32004).
+#[must_use]
+pub fn payload_too_large(id: Option<Value>, size: usize, limit: usize) -> Value {
+    super::status::jsonrpc_body(&ProxyError::PayloadTooLarge(size, limit), id)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_jsonrpc_error_shape() {
+        let response = jsonrpc_error(
+            Some(serde_json::json!(7)),
+            -32603,
+            "Internal error",
+            Some(serde_json::json!({ "error_type": "internal_error" })),
+        );
+
+        assert_eq!(
+            response,
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 7,
+                "error": {
+                    "code": -32603,
+                    "message": "Internal error",
+                    "data": { "error_type": "internal_error" }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_jsonrpc_error_omits_absent_data() {
+        let response = jsonrpc_error(Some(serde_json::json!(1)), -32700, "Parse error", None);
+        assert!(response["error"].get("data").is_none());
+    }
+
+    #[test]
+    fn test_jsonrpc_error_preserves_request_id() {
+        for id in [
+            serde_json::json!(42),
+            serde_json::json!("req-abc"),
+            serde_json::json!(-1.5),
+        ] {
+            let response = policy_denied(Some(id.clone()), "delete_user", "c1");
+            assert_eq!(response["id"], id);
+        }
+    }
+
+    #[test]
+    fn test_jsonrpc_error_null_id() {
+        for id in [
+            None,
+            Some(Value::Null),
+            Some(serde_json::json!({"nested": 1})),
+            Some(serde_json::json!(true)),
+        ] {
+            let response = upstream_unavailable(id, "http://mcp:8080", "c1");
+            // id must be present and null, never omitted
+            assert_eq!(response.get("id"), Some(&Value::Null));
+        }
+    }
+
+    #[test]
+    fn test_named_constructors_match_error_mapping() {
+        let id = Some(serde_json::json!("r1"));
+
+        let denied = policy_denied(id.clone(), "delete_user", "c1");
+        assert_eq!(denied["error"]["code"], -32003);
+        assert_eq!(denied["error"]["data"]["gate"], "policy");
+        assert_eq!(denied["error"]["data"]["tool"], "delete_user");
+        assert_eq!(denied["error"]["data"]["correlation_id"], "c1");
+
+        let timeout = approval_timeout(id.clone(), "delete_user", 300, "c1");
+        assert_eq!(timeout["error"]["code"], -32008);
+        assert_eq!(timeout["error"]["data"]["error_type"], "approval_timeout");
+
+        let upstream = upstream_unavailable(id.clone(), "http://mcp:8080", "c1");
+        assert_eq!(upstream["error"]["code"], -32000);
+        assert!(!upstream.to_string().contains("mcp:8080"));
+
+        let too_large = payload_too_large(id.clone(), 2048, 1024);
+        assert_eq!(too_large["error"]["code"], -32004);
+        assert_eq!(
+            too_large["error"]["data"]["details"],
+            "2048 bytes exceeds limit of 1024 bytes"
+        );
+
+        // Same envelope as the MCP handler builds for the error
+        let error = ThoughtGateError::RateLimited {
+            retry_after_ms: Some(1500),
+        };
+        let response = error_response(id, &error, "c1");
+        assert_eq!(
+            response["error"],
+            serde_json::to_value(error.to_jsonrpc_error("c1")).unwrap()
+        );
+    }
+
     #[test]
     fn test_jsonrpc_error_serialization() {
         let error = JsonRpcError {
@@ -72,6 +283,7 @@ mod tests {
                 details: None, // Security: No policy details
                 error_type: "policy_denied".to_string(),
                 retry_after: None,
+                retry_after_ms: None,
             }),
         };
 
@@ -117,11 +329,13 @@ mod tests {
                 details: Some("Retry after 60s".to_string()),
                 error_type: "rate_limited".to_string(),
                 retry_after: Some(60),
+                retry_after_ms: Some(60_000),
             }),
         };
 
         let json = serde_json::to_value(&error).unwrap();
         assert_eq!(json["data"]["retry_after"], 60);
+        assert_eq!(json["data"]["retry_after_ms"], 60_000);
     }
 
     #[test]
@@ -136,6 +350,7 @@ mod tests {
                 details: None,
                 error_type: "internal_error".to_string(),
                 retry_after: None,
+                retry_after_ms: None,
             }),
         };
 
@@ -160,6 +375,7 @@ mod tests {
                 details: None,
                 error_type: "tool_not_exposed".to_string(),
                 retry_after: None,
+                retry_after_ms: None,
             }),
         };
 
@@ -19,14 +19,25 @@
 //!
 //! - `jsonrpc` - JSON-RPC 2.0 error response structures (REQ-CORE-004)
 //! - `proxy` - HTTP proxy error types (deferred: REQ-CORE-001, REQ-CORE-002)
+//! - `status` - HTTP status mapping for proxy errors (REQ-CORE-001)
+//! - `tls` - Classification of upstream TLS handshake failures
 //! - `ThoughtGateError` - MCP/JSON-RPC error types (REQ-CORE-004)
 
 pub mod jsonrpc;
 pub mod proxy;
+pub mod status;
+pub mod tls;
 
 // Re-export proxy errors for backwards compatibility
+pub use jsonrpc::{
+    approval_timeout, error_response, jsonrpc_error, payload_too_large, policy_denied,
+    upstream_unavailable,
+};
 pub use proxy::{ProxyError, ProxyResult};
+pub use status::{http_status, status_for_jsonrpc_code};
+pub use tls::TlsFailure;
 
+use crate::config::RejectCode;
 use jsonrpc::{ErrorData, JsonRpcError};
 use thiserror::Error;
 
@@ -87,6 +98,20 @@ pub enum ThoughtGateError {
         reason: String,
     },
 
+    /// TLS handshake with the upstream MCP server failed.
+    ///
+    /// Implements: REQ-CORE-004/EC-ERR-005
+    ///
+    /// Reported like a connection failure (-32000), but with its own error
+    /// type so certificate problems can be told apart from outages.
+    #[error("Cannot establish a secure connection to MCP server")]
+    UpstreamTlsFailed {
+        /// The upstream URL whose certificate was rejected
+        url: String,
+        /// Why the handshake failed
+        failure: TlsFailure,
+    },
+
     /// Upstream MCP server did not respond in time.
     ///
     /// Implements: REQ-CORE-004/EC-ERR-006
@@ -149,8 +174,13 @@ pub enum ThoughtGateError {
         tool: String,
         /// The policy ID that denied (for logging, not exposed to client)
         policy_id: Option<String>,
-        /// Optional reason for the denial (does not expose policy internals)
-        reason: Option<String>,
+        /// Detailed reason for logs and the audit trail, never exposed to
+        /// the client
+        internal_reason: Option<String>,
+        /// What the client is told, returned as `data.details`
+        client_message: String,
+        /// Why the policy denied, if known
+        code: Option<RejectCode>,
     },
 
     // Task errors (from REQ-GOV-001) - v0.2+
@@ -188,6 +218,8 @@ pub enum ThoughtGateError {
     TaskResultNotReady {
         /// The task ID whose result is pending
         task_id: String,
+        /// Milliseconds until the approval times out, if known
+        retry_after_ms: Option<u64>,
     },
 
     // ═══════════════════════════════════════════════════════════
@@ -219,6 +251,33 @@ pub enum ThoughtGateError {
         workflow: Option<String>,
     },
 
+    /// The approval request could not be delivered to any approver.
+    ///
+    /// Implements: REQ-CORE-004/§5.2 (-32018)
+    ///
+    /// The request was dead-lettered, so an operator can find it by task ID.
+    #[error("Approval request for tool '{tool}' could not be delivered")]
+    ApprovalUndeliverable {
+        /// The tool awaiting approval
+        tool: String,
+        /// The dead-lettered task
+        task_id: String,
+    },
+
+    /// Too many requests are already awaiting approval.
+    ///
+    /// Implements: REQ-CORE-004/§5.2 (-32019)
+    ///
+    /// The request was shed before a task was created or approvers were
+    /// notified.
+    #[error("Approval backlog full, request for tool '{tool}' not queued")]
+    ApprovalBacklogFull {
+        /// The tool that required approval
+        tool: String,
+        /// Milliseconds until a pending approval is due to expire
+        retry_after_ms: u64,
+    },
+
     /// Approval workflow not found in configuration.
     ///
     /// Implements: REQ-CORE-004/§5.2 (-32017)
@@ -317,10 +376,10 @@ pub enum ThoughtGateError {
     /// Too many requests - rate limit exceeded.
     ///
     /// Implements: REQ-CORE-004/EC-ERR-014
-    #[error("Too many requests. Retry after {retry_after_secs:?} seconds")]
+    #[error("Too many requests. Retry after {retry_after_ms:?} ms")]
     RateLimited {
-        /// Optional seconds to wait before retrying
-        retry_after_secs: Option<u64>,
+        /// Optional milliseconds to wait before retrying
+        retry_after_ms: Option<u64>,
     },
 
     /// Service is temporarily unavailable.
@@ -332,6 +391,18 @@ pub enum ThoughtGateError {
         reason: String,
     },
 
+    /// The request's overall deadline passed before it could be answered.
+    ///
+    /// Implements: REQ-CORE-001 F-005 (Timeout Handling)
+    ///
+    /// Reported with the timeout code (-32001); the error type tells it
+    /// apart from an upstream that was too slow.
+    #[error("Request deadline exceeded")]
+    DeadlineExceeded {
+        /// The budget the request was given, in milliseconds
+        budget_ms: u64,
+    },
+
     /// Internal server error - should not happen.
     ///
     /// Implements: REQ-CORE-004/EC-ERR-019
@@ -348,7 +419,7 @@ impl ThoughtGateError {
     /// Implements: REQ-CORE-004/§5.1, §5.2 (Error Code Mapping)
     ///
     /// Standard JSON-RPC codes (-32700 to -32603) are used for protocol errors.
-    /// ThoughtGate custom codes (-32000 to -32017) are used for application errors.
+    /// ThoughtGate custom codes (-32000 to -32020) are used for application errors.
     pub fn to_jsonrpc_code(&self) -> i32 {
         match self {
             // Standard JSON-RPC codes
@@ -359,12 +430,14 @@ impl ThoughtGateError {
             Self::InternalError { .. } => -32603,
 
             // ThoughtGate custom codes: Upstream (-32000 to -32002)
-            Self::UpstreamConnectionFailed { .. } => -32000,
-            Self::UpstreamTimeout { .. } => -32001,
+            Self::UpstreamConnectionFailed { .. } | Self::UpstreamTlsFailed { .. } => {
+                jsonrpc::UPSTREAM_UNAVAILABLE as i32
+            }
+            Self::UpstreamTimeout { .. } | Self::DeadlineExceeded { .. } => -32001,
             Self::UpstreamError { .. } => -32002,
 
             // ThoughtGate custom codes: Gate 3 - Cedar Policy (-32003)
-            Self::PolicyDenied { .. } => -32003,
+            Self::PolicyDenied { .. } => jsonrpc::POLICY_DENIED as i32,
 
             // ThoughtGate custom codes: Task errors (-32005, -32006, -32020)
             // Note: TaskNotFound uses -32602 (Invalid params) per MCP Tasks spec
@@ -373,10 +446,12 @@ impl ThoughtGateError {
             Self::TaskCancelled { .. } => -32006,
             Self::TaskResultNotReady { .. } => -32020,
 
-            // ThoughtGate custom codes: Gate 4 - Approval (-32007, -32008, -32017)
+            // ThoughtGate custom codes: Gate 4 - Approval (-32007, -32008, -32017 to -32019)
             Self::ApprovalRejected { .. } => -32007,
-            Self::ApprovalTimeout { .. } => -32008,
+            Self::ApprovalTimeout { .. } => jsonrpc::APPROVAL_TIMEOUT as i32,
             Self::WorkflowNotFound { .. } => -32017,
+            Self::ApprovalUndeliverable { .. } => -32018,
+            Self::ApprovalBacklogFull { .. } => -32019,
 
             // ThoughtGate custom codes: Rate limiting (-32009)
             Self::RateLimited { .. } => -32009,
@@ -416,6 +491,7 @@ impl ThoughtGateError {
             Self::MethodNotFound { .. } => "method_not_found",
             Self::InvalidParams { .. } => "invalid_params",
             Self::UpstreamConnectionFailed { .. } => "upstream_connection_failed",
+            Self::UpstreamTlsFailed { .. } => "upstream_tls_failed",
             Self::UpstreamTimeout { .. } => "upstream_timeout",
             Self::UpstreamError { .. } => "upstream_error",
             Self::ToolNotExposed { .. } => "tool_not_exposed",
@@ -428,24 +504,61 @@ impl ThoughtGateError {
             Self::ApprovalRejected { .. } => "approval_rejected",
             Self::ApprovalTimeout { .. } => "approval_timeout",
             Self::WorkflowNotFound { .. } => "workflow_not_found",
+            Self::ApprovalUndeliverable { .. } => "approval_undeliverable",
+            Self::ApprovalBacklogFull { .. } => "approval_backlog_full",
             Self::RateLimited { .. } => "rate_limited",
             Self::InspectionFailed { .. } => "inspection_failed",
             Self::PolicyDrift { .. } => "policy_drift",
             Self::TransformDrift { .. } => "transform_drift",
             Self::ConfigurationError { .. } => "configuration_error",
             Self::ServiceUnavailable { .. } => "service_unavailable",
+            Self::DeadlineExceeded { .. } => "deadline_exceeded",
             Self::InternalError { .. } => "internal_error",
             Self::TaskRequired { .. } => "task_required",
             Self::TaskForbidden { .. } => "task_forbidden",
         }
     }
 
-    /// Returns retry-after hint for retriable errors.
+    /// Machine-readable code for a governance refusal, or `None` for
+    /// other errors.
+    ///
+    /// Cedar denials report their [`RejectCode`] (`forbidden`,
+    /// `default_deny`, ...); other gates report their error type. The code
+    /// is safe to expose: it never carries the internal reason.
+    ///
+    /// Implements: REQ-OBS-002 (Audit Trail)
+    pub fn reject_code(&self) -> Option<&'static str> {
+        match self {
+            Self::PolicyDenied {
+                code: Some(code), ..
+            } => Some(code.as_str()),
+            Self::ToolNotExposed { .. }
+            | Self::GovernanceRuleDenied { .. }
+            | Self::PolicyDenied { .. }
+            | Self::ApprovalRejected { .. }
+            | Self::ApprovalTimeout { .. } => Some(self.error_type_name()),
+            _ => None,
+        }
+    }
+
+    /// Returns retry-after hint in whole seconds (rounded up) for retriable errors.
     ///
     /// Implements: REQ-CORE-004/F-003.4 (Retry Guidance)
     pub fn retry_after(&self) -> Option<u64> {
+        self.retry_after_ms().map(|ms| ms.div_ceil(1000))
+    }
+
+    /// Returns retry-after hint in milliseconds for retriable errors.
+    ///
+    /// Set for rate limiting, a full approval backlog, and task results
+    /// still awaiting approval.
+    ///
+    /// Implements: REQ-CORE-004/F-003.4 (Retry Guidance)
+    pub fn retry_after_ms(&self) -> Option<u64> {
         match self {
-            Self::RateLimited { retry_after_secs } => *retry_after_secs,
+            Self::RateLimited { retry_after_ms }
+            | Self::TaskResultNotReady { retry_after_ms, .. } => *retry_after_ms,
+            Self::ApprovalBacklogFull { retry_after_ms, .. } => Some(*retry_after_ms),
             _ => None,
         }
     }
@@ -467,7 +580,9 @@ impl ThoughtGateError {
             // Gate 4: Approval
             Self::ApprovalRejected { .. }
             | Self::ApprovalTimeout { .. }
-            | Self::WorkflowNotFound { .. } => Some("approval"),
+            | Self::WorkflowNotFound { .. }
+            | Self::ApprovalUndeliverable { .. }
+            | Self::ApprovalBacklogFull { .. } => Some("approval"),
 
             // Non-gate errors
             _ => None,
@@ -484,6 +599,8 @@ impl ThoughtGateError {
             | Self::PolicyDenied { tool, .. }
             | Self::ApprovalRejected { tool, .. }
             | Self::ApprovalTimeout { tool, .. }
+            | Self::ApprovalUndeliverable { tool, .. }
+            | Self::ApprovalBacklogFull { tool, .. }
             | Self::TaskRequired { tool, .. }
             | Self::TaskForbidden { tool, .. } => Some(tool),
             _ => None,
@@ -504,8 +621,10 @@ impl ThoughtGateError {
                 rule.as_ref().map(|r| format!("Matched rule: {}", r))
             }
 
-            // Gate 3: Policy - No details (security: don't expose policy internals)
-            Self::PolicyDenied { .. } => None,
+            // Gate 3: Policy - Only the client message, which is generic
+            // unless the operator opted the denial in (don't expose policy
+            // internals)
+            Self::PolicyDenied { client_message, .. } => Some(client_message.clone()),
 
             // Gate 4: Approval
             Self::ApprovalRejected { rejected_by, .. } => rejected_by
@@ -517,9 +636,16 @@ impl ThoughtGateError {
             Self::WorkflowNotFound { workflow } => {
                 Some(format!("Check approval.{} in config", workflow))
             }
+            Self::ApprovalUndeliverable { task_id, .. } => {
+                Some(format!("Approval channel unavailable, task {}", task_id))
+            }
+            Self::ApprovalBacklogFull { .. } => self
+                .retry_after()
+                .map(|s| format!("Too many requests awaiting approval, retry after {}s", s)),
 
             // Upstream errors
             Self::UpstreamConnectionFailed { .. } => None, // Don't expose internal URLs
+            Self::UpstreamTlsFailed { .. } => None,        // Certificate details stay in the logs
             Self::UpstreamTimeout { timeout_secs, .. } => {
                 Some(format!("Timeout after {}s", timeout_secs))
             }
@@ -532,7 +658,7 @@ impl ThoughtGateError {
             Self::TaskNotFound { .. } => None,
             Self::TaskExpired { task_id, .. } => Some(format!("Task {} expired", task_id)),
             Self::TaskCancelled { .. } => None,
-            Self::TaskResultNotReady { task_id } => {
+            Self::TaskResultNotReady { task_id, .. } => {
                 Some(format!("Task {} result pending", task_id))
             }
 
@@ -548,10 +674,9 @@ impl ThoughtGateError {
             Self::ConfigurationError { details } => Some(details.clone()),
 
             // Operational errors
-            Self::RateLimited { retry_after_secs } => {
-                retry_after_secs.map(|s| format!("Retry after {}s", s))
-            }
+            Self::RateLimited { .. } => self.retry_after().map(|s| format!("Retry after {}s", s)),
             Self::ServiceUnavailable { reason } => Some(reason.clone()),
+            Self::DeadlineExceeded { budget_ms } => Some(format!("Deadline of {}ms", budget_ms)),
 
             // Protocol errors
             Self::ParseError { details } => Some(details.clone()),
@@ -583,6 +708,7 @@ impl ThoughtGateError {
                 details: self.safe_details(),
                 error_type: self.error_type_name().to_string(),
                 retry_after: self.retry_after(),
+                retry_after_ms: self.retry_after_ms(),
             }),
         }
     }
@@ -591,6 +717,7 @@ impl ThoughtGateError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::DEFAULT_REJECT_MESSAGE;
 
     /// Tests error code mapping for all error types.
     ///
@@ -663,7 +790,9 @@ mod tests {
             ThoughtGateError::PolicyDenied {
                 tool: "test".to_string(),
                 policy_id: None,
-                reason: None
+                internal_reason: None,
+                client_message: DEFAULT_REJECT_MESSAGE.to_string(),
+                code: None,
             }
             .to_jsonrpc_code(),
             -32003
@@ -709,7 +838,7 @@ mod tests {
         );
         assert_eq!(
             ThoughtGateError::RateLimited {
-                retry_after_secs: Some(60)
+                retry_after_ms: Some(60_000)
             }
             .to_jsonrpc_code(),
             -32009
@@ -761,7 +890,9 @@ mod tests {
             ThoughtGateError::PolicyDenied {
                 tool: "test".to_string(),
                 policy_id: None,
-                reason: None
+                internal_reason: None,
+                client_message: DEFAULT_REJECT_MESSAGE.to_string(),
+                code: None,
             }
             .error_type_name(),
             "policy_denied"
@@ -775,25 +906,67 @@ mod tests {
         );
     }
 
-    /// Tests that retry_after is only set for rate limit errors.
+    /// Tests that upstream TLS failures are reported without upstream details.
+    ///
+    /// Verifies: REQ-CORE-004/EC-ERR-005, REQ-CORE-004/NFR-002
+    #[test]
+    fn test_upstream_tls_failed_is_safe() {
+        let error = ThoughtGateError::UpstreamTlsFailed {
+            url: "https://billing.internal:8443".to_string(),
+            failure: TlsFailure::CertificateExpired,
+        };
+        let jsonrpc = error.to_jsonrpc_error("test");
+
+        assert_eq!(jsonrpc.code, -32000);
+        assert!(!jsonrpc.message.contains("billing"));
+        let data = jsonrpc.data.unwrap();
+        assert_eq!(data.error_type, "upstream_tls_failed");
+        assert_eq!(data.details, None);
+    }
+
+    /// Tests that an exceeded deadline is a timeout distinct from upstream's.
+    ///
+    /// Verifies: REQ-CORE-001 F-005
+    #[test]
+    fn test_deadline_exceeded_mapping() {
+        let error = ThoughtGateError::DeadlineExceeded { budget_ms: 250 };
+        let jsonrpc = error.to_jsonrpc_error("test");
+
+        assert_eq!(jsonrpc.code, -32001);
+        let data = jsonrpc.data.unwrap();
+        assert_eq!(data.error_type, "deadline_exceeded");
+        assert_eq!(data.details, Some("Deadline of 250ms".to_string()));
+    }
+
+    /// Tests that retry_after is only set for rate limit and pending-result errors.
     ///
     /// Verifies: REQ-CORE-004/F-003.4
     #[test]
     fn test_retry_after() {
         assert_eq!(
             ThoughtGateError::RateLimited {
-                retry_after_secs: Some(60)
+                retry_after_ms: Some(60_000)
             }
             .retry_after(),
             Some(60)
         );
         assert_eq!(
             ThoughtGateError::RateLimited {
-                retry_after_secs: None
+                retry_after_ms: None
             }
             .retry_after(),
             None
         );
+        // Partial seconds round up so clients never retry early
+        let pending = ThoughtGateError::TaskResultNotReady {
+            task_id: "test".to_string(),
+            retry_after_ms: Some(1_500),
+        };
+        assert_eq!(pending.retry_after(), Some(2));
+        assert_eq!(pending.retry_after_ms(), Some(1_500));
+        let data = pending.to_jsonrpc_error("test").data.unwrap();
+        assert_eq!(data.retry_after, Some(2));
+        assert_eq!(data.retry_after_ms, Some(1_500));
         assert_eq!(
             ThoughtGateError::InternalError {
                 correlation_id: "test".to_string()
@@ -843,11 +1016,13 @@ mod tests {
         let err = ThoughtGateError::PolicyDenied {
             tool: "delete_user".to_string(),
             policy_id: Some("secret_policy".to_string()),
-            reason: Some("Internal rule matched".to_string()),
+            internal_reason: Some("Internal rule matched".to_string()),
+            client_message: DEFAULT_REJECT_MESSAGE.to_string(),
+            code: None,
         };
         let details = err.safe_details();
-        // Security: No details for policy denied
-        assert!(details.is_none());
+        // Security: only the generic message for policy denied
+        assert_eq!(details.as_deref(), Some("Request not permitted"));
     }
 
     /// Tests error message generation follows templates.
@@ -867,7 +1042,9 @@ mod tests {
             ThoughtGateError::PolicyDenied {
                 tool: "delete_user".to_string(),
                 policy_id: None,
-                reason: None
+                internal_reason: None,
+                client_message: DEFAULT_REJECT_MESSAGE.to_string(),
+                code: None,
             }
             .to_string(),
             "Policy denied access to tool 'delete_user'"
@@ -892,7 +1069,9 @@ mod tests {
         let err = ThoughtGateError::PolicyDenied {
             tool: "delete_user".to_string(),
             policy_id: Some("finance_policy".to_string()),
-            reason: Some("Admin approval required".to_string()),
+            internal_reason: Some("Admin approval required".to_string()),
+            client_message: DEFAULT_REJECT_MESSAGE.to_string(),
+            code: None,
         };
 
         let correlation_id = "550e8400-e29b-41d4-a716-446655440000";
@@ -904,13 +1083,16 @@ mod tests {
             "Policy denied access to tool 'delete_user'"
         );
 
-        let data = jsonrpc_err.data.unwrap();
+        let data = jsonrpc_err.data.clone().unwrap();
         assert_eq!(data.correlation_id, correlation_id);
         assert_eq!(data.error_type, "policy_denied");
         assert_eq!(data.gate, Some("policy".to_string()));
         assert_eq!(data.tool, Some("delete_user".to_string()));
-        // Security: PolicyDenied should NOT expose details
-        assert!(data.details.is_none());
+        // Security: PolicyDenied should NOT expose the internal reason
+        assert_eq!(data.details.as_deref(), Some("Request not permitted"));
+        let json = serde_json::to_string(&jsonrpc_err).unwrap();
+        assert!(!json.contains("Admin approval required"), "{json}");
+        assert!(!json.contains("finance_policy"), "{json}");
         assert_eq!(data.retry_after, None);
     }
 
@@ -971,7 +1153,9 @@ mod tests {
         let err = ThoughtGateError::PolicyDenied {
             tool: "transfer_funds".to_string(),
             policy_id: Some("finance".to_string()),
-            reason: Some("Amount exceeds limit".to_string()),
+            internal_reason: Some("Amount exceeds limit".to_string()),
+            client_message: DEFAULT_REJECT_MESSAGE.to_string(),
+            code: None,
         };
 
         assert_eq!(err.to_jsonrpc_code(), -32003);
@@ -983,7 +1167,7 @@ mod tests {
         assert_eq!(data.gate, Some("policy".to_string()));
         assert_eq!(data.tool, Some("transfer_funds".to_string()));
         // Security: No policy details exposed
-        assert!(data.details.is_none());
+        assert_eq!(data.details.as_deref(), Some(DEFAULT_REJECT_MESSAGE));
     }
 
     /// Tests Gate 4 (Approval) rejected error format.
@@ -1032,6 +1216,58 @@ mod tests {
         assert_eq!(data.details, Some("Timeout after 300s".to_string()));
     }
 
+    /// Tests undeliverable approval error format.
+    ///
+    /// Verifies: REQ-CORE-004/§5.2 (-32018)
+    #[test]
+    fn test_approval_undeliverable_format() {
+        let err = ThoughtGateError::ApprovalUndeliverable {
+            tool: "deploy_prod".to_string(),
+            task_id: "tg_abc".to_string(),
+        };
+
+        assert_eq!(err.to_jsonrpc_code(), -32018);
+        assert_eq!(err.error_type_name(), "approval_undeliverable");
+        assert_eq!(err.gate(), Some("approval"));
+        assert_eq!(err.tool(), Some("deploy_prod"));
+        assert_eq!(
+            status::status_for_jsonrpc_code(-32018),
+            hyper::StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        let data = err.to_jsonrpc_error("test-id").data.unwrap();
+        assert_eq!(
+            data.details,
+            Some("Approval channel unavailable, task tg_abc".to_string())
+        );
+    }
+
+    /// Tests approval backlog error format.
+    ///
+    /// Verifies: REQ-CORE-004/§5.2 (-32019)
+    #[test]
+    fn test_approval_backlog_full_format() {
+        let err = ThoughtGateError::ApprovalBacklogFull {
+            tool: "deploy_prod".to_string(),
+            retry_after_ms: 90_500,
+        };
+
+        assert_eq!(err.to_jsonrpc_code(), -32019);
+        assert_eq!(err.error_type_name(), "approval_backlog_full");
+        assert_eq!(err.gate(), Some("approval"));
+        assert_eq!(err.retry_after(), Some(91));
+        assert_eq!(
+            status::status_for_jsonrpc_code(-32019),
+            hyper::StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        let data = err.to_jsonrpc_error("test-id").data.unwrap();
+        assert_eq!(
+            data.details,
+            Some("Too many requests awaiting approval, retry after 91s".to_string())
+        );
+    }
+
     /// Tests workflow not found error format.
     ///
     /// Verifies: REQ-CORE-004/§9.1 test_workflow_not_found_format
@@ -1126,7 +1362,9 @@ mod tests {
                 ThoughtGateError::PolicyDenied {
                     tool: "t".to_string(),
                     policy_id: None,
-                    reason: None,
+                    internal_reason: None,
+                    client_message: DEFAULT_REJECT_MESSAGE.to_string(),
+                    code: None,
                 },
                 "policy",
             ),
@@ -1157,7 +1395,7 @@ mod tests {
                 task_id: "test".to_string(),
             },
             ThoughtGateError::RateLimited {
-                retry_after_secs: None,
+                retry_after_ms: None,
             },
         ];
 
@@ -1251,4 +1489,29 @@ mod tests {
         assert_eq!(jsonrpc_err.code, -32601);
         assert!(jsonrpc_err.message.contains("simple_tool"));
     }
+
+    /// Tests reject codes name the gate without exposing the reason.
+    #[test]
+    fn test_reject_code() {
+        let cedar = ThoughtGateError::PolicyDenied {
+            tool: "delete_user".to_string(),
+            policy_id: None,
+            internal_reason: Some("internal".to_string()),
+            client_message: DEFAULT_REJECT_MESSAGE.to_string(),
+            code: Some(RejectCode::Forbidden),
+        };
+        assert_eq!(cedar.reject_code(), Some("forbidden"));
+
+        let rule = ThoughtGateError::GovernanceRuleDenied {
+            tool: "delete_user".to_string(),
+            rule: Some("delete_*".to_string()),
+        };
+        assert_eq!(rule.reject_code(), Some("governance_rule_denied"));
+
+        let upstream = ThoughtGateError::UpstreamTimeout {
+            url: "http://mcp".to_string(),
+            timeout_secs: 30,
+        };
+        assert_eq!(upstream.reject_code(), None);
+    }
 }
@@ -18,6 +18,8 @@ use http_body_util::Full;
 use hyper::{Response, StatusCode};
 use thiserror::Error;
 
+use super::TlsFailure;
+
 /// Errors that can occur during HTTP proxy operations.
 ///
 /// This enum uses `thiserror` to provide structured error types that preserve
@@ -61,10 +63,29 @@ pub enum ProxyError {
     #[error("Connection refused: {0}")]
     ConnectionRefused(String),
 
+    /// TLS handshake with the upstream failed (maps to 502 Bad Gateway)
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-004/EC-ERR-005 (Upstream Connection Failure)
+    #[error("TLS handshake with {upstream} failed: {failure}")]
+    Tls {
+        /// Upstream scheme and authority
+        upstream: String,
+        /// Why the handshake failed
+        failure: TlsFailure,
+    },
+
     /// Timeout error (maps to 504 Gateway Timeout)
     #[error("Timeout: {0}")]
     Timeout(String),
 
+    /// The request's overall deadline passed (maps to 504 Gateway Timeout)
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+    #[error("Request deadline of {0:?} exceeded")]
+    DeadlineExceeded(std::time::Duration),
+
     /// Client disconnected (should close upstream immediately)
     #[error("Client disconnected")]
     ClientDisconnect,
@@ -77,6 +98,21 @@ pub enum ProxyError {
     #[error("Client error: {0}")]
     Client(String),
 
+    /// Request headers exceed a configured limit (maps to 431 Request
+    /// Header Fields Too Large)
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 Section 3.2 (Header Limits)
+    #[error("Request headers too large: {0}")]
+    HeadersTooLarge(String),
+
+    /// Too many requests in flight; the request was shed (maps to 503)
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 Section 3.2 (Concurrency Limit)
+    #[error("Service unavailable: too many requests in flight ({0} limit)")]
+    Overloaded(String),
+
     // ─────────────────────────────────────────────────────────────────────────
     // Inspection Errors - DEFERRED TO v0.2+ (REQ-CORE-002)
     // These errors are retained for when Amber Path inspection is enabled.
@@ -102,6 +138,14 @@ pub enum ProxyError {
     #[error("Service unavailable: too many concurrent buffered requests")]
     BufferSemaphoreExhausted,
 
+    /// Global buffer budget exhausted - admitting the request would exceed
+    /// the total bytes allowed across all in-flight buffers (maps to 503)
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-002 Section 3.2 (Memory Management)
+    #[error("Service unavailable: buffer budget exhausted")]
+    BufferBudgetExhausted,
+
     /// Compressed response detected in Amber Path (maps to 502 Bad Gateway)
     ///
     /// # Traceability
@@ -137,100 +181,23 @@ impl ProxyError {
     /// - Implements: REQ-CORE-001 F-002 (Fail-Fast Error Propagation)
     /// - Implements: REQ-CORE-002 F-002 (Fail-Closed State)
     ///
-    /// # Error Mapping (Green Path - REQ-CORE-001)
-    /// - `ConnectionRefused` / `Connection` -> 502 Bad Gateway
-    /// - `Timeout` -> 504 Gateway Timeout
-    /// - `RequestTimeout` -> 408 Request Timeout
-    /// - `InvalidUri` -> 400 Bad Request
-    ///
-    /// # Error Mapping (Amber Path - REQ-CORE-002)
-    /// - `PayloadTooLarge` -> 413 Payload Too Large
-    /// - `BufferTimeout` -> 408 Request Timeout
-    /// - `BufferSemaphoreExhausted` -> 503 Service Unavailable
-    /// - `CompressedResponse` -> 502 Bad Gateway
-    /// - `Rejected` -> Status code from Decision
-    /// - `InspectorPanic` / `InspectorError` -> 500 Internal Server Error
-    ///
-    /// # Others
-    /// - Fallback -> 500 Internal Server Error
+    /// The status comes from [`http_status`](super::status::http_status);
+    /// see [`super::status`] for the full mapping. The body is a JSON-RPC
+    /// error with a `null` id, since the proxy layer fails before a request
+    /// id is known. A `Retry-After` header is added for errors that carry
+    /// one.
     pub fn to_response(&self) -> Response<Full<Bytes>> {
-        let (status, message) = match self {
-            // Green Path errors
-            ProxyError::ConnectionRefused(_) | ProxyError::Connection(_) => (
-                StatusCode::BAD_GATEWAY,
-                "502 Bad Gateway\n\nFailed to connect to upstream server.",
-            ),
-            ProxyError::Timeout(_) => (
-                StatusCode::GATEWAY_TIMEOUT,
-                "504 Gateway Timeout\n\nUpstream server did not respond in time.",
-            ),
-            ProxyError::RequestTimeout(_) => (
-                StatusCode::REQUEST_TIMEOUT,
-                "408 Request Timeout\n\nRequest took too long to complete.",
-            ),
-            ProxyError::InvalidUri(_) => (
-                StatusCode::BAD_REQUEST,
-                "400 Bad Request\n\nInvalid request URI.",
-            ),
-            ProxyError::ClientDisconnect => {
-                // Client has disconnected - return 400 for consistency, though
-                // in practice this response won't be sent since the client is gone
-                (
-                    StatusCode::BAD_REQUEST,
-                    "400 Bad Request\n\nClient disconnected.",
-                )
-            }
-
-            // Amber Path errors (REQ-CORE-002)
-            ProxyError::PayloadTooLarge(_, _) => (
-                StatusCode::PAYLOAD_TOO_LARGE,
-                "413 Payload Too Large\n\nRequest body exceeds maximum allowed size.",
-            ),
-            ProxyError::BufferTimeout(_) => (
-                StatusCode::REQUEST_TIMEOUT,
-                "408 Request Timeout\n\nBuffering operation timed out.",
-            ),
-            ProxyError::BufferSemaphoreExhausted => (
-                StatusCode::SERVICE_UNAVAILABLE,
-                "503 Service Unavailable\n\nToo many concurrent requests. Please retry later.",
-            ),
-            ProxyError::CompressedResponse(_) => (
-                StatusCode::BAD_GATEWAY,
-                "502 Bad Gateway\n\nUpstream returned compressed response which cannot be inspected.",
-            ),
-            ProxyError::Rejected(_, status) => {
-                // Use the status code from the rejection decision
-                return Response::builder()
-                    .status(*status)
-                    .header("Content-Type", "text/plain")
-                    .body(Full::new(Bytes::from(format!(
-                        "{} {}\n\nRequest rejected by policy.",
-                        status.as_u16(),
-                        status.canonical_reason().unwrap_or("Unknown")
-                    ))))
-                    .unwrap_or_else(|_| {
-                        Response::builder()
-                            .status(*status)
-                            .body(Full::new(Bytes::from("Request rejected")))
-                            .unwrap()
-                    });
-            }
-            ProxyError::InspectorPanic(_) | ProxyError::InspectorError(_, _) => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "500 Internal Server Error\n\nInspection failed.",
-            ),
-
-            // Fallback
-            _ => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "500 Internal Server Error\n\nAn internal error occurred.",
-            ),
-        };
+        let status = super::status::http_status(self);
+        let body = super::status::jsonrpc_body(self, None);
 
-        Response::builder()
+        let mut builder = Response::builder()
             .status(status)
-            .header("Content-Type", "text/plain")
-            .body(Full::new(Bytes::from(message)))
+            .header("Content-Type", "application/json");
+        if let Some(secs) = self.retry_after() {
+            builder = builder.header("Retry-After", secs);
+        }
+        builder
+            .body(Full::new(Bytes::from(body.to_string())))
             .unwrap_or_else(|_| {
                 Response::builder()
                     .status(StatusCode::INTERNAL_SERVER_ERROR)
@@ -239,12 +206,22 @@ impl ProxyError {
             })
     }
 
+    /// Seconds the client should wait before retrying, for errors that are
+    /// expected to clear on their own.
+    pub fn retry_after(&self) -> Option<u64> {
+        match self {
+            ProxyError::Overloaded(_) => Some(crate::admission::OVERLOAD_RETRY_AFTER_SECS),
+            _ => None,
+        }
+    }
+
     /// Check if this error indicates an upstream connection failure.
     pub fn is_upstream_error(&self) -> bool {
         matches!(
             self,
             ProxyError::ConnectionRefused(_)
                 | ProxyError::Connection(_)
+                | ProxyError::Tls { .. }
                 | ProxyError::Timeout(_)
                 | ProxyError::CompressedResponse(_)
         )
@@ -257,6 +234,7 @@ impl ProxyError {
             ProxyError::InvalidUri(_)
                 | ProxyError::ClientDisconnect
                 | ProxyError::RequestTimeout(_)
+                | ProxyError::HeadersTooLarge(_)
                 | ProxyError::PayloadTooLarge(_, _)
                 | ProxyError::BufferTimeout(_)
         )
@@ -269,6 +247,7 @@ impl ProxyError {
             ProxyError::PayloadTooLarge(_, _)
                 | ProxyError::BufferTimeout(_)
                 | ProxyError::BufferSemaphoreExhausted
+                | ProxyError::BufferBudgetExhausted
                 | ProxyError::CompressedResponse(_)
                 | ProxyError::Rejected(_, _)
                 | ProxyError::InspectorPanic(_)
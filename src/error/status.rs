@@ -0,0 +1,340 @@
+//! HTTP status mapping for proxy errors.
+//!
+//! Implements: REQ-CORE-001 F-002 (Fail-Fast Error Propagation)
+//! Implements: REQ-CORE-002 F-002 (Fail-Closed State)
+//!
+//! Single source of truth for the HTTP status and JSON-RPC error code a
+//! [`ProxyError`] is reported with. Every variant is mapped explicitly;
+//! there is no catch-all, so adding a variant fails to compile until it
+//! is given a status here.
+//!
+//! | Error | HTTP status | JSON-RPC code |
+//! |-------|-------------|---------------|
+//! | `Connection`, `ConnectionRefused`, `Io`, `Client`, `Tls` | 502 | -32000 |
+//! | `Http`, `CompressedResponse` | 502 | -32002 |
+//! | `Timeout`, `DeadlineExceeded` | 504 | -32001 |
+//! | `RequestTimeout`, `BufferTimeout` | 408 | -32600 |
+//! | `InvalidUri`, `ClientDisconnect` | 400 | -32600 |
+//! | `PayloadTooLarge` | 413 | -32004 |
+//! | `HeadersTooLarge` | 431 | -32600 |
+//! | `BufferSemaphoreExhausted`, `BufferBudgetExhausted`, `Overloaded` | 503 | -32013 |
+//! | `Rejected` | status from the decision (403 by convention) | -32003 |
+//! | `InspectorPanic`, `InspectorError` | 500 | -32010 |
+//!
+//! Inspection failures are the only errors reported as 500: they are
+//! internal faults and the request fails closed.
+//!
+//! [`status_for_jsonrpc_code`] maps the other way, from a JSON-RPC error
+//! code to the HTTP status used when an error is surfaced over plain HTTP
+//! (policy denial 403, approval timeout 408, upstream down 502/503).
+
+use hyper::StatusCode;
+use serde_json::Value;
+
+use super::ProxyError;
+use super::jsonrpc::{self, APPROVAL_TIMEOUT, PAYLOAD_TOO_LARGE, POLICY_DENIED};
+
+/// HTTP status for a proxy error.
+///
+/// Implements: REQ-CORE-001 F-002
+#[must_use]
+pub fn http_status(error: &ProxyError) -> StatusCode {
+    match error {
+        ProxyError::Http(_)
+        | ProxyError::Io(_)
+        | ProxyError::Connection(_)
+        | ProxyError::ConnectionRefused(_)
+        | ProxyError::Client(_)
+        | ProxyError::Tls { .. }
+        | ProxyError::CompressedResponse(_) => StatusCode::BAD_GATEWAY,
+        ProxyError::Timeout(_) | ProxyError::DeadlineExceeded(_) => StatusCode::GATEWAY_TIMEOUT,
+        ProxyError::RequestTimeout(_) | ProxyError::BufferTimeout(_) => StatusCode::REQUEST_TIMEOUT,
+        ProxyError::InvalidUri(_) | ProxyError::ClientDisconnect => StatusCode::BAD_REQUEST,
+        ProxyError::PayloadTooLarge(_, _) => StatusCode::PAYLOAD_TOO_LARGE,
+        ProxyError::HeadersTooLarge(_) => StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE,
+        ProxyError::BufferSemaphoreExhausted
+        | ProxyError::BufferBudgetExhausted
+        | ProxyError::Overloaded(_) => StatusCode::SERVICE_UNAVAILABLE,
+        ProxyError::Rejected(_, status) => *status,
+        ProxyError::InspectorPanic(_) | ProxyError::InspectorError(_, _) => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+/// JSON-RPC error code for a proxy error.
+///
+/// Implements: REQ-CORE-004/§5.2
+#[must_use]
+pub fn jsonrpc_code(error: &ProxyError) -> i64 {
+    match error {
+        ProxyError::Io(_)
+        | ProxyError::Connection(_)
+        | ProxyError::ConnectionRefused(_)
+        | ProxyError::Client(_)
+        | ProxyError::Tls { .. } => jsonrpc::UPSTREAM_UNAVAILABLE,
+        ProxyError::Http(_) | ProxyError::CompressedResponse(_) => -32002,
+        ProxyError::Timeout(_) | ProxyError::DeadlineExceeded(_) => -32001,
+        ProxyError::RequestTimeout(_)
+        | ProxyError::BufferTimeout(_)
+        | ProxyError::InvalidUri(_)
+        | ProxyError::HeadersTooLarge(_)
+        | ProxyError::ClientDisconnect => -32600,
+        ProxyError::PayloadTooLarge(_, _) => PAYLOAD_TOO_LARGE,
+        ProxyError::BufferSemaphoreExhausted
+        | ProxyError::BufferBudgetExhausted
+        | ProxyError::Overloaded(_) => -32013,
+        ProxyError::Rejected(_, _) => POLICY_DENIED,
+        ProxyError::InspectorPanic(_) | ProxyError::InspectorError(_, _) => -32010,
+    }
+}
+
+/// JSON-RPC error body for a proxy error.
+///
+/// The message is a fixed description per variant; upstream addresses,
+/// I/O details and inspector names stay in the logs.
+#[must_use]
+pub fn jsonrpc_body(error: &ProxyError, id: Option<Value>) -> Value {
+    let message = match error {
+        ProxyError::Http(_) | ProxyError::CompressedResponse(_) => {
+            "Upstream returned an invalid response"
+        }
+        ProxyError::Io(_)
+        | ProxyError::Connection(_)
+        | ProxyError::ConnectionRefused(_)
+        | ProxyError::Client(_) => "Cannot connect to MCP server",
+        ProxyError::Tls { .. } => "Cannot establish a secure connection to MCP server",
+        ProxyError::Timeout(_) => "MCP server did not respond in time",
+        ProxyError::DeadlineExceeded(_) => "Request deadline exceeded",
+        ProxyError::RequestTimeout(_) | ProxyError::BufferTimeout(_) => {
+            "Request took too long to complete"
+        }
+        ProxyError::InvalidUri(_) => "Invalid request URI",
+        ProxyError::ClientDisconnect => "Client disconnected",
+        ProxyError::PayloadTooLarge(_, _) => "Payload too large",
+        ProxyError::HeadersTooLarge(_) => "Request headers too large",
+        ProxyError::BufferSemaphoreExhausted
+        | ProxyError::BufferBudgetExhausted
+        | ProxyError::Overloaded(_) => "Too many concurrent requests",
+        ProxyError::Rejected(_, _) => "Request rejected by policy",
+        ProxyError::InspectorPanic(_) | ProxyError::InspectorError(_, _) => "Inspection failed",
+    };
+    let data = match error {
+        ProxyError::PayloadTooLarge(size, limit) => Some(serde_json::json!({
+            "details": format!("{size} bytes exceeds limit of {limit} bytes"),
+        })),
+        _ => None,
+    };
+    jsonrpc::jsonrpc_error(id, jsonrpc_code(error), message, data)
+}
+
+/// HTTP status for a JSON-RPC error code surfaced over plain HTTP.
+///
+/// Implements: REQ-CORE-004/§5.2
+///
+/// Codes without a more specific meaning map to 500.
+#[must_use]
+pub fn status_for_jsonrpc_code(code: i64) -> StatusCode {
+    match code {
+        -32700 | -32600 | -32601 | -32602 => StatusCode::BAD_REQUEST,
+        POLICY_DENIED | -32007 | -32014 | -32015 => StatusCode::FORBIDDEN,
+        PAYLOAD_TOO_LARGE => StatusCode::PAYLOAD_TOO_LARGE,
+        APPROVAL_TIMEOUT => StatusCode::REQUEST_TIMEOUT,
+        -32009 => StatusCode::TOO_MANY_REQUESTS,
+        jsonrpc::UPSTREAM_UNAVAILABLE | -32002 => StatusCode::BAD_GATEWAY,
+        -32001 => StatusCode::GATEWAY_TIMEOUT,
+        -32013 | -32018 | -32019 => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::TlsFailure;
+
+    /// Every constructible variant with its expected status.
+    ///
+    /// `ProxyError::Http` wraps a `hyper::Error`, which cannot be built
+    /// outside hyper; the exhaustive match below still forces a decision
+    /// for it and for any future variant.
+    fn all_variants() -> Vec<(ProxyError, StatusCode)> {
+        vec![
+            (
+                ProxyError::InvalidUri("x".to_string()),
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                ProxyError::Io(std::io::Error::other("reset")),
+                StatusCode::BAD_GATEWAY,
+            ),
+            (
+                ProxyError::Connection("x".to_string()),
+                StatusCode::BAD_GATEWAY,
+            ),
+            (
+                ProxyError::ConnectionRefused("x".to_string()),
+                StatusCode::BAD_GATEWAY,
+            ),
+            (
+                ProxyError::Timeout("x".to_string()),
+                StatusCode::GATEWAY_TIMEOUT,
+            ),
+            (
+                ProxyError::DeadlineExceeded(std::time::Duration::from_secs(5)),
+                StatusCode::GATEWAY_TIMEOUT,
+            ),
+            (ProxyError::ClientDisconnect, StatusCode::BAD_REQUEST),
+            (
+                ProxyError::RequestTimeout("x".to_string()),
+                StatusCode::REQUEST_TIMEOUT,
+            ),
+            (ProxyError::Client("x".to_string()), StatusCode::BAD_GATEWAY),
+            (
+                ProxyError::PayloadTooLarge(2048, 1024),
+                StatusCode::PAYLOAD_TOO_LARGE,
+            ),
+            (
+                ProxyError::HeadersTooLarge("x".to_string()),
+                StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE,
+            ),
+            (
+                ProxyError::BufferTimeout("x".to_string()),
+                StatusCode::REQUEST_TIMEOUT,
+            ),
+            (
+                ProxyError::BufferSemaphoreExhausted,
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            (
+                ProxyError::BufferBudgetExhausted,
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            (
+                ProxyError::Overloaded("global".to_string()),
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            (
+                ProxyError::Tls {
+                    upstream: "https://billing:8443".to_string(),
+                    failure: TlsFailure::UnknownCa,
+                },
+                StatusCode::BAD_GATEWAY,
+            ),
+            (
+                ProxyError::CompressedResponse("gzip".to_string()),
+                StatusCode::BAD_GATEWAY,
+            ),
+            (
+                ProxyError::Rejected("pii".to_string(), StatusCode::FORBIDDEN),
+                StatusCode::FORBIDDEN,
+            ),
+            (
+                ProxyError::InspectorPanic("pii".to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                ProxyError::InspectorError("pii".to_string(), "boom".to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_every_variant_has_explicit_status() {
+        for (error, expected) in all_variants() {
+            // Compile-time guard: a new variant must be added to all_variants.
+            match &error {
+                ProxyError::Http(_)
+                | ProxyError::InvalidUri(_)
+                | ProxyError::Io(_)
+                | ProxyError::Connection(_)
+                | ProxyError::ConnectionRefused(_)
+                | ProxyError::Tls { .. }
+                | ProxyError::Timeout(_)
+                | ProxyError::DeadlineExceeded(_)
+                | ProxyError::ClientDisconnect
+                | ProxyError::RequestTimeout(_)
+                | ProxyError::Client(_)
+                | ProxyError::PayloadTooLarge(_, _)
+                | ProxyError::HeadersTooLarge(_)
+                | ProxyError::BufferTimeout(_)
+                | ProxyError::BufferSemaphoreExhausted
+                | ProxyError::BufferBudgetExhausted
+                | ProxyError::Overloaded(_)
+                | ProxyError::CompressedResponse(_)
+                | ProxyError::Rejected(_, _)
+                | ProxyError::InspectorPanic(_)
+                | ProxyError::InspectorError(_, _) => {}
+            }
+
+            let status = http_status(&error);
+            assert_eq!(status, expected, "{error:?}");
+            if status == StatusCode::INTERNAL_SERVER_ERROR {
+                assert!(
+                    error.is_inspection_failure(),
+                    "{error:?} fell through to 500"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_response_carries_jsonrpc_body() {
+        for (error, expected) in all_variants() {
+            let body = jsonrpc_body(&error, None);
+            assert_eq!(body["jsonrpc"], "2.0");
+            assert!(body["id"].is_null());
+            assert_eq!(body["error"]["code"], jsonrpc_code(&error));
+            // Body code and HTTP status agree on client vs server fault
+            assert_eq!(
+                status_for_jsonrpc_code(jsonrpc_code(&error)).is_server_error(),
+                expected.is_server_error(),
+                "{error:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_status_for_jsonrpc_code() {
+        assert_eq!(status_for_jsonrpc_code(-32003), StatusCode::FORBIDDEN);
+        assert_eq!(status_for_jsonrpc_code(-32008), StatusCode::REQUEST_TIMEOUT);
+        assert_eq!(
+            status_for_jsonrpc_code(-32004),
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
+        assert_eq!(status_for_jsonrpc_code(-32000), StatusCode::BAD_GATEWAY);
+        assert_eq!(
+            status_for_jsonrpc_code(-32013),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+    }
+
+    #[test]
+    fn test_payload_too_large_body_details() {
+        let body = jsonrpc_body(&ProxyError::PayloadTooLarge(2048, 1024), Some(3.into()));
+        assert_eq!(body["id"], 3);
+        assert_eq!(body["error"]["code"], -32004);
+        assert_eq!(
+            body["error"]["data"]["details"],
+            "2048 bytes exceeds limit of 1024 bytes"
+        );
+    }
+
+    #[test]
+    fn test_tls_body_hides_upstream() {
+        let error = ProxyError::Tls {
+            upstream: "https://billing:8443".to_string(),
+            failure: TlsFailure::CertificateExpired,
+        };
+        let body = jsonrpc_body(&error, None);
+        assert_eq!(body["error"]["code"], -32000);
+        let body = body.to_string();
+        assert!(!body.contains("billing"), "{body}");
+        assert!(!body.contains("expired"), "{body}");
+    }
+}
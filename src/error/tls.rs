@@ -0,0 +1,126 @@
+//! Classification of upstream TLS handshake failures.
+//!
+//! Implements: REQ-CORE-004/EC-ERR-005 (Upstream Connection Failure)
+//!
+//! The HTTP clients report a failed handshake as a generic connect error.
+//! [`classify`] walks the error's source chain for the underlying
+//! [`rustls::Error`] so the failure can be reported, counted and audited
+//! with its cause.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use rustls::CertificateError;
+
+/// Why a TLS handshake with an upstream failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsFailure {
+    /// The upstream's certificate has expired (or is not yet valid).
+    CertificateExpired,
+    /// The certificate is not valid for the upstream's hostname.
+    HostnameMismatch,
+    /// The certificate is not signed by a trusted CA.
+    UnknownCa,
+    /// Any other handshake failure (revoked certificate, protocol alert, ...).
+    Other,
+}
+
+impl TlsFailure {
+    /// Stable reason code, used as the metric label and audit reason.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CertificateExpired => "certificate_expired",
+            Self::HostnameMismatch => "hostname_mismatch",
+            Self::UnknownCa => "unknown_ca",
+            Self::Other => "handshake_failed",
+        }
+    }
+}
+
+impl fmt::Display for TlsFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Find the TLS failure behind a client error, if there is one.
+///
+/// Returns `None` for errors not caused by the TLS handshake (refused
+/// connections, timeouts, DNS failures).
+pub fn classify(error: &(dyn StdError + 'static)) -> Option<TlsFailure> {
+    let mut current = Some(error);
+    while let Some(err) = current {
+        if let Some(tls) = err.downcast_ref::<rustls::Error>() {
+            return Some(from_rustls(tls));
+        }
+        // rustls errors reach the client wrapped in (nested) io::Errors,
+        // whose `source` skips the wrapped error itself.
+        current = match err
+            .downcast_ref::<std::io::Error>()
+            .and_then(|io| io.get_ref())
+        {
+            Some(inner) => Some(inner as &(dyn StdError + 'static)),
+            None => err.source(),
+        };
+    }
+    None
+}
+
+fn from_rustls(error: &rustls::Error) -> TlsFailure {
+    let rustls::Error::InvalidCertificate(cert) = error else {
+        return TlsFailure::Other;
+    };
+    match cert {
+        CertificateError::Expired
+        | CertificateError::ExpiredContext { .. }
+        | CertificateError::NotValidYet
+        | CertificateError::NotValidYetContext { .. } => TlsFailure::CertificateExpired,
+        CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. } => {
+            TlsFailure::HostnameMismatch
+        }
+        CertificateError::UnknownIssuer => TlsFailure::UnknownCa,
+        _ => TlsFailure::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wrapped twice, as hyper-rustls reports it through hyper-util.
+    fn io_wrapped(error: rustls::Error) -> std::io::Error {
+        std::io::Error::other(std::io::Error::new(std::io::ErrorKind::InvalidData, error))
+    }
+
+    #[test]
+    fn test_classify_certificate_errors() {
+        let cases = [
+            (CertificateError::Expired, TlsFailure::CertificateExpired),
+            (
+                CertificateError::NotValidYet,
+                TlsFailure::CertificateExpired,
+            ),
+            (
+                CertificateError::NotValidForName,
+                TlsFailure::HostnameMismatch,
+            ),
+            (CertificateError::UnknownIssuer, TlsFailure::UnknownCa),
+            (CertificateError::Revoked, TlsFailure::Other),
+        ];
+        for (cert, expected) in cases {
+            let error = io_wrapped(rustls::Error::InvalidCertificate(cert));
+            assert_eq!(classify(&error), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_classify_ignores_non_tls_errors() {
+        let refused = std::io::Error::from(std::io::ErrorKind::ConnectionRefused);
+        assert_eq!(classify(&refused), None);
+
+        let alert = io_wrapped(rustls::Error::AlertReceived(
+            rustls::AlertDescription::HandshakeFailure,
+        ));
+        assert_eq!(classify(&alert), Some(TlsFailure::Other));
+    }
+}
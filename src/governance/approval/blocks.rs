@@ -0,0 +1,337 @@
+//! Slack Block Kit rendering for interactive approval messages.
+//!
+//! Implements: REQ-GOV-003/F-006 (Approval Message Format)
+//!
+//! Renders an approval request as a Block Kit payload with a header, request
+//! context, an argument summary, and Approve/Reject buttons. Each button
+//! carries the task ID in its `value` so an interaction callback can be
+//! mapped back to the pending task with [`parse_block_action`]. Once
+//! decided, [`render_resolved_blocks`] replaces the message so the buttons
+//! cannot be clicked again.
+
+use crate::governance::TaskId;
+
+use super::{ApprovalRequest, PollDecision, PollResult};
+
+/// `block_id` of the actions block containing the approval buttons.
+pub const ACTIONS_BLOCK_ID: &str = "thoughtgate_approval";
+
+/// `action_id` of the Approve button.
+pub const APPROVE_ACTION_ID: &str = "thoughtgate_approve";
+
+/// `action_id` of the Reject button.
+pub const REJECT_ACTION_ID: &str = "thoughtgate_reject";
+
+/// Maximum characters of the argument summary shown in the message.
+///
+/// Slack rejects `text` objects over 3000 characters; this leaves room for
+/// the surrounding markup.
+pub const MAX_ARGUMENT_SUMMARY_CHARS: usize = 2000;
+
+/// Render an approval request as a Block Kit payload.
+///
+/// Implements: REQ-GOV-003/F-006.2
+///
+/// The returned value is the `blocks` array for `chat.postMessage`.
+#[must_use]
+pub fn render_blocks(request: &ApprovalRequest) -> serde_json::Value {
+    let task_id = request.task_id.to_string();
+    let user = request.principal.user_id.as_deref().unwrap_or("-");
+    let required = if request.min_approvals > 1 {
+        format!(" • Requires {} approvals", request.min_approvals)
+    } else {
+        String::new()
+    };
+
+    serde_json::json!([
+        {
+            "type": "header",
+            "text": {
+                "type": "plain_text",
+                "text": truncate(&format!("🔒 Approval Required: {}", request.tool_name), 150),
+                "emoji": true
+            }
+        },
+        {
+            "type": "section",
+            "fields": [
+                {
+                    "type": "mrkdwn",
+                    "text": format!("*Tool:* `{}`", request.tool_name)
+                },
+                {
+                    "type": "mrkdwn",
+                    "text": format!("*Principal:* {}", request.principal.app_name)
+                },
+                {
+                    "type": "mrkdwn",
+                    "text": format!("*User:* {}", user)
+                },
+                {
+                    "type": "mrkdwn",
+                    "text": format!("*Correlation ID:* `{}`", request.correlation_id)
+                }
+            ]
+        },
+        {
+            "type": "section",
+            "text": {
+                "type": "mrkdwn",
+                "text": format!(
+                    "*Arguments:*\n```\n{}\n```",
+                    summarize_arguments(&request.tool_arguments)
+                )
+            }
+        },
+        {
+            "type": "context",
+            "elements": [
+                {
+                    "type": "mrkdwn",
+                    "text": format!(
+                        "Task ID: `{}` • Expires: {}{}",
+                        task_id,
+                        request.expires_at.format("%Y-%m-%d %H:%M UTC"),
+                        required
+                    )
+                }
+            ]
+        },
+        {
+            "type": "actions",
+            "block_id": ACTIONS_BLOCK_ID,
+            "elements": [
+                {
+                    "type": "button",
+                    "action_id": APPROVE_ACTION_ID,
+                    "style": "primary",
+                    "text": { "type": "plain_text", "text": "Approve", "emoji": true },
+                    "value": task_id
+                },
+                {
+                    "type": "button",
+                    "action_id": REJECT_ACTION_ID,
+                    "style": "danger",
+                    "text": { "type": "plain_text", "text": "Reject", "emoji": true },
+                    "value": task_id
+                }
+            ]
+        }
+    ])
+}
+
+/// One-line outcome of a decision, e.g. "Approved by alice at 10:30 UTC".
+///
+/// Implements: REQ-GOV-003/F-004
+#[must_use]
+pub fn resolution_summary(result: &PollResult) -> String {
+    let outcome = match result.decision {
+        PollDecision::Approved => "Approved",
+        PollDecision::Rejected => "Rejected",
+    };
+    format!(
+        "{} by {} at {}",
+        outcome,
+        result.decided_by,
+        result.decided_at.format("%Y-%m-%d %H:%M UTC")
+    )
+}
+
+/// Render the Block Kit payload that replaces a decided approval message.
+///
+/// Implements: REQ-GOV-003/F-004
+///
+/// The returned value is the `blocks` array for `chat.update`. It has no
+/// actions block, so the Approve/Reject buttons are gone.
+#[must_use]
+pub fn render_resolved_blocks(task_id: &TaskId, result: &PollResult) -> serde_json::Value {
+    let icon = match result.decision {
+        PollDecision::Approved => "✅",
+        PollDecision::Rejected => "❌",
+    };
+
+    serde_json::json!([
+        {
+            "type": "section",
+            "text": {
+                "type": "mrkdwn",
+                "text": format!("{} *{}*", icon, resolution_summary(result))
+            }
+        },
+        {
+            "type": "context",
+            "elements": [
+                {
+                    "type": "mrkdwn",
+                    "text": format!(
+                        "Task ID: `{}` • Decided via {}",
+                        task_id,
+                        result.method.description()
+                    )
+                }
+            ]
+        }
+    ])
+}
+
+/// Map a button interaction back to a decision and task ID.
+///
+/// Implements: REQ-GOV-003/F-003
+///
+/// Returns `None` for unknown action IDs or values that are not
+/// ThoughtGate-owned task IDs.
+#[must_use]
+pub fn parse_block_action(action_id: &str, value: &str) -> Option<(PollDecision, TaskId)> {
+    let decision = match action_id {
+        APPROVE_ACTION_ID => PollDecision::Approved,
+        REJECT_ACTION_ID => PollDecision::Rejected,
+        _ => return None,
+    };
+    let task_id = TaskId::from_raw(value);
+    task_id
+        .is_thoughtgate_owned()
+        .then_some((decision, task_id))
+}
+
+/// Pretty-print tool arguments, truncated to [`MAX_ARGUMENT_SUMMARY_CHARS`].
+fn summarize_arguments(arguments: &serde_json::Value) -> String {
+    let pretty = serde_json::to_string_pretty(arguments).unwrap_or_else(|_| arguments.to_string());
+    // Triple backticks would close the code block early.
+    truncate(&pretty.replace("```", "'''"), MAX_ARGUMENT_SUMMARY_CHARS)
+}
+
+/// Truncate to at most `max_chars` characters on a char boundary.
+///
+/// The "… (N more chars)" marker counts towards the limit, since Slack
+/// rejects the whole message when a field is over it.
+fn truncate(text: &str, max_chars: usize) -> String {
+    let total = text.chars().count();
+    if total <= max_chars {
+        return text.to_string();
+    }
+    let marker = |omitted: usize| format!("… ({omitted} more chars)");
+    // Fewer omitted chars never need more digits than the total
+    let kept = max_chars.saturating_sub(marker(total).chars().count());
+    let byte_idx = text.char_indices().nth(kept).map_or(text.len(), |(i, _)| i);
+    format!("{}{}", &text[..byte_idx], marker(total - kept))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::Principal;
+    use chrono::Utc;
+
+    fn test_request(arguments: serde_json::Value) -> ApprovalRequest {
+        ApprovalRequest {
+            task_id: TaskId::new(),
+            tool_name: "delete_user".to_string(),
+            tool_arguments: arguments,
+            principal: Principal::new("test-app"),
+            expires_at: Utc::now() + chrono::Duration::hours(1),
+            created_at: Utc::now(),
+            correlation_id: "test-correlation".to_string(),
+            escalation: None,
+            min_approvals: 1,
+        }
+    }
+
+    #[test]
+    fn test_render_blocks_structure() {
+        let request = test_request(serde_json::json!({"user_id": "12345"}));
+        let blocks = render_blocks(&request);
+        let blocks = blocks.as_array().expect("blocks array");
+
+        let types: Vec<&str> = blocks.iter().filter_map(|b| b["type"].as_str()).collect();
+        assert_eq!(
+            types,
+            ["header", "section", "section", "context", "actions"]
+        );
+        assert!(
+            blocks[0]["text"]["text"]
+                .as_str()
+                .is_some_and(|t| t.contains("delete_user"))
+        );
+        assert_eq!(blocks[1]["fields"].as_array().map(Vec::len), Some(4));
+        assert!(
+            blocks[2]["text"]["text"]
+                .as_str()
+                .is_some_and(|t| t.contains("12345"))
+        );
+
+        let buttons = blocks[4]["elements"].as_array().expect("buttons");
+        assert_eq!(blocks[4]["block_id"], ACTIONS_BLOCK_ID);
+        assert_eq!(buttons.len(), 2);
+        assert_eq!(buttons[0]["action_id"], APPROVE_ACTION_ID);
+        assert_eq!(buttons[0]["style"], "primary");
+        assert_eq!(buttons[1]["action_id"], REJECT_ACTION_ID);
+        assert_eq!(buttons[1]["style"], "danger");
+    }
+
+    #[test]
+    fn test_button_values_round_trip_task_id() {
+        let request = test_request(serde_json::json!({}));
+        let blocks = render_blocks(&request);
+
+        for (button, expected) in blocks[4]["elements"]
+            .as_array()
+            .expect("buttons")
+            .iter()
+            .zip([PollDecision::Approved, PollDecision::Rejected])
+        {
+            let action_id = button["action_id"].as_str().expect("action_id");
+            let value = button["value"].as_str().expect("value");
+            let (decision, task_id) = parse_block_action(action_id, value).expect("parses");
+            assert_eq!(decision, expected);
+            assert_eq!(task_id, request.task_id);
+        }
+    }
+
+    #[test]
+    fn test_parse_block_action_rejects_unknown() {
+        let task_id = TaskId::new().to_string();
+        assert!(parse_block_action("other_action", &task_id).is_none());
+        assert!(parse_block_action(APPROVE_ACTION_ID, "not a task id").is_none());
+    }
+
+    #[test]
+    fn test_long_arguments_truncated_on_char_boundary() {
+        // Multi-byte characters straddling the limit must not panic.
+        let request = test_request(serde_json::json!({ "note": "é".repeat(5000) }));
+        let blocks = render_blocks(&request);
+        let text = blocks[2]["text"]["text"].as_str().expect("text");
+
+        assert!(text.contains("more chars)"));
+        assert!(text.chars().count() < MAX_ARGUMENT_SUMMARY_CHARS + 100);
+        assert!(text.ends_with("\n```"));
+    }
+
+    #[test]
+    fn test_long_tool_name_header_within_slack_limit() {
+        let mut request = test_request(serde_json::json!({}));
+        request.tool_name = "x".repeat(500);
+        let blocks = render_blocks(&request);
+        let header = blocks[0]["text"]["text"].as_str().expect("header");
+
+        assert!(header.ends_with("more chars)"));
+        assert_eq!(header.chars().count(), 150);
+    }
+
+    #[test]
+    fn test_truncate_counts_marker() {
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(truncate(&"a".repeat(30), 20), "aaa… (27 more chars)");
+    }
+
+    #[test]
+    fn test_arguments_cannot_close_code_block() {
+        let request = test_request(serde_json::json!({ "q": "```<!channel>" }));
+        let blocks = render_blocks(&request);
+        let text = blocks[2]["text"]["text"].as_str().expect("text");
+        assert_eq!(text.matches("```").count(), 2);
+    }
+}
@@ -0,0 +1,391 @@
+//! Dead-letter log of approval requests that could not be delivered.
+//!
+//! Implements: REQ-GOV-003/F-001
+//!
+//! When an approval request cannot be posted, even after retries, no
+//! approver will ever see it. Rather than let it vanish into a timeout, the
+//! engine settles the task at once (see `THOUGHTGATE_ON_POST_FAILURE`) and
+//! writes the full request here as one newline-delimited JSON
+//! [`DeadLetterRecord`], so an operator can inspect it and, once the
+//! approval channel is back, replay the tool call.
+//!
+//! # Configuration
+//!
+//! - `THOUGHTGATE_APPROVAL_DEAD_LETTER_LOG`: `stdout`, or a file path opened
+//!   in append mode. Unset keeps dead letters in the error log only.
+//!
+//! # Durability
+//!
+//! Records are queued for a dedicated writer thread, which writes each with
+//! a single `write` call and flushes it, so the engine never waits on the
+//! file. Records still queued when the process crashes are lost;
+//! [`DeadLetterLog::flush`] waits for the queue to drain and is called on
+//! shutdown. Records carry tool arguments in full, so the file should be
+//! protected like the audit log.
+
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+
+use super::{AdapterError, ApprovalRequest};
+use crate::governance::{PostFailureAction, Principal};
+
+/// Version of the [`DeadLetterRecord`] schema.
+pub const DEAD_LETTER_SCHEMA_VERSION: u32 = 1;
+
+/// Records waiting to be written before new ones are dropped.
+const QUEUE_SIZE: usize = 1024;
+
+/// One undeliverable approval request (one line of the dead-letter log).
+///
+/// Implements: REQ-GOV-003/F-001
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadLetterRecord {
+    /// Schema version ([`DEAD_LETTER_SCHEMA_VERSION`]).
+    pub version: u32,
+    /// When delivery was given up.
+    pub timestamp: DateTime<Utc>,
+    /// Task the approval was for.
+    pub task_id: String,
+    /// Correlation ID of the approval workflow.
+    pub correlation_id: String,
+    /// Who made the request.
+    pub principal: Principal,
+    /// Tool awaiting approval.
+    pub tool_name: String,
+    /// Tool arguments, as they would have been shown to the approver.
+    pub tool_arguments: serde_json::Value,
+    /// When the task was created.
+    pub created_at: DateTime<Utc>,
+    /// When the task would have expired.
+    pub expires_at: DateTime<Utc>,
+    /// Distinct approvers that were required.
+    pub min_approvals: u8,
+    /// Adapter that failed to deliver (e.g. `slack`).
+    pub adapter: String,
+    /// The last delivery error.
+    pub error: String,
+    /// How the task was settled instead.
+    pub action: PostFailureAction,
+}
+
+impl DeadLetterRecord {
+    /// Record `request`, which `adapter` failed to deliver with `error`.
+    pub fn new(
+        request: &ApprovalRequest,
+        adapter: &str,
+        error: &AdapterError,
+        action: PostFailureAction,
+    ) -> Self {
+        Self {
+            version: DEAD_LETTER_SCHEMA_VERSION,
+            timestamp: Utc::now(),
+            task_id: request.task_id.to_string(),
+            correlation_id: request.correlation_id.clone(),
+            principal: request.principal.clone(),
+            tool_name: request.tool_name.clone(),
+            tool_arguments: request.tool_arguments.clone(),
+            created_at: request.created_at,
+            expires_at: request.expires_at,
+            min_approvals: request.min_approvals,
+            adapter: adapter.to_string(),
+            error: error.to_string(),
+            action,
+        }
+    }
+}
+
+/// Newline-delimited JSON dead-letter sink.
+///
+/// Implements: REQ-GOV-003/F-001
+pub struct DeadLetterLog {
+    queue: SyncSender<Command>,
+    dropped: AtomicU64,
+}
+
+/// Work for the writer thread.
+enum Command {
+    /// Write one record.
+    Record(Box<DeadLetterRecord>),
+    /// Acknowledge once everything queued before has been written.
+    Flush(SyncSender<()>),
+}
+
+impl std::fmt::Debug for DeadLetterLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeadLetterLog").finish_non_exhaustive()
+    }
+}
+
+impl DeadLetterLog {
+    /// Write records to any writer from a dedicated thread.
+    ///
+    /// The thread exits once the log is dropped and its queue drained.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the writer thread cannot start.
+    pub fn from_writer(mut writer: impl Write + Send + 'static) -> std::io::Result<Self> {
+        let (queue, commands) = mpsc::sync_channel::<Command>(QUEUE_SIZE);
+        std::thread::Builder::new()
+            .name("thoughtgate-dead-letter".to_string())
+            .spawn(move || {
+                for command in commands {
+                    match command {
+                        Command::Record(record) => write_record(&mut writer, &record),
+                        // The caller may have stopped waiting
+                        Command::Flush(done) => {
+                            let _ = done.send(());
+                        }
+                    }
+                }
+            })?;
+        Ok(Self {
+            queue,
+            dropped: AtomicU64::new(0),
+        })
+    }
+
+    /// Append records to a file, creating it if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the file cannot be opened or the writer
+    /// thread cannot start.
+    pub fn file(path: &Path) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Self::from_writer(file)
+    }
+
+    /// Build the sink named by `THOUGHTGATE_APPROVAL_DEAD_LETTER_LOG`, if set.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the file cannot be opened or the writer
+    /// thread cannot start.
+    pub fn from_env() -> std::io::Result<Option<Self>> {
+        match std::env::var("THOUGHTGATE_APPROVAL_DEAD_LETTER_LOG").as_deref() {
+            Err(_) | Ok("") => Ok(None),
+            Ok("stdout") => Self::from_writer(std::io::stdout()).map(Some),
+            Ok(path) => Self::file(Path::new(path)).map(Some),
+        }
+    }
+
+    /// Queue one record for writing.
+    ///
+    /// Never blocks. Failures are logged; the task is settled either way.
+    pub fn record(&self, record: &DeadLetterRecord) {
+        match self
+            .queue
+            .try_send(Command::Record(Box::new(record.clone())))
+        {
+            Ok(()) => {}
+            Err(TrySendError::Full(_) | TrySendError::Disconnected(_)) => {
+                // Disconnected only if the writer thread died; either way it is lost
+                error!(task_id = %record.task_id, "Dead-letter record dropped");
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Records dropped because the queue was full.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Block until every record queued so far has been written.
+    ///
+    /// Returns immediately if the writer thread has died.
+    pub fn flush(&self) {
+        let (done, flushed) = mpsc::sync_channel(1);
+        if self.queue.send(Command::Flush(done)).is_ok() {
+            let _ = flushed.recv();
+        }
+    }
+}
+
+/// Write one record as a line, on the writer thread.
+fn write_record(writer: &mut impl Write, record: &DeadLetterRecord) {
+    let mut line = match serde_json::to_string(record) {
+        Ok(line) => line,
+        Err(e) => {
+            error!(error = %e, "Failed to serialize dead-letter record");
+            return;
+        }
+    };
+    line.push('\n');
+
+    if let Err(e) = writer
+        .write_all(line.as_bytes())
+        .and_then(|()| writer.flush())
+    {
+        error!(
+            task_id = %record.task_id,
+            error = %e,
+            "Failed to write dead-letter record"
+        );
+    }
+}
+
+/// Read the records of a dead-letter log, for inspection or replay.
+///
+/// # Errors
+///
+/// Returns the first I/O or parse error; blank lines are skipped.
+pub fn read_dead_letters(reader: impl std::io::Read) -> std::io::Result<Vec<DeadLetterRecord>> {
+    BufReader::new(reader)
+        .lines()
+        .filter(|line| !matches!(line, Ok(l) if l.is_empty()))
+        .map(|line| serde_json::from_str(&line?).map_err(std::io::Error::other))
+        .collect()
+}
+
+/// Global dead-letter sink.
+static DEAD_LETTER_LOG: once_cell::sync::OnceCell<Arc<DeadLetterLog>> =
+    once_cell::sync::OnceCell::new();
+
+/// Install the global dead-letter sink (first call wins).
+pub fn init_dead_letters(log: DeadLetterLog) {
+    let _ = DEAD_LETTER_LOG.set(Arc::new(log));
+}
+
+/// Get the global dead-letter sink, if installed.
+pub fn get_dead_letter_log() -> Option<Arc<DeadLetterLog>> {
+    DEAD_LETTER_LOG.get().cloned()
+}
+
+/// Dead-letter an undeliverable request.
+///
+/// Always logged at error level with its context, and written to the global
+/// sink if one is installed.
+pub fn record(record: DeadLetterRecord) {
+    error!(
+        task_id = %record.task_id,
+        correlation_id = %record.correlation_id,
+        principal = %record.principal.app_name,
+        tool = %record.tool_name,
+        adapter = %record.adapter,
+        error = %record.error,
+        action = ?record.action,
+        "Approval request undeliverable, dead-lettered"
+    );
+    if let Some(metrics) = crate::metrics::get_approval_metrics() {
+        metrics.record_dead_letter(&record.adapter);
+    }
+    if let Some(log) = DEAD_LETTER_LOG.get() {
+        log.record(&record);
+    }
+}
+
+/// Wait for the global dead-letter sink, if installed, to write every
+/// queued record.
+pub fn flush() {
+    if let Some(log) = DEAD_LETTER_LOG.get() {
+        log.flush();
+    }
+}
+
+/// Test support: a shared in-memory sink installed as the global sink.
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    static CAPTURED: once_cell::sync::Lazy<Captured> = once_cell::sync::Lazy::new(|| {
+        let captured = Captured::default();
+        init_dead_letters(DeadLetterLog::from_writer(captured.clone()).unwrap());
+        captured
+    });
+
+    /// Install the capturing sink (idempotent).
+    pub(crate) fn install() {
+        once_cell::sync::Lazy::force(&CAPTURED);
+    }
+
+    /// Records captured so far for `tool`.
+    ///
+    /// Tests share the global sink, so each should use a unique tool name.
+    pub(crate) fn records_for(tool: &str) -> Vec<DeadLetterRecord> {
+        flush();
+        let bytes = CAPTURED.0.lock().unwrap().clone();
+        read_dead_letters(bytes.as_slice())
+            .unwrap()
+            .into_iter()
+            .filter(|r| r.tool_name == tool)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::TaskId;
+
+    #[test]
+    fn test_file_records_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "thoughtgate_test_dead_letters_{}.ndjson",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let request = ApprovalRequest {
+            task_id: TaskId::new(),
+            tool_name: "drop_table".to_string(),
+            tool_arguments: serde_json::json!({"table": "users"}),
+            principal: Principal::new("app"),
+            expires_at: Utc::now(),
+            created_at: Utc::now(),
+            correlation_id: "corr".to_string(),
+            escalation: None,
+            min_approvals: 2,
+        };
+
+        let log = DeadLetterLog::file(&path).unwrap();
+        log.record(&DeadLetterRecord::new(
+            &request,
+            "slack",
+            &AdapterError::InvalidToken,
+            PostFailureAction::Deny,
+        ));
+        log.flush();
+        // Reopening appends
+        let log = DeadLetterLog::file(&path).unwrap();
+        log.record(&DeadLetterRecord::new(
+            &request,
+            "slack",
+            &AdapterError::InvalidToken,
+            PostFailureAction::Approve,
+        ));
+        log.flush();
+
+        let records = read_dead_letters(std::fs::File::open(&path).unwrap()).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].task_id, request.task_id.to_string());
+        assert_eq!(records[0].tool_arguments["table"], "users");
+        assert_eq!(records[0].min_approvals, 2);
+        assert_eq!(records[0].action, PostFailureAction::Deny);
+        assert_eq!(records[1].action, PostFailureAction::Approve);
+    }
+}
@@ -243,6 +243,8 @@ mod tests {
             expires_at: Utc::now() + chrono::Duration::minutes(5),
             created_at: Utc::now(),
             correlation_id: "test-123".to_string(),
+            escalation: None,
+            min_approvals: 1,
         }
     }
 
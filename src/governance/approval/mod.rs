@@ -7,6 +7,11 @@
 //! decisions rather than receiving callbacks—necessary because sidecars are
 //! not individually addressable from external systems.
 //!
+//! Where the approval channel can reach the sidecar, decisions may instead be
+//! pushed to the callback endpoint (see [`crate::callback`]); the generic
+//! webhook adapter works only this way. Either path resolves the task through
+//! the same scheduler, so nothing waiting on it can tell the difference.
+//!
 //! ## Architecture
 //!
 //! ```text
@@ -24,20 +29,44 @@
 //! ## Module Organization
 //!
 //! - `mod.rs` - Trait definitions, types, and configuration
+//! - `blocks.rs` - Block Kit rendering for interactive approval messages
+//! - `dead_letter.rs` - Log of approval requests that could not be delivered
+//! - `signature.rs` - Slack request signature verification for callbacks
+//! - `store.rs` - Pending approval store (in-memory, Redis behind `redis` feature)
+//! - `reaper.rs` - Timeout reaper that expires approvals nobody answered
 //! - `slack.rs` - Slack adapter implementation
-//! - `scheduler.rs` - Polling scheduler with rate limiting
-//! - `rate_limiter.rs` - Token bucket rate limiter
+//! - `webhook.rs` - Generic HTTP webhook adapter, decided via signed callbacks
+//! - `scheduler.rs` - Polling scheduler with rate limiting and escalation
+//! - `rate_limiter.rs` - Token bucket and sliding window rate limiters
 
+pub mod blocks;
+pub mod dead_letter;
 pub mod mock;
 pub mod rate_limiter;
+pub mod reaper;
 pub mod scheduler;
+pub mod signature;
 pub mod slack;
+pub mod store;
+pub mod webhook;
 
 // Re-exports
+pub use blocks::{parse_block_action, render_blocks, render_resolved_blocks, resolution_summary};
 pub use mock::MockAdapter;
-pub use rate_limiter::RateLimiter;
+pub use rate_limiter::{
+    Limiter, RateLimitAlgorithm, RateLimiter, SlidingWindowLimiter, jitter_from_env,
+};
+pub use reaper::{ApprovalReaper, ReaperConfig};
 pub use scheduler::PollingScheduler;
+pub use signature::{
+    SignatureError, sign_webhook, verify_slack_signature, verify_webhook_signature,
+};
 pub use slack::{SlackAdapter, SlackConfig};
+pub use store::{
+    ApprovalState, ApprovalStore, InMemoryApprovalStore, PendingApproval, RejectionReason,
+    ResolveOutcome, SYSTEM_DECIDER, StoreError,
+};
+pub use webhook::{WebhookAdapter, WebhookConfig};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -45,6 +74,7 @@ use serde::{Deserialize, Serialize};
 use std::time::{Duration, Instant};
 use thiserror::Error;
 
+use crate::config::{ApproverRoute, Escalation};
 use crate::governance::{Principal, TaskId};
 
 // ============================================================================
@@ -73,6 +103,10 @@ pub struct ApprovalRequest {
     pub created_at: DateTime<Utc>,
     /// Correlation ID for tracing
     pub correlation_id: String,
+    /// Escalation to a secondary approver group, if configured
+    pub escalation: Option<Escalation>,
+    /// Distinct approvers required to approve
+    pub min_approvals: u8,
 }
 
 // ============================================================================
@@ -149,6 +183,11 @@ pub enum DecisionMethod {
         /// The reply text
         text: String,
     },
+    /// Decision pushed to the callback endpoint
+    Callback {
+        /// What sent it (e.g., "button", "webhook")
+        source: String,
+    },
 }
 
 impl DecisionMethod {
@@ -165,6 +204,7 @@ impl DecisionMethod {
                     format!("reply \"{preview}\"")
                 }
             }
+            Self::Callback { source } => format!("{source} callback"),
         }
     }
 }
@@ -220,6 +260,15 @@ pub enum AdapterError {
         /// The message timestamp that was not found
         ts: String,
     },
+
+    /// Gave up after retrying a transient failure
+    #[error("Gave up after {attempts} attempts: {last}")]
+    RetriesExhausted {
+        /// Attempts made, including the first
+        attempts: u32,
+        /// The last failure
+        last: Box<AdapterError>,
+    },
 }
 
 impl AdapterError {
@@ -229,13 +278,49 @@ impl AdapterError {
         match self {
             Self::PostFailed { retriable, .. } | Self::PollFailed { retriable, .. } => *retriable,
             Self::RateLimited { .. } => true,
-            Self::InvalidToken | Self::ChannelNotFound { .. } | Self::MessageNotFound { .. } => {
-                false
-            }
+            Self::InvalidToken
+            | Self::ChannelNotFound { .. }
+            | Self::MessageNotFound { .. }
+            | Self::RetriesExhausted { .. } => false,
         }
     }
 }
 
+/// Errors from verifying a decision pushed to the callback endpoint.
+///
+/// Implements: REQ-GOV-003/F-003
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum CallbackError {
+    /// The adapter only learns decisions by polling
+    #[error("The {adapter} adapter does not accept callbacks")]
+    Unsupported {
+        /// The adapter name
+        adapter: &'static str,
+    },
+
+    /// Signature or timestamp check failed
+    #[error("Callback signature rejected: {0}")]
+    Signature(#[from] signature::SignatureError),
+
+    /// The body is not a decision this adapter understands
+    #[error("Invalid callback payload: {reason}")]
+    InvalidPayload {
+        /// What was wrong with it
+        reason: String,
+    },
+
+    /// No approval for the task is awaiting a decision
+    #[error("No pending approval for task {task_id}")]
+    UnknownTask {
+        /// The task named in the callback
+        task_id: TaskId,
+    },
+
+    /// The decision could not be counted in the approval store
+    #[error("Failed to record decision: {0}")]
+    Store(#[from] StoreError),
+}
+
 // ============================================================================
 // Approval Adapter Trait
 // ============================================================================
@@ -244,11 +329,14 @@ impl AdapterError {
 ///
 /// Implements: REQ-GOV-003/§6.4
 ///
-/// This trait abstracts over different approval backends (Slack, Teams, etc.).
-/// Each adapter handles:
+/// This trait abstracts over different approval channels (Slack, a generic
+/// webhook, etc.). Each adapter handles:
 /// - Posting approval request messages
-/// - Polling for approval decisions
-/// - Cancelling pending approvals
+/// - Learning decisions, by polling or from verified callbacks
+/// - Updating or cancelling messages once the approval is settled
+///
+/// Everything that holds a request open waits on the task store, so it
+/// works the same whichever adapter delivered the decision.
 #[async_trait]
 pub trait ApprovalAdapter: Send + Sync {
     /// Post an approval request to the external system.
@@ -301,6 +389,89 @@ pub trait ApprovalAdapter: Send + Sync {
     /// * `reference` - The reference to cancel
     async fn cancel_approval(&self, reference: &ApprovalReference) -> Result<(), AdapterError>;
 
+    /// Mark a decided approval as resolved (best-effort).
+    ///
+    /// Implements: REQ-GOV-003/F-004
+    ///
+    /// Typically updates the message to show the decision and who made it,
+    /// removing anything that could be clicked again. Adapters that cannot
+    /// edit their messages keep the default, which does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The message to update
+    /// * `result` - The decision that resolved it
+    ///
+    /// # Errors
+    ///
+    /// Returns `AdapterError` if the update fails; the decision stands.
+    async fn resolve_approval(
+        &self,
+        reference: &ApprovalReference,
+        result: &PollResult,
+    ) -> Result<(), AdapterError> {
+        let _ = (reference, result);
+        Ok(())
+    }
+
+    /// Post a follow-up that escalates an unanswered approval.
+    ///
+    /// Implements: REQ-GOV-003/F-007
+    ///
+    /// The follow-up goes to `route` and references the original message.
+    /// Decisions on the returned reference resolve the same task as the
+    /// original. Adapters without escalation support keep the default,
+    /// which fails without retry.
+    ///
+    /// # Arguments
+    ///
+    /// * `original` - Reference to the original approval message
+    /// * `request` - The approval request being escalated
+    /// * `route` - Where to post the follow-up
+    ///
+    /// # Errors
+    ///
+    /// Returns `AdapterError` if posting fails.
+    async fn post_escalation(
+        &self,
+        original: &ApprovalReference,
+        request: &ApprovalRequest,
+        route: &ApproverRoute,
+    ) -> Result<ApprovalReference, AdapterError> {
+        let _ = (original, request, route);
+        Err(AdapterError::PostFailed {
+            reason: format!("{} adapter does not support escalation", self.name()),
+            retriable: false,
+        })
+    }
+
+    /// Verify a decision pushed to the callback endpoint.
+    ///
+    /// Implements: REQ-GOV-003/F-003
+    ///
+    /// Adapters that only poll keep the default, which refuses every
+    /// callback.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - The callback's HTTP headers
+    /// * `body` - The body exactly as received (signatures cover its bytes)
+    ///
+    /// # Errors
+    ///
+    /// Returns `CallbackError` unless the callback is authentic and names a
+    /// task and decision.
+    fn verify_callback(
+        &self,
+        headers: &http::HeaderMap,
+        body: &[u8],
+    ) -> Result<(TaskId, PollResult), CallbackError> {
+        let _ = (headers, body);
+        Err(CallbackError::Unsupported {
+            adapter: self.name(),
+        })
+    }
+
     /// Returns the adapter name for logging and metrics.
     fn name(&self) -> &'static str;
 }
@@ -324,6 +495,12 @@ pub struct PollingConfig {
     pub approval_valid_for: Duration,
     /// Rate limit for API calls (requests per second)
     pub rate_limit_per_sec: f64,
+    /// Algorithm enforcing `rate_limit_per_sec`
+    pub rate_limit_algorithm: RateLimitAlgorithm,
+    /// Largest random delay added to rate limit waits (zero: none)
+    pub rate_limit_jitter: Duration,
+    /// Retries of a failed approval post
+    pub post_retry: PostRetryConfig,
 }
 
 impl Default for PollingConfig {
@@ -334,6 +511,9 @@ impl Default for PollingConfig {
             max_concurrent: 100,
             approval_valid_for: Duration::from_secs(60),
             rate_limit_per_sec: 1.0,
+            rate_limit_algorithm: RateLimitAlgorithm::TokenBucket,
+            rate_limit_jitter: Duration::ZERO,
+            post_retry: PostRetryConfig::default(),
         }
     }
 }
@@ -349,6 +529,11 @@ impl PollingConfig {
     /// - `THOUGHTGATE_APPROVAL_POLL_MAX_INTERVAL_SECS` - Max poll interval (default: 30)
     /// - `THOUGHTGATE_MAX_CONCURRENT_POLLS` - Max concurrent polls (default: 100)
     /// - `THOUGHTGATE_SLACK_RATE_LIMIT_PER_SEC` - API rate limit (default: 1)
+    /// - `THOUGHTGATE_SLACK_RATE_LIMIT_ALGORITHM` - `token_bucket` (default) or
+    ///   `sliding_window` (see [`RateLimitAlgorithm::from_env`])
+    /// - `THOUGHTGATE_SLACK_RATE_LIMIT_JITTER_MS` - Max random delay added to
+    ///   rate limit waits (default: 0)
+    /// - Post retries, see [`PostRetryConfig::from_env`]
     #[must_use]
     pub fn from_env() -> Self {
         let base_interval = std::env::var("THOUGHTGATE_APPROVAL_POLL_INTERVAL_SECS")
@@ -379,6 +564,9 @@ impl PollingConfig {
             max_concurrent,
             approval_valid_for: Duration::from_secs(60),
             rate_limit_per_sec,
+            rate_limit_algorithm: RateLimitAlgorithm::from_env(),
+            rate_limit_jitter: jitter_from_env(),
+            post_retry: PostRetryConfig::from_env(),
         }
     }
 
@@ -397,6 +585,75 @@ impl PollingConfig {
     }
 }
 
+/// Retries of an approval post that failed transiently.
+///
+/// Implements: REQ-GOV-003/F-001
+///
+/// A 429 is retried after its `Retry-After`; other retriable failures
+/// (timeouts, 5xx) after an exponential backoff from `base_delay`. Either
+/// way, a wait longer than `max_wait` gives up rather than hold the request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostRetryConfig {
+    /// Attempts in total, including the first (at least 1)
+    pub max_attempts: u32,
+    /// Backoff before the first retry, doubled for each later one
+    pub base_delay: Duration,
+    /// Longest single wait before a retry
+    pub max_wait: Duration,
+}
+
+impl Default for PostRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_wait: Duration::from_secs(10),
+        }
+    }
+}
+
+impl PostRetryConfig {
+    /// Load from environment variables.
+    ///
+    /// - `THOUGHTGATE_SLACK_POST_MAX_ATTEMPTS` - Attempts in total (default: 3)
+    /// - `THOUGHTGATE_SLACK_POST_RETRY_BASE_MS` - First backoff (default: 500)
+    /// - `THOUGHTGATE_SLACK_POST_RETRY_MAX_WAIT_SECS` - Longest single wait
+    ///   (default: 10)
+    #[must_use]
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let env = |var: &str| std::env::var(var).ok().and_then(|s| s.parse::<u64>().ok());
+        Self {
+            max_attempts: env("THOUGHTGATE_SLACK_POST_MAX_ATTEMPTS")
+                .and_then(|n| u32::try_from(n).ok())
+                .unwrap_or(defaults.max_attempts)
+                .max(1),
+            base_delay: env("THOUGHTGATE_SLACK_POST_RETRY_BASE_MS")
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.base_delay),
+            max_wait: env("THOUGHTGATE_SLACK_POST_RETRY_MAX_WAIT_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.max_wait),
+        }
+    }
+
+    /// Wait before retrying after attempt `attempt` (1-based) failed with
+    /// `error`, or `None` to give up.
+    #[must_use]
+    pub fn retry_delay(&self, attempt: u32, error: &AdapterError) -> Option<Duration> {
+        if attempt >= self.max_attempts || !error.is_retriable() {
+            return None;
+        }
+        let wait = match error {
+            AdapterError::RateLimited { retry_after } => *retry_after,
+            _ => self
+                .base_delay
+                .saturating_mul(2u32.saturating_pow(attempt - 1)),
+        };
+        (wait <= self.max_wait).then_some(wait)
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -457,6 +714,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_post_retry_delay() {
+        let retry = PostRetryConfig::default();
+        let transient = AdapterError::PostFailed {
+            reason: "502".to_string(),
+            retriable: true,
+        };
+
+        assert_eq!(
+            retry.retry_delay(1, &transient),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(
+            retry.retry_delay(2, &transient),
+            Some(Duration::from_secs(1))
+        );
+        // Attempts exhausted
+        assert_eq!(retry.retry_delay(3, &transient), None);
+        // Retry-After is honored, unless it is longer than max_wait
+        let limited = |secs| AdapterError::RateLimited {
+            retry_after: Duration::from_secs(secs),
+        };
+        assert_eq!(
+            retry.retry_delay(1, &limited(2)),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(retry.retry_delay(1, &limited(60)), None);
+        assert_eq!(retry.retry_delay(1, &AdapterError::InvalidToken), None);
+    }
+
     #[test]
     fn test_polling_config_default() {
         let config = PollingConfig::default();
@@ -1,13 +1,124 @@
-//! Token bucket rate limiter for Slack API calls.
+//! Rate limiters for Slack API calls.
 //!
 //! Implements: REQ-GOV-003/§5.3
 //!
 //! Provides a simple token bucket rate limiter to prevent exhausting
-//! Slack API rate limits (typically 1 request/second for tier 3 methods).
+//! Slack API rate limits (typically 1 request/second for tier 3 methods),
+//! and a sliding window limiter for providers whose limits are strict
+//! counts per window, which a full bucket's burst can overrun.
 
+use crate::clock::{Clock, SystemClock};
+use async_trait::async_trait;
+use std::collections::VecDeque;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 
+// ============================================================================
+// Limiter Trait
+// ============================================================================
+
+/// A rate limiter that callers acquire a permit from before each call.
+///
+/// Implements: REQ-GOV-003/§5.3
+///
+/// Object-safe, so call sites hold an `Arc<dyn Limiter>` and the algorithm
+/// is chosen where the limiter is built.
+#[async_trait]
+pub trait Limiter: Send + Sync {
+    /// Acquire a permit, waiting if necessary. Cancel-safe.
+    async fn acquire(&self);
+
+    /// Try to acquire a permit without waiting.
+    ///
+    /// Returns `true` if a permit was acquired, `false` otherwise.
+    async fn try_acquire(&self) -> bool;
+
+    /// Number of permits that could be acquired right now without waiting.
+    async fn available(&self) -> u32;
+}
+
+/// Which algorithm a limiter uses.
+///
+/// Implements: REQ-GOV-003/§5.3
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitAlgorithm {
+    /// Token bucket: the average rate, allowing a burst of one second's worth.
+    #[default]
+    TokenBucket,
+    /// Sliding window: never more than the rate's worth of calls in any
+    /// `window`.
+    SlidingWindow {
+        /// Length of the window
+        window: Duration,
+    },
+}
+
+impl RateLimitAlgorithm {
+    /// Load from environment variables.
+    ///
+    /// - `THOUGHTGATE_SLACK_RATE_LIMIT_ALGORITHM` - `token_bucket` (default)
+    ///   or `sliding_window`
+    /// - `THOUGHTGATE_SLACK_RATE_LIMIT_WINDOW_SECS` - Sliding window length
+    ///   (default: 60)
+    #[must_use]
+    pub fn from_env() -> Self {
+        match std::env::var("THOUGHTGATE_SLACK_RATE_LIMIT_ALGORITHM").as_deref() {
+            Ok("sliding_window") => {
+                let window = std::env::var("THOUGHTGATE_SLACK_RATE_LIMIT_WINDOW_SECS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .filter(|secs| *secs > 0)
+                    .map(Duration::from_secs)
+                    .unwrap_or(Duration::from_secs(60));
+                Self::SlidingWindow { window }
+            }
+            _ => Self::TokenBucket,
+        }
+    }
+
+    /// Build a limiter allowing `rate_per_second` calls on average, whose
+    /// waits are stretched by up to `jitter`.
+    ///
+    /// A sliding window allows `rate_per_second` times its length in calls,
+    /// at least one.
+    #[must_use]
+    pub fn build(self, rate_per_second: f64, jitter: Duration) -> Arc<dyn Limiter> {
+        match self {
+            Self::TokenBucket => Arc::new(RateLimiter::new(rate_per_second).with_jitter(jitter)),
+            Self::SlidingWindow { window } => {
+                let max = (rate_per_second * window.as_secs_f64()).floor().max(1.0) as u32;
+                Arc::new(SlidingWindowLimiter::new(max, window).with_jitter(jitter))
+            }
+        }
+    }
+}
+
+/// Load the wait jitter from `THOUGHTGATE_SLACK_RATE_LIMIT_JITTER_MS`
+/// (default: 0, no jitter).
+///
+/// Implements: REQ-GOV-003/§5.3
+#[must_use]
+pub fn jitter_from_env() -> Duration {
+    std::env::var("THOUGHTGATE_SLACK_RATE_LIMIT_JITTER_MS")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(Duration::ZERO)
+}
+
+/// A random extra wait of up to `jitter`.
+///
+/// Jitter only ever lengthens waits, so it cannot raise the long-run rate;
+/// it spreads out replicas whose limiters would otherwise refill in lockstep.
+fn jittered(wait: Duration, jitter: Duration) -> Duration {
+    if jitter.is_zero() {
+        return wait;
+    }
+    let max = u64::try_from(jitter.as_nanos()).unwrap_or(u64::MAX);
+    wait + Duration::from_nanos(rand::random_range(0..=max))
+}
+
 // ============================================================================
 // Rate Limiter
 // ============================================================================
@@ -19,9 +130,12 @@ use tokio::sync::Mutex;
 /// Limits the rate of API calls using a token bucket algorithm:
 /// - Tokens accumulate at `refill_rate` per second up to `max_tokens`
 /// - Each `acquire()` consumes one token
-/// - If no tokens available, `acquire()` waits until one is available
+/// - If no tokens available, `acquire()` waits until one is available,
+///   plus a random jitter of up to `jitter` (none by default)
 pub struct RateLimiter {
     inner: Mutex<RateLimiterInner>,
+    jitter: Duration,
+    clock: Arc<dyn Clock>,
 }
 
 struct RateLimiterInner {
@@ -45,33 +159,72 @@ impl RateLimiter {
     /// * `rate_per_second` - Maximum requests per second (e.g., 1.0 for Slack)
     #[must_use]
     pub fn new(rate_per_second: f64) -> Self {
+        Self::with_burst(rate_per_second, rate_per_second)
+    }
+
+    /// Create a rate limiter whose bucket holds `burst` tokens.
+    ///
+    /// Implements: REQ-GOV-003/§5.3
+    #[must_use]
+    pub fn with_burst(rate_per_second: f64, burst: f64) -> Self {
         Self {
             inner: Mutex::new(RateLimiterInner {
-                tokens: rate_per_second, // Start with full bucket
-                max_tokens: rate_per_second,
+                tokens: burst, // Start with full bucket
+                max_tokens: burst,
                 refill_rate: rate_per_second,
                 last_refill: Instant::now(),
             }),
+            jitter: Duration::ZERO,
+            clock: SystemClock::shared(),
         }
     }
 
+    /// Read the time from `clock` instead of the system clock.
+    ///
+    /// Implements: REQ-GOV-003/§5.3
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.inner.get_mut().last_refill = clock.instant();
+        self.clock = clock;
+        self
+    }
+
+    /// Add a random delay of up to `jitter` to each wait for a token.
+    ///
+    /// Implements: REQ-GOV-003/§5.3
+    ///
+    /// Replicas sharing an upstream quota then desynchronize instead of all
+    /// calling the moment their buckets refill.
+    #[must_use]
+    pub fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+}
+
+impl RateLimiterInner {
+    /// Refill tokens based on the time elapsed until `now`.
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.last_refill);
+        self.tokens += elapsed.as_secs_f64() * self.refill_rate;
+        self.tokens = self.tokens.min(self.max_tokens);
+        self.last_refill = now;
+    }
+}
+
+#[async_trait]
+impl Limiter for RateLimiter {
     /// Acquire a token, waiting if necessary.
     ///
     /// Implements: REQ-GOV-003/§5.3
     ///
     /// This method will block (async) until a token is available.
     /// It is cancel-safe.
-    pub async fn acquire(&self) {
+    async fn acquire(&self) {
         loop {
             let wait_time = {
                 let mut inner = self.inner.lock().await;
-
-                // Refill tokens based on elapsed time
-                let now = Instant::now();
-                let elapsed = now.duration_since(inner.last_refill);
-                inner.tokens += elapsed.as_secs_f64() * inner.refill_rate;
-                inner.tokens = inner.tokens.min(inner.max_tokens);
-                inner.last_refill = now;
+                inner.refill(self.clock.instant());
 
                 // Try to acquire a token
                 if inner.tokens >= 1.0 {
@@ -81,27 +234,20 @@ impl RateLimiter {
 
                 // Calculate wait time for one token
                 let deficit = 1.0 - inner.tokens;
-                Duration::from_secs_f64(deficit / inner.refill_rate)
+                jittered(
+                    Duration::from_secs_f64(deficit / inner.refill_rate),
+                    self.jitter,
+                )
             };
 
             // Wait and retry
-            tokio::time::sleep(wait_time).await;
+            self.clock.sleep(wait_time).await;
         }
     }
 
-    /// Try to acquire a token without waiting.
-    ///
-    /// Returns `true` if a token was acquired, `false` otherwise.
-    #[must_use]
-    pub async fn try_acquire(&self) -> bool {
+    async fn try_acquire(&self) -> bool {
         let mut inner = self.inner.lock().await;
-
-        // Refill tokens based on elapsed time
-        let now = Instant::now();
-        let elapsed = now.duration_since(inner.last_refill);
-        inner.tokens += elapsed.as_secs_f64() * inner.refill_rate;
-        inner.tokens = inner.tokens.min(inner.max_tokens);
-        inner.last_refill = now;
+        inner.refill(self.clock.instant());
 
         // Try to acquire a token
         if inner.tokens >= 1.0 {
@@ -111,6 +257,107 @@ impl RateLimiter {
             false
         }
     }
+
+    async fn available(&self) -> u32 {
+        let mut inner = self.inner.lock().await;
+        inner.refill(self.clock.instant());
+        inner.tokens.floor() as u32
+    }
+}
+
+// ============================================================================
+// Sliding Window Limiter
+// ============================================================================
+
+/// Sliding window rate limiter.
+///
+/// Implements: REQ-GOV-003/§5.3
+///
+/// Keeps the time of each call in the last `window` and allows a call only
+/// while fewer than `max` remain, so no span of `window` ever sees more than
+/// `max` calls. Unlike [`RateLimiter`], a quiet period does not bank a burst
+/// on top of calls made just before it. Memory is bounded by `max`.
+pub struct SlidingWindowLimiter {
+    max: usize,
+    window: Duration,
+    jitter: Duration,
+    calls: Mutex<VecDeque<Instant>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl SlidingWindowLimiter {
+    /// Create a limiter allowing `max` calls (at least 1) per `window`.
+    ///
+    /// Implements: REQ-GOV-003/§5.3
+    #[must_use]
+    pub fn new(max: u32, window: Duration) -> Self {
+        let max = max.max(1) as usize;
+        Self {
+            max,
+            window,
+            jitter: Duration::ZERO,
+            calls: Mutex::new(VecDeque::with_capacity(max)),
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// Add a random delay of up to `jitter` to each wait for room.
+    #[must_use]
+    pub fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Read the time from `clock` instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Record a call now if the window has room; otherwise return how long
+    /// until the oldest call leaves it.
+    async fn try_record(&self) -> Result<(), Duration> {
+        let mut calls = self.calls.lock().await;
+        let now = self.clock.instant();
+        self.expire(&mut calls, now);
+
+        if calls.len() < self.max {
+            calls.push_back(now);
+            return Ok(());
+        }
+        let oldest = calls.front().copied().unwrap_or(now);
+        Err(self.window.saturating_sub(now.duration_since(oldest)))
+    }
+
+    /// Drop calls that have left the window.
+    fn expire(&self, calls: &mut VecDeque<Instant>, now: Instant) {
+        while calls
+            .front()
+            .is_some_and(|oldest| now.duration_since(*oldest) >= self.window)
+        {
+            calls.pop_front();
+        }
+    }
+}
+
+#[async_trait]
+impl Limiter for SlidingWindowLimiter {
+    async fn acquire(&self) {
+        while let Err(wait_time) = self.try_record().await {
+            self.clock.sleep(jittered(wait_time, self.jitter)).await;
+        }
+    }
+
+    async fn try_acquire(&self) -> bool {
+        self.try_record().await.is_ok()
+    }
+
+    async fn available(&self) -> u32 {
+        let mut calls = self.calls.lock().await;
+        self.expire(&mut calls, self.clock.instant());
+        (self.max - calls.len()) as u32
+    }
 }
 
 // ============================================================================
@@ -120,6 +367,7 @@ impl RateLimiter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::MockClock;
 
     /// Tests that the rate limiter allows burst up to capacity.
     ///
@@ -173,18 +421,148 @@ mod tests {
     /// Verifies: REQ-GOV-003/§5.3
     #[tokio::test]
     async fn test_token_refill() {
-        let limiter = RateLimiter::new(10.0); // 10 per second
+        let clock = MockClock::new();
+        let limiter = RateLimiter::new(10.0).with_clock(Arc::new(clock.clone())); // 10 per second
 
         // Drain the bucket
         for _ in 0..10 {
             limiter.acquire().await;
         }
+        assert_eq!(limiter.available().await, 0);
+
+        // 200ms refills exactly 2 tokens
+        clock.advance(Duration::from_millis(200));
+        assert!(limiter.try_acquire().await);
+        assert!(limiter.try_acquire().await);
+        assert!(!limiter.try_acquire().await);
+
+        // Refill stops at the bucket's capacity
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(limiter.available().await, 10);
+    }
+
+    /// Tests that acquire() waits on the limiter's clock for the next token.
+    ///
+    /// Verifies: REQ-GOV-003/§5.3
+    #[tokio::test]
+    async fn test_acquire_waits_for_clock() {
+        let clock = MockClock::new();
+        let limiter = RateLimiter::new(10.0).with_clock(Arc::new(clock.clone()));
+        for _ in 0..10 {
+            limiter.acquire().await;
+        }
+
+        let mut acquire = limiter.acquire();
+        assert!(futures::poll!(acquire.as_mut()).is_pending());
+        clock.advance(Duration::from_millis(50));
+        assert!(futures::poll!(acquire.as_mut()).is_pending());
+        clock.advance(Duration::from_millis(50));
+        assert!(futures::poll!(acquire.as_mut()).is_ready());
+    }
+
+    /// Tests that jitter never lets sustained throughput exceed the rate.
+    ///
+    /// Verifies: REQ-GOV-003/§5.3
+    #[tokio::test]
+    async fn test_jitter_keeps_throughput_within_rate() {
+        let limiter = RateLimiter::new(50.0).with_jitter(Duration::from_millis(10));
+
+        // Drain the burst, then 25 more calls need at least 500ms of refill
+        for _ in 0..50 {
+            limiter.acquire().await;
+        }
+        let start = Instant::now();
+        for _ in 0..25 {
+            limiter.acquire().await;
+        }
+        let elapsed = start.elapsed();
+
+        assert!(elapsed >= Duration::from_millis(480), "{elapsed:?}");
+        // At most 10ms of jitter per wait on top of the 500ms
+        assert!(elapsed < Duration::from_millis(1500), "{elapsed:?}");
+    }
+
+    /// Tests that the sliding window rejects a burst the token bucket allows.
+    ///
+    /// Both allow 10 calls per second. After 10 calls and a short pause the
+    /// bucket has refilled enough for more, but all 10 calls are still in
+    /// the window.
+    ///
+    /// Verifies: REQ-GOV-003/§5.3
+    #[tokio::test]
+    async fn test_sliding_window_rejects_burst_token_bucket_allows() {
+        let bucket = RateLimitAlgorithm::TokenBucket.build(10.0, Duration::ZERO);
+        let window = RateLimitAlgorithm::SlidingWindow {
+            window: Duration::from_secs(1),
+        }
+        .build(10.0, Duration::ZERO);
+
+        for limiter in [&bucket, &window] {
+            for _ in 0..10 {
+                assert!(limiter.try_acquire().await);
+            }
+        }
 
-        // Wait for refill
         tokio::time::sleep(Duration::from_millis(200)).await;
 
-        // Should have ~2 tokens now
+        assert!(bucket.try_acquire().await);
+        assert!(!window.try_acquire().await);
+    }
+
+    /// Tests that the sliding window frees capacity as calls age out.
+    ///
+    /// Verifies: REQ-GOV-003/§5.3
+    #[tokio::test]
+    async fn test_sliding_window_refills_after_window() {
+        let clock = MockClock::new();
+        let limiter = SlidingWindowLimiter::new(2, Duration::from_millis(100))
+            .with_clock(Arc::new(clock.clone()));
+
+        assert!(limiter.try_acquire().await);
+        clock.advance(Duration::from_millis(40));
         assert!(limiter.try_acquire().await);
+        assert!(!limiter.try_acquire().await);
+
+        // acquire() waits for the oldest call to leave the window
+        let mut acquire = limiter.acquire();
+        assert!(futures::poll!(acquire.as_mut()).is_pending());
+        clock.advance(Duration::from_millis(59));
+        assert!(futures::poll!(acquire.as_mut()).is_pending());
+        clock.advance(Duration::from_millis(1));
+        assert!(futures::poll!(acquire.as_mut()).is_ready());
+        drop(acquire);
+
+        // The second call leaves the window 40ms later
+        assert!(!limiter.try_acquire().await);
+        clock.advance(Duration::from_millis(40));
+
+        // Only the acquired call is left in the window, leaving room for one more
         assert!(limiter.try_acquire().await);
+        assert!(!limiter.try_acquire().await);
+    }
+
+    /// Tests that call sites behave the same whichever limiter is behind
+    /// the trait.
+    ///
+    /// Verifies: REQ-GOV-003/§5.3
+    #[tokio::test]
+    async fn test_limiter_implementations_are_interchangeable() {
+        async fn drain(limiter: Arc<dyn Limiter>) -> u32 {
+            let mut acquired = 0;
+            while limiter.try_acquire().await {
+                acquired += 1;
+            }
+            acquired
+        }
+
+        let limiters: [Arc<dyn Limiter>; 2] = [
+            Arc::new(RateLimiter::with_burst(1.0, 3.0)),
+            Arc::new(SlidingWindowLimiter::new(3, Duration::from_secs(1))),
+        ];
+        for limiter in limiters {
+            assert_eq!(limiter.available().await, 3);
+            assert_eq!(drain(limiter.clone()).await, 3);
+            assert_eq!(limiter.available().await, 0);
+        }
     }
 }
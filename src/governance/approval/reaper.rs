@@ -0,0 +1,326 @@
+//! Timeout reaper for pending approvals.
+//!
+//! Implements: REQ-GOV-003/F-004 (Decision Handling), REQ-GOV-001/F-008 (TTL Enforcement)
+//!
+//! The [`ApprovalReaper`] periodically rejects approvals that are past their
+//! timeout with [`RejectionReason::ApprovalTimeout`](super::store::RejectionReason)
+//! and expires their tasks, which wakes any handler holding the request
+//! open. Both the reaper and decision handlers go through the store's
+//! atomic transitions, so when an approval and a timeout race exactly one
+//! of them wins and the other observes the winner's state.
+
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+use super::store::{ApprovalStore, PendingApproval, StoreError};
+use crate::governance::{TaskId, TaskStore};
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Configuration for the approval reaper.
+///
+/// Implements: REQ-GOV-001/F-008
+#[derive(Debug, Clone)]
+pub struct ReaperConfig {
+    /// How often to scan for timed-out approvals
+    pub interval: Duration,
+}
+
+impl Default for ReaperConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl ReaperConfig {
+    /// Load configuration from environment variables.
+    ///
+    /// # Environment Variables
+    ///
+    /// - `THOUGHTGATE_APPROVAL_REAPER_INTERVAL_MS` - Scan interval (default: 1000)
+    #[must_use]
+    pub fn from_env() -> Self {
+        let interval = std::env::var("THOUGHTGATE_APPROVAL_REAPER_INTERVAL_MS")
+            .ok()
+            .and_then(|s| s.parse::<u64>().ok())
+            .filter(|ms| *ms > 0)
+            .map(Duration::from_millis)
+            .unwrap_or(Duration::from_secs(1));
+
+        Self { interval }
+    }
+}
+
+// ============================================================================
+// Reaper
+// ============================================================================
+
+/// Reason recorded on tasks whose approval timed out.
+pub const APPROVAL_TIMEOUT_REASON: &str = "Approval timed out";
+
+/// Expire the task of an approval that timed out.
+///
+/// Implements: REQ-GOV-001/F-008
+///
+/// Best-effort: a task that is not held here (another replica owns it) or
+/// has already finished is left alone.
+pub fn expire_task(task_store: &TaskStore, task_id: &TaskId) {
+    if let Err(e) = task_store.expire(task_id, APPROVAL_TIMEOUT_REASON) {
+        debug!(task_id = %task_id, error = %e, "Timed-out approval's task not expired");
+    }
+}
+
+/// Background task that rejects approvals past their timeout.
+///
+/// Implements: REQ-GOV-001/F-008
+pub struct ApprovalReaper {
+    store: Arc<dyn ApprovalStore>,
+    task_store: Arc<TaskStore>,
+    config: ReaperConfig,
+}
+
+impl ApprovalReaper {
+    /// Create a reaper over `store`, expiring timed-out tasks in `task_store`.
+    pub fn new(
+        store: Arc<dyn ApprovalStore>,
+        task_store: Arc<TaskStore>,
+        config: ReaperConfig,
+    ) -> Self {
+        Self {
+            store,
+            task_store,
+            config,
+        }
+    }
+
+    /// Run a single sweep, returning the approvals that timed out.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StoreError` if the store sweep fails.
+    pub async fn sweep(&self) -> Result<Vec<PendingApproval>, StoreError> {
+        let expired = self.store.expire(Utc::now()).await?;
+        for approval in &expired {
+            info!(
+                task_id = %approval.task_id,
+                tool = %approval.tool_name,
+                "Approval timed out, rejecting"
+            );
+            expire_task(&self.task_store, &approval.task_id);
+        }
+        Ok(expired)
+    }
+
+    /// Run sweeps every `interval` until `shutdown` is cancelled.
+    pub async fn run(&self, shutdown: CancellationToken) {
+        let mut interval = tokio::time::interval(self.config.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        debug!(
+            interval_ms = self.config.interval.as_millis() as u64,
+            "Approval reaper started"
+        );
+
+        loop {
+            tokio::select! {
+                biased;
+
+                _ = shutdown.cancelled() => break,
+
+                _ = interval.tick() => {
+                    if let Err(e) = self.sweep().await {
+                        warn!(error = %e, "Approval reaper sweep failed");
+                    }
+                }
+            }
+        }
+
+        debug!("Approval reaper stopped");
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::approval::PollDecision;
+    use crate::governance::approval::store::{
+        ApprovalState, InMemoryApprovalStore, RejectionReason, ResolveOutcome,
+    };
+    use crate::governance::{
+        JsonRpcId, Principal, TaskStatus, TaskStoreConfig, TimeoutAction, ToolCallRequest,
+    };
+
+    fn setup() -> (Arc<InMemoryApprovalStore>, Arc<TaskStore>, ApprovalReaper) {
+        let store = Arc::new(InMemoryApprovalStore::new());
+        let task_store = Arc::new(TaskStore::new(TaskStoreConfig {
+            max_pending_per_principal: 1000,
+            ..TaskStoreConfig::default()
+        }));
+        let reaper = ApprovalReaper::new(
+            store.clone(),
+            task_store.clone(),
+            ReaperConfig {
+                interval: Duration::from_millis(10),
+            },
+        );
+        (store, task_store, reaper)
+    }
+
+    fn pending(expires_in: chrono::Duration) -> PendingApproval {
+        let now = Utc::now();
+        PendingApproval::new(
+            TaskId::new(),
+            "delete_user",
+            "test-app",
+            now,
+            now + expires_in,
+        )
+    }
+
+    /// Create an InputRequired task and a pending approval for it.
+    fn pending_task(task_store: &TaskStore, expires_in: chrono::Duration) -> PendingApproval {
+        let request = ToolCallRequest {
+            method: "tools/call".to_string(),
+            name: "delete_user".to_string(),
+            arguments: serde_json::json!({}),
+            mcp_request_id: JsonRpcId::Null,
+        };
+        let task = task_store
+            .create(
+                request.clone(),
+                request,
+                Principal::new("test-app"),
+                None,
+                TimeoutAction::default(),
+            )
+            .unwrap();
+        task_store
+            .transition(&task.id, TaskStatus::InputRequired, None)
+            .unwrap();
+        PendingApproval {
+            task_id: task.id,
+            ..pending(expires_in)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reaper_rejects_timed_out_and_wakes_waiter() {
+        let (store, task_store, reaper) = setup();
+        let approval = pending_task(&task_store, chrono::Duration::milliseconds(50));
+        let task_id = approval.task_id.clone();
+        store.create_pending(approval).await.unwrap();
+
+        let shutdown = CancellationToken::new();
+        let reaper_shutdown = shutdown.clone();
+        let reaper_handle = tokio::spawn(async move { reaper.run(reaper_shutdown).await });
+
+        // A blocking-mode handler waits on the task, not the store
+        let task = task_store
+            .wait_for_decision(&task_id, Duration::from_secs(2))
+            .await
+            .expect("waiter woken before timeout");
+        assert_eq!(task.status, TaskStatus::Expired);
+
+        let resolved = store.get(&task_id).await.unwrap().unwrap();
+        assert!(matches!(
+            resolved.state,
+            ApprovalState::Rejected {
+                reason: RejectionReason::ApprovalTimeout,
+                ..
+            }
+        ));
+
+        shutdown.cancel();
+        reaper_handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sweep_leaves_decided_task() {
+        let (store, task_store, reaper) = setup();
+        let approval = pending_task(&task_store, chrono::Duration::zero());
+        let task_id = approval.task_id.clone();
+        store.create_pending(approval).await.unwrap();
+        store
+            .resolve(&task_id, PollDecision::Approved, "alice")
+            .await
+            .unwrap();
+
+        assert!(reaper.sweep().await.unwrap().is_empty());
+        assert_eq!(
+            task_store.get(&task_id).unwrap().status,
+            TaskStatus::InputRequired
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_approval_and_timeout_race_single_winner() {
+        let (store, task_store, reaper) = setup();
+        let reaper = Arc::new(reaper);
+
+        for _ in 0..200 {
+            // Already due, so the reaper and the approval genuinely race
+            let approval = pending_task(&task_store, chrono::Duration::zero());
+            let task_id = approval.task_id.clone();
+            store.create_pending(approval).await.unwrap();
+
+            let approve = {
+                let store = store.clone();
+                let task_id = task_id.clone();
+                tokio::spawn(async move {
+                    store
+                        .resolve(&task_id, PollDecision::Approved, "alice")
+                        .await
+                        .unwrap()
+                })
+            };
+            let sweep = {
+                let reaper = reaper.clone();
+                tokio::spawn(async move { reaper.sweep().await.unwrap() })
+            };
+
+            let outcome = approve.await.unwrap();
+            let swept = sweep.await.unwrap();
+            let reaped = swept.iter().any(|a| a.task_id == task_id);
+            let final_state = store.get(&task_id).await.unwrap().unwrap().state;
+            let task_status = task_store.get(&task_id).unwrap().status;
+
+            match outcome {
+                ResolveOutcome::Resolved(a) => {
+                    assert!(!reaped, "timeout must not override approval");
+                    assert!(matches!(a.state, ApprovalState::Approved { .. }));
+                    assert_eq!(final_state, a.state);
+                    assert_eq!(task_status, TaskStatus::InputRequired);
+                }
+                ResolveOutcome::AlreadyResolved(a) => {
+                    assert!(reaped, "approval lost only to the reaper");
+                    assert!(matches!(
+                        a.state,
+                        ApprovalState::Rejected {
+                            reason: RejectionReason::ApprovalTimeout,
+                            ..
+                        }
+                    ));
+                    assert_eq!(final_state, a.state);
+                    assert_eq!(task_status, TaskStatus::Expired);
+                }
+                other => panic!("unexpected outcome: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_reaper_config_default() {
+        assert_eq!(ReaperConfig::default().interval, Duration::from_secs(1));
+    }
+}
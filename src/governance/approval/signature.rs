@@ -0,0 +1,390 @@
+//! Approval callback signature verification.
+//!
+//! Implements: REQ-GOV-003/F-003 (Decision Authenticity)
+//!
+//! Slack signs every interaction callback with the app's signing secret
+//! using the `v0` scheme:
+//!
+//! ```text
+//! X-Slack-Signature = "v0=" + hex(HMAC-SHA256(secret, "v0:" + timestamp + ":" + body))
+//! ```
+//!
+//! Webhook events and callbacks are signed the same way with the shared
+//! webhook secret, under the `v1` prefix (`X-ThoughtGate-Signature`).
+//!
+//! A callback must pass [`verify_slack_signature`] or
+//! [`verify_webhook_signature`] before its payload is trusted to resolve an
+//! approval. Timestamps more than [`MAX_TIMESTAMP_SKEW`] away from the local
+//! clock are rejected to prevent replay of captured requests.
+
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Maximum allowed difference between the request timestamp and now.
+pub const MAX_TIMESTAMP_SKEW: Duration = Duration::from_secs(5 * 60);
+
+/// Signature scheme version prefix used by Slack.
+const SLACK_SIGNATURE_VERSION: &str = "v0";
+
+/// Signature scheme version prefix used by webhooks.
+const WEBHOOK_SIGNATURE_VERSION: &str = "v1";
+
+/// SHA-256 block size in bytes (HMAC key padding length).
+const SHA256_BLOCK_SIZE: usize = 64;
+
+/// Errors from callback signature verification.
+///
+/// Implements: REQ-GOV-003/F-003
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The signing secret is empty
+    #[error("Signing secret is not configured")]
+    MissingSecret,
+
+    /// The timestamp header is not a Unix timestamp
+    #[error("Invalid request timestamp")]
+    InvalidTimestamp,
+
+    /// Timestamp is outside the allowed window (possible replay)
+    #[error("Request timestamp is {skew_secs}s from local clock")]
+    StaleTimestamp {
+        /// Absolute difference from local clock in seconds
+        skew_secs: u64,
+    },
+
+    /// The signature header is not a `<version>=<hex>` value
+    #[error("Malformed signature header")]
+    MalformedSignature,
+
+    /// Signature does not match the request
+    #[error("Signature mismatch")]
+    Mismatch,
+}
+
+/// Verify a Slack interaction callback signature.
+///
+/// Implements: REQ-GOV-003/F-003
+///
+/// # Arguments
+///
+/// * `signing_secret` - The Slack app's signing secret
+/// * `timestamp_header` - Value of `X-Slack-Request-Timestamp`
+/// * `signature_header` - Value of `X-Slack-Signature`
+/// * `raw_body` - The request body exactly as received
+///
+/// # Errors
+///
+/// Returns `SignatureError` if the timestamp is invalid or stale, or the
+/// signature is malformed or does not match.
+pub fn verify_slack_signature(
+    signing_secret: &str,
+    timestamp_header: &str,
+    signature_header: &str,
+    raw_body: &[u8],
+) -> Result<(), SignatureError> {
+    verify_slack_signature_at(
+        signing_secret,
+        timestamp_header,
+        signature_header,
+        raw_body,
+        chrono::Utc::now().timestamp(),
+    )
+}
+
+/// Verify a webhook callback signature (`v1` scheme).
+///
+/// Implements: REQ-GOV-003/F-003
+///
+/// # Arguments
+///
+/// * `secret` - The shared webhook secret
+/// * `timestamp_header` - Value of `X-ThoughtGate-Timestamp`
+/// * `signature_header` - Value of `X-ThoughtGate-Signature`
+/// * `raw_body` - The request body exactly as received
+///
+/// # Errors
+///
+/// Returns `SignatureError` if the timestamp is invalid or stale, or the
+/// signature is malformed or does not match.
+pub fn verify_webhook_signature(
+    secret: &str,
+    timestamp_header: &str,
+    signature_header: &str,
+    raw_body: &[u8],
+) -> Result<(), SignatureError> {
+    verify_at(
+        WEBHOOK_SIGNATURE_VERSION,
+        secret,
+        timestamp_header,
+        signature_header,
+        raw_body,
+        chrono::Utc::now().timestamp(),
+    )
+}
+
+/// Sign a webhook body sent at `timestamp` (Unix seconds).
+///
+/// Returns the `X-ThoughtGate-Signature` value, `v1=<hex>`.
+#[must_use]
+pub fn sign_webhook(secret: &str, timestamp: i64, raw_body: &[u8]) -> String {
+    let mac = hmac_sha256(
+        secret.as_bytes(),
+        &signature_base(WEBHOOK_SIGNATURE_VERSION, &timestamp.to_string(), raw_body),
+    );
+    format!("{WEBHOOK_SIGNATURE_VERSION}={}", hex::encode(mac))
+}
+
+/// Verify a Slack signature against an explicit current time (Unix seconds).
+fn verify_slack_signature_at(
+    signing_secret: &str,
+    timestamp_header: &str,
+    signature_header: &str,
+    raw_body: &[u8],
+    now: i64,
+) -> Result<(), SignatureError> {
+    verify_at(
+        SLACK_SIGNATURE_VERSION,
+        signing_secret,
+        timestamp_header,
+        signature_header,
+        raw_body,
+        now,
+    )
+}
+
+/// Verify a `version`-scheme signature against an explicit current time.
+fn verify_at(
+    version: &str,
+    signing_secret: &str,
+    timestamp_header: &str,
+    signature_header: &str,
+    raw_body: &[u8],
+    now: i64,
+) -> Result<(), SignatureError> {
+    if signing_secret.is_empty() {
+        return Err(SignatureError::MissingSecret);
+    }
+
+    let timestamp: i64 = timestamp_header
+        .trim()
+        .parse()
+        .map_err(|_| SignatureError::InvalidTimestamp)?;
+    let skew_secs = now.abs_diff(timestamp);
+    if skew_secs > MAX_TIMESTAMP_SKEW.as_secs() {
+        return Err(SignatureError::StaleTimestamp { skew_secs });
+    }
+
+    let provided = signature_header
+        .trim()
+        .strip_prefix(version)
+        .and_then(|s| s.strip_prefix('='))
+        .and_then(|hex_sig| hex::decode(hex_sig).ok())
+        .ok_or(SignatureError::MalformedSignature)?;
+
+    let base = signature_base(version, timestamp_header.trim(), raw_body);
+    let expected = hmac_sha256(signing_secret.as_bytes(), &base);
+    if constant_time_eq(&expected, &provided) {
+        Ok(())
+    } else {
+        Err(SignatureError::Mismatch)
+    }
+}
+
+/// The signed string: `<version>:<timestamp>:<body>`.
+fn signature_base(version: &str, timestamp: &str, raw_body: &[u8]) -> Vec<u8> {
+    let mut base = Vec::with_capacity(raw_body.len() + 32);
+    base.extend_from_slice(version.as_bytes());
+    base.push(b':');
+    base.extend_from_slice(timestamp.as_bytes());
+    base.push(b':');
+    base.extend_from_slice(raw_body);
+    base
+}
+
+/// HMAC-SHA256 (RFC 2104).
+pub(crate) fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut block = [0u8; SHA256_BLOCK_SIZE];
+    if key.len() > SHA256_BLOCK_SIZE {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    inner.update(message);
+    let inner_hash = inner.finalize();
+
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner_hash);
+    outer.finalize().into()
+}
+
+/// Compare without early exit so timing does not reveal the match length.
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Example from Slack's "Verifying requests from Slack" documentation.
+    const SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+    const TIMESTAMP: &str = "1531420618";
+    const BODY: &[u8] = b"token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+    const SIGNATURE: &str = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+    const NOW: i64 = 1531420618 + 30;
+
+    fn verify(
+        secret: &str,
+        timestamp: &str,
+        signature: &str,
+        body: &[u8],
+    ) -> Result<(), SignatureError> {
+        verify_slack_signature_at(secret, timestamp, signature, body, NOW)
+    }
+
+    #[test]
+    fn test_hmac_sha256_rfc4231_case_2() {
+        let mac = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(
+            hex::encode(mac),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_known_good_signature() {
+        assert_eq!(verify(SECRET, TIMESTAMP, SIGNATURE, BODY), Ok(()));
+    }
+
+    #[test]
+    fn test_tampered_body_rejected() {
+        let mut body = BODY.to_vec();
+        body[6] ^= 0x01;
+        assert_eq!(
+            verify(SECRET, TIMESTAMP, SIGNATURE, &body),
+            Err(SignatureError::Mismatch)
+        );
+    }
+
+    #[test]
+    fn test_tampered_signature_rejected() {
+        let mut signature = SIGNATURE.to_string();
+        signature.replace_range(signature.len() - 1.., "4");
+        assert_eq!(
+            verify(SECRET, TIMESTAMP, &signature, BODY),
+            Err(SignatureError::Mismatch)
+        );
+    }
+
+    #[test]
+    fn test_wrong_secret_rejected() {
+        assert_eq!(
+            verify("not-the-secret", TIMESTAMP, SIGNATURE, BODY),
+            Err(SignatureError::Mismatch)
+        );
+        assert_eq!(
+            verify("", TIMESTAMP, SIGNATURE, BODY),
+            Err(SignatureError::MissingSecret)
+        );
+    }
+
+    #[test]
+    fn test_tampered_timestamp_rejected() {
+        // A different timestamp changes the base string
+        assert_eq!(
+            verify(SECRET, "1531420619", SIGNATURE, BODY),
+            Err(SignatureError::Mismatch)
+        );
+        assert_eq!(
+            verify(SECRET, "yesterday", SIGNATURE, BODY),
+            Err(SignatureError::InvalidTimestamp)
+        );
+    }
+
+    #[test]
+    fn test_stale_timestamp_rejected() {
+        let now = 1531420618 + MAX_TIMESTAMP_SKEW.as_secs() as i64 + 1;
+        assert_eq!(
+            verify_slack_signature_at(SECRET, TIMESTAMP, SIGNATURE, BODY, now),
+            Err(SignatureError::StaleTimestamp {
+                skew_secs: MAX_TIMESTAMP_SKEW.as_secs() + 1
+            })
+        );
+
+        // Future timestamps are bounded too
+        let now = 1531420618 - MAX_TIMESTAMP_SKEW.as_secs() as i64 - 1;
+        assert!(matches!(
+            verify_slack_signature_at(SECRET, TIMESTAMP, SIGNATURE, BODY, now),
+            Err(SignatureError::StaleTimestamp { .. })
+        ));
+    }
+
+    #[test]
+    fn test_malformed_signature_rejected() {
+        for signature in [
+            "",
+            "a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503",
+            "v1=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503",
+            "v0=not-hex",
+        ] {
+            assert_eq!(
+                verify(SECRET, TIMESTAMP, signature, BODY),
+                Err(SignatureError::MalformedSignature),
+                "{signature:?}"
+            );
+        }
+        // Truncated but valid hex
+        assert_eq!(
+            verify(SECRET, TIMESTAMP, "v0=a2114d57", BODY),
+            Err(SignatureError::Mismatch)
+        );
+    }
+
+    #[test]
+    fn test_webhook_signature_round_trip() {
+        let body = br#"{"task_id":"tg_x","decision":"approve"}"#;
+        let now = chrono::Utc::now().timestamp();
+        let signature = sign_webhook("secret", now, body);
+
+        assert!(signature.starts_with("v1="));
+        assert_eq!(
+            verify_webhook_signature("secret", &now.to_string(), &signature, body),
+            Ok(())
+        );
+        assert_eq!(
+            verify_webhook_signature("other", &now.to_string(), &signature, body),
+            Err(SignatureError::Mismatch)
+        );
+        // A Slack-scheme signature is not accepted for a webhook
+        let slack = signature.replacen("v1=", "v0=", 1);
+        assert_eq!(
+            verify_webhook_signature("secret", &now.to_string(), &slack, body),
+            Err(SignatureError::MalformedSignature)
+        );
+    }
+
+    #[test]
+    fn test_long_key_is_hashed() {
+        let key = [0xaa_u8; 131];
+        let mac = hmac_sha256(
+            &key,
+            b"Test Using Larger Than Block-Size Key - Hash Key First",
+        );
+        assert_eq!(
+            hex::encode(mac),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+}
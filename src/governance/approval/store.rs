@@ -0,0 +1,966 @@
+//! Approval store abstraction for pending approvals.
+//!
+//! Implements: REQ-GOV-003/F-002 (Pending Approval Tracking)
+//!
+//! The [`ApprovalStore`] trait decouples approval bookkeeping from where it
+//! lives. [`InMemoryApprovalStore`] is the default and loses state on
+//! restart; the Redis-backed store (feature `redis`) lets approvals survive
+//! restarts and be resolved by any replica that receives the decision.
+//!
+//! All backends guarantee first-writer-wins: once an approval leaves
+//! [`ApprovalState::Pending`] it never changes again, and later `resolve`
+//! or `expire` calls report the existing outcome instead of failing.
+//!
+//! Approvals may require several distinct approvers (`min_approvals`).
+//! Each approve is recorded once per approver; a single reject resolves
+//! the approval immediately.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use dashmap::mapref::entry::Entry;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use thiserror::Error;
+
+use super::{ApprovalRequest, PollDecision};
+use crate::governance::TaskId;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// A tracked approval and its current state.
+///
+/// Implements: REQ-GOV-003/F-002
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingApproval {
+    /// The task awaiting approval
+    pub task_id: TaskId,
+    /// Name of the tool being called
+    pub tool_name: String,
+    /// Application name of the requesting principal
+    pub principal: String,
+    /// When the approval was requested
+    pub created_at: DateTime<Utc>,
+    /// When the approval expires if undecided
+    pub expires_at: DateTime<Utc>,
+    /// Distinct approvers required to approve
+    #[serde(default = "default_min_approvals")]
+    pub min_approvals: u8,
+    /// Approvers who have approved so far
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub approvers: BTreeSet<String>,
+    /// Current state
+    pub state: ApprovalState,
+}
+
+fn default_min_approvals() -> u8 {
+    1
+}
+
+impl PendingApproval {
+    /// Create a new approval in the `Pending` state.
+    #[must_use]
+    pub fn new(
+        task_id: TaskId,
+        tool_name: impl Into<String>,
+        principal: impl Into<String>,
+        created_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            task_id,
+            tool_name: tool_name.into(),
+            principal: principal.into(),
+            created_at,
+            expires_at,
+            min_approvals: 1,
+            approvers: BTreeSet::new(),
+            state: ApprovalState::Pending,
+        }
+    }
+
+    /// Create a pending approval for a posted approval request.
+    #[must_use]
+    pub fn for_request(request: &ApprovalRequest) -> Self {
+        Self::new(
+            request.task_id.clone(),
+            request.tool_name.clone(),
+            request.principal.app_name.clone(),
+            request.created_at,
+            request.expires_at,
+        )
+        .with_min_approvals(request.min_approvals)
+    }
+
+    /// Require `min_approvals` distinct approvers (at least one).
+    #[must_use]
+    pub fn with_min_approvals(mut self, min_approvals: u8) -> Self {
+        self.min_approvals = min_approvals.max(1);
+        self
+    }
+
+    /// Returns true if the approval is still awaiting a decision.
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        matches!(self.state, ApprovalState::Pending)
+    }
+}
+
+/// State of a tracked approval.
+///
+/// Implements: REQ-GOV-003/F-002
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum ApprovalState {
+    /// Awaiting a decision
+    Pending,
+    /// An approver approved the request
+    Approved {
+        /// Who approved
+        decided_by: String,
+        /// When the approval was recorded
+        decided_at: DateTime<Utc>,
+    },
+    /// The request was rejected by an approver or by timeout
+    Rejected {
+        /// Why the request was rejected
+        reason: RejectionReason,
+        /// Who rejected (`SYSTEM_DECIDER` for timeouts)
+        decided_by: String,
+        /// When the rejection was recorded
+        decided_at: DateTime<Utc>,
+    },
+}
+
+impl ApprovalState {
+    /// Terminal state for an approver's decision.
+    #[must_use]
+    pub fn from_decision(decision: PollDecision, decided_by: &str, now: DateTime<Utc>) -> Self {
+        match decision {
+            PollDecision::Approved => Self::Approved {
+                decided_by: decided_by.to_string(),
+                decided_at: now,
+            },
+            PollDecision::Rejected => Self::Rejected {
+                reason: RejectionReason::Denied,
+                decided_by: decided_by.to_string(),
+                decided_at: now,
+            },
+        }
+    }
+
+    /// Terminal approved state once enough approvers have approved.
+    #[must_use]
+    pub fn approved_by(approvers: &BTreeSet<String>, now: DateTime<Utc>) -> Self {
+        Self::Approved {
+            decided_by: approvers
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", "),
+            decided_at: now,
+        }
+    }
+
+    /// Terminal state for an approval that timed out.
+    #[must_use]
+    pub fn timed_out(now: DateTime<Utc>) -> Self {
+        Self::Rejected {
+            reason: RejectionReason::ApprovalTimeout,
+            decided_by: SYSTEM_DECIDER.to_string(),
+            decided_at: now,
+        }
+    }
+}
+
+/// Identity recorded as `decided_by` for system-initiated rejections.
+pub const SYSTEM_DECIDER: &str = "thoughtgate";
+
+/// Why an approval was rejected.
+///
+/// Implements: REQ-GOV-003/F-004
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RejectionReason {
+    /// An approver rejected the request
+    Denied,
+    /// No decision arrived before the approval timeout
+    ApprovalTimeout,
+}
+
+/// Result of a [`ApprovalStore::resolve`] call.
+///
+/// Implements: REQ-GOV-003/F-002
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolveOutcome {
+    /// This call recorded the decision
+    Resolved(PendingApproval),
+    /// The approve was counted but more approvers are required
+    Recorded(PendingApproval),
+    /// This approver had already approved; nothing changed
+    DuplicateApprover(PendingApproval),
+    /// The approval had already left `Pending`; it is returned unchanged
+    AlreadyResolved(PendingApproval),
+    /// No approval exists for the task
+    NotFound,
+}
+
+/// Errors from approval store backends.
+///
+/// Implements: REQ-GOV-003/§6.5
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum StoreError {
+    /// An approval for this task is already tracked
+    #[error("Approval already exists for task {task_id}")]
+    AlreadyExists {
+        /// The duplicate task ID
+        task_id: TaskId,
+    },
+
+    /// The backend could not be reached or returned an error
+    #[error("Approval store backend error: {details}")]
+    Backend {
+        /// Backend error details
+        details: String,
+    },
+}
+
+// ============================================================================
+// Store Trait
+// ============================================================================
+
+/// Storage for pending approvals.
+///
+/// Implements: REQ-GOV-003/F-002
+///
+/// Implementations must make `resolve` and `expire` atomic per task so a
+/// decision and an expiry racing on the same approval produce exactly one
+/// terminal state.
+#[async_trait]
+pub trait ApprovalStore: Send + Sync {
+    /// Start tracking a pending approval.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StoreError::AlreadyExists` if the task is already tracked.
+    async fn create_pending(&self, approval: PendingApproval) -> Result<(), StoreError>;
+
+    /// Record a decision for a pending approval.
+    ///
+    /// A reject resolves immediately. An approve adds `decided_by` to the
+    /// approver set and resolves once `min_approvals` distinct approvers
+    /// have approved; until then it returns `Recorded`, or
+    /// `DuplicateApprover` if `decided_by` already approved.
+    ///
+    /// Idempotent: resolving an unknown or already-resolved task returns
+    /// `NotFound` or `AlreadyResolved` rather than an error.
+    async fn resolve(
+        &self,
+        task_id: &TaskId,
+        decision: PollDecision,
+        decided_by: &str,
+    ) -> Result<ResolveOutcome, StoreError>;
+
+    /// Look up an approval by task ID.
+    async fn get(&self, task_id: &TaskId) -> Result<Option<PendingApproval>, StoreError>;
+
+    /// Reject every pending approval whose `expires_at` is at or before `now`
+    /// with [`RejectionReason::ApprovalTimeout`].
+    ///
+    /// Returns the approvals this call transitioned.
+    async fn expire(&self, now: DateTime<Utc>) -> Result<Vec<PendingApproval>, StoreError>;
+}
+
+// ============================================================================
+// In-Memory Store
+// ============================================================================
+
+/// In-memory approval store.
+///
+/// Implements: REQ-GOV-003/F-002
+///
+/// Per-task atomicity comes from DashMap's shard locks. State is lost on
+/// restart and is not shared between replicas.
+#[derive(Debug, Default)]
+pub struct InMemoryApprovalStore {
+    approvals: DashMap<TaskId, PendingApproval>,
+}
+
+impl InMemoryApprovalStore {
+    /// Create an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of tracked approvals in any state.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.approvals.len()
+    }
+
+    /// Returns true if no approvals are tracked.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.approvals.is_empty()
+    }
+}
+
+#[async_trait]
+impl ApprovalStore for InMemoryApprovalStore {
+    async fn create_pending(&self, approval: PendingApproval) -> Result<(), StoreError> {
+        match self.approvals.entry(approval.task_id.clone()) {
+            Entry::Occupied(_) => Err(StoreError::AlreadyExists {
+                task_id: approval.task_id,
+            }),
+            Entry::Vacant(slot) => {
+                slot.insert(approval);
+                Ok(())
+            }
+        }
+    }
+
+    async fn resolve(
+        &self,
+        task_id: &TaskId,
+        decision: PollDecision,
+        decided_by: &str,
+    ) -> Result<ResolveOutcome, StoreError> {
+        let Some(mut approval) = self.approvals.get_mut(task_id) else {
+            return Ok(ResolveOutcome::NotFound);
+        };
+        if !approval.is_pending() {
+            return Ok(ResolveOutcome::AlreadyResolved(approval.clone()));
+        }
+        if decision == PollDecision::Rejected {
+            approval.state = ApprovalState::from_decision(decision, decided_by, Utc::now());
+            return Ok(ResolveOutcome::Resolved(approval.clone()));
+        }
+        if !approval.approvers.insert(decided_by.to_string()) {
+            return Ok(ResolveOutcome::DuplicateApprover(approval.clone()));
+        }
+        if approval.approvers.len() < usize::from(approval.min_approvals) {
+            return Ok(ResolveOutcome::Recorded(approval.clone()));
+        }
+        approval.state = ApprovalState::approved_by(&approval.approvers, Utc::now());
+        Ok(ResolveOutcome::Resolved(approval.clone()))
+    }
+
+    async fn get(&self, task_id: &TaskId) -> Result<Option<PendingApproval>, StoreError> {
+        Ok(self.approvals.get(task_id).map(|a| a.clone()))
+    }
+
+    async fn expire(&self, now: DateTime<Utc>) -> Result<Vec<PendingApproval>, StoreError> {
+        let mut expired = Vec::new();
+        for mut approval in self.approvals.iter_mut() {
+            if approval.is_pending() && approval.expires_at <= now {
+                approval.state = ApprovalState::timed_out(now);
+                expired.push(approval.clone());
+            }
+        }
+        Ok(expired)
+    }
+}
+
+// ============================================================================
+// Redis Store
+// ============================================================================
+
+#[cfg(feature = "redis")]
+pub use redis_store::RedisApprovalStore;
+
+#[cfg(feature = "redis")]
+mod redis_store {
+    use super::*;
+    use redis::AsyncCommands;
+
+    /// Lua script: set a pending approval to the given terminal state.
+    ///
+    /// KEYS[1] = approval key, KEYS[2] = expiry index
+    /// ARGV[1] = new state JSON, ARGV[2] = task ID
+    ///
+    /// Returns `{status, approval_json}` where status is `resolved`,
+    /// `already` or `missing`.
+    const TRANSITION_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if not current then
+  return {'missing', ''}
+end
+local approval = cjson.decode(current)
+if approval['state']['state'] ~= 'pending' then
+  return {'already', current}
+end
+approval['state'] = cjson.decode(ARGV[1])
+local updated = cjson.encode(approval)
+redis.call('SET', KEYS[1], updated, 'KEEPTTL')
+redis.call('ZREM', KEYS[2], ARGV[2])
+return {'resolved', updated}
+"#;
+
+    /// Lua script: record an approve vote on a pending approval.
+    ///
+    /// KEYS[1] = approval key, KEYS[2] = expiry index
+    /// ARGV[1] = approver, ARGV[2] = decided_at JSON, ARGV[3] = task ID
+    ///
+    /// Returns `{status, approval_json}` where status is `resolved`,
+    /// `recorded`, `duplicate`, `already` or `missing`.
+    const VOTE_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if not current then
+  return {'missing', ''}
+end
+local approval = cjson.decode(current)
+if approval['state']['state'] ~= 'pending' then
+  return {'already', current}
+end
+local approvers = approval['approvers'] or {}
+for _, approver in ipairs(approvers) do
+  if approver == ARGV[1] then
+    return {'duplicate', current}
+  end
+end
+table.insert(approvers, ARGV[1])
+table.sort(approvers)
+approval['approvers'] = approvers
+local status = 'recorded'
+if #approvers >= (approval['min_approvals'] or 1) then
+  approval['state'] = {
+    state = 'approved',
+    decided_by = table.concat(approvers, ', '),
+    decided_at = cjson.decode(ARGV[2]),
+  }
+  redis.call('ZREM', KEYS[2], ARGV[3])
+  status = 'resolved'
+end
+local updated = cjson.encode(approval)
+redis.call('SET', KEYS[1], updated, 'KEEPTTL')
+return {status, updated}
+"#;
+
+    /// How long terminal approvals are kept after expiry for idempotent lookups.
+    const RETENTION_SECS: i64 = 24 * 60 * 60;
+
+    /// Redis-backed approval store.
+    ///
+    /// Implements: REQ-GOV-003/F-002
+    ///
+    /// Each approval is a JSON value at `{prefix}:approval:{task_id}`; pending
+    /// approvals are indexed by expiry in the sorted set `{prefix}:expiry`.
+    /// State transitions run as a Lua script so they are atomic across
+    /// replicas.
+    #[derive(Clone)]
+    pub struct RedisApprovalStore {
+        conn: redis::aio::ConnectionManager,
+        prefix: String,
+        transition: redis::Script,
+        vote: redis::Script,
+    }
+
+    impl RedisApprovalStore {
+        /// Connect to Redis at `url` using `prefix` for all keys.
+        ///
+        /// # Errors
+        ///
+        /// Returns `StoreError::Backend` if the connection fails.
+        pub async fn connect(url: &str, prefix: impl Into<String>) -> Result<Self, StoreError> {
+            let client = redis::Client::open(url).map_err(backend)?;
+            let conn = redis::aio::ConnectionManager::new(client)
+                .await
+                .map_err(backend)?;
+            Ok(Self {
+                conn,
+                prefix: prefix.into(),
+                transition: redis::Script::new(TRANSITION_SCRIPT),
+                vote: redis::Script::new(VOTE_SCRIPT),
+            })
+        }
+
+        fn approval_key(&self, task_id: &TaskId) -> String {
+            format!("{}:approval:{}", self.prefix, task_id)
+        }
+
+        fn expiry_key(&self) -> String {
+            format!("{}:expiry", self.prefix)
+        }
+
+        async fn transition(
+            &self,
+            task_id: &TaskId,
+            state: &ApprovalState,
+        ) -> Result<ResolveOutcome, StoreError> {
+            let state_json = serde_json::to_string(state).map_err(backend)?;
+            let mut conn = self.conn.clone();
+            let (status, approval): (String, String) = self
+                .transition
+                .key(self.approval_key(task_id))
+                .key(self.expiry_key())
+                .arg(state_json)
+                .arg(task_id.as_str())
+                .invoke_async(&mut conn)
+                .await
+                .map_err(backend)?;
+
+            outcome(&status, &approval)
+        }
+
+        async fn vote(
+            &self,
+            task_id: &TaskId,
+            approver: &str,
+        ) -> Result<ResolveOutcome, StoreError> {
+            let decided_at = serde_json::to_string(&Utc::now()).map_err(backend)?;
+            let mut conn = self.conn.clone();
+            let (status, approval): (String, String) = self
+                .vote
+                .key(self.approval_key(task_id))
+                .key(self.expiry_key())
+                .arg(approver)
+                .arg(decided_at)
+                .arg(task_id.as_str())
+                .invoke_async(&mut conn)
+                .await
+                .map_err(backend)?;
+
+            outcome(&status, &approval)
+        }
+    }
+
+    #[async_trait]
+    impl ApprovalStore for RedisApprovalStore {
+        async fn create_pending(&self, approval: PendingApproval) -> Result<(), StoreError> {
+            let json = serde_json::to_string(&approval).map_err(backend)?;
+            let ttl = (approval.expires_at - Utc::now()).num_seconds().max(0) + RETENTION_SECS;
+            let mut conn = self.conn.clone();
+
+            let created: Option<String> = redis::cmd("SET")
+                .arg(self.approval_key(&approval.task_id))
+                .arg(json)
+                .arg("NX")
+                .arg("EX")
+                .arg(ttl)
+                .query_async(&mut conn)
+                .await
+                .map_err(backend)?;
+            if created.is_none() {
+                return Err(StoreError::AlreadyExists {
+                    task_id: approval.task_id,
+                });
+            }
+
+            let _: () = conn
+                .zadd(
+                    self.expiry_key(),
+                    approval.task_id.as_str(),
+                    approval.expires_at.timestamp_millis(),
+                )
+                .await
+                .map_err(backend)?;
+            Ok(())
+        }
+
+        async fn resolve(
+            &self,
+            task_id: &TaskId,
+            decision: PollDecision,
+            decided_by: &str,
+        ) -> Result<ResolveOutcome, StoreError> {
+            match decision {
+                PollDecision::Approved => self.vote(task_id, decided_by).await,
+                PollDecision::Rejected => {
+                    let state = ApprovalState::from_decision(decision, decided_by, Utc::now());
+                    self.transition(task_id, &state).await
+                }
+            }
+        }
+
+        async fn get(&self, task_id: &TaskId) -> Result<Option<PendingApproval>, StoreError> {
+            let mut conn = self.conn.clone();
+            let json: Option<String> = conn
+                .get(self.approval_key(task_id))
+                .await
+                .map_err(backend)?;
+            json.as_deref().map(decode).transpose()
+        }
+
+        async fn expire(&self, now: DateTime<Utc>) -> Result<Vec<PendingApproval>, StoreError> {
+            let mut conn = self.conn.clone();
+            let due: Vec<String> = conn
+                .zrangebyscore(self.expiry_key(), "-inf", now.timestamp_millis())
+                .await
+                .map_err(backend)?;
+
+            let state = ApprovalState::timed_out(now);
+            let mut expired = Vec::new();
+            for task_id in due {
+                if let ResolveOutcome::Resolved(approval) =
+                    self.transition(&TaskId::from_raw(task_id), &state).await?
+                {
+                    expired.push(approval);
+                }
+            }
+            Ok(expired)
+        }
+    }
+
+    fn outcome(status: &str, approval: &str) -> Result<ResolveOutcome, StoreError> {
+        match status {
+            "missing" => Ok(ResolveOutcome::NotFound),
+            "already" => Ok(ResolveOutcome::AlreadyResolved(decode(approval)?)),
+            "recorded" => Ok(ResolveOutcome::Recorded(decode(approval)?)),
+            "duplicate" => Ok(ResolveOutcome::DuplicateApprover(decode(approval)?)),
+            _ => Ok(ResolveOutcome::Resolved(decode(approval)?)),
+        }
+    }
+
+    fn decode(json: &str) -> Result<PendingApproval, StoreError> {
+        serde_json::from_str(json).map_err(backend)
+    }
+
+    fn backend(e: impl std::fmt::Display) -> StoreError {
+        StoreError::Backend {
+            details: e.to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// Global Store
+// ============================================================================
+
+static APPROVAL_STORE: once_cell::sync::OnceCell<Arc<dyn ApprovalStore>> =
+    once_cell::sync::OnceCell::new();
+
+/// Install the global approval store (first call wins).
+pub fn init_approval_store(store: Arc<dyn ApprovalStore>) {
+    let _ = APPROVAL_STORE.set(store);
+}
+
+/// Get the global approval store, or a fresh in-memory store if none was
+/// installed.
+#[must_use]
+pub fn get_approval_store() -> Arc<dyn ApprovalStore> {
+    APPROVAL_STORE
+        .get()
+        .cloned()
+        .unwrap_or_else(|| Arc::new(InMemoryApprovalStore::new()))
+}
+
+/// Build the shared approval store selected by the environment.
+///
+/// Implements: REQ-GOV-003/F-002
+///
+/// # Environment Variables
+///
+/// - `THOUGHTGATE_APPROVAL_REDIS_URL` - Redis URL; unset keeps approvals in memory
+/// - `THOUGHTGATE_APPROVAL_REDIS_PREFIX` - Key prefix (default: "thoughtgate")
+///
+/// # Errors
+///
+/// Returns `StoreError::Backend` if Redis is configured but cannot be
+/// reached, or this build lacks the `redis` feature.
+pub async fn approval_store_from_env() -> Result<Option<Arc<dyn ApprovalStore>>, StoreError> {
+    let url = match std::env::var("THOUGHTGATE_APPROVAL_REDIS_URL") {
+        Ok(url) if !url.is_empty() => url,
+        _ => return Ok(None),
+    };
+
+    #[cfg(feature = "redis")]
+    {
+        let prefix = std::env::var("THOUGHTGATE_APPROVAL_REDIS_PREFIX")
+            .ok()
+            .filter(|p| !p.is_empty())
+            .unwrap_or_else(|| "thoughtgate".to_string());
+        let store = RedisApprovalStore::connect(&url, prefix).await?;
+        Ok(Some(Arc::new(store)))
+    }
+
+    #[cfg(not(feature = "redis"))]
+    {
+        let _ = url;
+        Err(StoreError::Backend {
+            details:
+                "THOUGHTGATE_APPROVAL_REDIS_URL is set but this build lacks the `redis` feature"
+                    .to_string(),
+        })
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    fn pending(expires_in: chrono::Duration) -> PendingApproval {
+        let now = Utc::now();
+        PendingApproval::new(
+            TaskId::new(),
+            "delete_user",
+            "test-app",
+            now,
+            now + expires_in,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_create_and_get() {
+        let store = InMemoryApprovalStore::new();
+        let approval = pending(chrono::Duration::minutes(5));
+        let task_id = approval.task_id.clone();
+
+        store.create_pending(approval.clone()).await.unwrap();
+        assert_eq!(store.get(&task_id).await.unwrap(), Some(approval));
+        assert_eq!(store.get(&TaskId::new()).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_create_duplicate_rejected() {
+        let store = InMemoryApprovalStore::new();
+        let approval = pending(chrono::Duration::minutes(5));
+
+        store.create_pending(approval.clone()).await.unwrap();
+        assert!(matches!(
+            store.create_pending(approval).await,
+            Err(StoreError::AlreadyExists { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_is_idempotent() {
+        let store = InMemoryApprovalStore::new();
+        let approval = pending(chrono::Duration::minutes(5));
+        let task_id = approval.task_id.clone();
+        store.create_pending(approval).await.unwrap();
+
+        let first = store
+            .resolve(&task_id, PollDecision::Approved, "alice")
+            .await
+            .unwrap();
+        let ResolveOutcome::Resolved(resolved) = first else {
+            panic!("expected Resolved, got {first:?}");
+        };
+
+        // A conflicting second decision does not change the outcome
+        let second = store
+            .resolve(&task_id, PollDecision::Rejected, "bob")
+            .await
+            .unwrap();
+        assert_eq!(second, ResolveOutcome::AlreadyResolved(resolved));
+
+        assert_eq!(
+            store
+                .resolve(&TaskId::new(), PollDecision::Approved, "alice")
+                .await
+                .unwrap(),
+            ResolveOutcome::NotFound
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_resolve_single_winner() {
+        let store = Arc::new(InMemoryApprovalStore::new());
+        let approval = pending(chrono::Duration::minutes(5));
+        let task_id = approval.task_id.clone();
+        store.create_pending(approval).await.unwrap();
+
+        let handles: Vec<_> = (0..32)
+            .map(|i| {
+                let store = store.clone();
+                let task_id = task_id.clone();
+                tokio::spawn(async move {
+                    let decision = if i % 2 == 0 {
+                        PollDecision::Approved
+                    } else {
+                        PollDecision::Rejected
+                    };
+                    store
+                        .resolve(&task_id, decision, &format!("user-{i}"))
+                        .await
+                        .unwrap()
+                })
+            })
+            .collect();
+
+        let mut winners = Vec::new();
+        let mut losers = Vec::new();
+        for handle in handles {
+            match handle.await.unwrap() {
+                ResolveOutcome::Resolved(a) => winners.push(a),
+                ResolveOutcome::AlreadyResolved(a) => losers.push(a),
+                ResolveOutcome::NotFound => panic!("task should exist"),
+                other => panic!("single-approver workflow should not record: {other:?}"),
+            }
+        }
+
+        assert_eq!(winners.len(), 1);
+        assert_eq!(losers.len(), 31);
+        // Every loser observes the winner's decision
+        assert!(losers.iter().all(|a| a.state == winners[0].state));
+        assert_eq!(
+            store.get(&task_id).await.unwrap().map(|a| a.state),
+            Some(winners[0].state.clone())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expire_only_overdue_pending() {
+        let store = InMemoryApprovalStore::new();
+        let overdue = pending(chrono::Duration::seconds(-1));
+        let fresh = pending(chrono::Duration::minutes(5));
+        let decided = pending(chrono::Duration::seconds(-1));
+        let (overdue_id, fresh_id, decided_id) = (
+            overdue.task_id.clone(),
+            fresh.task_id.clone(),
+            decided.task_id.clone(),
+        );
+        store.create_pending(overdue).await.unwrap();
+        store.create_pending(fresh).await.unwrap();
+        store.create_pending(decided).await.unwrap();
+        store
+            .resolve(&decided_id, PollDecision::Approved, "alice")
+            .await
+            .unwrap();
+
+        let expired = store.expire(Utc::now()).await.unwrap();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].task_id, overdue_id);
+
+        // Expiry is terminal: a late decision is reported, not applied
+        assert!(matches!(
+            store
+                .resolve(&overdue_id, PollDecision::Approved, "alice")
+                .await
+                .unwrap(),
+            ResolveOutcome::AlreadyResolved(PendingApproval {
+                state: ApprovalState::Rejected {
+                    reason: RejectionReason::ApprovalTimeout,
+                    ..
+                },
+                ..
+            })
+        ));
+        assert!(store.get(&fresh_id).await.unwrap().unwrap().is_pending());
+        assert!(store.expire(Utc::now()).await.unwrap().is_empty());
+    }
+
+    fn pending_two_of_n() -> PendingApproval {
+        pending(chrono::Duration::minutes(5)).with_min_approvals(2)
+    }
+
+    #[tokio::test]
+    async fn test_two_of_n_approval() {
+        let store = InMemoryApprovalStore::new();
+        let approval = pending_two_of_n();
+        let task_id = approval.task_id.clone();
+        store.create_pending(approval).await.unwrap();
+
+        let first = store
+            .resolve(&task_id, PollDecision::Approved, "bob")
+            .await
+            .unwrap();
+        let ResolveOutcome::Recorded(recorded) = first else {
+            panic!("expected Recorded, got {first:?}");
+        };
+        assert!(recorded.is_pending());
+        assert_eq!(recorded.approvers.len(), 1);
+
+        let second = store
+            .resolve(&task_id, PollDecision::Approved, "alice")
+            .await
+            .unwrap();
+        let ResolveOutcome::Resolved(resolved) = second else {
+            panic!("expected Resolved, got {second:?}");
+        };
+        assert!(matches!(
+            resolved.state,
+            ApprovalState::Approved { ref decided_by, .. } if decided_by == "alice, bob"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_single_deny_overrides_partial_approval() {
+        let store = InMemoryApprovalStore::new();
+        let approval = pending_two_of_n();
+        let task_id = approval.task_id.clone();
+        store.create_pending(approval).await.unwrap();
+
+        store
+            .resolve(&task_id, PollDecision::Approved, "alice")
+            .await
+            .unwrap();
+        let denied = store
+            .resolve(&task_id, PollDecision::Rejected, "bob")
+            .await
+            .unwrap();
+        assert!(matches!(
+            denied,
+            ResolveOutcome::Resolved(PendingApproval {
+                state: ApprovalState::Rejected {
+                    reason: RejectionReason::Denied,
+                    ..
+                },
+                ..
+            })
+        ));
+
+        // Later approvals cannot revive it
+        assert!(matches!(
+            store
+                .resolve(&task_id, PollDecision::Approved, "carol")
+                .await
+                .unwrap(),
+            ResolveOutcome::AlreadyResolved(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_approver_not_counted_twice() {
+        let store = InMemoryApprovalStore::new();
+        let approval = pending_two_of_n();
+        let task_id = approval.task_id.clone();
+        store.create_pending(approval).await.unwrap();
+
+        store
+            .resolve(&task_id, PollDecision::Approved, "alice")
+            .await
+            .unwrap();
+        let again = store
+            .resolve(&task_id, PollDecision::Approved, "alice")
+            .await
+            .unwrap();
+        let ResolveOutcome::DuplicateApprover(current) = again else {
+            panic!("expected DuplicateApprover, got {again:?}");
+        };
+        assert!(current.is_pending());
+        assert_eq!(current.approvers.len(), 1);
+        assert!(store.get(&task_id).await.unwrap().unwrap().is_pending());
+    }
+
+    #[test]
+    fn test_min_approvals_at_least_one() {
+        assert_eq!(
+            pending(chrono::Duration::minutes(5))
+                .with_min_approvals(0)
+                .min_approvals,
+            1
+        );
+    }
+
+    #[test]
+    fn test_state_serialization() {
+        let json = serde_json::to_value(ApprovalState::Pending).unwrap();
+        assert_eq!(json, serde_json::json!({"state": "pending"}));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_store_from_env_defaults_to_memory() {
+        unsafe { std::env::remove_var("THOUGHTGATE_APPROVAL_REDIS_URL") };
+        assert!(approval_store_from_env().await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,630 @@
+//! Generic HTTP webhook adapter for approval workflows.
+//!
+//! Implements: REQ-GOV-003/F-001, REQ-GOV-003/F-003
+//!
+//! Approval requests are POSTed as signed JSON events to a receiver the
+//! operator controls (a ticketing bridge, an internal approvals service).
+//! The receiver reports the decision by POSTing a signed callback to
+//! ThoughtGate's callback endpoint; nothing is polled.
+//!
+//! ## Events
+//!
+//! Every event body carries `"event"`: `approval_requested`,
+//! `approval_resolved` or `approval_cancelled`, plus the `task_id`.
+//!
+//! ## Callback
+//!
+//! ```json
+//! {"task_id": "tg_...", "decision": "approve", "decided_by": "alice"}
+//! ```
+//!
+//! `decision` is `approve` or `reject`. Each callback is one approver's
+//! vote: when the request event carries `min_approvals` above 1, the
+//! task is approved once that many distinct `decided_by` values have
+//! approved, while a single reject settles it.
+//!
+//! ## Security
+//!
+//! - Events and callbacks are both signed with the shared secret
+//!   (`X-ThoughtGate-Timestamp`, `X-ThoughtGate-Signature`, `v1` scheme)
+//! - The secret and auth token are NEVER logged
+
+use super::signature::{sign_webhook, verify_webhook_signature};
+use super::{
+    AdapterError, ApprovalAdapter, ApprovalReference, ApprovalRequest, CallbackError,
+    DecisionMethod, PollDecision, PollResult,
+};
+use crate::config::{ApprovalDestination, WebhookAuth};
+use crate::governance::TaskId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::Deserialize;
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, warn};
+
+/// Header carrying the Unix timestamp a webhook body was signed at.
+pub const TIMESTAMP_HEADER: &str = "x-thoughtgate-timestamp";
+
+/// Header carrying the `v1=<hex>` webhook signature.
+pub const SIGNATURE_HEADER: &str = "x-thoughtgate-signature";
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Configuration for the webhook adapter.
+///
+/// Implements: REQ-GOV-003/F-001
+#[derive(Clone)]
+pub struct WebhookConfig {
+    /// Receiver URL that approval events are POSTed to
+    pub url: String,
+    /// Shared secret signing events and callbacks
+    pub secret: String,
+    /// Callback URL advertised to the receiver in each request event
+    pub callback_url: Option<String>,
+    /// Full `Authorization` header value sent with events
+    pub authorization: Option<String>,
+    /// HTTP timeout for event delivery
+    pub timeout: Duration,
+}
+
+impl std::fmt::Debug for WebhookConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookConfig")
+            .field("url", &self.url)
+            .field("secret", &"[REDACTED]")
+            .field("callback_url", &self.callback_url)
+            .field(
+                "authorization",
+                &self.authorization.as_ref().map(|_| "[REDACTED]"),
+            )
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl WebhookConfig {
+    /// Create a new webhook configuration.
+    pub fn new(url: impl Into<String>, secret: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            secret: secret.into(),
+            callback_url: None,
+            authorization: None,
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Load configuration from environment variables.
+    ///
+    /// Implements: REQ-GOV-003/F-001
+    ///
+    /// # Environment Variables
+    ///
+    /// - `THOUGHTGATE_APPROVAL_WEBHOOK_URL` (required) - Receiver URL
+    /// - `THOUGHTGATE_APPROVAL_WEBHOOK_SECRET` (required) - Shared signing secret
+    /// - `THOUGHTGATE_APPROVAL_WEBHOOK_TOKEN` - Bearer token sent with events
+    /// - `THOUGHTGATE_APPROVAL_CALLBACK_URL` - Callback URL given to the receiver
+    ///
+    /// # Errors
+    ///
+    /// Returns `AdapterError::InvalidToken` if the URL or secret is not set.
+    pub fn from_env() -> Result<Self, AdapterError> {
+        let url = non_empty_env("THOUGHTGATE_APPROVAL_WEBHOOK_URL")?;
+        let mut config = Self::new(url, non_empty_env("THOUGHTGATE_APPROVAL_WEBHOOK_SECRET")?);
+        config.authorization = std::env::var("THOUGHTGATE_APPROVAL_WEBHOOK_TOKEN")
+            .ok()
+            .filter(|t| !t.is_empty())
+            .map(|t| format!("Bearer {t}"));
+        config.callback_url = callback_url_from_env();
+        Ok(config)
+    }
+
+    /// Build configuration from a workflow's `webhook` destination.
+    ///
+    /// Implements: REQ-GOV-003/F-001
+    ///
+    /// The URL and auth come from the destination; the secret and callback
+    /// URL still come from the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AdapterError::InvalidToken` if the destination is not a
+    /// webhook, the secret is not set, or `auth.token_env` names an unset
+    /// variable.
+    pub fn from_destination(destination: &ApprovalDestination) -> Result<Self, AdapterError> {
+        let ApprovalDestination::Webhook { url, auth } = destination else {
+            return Err(AdapterError::InvalidToken);
+        };
+        let mut config = Self::new(
+            url.clone(),
+            non_empty_env("THOUGHTGATE_APPROVAL_WEBHOOK_SECRET")?,
+        );
+        config.authorization = auth.as_ref().map(authorization_header).transpose()?;
+        config.callback_url = callback_url_from_env();
+        Ok(config)
+    }
+
+    /// Set the callback URL advertised to the receiver.
+    #[must_use]
+    pub fn with_callback_url(mut self, url: impl Into<String>) -> Self {
+        self.callback_url = Some(url.into());
+        self
+    }
+
+    /// Send `Authorization: Bearer <token>` with every event.
+    #[must_use]
+    pub fn with_bearer_token(mut self, token: impl AsRef<str>) -> Self {
+        self.authorization = Some(format!("Bearer {}", token.as_ref()));
+        self
+    }
+
+    /// Set the HTTP timeout.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+fn non_empty_env(name: &str) -> Result<String, AdapterError> {
+    std::env::var(name)
+        .ok()
+        .filter(|v| !v.is_empty())
+        .ok_or(AdapterError::InvalidToken)
+}
+
+fn callback_url_from_env() -> Option<String> {
+    std::env::var("THOUGHTGATE_APPROVAL_CALLBACK_URL")
+        .ok()
+        .filter(|v| !v.is_empty())
+}
+
+/// Resolve a destination's auth block to an `Authorization` header value.
+fn authorization_header(auth: &WebhookAuth) -> Result<String, AdapterError> {
+    let token = match &auth.token_env {
+        Some(var) => non_empty_env(var)?,
+        None => return Err(AdapterError::InvalidToken),
+    };
+    match auth.auth_type.as_str() {
+        "bearer" => Ok(format!("Bearer {token}")),
+        // The variable holds the already-encoded `user:password` credentials
+        "basic" => Ok(format!("Basic {token}")),
+        _ => Err(AdapterError::InvalidToken),
+    }
+}
+
+// ============================================================================
+// Webhook Adapter
+// ============================================================================
+
+/// Generic HTTP webhook adapter.
+///
+/// Implements: REQ-GOV-003/F-001, REQ-GOV-003/F-003
+///
+/// Decisions arrive only through [`ApprovalAdapter::verify_callback`];
+/// polling always reports the approval as pending.
+pub struct WebhookAdapter {
+    client: Client,
+    config: WebhookConfig,
+}
+
+impl WebhookAdapter {
+    /// Create a new webhook adapter.
+    ///
+    /// Implements: REQ-GOV-003/F-001
+    ///
+    /// # Errors
+    ///
+    /// Returns `AdapterError::PostFailed` if the HTTP client cannot be built.
+    pub fn new(config: WebhookConfig) -> Result<Self, AdapterError> {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .map_err(|e| AdapterError::PostFailed {
+                reason: format!("Failed to build HTTP client: {e}"),
+                retriable: false,
+            })?;
+
+        Ok(Self { client, config })
+    }
+
+    /// Build the `approval_requested` event.
+    fn request_event(&self, request: &ApprovalRequest) -> serde_json::Value {
+        serde_json::json!({
+            "event": "approval_requested",
+            "task_id": request.task_id,
+            "correlation_id": request.correlation_id,
+            "tool_name": request.tool_name,
+            "tool_arguments": request.tool_arguments,
+            "principal": request.principal,
+            "created_at": request.created_at,
+            "expires_at": request.expires_at,
+            "min_approvals": request.min_approvals,
+            "callback_url": self.config.callback_url,
+        })
+    }
+
+    /// POST a signed event, returning the response body on success.
+    ///
+    /// Implements: REQ-GOV-003/F-001
+    async fn send_event(
+        &self,
+        task_id: &TaskId,
+        event: &serde_json::Value,
+    ) -> Result<bytes::Bytes, AdapterError> {
+        let body = serde_json::to_vec(event).map_err(|e| AdapterError::PostFailed {
+            reason: format!("Failed to serialize webhook event: {e}"),
+            retriable: false,
+        })?;
+        let timestamp = Utc::now().timestamp();
+        let signature = sign_webhook(&self.config.secret, timestamp, &body);
+
+        let mut builder = self
+            .client
+            .post(&self.config.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(SIGNATURE_HEADER, signature);
+        if let Some(authorization) = &self.config.authorization {
+            builder = builder.header(reqwest::header::AUTHORIZATION, authorization);
+        }
+
+        let response = builder.body(body).send().await.map_err(|e| {
+            error!(task_id = %task_id, error = %e, "Failed to deliver webhook event");
+            AdapterError::PostFailed {
+                reason: e.to_string(),
+                retriable: e.is_connect() || e.is_timeout(),
+            }
+        })?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get("retry-after")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(60);
+            return Err(AdapterError::RateLimited {
+                retry_after: Duration::from_secs(retry_after),
+            });
+        }
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            return Err(AdapterError::InvalidToken);
+        }
+        if !status.is_success() {
+            return Err(AdapterError::PostFailed {
+                reason: format!("Webhook returned HTTP {status}"),
+                retriable: status.is_server_error(),
+            });
+        }
+
+        response
+            .bytes()
+            .await
+            .map_err(|e| AdapterError::PostFailed {
+                reason: format!("Failed to read webhook response: {e}"),
+                retriable: false,
+            })
+    }
+}
+
+/// Optional body of the receiver's reply to `approval_requested`.
+#[derive(Debug, Deserialize)]
+struct WebhookPostResponse {
+    /// The receiver's own identifier for the request (e.g., a ticket key)
+    id: Option<String>,
+}
+
+/// Body of a decision callback.
+#[derive(Debug, Deserialize)]
+struct WebhookCallback {
+    task_id: TaskId,
+    decision: String,
+    decided_by: String,
+    #[serde(default)]
+    decided_at: Option<DateTime<Utc>>,
+}
+
+#[async_trait]
+impl ApprovalAdapter for WebhookAdapter {
+    /// Deliver an `approval_requested` event.
+    ///
+    /// Implements: REQ-GOV-003/F-001
+    async fn post_approval_request(
+        &self,
+        request: &ApprovalRequest,
+    ) -> Result<ApprovalReference, AdapterError> {
+        let body = self
+            .send_event(&request.task_id, &self.request_event(request))
+            .await?;
+
+        // The receiver may name its own record; the task ID is the fallback
+        let external_id = serde_json::from_slice::<WebhookPostResponse>(&body)
+            .ok()
+            .and_then(|r| r.id)
+            .unwrap_or_else(|| request.task_id.to_string());
+
+        info!(
+            task_id = %request.task_id,
+            external_id = %external_id,
+            "Delivered approval request to webhook"
+        );
+
+        Ok(ApprovalReference {
+            task_id: request.task_id.clone(),
+            external_id,
+            channel: self.config.url.clone(),
+            posted_at: Utc::now(),
+            next_poll_at: Instant::now(),
+            poll_count: 0,
+        })
+    }
+
+    /// Webhook decisions arrive by callback; there is nothing to poll.
+    async fn poll_for_decision(
+        &self,
+        _reference: &ApprovalReference,
+    ) -> Result<Option<PollResult>, AdapterError> {
+        Ok(None)
+    }
+
+    /// Deliver an `approval_cancelled` event (best-effort).
+    ///
+    /// Implements: REQ-GOV-003/F-005
+    async fn cancel_approval(&self, reference: &ApprovalReference) -> Result<(), AdapterError> {
+        let event = serde_json::json!({
+            "event": "approval_cancelled",
+            "task_id": reference.task_id,
+            "external_id": reference.external_id,
+        });
+        if let Err(e) = self.send_event(&reference.task_id, &event).await {
+            warn!(task_id = %reference.task_id, error = %e, "Failed to deliver cancellation");
+        }
+        Ok(())
+    }
+
+    /// Deliver an `approval_resolved` event.
+    ///
+    /// Implements: REQ-GOV-003/F-004
+    async fn resolve_approval(
+        &self,
+        reference: &ApprovalReference,
+        result: &PollResult,
+    ) -> Result<(), AdapterError> {
+        let decision = match result.decision {
+            PollDecision::Approved => "approved",
+            PollDecision::Rejected => "rejected",
+        };
+        let event = serde_json::json!({
+            "event": "approval_resolved",
+            "task_id": reference.task_id,
+            "external_id": reference.external_id,
+            "decision": decision,
+            "decided_by": result.decided_by,
+            "decided_at": result.decided_at,
+            "method": result.method.description(),
+        });
+        self.send_event(&reference.task_id, &event).await?;
+        debug!(task_id = %reference.task_id, decision, "Delivered resolution to webhook");
+        Ok(())
+    }
+
+    /// Verify a signed decision callback.
+    ///
+    /// Implements: REQ-GOV-003/F-003
+    fn verify_callback(
+        &self,
+        headers: &http::HeaderMap,
+        body: &[u8],
+    ) -> Result<(TaskId, PollResult), CallbackError> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+        };
+        verify_webhook_signature(
+            &self.config.secret,
+            header(TIMESTAMP_HEADER),
+            header(SIGNATURE_HEADER),
+            body,
+        )?;
+
+        let callback: WebhookCallback =
+            serde_json::from_slice(body).map_err(|e| CallbackError::InvalidPayload {
+                reason: e.to_string(),
+            })?;
+        let decision = match callback.decision.as_str() {
+            "approve" | "approved" => PollDecision::Approved,
+            "reject" | "rejected" => PollDecision::Rejected,
+            other => {
+                return Err(CallbackError::InvalidPayload {
+                    reason: format!("unknown decision \"{other}\""),
+                });
+            }
+        };
+        if callback.decided_by.is_empty() {
+            return Err(CallbackError::InvalidPayload {
+                reason: "decided_by is empty".to_string(),
+            });
+        }
+
+        Ok((
+            callback.task_id,
+            PollResult {
+                decision,
+                decided_by: callback.decided_by,
+                decided_at: callback.decided_at.unwrap_or_else(Utc::now),
+                method: DecisionMethod::Callback {
+                    source: "webhook".to_string(),
+                },
+            },
+        ))
+    }
+
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::Principal;
+    use serial_test::serial;
+    use wiremock::matchers::{header, header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn request() -> ApprovalRequest {
+        ApprovalRequest {
+            task_id: TaskId::new(),
+            tool_name: "delete_user".to_string(),
+            tool_arguments: serde_json::json!({"user_id": "123"}),
+            principal: Principal::new("test-app"),
+            expires_at: Utc::now() + chrono::Duration::hours(1),
+            created_at: Utc::now(),
+            correlation_id: "corr".to_string(),
+            escalation: None,
+            min_approvals: 1,
+        }
+    }
+
+    /// Sign `body` as a receiver would and return the callback headers.
+    fn signed_headers(secret: &str, body: &[u8]) -> http::HeaderMap {
+        let timestamp = Utc::now().timestamp();
+        let mut headers = http::HeaderMap::new();
+        headers.insert(TIMESTAMP_HEADER, timestamp.to_string().parse().unwrap());
+        headers.insert(
+            SIGNATURE_HEADER,
+            sign_webhook(secret, timestamp, body).parse().unwrap(),
+        );
+        headers
+    }
+
+    #[tokio::test]
+    async fn test_post_sends_signed_event() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/hook"))
+            .and(header("authorization", "Bearer tok"))
+            .and(header_exists(SIGNATURE_HEADER))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": "T-1"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let adapter = WebhookAdapter::new(
+            WebhookConfig::new(format!("{}/hook", server.uri()), "secret")
+                .with_bearer_token("tok")
+                .with_callback_url("https://tg.example/approval/callback"),
+        )
+        .unwrap();
+        let request = request();
+        let reference = adapter.post_approval_request(&request).await.unwrap();
+        assert_eq!(reference.external_id, "T-1");
+
+        let received = &server.received_requests().await.unwrap()[0];
+        let ts = received.headers.get(TIMESTAMP_HEADER).unwrap();
+        let sig = received.headers.get(SIGNATURE_HEADER).unwrap();
+        verify_webhook_signature(
+            "secret",
+            ts.to_str().unwrap(),
+            sig.to_str().unwrap(),
+            &received.body,
+        )
+        .unwrap();
+        let event: serde_json::Value = serde_json::from_slice(&received.body).unwrap();
+        assert_eq!(event["event"], "approval_requested");
+        assert_eq!(event["task_id"], request.task_id.to_string());
+        assert_eq!(
+            event["callback_url"],
+            "https://tg.example/approval/callback"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_post_maps_http_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400))
+            .mount(&server)
+            .await;
+
+        let adapter = WebhookAdapter::new(WebhookConfig::new(server.uri(), "secret")).unwrap();
+        let retriable = adapter.post_approval_request(&request()).await.unwrap_err();
+        assert!(retriable.is_retriable(), "{retriable}");
+        let permanent = adapter.post_approval_request(&request()).await.unwrap_err();
+        assert!(!permanent.is_retriable(), "{permanent}");
+    }
+
+    #[test]
+    fn test_verify_callback() {
+        let adapter = WebhookAdapter::new(WebhookConfig::new("http://unused", "secret")).unwrap();
+        let task_id = TaskId::new();
+        let body = serde_json::to_vec(&serde_json::json!({
+            "task_id": task_id,
+            "decision": "reject",
+            "decided_by": "alice",
+        }))
+        .unwrap();
+
+        let (id, result) = adapter
+            .verify_callback(&signed_headers("secret", &body), &body)
+            .unwrap();
+        assert_eq!(id, task_id);
+        assert_eq!(result.decision, PollDecision::Rejected);
+        assert_eq!(result.decided_by, "alice");
+        assert_eq!(result.method.description(), "webhook callback");
+
+        assert!(matches!(
+            adapter.verify_callback(&signed_headers("wrong", &body), &body),
+            Err(CallbackError::Signature(_))
+        ));
+
+        let bad = br#"{"task_id":"tg_x","decision":"maybe","decided_by":"bob"}"#;
+        assert!(matches!(
+            adapter.verify_callback(&signed_headers("secret", bad), bad),
+            Err(CallbackError::InvalidPayload { .. })
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn test_from_destination_resolves_auth() {
+        unsafe {
+            std::env::set_var("THOUGHTGATE_APPROVAL_WEBHOOK_SECRET", "secret");
+            std::env::set_var("TEST_WEBHOOK_TOKEN", "tok");
+        }
+        let destination = ApprovalDestination::Webhook {
+            url: "https://hooks.example/approvals".to_string(),
+            auth: Some(WebhookAuth {
+                auth_type: "bearer".to_string(),
+                token_env: Some("TEST_WEBHOOK_TOKEN".to_string()),
+            }),
+        };
+        let config = WebhookConfig::from_destination(&destination).unwrap();
+        assert_eq!(config.url, "https://hooks.example/approvals");
+        assert_eq!(config.authorization.as_deref(), Some("Bearer tok"));
+        assert!(!format!("{config:?}").contains("tok\""));
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_APPROVAL_WEBHOOK_SECRET");
+            std::env::remove_var("TEST_WEBHOOK_TOKEN");
+        }
+        assert!(matches!(
+            WebhookConfig::from_destination(&destination),
+            Err(AdapterError::InvalidToken)
+        ));
+    }
+}
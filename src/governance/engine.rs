@@ -42,18 +42,37 @@
 //! If Rejected → Return error (-32007)
 //! If Pending  → Return "result not ready"
 //! ```
+//!
+//! In blocking mode ([`ApprovalMode::Blocking`]) the request handler calls
+//! [`ApprovalEngine::await_result`] instead of returning the task ID, holding
+//! the connection until the decision and returning the replayed result.
+//!
+//! A call whose body is still streaming in is not stored for replay: its
+//! handler waits with [`ApprovalEngine::claim_stream`] instead, then sends
+//! the body upstream itself and reports back through the [`StreamClaim`].
 
 use std::sync::Arc;
 use std::time::Duration;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+use crate::config::{ApprovalMode, DEFAULT_REJECT_MESSAGE, HumanWorkflow};
 use crate::error::ThoughtGateError;
-use crate::transport::UpstreamForwarder;
-
-use super::approval::{ApprovalAdapter, ApprovalRequest, PollingConfig, PollingScheduler};
-use super::pipeline::{ApprovalPipeline, ExecutionPipeline, PipelineConfig, PipelineResult};
-use super::task::{FailureInfo, FailureStage, TaskStatus, ToolCallResult};
+use crate::policy::engine::CedarEngine;
+use crate::transport::{JsonRpcResponse, UpstreamForwarder};
+
+use super::approval::dead_letter::{self, DeadLetterRecord};
+use super::approval::store;
+use super::approval::{
+    AdapterError, ApprovalAdapter, ApprovalReaper, ApprovalRequest, CallbackError, PollingConfig,
+    PollingScheduler, PostRetryConfig, RateLimitAlgorithm, ReaperConfig, jitter_from_env,
+};
+use super::pipeline::{
+    ApprovalPipeline, ExecutionPipeline, PipelineConfig, PipelineResult, reevaluate_policy,
+    upstream_outcome,
+};
+use super::replay::CanonicalRequest;
+use super::task::{ApprovalDecision, FailureInfo, FailureStage, Task, TaskStatus, ToolCallResult};
 use super::{Principal, TaskError, TaskId, TaskStore, ToolCallRequest};
 
 // ============================================================================
@@ -71,6 +90,10 @@ pub struct ApprovalEngineConfig {
     pub on_timeout: TimeoutAction,
     /// Execution timeout for upstream calls
     pub execution_timeout: Duration,
+    /// Default response mode when the workflow does not set one
+    pub mode: ApprovalMode,
+    /// Action when the approval request cannot be posted
+    pub on_post_failure: PostFailureAction,
 }
 
 impl Default for ApprovalEngineConfig {
@@ -79,6 +102,8 @@ impl Default for ApprovalEngineConfig {
             approval_timeout: Duration::from_secs(600), // 10 minutes
             on_timeout: TimeoutAction::Deny,
             execution_timeout: Duration::from_secs(30),
+            mode: ApprovalMode::Async,
+            on_post_failure: PostFailureAction::Deny,
         }
     }
 }
@@ -95,6 +120,9 @@ impl ApprovalEngineConfig {
     /// - `THOUGHTGATE_APPROVAL_TIMEOUT_SECS` - Approval timeout (default: 600)
     /// - `THOUGHTGATE_ON_TIMEOUT` - Action on timeout: "deny" or "approve" (default: deny)
     /// - `THOUGHTGATE_EXECUTION_TIMEOUT_SECS` - Execution timeout (default: 30)
+    /// - `THOUGHTGATE_APPROVAL_MODE` - "async" or "blocking" (default: async)
+    /// - `THOUGHTGATE_ON_POST_FAILURE` - Action when the approval request
+    ///   cannot be posted: "deny" or "approve" (default: deny)
     #[must_use]
     pub fn from_env() -> Self {
         let approval_timeout = std::env::var("THOUGHTGATE_APPROVAL_TIMEOUT_SECS")
@@ -117,10 +145,25 @@ impl ApprovalEngineConfig {
             .map(Duration::from_secs)
             .unwrap_or(Duration::from_secs(30));
 
+        let mode = std::env::var("THOUGHTGATE_APPROVAL_MODE")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_default();
+
+        let on_post_failure = std::env::var("THOUGHTGATE_ON_POST_FAILURE")
+            .ok()
+            .map(|s| match s.to_lowercase().as_str() {
+                "approve" => PostFailureAction::Approve,
+                _ => PostFailureAction::Deny,
+            })
+            .unwrap_or(PostFailureAction::Deny);
+
         Self {
             approval_timeout,
             on_timeout,
             execution_timeout,
+            mode,
+            on_post_failure,
         }
     }
 }
@@ -138,6 +181,21 @@ pub enum TimeoutAction {
     Approve,
 }
 
+/// Action to take when the approval request cannot be posted, after
+/// retries.
+///
+/// Implements: REQ-GOV-003/F-001
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PostFailureAction {
+    /// Reject the task and fail the request (503)
+    #[default]
+    Deny,
+    /// Approve without a human (dangerous: skips review while the approval
+    /// channel is down)
+    Approve,
+}
+
 // ============================================================================
 // Approval Engine Errors
 // ============================================================================
@@ -149,8 +207,12 @@ pub enum TimeoutAction {
 pub enum ApprovalEngineError {
     /// Failed to create task
     TaskCreation { details: String },
+    /// Too many approvals pending to accept another
+    BacklogFull { retry_after: Duration },
     /// Failed to post approval request
     PostFailed { details: String },
+    /// Approval request could not be delivered and was dead-lettered
+    Undeliverable { task_id: TaskId, details: String },
     /// Task not found
     TaskNotFound { task_id: TaskId },
     /// Task in unexpected state
@@ -165,7 +227,16 @@ impl std::fmt::Display for ApprovalEngineError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::TaskCreation { details } => write!(f, "Task creation failed: {details}"),
+            Self::BacklogFull { retry_after } => {
+                write!(f, "Approval backlog full, retry after {retry_after:?}")
+            }
             Self::PostFailed { details } => write!(f, "Approval post failed: {details}"),
+            Self::Undeliverable { task_id, details } => {
+                write!(
+                    f,
+                    "Approval request for task {task_id} undeliverable: {details}"
+                )
+            }
             Self::TaskNotFound { task_id } => write!(f, "Task not found: {task_id}"),
             Self::InvalidState { task_id, status } => {
                 write!(f, "Task {task_id} in invalid state: {status}")
@@ -202,12 +273,90 @@ impl From<TaskError> for ApprovalEngineError {
 /// Implements: REQ-GOV-002/§6.2
 #[derive(Debug, Clone)]
 pub struct ApprovalStartResult {
-    /// The created task ID
+    /// The created (or joined) task ID
     pub task_id: TaskId,
-    /// Task status (always InputRequired on success)
+    /// Task status (InputRequired for a new task; a joined task may have
+    /// moved on)
     pub status: TaskStatus,
     /// Poll interval hint for client
     pub poll_interval: Duration,
+    /// Whether an identical pending request's task was joined instead of
+    /// starting a new approval
+    pub joined: bool,
+}
+
+// ============================================================================
+// Replay Claim
+// ============================================================================
+
+/// An approved task claimed for replay of its stored request.
+///
+/// Implements: REQ-GOV-002/F-006 (Execution of approved requests)
+///
+/// Holds the task's execution slot: no other call can execute the task
+/// until the claim is finished with [`ApprovalEngine::finish_replay`] or
+/// dropped.
+pub struct ReplayClaim<'a> {
+    engine: &'a ApprovalEngine,
+    task: Task,
+    request: CanonicalRequest,
+}
+
+impl ReplayClaim<'_> {
+    /// The stored request to send upstream.
+    pub fn request(&self) -> &CanonicalRequest {
+        &self.request
+    }
+}
+
+impl Drop for ReplayClaim<'_> {
+    fn drop(&mut self) {
+        self.engine.executing.remove(&self.task.id);
+    }
+}
+
+/// An approved task claimed by the streamed call it was created for.
+///
+/// Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+///
+/// Holds the task's execution slot like a [`ReplayClaim`]. The caller
+/// streams the request upstream and records how that went with
+/// [`StreamClaim::finish`].
+pub struct StreamClaim {
+    engine: Arc<ApprovalEngine>,
+    task: Task,
+}
+
+impl StreamClaim {
+    /// The claimed task.
+    pub fn task_id(&self) -> &TaskId {
+        &self.task.id
+    }
+
+    /// Record the upstream's response status, or the error that prevented
+    /// one, as the task's outcome.
+    pub fn finish(self, outcome: Result<http::StatusCode, ThoughtGateError>) {
+        // An auto-approved expired task is terminal and keeps no outcome
+        if self.task.status == TaskStatus::Expired {
+            return;
+        }
+        let result = match outcome {
+            Ok(status) => PipelineResult::Success {
+                result: ToolCallResult {
+                    content: serde_json::json!({ "status": status.as_u16() }),
+                    is_error: !status.is_success(),
+                },
+            },
+            Err(e) => upstream_outcome(&self.task, Err(e)),
+        };
+        let _ = self.engine.settle(&self.task, result);
+    }
+}
+
+impl Drop for StreamClaim {
+    fn drop(&mut self) {
+        self.engine.executing.remove(&self.task.id);
+    }
 }
 
 // ============================================================================
@@ -228,6 +377,10 @@ pub struct ApprovalEngine {
     task_store: Arc<TaskStore>,
     /// Polling scheduler for approval decisions
     scheduler: Arc<PollingScheduler>,
+    /// Rejects approvals nobody answered in time
+    reaper: Arc<ApprovalReaper>,
+    /// Stops the background tasks
+    shutdown: tokio_util::sync::CancellationToken,
     /// Full pipeline for execution (includes pre/post amber phases, upstream forwarding)
     pipeline: Arc<ApprovalPipeline>,
     /// Engine configuration
@@ -269,14 +422,27 @@ impl ApprovalEngine {
             max_concurrent: 100,
             approval_valid_for: Duration::from_secs(60), // Approval validity window
             rate_limit_per_sec: 1.0,
+            rate_limit_algorithm: RateLimitAlgorithm::from_env(),
+            rate_limit_jitter: jitter_from_env(),
+            post_retry: PostRetryConfig::from_env(),
         };
 
-        let scheduler = Arc::new(PollingScheduler::new(
-            adapter.clone(),
+        // Shared approval state when a store is installed, else in memory
+        let approvals = store::get_approval_store();
+        let reaper = Arc::new(ApprovalReaper::new(
+            approvals.clone(),
             task_store.clone(),
-            polling_config,
-            shutdown,
+            ReaperConfig::from_env(),
         ));
+        let scheduler = Arc::new(
+            PollingScheduler::new(
+                adapter.clone(),
+                task_store.clone(),
+                polling_config,
+                shutdown.clone(),
+            )
+            .with_approval_store(approvals),
+        );
 
         // Create pipeline configuration
         let pipeline_config = PipelineConfig {
@@ -293,16 +459,21 @@ impl ApprovalEngine {
         })?;
 
         // Create pipeline with no inspectors for v0.2 (simplified)
-        let pipeline = Arc::new(ApprovalPipeline::new(
-            vec![], // No inspectors in v0.2
-            Arc::new(cedar_engine),
-            upstream.clone(),
-            pipeline_config,
-        ));
+        let pipeline = Arc::new(
+            ApprovalPipeline::new(
+                vec![], // No inspectors in v0.2
+                Arc::new(cedar_engine),
+                upstream.clone(),
+                pipeline_config,
+            )
+            .with_clock(task_store.clock().clone()),
+        );
 
         Ok(Self {
             task_store,
             scheduler,
+            reaper,
+            shutdown,
             pipeline,
             config,
             executing: dashmap::DashSet::new(),
@@ -316,6 +487,7 @@ impl ApprovalEngine {
     /// This must be called after creating the engine to start:
     /// - The polling scheduler loop that checks for approval decisions
     /// - Periodic expiration sweeps for overdue tasks
+    /// - The reaper that times out unanswered approvals
     ///
     /// The tasks will run until the shutdown token is cancelled.
     pub fn spawn_background_tasks(&self) {
@@ -323,6 +495,12 @@ impl ApprovalEngine {
         tokio::spawn(async move {
             scheduler.run().await;
         });
+
+        let reaper = self.reaper.clone();
+        let shutdown = self.shutdown.clone();
+        tokio::spawn(async move {
+            reaper.run(shutdown).await;
+        });
     }
 
     /// Start an approval workflow.
@@ -339,6 +517,7 @@ impl ApprovalEngine {
     /// * `request` - The original tool call request
     /// * `principal` - Who is making the request
     /// * `workflow_timeout` - Optional workflow-specific timeout (overrides engine config)
+    /// * `workflow` - Optional workflow supplying escalation and `min_approvals`
     ///
     /// # Returns
     ///
@@ -346,12 +525,14 @@ impl ApprovalEngine {
     ///
     /// # Errors
     ///
-    /// Returns error if task creation or approval posting fails.
+    /// Returns error if task creation or approval posting fails, or
+    /// `BacklogFull` if the pending-task limit has been reached.
     pub async fn start_approval(
         &self,
         request: ToolCallRequest,
         principal: Principal,
         workflow_timeout: Option<Duration>,
+        workflow: Option<&HumanWorkflow>,
     ) -> Result<ApprovalStartResult, ApprovalEngineError> {
         let correlation_id = Uuid::new_v4().to_string();
 
@@ -374,19 +555,47 @@ impl ApprovalEngine {
         // F-001.2: Create task with stored request
         // Use workflow-specific timeout if provided, otherwise fall back to engine config
         let timeout = workflow_timeout.unwrap_or(self.config.approval_timeout);
-        let task = self
+        let (task, joined) = self
             .task_store
-            .create(
+            .create_or_join(
                 request.clone(),
                 pre_result.transformed_request,
                 principal.clone(),
                 Some(timeout),
                 self.config.on_timeout,
             )
-            .map_err(|e| ApprovalEngineError::TaskCreation {
-                details: e.to_string(),
+            .map_err(|e| match e {
+                // F-009.4: shed rather than queue; nothing is posted to approvers
+                TaskError::CapacityExceeded { retry_after } => {
+                    warn!(
+                        tool = %request.name,
+                        correlation_id = %correlation_id,
+                        "Approval backlog full, shedding request"
+                    );
+                    ApprovalEngineError::BacklogFull { retry_after }
+                }
+                e => ApprovalEngineError::TaskCreation {
+                    details: e.to_string(),
+                },
             })?;
 
+        // A retry of a request already awaiting approval shares its task:
+        // the approver sees one message and one decision resolves both.
+        if joined {
+            info!(
+                task_id = %task.id,
+                tool = %request.name,
+                correlation_id = %correlation_id,
+                "Identical request already pending, joined its approval"
+            );
+            return Ok(ApprovalStartResult {
+                task_id: task.id,
+                status: task.status,
+                poll_interval: task.poll_interval,
+                joined: true,
+            });
+        }
+
         // Store the request hash for later drift detection
         // (Already stored by create() in pre_approval_transformed)
 
@@ -406,14 +615,14 @@ impl ApprovalEngine {
             expires_at: task.expires_at,
             created_at: task.created_at,
             correlation_id: correlation_id.clone(),
+            escalation: workflow.and_then(|w| w.escalation.clone()),
+            min_approvals: workflow.map_or(1, HumanWorkflow::min_approvals_or_default),
         };
 
         // F-002.2: Submit to scheduler (posts to Slack and starts polling)
-        self.scheduler.submit(approval_request).await.map_err(|e| {
-            ApprovalEngineError::PostFailed {
-                details: e.to_string(),
-            }
-        })?;
+        if let Err(e) = self.scheduler.submit(approval_request.clone()).await {
+            return self.on_post_failure(task, &approval_request, &e);
+        }
 
         info!(
             task_id = %task.id,
@@ -427,6 +636,76 @@ impl ApprovalEngine {
             task_id: task.id,
             status: TaskStatus::InputRequired,
             poll_interval: task.poll_interval,
+            joined: false,
+        })
+    }
+
+    /// Settle a task whose approval request could not be posted.
+    ///
+    /// Implements: REQ-GOV-003/F-001
+    ///
+    /// Nobody will ever see the request, so the task is decided now rather
+    /// than left waiting for its timeout: rejected by default, or approved
+    /// when `on_post_failure` is `approve`. Either way the request is
+    /// dead-lettered for later inspection.
+    fn on_post_failure(
+        &self,
+        task: Task,
+        request: &ApprovalRequest,
+        error: &AdapterError,
+    ) -> Result<ApprovalStartResult, ApprovalEngineError> {
+        dead_letter::record(DeadLetterRecord::new(
+            request,
+            self.scheduler.adapter_name(),
+            error,
+            self.config.on_post_failure,
+        ));
+
+        let details = error.to_string();
+        let decision = match self.config.on_post_failure {
+            PostFailureAction::Deny => {
+                error!(
+                    task_id = %task.id,
+                    error = %details,
+                    "Failed to post approval request, rejecting task"
+                );
+                ApprovalDecision::Rejected {
+                    reason: Some(format!("Approval request could not be posted: {details}")),
+                }
+            }
+            PostFailureAction::Approve => {
+                warn!(
+                    task_id = %task.id,
+                    error = %details,
+                    "Failed to post approval request, approving without review"
+                );
+                ApprovalDecision::Approved
+            }
+        };
+
+        let approved = matches!(decision, ApprovalDecision::Approved);
+        self.task_store
+            .record_approval(
+                &task.id,
+                decision,
+                "system:post-failure".to_string(),
+                self.scheduler.config().approval_valid_for,
+            )
+            .map_err(|e| ApprovalEngineError::Internal {
+                details: format!("Failed to settle task after post failure: {e}"),
+            })?;
+
+        if !approved {
+            return Err(ApprovalEngineError::Undeliverable {
+                task_id: task.id,
+                details,
+            });
+        }
+        Ok(ApprovalStartResult {
+            task_id: task.id,
+            status: TaskStatus::Executing,
+            poll_interval: task.poll_interval,
+            joined: false,
         })
     }
 
@@ -467,6 +746,7 @@ impl ApprovalEngine {
                 // Still waiting for approval - return TaskResultNotReady per SEP-1686
                 return Err(ThoughtGateError::TaskResultNotReady {
                     task_id: task_id.to_string(),
+                    retry_after_ms: Some(retry_after_ms(&task)),
                 });
             }
             TaskStatus::Executing => {
@@ -499,7 +779,7 @@ impl ApprovalEngine {
                         // Prevent concurrent execution - ensures at-most-once semantics
                         if !self.executing.insert(task_id.clone()) {
                             return Err(ThoughtGateError::ServiceUnavailable {
-                                reason: "Task execution already in progress".to_string(),
+                                reason: EXECUTION_IN_PROGRESS.to_string(),
                             });
                         }
 
@@ -580,6 +860,7 @@ impl ApprovalEngine {
                 // Still in pre-approval phase - return TaskResultNotReady per SEP-1686
                 return Err(ThoughtGateError::TaskResultNotReady {
                     task_id: task_id.to_string(),
+                    retry_after_ms: Some(retry_after_ms(&task)),
                 });
             }
         }
@@ -591,7 +872,7 @@ impl ApprovalEngine {
         // If another call is already executing this task, return "in progress" error
         if !self.executing.insert(task_id.clone()) {
             return Err(ThoughtGateError::ServiceUnavailable {
-                reason: "Task execution already in progress".to_string(),
+                reason: EXECUTION_IN_PROGRESS.to_string(),
             });
         }
 
@@ -609,7 +890,108 @@ impl ApprovalEngine {
         // Remove from executing set now that pipeline is complete
         self.executing.remove(task_id);
 
-        // Handle pipeline result
+        self.settle(&task, pipeline_result)
+    }
+
+    /// Whether `task_id` is approved and has a stored request to replay.
+    ///
+    /// Implements: REQ-GOV-002/F-006 (Execution of approved requests)
+    #[must_use]
+    pub fn is_replayable(&self, task_id: &TaskId) -> bool {
+        self.task_store.get(task_id).is_ok_and(|task| {
+            task.status == TaskStatus::Executing && task.canonical_request.is_some()
+        })
+    }
+
+    /// Claim an approved task to replay its stored request.
+    ///
+    /// Implements: REQ-GOV-002/F-003, F-004 (Approval validation and policy
+    /// re-evaluation)
+    ///
+    /// Checks the approval is still valid, then re-evaluates `policy_engine`
+    /// with the approval grant in the policy context, so a task whose
+    /// policy has since changed to deny is never replayed. A task that
+    /// fails either check is failed, as `execute_on_result` would.
+    ///
+    /// # Errors
+    ///
+    /// - `TaskNotFound` if the task does not exist
+    /// - `ServiceUnavailable` if the task has no request to replay or is
+    ///   already executing
+    /// - `PolicyDenied` or `ApprovalTimeout` if the task may no longer run
+    pub fn begin_replay(
+        &self,
+        task_id: &TaskId,
+        policy_engine: &CedarEngine,
+    ) -> Result<ReplayClaim<'_>, ThoughtGateError> {
+        let task = self
+            .task_store
+            .get(task_id)
+            .map_err(|_| ThoughtGateError::TaskNotFound {
+                task_id: task_id.to_string(),
+            })?;
+        let (Some(approval), Some(request)) =
+            (task.approval.clone(), task.canonical_request.clone())
+        else {
+            return Err(ThoughtGateError::ServiceUnavailable {
+                reason: "Task has no approved request to replay".to_string(),
+            });
+        };
+
+        if !self.executing.insert(task_id.clone()) {
+            return Err(ThoughtGateError::ServiceUnavailable {
+                reason: EXECUTION_IN_PROGRESS.to_string(),
+            });
+        }
+        let claim = ReplayClaim {
+            engine: self,
+            task,
+            request,
+        };
+
+        let checked = self
+            .pipeline
+            .validate_approval(&claim.task, &approval)
+            .and_then(|()| reevaluate_policy(policy_engine, &claim.task, &approval));
+        if let Err(failure) = checked {
+            return Err(match self.settle(&claim.task, failure) {
+                Err(e) => e,
+                Ok(_) => ThoughtGateError::ServiceUnavailable {
+                    reason: "Task could not be replayed".to_string(),
+                },
+            });
+        }
+
+        info!(task_id = %task_id, "Replaying approved request");
+        Ok(claim)
+    }
+
+    /// Record the upstream's answer to a replayed request.
+    ///
+    /// Implements: REQ-GOV-002/F-006 (Execution of approved requests)
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `execute_on_result` for a failed execution.
+    pub fn finish_replay(
+        &self,
+        claim: ReplayClaim<'_>,
+        outcome: Result<JsonRpcResponse, ThoughtGateError>,
+    ) -> Result<ToolCallResult, ThoughtGateError> {
+        let result = upstream_outcome(&claim.task, outcome);
+        self.settle(&claim.task, result)
+    }
+
+    /// Record the outcome of executing an approved task and map it to
+    /// the agent's result.
+    ///
+    /// Implements: REQ-GOV-002/F-006 (Execution)
+    fn settle(
+        &self,
+        task: &Task,
+        pipeline_result: PipelineResult,
+    ) -> Result<ToolCallResult, ThoughtGateError> {
+        let task_id = &task.id;
         match pipeline_result {
             PipelineResult::Success { result } => {
                 // Store result and mark complete
@@ -649,7 +1031,9 @@ impl ApprovalEngine {
                     FailureStage::PolicyDrift => Err(ThoughtGateError::PolicyDenied {
                         tool: tool_name,
                         policy_id: None, // v0.2: policy_id not tracked
-                        reason: Some(reason),
+                        internal_reason: Some(reason),
+                        client_message: DEFAULT_REJECT_MESSAGE.to_string(),
+                        code: None,
                     }),
                     FailureStage::TransformDrift => Err(ThoughtGateError::ServiceUnavailable {
                         reason: format!("Transform drift: {reason}"),
@@ -673,6 +1057,195 @@ impl ApprovalEngine {
         }
     }
 
+    /// Wait for the decision on a task, then return its result.
+    ///
+    /// Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    ///
+    /// Used in blocking mode in place of the agent's `tasks/result` call:
+    /// holds until the approver decides or the task's TTL elapses, then
+    /// replays the stored request exactly as `execute_on_result` would.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `execute_on_result`; a decision that does
+    /// not arrive within the TTL yields `ApprovalTimeout` (or the
+    /// auto-approved result, per the task's `on_timeout`).
+    pub async fn await_result(&self, task_id: &TaskId) -> Result<ToolCallResult, ThoughtGateError> {
+        self.await_decision(task_id).await?;
+
+        // Every request joined to the task is woken by the decision, but
+        // only one replays it; the rest share that execution's outcome.
+        // (An expired auto-approved task stores no outcome to share.)
+        match self.execute_on_result(task_id).await {
+            Err(ThoughtGateError::ServiceUnavailable { reason })
+                if reason == EXECUTION_IN_PROGRESS
+                    && self
+                        .task_store
+                        .get(task_id)
+                        .is_ok_and(|task| task.status != TaskStatus::Expired) =>
+            {
+                let _ = self
+                    .task_store
+                    .wait_for_terminal(task_id, self.config.execution_timeout)
+                    .await;
+                self.execute_on_result(task_id).await
+            }
+            result => result,
+        }
+    }
+
+    /// Wait for the decision on a task, then claim it for a streamed call.
+    ///
+    /// Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    ///
+    /// Like [`await_result`](Self::await_result), but for a call whose body
+    /// the caller still holds: instead of replaying a stored request, the
+    /// approved task is handed back for the caller to send. The approval
+    /// must still be valid and the live policy must still permit the call,
+    /// as for [`begin_replay`](Self::begin_replay). A task that expired
+    /// with `on_timeout: approve` is claimed as auto-approved.
+    ///
+    /// # Errors
+    ///
+    /// Returns the decision as an error if the call may not proceed, or
+    /// `ServiceUnavailable` if the task was already executed by an
+    /// identical call that joined it.
+    pub async fn claim_stream(
+        self: &Arc<Self>,
+        task_id: &TaskId,
+        policy_engine: &CedarEngine,
+    ) -> Result<StreamClaim, ThoughtGateError> {
+        self.await_decision(task_id).await?;
+        let task = self
+            .task_store
+            .get(task_id)
+            .map_err(|_| ThoughtGateError::TaskNotFound {
+                task_id: task_id.to_string(),
+            })?;
+
+        let approval =
+            match task.status {
+                TaskStatus::Executing => Some(task.approval.clone().ok_or_else(|| {
+                    ThoughtGateError::ServiceUnavailable {
+                        reason: "Task approved but no approval record".to_string(),
+                    }
+                })?),
+                TaskStatus::Expired if task.on_timeout == TimeoutAction::Approve => {
+                    warn!(
+                        task_id = %task_id,
+                        "Auto-approving timed-out streamed call (on_timeout: approve)"
+                    );
+                    None
+                }
+                TaskStatus::Completed => {
+                    return Err(ThoughtGateError::ServiceUnavailable {
+                        reason: "Approved call already executed".to_string(),
+                    });
+                }
+                // Every other state is a decision against the call
+                _ => {
+                    return Err(self
+                        .execute_on_result(task_id)
+                        .await
+                        .err()
+                        .unwrap_or_else(|| ThoughtGateError::ServiceUnavailable {
+                            reason: "Task has no approval to stream".to_string(),
+                        }));
+                }
+            };
+
+        if !self.executing.insert(task_id.clone()) {
+            return Err(ThoughtGateError::ServiceUnavailable {
+                reason: EXECUTION_IN_PROGRESS.to_string(),
+            });
+        }
+        let claim = StreamClaim {
+            engine: self.clone(),
+            task,
+        };
+
+        if let Some(approval) = approval {
+            let checked = self
+                .pipeline
+                .validate_approval(&claim.task, &approval)
+                .and_then(|()| reevaluate_policy(policy_engine, &claim.task, &approval));
+            if let Err(failure) = checked {
+                return Err(match self.settle(&claim.task, failure) {
+                    Err(e) => e,
+                    Ok(_) => ThoughtGateError::ServiceUnavailable {
+                        reason: "Task could not be streamed".to_string(),
+                    },
+                });
+            }
+        }
+
+        info!(task_id = %task_id, "Streaming approved call");
+        Ok(claim)
+    }
+
+    /// Wait until the task is decided or its TTL elapses.
+    ///
+    /// Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    async fn await_decision(&self, task_id: &TaskId) -> Result<(), ThoughtGateError> {
+        let remaining = self
+            .task_store
+            .get(task_id)
+            .map(|task| task.remaining_ttl_at(self.task_store.clock().now()))
+            .map_err(|_| ThoughtGateError::TaskNotFound {
+                task_id: task_id.to_string(),
+            })?;
+
+        match self.task_store.wait_for_decision(task_id, remaining).await {
+            Ok(_) => Ok(()),
+            Err(TaskError::ResultNotReady { .. }) => {
+                // TTL elapsed before the scheduler's expiry sweep ran
+                self.task_store.expire_overdue();
+                Ok(())
+            }
+            Err(e) => Err(ThoughtGateError::ServiceUnavailable {
+                reason: e.to_string(),
+            }),
+        }
+    }
+
+    /// Give up on a task whose agent disconnected while waiting on it.
+    ///
+    /// Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    ///
+    /// Cancels the task, so a late decision can no longer execute it, and
+    /// withdraws the approval request from Slack. A task that was decided
+    /// in the meantime, or that other joined requests still wait on, is
+    /// left as it is.
+    pub async fn abandon(&self, task_id: &TaskId) {
+        match self.task_store.abandon(task_id) {
+            Ok(task) if !task.status.is_terminal() => {
+                debug!(task_id = %task_id, "Abandoned task still has waiters");
+                return;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                debug!(task_id = %task_id, error = %e, "Abandoned task not cancelled");
+            }
+        }
+        self.scheduler.cancel(task_id).await;
+    }
+
+    /// Apply an approval decision pushed to the callback endpoint.
+    ///
+    /// Implements: REQ-GOV-003/F-003
+    ///
+    /// # Errors
+    ///
+    /// Returns `CallbackError` if the adapter rejects the callback or the
+    /// task has no pending approval.
+    pub async fn handle_callback(
+        &self,
+        headers: &http::HeaderMap,
+        body: &[u8],
+    ) -> Result<TaskId, CallbackError> {
+        self.scheduler.handle_callback(headers, body).await
+    }
+
     /// Returns the polling scheduler.
     ///
     /// Used to run the background polling loop.
@@ -700,6 +1273,16 @@ impl ApprovalEngine {
     }
 }
 
+/// Reason returned when another call is already executing a task.
+const EXECUTION_IN_PROGRESS: &str = "Task execution already in progress";
+
+/// Retry hint for a task still awaiting a decision: its remaining timeout.
+///
+/// Implements: REQ-CORE-004/F-003.4 (Retry Guidance)
+fn retry_after_ms(task: &Task) -> u64 {
+    u64::try_from(task.remaining_ttl().as_millis()).unwrap_or(u64::MAX)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -707,12 +1290,13 @@ impl ApprovalEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::MockClock;
     use crate::governance::ApprovalDecision;
     use crate::governance::approval::{AdapterError, ApprovalReference, PollResult};
     use crate::governance::task::JsonRpcId;
     use crate::transport::JsonRpcResponse;
     use async_trait::async_trait;
-    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
     use tokio::sync::Mutex;
     use tokio_util::sync::CancellationToken;
 
@@ -727,6 +1311,7 @@ mod tests {
         assert_eq!(config.approval_timeout, Duration::from_secs(600));
         assert_eq!(config.on_timeout, TimeoutAction::Deny);
         assert_eq!(config.execution_timeout, Duration::from_secs(30));
+        assert_eq!(config.mode, ApprovalMode::Async);
     }
 
     #[test]
@@ -756,6 +1341,7 @@ mod tests {
         post_count: AtomicU32,
         poll_count: AtomicU32,
         poll_result: Mutex<Option<PollResult>>,
+        post_fails: AtomicBool,
     }
 
     impl MockApprovalAdapter {
@@ -764,6 +1350,7 @@ mod tests {
                 post_count: AtomicU32::new(0),
                 poll_count: AtomicU32::new(0),
                 poll_result: Mutex::new(None),
+                post_fails: AtomicBool::new(false),
             }
         }
     }
@@ -775,6 +1362,9 @@ mod tests {
             request: &super::super::approval::ApprovalRequest,
         ) -> Result<ApprovalReference, AdapterError> {
             self.post_count.fetch_add(1, Ordering::SeqCst);
+            if self.post_fails.load(Ordering::SeqCst) {
+                return Err(AdapterError::InvalidToken);
+            }
 
             Ok(ApprovalReference {
                 task_id: request.task_id.clone(),
@@ -887,7 +1477,7 @@ mod tests {
         .expect("Failed to create engine");
 
         let result = engine
-            .start_approval(test_request(), test_principal(), None)
+            .start_approval(test_request(), test_principal(), None, None)
             .await;
 
         assert!(result.is_ok(), "start_approval should succeed");
@@ -904,6 +1494,62 @@ mod tests {
         assert_eq!(adapter.post_count.load(Ordering::SeqCst), 1);
     }
 
+    /// Tests that approvals beyond the pending limit are shed, not posted.
+    ///
+    /// Verifies: REQ-GOV-001/F-009.4 (Global capacity)
+    #[tokio::test]
+    async fn test_start_approval_sheds_when_backlog_full() {
+        let task_store = Arc::new(TaskStore::new(super::super::TaskStoreConfig {
+            max_pending_global: 2,
+            ..Default::default()
+        }));
+        let adapter = Arc::new(MockApprovalAdapter::new());
+        let upstream = Arc::new(MockUpstream::new());
+        let config = ApprovalEngineConfig::default();
+        let approval_timeout = config.approval_timeout;
+        let shutdown = CancellationToken::new();
+
+        let engine = ApprovalEngine::new(
+            task_store.clone(),
+            adapter.clone(),
+            upstream,
+            config,
+            shutdown,
+        )
+        .expect("Failed to create engine");
+
+        let request = |user_id: &str| ToolCallRequest {
+            arguments: serde_json::json!({ "user_id": user_id }),
+            ..test_request()
+        };
+        for user_id in ["1", "2"] {
+            engine
+                .start_approval(request(user_id), test_principal(), None, None)
+                .await
+                .expect("backlog has room");
+        }
+
+        let result = engine
+            .start_approval(request("3"), test_principal(), None, None)
+            .await;
+        match result {
+            Err(ApprovalEngineError::BacklogFull { retry_after }) => {
+                assert!(retry_after > Duration::ZERO);
+                assert!(retry_after <= approval_timeout);
+            }
+            other => panic!("Expected BacklogFull, got {:?}", other.map(|r| r.task_id)),
+        }
+        assert_eq!(task_store.pending_count(), 2);
+        assert_eq!(adapter.post_count.load(Ordering::SeqCst), 2);
+
+        // A retry of a pending request adds nothing to the backlog
+        let joined = engine
+            .start_approval(request("1"), test_principal(), None, None)
+            .await
+            .expect("retry joins its pending approval");
+        assert!(joined.joined);
+    }
+
     /// Tests execute_on_result returns error when task not found.
     ///
     /// Verifies: EC-PIP-002 (Task not found)
@@ -936,6 +1582,7 @@ mod tests {
         let adapter = Arc::new(MockApprovalAdapter::new());
         let upstream = Arc::new(MockUpstream::new());
         let config = ApprovalEngineConfig::default();
+        let approval_timeout = config.approval_timeout;
         let shutdown = CancellationToken::new();
 
         let engine = ApprovalEngine::new(task_store.clone(), adapter, upstream, config, shutdown)
@@ -943,7 +1590,7 @@ mod tests {
 
         // Start approval
         let start_result = engine
-            .start_approval(test_request(), test_principal(), None)
+            .start_approval(test_request(), test_principal(), None, None)
             .await
             .unwrap();
 
@@ -952,9 +1599,16 @@ mod tests {
 
         assert!(result.is_err());
         match result.unwrap_err() {
-            ThoughtGateError::TaskResultNotReady { task_id } => {
+            ThoughtGateError::TaskResultNotReady {
+                task_id,
+                retry_after_ms,
+            } => {
                 // Per SEP-1686: pending tasks return TaskResultNotReady
                 assert_eq!(task_id, start_result.task_id.to_string());
+                // Hint is bounded by the approval's remaining timeout
+                let retry_after_ms = retry_after_ms.expect("retry hint");
+                assert!(retry_after_ms > 0);
+                assert!(u128::from(retry_after_ms) <= approval_timeout.as_millis());
             }
             other => panic!("Expected TaskResultNotReady, got {:?}", other),
         }
@@ -976,7 +1630,7 @@ mod tests {
 
         // Start approval
         let start_result = engine
-            .start_approval(test_request(), test_principal(), None)
+            .start_approval(test_request(), test_principal(), None, None)
             .await
             .unwrap();
 
@@ -1026,7 +1680,7 @@ mod tests {
 
         // Start approval
         let start_result = engine
-            .start_approval(test_request(), test_principal(), None)
+            .start_approval(test_request(), test_principal(), None, None)
             .await
             .unwrap();
 
@@ -1063,7 +1717,7 @@ mod tests {
 
         // Start approval
         let start_result = engine
-            .start_approval(test_request(), test_principal(), None)
+            .start_approval(test_request(), test_principal(), None, None)
             .await
             .unwrap();
 
@@ -1098,7 +1752,7 @@ mod tests {
 
         // Start approval
         let start_result = engine
-            .start_approval(test_request(), test_principal(), None)
+            .start_approval(test_request(), test_principal(), None, None)
             .await
             .unwrap();
 
@@ -1132,6 +1786,329 @@ mod tests {
         assert_eq!(tool_result.content["cached"], "result");
     }
 
+    /// Tests the async cycle: pending → approved → replay on `tasks/result`.
+    ///
+    /// Verifies: REQ-GOV-002/F-002.3, F-005 (Stored request replayed once)
+    #[tokio::test]
+    async fn test_async_pending_approved_replay() {
+        let task_store = Arc::new(TaskStore::with_defaults());
+        let adapter = Arc::new(MockApprovalAdapter::new());
+        let upstream = Arc::new(MockUpstream::new());
+        let config = ApprovalEngineConfig::default();
+        let shutdown = CancellationToken::new();
+
+        let engine = ApprovalEngine::new(
+            task_store.clone(),
+            adapter,
+            upstream.clone(),
+            config,
+            shutdown,
+        )
+        .expect("Failed to create engine");
+
+        let start_result = engine
+            .start_approval(test_request(), test_principal(), None, None)
+            .await
+            .unwrap();
+        assert_eq!(start_result.status, TaskStatus::InputRequired);
+
+        // Pending: result not ready, nothing forwarded
+        assert!(matches!(
+            engine.execute_on_result(&start_result.task_id).await,
+            Err(ThoughtGateError::TaskResultNotReady { .. })
+        ));
+        assert_eq!(upstream.forward_count.load(Ordering::SeqCst), 0);
+
+        task_store
+            .record_approval(
+                &start_result.task_id,
+                ApprovalDecision::Approved,
+                "test-reviewer".to_string(),
+                Duration::from_secs(60),
+            )
+            .unwrap();
+
+        // Approved: stored request is replayed upstream
+        let result = engine
+            .execute_on_result(&start_result.task_id)
+            .await
+            .unwrap();
+        assert!(!result.is_error);
+        assert_eq!(upstream.forward_count.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            task_store.get(&start_result.task_id).unwrap().status,
+            TaskStatus::Completed
+        );
+
+        // Subsequent calls return the stored result without replaying again
+        engine
+            .execute_on_result(&start_result.task_id)
+            .await
+            .unwrap();
+        assert_eq!(upstream.forward_count.load(Ordering::SeqCst), 1);
+    }
+
+    /// Tests that an approval is not replayed once its validity has lapsed.
+    ///
+    /// Verifies: REQ-GOV-002/F-003.1 (Approval validity window)
+    #[tokio::test]
+    async fn test_expired_approval_is_not_replayed() {
+        let clock = MockClock::new();
+        let task_store = Arc::new(TaskStore::with_defaults().with_clock(Arc::new(clock.clone())));
+        let upstream = Arc::new(MockUpstream::new());
+        let engine = ApprovalEngine::new(
+            task_store.clone(),
+            Arc::new(MockApprovalAdapter::new()),
+            upstream.clone(),
+            ApprovalEngineConfig::default(),
+            CancellationToken::new(),
+        )
+        .expect("Failed to create engine");
+
+        let start_result = engine
+            .start_approval(test_request(), test_principal(), None, None)
+            .await
+            .unwrap();
+        task_store
+            .record_approval(
+                &start_result.task_id,
+                ApprovalDecision::Approved,
+                "test-reviewer".to_string(),
+                Duration::from_secs(60),
+            )
+            .unwrap();
+
+        // Still within the task's TTL, but past the approval's validity
+        clock.advance(Duration::from_secs(61));
+        assert!(matches!(
+            engine.execute_on_result(&start_result.task_id).await,
+            Err(ThoughtGateError::ApprovalTimeout { .. })
+        ));
+        assert_eq!(upstream.forward_count.load(Ordering::SeqCst), 0);
+        assert_eq!(
+            task_store.get(&start_result.task_id).unwrap().status,
+            TaskStatus::Failed
+        );
+    }
+
+    /// Tests blocking mode holds until approval, then returns the result.
+    ///
+    /// Verifies: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    #[tokio::test]
+    async fn test_await_result_returns_after_approval() {
+        let task_store = Arc::new(TaskStore::with_defaults());
+        let adapter = Arc::new(MockApprovalAdapter::new());
+        let upstream = Arc::new(MockUpstream::new());
+        let config = ApprovalEngineConfig {
+            mode: ApprovalMode::Blocking,
+            ..Default::default()
+        };
+        let shutdown = CancellationToken::new();
+
+        let engine = Arc::new(
+            ApprovalEngine::new(
+                task_store.clone(),
+                adapter,
+                upstream.clone(),
+                config,
+                shutdown,
+            )
+            .expect("Failed to create engine"),
+        );
+
+        let start_result = engine
+            .start_approval(test_request(), test_principal(), None, None)
+            .await
+            .unwrap();
+
+        let waiter = {
+            let engine = engine.clone();
+            let task_id = start_result.task_id.clone();
+            tokio::spawn(async move { engine.await_result(&task_id).await })
+        };
+        tokio::task::yield_now().await;
+        assert!(!waiter.is_finished());
+
+        task_store
+            .record_approval(
+                &start_result.task_id,
+                ApprovalDecision::Approved,
+                "test-reviewer".to_string(),
+                Duration::from_secs(60),
+            )
+            .unwrap();
+
+        let result = tokio::time::timeout(Duration::from_secs(5), waiter)
+            .await
+            .expect("blocking handler woken")
+            .unwrap()
+            .unwrap();
+        assert!(!result.is_error);
+        assert_eq!(upstream.forward_count.load(Ordering::SeqCst), 1);
+    }
+
+    /// Tests a request whose approval cannot be posted is rejected and
+    /// dead-lettered, not left pending, and an identical retry starts a
+    /// fresh approval.
+    ///
+    /// Verifies: REQ-GOV-003/F-001 (post failure fails safely)
+    #[tokio::test]
+    async fn test_post_failure_dead_letters_task() {
+        crate::governance::approval::dead_letter::testing::install();
+        let task_store = Arc::new(TaskStore::with_defaults());
+        let adapter = Arc::new(MockApprovalAdapter::new());
+        adapter.post_fails.store(true, Ordering::SeqCst);
+        let upstream = Arc::new(MockUpstream::new());
+        let engine = ApprovalEngine::new(
+            task_store.clone(),
+            adapter.clone(),
+            upstream,
+            ApprovalEngineConfig::default(),
+            CancellationToken::new(),
+        )
+        .expect("Failed to create engine");
+        let request = ToolCallRequest {
+            name: "dead_letter_tool".to_string(),
+            ..test_request()
+        };
+
+        let result = engine
+            .start_approval(request.clone(), test_principal(), None, None)
+            .await;
+        let Err(ApprovalEngineError::Undeliverable { task_id, .. }) = result else {
+            panic!("Expected Undeliverable, got {result:?}");
+        };
+        assert_eq!(
+            task_store.get(&task_id).unwrap().status,
+            TaskStatus::Rejected
+        );
+
+        let records =
+            crate::governance::approval::dead_letter::testing::records_for("dead_letter_tool");
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].task_id, task_id.to_string());
+        assert_eq!(records[0].principal, test_principal());
+        assert_eq!(records[0].tool_arguments, request.arguments);
+        assert_eq!(records[0].adapter, "mock");
+        assert_eq!(records[0].action, PostFailureAction::Deny);
+
+        adapter.post_fails.store(false, Ordering::SeqCst);
+        let retry = engine
+            .start_approval(request, test_principal(), None, None)
+            .await
+            .unwrap();
+        assert!(!retry.joined);
+        assert_ne!(retry.task_id, task_id);
+        assert_eq!(retry.status, TaskStatus::InputRequired);
+    }
+
+    /// Tests identical blocking requests share one approval and execution.
+    ///
+    /// Verifies: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    #[tokio::test]
+    async fn test_identical_requests_share_one_approval() {
+        let task_store = Arc::new(TaskStore::with_defaults());
+        let adapter = Arc::new(MockApprovalAdapter::new());
+        let upstream = Arc::new(MockUpstream::new());
+        let config = ApprovalEngineConfig {
+            mode: ApprovalMode::Blocking,
+            ..Default::default()
+        };
+        let shutdown = CancellationToken::new();
+
+        let engine = Arc::new(
+            ApprovalEngine::new(
+                task_store.clone(),
+                adapter.clone(),
+                upstream.clone(),
+                config,
+                shutdown,
+            )
+            .expect("Failed to create engine"),
+        );
+
+        let first = engine
+            .start_approval(test_request(), test_principal(), None, None)
+            .await
+            .unwrap();
+        let second = engine
+            .start_approval(test_request(), test_principal(), None, None)
+            .await
+            .unwrap();
+        assert!(!first.joined);
+        assert!(second.joined);
+        assert_eq!(second.task_id, first.task_id);
+        assert_eq!(adapter.post_count.load(Ordering::SeqCst), 1);
+
+        let waiters: Vec<_> = [first.task_id.clone(), second.task_id]
+            .into_iter()
+            .map(|task_id| {
+                let engine = engine.clone();
+                tokio::spawn(async move { engine.await_result(&task_id).await })
+            })
+            .collect();
+        tokio::task::yield_now().await;
+
+        task_store
+            .record_approval(
+                &first.task_id,
+                ApprovalDecision::Approved,
+                "test-reviewer".to_string(),
+                Duration::from_secs(60),
+            )
+            .unwrap();
+
+        for waiter in waiters {
+            let result = tokio::time::timeout(Duration::from_secs(5), waiter)
+                .await
+                .expect("every waiter woken")
+                .unwrap()
+                .unwrap();
+            assert!(!result.is_error);
+        }
+        assert_eq!(upstream.forward_count.load(Ordering::SeqCst), 1);
+    }
+
+    /// Tests blocking mode returns a timeout error when no decision arrives.
+    ///
+    /// Verifies: REQ-GOV-002/F-002.3, EC-PIP-005
+    #[tokio::test]
+    async fn test_await_result_times_out() {
+        let task_store = Arc::new(TaskStore::new(super::super::TaskStoreConfig {
+            min_ttl: Duration::from_millis(10),
+            ..Default::default()
+        }));
+        let adapter = Arc::new(MockApprovalAdapter::new());
+        let upstream = Arc::new(MockUpstream::new());
+        let shutdown = CancellationToken::new();
+
+        let engine = ApprovalEngine::new(
+            task_store,
+            adapter,
+            upstream.clone(),
+            ApprovalEngineConfig::default(),
+            shutdown,
+        )
+        .expect("Failed to create engine");
+
+        let start_result = engine
+            .start_approval(
+                test_request(),
+                test_principal(),
+                Some(Duration::from_millis(50)),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let result = engine.await_result(&start_result.task_id).await;
+        assert!(matches!(
+            result,
+            Err(ThoughtGateError::ApprovalTimeout { .. })
+        ));
+        assert_eq!(upstream.forward_count.load(Ordering::SeqCst), 0);
+    }
+
     /// Tests poll interval is returned in start result.
     ///
     /// Verifies: EC-PIP-008 (Poll interval provided)
@@ -1147,7 +2124,7 @@ mod tests {
             .expect("Failed to create engine");
 
         let result = engine
-            .start_approval(test_request(), test_principal(), None)
+            .start_approval(test_request(), test_principal(), None, None)
             .await
             .unwrap();
 
@@ -1156,6 +2133,80 @@ mod tests {
         assert!(result.poll_interval <= Duration::from_secs(30));
     }
 
+    /// Tests the webhook flow end to end: the request is delivered to the
+    /// receiver, a signed callback approves it, and the receiver is told.
+    ///
+    /// Verifies: REQ-GOV-003/F-003 (Decisions via callback)
+    #[tokio::test]
+    async fn test_webhook_post_callback_resolve_flow() {
+        use crate::governance::approval::{WebhookAdapter, WebhookConfig, sign_webhook};
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let receiver = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202))
+            .mount(&receiver)
+            .await;
+
+        let task_store = Arc::new(TaskStore::with_defaults());
+        let adapter =
+            Arc::new(WebhookAdapter::new(WebhookConfig::new(receiver.uri(), "secret")).unwrap());
+        let engine = ApprovalEngine::new(
+            task_store.clone(),
+            adapter,
+            Arc::new(MockUpstream::new()),
+            ApprovalEngineConfig::default(),
+            CancellationToken::new(),
+        )
+        .expect("Failed to create engine");
+
+        let start = engine
+            .start_approval(test_request(), test_principal(), None, None)
+            .await
+            .unwrap();
+        let delivered = receiver.received_requests().await.unwrap();
+        assert_eq!(delivered.len(), 1);
+        let event: serde_json::Value = serde_json::from_slice(&delivered[0].body).unwrap();
+        assert_eq!(event["event"], "approval_requested");
+        assert_eq!(event["task_id"], start.task_id.to_string());
+
+        // The receiver answers through the callback endpoint
+        let body = serde_json::to_vec(&serde_json::json!({
+            "task_id": event["task_id"],
+            "decision": "approve",
+            "decided_by": "alice",
+        }))
+        .unwrap();
+        let timestamp = chrono::Utc::now().timestamp();
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            "x-thoughtgate-timestamp",
+            timestamp.to_string().parse().unwrap(),
+        );
+        headers.insert(
+            "x-thoughtgate-signature",
+            sign_webhook("secret", timestamp, &body).parse().unwrap(),
+        );
+
+        let task_id = engine.handle_callback(&headers, &body).await.unwrap();
+        assert_eq!(task_id, start.task_id);
+        let task = task_store.get(&task_id).unwrap();
+        assert_eq!(task.status, TaskStatus::Executing);
+        assert_eq!(task.approval.unwrap().decided_by, "alice");
+
+        let delivered = receiver.received_requests().await.unwrap();
+        let resolved: serde_json::Value = serde_json::from_slice(&delivered[1].body).unwrap();
+        assert_eq!(resolved["event"], "approval_resolved");
+        assert_eq!(resolved["decision"], "approved");
+
+        // A replayed callback finds nothing pending
+        assert!(matches!(
+            engine.handle_callback(&headers, &body).await,
+            Err(CallbackError::UnknownTask { .. })
+        ));
+    }
+
     /// Tests environment variable configuration loading.
     ///
     /// Verifies: REQ-GOV-002/§5.1 (Environment configuration)
@@ -13,6 +13,8 @@
 //! - `pipeline` - Approval execution pipeline (REQ-GOV-002)
 //! - `engine` - Approval engine coordinator (REQ-GOV-002)
 //! - `approval` - External approval system integration (REQ-GOV-003)
+//! - `quota` - Per-principal tool call quotas (REQ-GOV-001/F-009)
+//! - `replay` - Stored requests for replay after approval (REQ-GOV-002/F-006)
 //!
 //! ## v0.2 Features
 //!
@@ -26,12 +28,14 @@ pub mod approval;
 pub mod engine;
 pub mod handlers;
 pub mod pipeline;
+pub mod quota;
+pub mod replay;
 pub mod task;
 
 pub use task::{
     ApprovalDecision, ApprovalRecord, FailureInfo, FailureStage, JsonRpcId, Principal, Task,
     TaskError, TaskId, TaskStatus, TaskStore, TaskStoreConfig, TaskTransition, ToolCallRequest,
-    ToolCallResult, hash_request,
+    ToolCallResult, hash_request, request_fingerprint,
 };
 
 // Re-export handler types
@@ -39,9 +43,10 @@ pub use handlers::TaskHandler;
 
 // Re-export approval types
 pub use approval::{
-    AdapterError, ApprovalAdapter, ApprovalReference, ApprovalRequest, DecisionMethod,
-    PollDecision, PollResult, PollingConfig, PollingScheduler, RateLimiter, SlackAdapter,
-    SlackConfig,
+    AdapterError, ApprovalAdapter, ApprovalReference, ApprovalRequest, CallbackError,
+    DecisionMethod, Limiter, PollDecision, PollResult, PollingConfig, PollingScheduler,
+    PostRetryConfig, RateLimitAlgorithm, RateLimiter, SlackAdapter, SlackConfig,
+    SlidingWindowLimiter, WebhookAdapter, WebhookConfig,
 };
 
 // Re-export pipeline types
@@ -50,7 +55,14 @@ pub use pipeline::{
     PreHitlResult, TransformDriftMode,
 };
 
+// Re-export replay types
+pub use replay::{CanonicalRequest, with_request_headers};
+
+// Re-export quota types
+pub use quota::{LimiterFactory, QuotaConfig, QuotaLimiter};
+
 // Re-export engine types
 pub use engine::{
-    ApprovalEngine, ApprovalEngineConfig, ApprovalEngineError, ApprovalStartResult, TimeoutAction,
+    ApprovalEngine, ApprovalEngineConfig, ApprovalEngineError, ApprovalStartResult,
+    PostFailureAction, ReplayClaim, StreamClaim, TimeoutAction,
 };
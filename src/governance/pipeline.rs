@@ -22,6 +22,7 @@ use thiserror::Error;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
+use crate::clock::{Clock, SystemClock};
 use crate::inspector::{Decision, InspectionContext, Inspector};
 use crate::policy::engine::CedarEngine;
 #[allow(deprecated)] // v0.1 types for backward compatibility
@@ -29,7 +30,7 @@ use crate::policy::{
     ApprovalGrant, PolicyAction, PolicyContext, PolicyRequest, Principal as PolicyPrincipal,
     Resource,
 };
-use crate::transport::{JsonRpcId, McpRequest, UpstreamForwarder};
+use crate::transport::{JsonRpcId, JsonRpcResponse, McpRequest, UpstreamForwarder};
 
 use super::{
     ApprovalRecord, FailureStage, Principal, Task, TaskStatus, ToolCallRequest, ToolCallResult,
@@ -275,6 +276,8 @@ pub struct ApprovalPipeline {
     upstream_client: Arc<dyn UpstreamForwarder>,
     /// Pipeline configuration
     config: PipelineConfig,
+    /// Time source for approval validity
+    clock: Arc<dyn Clock>,
 }
 
 impl ApprovalPipeline {
@@ -292,9 +295,19 @@ impl ApprovalPipeline {
             policy_engine,
             upstream_client,
             config,
+            clock: SystemClock::shared(),
         }
     }
 
+    /// Check approval validity against `clock` instead of the system clock.
+    ///
+    /// Implements: REQ-GOV-002/F-003
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Create pipeline with configuration from environment.
     ///
     /// Implements: REQ-GOV-002/§5.1
@@ -410,13 +423,13 @@ impl ApprovalPipeline {
     /// Validate approval is still valid.
     ///
     /// Implements: REQ-GOV-002/F-003
-    fn validate_approval(
+    pub(crate) fn validate_approval(
         &self,
         task: &Task,
         approval: &ApprovalRecord,
     ) -> Result<(), PipelineResult> {
         // F-003.1: Check approval validity window
-        let now = chrono::Utc::now();
+        let now = self.clock.now();
         if now > approval.approval_valid_until {
             warn!(
                 task_id = %task.id,
@@ -450,53 +463,12 @@ impl ApprovalPipeline {
     /// Re-evaluate policy with approval context.
     ///
     /// Implements: REQ-GOV-002/F-004
-    #[allow(deprecated)] // Using v0.1 PolicyAction API
     fn reevaluate_policy(
         &self,
         task: &Task,
         approval: &ApprovalRecord,
     ) -> Result<(), PipelineResult> {
-        // Build approval grant for policy context
-        let approval_grant = ApprovalGrant {
-            task_id: task.id.to_string(),
-            approved_by: approval.decided_by.clone(),
-            approved_at: approval.decided_at.timestamp(),
-        };
-
-        // Build policy request with approval context
-        let policy_request = build_policy_request(
-            &task.pre_approval_transformed,
-            &task.principal,
-            Some(approval_grant),
-        );
-
-        // Evaluate policy
-        let action = self.policy_engine.evaluate(&policy_request);
-
-        // F-004.2: Any permit allows execution
-        match action {
-            PolicyAction::Forward | PolicyAction::Approve { .. } => {
-                debug!(
-                    task_id = %task.id,
-                    action = ?action,
-                    "Policy permits execution"
-                );
-                Ok(())
-            }
-            PolicyAction::Reject { reason } => {
-                // F-004.3: Policy drift
-                warn!(
-                    task_id = %task.id,
-                    reason = %reason,
-                    "Policy drift detected - no longer permitted"
-                );
-                Err(PipelineResult::Failure {
-                    stage: FailureStage::PolicyDrift,
-                    reason: "Policy changed - request no longer permitted".to_string(),
-                    retriable: false,
-                })
-            }
-        }
+        reevaluate_policy(&self.policy_engine, task, approval)
     }
 
     /// Run post-approval amber phase.
@@ -586,48 +558,7 @@ impl ApprovalPipeline {
         .await;
 
         match result {
-            Ok(Ok(response)) => {
-                // F-006.3: Success
-                if let Some(error) = response.error {
-                    // Upstream returned JSON-RPC error
-                    warn!(
-                        task_id = %task.id,
-                        error_code = error.code,
-                        error_message = %error.message,
-                        "Upstream returned error"
-                    );
-                    PipelineResult::Failure {
-                        stage: FailureStage::UpstreamError,
-                        reason: error.message,
-                        retriable: is_retriable_error(error.code),
-                    }
-                } else {
-                    // Success
-                    let result = ToolCallResult {
-                        content: response.result.unwrap_or(serde_json::Value::Null),
-                        is_error: false,
-                    };
-                    info!(
-                        task_id = %task.id,
-                        "Execution completed successfully"
-                    );
-                    PipelineResult::Success { result }
-                }
-            }
-            Ok(Err(e)) => {
-                // F-006.2: Handle upstream errors
-                warn!(
-                    task_id = %task.id,
-                    error = %e,
-                    "Upstream request failed"
-                );
-                let (retriable, reason) = classify_upstream_error(&e);
-                PipelineResult::Failure {
-                    stage: FailureStage::UpstreamError,
-                    reason,
-                    retriable,
-                }
-            }
+            Ok(outcome) => upstream_outcome(task, outcome),
             Err(_) => {
                 // Timeout
                 warn!(
@@ -787,6 +718,7 @@ fn build_policy_request(
         resource: Resource::ToolCall {
             name: request.name.clone(),
             server: "default".to_string(),
+            attributes: Default::default(),
         },
         context: approval_grant.map(|g| PolicyContext {
             approval_grant: Some(g),
@@ -794,6 +726,115 @@ fn build_policy_request(
     }
 }
 
+/// Re-evaluate policy for an approved task, with the approval grant in
+/// the policy context.
+///
+/// Implements: REQ-GOV-002/F-004
+///
+/// Any permit allows execution; a task no longer permitted fails with
+/// `FailureStage::PolicyDrift`.
+#[allow(deprecated)] // Using v0.1 PolicyAction API
+pub(crate) fn reevaluate_policy(
+    policy_engine: &CedarEngine,
+    task: &Task,
+    approval: &ApprovalRecord,
+) -> Result<(), PipelineResult> {
+    // Build approval grant for policy context
+    let approval_grant = ApprovalGrant {
+        task_id: task.id.to_string(),
+        approved_by: approval.decided_by.clone(),
+        approved_at: approval.decided_at.timestamp(),
+    };
+
+    // Build policy request with approval context
+    let policy_request = build_policy_request(
+        &task.pre_approval_transformed,
+        &task.principal,
+        Some(approval_grant),
+    );
+
+    // Evaluate policy
+    let action = policy_engine.evaluate(&policy_request);
+
+    // F-004.2: Any permit allows execution
+    match action {
+        PolicyAction::Forward | PolicyAction::Approve { .. } => {
+            debug!(
+                task_id = %task.id,
+                action = ?action,
+                "Policy permits execution"
+            );
+            Ok(())
+        }
+        PolicyAction::Reject { reason } => {
+            // F-004.3: Policy drift
+            warn!(
+                task_id = %task.id,
+                reason = %reason,
+                "Policy drift detected - no longer permitted"
+            );
+            Err(PipelineResult::Failure {
+                stage: FailureStage::PolicyDrift,
+                reason: "Policy changed - request no longer permitted".to_string(),
+                retriable: false,
+            })
+        }
+    }
+}
+
+/// Map the upstream's answer to an approved request to a pipeline result.
+///
+/// Implements: REQ-GOV-002/F-006
+pub(crate) fn upstream_outcome(
+    task: &Task,
+    outcome: Result<JsonRpcResponse, crate::error::ThoughtGateError>,
+) -> PipelineResult {
+    match outcome {
+        Ok(response) => {
+            // F-006.3: Success
+            if let Some(error) = response.error {
+                // Upstream returned JSON-RPC error
+                warn!(
+                    task_id = %task.id,
+                    error_code = error.code,
+                    error_message = %error.message,
+                    "Upstream returned error"
+                );
+                PipelineResult::Failure {
+                    stage: FailureStage::UpstreamError,
+                    reason: error.message,
+                    retriable: is_retriable_error(error.code),
+                }
+            } else {
+                // Success
+                let result = ToolCallResult {
+                    content: response.result.unwrap_or(serde_json::Value::Null),
+                    is_error: false,
+                };
+                info!(
+                    task_id = %task.id,
+                    "Execution completed successfully"
+                );
+                PipelineResult::Success { result }
+            }
+        }
+        Err(e) => {
+            // F-006.2: Handle upstream errors
+            warn!(
+                task_id = %task.id,
+                error = %e,
+                "Upstream request failed"
+            );
+            let (retriable, reason) = classify_upstream_error(&e);
+            PipelineResult::Failure {
+                stage: FailureStage::UpstreamError,
+                reason,
+                retriable,
+            }
+        }
+    }
+}
+
 /// Classify upstream errors for retriability.
 fn classify_upstream_error(error: &crate::error::ThoughtGateError) -> (bool, String) {
     use crate::error::ThoughtGateError;
@@ -803,6 +844,10 @@ fn classify_upstream_error(error: &crate::error::ThoughtGateError) -> (bool, Str
         ThoughtGateError::UpstreamConnectionFailed { reason, .. } => {
             (true, format!("Connection failed: {}", reason))
         }
+        // A rejected certificate will be rejected again
+        ThoughtGateError::UpstreamTlsFailed { failure, .. } => {
+            (false, format!("TLS handshake failed: {}", failure))
+        }
         ThoughtGateError::UpstreamError { message, .. } => (false, message.clone()),
         _ => (false, error.to_string()),
     }
@@ -972,6 +1017,7 @@ mod tests {
                 mcp_request_id: super::super::JsonRpcId::Number(1),
             },
             request_hash: "abc123".to_string(),
+            canonical_request: None,
             principal: Principal::new("test-app"),
             created_at: Utc::now(),
             ttl: Duration::from_secs(3600),
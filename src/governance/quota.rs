@@ -0,0 +1,303 @@
+//! Per-principal tool call quotas.
+//!
+//! Implements: REQ-GOV-001/F-009 (Rate Limiting)
+//!
+//! Separate from the Slack [`RateLimiter`](super::RateLimiter), which paces
+//! outgoing API calls: this limits how fast each principal (optionally per
+//! tool) may make `tools/call` requests, so a runaway agent is refused with
+//! `RateLimited` instead of flooding upstream.
+//!
+//! Each key gets its own [`Limiter`], a token bucket unless another is
+//! supplied, created on first use. Buckets that have been idle for
+//! `idle_ttl` are evicted, which bounds memory to the keys seen recently. An
+//! idle bucket has refilled completely, so evicting it never grants a key
+//! more than a fresh bucket would.
+//!
+//! # Configuration
+//!
+//! - `THOUGHTGATE_QUOTA_RATE`: tool calls per second per key. Unset or zero
+//!   disables quotas.
+//! - `THOUGHTGATE_QUOTA_BURST`: bucket capacity (default: the rate, at
+//!   least 1)
+//! - `THOUGHTGATE_QUOTA_PER_TOOL`: `true` to key buckets by principal and
+//!   tool instead of principal alone
+//! - `THOUGHTGATE_QUOTA_IDLE_SECS`: idle time before a bucket is evicted
+//!   (default: 300)
+
+use super::{Limiter, RateLimiter};
+use dashmap::DashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Default idle time before a bucket is evicted.
+pub const DEFAULT_QUOTA_IDLE_TTL: Duration = Duration::from_secs(300);
+
+/// Quota configuration.
+///
+/// Implements: REQ-GOV-001/F-009 (Rate Limiting)
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuotaConfig {
+    /// Tokens added per second to each bucket.
+    pub rate_per_second: f64,
+    /// Bucket capacity (largest burst).
+    pub burst: f64,
+    /// Key buckets by principal and tool rather than principal alone.
+    pub per_tool: bool,
+    /// Idle time before a bucket is evicted.
+    pub idle_ttl: Duration,
+}
+
+impl QuotaConfig {
+    /// Create a configuration allowing `rate_per_second` calls per key,
+    /// with a burst of the same size (at least 1).
+    #[must_use]
+    pub fn new(rate_per_second: f64) -> Self {
+        Self {
+            rate_per_second,
+            burst: rate_per_second.max(1.0),
+            per_tool: false,
+            idle_ttl: DEFAULT_QUOTA_IDLE_TTL,
+        }
+    }
+
+    /// Load from `THOUGHTGATE_QUOTA_*` environment variables.
+    ///
+    /// Returns `None` when `THOUGHTGATE_QUOTA_RATE` is unset, unparseable
+    /// or not positive.
+    pub fn from_env() -> Option<Self> {
+        fn var<T: std::str::FromStr>(name: &str) -> Option<T> {
+            std::env::var(name).ok().and_then(|v| v.parse().ok())
+        }
+
+        let rate: f64 = var("THOUGHTGATE_QUOTA_RATE")?;
+        if !rate.is_finite() || rate <= 0.0 {
+            return None;
+        }
+        let mut config = Self::new(rate);
+        if let Some(burst) = var::<f64>("THOUGHTGATE_QUOTA_BURST").filter(|b| *b >= 1.0) {
+            config.burst = burst;
+        }
+        config.per_tool = std::env::var("THOUGHTGATE_QUOTA_PER_TOOL").as_deref() == Ok("true");
+        if let Some(secs) = var("THOUGHTGATE_QUOTA_IDLE_SECS") {
+            config.idle_ttl = Duration::from_secs(secs);
+        }
+        Some(config)
+    }
+}
+
+/// Builds the limiter for a newly seen key.
+pub type LimiterFactory = Arc<dyn Fn() -> Arc<dyn Limiter> + Send + Sync>;
+
+/// One key's limiter and when it was last used.
+struct Bucket {
+    limiter: Arc<dyn Limiter>,
+    last_used: Instant,
+}
+
+/// Keyed quota for tool calls, with one [`Limiter`] per key.
+///
+/// Implements: REQ-GOV-001/F-009 (Rate Limiting)
+pub struct QuotaLimiter {
+    config: QuotaConfig,
+    factory: LimiterFactory,
+    buckets: DashMap<String, Bucket>,
+    /// Reference point for `last_sweep`.
+    created: Instant,
+    /// When idle buckets were last evicted, in nanoseconds since `created`.
+    last_sweep: AtomicU64,
+}
+
+impl QuotaLimiter {
+    /// Create a limiter giving each key a token bucket.
+    #[must_use]
+    pub fn new(config: QuotaConfig) -> Self {
+        let (rate, burst) = (config.rate_per_second, config.burst);
+        Self::with_limiter(
+            config,
+            Arc::new(move || Arc::new(RateLimiter::with_burst(rate, burst)) as Arc<dyn Limiter>),
+        )
+    }
+
+    /// Create a limiter giving each key a limiter built by `factory`.
+    ///
+    /// Evicting an idle key must not grant it more than a fresh limiter
+    /// would, so `factory`'s limiters should replenish fully within the
+    /// configured idle time.
+    #[must_use]
+    pub fn with_limiter(config: QuotaConfig, factory: LimiterFactory) -> Self {
+        Self {
+            config,
+            factory,
+            buckets: DashMap::new(),
+            created: Instant::now(),
+            last_sweep: AtomicU64::new(0),
+        }
+    }
+
+    /// The limiter's configuration.
+    pub fn config(&self) -> &QuotaConfig {
+        &self.config
+    }
+
+    /// Take one permit for a call by `principal` to `tool`.
+    ///
+    /// `tool` only selects the bucket when `per_tool` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns a hint of how long to wait, one permit's worth of the
+    /// configured rate, if the key's limiter is exhausted.
+    pub async fn check(&self, principal: &str, tool: &str) -> Result<(), Duration> {
+        let now = Instant::now();
+        self.maybe_sweep(now);
+
+        let key = if self.config.per_tool {
+            format!("{principal}\u{0}{tool}")
+        } else {
+            principal.to_string()
+        };
+        let limiter = {
+            let mut bucket = self.buckets.entry(key).or_insert_with(|| Bucket {
+                limiter: (self.factory)(),
+                last_used: now,
+            });
+            bucket.last_used = now;
+            bucket.limiter.clone()
+        };
+
+        if limiter.try_acquire().await {
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(1.0 / self.config.rate_per_second))
+        }
+    }
+
+    /// Evict buckets idle for at least `idle_ttl`; returns how many.
+    pub fn evict_idle(&self) -> usize {
+        self.evict_idle_at(Instant::now())
+    }
+
+    /// [`evict_idle`](Self::evict_idle) as of `now`.
+    fn evict_idle_at(&self, now: Instant) -> usize {
+        let idle_ttl = self.idle_ttl();
+        let before = self.buckets.len();
+        self.buckets
+            .retain(|_, bucket| now.saturating_duration_since(bucket.last_used) < idle_ttl);
+        before.saturating_sub(self.buckets.len())
+    }
+
+    /// Number of live buckets.
+    pub fn len(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Whether no buckets are live.
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+
+    /// Idle time before eviction, never shorter than a full refill so an
+    /// evicted bucket was already full.
+    fn idle_ttl(&self) -> Duration {
+        let refill = Duration::from_secs_f64(self.config.burst / self.config.rate_per_second);
+        self.config.idle_ttl.max(refill)
+    }
+
+    /// Evict idle buckets at most once per idle period, from the request
+    /// path, so no background task is needed.
+    fn maybe_sweep(&self, now: Instant) {
+        let now_nanos = u64::try_from(now.saturating_duration_since(self.created).as_nanos())
+            .unwrap_or(u64::MAX);
+        let last_sweep = self.last_sweep.load(Ordering::Acquire);
+        if Duration::from_nanos(now_nanos.saturating_sub(last_sweep)) < self.idle_ttl() {
+            return;
+        }
+        // Only the request that claims the sweep runs it
+        if self
+            .last_sweep
+            .compare_exchange(last_sweep, now_nanos, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            self.evict_idle_at(now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::SlidingWindowLimiter;
+
+    fn limiter(rate: f64, burst: f64, per_tool: bool) -> QuotaLimiter {
+        QuotaLimiter::new(QuotaConfig {
+            rate_per_second: rate,
+            burst,
+            per_tool,
+            idle_ttl: Duration::from_secs(60),
+        })
+    }
+
+    /// Exhausting one principal's quota leaves others untouched.
+    ///
+    /// Verifies: REQ-GOV-001/F-009 (Rate Limiting)
+    #[tokio::test]
+    async fn test_quota_isolated_per_principal() {
+        let quota = limiter(1.0, 2.0, false);
+
+        assert!(quota.check("agent-a", "read").await.is_ok());
+        assert!(quota.check("agent-a", "write").await.is_ok());
+        let retry = quota.check("agent-a", "read").await.unwrap_err();
+        assert_eq!(retry, Duration::from_secs(1));
+
+        assert!(quota.check("agent-b", "read").await.is_ok());
+        assert!(quota.check("agent-b", "read").await.is_ok());
+        assert!(quota.check("agent-b", "read").await.is_err());
+    }
+
+    /// With `per_tool`, each tool has its own bucket.
+    ///
+    /// Verifies: REQ-GOV-001/F-009 (Rate Limiting)
+    #[tokio::test]
+    async fn test_quota_per_tool() {
+        let quota = limiter(1.0, 1.0, true);
+
+        assert!(quota.check("agent", "read").await.is_ok());
+        assert!(quota.check("agent", "read").await.is_err());
+        assert!(quota.check("agent", "write").await.is_ok());
+        assert_eq!(quota.len(), 2);
+    }
+
+    /// Idle buckets are evicted, and a returning key starts full.
+    ///
+    /// Verifies: REQ-GOV-001/F-009 (Rate Limiting)
+    #[tokio::test]
+    async fn test_quota_evicts_idle_buckets() {
+        let quota = limiter(1.0, 1.0, false);
+        let start = Instant::now();
+
+        assert!(quota.check("idle", "read").await.is_ok());
+        assert!(quota.check("idle", "read").await.is_err());
+        assert_eq!(quota.evict_idle_at(start + Duration::from_secs(30)), 0);
+        assert_eq!(quota.evict_idle_at(start + Duration::from_secs(61)), 1);
+        assert!(quota.is_empty());
+
+        assert!(quota.check("idle", "read").await.is_ok());
+    }
+
+    /// Keys can be given any limiter behind the trait.
+    ///
+    /// Verifies: REQ-GOV-001/F-009 (Rate Limiting)
+    #[tokio::test]
+    async fn test_quota_with_sliding_window_limiter() {
+        let quota = QuotaLimiter::with_limiter(
+            QuotaConfig::new(2.0),
+            Arc::new(|| Arc::new(SlidingWindowLimiter::new(2, Duration::from_secs(1)))),
+        );
+
+        assert!(quota.check("agent", "read").await.is_ok());
+        assert!(quota.check("agent", "read").await.is_ok());
+        assert!(quota.check("agent", "read").await.is_err());
+        assert!(quota.check("other", "read").await.is_ok());
+    }
+}
@@ -0,0 +1,130 @@
+//! Replay of approved requests.
+//!
+//! Implements: REQ-GOV-002/F-006 (Execution of approved requests)
+//!
+//! In non-blocking approval mode the agent's connection is long gone by the
+//! time a task is approved, so the request must be sent again. The proxy
+//! records the headers of each governed request with
+//! [`with_request_headers`]; when a task is created its
+//! [`CanonicalRequest`] - those headers and the JSON-RPC message on its
+//! own - is stored with it, and replayed as-is once approved.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use http::HeaderMap;
+use http::header::{CONTENT_LENGTH, HOST};
+use serde::{Deserialize, Serialize};
+
+use crate::transport::McpRequest;
+
+tokio::task_local! {
+    /// Headers of the request being processed by the current task.
+    static REQUEST_HEADERS: Arc<HeaderMap>;
+}
+
+/// Run `fut` with `headers` as the headers of the request being governed.
+///
+/// `headers` should already be what the upstream would receive: without
+/// connection-specific headers and with the upstream's header rules applied.
+///
+/// Implements: REQ-GOV-002/F-006 (Execution of approved requests)
+pub async fn with_request_headers<F: Future>(headers: HeaderMap, fut: F) -> F::Output {
+    REQUEST_HEADERS.scope(Arc::new(headers), fut).await
+}
+
+/// A governed request as received, stored with its task for replay.
+///
+/// Implements: REQ-GOV-002/F-006 (Execution of approved requests)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CanonicalRequest {
+    /// Request headers in arrival order, minus `Host` and `Content-Length`
+    pub headers: Vec<(String, String)>,
+    /// The JSON-RPC message, serialized on its own even if it arrived in a batch
+    pub body: String,
+}
+
+impl CanonicalRequest {
+    /// Capture `request` with the headers recorded by [`with_request_headers`].
+    ///
+    /// Returns `None` outside [`with_request_headers`]: a request that did
+    /// not come through the proxy cannot be replayed. Header values that
+    /// are not valid UTF-8 are dropped.
+    pub fn capture(request: &McpRequest) -> Option<Self> {
+        let headers = REQUEST_HEADERS
+            .try_with(|headers| {
+                headers
+                    .iter()
+                    .filter(|(name, _)| *name != HOST && *name != CONTENT_LENGTH)
+                    .filter_map(|(name, value)| {
+                        Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+                    })
+                    .collect()
+            })
+            .ok()?;
+        let body = serde_json::to_string(&request.to_jsonrpc_request()).ok()?;
+        Some(Self { headers, body })
+    }
+
+    /// The stored headers as a `HeaderMap`.
+    pub fn header_map(&self) -> HeaderMap {
+        let mut map = HeaderMap::with_capacity(self.headers.len());
+        for (name, value) in &self.headers {
+            if let (Ok(name), Ok(value)) = (
+                http::HeaderName::from_bytes(name.as_bytes()),
+                http::HeaderValue::from_str(value),
+            ) {
+                map.append(name, value);
+            }
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::jsonrpc::JsonRpcId;
+
+    fn request() -> McpRequest {
+        McpRequest {
+            id: Some(JsonRpcId::Number(7)),
+            method: "tools/call".to_string(),
+            params: Some(serde_json::json!({"name": "delete_user", "arguments": {"id": 1}})),
+            task_metadata: None,
+            received_at: std::time::Instant::now(),
+            correlation_id: uuid::Uuid::new_v4(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_capture_round_trips_headers_and_body() {
+        let mut headers = HeaderMap::new();
+        headers.insert(HOST, "proxy".parse().unwrap());
+        headers.insert(CONTENT_LENGTH, "512".parse().unwrap());
+        headers.insert("authorization", "Bearer agent".parse().unwrap());
+        headers.append("x-trace", "a".parse().unwrap());
+        headers.append("x-trace", "b".parse().unwrap());
+
+        let canonical =
+            with_request_headers(headers, async { CanonicalRequest::capture(&request()) })
+                .await
+                .unwrap();
+
+        let map = canonical.header_map();
+        assert!(map.get(HOST).is_none());
+        assert!(map.get(CONTENT_LENGTH).is_none());
+        assert_eq!(map["authorization"], "Bearer agent");
+        let traces: Vec<_> = map.get_all("x-trace").iter().collect();
+        assert_eq!(traces, ["a", "b"]);
+
+        let body: serde_json::Value = serde_json::from_str(&canonical.body).unwrap();
+        assert_eq!(body["id"], 7);
+        assert_eq!(body["params"]["arguments"]["id"], 1);
+    }
+
+    #[test]
+    fn test_capture_outside_proxy_is_none() {
+        assert!(CanonicalRequest::capture(&request()).is_none());
+    }
+}
@@ -8,6 +8,7 @@
 //! - In-memory TaskStore with concurrent access support
 //! - TTL enforcement and expiration cleanup
 //! - Rate limiting per principal
+//! - Deduplication of identical pending requests
 //!
 //! ## v0.2 Updates
 //!
@@ -21,6 +22,7 @@
 
 use chrono::{DateTime, Utc};
 use dashmap::DashMap;
+use dashmap::mapref::entry::Entry;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::sync::Arc;
@@ -34,6 +36,10 @@ pub use crate::protocol::Sep1686TaskId as TaskId;
 
 // Import TimeoutAction from engine module
 use super::engine::TimeoutAction;
+use super::replay::CanonicalRequest;
+use crate::audit::{self, AuditDecision, AuditGate, AuditRecord};
+use crate::clock::{Clock, SystemClock};
+use crate::config::LiveConfig;
 
 // ============================================================================
 // JSON-RPC ID (for MCP request tracking)
@@ -402,6 +408,11 @@ pub struct Task {
     pub pre_approval_transformed: ToolCallRequest,
     /// SHA256 hash of request for integrity verification
     pub request_hash: String,
+    /// The request as received, for replay once approved
+    ///
+    /// Implements: REQ-GOV-002/F-006
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canonical_request: Option<CanonicalRequest>,
 
     // Principal
     /// Identity of the requesting agent
@@ -457,9 +468,29 @@ impl Task {
         principal: Principal,
         ttl: Duration,
         on_timeout: TimeoutAction,
+    ) -> Self {
+        Self::new_at(
+            original_request,
+            pre_approval_transformed,
+            principal,
+            ttl,
+            on_timeout,
+            Utc::now(),
+        )
+    }
+
+    /// Creates a new task in Working state, created at `now`.
+    ///
+    /// Implements: REQ-GOV-001/F-002
+    fn new_at(
+        original_request: ToolCallRequest,
+        pre_approval_transformed: ToolCallRequest,
+        principal: Principal,
+        ttl: Duration,
+        on_timeout: TimeoutAction,
+        now: DateTime<Utc>,
     ) -> Self {
         let id = TaskId::new();
-        let now = Utc::now();
         // Clamp TTL to max 30 days if conversion fails (overflow for extremely large durations)
         let max_ttl = chrono::Duration::days(30);
         let chrono_ttl = chrono::Duration::from_std(ttl).unwrap_or(max_ttl);
@@ -474,6 +505,7 @@ impl Task {
             original_request,
             pre_approval_transformed,
             request_hash,
+            canonical_request: None,
             principal,
             created_at: now,
             ttl,
@@ -494,13 +526,26 @@ impl Task {
     /// Implements: REQ-GOV-001/F-008.2
     #[must_use]
     pub fn is_expired(&self) -> bool {
-        Utc::now() > self.expires_at
+        self.is_expired_at(Utc::now())
+    }
+
+    /// Returns true if the task has expired as of `now`.
+    ///
+    /// Implements: REQ-GOV-001/F-008.2
+    #[must_use]
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        now > self.expires_at
     }
 
     /// Returns the remaining TTL.
     #[must_use]
     pub fn remaining_ttl(&self) -> Duration {
-        let now = Utc::now();
+        self.remaining_ttl_at(Utc::now())
+    }
+
+    /// Returns the TTL remaining as of `now`.
+    #[must_use]
+    pub fn remaining_ttl_at(&self, now: DateTime<Utc>) -> Duration {
         if now >= self.expires_at {
             Duration::ZERO
         } else {
@@ -595,6 +640,28 @@ pub fn hash_request(request: &ToolCallRequest) -> String {
     format!("{:x}", hasher.finalize())
 }
 
+/// Identifies a request for deduplication: who asks for what.
+///
+/// Implements: REQ-GOV-001/F-002
+///
+/// Unlike [`hash_request`], this covers the principal and method too, so
+/// different agents (or the same name under different methods) never share
+/// an approval.
+#[must_use]
+pub fn request_fingerprint(principal: &Principal, request: &ToolCallRequest) -> String {
+    let mut hasher = Sha256::new();
+    for part in [
+        principal.rate_limit_key().as_str(),
+        &request.method,
+        &request.name,
+    ] {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    hasher.update(request.arguments.to_string().as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
 /// Computes the suggested poll interval based on remaining TTL.
 ///
 /// Implements: REQ-GOV-001/F-002.7
@@ -678,8 +745,11 @@ pub enum TaskError {
     },
 
     /// Global capacity exceeded.
-    #[error("Global task capacity exceeded")]
-    CapacityExceeded,
+    #[error("Global task capacity exceeded, retry after {retry_after:?}")]
+    CapacityExceeded {
+        /// How long until the soonest pending task expires
+        retry_after: Duration,
+    },
 
     /// Result is not yet available.
     #[error("Result not ready for task '{task_id}'")]
@@ -748,6 +818,8 @@ struct TaskEntry {
     terminal_at: Option<DateTime<Utc>>,
     /// Notifier for waiters on this task
     notify: Arc<Notify>,
+    /// Requests attached to this task (the creator plus any joined)
+    waiters: usize,
 }
 
 /// In-memory task store with concurrent access support.
@@ -763,8 +835,17 @@ pub struct TaskStore {
     by_principal: DashMap<String, Vec<TaskId>>,
     /// Configuration
     config: TaskStoreConfig,
+    /// Hot-reloadable overrides for the limits and default TTL in `config`
+    live: Option<Arc<LiveConfig>>,
     /// Counter for pending (non-terminal) tasks
     pending_count: AtomicUsize,
+    /// Task created for each request fingerprint, for joining retries
+    by_fingerprint: DashMap<String, TaskId>,
+    /// Secret mixed into derived task IDs, so they cannot be predicted
+    /// from the request alone
+    id_salt: String,
+    /// Time source for task and approval expiry
+    clock: Arc<dyn Clock>,
 }
 
 impl TaskStore {
@@ -777,10 +858,42 @@ impl TaskStore {
             tasks: DashMap::new(),
             by_principal: DashMap::new(),
             config,
+            live: None,
             pending_count: AtomicUsize::new(0),
+            by_fingerprint: DashMap::new(),
+            id_salt: nanoid::nanoid!(32),
+            clock: SystemClock::shared(),
         }
     }
 
+    /// Read the time from `clock` for task TTLs, approval validity and
+    /// waits, instead of the system clock.
+    ///
+    /// Implements: REQ-GOV-001/F-008
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// The store's time source.
+    ///
+    /// Implements: REQ-GOV-001/F-008
+    #[must_use]
+    pub fn clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
+
+    /// Read rate limits and the default TTL from `live` on every create,
+    /// falling back to the static configuration for unset settings.
+    ///
+    /// Implements: REQ-GOV-001/F-009
+    #[must_use]
+    pub fn with_live_config(mut self, live: Arc<LiveConfig>) -> Self {
+        self.live = Some(live);
+        self
+    }
+
     /// Creates a new task store with default configuration.
     ///
     /// Implements: REQ-GOV-001/§10
@@ -805,6 +918,23 @@ impl TaskStore {
         self.pending_count.load(Ordering::Relaxed)
     }
 
+    /// Counts a new pending task, updating the `approvals_pending` gauge.
+    fn pending_added(&self) {
+        let pending = self.pending_count.fetch_add(1, Ordering::Relaxed) + 1;
+        if let Some(metrics) = crate::metrics::get_approval_metrics() {
+            metrics.record_pending(pending);
+        }
+    }
+
+    /// Counts a task leaving the pending state, updating the
+    /// `approvals_pending` gauge.
+    fn pending_removed(&self) {
+        let pending = self.pending_count.fetch_sub(1, Ordering::Relaxed) - 1;
+        if let Some(metrics) = crate::metrics::get_approval_metrics() {
+            metrics.record_pending(pending);
+        }
+    }
+
     /// Returns the total number of tasks (including terminal).
     ///
     /// Implements: REQ-GOV-001/§10
@@ -832,6 +962,46 @@ impl TaskStore {
             .count()
     }
 
+    /// Projected time until the principal has a free pending-task slot.
+    ///
+    /// Implements: REQ-GOV-001/F-009 (Rate Limiting)
+    ///
+    /// This is the soonest expiry among the principal's pending tasks, since
+    /// each one frees a slot by its TTL at the latest. Clamped to at least
+    /// one second so overdue tasks awaiting the expiry sweep do not produce
+    /// a zero hint.
+    fn next_slot_for_principal(&self, principal_key: &str) -> Duration {
+        let now = self.clock.now();
+        self.by_principal
+            .get(principal_key)
+            .and_then(|ids| {
+                ids.iter()
+                    .filter_map(|id| {
+                        let entry = self.tasks.get(id)?;
+                        (!entry.task.status.is_terminal()).then(|| entry.task.remaining_ttl_at(now))
+                    })
+                    .min()
+            })
+            .unwrap_or(Duration::from_secs(60))
+            .max(Duration::from_secs(1))
+    }
+
+    /// Projected time until any pending-task slot frees up.
+    ///
+    /// Implements: REQ-GOV-001/F-009.4
+    ///
+    /// Like [`Self::next_slot_for_principal`], across all pending tasks.
+    fn next_global_slot(&self) -> Duration {
+        let now = self.clock.now();
+        self.tasks
+            .iter()
+            .filter(|entry| !entry.task.status.is_terminal())
+            .map(|entry| entry.task.remaining_ttl_at(now))
+            .min()
+            .unwrap_or(Duration::from_secs(60))
+            .max(Duration::from_secs(1))
+    }
+
     /// Creates and inserts a new task.
     ///
     /// Implements: REQ-GOV-001/F-002, F-009
@@ -852,33 +1022,144 @@ impl TaskStore {
         ttl: Option<Duration>,
         on_timeout: TimeoutAction,
     ) -> Result<Task, TaskError> {
+        let fingerprint = request_fingerprint(&principal, &pre_approval_transformed);
+        self.insert_task(
+            &fingerprint,
+            original_request,
+            pre_approval_transformed,
+            principal,
+            ttl,
+            on_timeout,
+        )
+    }
+
+    /// Creates a task, or joins the pending task for an identical request.
+    ///
+    /// Implements: REQ-GOV-001/F-002, F-009
+    ///
+    /// Requests are identical when the same principal asks for the same
+    /// method, name and (transformed) arguments. While a task for such a
+    /// request is not yet terminal, a retry is attached to it instead of
+    /// creating a second approval: the returned flag is `true` and no rate
+    /// limit is charged. Once the task is terminal, the next identical
+    /// request starts a new one.
+    ///
+    /// # Errors
+    /// Same as [`TaskStore::create`] when a new task is needed.
+    pub fn create_or_join(
+        &self,
+        original_request: ToolCallRequest,
+        pre_approval_transformed: ToolCallRequest,
+        principal: Principal,
+        ttl: Option<Duration>,
+        on_timeout: TimeoutAction,
+    ) -> Result<(Task, bool), TaskError> {
+        let fingerprint = request_fingerprint(&principal, &pre_approval_transformed);
+
+        // The fingerprint entry is held until the new task is indexed, so
+        // concurrent identical requests cannot both create one.
+        let slot = match self.by_fingerprint.entry(fingerprint) {
+            Entry::Occupied(slot) => {
+                if let Some(mut entry) = self.tasks.get_mut(slot.get())
+                    && !entry.task.status.is_terminal()
+                {
+                    entry.waiters += 1;
+                    return Ok((entry.task.clone(), true));
+                }
+                Entry::Occupied(slot)
+            }
+            vacant => vacant,
+        };
+
+        let task = self.insert_task(
+            slot.key(),
+            original_request,
+            pre_approval_transformed,
+            principal,
+            ttl,
+            on_timeout,
+        )?;
+        slot.insert_entry(task.id.clone());
+        Ok((task, false))
+    }
+
+    /// Derives a task ID from `fingerprint` that is not already in use.
+    ///
+    /// Implements: REQ-CORE-007/§6.5
+    ///
+    /// The ID is a keyed hash of the fingerprint and an attempt counter. The
+    /// counter only moves past 0 when an earlier task for the same request
+    /// is still stored, or on a (vanishingly unlikely) hash collision. With
+    /// one attempt more than there are stored tasks, only hash collisions
+    /// can exhaust the attempts.
+    fn derive_task_id(&self, fingerprint: &str) -> Result<TaskId, TaskError> {
+        let attempts = u32::try_from(self.tasks.len())
+            .unwrap_or(u32::MAX)
+            .saturating_add(1);
+        (0..attempts)
+            .map(|attempt| {
+                TaskId::derive(format!("{}\0{fingerprint}\0{attempt}", self.id_salt).as_bytes())
+            })
+            .find(|id| !self.tasks.contains_key(id))
+            .ok_or_else(|| TaskError::Internal {
+                details: format!("no free task ID after {attempts} attempts"),
+            })
+    }
+
+    /// Checks limits and inserts a new task under a derived ID.
+    fn insert_task(
+        &self,
+        fingerprint: &str,
+        original_request: ToolCallRequest,
+        pre_approval_transformed: ToolCallRequest,
+        principal: Principal,
+        ttl: Option<Duration>,
+        on_timeout: TimeoutAction,
+    ) -> Result<Task, TaskError> {
+        let runtime = self.live.as_ref().map(|live| live.load());
+        let runtime = runtime.as_deref();
+        let max_pending_global = runtime
+            .and_then(|r| r.max_pending_global)
+            .unwrap_or(self.config.max_pending_global);
+        let max_pending_per_principal = runtime
+            .and_then(|r| r.max_pending_per_principal)
+            .unwrap_or(self.config.max_pending_per_principal);
+        let default_ttl = runtime
+            .and_then(|r| r.default_task_ttl)
+            .unwrap_or(self.config.default_ttl);
+
         // F-009.3, F-009.4: Check global capacity
-        if self.pending_count() >= self.config.max_pending_global {
-            return Err(TaskError::CapacityExceeded);
+        if self.pending_count() >= max_pending_global {
+            return Err(TaskError::CapacityExceeded {
+                retry_after: self.next_global_slot(),
+            });
         }
 
         // F-009.1, F-009.2: Check per-principal limit
         let principal_key = principal.rate_limit_key();
         let pending = self.count_pending_for_principal(&principal_key);
-        if pending >= self.config.max_pending_per_principal {
+        if pending >= max_pending_per_principal {
+            let retry_after = self.next_slot_for_principal(&principal_key);
             return Err(TaskError::RateLimited {
                 principal: principal_key,
-                retry_after: Duration::from_secs(60),
+                retry_after,
             });
         }
 
         // F-002.4: Apply TTL bounds
-        let ttl = ttl.unwrap_or(self.config.default_ttl);
+        let ttl = ttl.unwrap_or(default_ttl);
         let ttl = ttl.clamp(self.config.min_ttl, self.config.max_ttl);
 
         // Create task with on_timeout captured at creation time
-        let task = Task::new(
+        let mut task = Task::new_at(
             original_request,
             pre_approval_transformed,
             principal,
             ttl,
             on_timeout,
+            self.clock.now(),
         );
+        task.id = self.derive_task_id(fingerprint)?;
         let task_id = task.id.clone();
         let task_clone = task.clone();
 
@@ -887,6 +1168,7 @@ impl TaskStore {
             task,
             terminal_at: None,
             notify: Arc::new(Notify::new()),
+            waiters: 1,
         };
         self.tasks.insert(task_id.clone(), entry);
 
@@ -897,7 +1179,7 @@ impl TaskStore {
             .push(task_id);
 
         // Increment pending count
-        self.pending_count.fetch_add(1, Ordering::Relaxed);
+        self.pending_added();
 
         Ok(task_clone)
     }
@@ -935,8 +1217,8 @@ impl TaskStore {
 
         // Track when task became terminal
         if !was_terminal && entry.task.status.is_terminal() {
-            entry.terminal_at = Some(Utc::now());
-            self.pending_count.fetch_sub(1, Ordering::Relaxed);
+            entry.terminal_at = Some(self.clock.now());
+            self.pending_removed();
             // Notify any waiters
             entry.notify.notify_waiters();
         }
@@ -968,8 +1250,8 @@ impl TaskStore {
 
         // Track when task became terminal
         if !was_terminal && entry.task.status.is_terminal() {
-            entry.terminal_at = Some(Utc::now());
-            self.pending_count.fetch_sub(1, Ordering::Relaxed);
+            entry.terminal_at = Some(self.clock.now());
+            self.pending_removed();
             // Notify any waiters
             entry.notify.notify_waiters();
         }
@@ -1003,7 +1285,7 @@ impl TaskStore {
             });
         }
 
-        let now = Utc::now();
+        let now = self.clock.now();
         let approval_valid_until = now
             + chrono::Duration::from_std(approval_valid_for).unwrap_or(chrono::Duration::zero());
 
@@ -1016,26 +1298,56 @@ impl TaskStore {
         });
 
         // Transition based on decision
-        let (new_status, reason) = match decision {
+        let (new_status, reason) = match &decision {
             ApprovalDecision::Approved => (TaskStatus::Executing, Some("Approved".to_string())),
             ApprovalDecision::Rejected { reason } => (
                 TaskStatus::Rejected,
-                reason.or_else(|| Some("Rejected".to_string())),
+                reason.clone().or_else(|| Some("Rejected".to_string())),
             ),
         };
 
         let was_terminal = entry.task.status.is_terminal();
         entry.task.transition(new_status, reason)?;
 
+        let (outcome, reason) = match decision {
+            ApprovalDecision::Approved => (AuditDecision::Approved, None),
+            ApprovalDecision::Rejected { reason } => (AuditDecision::Rejected, reason),
+        };
+        let approver = entry.task.approval.as_ref().map(|a| a.decided_by.clone());
+        audit_outcome(&entry.task, outcome, approver, reason);
+
         if !was_terminal && entry.task.status.is_terminal() {
-            entry.terminal_at = Some(Utc::now());
-            self.pending_count.fetch_sub(1, Ordering::Relaxed);
-            entry.notify.notify_waiters();
+            entry.terminal_at = Some(self.clock.now());
+            self.pending_removed();
         }
+        // Wake on approval too: blocking-mode handlers wait for the decision,
+        // not for the terminal state.
+        entry.notify.notify_waiters();
 
         Ok(entry.task.clone())
     }
 
+    /// Stores the request to replay once a task is approved.
+    ///
+    /// Implements: REQ-GOV-002/F-006
+    ///
+    /// Only the first request is kept: identical requests that join the
+    /// task are replayed as the one that created it.
+    pub fn set_canonical_request(
+        &self,
+        task_id: &TaskId,
+        request: CanonicalRequest,
+    ) -> Result<(), TaskError> {
+        let mut entry = self
+            .tasks
+            .get_mut(task_id)
+            .ok_or_else(|| TaskError::NotFound {
+                task_id: task_id.clone(),
+            })?;
+        entry.task.canonical_request.get_or_insert(request);
+        Ok(())
+    }
+
     /// Marks a task as completed with a result.
     ///
     /// Implements: REQ-GOV-001 (called by REQ-GOV-002)
@@ -1061,8 +1373,8 @@ impl TaskStore {
             TaskStatus::Completed,
             Some("Execution completed".to_string()),
         )?;
-        entry.terminal_at = Some(Utc::now());
-        self.pending_count.fetch_sub(1, Ordering::Relaxed);
+        entry.terminal_at = Some(self.clock.now());
+        self.pending_removed();
         entry.notify.notify_waiters();
 
         Ok(entry.task.clone())
@@ -1091,10 +1403,10 @@ impl TaskStore {
         entry
             .task
             .transition(TaskStatus::Failed, Some(failure.reason))?;
-        entry.terminal_at = Some(Utc::now());
+        entry.terminal_at = Some(self.clock.now());
 
         if !was_terminal {
-            self.pending_count.fetch_sub(1, Ordering::Relaxed);
+            self.pending_removed();
         }
         entry.notify.notify_waiters();
 
@@ -1108,6 +1420,52 @@ impl TaskStore {
     /// This operation is idempotent: cancelling an already-cancelled task
     /// returns success per SEP-1686 spec requirements.
     pub fn cancel(&self, task_id: &TaskId) -> Result<Task, TaskError> {
+        self.cancel_with_reason(task_id, "Cancelled by agent")
+            .map(|(task, _)| task)
+    }
+
+    /// Cancels a task whose agent disconnected while waiting on it.
+    ///
+    /// Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    ///
+    /// Same transition as [`cancel`](Self::cancel), but audited as
+    /// `client_disconnected` so the log shows why the approval went
+    /// undecided. While other requests joined to the task are still
+    /// waiting on it, the task is left pending and returned unchanged.
+    pub fn abandon(&self, task_id: &TaskId) -> Result<Task, TaskError> {
+        {
+            let mut entry = self
+                .tasks
+                .get_mut(task_id)
+                .ok_or_else(|| TaskError::NotFound {
+                    task_id: task_id.clone(),
+                })?;
+            if entry.waiters > 1 && !entry.task.status.is_terminal() {
+                entry.waiters -= 1;
+                return Ok(entry.task.clone());
+            }
+        }
+
+        let reason = "Client disconnected";
+        let (task, newly_cancelled) = self.cancel_with_reason(task_id, reason)?;
+        if newly_cancelled {
+            audit_outcome(
+                &task,
+                AuditDecision::ClientDisconnected,
+                None,
+                Some(reason.to_string()),
+            );
+        }
+        Ok(task)
+    }
+
+    /// Cancel from InputRequired; also reports whether this call made the
+    /// transition (false when the task was already cancelled).
+    fn cancel_with_reason(
+        &self,
+        task_id: &TaskId,
+        reason: &str,
+    ) -> Result<(Task, bool), TaskError> {
         let mut entry = self
             .tasks
             .get_mut(task_id)
@@ -1120,7 +1478,7 @@ impl TaskStore {
             if entry.task.status.is_terminal() {
                 // F-006.2: Idempotency - already cancelled returns success
                 if entry.task.status == TaskStatus::Cancelled {
-                    return Ok(entry.task.clone());
+                    return Ok((entry.task.clone(), false));
                 }
                 // F-006.2b: Other terminal states cannot be cancelled
                 return Err(TaskError::AlreadyTerminal {
@@ -1136,15 +1494,14 @@ impl TaskStore {
             });
         }
 
-        entry.task.transition(
-            TaskStatus::Cancelled,
-            Some("Cancelled by agent".to_string()),
-        )?;
-        entry.terminal_at = Some(Utc::now());
-        self.pending_count.fetch_sub(1, Ordering::Relaxed);
+        entry
+            .task
+            .transition(TaskStatus::Cancelled, Some(reason.to_string()))?;
+        entry.terminal_at = Some(self.clock.now());
+        self.pending_removed();
         entry.notify.notify_waiters();
 
-        Ok(entry.task.clone())
+        Ok((entry.task.clone(), true))
     }
 
     /// Expires non-terminal tasks that have exceeded their TTL.
@@ -1153,7 +1510,7 @@ impl TaskStore {
     ///
     /// Returns the number of tasks expired.
     pub fn expire_overdue(&self) -> usize {
-        let now = Utc::now();
+        let now = self.clock.now();
         let mut expired = 0;
 
         // Collect task IDs to expire (to avoid holding locks while modifying)
@@ -1175,34 +1532,71 @@ impl TaskStore {
             if let Some(mut entry) = self.tasks.get_mut(&task_id)
                 && !entry.task.status.is_terminal()
                 && now > entry.task.expires_at
-                && entry
-                    .task
-                    .transition(TaskStatus::Expired, Some("TTL exceeded".to_string()))
-                    .is_ok()
+                && self.expire_entry(&mut entry, now, "TTL exceeded").is_ok()
             {
-                entry.terminal_at = Some(now);
-                self.pending_count.fetch_sub(1, Ordering::Relaxed);
-                entry.notify.notify_waiters();
                 expired += 1;
-                tracing::warn!(
-                    task_id = %task_id,
-                    tool = %entry.task.original_request.name,
-                    age_seconds = (now - entry.task.created_at).num_seconds(),
-                    "Task expired"
-                );
             }
         }
 
         expired
     }
 
+    /// Expires a non-terminal task before its TTL, e.g. when its approval
+    /// timed out.
+    ///
+    /// Implements: REQ-GOV-001/F-008
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaskError::NotFound` for an unknown task, or the transition
+    /// error if it can no longer expire.
+    pub fn expire(&self, task_id: &TaskId, reason: &str) -> Result<Task, TaskError> {
+        let mut entry = self
+            .tasks
+            .get_mut(task_id)
+            .ok_or_else(|| TaskError::NotFound {
+                task_id: task_id.clone(),
+            })?;
+        self.expire_entry(&mut entry, self.clock.now(), reason)?;
+        Ok(entry.task.clone())
+    }
+
+    /// Move a task to `Expired`, waking waiters and auditing the outcome.
+    fn expire_entry(
+        &self,
+        entry: &mut TaskEntry,
+        now: DateTime<Utc>,
+        reason: &str,
+    ) -> Result<(), TaskError> {
+        entry
+            .task
+            .transition(TaskStatus::Expired, Some(reason.to_string()))?;
+        entry.terminal_at = Some(now);
+        self.pending_removed();
+        entry.notify.notify_waiters();
+        audit_outcome(
+            &entry.task,
+            AuditDecision::Expired,
+            None,
+            Some(reason.to_string()),
+        );
+        tracing::warn!(
+            task_id = %entry.task.id,
+            tool = %entry.task.original_request.name,
+            age_seconds = (now - entry.task.created_at).num_seconds(),
+            reason,
+            "Task expired"
+        );
+        Ok(())
+    }
+
     /// Removes terminal tasks that have exceeded the grace period.
     ///
     /// Implements: REQ-GOV-001/F-008.4
     ///
     /// Returns the number of tasks removed.
     pub fn cleanup_terminal(&self) -> usize {
-        let now = Utc::now();
+        let now = self.clock.now();
         let grace_period =
             chrono::Duration::from_std(self.config.terminal_grace_period).unwrap_or_default();
 
@@ -1230,6 +1624,13 @@ impl TaskStore {
                 if let Some(mut ids) = self.by_principal.get_mut(&principal_key) {
                     ids.retain(|id| id != &task_id);
                 }
+                // Clean up fingerprint index
+                let fingerprint = request_fingerprint(
+                    &entry.task.principal,
+                    &entry.task.pre_approval_transformed,
+                );
+                self.by_fingerprint
+                    .remove_if(&fingerprint, |_, id| id == &task_id);
             }
         }
 
@@ -1257,7 +1658,7 @@ impl TaskStore {
             .filter_map(|id| self.tasks.get(id).map(|e| e.task.clone()))
             .collect();
 
-        tasks.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        tasks.sort_by_key(|t| std::cmp::Reverse(t.created_at));
 
         // Apply pagination
         tasks.into_iter().skip(offset).take(limit).collect()
@@ -1269,16 +1670,45 @@ impl TaskStore {
     ///
     /// Returns the task if it reaches a terminal state within the timeout,
     /// or an error if the timeout is exceeded.
+    pub async fn wait_for_terminal(
+        &self,
+        task_id: &TaskId,
+        timeout: Duration,
+    ) -> Result<Task, TaskError> {
+        self.wait_until(task_id, timeout, TaskStatus::is_terminal)
+            .await
+    }
+
+    /// Waits for an approval decision on a task.
+    ///
+    /// Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    ///
+    /// Returns once the task has left `Working`/`InputRequired`: approved
+    /// tasks are returned in `Executing` so the caller can replay the
+    /// request, everything else in its terminal state.
+    pub async fn wait_for_decision(
+        &self,
+        task_id: &TaskId,
+        timeout: Duration,
+    ) -> Result<Task, TaskError> {
+        self.wait_until(task_id, timeout, |status| {
+            !matches!(status, TaskStatus::Working | TaskStatus::InputRequired)
+        })
+        .await
+    }
+
+    /// Waits until the task status satisfies `done`.
     ///
-    /// Uses a loop to handle the race between checking terminal status and
+    /// Uses a loop to handle the race between checking the status and
     /// registering for notifications. The Notify::notified() future is created
     /// before checking the status to avoid missing notifications.
-    pub async fn wait_for_terminal(
+    async fn wait_until(
         &self,
         task_id: &TaskId,
         timeout: Duration,
+        done: impl Fn(&TaskStatus) -> bool,
     ) -> Result<Task, TaskError> {
-        let deadline = tokio::time::Instant::now() + timeout;
+        let deadline = self.clock.instant() + timeout;
 
         loop {
             // Get the notify handle first
@@ -1292,19 +1722,19 @@ impl TaskStore {
             // Create the notified future BEFORE checking status to avoid race
             let notified = notify.notified();
 
-            // Now check if already terminal (after creating notified future)
+            // Now check if already done (after creating notified future)
             {
                 let entry = self.tasks.get(task_id).ok_or_else(|| TaskError::NotFound {
                     task_id: task_id.clone(),
                 })?;
 
-                if entry.task.status.is_terminal() {
+                if done(&entry.task.status) {
                     return Ok(entry.task.clone());
                 }
             }
 
             // Calculate remaining time
-            let now = tokio::time::Instant::now();
+            let now = self.clock.instant();
             if now >= deadline {
                 return Err(TaskError::ResultNotReady {
                     task_id: task_id.clone(),
@@ -1313,13 +1743,17 @@ impl TaskStore {
             let remaining = deadline - now;
 
             // Wait for notification or timeout
-            if tokio::time::timeout(remaining, notified).await.is_err() {
+            let timed_out = tokio::select! {
+                () = notified => false,
+                () = self.clock.sleep(remaining) => true,
+            };
+            if timed_out {
                 // Timeout - do one final check
                 let entry = self.tasks.get(task_id).ok_or_else(|| TaskError::NotFound {
                     task_id: task_id.clone(),
                 })?;
 
-                if entry.task.status.is_terminal() {
+                if done(&entry.task.status) {
                     return Ok(entry.task.clone());
                 }
                 return Err(TaskError::ResultNotReady {
@@ -1368,9 +1802,54 @@ impl TaskStore {
 // Tests
 // ============================================================================
 
+/// Write an audit record for a Gate 4 outcome.
+///
+/// Implements: REQ-OBS-002 (Audit Trail)
+///
+/// The decision arrives on the approver's request (or the expiry sweep), so
+/// the record is correlated by task ID rather than by the current request ID.
+fn audit_outcome(
+    task: &Task,
+    decision: AuditDecision,
+    approver: Option<String>,
+    reason: Option<String>,
+) {
+    if !audit::is_enabled() {
+        return;
+    }
+    let mut record = AuditRecord::new(
+        &task.original_request.method,
+        &task.original_request.name,
+        decision,
+        AuditGate::Approval,
+    )
+    .with_principal(&task.principal.app_name)
+    .with_task_id(task.id.to_string())
+    .with_reason(reason)
+    .with_reject_code(approval_reject_code(task, decision));
+    record.request_id = None;
+    if let Some(approver) = approver {
+        record = record.with_approver(approver);
+    }
+    audit::record(record);
+}
+
+/// Reject code of the error `tasks/result` returns for a task that ended
+/// with `decision` (see `ThoughtGateError::reject_code`).
+fn approval_reject_code(task: &Task, decision: AuditDecision) -> Option<&'static str> {
+    match decision {
+        AuditDecision::Rejected => Some("approval_rejected"),
+        AuditDecision::Expired if task.on_timeout == TimeoutAction::Deny => {
+            Some("approval_timeout")
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::MockClock;
 
     fn test_request() -> ToolCallRequest {
         ToolCallRequest {
@@ -1620,6 +2099,44 @@ mod tests {
         assert!(matches!(result, Err(TaskError::NotFound { .. })));
     }
 
+    /// Tests that the rate-limit hint is the soonest pending expiry.
+    ///
+    /// Verifies: REQ-GOV-001/F-009
+    #[test]
+    fn test_rate_limited_retry_after_tracks_soonest_expiry() {
+        let config = TaskStoreConfig {
+            max_pending_per_principal: 2,
+            ..Default::default()
+        };
+        let store = TaskStore::new(config);
+
+        for ttl in [Duration::from_secs(300), Duration::from_secs(120)] {
+            store
+                .create(
+                    test_request(),
+                    test_request(),
+                    test_principal(),
+                    Some(ttl),
+                    TimeoutAction::default(),
+                )
+                .unwrap();
+        }
+
+        let result = store.create(
+            test_request(),
+            test_request(),
+            test_principal(),
+            None,
+            TimeoutAction::default(),
+        );
+        let Err(TaskError::RateLimited { retry_after, .. }) = result else {
+            panic!("expected RateLimited, got {result:?}");
+        };
+        // Slot frees when the 120s task expires, not the 300s one
+        assert!(retry_after <= Duration::from_secs(120));
+        assert!(retry_after > Duration::from_secs(110));
+    }
+
     /// Tests rate limiting per principal.
     ///
     /// Verifies: EC-TASK-014, REQ-GOV-001/F-009.2
@@ -1674,6 +2191,42 @@ mod tests {
             .unwrap();
     }
 
+    /// Tests that a reloaded per-principal limit applies to the next create.
+    ///
+    /// Verifies: REQ-GOV-001/F-009.2
+    #[test]
+    fn test_rate_limit_follows_live_config() {
+        let live = Arc::new(LiveConfig::new(crate::config::RuntimeSettings {
+            max_pending_per_principal: Some(1),
+            ..Default::default()
+        }));
+        let store = TaskStore::with_defaults().with_live_config(live.clone());
+        let create = || {
+            store.create(
+                test_request(),
+                test_request(),
+                test_principal(),
+                None,
+                TimeoutAction::default(),
+            )
+        };
+
+        create().unwrap();
+        assert!(matches!(create(), Err(TaskError::RateLimited { .. })));
+
+        // Raise the limit at runtime
+        live.update(crate::config::RuntimeSettings {
+            max_pending_per_principal: Some(2),
+            ..Default::default()
+        });
+        create().unwrap();
+        assert!(matches!(create(), Err(TaskError::RateLimited { .. })));
+
+        // Unset falls back to the static configuration (10)
+        live.update(crate::config::RuntimeSettings::default());
+        create().unwrap();
+    }
+
     /// Tests global capacity limit.
     ///
     /// Verifies: EC-TASK-015, REQ-GOV-001/F-009.4
@@ -1712,7 +2265,12 @@ mod tests {
             None,
             TimeoutAction::default(),
         );
-        assert!(matches!(result, Err(TaskError::CapacityExceeded)));
+        match result {
+            Err(TaskError::CapacityExceeded { retry_after }) => {
+                assert!(retry_after <= store.config().default_ttl);
+            }
+            other => panic!("expected capacity exceeded, got {other:?}"),
+        }
     }
 
     /// Tests task cancellation.
@@ -1743,6 +2301,112 @@ mod tests {
         assert_eq!(store.pending_count(), 0);
     }
 
+    /// Abandoning cancels the task with its own reason, idempotently.
+    ///
+    /// Verifies: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    #[test]
+    fn test_task_abandon() {
+        let store = TaskStore::with_defaults();
+
+        let task = store
+            .create(
+                test_request(),
+                test_request(),
+                test_principal(),
+                None,
+                TimeoutAction::default(),
+            )
+            .unwrap();
+        store
+            .transition(&task.id, TaskStatus::InputRequired, None)
+            .unwrap();
+
+        let abandoned = store.abandon(&task.id).unwrap();
+        assert_eq!(abandoned.status, TaskStatus::Cancelled);
+        assert_eq!(
+            abandoned.transitions.last().unwrap().reason.as_deref(),
+            Some("Client disconnected")
+        );
+        assert_eq!(store.pending_count(), 0);
+        assert!(store.abandon(&task.id).is_ok());
+        assert_eq!(store.pending_count(), 0);
+    }
+
+    /// Tests identical pending requests collapse onto one task.
+    ///
+    /// Verifies: REQ-GOV-001/F-002 (idempotent task creation)
+    #[test]
+    fn test_create_or_join_collapses_identical_requests() {
+        let store = TaskStore::with_defaults();
+        let create = |principal: Principal| {
+            store
+                .create_or_join(
+                    test_request(),
+                    test_request(),
+                    principal,
+                    None,
+                    TimeoutAction::default(),
+                )
+                .unwrap()
+        };
+
+        let (first, joined) = create(test_principal());
+        assert!(!joined);
+        let (second, joined) = create(test_principal());
+        assert!(joined);
+        assert_eq!(second.id, first.id);
+        assert_eq!(store.pending_count(), 1);
+
+        // Another principal never shares the approval
+        let (other, joined) = create(Principal::new("other-app"));
+        assert!(!joined);
+        assert_ne!(other.id, first.id);
+
+        // Once the task is terminal, a retry starts a new one
+        store
+            .transition(&first.id, TaskStatus::InputRequired, None)
+            .unwrap();
+        store.cancel(&first.id).unwrap();
+        let (retry, joined) = create(test_principal());
+        assert!(!joined);
+        assert_ne!(retry.id, first.id);
+        assert!(retry.id.is_thoughtgate_owned());
+    }
+
+    /// Tests a joined task is only abandoned when its last waiter leaves.
+    ///
+    /// Verifies: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    #[test]
+    fn test_abandon_joined_task_waits_for_last_waiter() {
+        let store = TaskStore::with_defaults();
+        let create = || {
+            store
+                .create_or_join(
+                    test_request(),
+                    test_request(),
+                    test_principal(),
+                    None,
+                    TimeoutAction::default(),
+                )
+                .unwrap()
+                .0
+        };
+        let task = create();
+        create();
+        store
+            .transition(&task.id, TaskStatus::InputRequired, None)
+            .unwrap();
+
+        assert_eq!(
+            store.abandon(&task.id).unwrap().status,
+            TaskStatus::InputRequired
+        );
+        assert_eq!(
+            store.abandon(&task.id).unwrap().status,
+            TaskStatus::Cancelled
+        );
+    }
+
     /// Tests cannot cancel completed task.
     ///
     /// Verifies: EC-TASK-008
@@ -1787,6 +2451,52 @@ mod tests {
         assert!(matches!(result, Err(TaskError::AlreadyTerminal { .. })));
     }
 
+    /// Tests that an approval decision is written to the audit trail.
+    ///
+    /// Verifies: REQ-OBS-002 (approve-then-approved is audited)
+    #[test]
+    fn test_record_approval_is_audited() {
+        crate::audit::testing::install();
+        let store = TaskStore::with_defaults();
+        let request = ToolCallRequest {
+            name: "audit_approved_tool".to_string(),
+            ..test_request()
+        };
+
+        let task = store
+            .create(
+                request.clone(),
+                request,
+                test_principal(),
+                None,
+                TimeoutAction::default(),
+            )
+            .unwrap();
+        store
+            .transition(&task.id, TaskStatus::InputRequired, None)
+            .unwrap();
+        // Pending approval is not a final decision
+        assert!(crate::audit::testing::records_for("audit_approved_tool").is_empty());
+
+        store
+            .record_approval(
+                &task.id,
+                ApprovalDecision::Approved,
+                "alice".to_string(),
+                Duration::from_secs(60),
+            )
+            .unwrap();
+
+        let records = crate::audit::testing::records_for("audit_approved_tool");
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.decision, AuditDecision::Approved);
+        assert_eq!(record.gate, AuditGate::Approval);
+        assert_eq!(record.approver.as_deref(), Some("alice"));
+        assert_eq!(record.principal.as_deref(), Some("test-app"));
+        assert_eq!(record.task_id, Some(task.id.to_string()));
+    }
+
     /// Tests cannot cancel executing task.
     ///
     /// Verifies: EC-TASK-009
@@ -1835,11 +2545,11 @@ mod tests {
     #[test]
     fn test_task_expiration() {
         let config = TaskStoreConfig {
-            min_ttl: Duration::from_millis(10),
-            default_ttl: Duration::from_millis(10),
+            default_ttl: Duration::from_secs(60),
             ..Default::default()
         };
-        let store = TaskStore::new(config);
+        let clock = MockClock::new();
+        let store = TaskStore::new(config).with_clock(Arc::new(clock.clone()));
 
         let task = store
             .create(
@@ -1856,10 +2566,13 @@ mod tests {
             .transition(&task.id, TaskStatus::InputRequired, None)
             .unwrap();
 
-        // Wait for expiration (longer than TTL to ensure expiration)
-        std::thread::sleep(Duration::from_millis(50));
+        // Not yet expired at the TTL itself
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(store.expire_overdue(), 0);
+        assert!(!store.get(&task.id).unwrap().is_expired_at(clock.now()));
 
         // Run cleanup
+        clock.advance(Duration::from_secs(1));
         let expired = store.expire_overdue();
         assert_eq!(expired, 1);
 
@@ -2098,6 +2811,83 @@ mod tests {
         assert!(rejected.approval.is_some());
     }
 
+    /// Tests that approval wakes decision waiters before the task is terminal.
+    ///
+    /// Verifies: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    #[tokio::test]
+    async fn test_wait_for_decision_wakes_on_approval() {
+        let store = Arc::new(TaskStore::with_defaults());
+
+        let task = store
+            .create(
+                test_request(),
+                test_request(),
+                test_principal(),
+                None,
+                TimeoutAction::default(),
+            )
+            .unwrap();
+        store
+            .transition(&task.id, TaskStatus::InputRequired, None)
+            .unwrap();
+
+        // Not decided yet
+        assert!(matches!(
+            store
+                .wait_for_decision(&task.id, Duration::from_millis(20))
+                .await,
+            Err(TaskError::ResultNotReady { .. })
+        ));
+
+        let waiter = {
+            let store = store.clone();
+            let task_id = task.id.clone();
+            tokio::spawn(async move {
+                store
+                    .wait_for_decision(&task_id, Duration::from_secs(5))
+                    .await
+            })
+        };
+        tokio::task::yield_now().await;
+
+        store
+            .record_approval(
+                &task.id,
+                ApprovalDecision::Approved,
+                "approver@example.com".to_string(),
+                Duration::from_secs(300),
+            )
+            .unwrap();
+
+        let decided = waiter.await.unwrap().unwrap();
+        assert_eq!(decided.status, TaskStatus::Executing);
+    }
+
+    /// Tests that waiting for a decision times out on the store's clock.
+    ///
+    /// Verifies: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    #[tokio::test]
+    async fn test_wait_for_decision_times_out_on_clock() {
+        let clock = MockClock::new();
+        let store = TaskStore::with_defaults().with_clock(Arc::new(clock.clone()));
+        let task = store
+            .create(
+                test_request(),
+                test_request(),
+                test_principal(),
+                None,
+                TimeoutAction::default(),
+            )
+            .unwrap();
+
+        let mut wait = std::pin::pin!(store.wait_for_decision(&task.id, Duration::from_secs(30)));
+        assert!(futures::poll!(wait.as_mut()).is_pending());
+        clock.advance(Duration::from_secs(29));
+        assert!(futures::poll!(wait.as_mut()).is_pending());
+        clock.advance(Duration::from_secs(1));
+        assert!(matches!(wait.await, Err(TaskError::ResultNotReady { .. })));
+    }
+
     /// Tests full task lifecycle: create → approve → complete.
     #[test]
     fn test_full_lifecycle() {
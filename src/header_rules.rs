@@ -0,0 +1,322 @@
+//! Per-upstream header mutation.
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-001 F-003 (Transparency - header handling)
+//! - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+//!
+//! # Overview
+//!
+//! Each source can rewrite the headers of the traffic it carries with a
+//! `headers:` block (see [`SourceHeaders`]). Rules run in a fixed order, in
+//! both directions:
+//!
+//! 1. `remove` drops headers
+//! 2. `set` replaces any existing values
+//! 3. `add` appends, keeping existing values
+//! 4. `forwarded` appends `X-Forwarded-For` (requests only) and `Via`
+//! 5. `principal_header` carries the caller's identity (requests only)
+//!
+//! Connection-specific headers are removed by the proxy before any rule
+//! runs, so a rule cannot reintroduce them.
+
+use std::net::IpAddr;
+
+use http::Version;
+use http::header::{HeaderMap, HeaderName, HeaderValue, VIA};
+
+use crate::config::{ConfigError, HeaderMutations, SourceHeaders};
+use crate::policy::principal::request_principal;
+
+/// `X-Forwarded-For` header name.
+pub const X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+
+/// Pseudonym ThoughtGate uses in `Via`.
+const VIA_PSEUDONYM: &str = "thoughtgate";
+
+/// Parsed changes for one direction.
+#[derive(Debug, Clone, Default)]
+struct Mutations {
+    remove: Vec<HeaderName>,
+    set: Vec<(HeaderName, HeaderValue)>,
+    add: Vec<(HeaderName, HeaderValue)>,
+}
+
+impl Mutations {
+    fn parse(field: &str, config: &HeaderMutations) -> Result<Self, ConfigError> {
+        let name = |section: &str, name: &str| {
+            HeaderName::from_bytes(name.as_bytes()).map_err(|e| ConfigError::OutOfRange {
+                field: format!("{field}.{section}"),
+                message: format!("invalid header name '{name}': {e}"),
+            })
+        };
+        let pair = |section: &str, key: &String, value: &String| {
+            let value = HeaderValue::from_str(value).map_err(|e| ConfigError::OutOfRange {
+                field: format!("{field}.{section}.{key}"),
+                message: format!("invalid header value: {e}"),
+            })?;
+            Ok::<_, ConfigError>((name(section, key)?, value))
+        };
+
+        // Sorted so rules apply in the same order on every start
+        let mut set: Vec<_> = config.set.iter().collect();
+        set.sort();
+        let mut add: Vec<_> = config.add.iter().collect();
+        add.sort();
+
+        Ok(Self {
+            remove: config
+                .remove
+                .iter()
+                .map(|n| name("remove", n))
+                .collect::<Result<_, _>>()?,
+            set: set
+                .into_iter()
+                .map(|(k, v)| pair("set", k, v))
+                .collect::<Result<_, _>>()?,
+            add: add
+                .into_iter()
+                .map(|(k, v)| pair("add", k, v))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn apply(&self, headers: &mut HeaderMap) {
+        for name in &self.remove {
+            headers.remove(name);
+        }
+        for (name, value) in &self.set {
+            headers.insert(name.clone(), value.clone());
+        }
+        for (name, value) in &self.add {
+            headers.append(name.clone(), value.clone());
+        }
+    }
+}
+
+/// Header mutation rules for one upstream.
+///
+/// The default only adds the `forwarded` headers.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+#[derive(Debug, Clone)]
+pub struct HeaderRules {
+    request: Mutations,
+    response: Mutations,
+    forwarded: bool,
+    principal_header: Option<HeaderName>,
+}
+
+impl Default for HeaderRules {
+    fn default() -> Self {
+        Self {
+            request: Mutations::default(),
+            response: Mutations::default(),
+            forwarded: true,
+            principal_header: None,
+        }
+    }
+}
+
+impl HeaderRules {
+    /// Parse a source's `headers:` block.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::OutOfRange` naming the first invalid header
+    /// name or value.
+    pub fn from_config(source_id: &str, config: &SourceHeaders) -> Result<Self, ConfigError> {
+        let field = format!("sources.{source_id}.headers");
+        let principal_header = config
+            .principal_header
+            .as_deref()
+            .map(|name| {
+                HeaderName::from_bytes(name.as_bytes()).map_err(|e| ConfigError::OutOfRange {
+                    field: format!("{field}.principal_header"),
+                    message: format!("invalid header name '{name}': {e}"),
+                })
+            })
+            .transpose()?;
+        Ok(Self {
+            request: Mutations::parse(&format!("{field}.request"), &config.request)?,
+            response: Mutations::parse(&format!("{field}.response"), &config.response)?,
+            forwarded: config.forwarded,
+            principal_header,
+        })
+    }
+
+    /// Rewrite the headers of a request about to be sent upstream.
+    ///
+    /// `client` is the address the request came from, if known; `version`
+    /// is the protocol it arrived over, as recorded in `Via`.
+    pub fn apply_request(&self, headers: &mut HeaderMap, client: Option<IpAddr>, version: Version) {
+        self.request.apply(headers);
+        if self.forwarded {
+            if let Some(client) = client {
+                append_forwarded_for(headers, client);
+            }
+            append_via(headers, version);
+        }
+        if let Some(name) = &self.principal_header {
+            // Never pass on an identity the client claimed for itself
+            headers.remove(name);
+            if let Some(value) = request_principal()
+                .ok()
+                .and_then(|p| HeaderValue::from_str(&p.app_name).ok())
+            {
+                headers.insert(name.clone(), value);
+            }
+        }
+    }
+
+    /// Rewrite the headers of a response about to be returned to the client.
+    ///
+    /// `version` is the protocol the response arrived over, as recorded
+    /// in `Via`.
+    pub fn apply_response(&self, headers: &mut HeaderMap, version: Version) {
+        self.response.apply(headers);
+        if self.forwarded {
+            append_via(headers, version);
+        }
+    }
+}
+
+/// Add `client` to the end of the `X-Forwarded-For` chain.
+fn append_forwarded_for(headers: &mut HeaderMap, client: IpAddr) {
+    // Some upstreams only read the first field, so keep the chain in one
+    let mut chain: Vec<&str> = headers
+        .get_all(&X_FORWARDED_FOR)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .collect();
+    let client = client.to_string();
+    chain.push(&client);
+    if let Ok(value) = HeaderValue::from_str(&chain.join(", ")) {
+        headers.insert(X_FORWARDED_FOR, value);
+    }
+}
+
+/// Record this hop in `Via`.
+fn append_via(headers: &mut HeaderMap, version: Version) {
+    let protocol = match version {
+        Version::HTTP_09 => "0.9",
+        Version::HTTP_10 => "1.0",
+        Version::HTTP_2 => "2",
+        Version::HTTP_3 => "3",
+        _ => "1.1",
+    };
+    if let Ok(value) = HeaderValue::from_str(&format!("{protocol} {VIA_PSEUDONYM}")) {
+        headers.append(VIA, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::Principal;
+    use crate::policy::principal::with_client_principal;
+
+    fn rules(yaml: &str) -> HeaderRules {
+        let config: SourceHeaders = serde_saphyr::from_str(yaml).unwrap();
+        HeaderRules::from_config("billing", &config).unwrap()
+    }
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.append(*name, HeaderValue::from_static(value));
+        }
+        map
+    }
+
+    #[test]
+    fn test_request_strip_set_add() {
+        let rules = rules(
+            r#"
+request:
+  remove: [x-internal-auth]
+  set: { authorization: "Bearer upstream" }
+  add: { x-tenant: billing }
+forwarded: false
+"#,
+        );
+        let mut map = headers(&[
+            ("x-internal-auth", "secret"),
+            ("authorization", "Bearer client"),
+            ("x-tenant", "acme"),
+        ]);
+
+        rules.apply_request(&mut map, None, Version::HTTP_11);
+
+        assert!(map.get("x-internal-auth").is_none());
+        assert_eq!(map["authorization"], "Bearer upstream");
+        let tenants: Vec<_> = map.get_all("x-tenant").iter().collect();
+        assert_eq!(tenants, ["acme", "billing"]);
+        assert!(map.get(VIA).is_none());
+    }
+
+    #[test]
+    fn test_response_strip_set_add() {
+        let rules = rules(
+            r#"
+response:
+  remove: [server]
+  set: { cache-control: no-store }
+  add: { x-served-by: thoughtgate }
+"#,
+        );
+        let mut map = headers(&[("server", "internal/1.2"), ("cache-control", "max-age=60")]);
+
+        rules.apply_response(&mut map, Version::HTTP_2);
+
+        assert!(map.get("server").is_none());
+        assert_eq!(map["cache-control"], "no-store");
+        assert_eq!(map["x-served-by"], "thoughtgate");
+        assert_eq!(map[VIA], "2 thoughtgate");
+        // Request rules do not touch responses
+        assert!(map.get(X_FORWARDED_FOR).is_none());
+    }
+
+    #[test]
+    fn test_forwarded_headers_extend_chain() {
+        let rules = HeaderRules::default();
+        let mut map = headers(&[("x-forwarded-for", "203.0.113.7"), ("via", "1.1 edge")]);
+
+        rules.apply_request(&mut map, Some([10, 0, 0, 5].into()), Version::HTTP_11);
+
+        assert_eq!(map[X_FORWARDED_FOR], "203.0.113.7, 10.0.0.5");
+        let via: Vec<_> = map.get_all(VIA).iter().collect();
+        assert_eq!(via, ["1.1 edge", "1.1 thoughtgate"]);
+    }
+
+    #[tokio::test]
+    async fn test_principal_header_replaces_client_value() {
+        let rules = rules("principal_header: x-thoughtgate-principal\n");
+        let principal = Principal {
+            app_name: "billing-agent".to_string(),
+            namespace: "prod".to_string(),
+            service_account: "billing".to_string(),
+            roles: vec![],
+        };
+        let mut map = headers(&[("x-thoughtgate-principal", "admin")]);
+
+        with_client_principal(principal, async {
+            rules.apply_request(&mut map, None, Version::HTTP_11);
+        })
+        .await;
+
+        assert_eq!(map["x-thoughtgate-principal"], "billing-agent");
+    }
+
+    #[test]
+    fn test_invalid_header_name_rejected() {
+        let config: SourceHeaders =
+            serde_saphyr::from_str("request:\n  set: { \"bad header\": x }\n").unwrap();
+        let err = HeaderRules::from_config("billing", &config).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("sources.billing.headers.request.set"),
+            "{err}"
+        );
+    }
+}
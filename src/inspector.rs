@@ -14,15 +14,46 @@
 //! - When schema validation is required
 //! - When request/response transformation is needed
 //!
+//! Content classifiers ([`Classifier`]) are separate from inspectors: they
+//! never change the payload, only escalate a policy decision after Cedar
+//! has made it (see [`ClassifierPipeline`]). Their verdict depends only on
+//! the request content, so a [`ClassificationCache`] can reuse it for
+//! repeated identical calls.
+//!
+//! [`ResponsePolicy`] governs what comes back: it blocks, redacts, or
+//! truncates response bodies on the Amber Path.
+//!
+//! [`RequestShape`] parsers find the tool calls in a request body, so a
+//! `tools/call` carrying several calls ([`MultiCallShape`]) can be
+//! governed call by call alongside a plain one.
+//!
+//! [`JsonLimits`] bounds the nesting depth and size of JSON bodies with a
+//! single byte scan, so oversized or deeply nested payloads are refused
+//! before a parser builds them.
+//!
 //! # Traceability
 //! - Deferred: REQ-CORE-002 F-003 (Async Inspector Interface)
 //! - Deferred: REQ-CORE-002 F-004 (Chain Semantics)
+//! - Implements: REQ-CORE-006 (Content Classification)
+//! - Implements: REQ-CORE-004/EC-ERR-015 (JSON Limits)
+//! - Implements: REQ-CORE-002 F-003 (Response Governance)
+//! - Implements: REQ-CORE-003/F-007 (Multi-Call Requests)
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use dashmap::DashMap;
 use http::StatusCode;
+use regex::bytes::RegexSet;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::info;
 
-use crate::error::ProxyError;
+use crate::clock::{Clock, SystemClock};
+use crate::config::{ClassifierAction, ClassifierConfig};
+use crate::error::{ProxyError, ThoughtGateError};
+use crate::policy::{PolicyRequest, Resource};
 
 /// The result of an inspection operation.
 ///
@@ -33,7 +64,7 @@ use crate::error::ProxyError;
 ///
 /// # Traceability
 /// - Implements: REQ-CORE-002 F-003 (Decision enum)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Decision {
     /// Forward the original bytes unchanged.
     ///
@@ -228,6 +259,1110 @@ impl Inspector for NoOpInspector {
     }
 }
 
+/// Verdict of a content [`Classifier`].
+///
+/// Outcomes can only tighten a decision: `Clean` leaves it unchanged and
+/// there is deliberately no variant that loosens it.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-006 (Content Classification)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClassificationOutcome {
+    /// Nothing found; keep the current decision.
+    Clean,
+
+    /// Require human approval (escalates `Forward`).
+    Approve {
+        /// Why approval is needed (must not quote the payload).
+        reason: String,
+    },
+
+    /// Refuse the request (escalates `Forward` and `Approve`).
+    Reject {
+        /// Why the request is refused (must not quote the payload).
+        reason: String,
+    },
+}
+
+/// A content classifier that can escalate a policy decision.
+///
+/// Classifiers complement Cedar: Cedar decides on identity and resource,
+/// classifiers look at the payload (e.g. PII patterns or a model call).
+/// A classifier that cannot reach a verdict (e.g. its model is down)
+/// should return `Approve` or `Reject` rather than `Clean`, so failures
+/// fail closed.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-006 (Content Classification)
+#[async_trait]
+pub trait Classifier: Send + Sync {
+    /// Returns the unique name of this classifier, for logs.
+    fn name(&self) -> &'static str;
+
+    /// Classify a request from its policy view and raw body.
+    async fn classify(&self, request: &PolicyRequest, body: &[u8]) -> ClassificationOutcome;
+}
+
+/// The strongest outcome of a pipeline's classifiers and who gave it.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-006 (Content Classification)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict {
+    /// Name of the classifier that gave `outcome` ("none" if clean).
+    pub classifier: &'static str,
+    /// The strongest outcome.
+    pub outcome: ClassificationOutcome,
+}
+
+impl Verdict {
+    /// The verdict when no classifier found anything.
+    pub fn clean() -> Self {
+        Self {
+            classifier: "none",
+            outcome: ClassificationOutcome::Clean,
+        }
+    }
+}
+
+/// Short-lived memo of classifier verdicts for identical requests.
+///
+/// Polling agents repeat the same tool call with the same arguments; the
+/// classifiers would reach the same verdict each time. Entries are keyed
+/// by [`request_fingerprint`], which ignores the principal, so only
+/// content-based verdicts are shared: Cedar still decides every request.
+/// Entries expire after `ttl`; once `capacity` live entries are held, new
+/// verdicts are not stored until some expire. Expiry reads the time from a
+/// [`Clock`], the system clock unless [`with_clock`] sets another.
+///
+/// [`with_clock`]: ClassificationCache::with_clock
+///
+/// # Traceability
+/// - Implements: REQ-CORE-006 (Content Classification)
+#[derive(Debug)]
+pub struct ClassificationCache {
+    ttl: Duration,
+    capacity: usize,
+    entries: DashMap<String, (Instant, Verdict)>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ClassificationCache {
+    /// Default time a verdict is reused.
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(30);
+
+    /// Default number of verdicts held.
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Create an empty cache holding up to `capacity` verdicts for `ttl`.
+    pub fn new(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            ttl,
+            capacity,
+            entries: DashMap::new(),
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// Build the cache from the environment.
+    ///
+    /// `THOUGHTGATE_CLASSIFIER_CACHE_TTL_SECS` sets the TTL (`0` disables
+    /// the cache) and `THOUGHTGATE_CLASSIFIER_CACHE_SIZE` the capacity.
+    /// Returns `None` if the cache is disabled.
+    pub fn from_env() -> Option<Self> {
+        fn var<T: std::str::FromStr>(name: &str) -> Option<T> {
+            std::env::var(name).ok().and_then(|v| v.parse().ok())
+        }
+
+        let ttl = var::<u64>("THOUGHTGATE_CLASSIFIER_CACHE_TTL_SECS")
+            .map_or(Self::DEFAULT_TTL, Duration::from_secs);
+        let capacity =
+            var::<usize>("THOUGHTGATE_CLASSIFIER_CACHE_SIZE").unwrap_or(Self::DEFAULT_CAPACITY);
+        (!ttl.is_zero() && capacity > 0).then(|| Self::new(ttl, capacity))
+    }
+
+    /// Expire verdicts against `clock` instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Drop every cached verdict, e.g. after classifiers are reconfigured.
+    pub fn invalidate(&self) {
+        self.entries.clear();
+    }
+
+    /// Number of cached verdicts, including expired ones not yet purged.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no verdicts are cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// An empty cache with the same settings.
+    fn emptied(&self) -> Self {
+        Self::new(self.ttl, self.capacity).with_clock(self.clock.clone())
+    }
+
+    fn get(&self, key: &str) -> Option<Verdict> {
+        let hit = self
+            .entries
+            .get(key)
+            .filter(|entry| entry.0 > self.clock.instant())
+            .map(|entry| entry.1.clone());
+        if let Some(metrics) = crate::metrics::get_classification_metrics() {
+            metrics.record_cache_lookup(hit.is_some());
+        }
+        hit
+    }
+
+    fn insert(&self, key: String, verdict: Verdict) {
+        let now = self.clock.instant();
+        if self.entries.len() >= self.capacity {
+            self.entries.retain(|_, (expires, _)| *expires > now);
+            if self.entries.len() >= self.capacity {
+                return;
+            }
+        }
+        self.entries.insert(key, (now + self.ttl, verdict));
+    }
+}
+
+/// Runs content classifiers on a request Cedar has permitted.
+///
+/// Classifiers run in registration order and the strongest outcome wins:
+/// `Reject` over `Approve` over `Clean`. Once one rejects, the remaining
+/// classifiers are skipped. The caller applies the verdict to the Cedar
+/// decision, which it can only make stricter.
+///
+/// With a [`ClassificationCache`], the classifiers' verdict for a request
+/// is reused for identical requests until it expires. Adding a classifier
+/// starts from an empty cache, and [`invalidate_cache`] clears it when
+/// the classifiers' own configuration is reloaded.
+///
+/// [`invalidate_cache`]: ClassifierPipeline::invalidate_cache
+///
+/// # Traceability
+/// - Implements: REQ-CORE-006 (Content Classification)
+#[derive(Default, Clone)]
+pub struct ClassifierPipeline {
+    classifiers: Vec<Arc<dyn Classifier>>,
+    cache: Option<Arc<ClassificationCache>>,
+}
+
+impl std::fmt::Debug for ClassifierPipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClassifierPipeline")
+            .field(
+                "classifiers",
+                &self
+                    .classifiers
+                    .iter()
+                    .map(|c| c.name())
+                    .collect::<Vec<_>>(),
+            )
+            .field("cache", &self.cache)
+            .finish()
+    }
+}
+
+impl ClassifierPipeline {
+    /// Create an empty pipeline (every request is clean).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the pipeline for the `classifiers:` configuration section.
+    ///
+    /// # Errors
+    ///
+    /// Returns the regex error if a pattern is invalid. Validated
+    /// configurations never fail.
+    pub fn from_config(classifiers: &[ClassifierConfig]) -> Result<Self, regex::Error> {
+        classifiers
+            .iter()
+            .try_fold(Self::new(), |pipeline, classifier| {
+                let ClassifierConfig::Regex { action, patterns } = classifier;
+                let regex = match action {
+                    ClassifierAction::Approve => RegexClassifier::approve(patterns)?,
+                    ClassifierAction::Reject => RegexClassifier::reject(patterns)?,
+                };
+                Ok(pipeline.with_classifier(Arc::new(regex)))
+            })
+    }
+
+    /// Append a classifier.
+    #[must_use]
+    pub fn with_classifier(mut self, classifier: Arc<dyn Classifier>) -> Self {
+        self.classifiers.push(classifier);
+        // Verdicts from the old classifier list no longer apply
+        self.cache = self.cache.map(|cache| Arc::new(cache.emptied()));
+        self
+    }
+
+    /// Reuse classifier verdicts for identical requests.
+    #[must_use]
+    pub fn with_cache(mut self, cache: ClassificationCache) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Returns `true` if no classifiers are registered.
+    pub fn is_empty(&self) -> bool {
+        self.classifiers.is_empty()
+    }
+
+    /// Drop all cached verdicts.
+    pub fn invalidate_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate();
+        }
+    }
+
+    /// Classify a permitted request from its policy view and JSON body.
+    pub async fn classify(&self, request: &PolicyRequest, body: &[u8]) -> Verdict {
+        if self.classifiers.is_empty() {
+            return Verdict::clean();
+        }
+
+        let verdict = match &self.cache {
+            Some(cache) => {
+                let key = request_fingerprint(request, body);
+                match cache.get(&key) {
+                    Some(verdict) => verdict,
+                    None => {
+                        let verdict = self.run(request, body).await;
+                        cache.insert(key, verdict.clone());
+                        verdict
+                    }
+                }
+            }
+            None => self.run(request, body).await,
+        };
+
+        match &verdict.outcome {
+            ClassificationOutcome::Clean => {}
+            ClassificationOutcome::Approve { reason } => info!(
+                classifier = verdict.classifier,
+                reason = %reason,
+                "Classifier escalated request to approval"
+            ),
+            ClassificationOutcome::Reject { reason } => info!(
+                classifier = verdict.classifier,
+                reason = %reason,
+                "Classifier rejected request"
+            ),
+        }
+        verdict
+    }
+
+    /// Run the classifiers, keeping the first of the strongest outcomes.
+    async fn run(&self, request: &PolicyRequest, body: &[u8]) -> Verdict {
+        let mut verdict = Verdict::clean();
+        for classifier in &self.classifiers {
+            let outcome = classifier.classify(request, body).await;
+            let stronger = matches!(
+                (&outcome, &verdict.outcome),
+                (ClassificationOutcome::Reject { .. }, _)
+                    | (
+                        ClassificationOutcome::Approve { .. },
+                        ClassificationOutcome::Clean
+                    )
+            );
+            if stronger {
+                verdict = Verdict {
+                    classifier: classifier.name(),
+                    outcome,
+                };
+            }
+            if matches!(verdict.outcome, ClassificationOutcome::Reject { .. }) {
+                break;
+            }
+        }
+        verdict
+    }
+}
+
+/// Classifier that escalates requests whose body matches any regex.
+///
+/// Patterns are matched against the JSON body bytes, so they see JSON
+/// escaping (e.g. `\n` rather than a newline).
+///
+/// # Example
+///
+/// ```
+/// use thoughtgate::inspector::RegexClassifier;
+///
+/// // US Social Security numbers need a human to look first
+/// let ssn = RegexClassifier::approve([r"\b\d{3}-\d{2}-\d{4}\b"]).unwrap();
+/// ```
+///
+/// # Traceability
+/// - Implements: REQ-CORE-006 (Content Classification)
+#[derive(Debug, Clone)]
+pub struct RegexClassifier {
+    patterns: RegexSet,
+    reject: bool,
+}
+
+impl RegexClassifier {
+    /// Escalate matching requests to approval.
+    ///
+    /// # Errors
+    ///
+    /// Returns the regex error if any pattern is invalid.
+    pub fn approve<I, P>(patterns: I) -> Result<Self, regex::Error>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        Ok(Self {
+            patterns: RegexSet::new(patterns)?,
+            reject: false,
+        })
+    }
+
+    /// Reject matching requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns the regex error if any pattern is invalid.
+    pub fn reject<I, P>(patterns: I) -> Result<Self, regex::Error>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        Ok(Self {
+            patterns: RegexSet::new(patterns)?,
+            reject: true,
+        })
+    }
+}
+
+#[async_trait]
+impl Classifier for RegexClassifier {
+    fn name(&self) -> &'static str {
+        "regex"
+    }
+
+    async fn classify(&self, _request: &PolicyRequest, body: &[u8]) -> ClassificationOutcome {
+        let Some(index) = self.patterns.matches(body).iter().next() else {
+            return ClassificationOutcome::Clean;
+        };
+        // Name the pattern, never the matched content
+        let reason = format!("body matches pattern '{}'", self.patterns.patterns()[index]);
+        if self.reject {
+            ClassificationOutcome::Reject { reason }
+        } else {
+            ClassificationOutcome::Approve { reason }
+        }
+    }
+}
+
+/// Default maximum JSON nesting depth.
+pub const DEFAULT_MAX_JSON_DEPTH: usize = 64;
+
+/// Default maximum JSON body size in bytes (1 MiB).
+pub const DEFAULT_MAX_JSON_BYTES: usize = 1024 * 1024;
+
+/// Bounds on JSON bodies, checked before they are parsed.
+///
+/// Depth counts open objects and arrays, so `{"a":[1]}` has depth 2.
+/// The check is a single pass over the bytes that keeps only a depth
+/// counter; malformed JSON is left for the parser to report.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-004/EC-ERR-015 (JSON Limits)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonLimits {
+    /// Maximum nesting depth of objects and arrays.
+    pub max_depth: usize,
+    /// Maximum body size in bytes.
+    pub max_bytes: usize,
+}
+
+impl Default for JsonLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_JSON_DEPTH,
+            max_bytes: DEFAULT_MAX_JSON_BYTES,
+        }
+    }
+}
+
+/// A JSON body exceeded one of its [`JsonLimits`].
+///
+/// # Traceability
+/// - Implements: REQ-CORE-004/EC-ERR-015 (JSON Limits)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum JsonLimitExceeded {
+    /// Objects and arrays are nested deeper than allowed.
+    #[error("JSON nesting exceeds maximum depth of {max}")]
+    Depth {
+        /// Configured maximum depth
+        max: usize,
+    },
+
+    /// The body is larger than allowed.
+    #[error("JSON body of {actual} bytes exceeds maximum of {max} bytes")]
+    Bytes {
+        /// Body size in bytes
+        actual: usize,
+        /// Configured maximum size
+        max: usize,
+    },
+}
+
+impl JsonLimitExceeded {
+    /// Stable reason code reported to clients and in metrics.
+    pub fn reason_code(&self) -> &'static str {
+        match self {
+            Self::Depth { .. } => "json_depth_exceeded",
+            Self::Bytes { .. } => "json_size_exceeded",
+        }
+    }
+
+    /// HTTP status used when rejecting on the Amber Path.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::Depth { .. } => StatusCode::BAD_REQUEST,
+            Self::Bytes { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+        }
+    }
+}
+
+impl From<JsonLimitExceeded> for ThoughtGateError {
+    fn from(exceeded: JsonLimitExceeded) -> Self {
+        ThoughtGateError::InspectionFailed {
+            inspector: "json_limits".to_string(),
+            reason: exceeded.reason_code().to_string(),
+        }
+    }
+}
+
+impl JsonLimits {
+    /// Load limits from environment variables.
+    ///
+    /// # Environment Variables
+    ///
+    /// - `THOUGHTGATE_MAX_JSON_DEPTH` (default: 64): Max nesting depth
+    /// - `THOUGHTGATE_MAX_JSON_BYTES` (default: 1048576): Max body size
+    pub fn from_env() -> Self {
+        let max_depth = std::env::var("THOUGHTGATE_MAX_JSON_DEPTH")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_MAX_JSON_DEPTH);
+
+        let max_bytes = std::env::var("THOUGHTGATE_MAX_JSON_BYTES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_MAX_JSON_BYTES);
+
+        Self {
+            max_depth,
+            max_bytes,
+        }
+    }
+
+    /// Check `body` against the limits, recording a metric when exceeded.
+    ///
+    /// The size limit is checked first, without reading the body; the
+    /// depth scan stops at the first byte that crosses the limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns which limit was exceeded.
+    pub fn check(&self, body: &[u8]) -> Result<(), JsonLimitExceeded> {
+        let result = self.scan(body);
+        if let Err(exceeded) = &result
+            && let Some(metrics) = crate::metrics::get_limit_metrics()
+        {
+            metrics.record_json_limit_exceeded(exceeded.reason_code());
+        }
+        result
+    }
+
+    fn scan(&self, body: &[u8]) -> Result<(), JsonLimitExceeded> {
+        if body.len() > self.max_bytes {
+            return Err(JsonLimitExceeded::Bytes {
+                actual: body.len(),
+                max: self.max_bytes,
+            });
+        }
+
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+        for &byte in body {
+            if in_string {
+                match byte {
+                    _ if escaped => escaped = false,
+                    b'\\' => escaped = true,
+                    b'"' => in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+            match byte {
+                b'"' => in_string = true,
+                b'{' | b'[' => {
+                    depth += 1;
+                    if depth > self.max_depth {
+                        return Err(JsonLimitExceeded::Depth {
+                            max: self.max_depth,
+                        });
+                    }
+                }
+                b'}' | b']' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Inspector for JsonLimits {
+    fn name(&self) -> &'static str {
+        "json_limits"
+    }
+
+    async fn inspect(
+        &self,
+        body: &[u8],
+        _ctx: InspectionContext<'_>,
+    ) -> Result<Decision, ProxyError> {
+        Ok(match self.check(body) {
+            Ok(()) => Decision::Approve,
+            Err(exceeded) => Decision::Reject(exceeded.status()),
+        })
+    }
+}
+
+/// What a [`ResponsePolicy`] does with a response body over its size budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OversizeAction {
+    /// Cut the body at the budget (JSON bodies become invalid).
+    Truncate,
+    /// Replace the response with an error.
+    Block,
+}
+
+/// Action of a [`ResponsePolicy`] rule whose pattern matches.
+#[derive(Debug, Clone)]
+enum ResponseAction {
+    /// Replace each match with the given bytes.
+    Redact(Bytes),
+    /// Replace the response with an error.
+    Block,
+}
+
+/// Output governance for upstream responses.
+///
+/// Rules are regexes over the raw body, evaluated in order:
+///
+/// 1. Any `block` rule matching → the response is replaced with `502`
+/// 2. `redact` rules rewrite their matches
+/// 3. The size budget truncates or blocks what remains
+///
+/// Request bodies are always approved, so the policy can sit in a shared
+/// chain; register it with `BufferedForwarder::add_response_inspector` to
+/// run it on responses only. Streamed responses are buffered (up to
+/// `resp_buffer_max`) before the policy sees them.
+///
+/// # Example
+///
+/// ```
+/// use thoughtgate::inspector::{OversizeAction, ResponsePolicy};
+///
+/// let policy = ResponsePolicy::new()
+///     .block(r"-----BEGIN [A-Z ]*PRIVATE KEY-----")
+///     .unwrap()
+///     .redact(r"\b\d{3}-\d{2}-\d{4}\b", "[REDACTED]")
+///     .unwrap()
+///     .with_size_budget(64 * 1024, OversizeAction::Truncate);
+/// ```
+///
+/// # Traceability
+/// - Implements: REQ-CORE-002 F-003 (Response Governance)
+#[derive(Debug, Clone, Default)]
+pub struct ResponsePolicy {
+    rules: Vec<(regex::bytes::Regex, ResponseAction)>,
+    size_budget: Option<(usize, OversizeAction)>,
+}
+
+impl ResponsePolicy {
+    /// Status returned when a response is blocked.
+    pub const BLOCKED_STATUS: StatusCode = StatusCode::BAD_GATEWAY;
+
+    /// Create a policy with no rules (every response passes).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Block responses matching `pattern`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the regex error if `pattern` is invalid.
+    pub fn block(mut self, pattern: &str) -> Result<Self, regex::Error> {
+        self.rules
+            .push((regex::bytes::Regex::new(pattern)?, ResponseAction::Block));
+        Ok(self)
+    }
+
+    /// Replace matches of `pattern` with `replacement`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the regex error if `pattern` is invalid.
+    pub fn redact(mut self, pattern: &str, replacement: &str) -> Result<Self, regex::Error> {
+        self.rules.push((
+            regex::bytes::Regex::new(pattern)?,
+            ResponseAction::Redact(Bytes::copy_from_slice(replacement.as_bytes())),
+        ));
+        Ok(self)
+    }
+
+    /// Limit response bodies to `max_bytes`.
+    #[must_use]
+    pub fn with_size_budget(mut self, max_bytes: usize, action: OversizeAction) -> Self {
+        self.size_budget = Some((max_bytes, action));
+        self
+    }
+
+    /// Apply the policy to a response body.
+    pub fn evaluate(&self, body: &[u8]) -> Decision {
+        let blocked = self.rules.iter().any(|(pattern, action)| {
+            matches!(action, ResponseAction::Block) && pattern.is_match(body)
+        });
+        if blocked {
+            return Decision::Reject(Self::BLOCKED_STATUS);
+        }
+
+        let mut current = Cow::Borrowed(body);
+        for (pattern, action) in &self.rules {
+            if let ResponseAction::Redact(replacement) = action
+                && let Cow::Owned(redacted) = pattern.replace_all(&current, replacement.as_ref())
+            {
+                current = Cow::Owned(redacted);
+            }
+        }
+
+        if let Some((max_bytes, action)) = self.size_budget
+            && current.len() > max_bytes
+        {
+            match action {
+                OversizeAction::Block => return Decision::Reject(Self::BLOCKED_STATUS),
+                OversizeAction::Truncate => {
+                    return Decision::Modify(Bytes::copy_from_slice(&current[..max_bytes]));
+                }
+            }
+        }
+
+        match current {
+            Cow::Borrowed(_) => Decision::Approve,
+            Cow::Owned(redacted) => Decision::Modify(Bytes::from(redacted)),
+        }
+    }
+}
+
+#[async_trait]
+impl Inspector for ResponsePolicy {
+    fn name(&self) -> &'static str {
+        "response_policy"
+    }
+
+    async fn inspect(
+        &self,
+        body: &[u8],
+        ctx: InspectionContext<'_>,
+    ) -> Result<Decision, ProxyError> {
+        if ctx.is_request() {
+            return Ok(Decision::Approve);
+        }
+        Ok(self.evaluate(body))
+    }
+}
+
+/// One tool call found in a request body by a [`RequestShape`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolCallEntry {
+    /// Tool name
+    pub name: String,
+    /// Tool arguments (`null` if absent)
+    pub arguments: serde_json::Value,
+}
+
+impl ToolCallEntry {
+    /// Read `{"name": ..., "arguments": ...}`, the layout of `tools/call` params.
+    fn from_params(params: &serde_json::Value) -> Option<Self> {
+        Some(Self {
+            name: params.get("name")?.as_str()?.to_string(),
+            arguments: params.get("arguments").cloned().unwrap_or_default(),
+        })
+    }
+}
+
+/// A request body layout that carries tool calls.
+///
+/// The `tools/call` routing governs each call a shape finds separately,
+/// and uses [`RequestShape::retain`] to drop the refused ones when the
+/// request is split.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-003/F-007 (Multi-Call Requests)
+pub trait RequestShape: Send + Sync {
+    /// Returns the unique name of this shape, for logs.
+    fn name(&self) -> &'static str;
+
+    /// The tool calls in `body`, in order, or `None` if `body` is not this shape.
+    fn calls(&self, body: &serde_json::Value) -> Option<Vec<ToolCallEntry>>;
+
+    /// Remove the calls whose entry in `keep` is `false`.
+    ///
+    /// Only called on a body this shape recognized, with one entry per call.
+    fn retain(&self, body: &mut serde_json::Value, keep: &[bool]);
+}
+
+/// A JSON-RPC `tools/call` request: `params` is `{"name", "arguments"}`.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-003/F-007 (Multi-Call Requests)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SingleCallShape;
+
+impl RequestShape for SingleCallShape {
+    fn name(&self) -> &'static str {
+        "single"
+    }
+
+    fn calls(&self, body: &serde_json::Value) -> Option<Vec<ToolCallEntry>> {
+        if body.get("method")?.as_str()? != "tools/call" {
+            return None;
+        }
+        Some(vec![ToolCallEntry::from_params(body.get("params")?)?])
+    }
+
+    fn retain(&self, _body: &mut serde_json::Value, _keep: &[bool]) {
+        // A single call is either forwarded whole or refused
+    }
+}
+
+/// A `tools/call` request whose `params.calls` lists several calls.
+///
+/// ```json
+/// {"jsonrpc": "2.0", "id": 1, "method": "tools/call",
+///  "params": {"calls": [{"name": "read_file", "arguments": {}},
+///                       {"name": "delete_file", "arguments": {}}]}}
+/// ```
+///
+/// This is not a JSON-RPC batch: there is one request and one response.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-003/F-007 (Multi-Call Requests)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MultiCallShape;
+
+impl RequestShape for MultiCallShape {
+    fn name(&self) -> &'static str {
+        "multi_call"
+    }
+
+    fn calls(&self, body: &serde_json::Value) -> Option<Vec<ToolCallEntry>> {
+        if body.get("method")?.as_str()? != "tools/call" {
+            return None;
+        }
+        body.get("params")?
+            .get("calls")?
+            .as_array()?
+            .iter()
+            .map(ToolCallEntry::from_params)
+            .collect()
+    }
+
+    fn retain(&self, body: &mut serde_json::Value, keep: &[bool]) {
+        if let Some(calls) = body
+            .pointer_mut("/params/calls")
+            .and_then(serde_json::Value::as_array_mut)
+        {
+            let mut keep = keep.iter();
+            calls.retain(|_| keep.next().copied().unwrap_or(false));
+        }
+    }
+}
+
+/// Outcome of peeking at the start of a request body to classify it.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 (Zero-Copy Peeking Strategy)
+/// - Implements: REQ-CORE-003/F-002 (MCP Traffic Detection)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeekOutcome {
+    /// The bytes seen so far end before the JSON-RPC `method` value.
+    NeedMore,
+
+    /// The `method` value was found and is the object's only one.
+    ///
+    /// `bytes_consumed` is the offset just past the request object's
+    /// closing brace: the minimum prefix needed to rule out a second
+    /// `method` member.
+    Classified {
+        /// The JSON-RPC method (first request of a batch).
+        method: String,
+        /// Bytes needed to read the request object.
+        bytes_consumed: usize,
+    },
+
+    /// The bytes cannot be a JSON-RPC request with a single string
+    /// `method`.
+    ///
+    /// `bytes_consumed` is the offset at which this became certain.
+    Unclassifiable {
+        /// Bytes examined before giving up.
+        bytes_consumed: usize,
+    },
+}
+
+/// Why scanning stopped before finding the method.
+enum ScanStop {
+    NeedMore,
+    Invalid(usize),
+}
+
+/// Peek at a (possibly partial) request body and find its JSON-RPC method.
+///
+/// Scans the top-level object without allocating or building a JSON tree,
+/// so the caller can decide whether to stream or buffer without parsing
+/// the whole body. The scan runs to the end of the object: a request that
+/// repeats `method` is unclassifiable, because parsers disagree on which
+/// value wins and the forwarded request must not differ from the one
+/// classified. For a batch, the first request's method is reported.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 (Zero-Copy Peeking Strategy)
+/// - Implements: REQ-CORE-003/F-002 (MCP Traffic Detection)
+pub fn peek_for_classification(buf: &[u8]) -> PeekOutcome {
+    match scan_for_method(buf) {
+        Ok((method, bytes_consumed)) => PeekOutcome::Classified {
+            method,
+            bytes_consumed,
+        },
+        Err(ScanStop::NeedMore) => PeekOutcome::NeedMore,
+        Err(ScanStop::Invalid(bytes_consumed)) => PeekOutcome::Unclassifiable { bytes_consumed },
+    }
+}
+
+fn scan_for_method(buf: &[u8]) -> Result<(String, usize), ScanStop> {
+    let mut pos = skip_ws(buf, 0)?;
+    if buf[pos] == b'[' {
+        pos = skip_ws(buf, pos + 1)?;
+    }
+    if buf[pos] != b'{' {
+        return Err(ScanStop::Invalid(pos + 1));
+    }
+    pos += 1;
+
+    let mut method = None;
+    loop {
+        pos = skip_ws(buf, pos)?;
+        if buf[pos] != b'"' {
+            // '}' (no method) or not a key
+            return Err(ScanStop::Invalid(pos + 1));
+        }
+        let key_end = scan_string(buf, pos)?;
+        let is_method = decode_string(buf, pos, key_end)? == "method";
+        if is_method && method.is_some() {
+            // A repeated method could be read either way
+            return Err(ScanStop::Invalid(key_end));
+        }
+
+        pos = skip_ws(buf, key_end)?;
+        if buf[pos] != b':' {
+            return Err(ScanStop::Invalid(pos + 1));
+        }
+        pos = skip_ws(buf, pos + 1)?;
+
+        if is_method {
+            if buf[pos] != b'"' {
+                return Err(ScanStop::Invalid(pos + 1));
+            }
+            let end = scan_string(buf, pos)?;
+            method = Some(decode_string(buf, pos, end)?);
+            pos = end;
+        } else {
+            pos = skip_value(buf, pos)?;
+        }
+
+        pos = skip_ws(buf, pos)?;
+        match buf[pos] {
+            b',' => pos += 1,
+            b'}' => {
+                return method
+                    .map(|m| (m, pos + 1))
+                    .ok_or(ScanStop::Invalid(pos + 1));
+            }
+            _ => return Err(ScanStop::Invalid(pos + 1)),
+        }
+    }
+}
+
+/// Index of the next non-whitespace byte at or after `pos`.
+fn skip_ws(buf: &[u8], mut pos: usize) -> Result<usize, ScanStop> {
+    while pos < buf.len() {
+        if !matches!(buf[pos], b' ' | b'\t' | b'\n' | b'\r') {
+            return Ok(pos);
+        }
+        pos += 1;
+    }
+    Err(ScanStop::NeedMore)
+}
+
+/// Given the opening quote at `start`, the index just past the closing quote.
+fn scan_string(buf: &[u8], start: usize) -> Result<usize, ScanStop> {
+    let mut pos = start + 1;
+    while pos < buf.len() {
+        match buf[pos] {
+            b'\\' => pos += 2,
+            b'"' => return Ok(pos + 1),
+            _ => pos += 1,
+        }
+    }
+    Err(ScanStop::NeedMore)
+}
+
+fn decode_string(buf: &[u8], start: usize, end: usize) -> Result<String, ScanStop> {
+    serde_json::from_slice(&buf[start..end]).map_err(|_| ScanStop::Invalid(end))
+}
+
+/// Index just past the JSON value starting at `pos`.
+fn skip_value(buf: &[u8], pos: usize) -> Result<usize, ScanStop> {
+    match buf[pos] {
+        b'"' => scan_string(buf, pos),
+        b'{' | b'[' => {
+            let mut depth = 0usize;
+            let mut i = pos;
+            while i < buf.len() {
+                match buf[i] {
+                    b'"' => {
+                        i = scan_string(buf, i)?;
+                        continue;
+                    }
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Ok(i + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+            Err(ScanStop::NeedMore)
+        }
+        b'}' | b']' | b',' | b':' => Err(ScanStop::Invalid(pos + 1)),
+        _ => {
+            // Number, true, false or null: ends at a delimiter
+            let end = buf[pos..]
+                .iter()
+                .position(|b| matches!(b, b',' | b'}' | b']' | b' ' | b'\t' | b'\n' | b'\r'))
+                .ok_or(ScanStop::NeedMore)?;
+            Ok(pos + end)
+        }
+    }
+}
+
+/// Stable fingerprint of a request for audit logs and deduplication.
+///
+/// Hashes (SHA-256, hex) the resource kind, name and server from `request`
+/// together with the JSON-RPC `method` and canonicalized `params` from
+/// `body`. Canonical JSON sorts object keys and drops insignificant
+/// whitespace, so semantically identical requests fingerprint the same on
+/// every replica. Transport metadata — `id`, `jsonrpc` and `params._meta`
+/// (progress tokens) — is excluded, as are the principal and catalog
+/// attributes. A body that is not JSON is hashed as raw bytes.
+///
+/// # Traceability
+/// - Implements: REQ-GOV-001/F-002.3 (Request Integrity)
+#[must_use]
+pub fn request_fingerprint(request: &PolicyRequest, body: &[u8]) -> String {
+    let (kind, name, server) = match &request.resource {
+        Resource::ToolCall { name, server, .. } => ("tool", name, server),
+        Resource::McpMethod { method, server, .. } => ("method", method, server),
+    };
+
+    let mut hasher = Sha256::new();
+    // NUL-separated so field boundaries cannot be shifted
+    for field in ["v1", kind, name, server] {
+        hasher.update(field.as_bytes());
+        hasher.update([0]);
+    }
+
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(json) => {
+            let method = json.get("method").cloned().unwrap_or_default();
+            let mut params = json.get("params").cloned().unwrap_or_default();
+            if let Some(params) = params.as_object_mut() {
+                params.remove("_meta");
+            }
+            let mut canonical = String::new();
+            write_canonical_json(&method, &mut canonical);
+            canonical.push('\0');
+            write_canonical_json(&params, &mut canonical);
+            hasher.update(b"json\0");
+            hasher.update(canonical.as_bytes());
+        }
+        Err(_) => {
+            hasher.update(b"raw\0");
+            hasher.update(body);
+        }
+    }
+
+    hex::encode(hasher.finalize())
+}
+
+/// Serialize JSON with sorted object keys and no whitespace.
+///
+/// Sorting is explicit so the output does not depend on whether
+/// `serde_json` was built with `preserve_order`.
+fn write_canonical_json(value: &serde_json::Value, out: &mut String) {
+    use serde_json::Value;
+
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_json(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_unstable_by_key(|(key, _)| key.as_str());
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical_json(item, out);
+            }
+            out.push('}');
+        }
+        // Scalars already serialize without whitespace
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -371,4 +1506,480 @@ mod tests {
             _ => panic!("Expected Modify decision"),
         }
     }
+
+    fn classified(method: &str, bytes_consumed: usize) -> PeekOutcome {
+        PeekOutcome::Classified {
+            method: method.to_string(),
+            bytes_consumed,
+        }
+    }
+
+    #[test]
+    fn test_peek_method_early() {
+        let body = br#"{"method":"tools/call","jsonrpc":"2.0","id":1,"params":{}}"#;
+        assert_eq!(
+            peek_for_classification(body),
+            classified("tools/call", body.len())
+        );
+
+        // The rest of the object is still needed to rule out another method
+        assert_eq!(peek_for_classification(&body[..22]), PeekOutcome::NeedMore);
+    }
+
+    #[test]
+    fn test_peek_method_late() {
+        let body = br#"{"jsonrpc":"2.0","id":"a,}","params":{"name":"x","arguments":{"s":"\"}]","n":[1,{"k":null}]}},"method":"tools/call"}"#;
+        let end = body.len();
+        assert_eq!(peek_for_classification(body), classified("tools/call", end));
+
+        // Every shorter prefix needs more bytes
+        for len in 0..end {
+            assert_eq!(
+                peek_for_classification(&body[..len]),
+                PeekOutcome::NeedMore,
+                "prefix of {len} bytes"
+            );
+        }
+    }
+
+    #[test]
+    fn test_peek_batch_and_whitespace() {
+        let body = b" [ {\n  \"id\" : 7 ,\n  \"method\" : \"tools/list\" } ]";
+        let PeekOutcome::Classified { method, .. } = peek_for_classification(body) else {
+            panic!("expected classification");
+        };
+        assert_eq!(method, "tools/list");
+    }
+
+    #[test]
+    fn test_peek_unclassifiable() {
+        for body in [
+            &b"GET / HTTP/1.1"[..],
+            br#"{"jsonrpc":"2.0","id":1}"#,
+            br#"{"method":42}"#,
+            br#"["not an object"]"#,
+            br#"{"id" 1}"#,
+            // A repeated method, however it is spelled
+            br#"{"method":"tools/list","id":1,"method":"tools/call"}"#,
+            br#"{"method":"tools/list","met\u0068od":"tools/call"}"#,
+        ] {
+            assert!(
+                matches!(
+                    peek_for_classification(body),
+                    PeekOutcome::Unclassifiable { .. }
+                ),
+                "{}",
+                String::from_utf8_lossy(body)
+            );
+        }
+    }
+
+    fn fingerprint_request(name: &str) -> PolicyRequest {
+        PolicyRequest {
+            principal: crate::policy::Principal {
+                app_name: "test-app".to_string(),
+                namespace: "default".to_string(),
+                service_account: "default".to_string(),
+                roles: vec![],
+            },
+            resource: Resource::ToolCall {
+                name: name.to_string(),
+                server: "upstream".to_string(),
+                attributes: Default::default(),
+            },
+            context: None,
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_key_order_and_whitespace() {
+        let request = fingerprint_request("transfer_funds");
+        let a = br#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"transfer_funds","arguments":{"amount":100,"to":{"bank":"x","acct":"1"}}}}"#;
+        let b = br#"{
+            "method": "tools/call",
+            "params": {
+                "arguments": { "to": { "acct": "1", "bank": "x" }, "amount": 100 },
+                "name": "transfer_funds",
+                "_meta": { "progressToken": "p-9" }
+            },
+            "id": "other-id",
+            "jsonrpc": "2.0"
+        }"#;
+
+        let fingerprint = request_fingerprint(&request, a);
+        assert_eq!(fingerprint.len(), 64);
+        assert!(fingerprint.bytes().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(fingerprint, request_fingerprint(&request, b));
+    }
+
+    #[test]
+    fn test_fingerprint_distinguishes_requests() {
+        let request = fingerprint_request("transfer_funds");
+        let base = request_fingerprint(
+            &request,
+            br#"{"method":"tools/call","params":{"arguments":{"amount":100}}}"#,
+        );
+
+        for (request, body) in [
+            (
+                fingerprint_request("transfer_funds"),
+                &br#"{"method":"tools/call","params":{"arguments":{"amount":101}}}"#[..],
+            ),
+            (
+                fingerprint_request("transfer_funds"),
+                br#"{"method":"tools/call","params":{"arguments":{"amount":"100"}}}"#,
+            ),
+            (
+                fingerprint_request("refund_funds"),
+                br#"{"method":"tools/call","params":{"arguments":{"amount":100}}}"#,
+            ),
+            (
+                fingerprint_request("transfer_funds"),
+                br#"{"method":"tools/call","params":{"arguments":[{"amount":100}]}}"#,
+            ),
+        ] {
+            assert_ne!(
+                request_fingerprint(&request, body),
+                base,
+                "{}",
+                String::from_utf8_lossy(body)
+            );
+        }
+
+        // Raw (non-JSON) bodies are hashed byte for byte
+        assert_ne!(
+            request_fingerprint(&request, b"not json"),
+            request_fingerprint(&request, b"not  json")
+        );
+    }
+
+    fn tool_request() -> PolicyRequest {
+        PolicyRequest {
+            principal: crate::policy::Principal {
+                app_name: "agent".to_string(),
+                namespace: "default".to_string(),
+                service_account: "default".to_string(),
+                roles: vec![],
+            },
+            resource: Resource::ToolCall {
+                name: "send_email".to_string(),
+                server: "upstream".to_string(),
+                attributes: Default::default(),
+            },
+            context: None,
+        }
+    }
+
+    /// Classifier returning a fixed outcome.
+    struct Fixed(ClassificationOutcome);
+
+    #[async_trait]
+    impl Classifier for Fixed {
+        fn name(&self) -> &'static str {
+            "fixed"
+        }
+
+        async fn classify(&self, _: &PolicyRequest, _: &[u8]) -> ClassificationOutcome {
+            self.0.clone()
+        }
+    }
+
+    fn approving() -> Arc<dyn Classifier> {
+        Arc::new(Fixed(ClassificationOutcome::Approve {
+            reason: "pii".to_string(),
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_classifier_escalates_to_approve() {
+        let pipeline = ClassifierPipeline::new().with_classifier(approving());
+
+        let verdict = pipeline.classify(&tool_request(), b"{}").await;
+
+        assert_eq!(verdict.classifier, "fixed");
+        assert!(matches!(
+            verdict.outcome,
+            ClassificationOutcome::Approve { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_strongest_outcome_wins() {
+        let rejecting = Arc::new(Fixed(ClassificationOutcome::Reject {
+            reason: "secret".to_string(),
+        }));
+        let pipeline = ClassifierPipeline::new()
+            .with_classifier(Arc::new(Fixed(ClassificationOutcome::Clean)))
+            .with_classifier(approving())
+            .with_classifier(rejecting)
+            .with_classifier(approving());
+
+        let verdict = pipeline.classify(&tool_request(), b"{}").await;
+
+        assert_eq!(
+            verdict.outcome,
+            ClassificationOutcome::Reject {
+                reason: "secret".to_string()
+            }
+        );
+        assert_eq!(
+            ClassifierPipeline::new()
+                .classify(&tool_request(), b"{}")
+                .await
+                .outcome,
+            ClassificationOutcome::Clean
+        );
+    }
+
+    #[tokio::test]
+    async fn test_regex_classifier_escalates_matching_body() {
+        let ssn = RegexClassifier::reject([r"\b\d{3}-\d{2}-\d{4}\b"]).expect("valid pattern");
+        let pipeline = ClassifierPipeline::new().with_classifier(Arc::new(ssn));
+
+        let clean = pipeline
+            .classify(&tool_request(), br#"{"to":"a@b.c"}"#)
+            .await;
+        let pii = pipeline
+            .classify(&tool_request(), br#"{"ssn":"123-45-6789"}"#)
+            .await;
+
+        assert_eq!(clean.outcome, ClassificationOutcome::Clean);
+        assert!(
+            matches!(pii.outcome, ClassificationOutcome::Reject { reason } if !reason.contains("6789"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_from_config() {
+        let config = [
+            ClassifierConfig::Regex {
+                action: ClassifierAction::Approve,
+                patterns: vec!["secret".to_string()],
+            },
+            ClassifierConfig::Regex {
+                action: ClassifierAction::Reject,
+                patterns: vec!["password".to_string()],
+            },
+        ];
+        let pipeline = ClassifierPipeline::from_config(&config).expect("valid patterns");
+
+        let outcome = |body: &'static [u8]| {
+            let pipeline = pipeline.clone();
+            async move { pipeline.classify(&tool_request(), body).await.outcome }
+        };
+        assert_eq!(outcome(b"{}").await, ClassificationOutcome::Clean);
+        assert!(matches!(
+            outcome(br#"{"q":"secret"}"#).await,
+            ClassificationOutcome::Approve { .. }
+        ));
+        assert!(matches!(
+            outcome(br#"{"q":"secret password"}"#).await,
+            ClassificationOutcome::Reject { .. }
+        ));
+
+        let invalid = [ClassifierConfig::Regex {
+            action: ClassifierAction::Reject,
+            patterns: vec!["(".to_string()],
+        }];
+        assert!(ClassifierPipeline::from_config(&invalid).is_err());
+    }
+
+    /// Classifier counting how often it runs.
+    struct Counting(std::sync::atomic::AtomicUsize);
+
+    #[async_trait]
+    impl Classifier for Counting {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        async fn classify(&self, _: &PolicyRequest, _: &[u8]) -> ClassificationOutcome {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            ClassificationOutcome::Approve {
+                reason: "pii".to_string(),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_classification_cache_hits_identical_bodies() {
+        let counting = Arc::new(Counting(Default::default()));
+        let clock = crate::clock::MockClock::new();
+        let pipeline = ClassifierPipeline::new()
+            .with_classifier(counting.clone())
+            .with_cache(
+                ClassificationCache::new(Duration::from_millis(200), 16)
+                    .with_clock(Arc::new(clock.clone())),
+            );
+        let runs = || counting.0.load(std::sync::atomic::Ordering::SeqCst);
+        let call = |arguments: &str| {
+            format!(
+                r#"{{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{{"name":"send_email","arguments":{arguments}}}}}"#
+            )
+        };
+
+        for _ in 0..3 {
+            let verdict = pipeline
+                .classify(&tool_request(), call(r#"{"to":"a@b.c"}"#).as_bytes())
+                .await;
+            assert_eq!(verdict.classifier, "counting");
+        }
+        assert_eq!(runs(), 1, "identical bodies should hit the cache");
+
+        pipeline
+            .classify(&tool_request(), call(r#"{"to":"x@y.z"}"#).as_bytes())
+            .await;
+        assert_eq!(runs(), 2, "different arguments should miss");
+
+        clock.advance(Duration::from_millis(250));
+        pipeline
+            .classify(&tool_request(), call(r#"{"to":"a@b.c"}"#).as_bytes())
+            .await;
+        assert_eq!(runs(), 3, "expired verdicts should miss");
+
+        pipeline.invalidate_cache();
+        pipeline
+            .classify(&tool_request(), call(r#"{"to":"a@b.c"}"#).as_bytes())
+            .await;
+        assert_eq!(runs(), 4, "invalidated verdicts should miss");
+
+        // Adding a classifier starts from an empty cache
+        let extended = pipeline.with_classifier(Arc::new(Fixed(ClassificationOutcome::Clean)));
+        extended
+            .classify(&tool_request(), call(r#"{"to":"a@b.c"}"#).as_bytes())
+            .await;
+        assert_eq!(runs(), 5, "a changed pipeline should not reuse verdicts");
+    }
+
+    #[test]
+    fn test_json_limits_reject_deeply_nested_object() {
+        let limits = JsonLimits {
+            max_depth: 32,
+            max_bytes: 1024 * 1024,
+        };
+        let nested = format!("{}{}", r#"{"a":"#.repeat(1000), "}".repeat(1000));
+        let shallow = format!("{}1{}", r#"{"a":"#.repeat(32), "}".repeat(32));
+
+        assert_eq!(
+            limits.check(nested.as_bytes()),
+            Err(JsonLimitExceeded::Depth { max: 32 })
+        );
+        assert_eq!(limits.check(shallow.as_bytes()), Ok(()));
+    }
+
+    #[test]
+    fn test_json_limits_reject_oversized_flat_object() {
+        let limits = JsonLimits {
+            max_depth: 64,
+            max_bytes: 1024,
+        };
+        let flat = format!(r#"{{"blob":"{}"}}"#, "x".repeat(2048));
+
+        let exceeded = limits.check(flat.as_bytes()).unwrap_err();
+
+        assert_eq!(
+            exceeded,
+            JsonLimitExceeded::Bytes {
+                actual: flat.len(),
+                max: 1024
+            }
+        );
+        assert_eq!(exceeded.reason_code(), "json_size_exceeded");
+        assert_eq!(exceeded.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[test]
+    fn test_json_limits_ignore_brackets_in_strings() {
+        let limits = JsonLimits {
+            max_depth: 2,
+            max_bytes: 1024,
+        };
+        let body = br#"{"text":"[[[{{{ \" ]]] }}}","list":[1]}"#;
+
+        assert_eq!(limits.check(body), Ok(()));
+    }
+
+    #[test]
+    fn test_response_policy_redacts_and_truncates() {
+        let policy = ResponsePolicy::new()
+            .redact(r"\d{3}-\d{2}-\d{4}", "[SSN]")
+            .unwrap()
+            .with_size_budget(16, OversizeAction::Truncate);
+
+        assert_eq!(policy.evaluate(b"ok"), Decision::Approve);
+        assert_eq!(
+            policy.evaluate(b"ssn 123-45-6789"),
+            Decision::Modify(Bytes::from_static(b"ssn [SSN]"))
+        );
+        assert_eq!(
+            policy.evaluate(b"a long response body"),
+            Decision::Modify(Bytes::from_static(b"a long response "))
+        );
+    }
+
+    #[test]
+    fn test_response_policy_blocks_oversize() {
+        let policy = ResponsePolicy::new().with_size_budget(4, OversizeAction::Block);
+
+        assert_eq!(
+            policy.evaluate(b"too large"),
+            Decision::Reject(ResponsePolicy::BLOCKED_STATUS)
+        );
+    }
+
+    fn multi_call(names: &[&str]) -> Vec<u8> {
+        let calls: Vec<_> = names
+            .iter()
+            .map(|name| serde_json::json!({"name": name, "arguments": {"path": "/tmp"}}))
+            .collect();
+        serde_json::to_vec(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {"calls": calls}
+        }))
+        .unwrap()
+    }
+
+    /// Verifies: REQ-CORE-003/F-007 (multi-call bodies list every call)
+    #[test]
+    fn test_multi_call_shape_calls() {
+        let body: serde_json::Value =
+            serde_json::from_slice(&multi_call(&["read_file", "delete_file"])).unwrap();
+        let calls = MultiCallShape.calls(&body).unwrap();
+        assert_eq!(
+            calls.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(),
+            ["read_file", "delete_file"]
+        );
+        assert_eq!(calls[0].arguments["path"], "/tmp");
+
+        // A plain tools/call is not a multi-call body
+        let single = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {"name": "read_file", "arguments": {}}
+        });
+        assert!(MultiCallShape.calls(&single).is_none());
+        assert_eq!(SingleCallShape.calls(&single).unwrap()[0].name, "read_file");
+
+        let list = serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "tools/list"});
+        assert!(MultiCallShape.calls(&list).is_none());
+        assert!(SingleCallShape.calls(&list).is_none());
+    }
+
+    /// Verifies: REQ-CORE-003/F-007 (split keeps only permitted calls)
+    #[test]
+    fn test_multi_call_shape_retain() {
+        let mut body: serde_json::Value =
+            serde_json::from_slice(&multi_call(&["delete_file", "read_file", "send_email"]))
+                .unwrap();
+        MultiCallShape.retain(&mut body, &[false, true, false]);
+
+        assert_eq!(body["id"], 1);
+        let calls = body["params"]["calls"].as_array().unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0]["name"], "read_file");
+        assert_eq!(calls[0]["arguments"]["path"], "/tmp");
+    }
 }
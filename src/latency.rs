@@ -0,0 +1,175 @@
+//! Where a request's time goes: governance or upstream.
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-001 NFR-001 (Observability)
+//!
+//! Every request the proxy handles carries a [`LatencyBreakdown`] in a
+//! task-local, like its deadline. Calls to an upstream add their time with
+//! [`upstream`]; the governance phases (inspection, policy evaluation and
+//! any approval wait) are wrapped in [`governance`], which counts its span
+//! minus the upstream time inside it. When the response head is ready the
+//! breakdown is recorded as three histograms:
+//!
+//! - `thoughtgate_request_duration_seconds`: until the response head
+//! - `thoughtgate_governance_overhead_seconds`: time spent governing
+//! - `thoughtgate_upstream_seconds`: time waiting on upstreams
+//!
+//! The rest of the total is the proxy's own work, such as reading the
+//! request body, so overhead and upstream time never add up to more than
+//! the total. Streamed response bodies are not included.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::metrics::LatencyMetrics;
+
+tokio::task_local! {
+    /// Breakdown of the request being handled.
+    static BREAKDOWN: Arc<LatencyBreakdown>;
+}
+
+/// Time a request has spent governing and waiting on upstreams.
+#[derive(Debug)]
+pub struct LatencyBreakdown {
+    started: Instant,
+    governance_nanos: AtomicU64,
+    upstream_nanos: AtomicU64,
+}
+
+impl LatencyBreakdown {
+    /// A breakdown for a request arriving now.
+    pub fn start() -> Arc<Self> {
+        Arc::new(Self {
+            started: Instant::now(),
+            governance_nanos: AtomicU64::new(0),
+            upstream_nanos: AtomicU64::new(0),
+        })
+    }
+
+    /// Time since the request arrived.
+    pub fn total(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Time spent governing, excluding upstream calls made meanwhile.
+    pub fn governance(&self) -> Duration {
+        Duration::from_nanos(self.governance_nanos.load(Ordering::Relaxed))
+    }
+
+    /// Time spent waiting on upstreams.
+    ///
+    /// Concurrent calls each count in full, so this is capped at what the
+    /// rest of [`Self::total`] leaves.
+    pub fn upstream(&self) -> Duration {
+        let upstream = Duration::from_nanos(self.upstream_nanos.load(Ordering::Relaxed));
+        upstream.min(self.total().saturating_sub(self.governance()))
+    }
+
+    /// Record the breakdown, taking the total as of now.
+    pub fn record(&self, metrics: &LatencyMetrics) {
+        let total = self.total();
+        let governance = self.governance().min(total);
+        let upstream = self.upstream().min(total - governance);
+        metrics.record(total, governance, upstream);
+    }
+
+    fn upstream_so_far(&self) -> u64 {
+        self.upstream_nanos.load(Ordering::Relaxed)
+    }
+}
+
+/// Run `fut` with `breakdown` as the request's breakdown.
+pub async fn with_breakdown<F: Future>(breakdown: Arc<LatencyBreakdown>, fut: F) -> F::Output {
+    BREAKDOWN.scope(breakdown, fut).await
+}
+
+/// The breakdown of the request being handled, if any.
+pub fn current() -> Option<Arc<LatencyBreakdown>> {
+    BREAKDOWN.try_with(Arc::clone).ok()
+}
+
+/// Run `fut`, a call to an upstream, counting its time as upstream time.
+pub async fn upstream<F: Future>(fut: F) -> F::Output {
+    let Some(breakdown) = current() else {
+        return fut.await;
+    };
+    let started = Instant::now();
+    let output = fut.await;
+    breakdown
+        .upstream_nanos
+        .fetch_add(nanos(started.elapsed()), Ordering::Relaxed);
+    output
+}
+
+/// Run `fut`, a governance phase, counting its time as governance overhead
+/// less any upstream calls it makes.
+pub async fn governance<F: Future>(fut: F) -> F::Output {
+    let Some(breakdown) = current() else {
+        return fut.await;
+    };
+    let started = Instant::now();
+    let upstream_before = breakdown.upstream_so_far();
+    let output = fut.await;
+    let upstream = breakdown.upstream_so_far().saturating_sub(upstream_before);
+    breakdown.governance_nanos.fetch_add(
+        nanos(started.elapsed()).saturating_sub(upstream),
+        Ordering::Relaxed,
+    );
+    output
+}
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_governance_excludes_upstream_time() {
+        let breakdown = LatencyBreakdown::start();
+
+        with_breakdown(breakdown.clone(), async {
+            governance(async {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                upstream(tokio::time::sleep(Duration::from_millis(50))).await;
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            })
+            .await;
+            // Body handling outside both phases
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        })
+        .await;
+
+        let (governance, upstream) = (breakdown.governance(), breakdown.upstream());
+        assert!(upstream >= Duration::from_millis(50), "{upstream:?}");
+        assert!(governance >= Duration::from_millis(30), "{governance:?}");
+        assert!(governance + upstream + Duration::from_millis(5) <= breakdown.total());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_upstream_time_capped_by_total() {
+        let breakdown = LatencyBreakdown::start();
+
+        with_breakdown(breakdown.clone(), async {
+            let call = || upstream(tokio::time::sleep(Duration::from_millis(40)));
+            tokio::join!(call(), call());
+        })
+        .await;
+
+        assert!(breakdown.upstream_so_far() >= nanos(Duration::from_millis(80)));
+        assert!(breakdown.upstream() <= breakdown.total());
+    }
+
+    #[tokio::test]
+    async fn test_outside_a_request_nothing_is_counted() {
+        assert!(current().is_none());
+        assert_eq!(upstream(async { 7 }).await, 7);
+        assert_eq!(governance(async { 8 }).await, 8);
+    }
+}
@@ -42,12 +42,22 @@
 // Enable with `--features amber_path` when response inspection is needed
 #[cfg(feature = "amber_path")]
 pub mod buffered_forwarder;
+#[cfg(feature = "amber_path")]
+pub mod spill_buffer;
 
 pub mod admin;
+pub mod admission;
+pub mod audit;
+pub mod callback;
+pub mod clock;
 pub mod config;
+pub mod connection;
+pub mod deadline;
 pub mod error;
 pub mod governance;
+pub mod header_rules;
 pub mod inspector;
+pub mod latency;
 pub mod lifecycle;
 pub mod logging_layer;
 pub mod metrics;
@@ -57,6 +67,13 @@ pub mod protocol;
 pub mod proxy_body;
 pub mod proxy_config;
 pub mod proxy_service;
+pub mod secrets;
+pub mod startup;
+pub mod tap;
 pub mod timeout;
 pub mod traffic;
 pub mod transport;
+
+// In-process proxy harness for integration-style unit tests
+#[cfg(test)]
+pub(crate) mod test_harness;
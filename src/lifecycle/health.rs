@@ -7,14 +7,18 @@
 //! - `/health` (liveness): Returns 200 if process is alive
 //! - `/ready` (readiness): Returns 200 only when all checks pass
 //!
+//! [`probe_router`] serves the same handlers as `/healthz` and `/readyz`.
+//!
 //! ## Response Codes
 //!
 //! | Endpoint | Condition | Status |
 //! |----------|-----------|--------|
 //! | /health  | Process alive | 200 |
 //! | /health  | Process stopped | 503 |
+//! | /health  | Event loop heartbeat overdue | 503 |
 //! | /ready   | All checks pass | 200 |
 //! | /ready   | Any check fails | 503 |
+//! | /ready   | Last policy load failed | 503 |
 //! | /ready   | Shutting down | 503 |
 
 use axum::{
@@ -67,6 +71,8 @@ pub struct UnhealthyResponse {
 pub struct ReadinessChecks {
     /// Whether configuration is loaded and validated
     pub config_loaded: bool,
+    /// Whether the last policy load or reload succeeded
+    pub policy_loaded: bool,
     /// Whether upstream is reachable (cached)
     pub upstream_reachable: bool,
     /// Whether approval store is initialized
@@ -79,7 +85,10 @@ impl ReadinessChecks {
     /// Implements: REQ-CORE-005/F-003.1
     #[must_use]
     pub fn all_pass(&self) -> bool {
-        self.config_loaded && self.upstream_reachable && self.approval_store_initialized
+        self.config_loaded
+            && self.policy_loaded
+            && self.upstream_reachable
+            && self.approval_store_initialized
     }
 
     /// Returns the first failing check name.
@@ -89,6 +98,8 @@ impl ReadinessChecks {
     pub fn first_failure(&self) -> Option<&'static str> {
         if !self.config_loaded {
             Some("config_loaded")
+        } else if !self.policy_loaded {
+            Some("policy_loaded")
         } else if !self.upstream_reachable {
             Some("upstream_reachable")
         } else if !self.approval_store_initialized {
@@ -131,6 +142,20 @@ pub fn health_router(lifecycle: Arc<LifecycleManager>) -> Router {
         .with_state(lifecycle)
 }
 
+/// Create the Kubernetes-style probe router.
+///
+/// Implements: REQ-CORE-005/F-002, F-003
+///
+/// Returns an Axum router with:
+/// - `GET /healthz` - Liveness probe
+/// - `GET /readyz` - Readiness probe
+pub fn probe_router<S>(lifecycle: Arc<LifecycleManager>) -> Router<S> {
+    Router::new()
+        .route("/healthz", get(health_handler))
+        .route("/readyz", get(readiness_handler))
+        .with_state(lifecycle)
+}
+
 // ============================================================================
 // Handlers
 // ============================================================================
@@ -140,7 +165,8 @@ pub fn health_router(lifecycle: Arc<LifecycleManager>) -> Router {
 /// Implements: REQ-CORE-005/F-002
 ///
 /// Returns 200 if process is alive and responsive.
-/// Returns 503 if service is in Stopped state.
+/// Returns 503 if service is in Stopped state or the event loop heartbeat
+/// is overdue.
 ///
 /// # Requirements
 ///
@@ -166,6 +192,19 @@ async fn health_handler(State(lifecycle): State<Arc<LifecycleManager>>) -> Respo
             .into_response();
     }
 
+    // F-002.2: A stalled heartbeat means the runtime is wedged
+    if lifecycle.is_event_loop_stalled() {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(UnhealthyResponse {
+                status: "unhealthy",
+                reason: "event_loop_stalled".to_string(),
+                details: None,
+            }),
+        )
+            .into_response();
+    }
+
     // F-002.1: Return 200 if process is alive
     // F-002.3: Include version and uptime
     (
@@ -190,7 +229,7 @@ async fn health_handler(State(lifecycle): State<Arc<LifecycleManager>>) -> Respo
 ///
 /// - F-003.1: Return 200 only when ALL checks pass
 /// - F-003.2: Return 503 with failed checks in response
-/// - F-003.3: Check policy loading status
+/// - F-003.3: Check policy loading status (last load or reload succeeded)
 /// - F-003.4: Check upstream connectivity (cached)
 /// - F-003.5: Check task store initialization
 /// - F-003.6: During shutdown, return 503
@@ -260,6 +299,7 @@ mod tests {
     use axum::http::Request;
     use http_body_util::BodyExt;
     use serde::Deserialize;
+    use std::time::Duration;
     use tower::ServiceExt;
 
     // Test-only deserializable versions of response types
@@ -277,13 +317,17 @@ mod tests {
     #[derive(Debug, Deserialize)]
     struct TestReadinessChecks {
         config_loaded: bool,
+        policy_loaded: bool,
         upstream_reachable: bool,
         approval_store_initialized: bool,
     }
 
     impl TestReadinessChecks {
         fn all_pass(&self) -> bool {
-            self.config_loaded && self.upstream_reachable && self.approval_store_initialized
+            self.config_loaded
+                && self.policy_loaded
+                && self.upstream_reachable
+                && self.approval_store_initialized
         }
     }
 
@@ -402,6 +446,7 @@ mod tests {
     async fn test_ready_all_checks_pass() {
         let lifecycle = Arc::new(LifecycleManager::new(LifecycleConfig::default()));
         lifecycle.mark_config_loaded();
+        lifecycle.record_policy_load(None);
         lifecycle.mark_approval_store_initialized();
         lifecycle.update_upstream_health(true, None);
         lifecycle.mark_ready();
@@ -429,6 +474,7 @@ mod tests {
     async fn test_ready_upstream_unhealthy() {
         let lifecycle = Arc::new(LifecycleManager::new(LifecycleConfig::default()));
         lifecycle.mark_config_loaded();
+        lifecycle.record_policy_load(None);
         lifecycle.mark_approval_store_initialized();
         // upstream_health stays false (default)
         lifecycle.mark_ready();
@@ -455,6 +501,7 @@ mod tests {
     async fn test_ready_during_shutdown() {
         let lifecycle = Arc::new(LifecycleManager::new(LifecycleConfig::default()));
         lifecycle.mark_config_loaded();
+        lifecycle.record_policy_load(None);
         lifecycle.mark_approval_store_initialized();
         lifecycle.update_upstream_health(true, None);
         lifecycle.mark_ready();
@@ -480,6 +527,7 @@ mod tests {
     fn test_readiness_checks_helpers() {
         let checks = ReadinessChecks {
             config_loaded: false,
+            policy_loaded: true,
             upstream_reachable: true,
             approval_store_initialized: true,
         };
@@ -488,6 +536,16 @@ mod tests {
 
         let checks = ReadinessChecks {
             config_loaded: true,
+            policy_loaded: false,
+            upstream_reachable: true,
+            approval_store_initialized: true,
+        };
+        assert!(!checks.all_pass());
+        assert_eq!(checks.first_failure(), Some("policy_loaded"));
+
+        let checks = ReadinessChecks {
+            config_loaded: true,
+            policy_loaded: true,
             upstream_reachable: false,
             approval_store_initialized: true,
         };
@@ -496,6 +554,7 @@ mod tests {
 
         let checks = ReadinessChecks {
             config_loaded: true,
+            policy_loaded: true,
             upstream_reachable: true,
             approval_store_initialized: false,
         };
@@ -504,10 +563,112 @@ mod tests {
 
         let checks = ReadinessChecks {
             config_loaded: true,
+            policy_loaded: true,
             upstream_reachable: true,
             approval_store_initialized: true,
         };
         assert!(checks.all_pass());
         assert_eq!(checks.first_failure(), None);
     }
+
+    async fn ready_status(
+        lifecycle: &Arc<LifecycleManager>,
+    ) -> (StatusCode, TestReadinessResponse) {
+        let router: Router = probe_router(lifecycle.clone());
+        let req = Request::builder()
+            .uri("/readyz")
+            .body(Body::empty())
+            .unwrap();
+        let resp = router.oneshot(req).await.unwrap();
+        let status = resp.status();
+        let body = resp.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    /// Test readiness flips around a failed policy reload.
+    ///
+    /// Verifies: REQ-CORE-005/F-003.3
+    #[tokio::test]
+    async fn test_readyz_flips_on_policy_load() {
+        let lifecycle = Arc::new(LifecycleManager::new(LifecycleConfig::default()));
+        lifecycle.mark_config_loaded();
+        lifecycle.mark_approval_store_initialized();
+        lifecycle.update_upstream_health(true, None);
+        lifecycle.mark_ready();
+
+        // No policy loaded yet
+        let (status, json) = ready_status(&lifecycle).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(json.reason, Some("policy_loaded".to_string()));
+
+        lifecycle.record_policy_load(None);
+        let (status, json) = ready_status(&lifecycle).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(json.checks.all_pass());
+
+        // A failed reload reports not ready
+        lifecycle.record_policy_load(Some("invalid policy".to_string()));
+        assert_eq!(lifecycle.policy_error(), Some("invalid policy".to_string()));
+        let (status, json) = ready_status(&lifecycle).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert!(!json.checks.policy_loaded);
+
+        // A later successful reload recovers
+        lifecycle.record_policy_load(None);
+        assert_eq!(lifecycle.policy_error(), None);
+        let (status, _) = ready_status(&lifecycle).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    /// Test readiness ignores upstream health when allowed to be down.
+    ///
+    /// Verifies: EC-OPS-011
+    #[tokio::test]
+    async fn test_readyz_allow_upstream_down() {
+        let lifecycle = Arc::new(LifecycleManager::new(LifecycleConfig {
+            allow_upstream_down: true,
+            ..LifecycleConfig::default()
+        }));
+        lifecycle.mark_config_loaded();
+        lifecycle.record_policy_load(None);
+        lifecycle.mark_approval_store_initialized();
+        lifecycle.update_upstream_health(false, Some("connection refused".to_string()));
+        lifecycle.mark_ready();
+
+        let (status, json) = ready_status(&lifecycle).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(json.checks.upstream_reachable);
+    }
+
+    /// Test liveness reports a stalled event loop heartbeat.
+    ///
+    /// Verifies: REQ-CORE-005/F-002.2
+    #[tokio::test]
+    async fn test_healthz_event_loop_stalled() {
+        let lifecycle = Arc::new(LifecycleManager::new(LifecycleConfig {
+            liveness_stall_timeout: Duration::from_millis(50),
+            ..LifecycleConfig::default()
+        }));
+        let heartbeat = lifecycle.spawn_heartbeat();
+        let router: Router = probe_router(lifecycle.clone());
+        let healthz = || {
+            Request::builder()
+                .uri("/healthz")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let resp = router.clone().oneshot(healthz()).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        // Stop the heartbeat and let it go stale
+        heartbeat.abort();
+        std::thread::sleep(Duration::from_millis(100));
+
+        let resp = router.oneshot(healthz()).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body = resp.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["reason"], "event_loop_stalled");
+    }
 }
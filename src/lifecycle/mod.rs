@@ -7,6 +7,7 @@
 //! - Health and readiness probes for Kubernetes
 //! - Graceful shutdown with request draining
 //! - Upstream health monitoring
+//! - Policy load tracking and an event loop heartbeat
 //!
 //! ## Lifecycle States
 //!
@@ -20,7 +21,7 @@
 //! - **Stopped**: Shutdown complete
 
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 
 use arc_swap::ArcSwap;
@@ -30,7 +31,10 @@ use tracing::{info, warn};
 
 pub mod health;
 
-pub use health::{HealthResponse, ReadinessChecks, ReadinessResponse, health_router};
+pub use health::{HealthResponse, ReadinessChecks, ReadinessResponse, health_router, probe_router};
+
+/// How often the event loop heartbeat ticks.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(1);
 
 // ============================================================================
 // Lifecycle State
@@ -81,6 +85,11 @@ pub struct LifecycleConfig {
     pub require_upstream_at_startup: bool,
     /// Upstream health check interval (default: 30s)
     pub upstream_health_interval: Duration,
+    /// Report ready even when the upstream is unreachable (default: false)
+    pub allow_upstream_down: bool,
+    /// Heartbeat age after which liveness reports a stalled event loop
+    /// (default: 10s)
+    pub liveness_stall_timeout: Duration,
 }
 
 impl Default for LifecycleConfig {
@@ -91,6 +100,8 @@ impl Default for LifecycleConfig {
             startup_timeout: Duration::from_secs(15),
             require_upstream_at_startup: false,
             upstream_health_interval: Duration::from_secs(30),
+            allow_upstream_down: false,
+            liveness_stall_timeout: Duration::from_secs(10),
         }
     }
 }
@@ -107,6 +118,8 @@ impl LifecycleConfig {
     /// - `THOUGHTGATE_STARTUP_TIMEOUT_SECS` (default: 15)
     /// - `THOUGHTGATE_REQUIRE_UPSTREAM_AT_STARTUP` (default: false)
     /// - `THOUGHTGATE_UPSTREAM_HEALTH_INTERVAL_SECS` (default: 30)
+    /// - `THOUGHTGATE_ALLOW_UPSTREAM_DOWN` (default: false)
+    /// - `THOUGHTGATE_LIVENESS_STALL_TIMEOUT_SECS` (default: 10)
     #[must_use]
     pub fn from_env() -> Self {
         let default = Self::default();
@@ -132,6 +145,16 @@ impl LifecycleConfig {
             default.upstream_health_interval,
         );
 
+        let allow_upstream_down = std::env::var("THOUGHTGATE_ALLOW_UPSTREAM_DOWN")
+            .ok()
+            .map(|s| s.eq_ignore_ascii_case("true") || s == "1")
+            .unwrap_or(default.allow_upstream_down);
+
+        let liveness_stall_timeout = parse_duration_env(
+            "THOUGHTGATE_LIVENESS_STALL_TIMEOUT_SECS",
+            default.liveness_stall_timeout,
+        );
+
         // Validate drain_timeout < shutdown_timeout as documented
         // Reserve at least 1 second for post-drain cleanup
         // Also enforce a minimum drain of 1 second
@@ -182,6 +205,8 @@ impl LifecycleConfig {
             startup_timeout,
             require_upstream_at_startup,
             upstream_health_interval,
+            allow_upstream_down,
+            liveness_stall_timeout,
         }
     }
 }
@@ -269,6 +294,18 @@ pub struct LifecycleManager {
     /// Whether configuration is loaded and validated
     config_loaded: AtomicBool,
 
+    /// Whether the last policy load or reload succeeded
+    policy_loaded: AtomicBool,
+
+    /// Whether any policy load has succeeded since startup
+    policies_initialized: AtomicBool,
+
+    /// Error from the last failed policy load or reload
+    policy_error: ArcSwap<Option<String>>,
+
+    /// Milliseconds since `started_at` of the last heartbeat (0 = not running)
+    last_heartbeat_ms: AtomicU64,
+
     /// Whether approval store is initialized
     approval_store_initialized: AtomicBool,
 
@@ -294,6 +331,10 @@ impl LifecycleManager {
             active_requests: AtomicUsize::new(0),
             upstream_health: ArcSwap::new(Arc::new(UpstreamHealthStatus::default())),
             config_loaded: AtomicBool::new(false),
+            policy_loaded: AtomicBool::new(false),
+            policies_initialized: AtomicBool::new(false),
+            policy_error: ArcSwap::from_pointee(None),
+            last_heartbeat_ms: AtomicU64::new(0),
             approval_store_initialized: AtomicBool::new(false),
             config,
             version: env!("CARGO_PKG_VERSION"),
@@ -342,6 +383,40 @@ impl LifecycleManager {
         self.config_loaded.store(true, Ordering::SeqCst);
     }
 
+    /// Record the outcome of a policy load or reload.
+    ///
+    /// Implements: REQ-CORE-005/F-003.3
+    ///
+    /// Readiness fails until a load succeeds, and again whenever the most
+    /// recent reload failed, even though the previous policies stay active.
+    pub fn record_policy_load(&self, error: Option<String>) {
+        if let Some(ref e) = error {
+            warn!(error = %e, "Policy load failed, reporting not ready");
+        }
+        self.policy_loaded.store(error.is_none(), Ordering::SeqCst);
+        if error.is_none() {
+            self.policies_initialized.store(true, Ordering::SeqCst);
+        }
+        self.policy_error.store(Arc::new(error));
+    }
+
+    /// Returns true once a policy load has succeeded.
+    ///
+    /// Implements: REQ-CORE-005/F-001
+    ///
+    /// Unlike the `policy_loaded` readiness check this stays true after a
+    /// failed reload, since the previous policies are still being enforced.
+    #[must_use]
+    pub fn policies_initialized(&self) -> bool {
+        self.policies_initialized.load(Ordering::SeqCst)
+    }
+
+    /// Error from the last failed policy load, if the last load failed.
+    #[must_use]
+    pub fn policy_error(&self) -> Option<String> {
+        (**self.policy_error.load()).clone()
+    }
+
     /// Mark approval store as initialized.
     ///
     /// Implements: REQ-CORE-005/F-003.5
@@ -436,16 +511,62 @@ impl LifecycleManager {
     /// Get readiness checks status.
     ///
     /// Implements: REQ-CORE-005/F-003
+    ///
+    /// With `allow_upstream_down`, `upstream_reachable` always passes.
     #[must_use]
     pub fn readiness_checks(&self) -> ReadinessChecks {
         let upstream_health = self.upstream_health.load();
         ReadinessChecks {
             config_loaded: self.config_loaded.load(Ordering::SeqCst),
-            upstream_reachable: upstream_health.is_healthy,
+            policy_loaded: self.policy_loaded.load(Ordering::SeqCst),
+            upstream_reachable: upstream_health.is_healthy || self.config.allow_upstream_down,
             approval_store_initialized: self.approval_store_initialized.load(Ordering::SeqCst),
         }
     }
 
+    /// Returns true if the event loop heartbeat is overdue.
+    ///
+    /// Implements: REQ-CORE-005/F-002.2
+    ///
+    /// Always false until [`spawn_heartbeat`](Self::spawn_heartbeat) runs.
+    #[must_use]
+    pub fn is_event_loop_stalled(&self) -> bool {
+        let last = self.last_heartbeat_ms.load(Ordering::SeqCst);
+        if last == 0 {
+            return false;
+        }
+        let now = self.started_at.elapsed().as_millis() as u64;
+        Duration::from_millis(now.saturating_sub(last)) > self.config.liveness_stall_timeout
+    }
+
+    /// Record a heartbeat now.
+    fn heartbeat(&self) {
+        let now = self.started_at.elapsed().as_millis() as u64;
+        self.last_heartbeat_ms.store(now.max(1), Ordering::SeqCst);
+    }
+
+    /// Spawn a task that ticks the event loop heartbeat until shutdown.
+    ///
+    /// Implements: REQ-CORE-005/F-002.2
+    ///
+    /// If the runtime is too busy or blocked to run this task, liveness
+    /// reports `event_loop_stalled` after `liveness_stall_timeout`.
+    pub fn spawn_heartbeat(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let lifecycle = Arc::clone(self);
+        let shutdown_token = self.shutdown_token.clone();
+        self.heartbeat();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(HEARTBEAT_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = shutdown_token.cancelled() => break,
+                    _ = interval.tick() => lifecycle.heartbeat(),
+                }
+            }
+        })
+    }
+
     /// Drain active requests with timeout.
     ///
     /// Implements: REQ-CORE-005/F-005
@@ -770,9 +891,33 @@ mod tests {
         lifecycle.update_upstream_health(true, None);
         let checks = lifecycle.readiness_checks();
         assert!(checks.upstream_reachable);
+        assert!(!checks.all_pass()); // no policy loaded yet
+
+        lifecycle.record_policy_load(None);
+        let checks = lifecycle.readiness_checks();
+        assert!(checks.policy_loaded);
         assert!(checks.all_pass());
     }
 
+    /// A failed reload keeps the policies initialized but fails readiness.
+    ///
+    /// Verifies: REQ-CORE-005/F-003.3
+    #[test]
+    fn test_policies_initialized_survives_failed_reload() {
+        let lifecycle = LifecycleManager::new(LifecycleConfig::default());
+        assert!(!lifecycle.policies_initialized());
+
+        lifecycle.record_policy_load(Some("parse error".to_string()));
+        assert!(!lifecycle.policies_initialized());
+
+        lifecycle.record_policy_load(None);
+        assert!(lifecycle.policies_initialized());
+
+        lifecycle.record_policy_load(Some("parse error".to_string()));
+        assert!(lifecycle.policies_initialized());
+        assert!(!lifecycle.readiness_checks().policy_loaded);
+    }
+
     /// Test configuration defaults.
     ///
     /// Verifies: REQ-CORE-005/§5.3
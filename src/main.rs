@@ -14,28 +14,32 @@ use mimalloc::MiMalloc;
 static GLOBAL: MiMalloc = MiMalloc;
 
 use clap::Parser;
-use http_body_util::BodyExt;
-use hyper::body::Incoming;
-use hyper::{Request, Response};
-use hyper_util::rt::TokioIo;
-use hyper_util::server::conn::auto;
-use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 use thoughtgate::admin::AdminServer;
-use thoughtgate::config::{self, Version, find_config_file, load_and_validate};
-use thoughtgate::error::ProxyError;
+use thoughtgate::callback::{CALLBACK_PATH, CallbackServer};
+use thoughtgate::config::{
+    self, ConfigWatcher, LiveConfig, Version, find_config_file, load_and_validate,
+};
+use thoughtgate::connection::{
+    ListenerTimeouts, bind_listener, configure_stream, handle_connection,
+};
+use thoughtgate::error::ThoughtGateError;
+use thoughtgate::header_rules::HeaderRules;
+use thoughtgate::inspector::{ClassificationCache, ClassifierPipeline};
 use thoughtgate::lifecycle::{DrainResult, LifecycleConfig, LifecycleManager};
-use thoughtgate::logging_layer::LoggingLayer;
+use thoughtgate::logging_layer::{LogReloadHandle, LoggingConfig, LoggingLayer, init_tracing};
 use thoughtgate::ports::{admin_port, inbound_port, outbound_port};
-use thoughtgate::proxy_config::ProxyConfig;
+use thoughtgate::proxy_config::ProxyConfigLayer;
 use thoughtgate::proxy_service::ProxyService;
+use thoughtgate::transport::tls::InboundTlsConfig;
 use thoughtgate::transport::{
-    McpHandler, McpHandlerConfig, UpstreamClient, UpstreamConfig, create_governance_components,
+    McpHandler, McpHandlerConfig, UpstreamClient, UpstreamConfig, UpstreamForwarder, UpstreamGroup,
+    create_governance_components,
 };
-use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::Semaphore;
+use tokio::net::TcpStream;
+use tokio::sync::{Notify, Semaphore};
 use tokio_util::sync::CancellationToken;
 use tower::ServiceBuilder;
 use tracing::{debug, error, info, warn};
@@ -47,7 +51,7 @@ use tracing::{debug, error, info, warn};
 /// | Port | Env Variable | Default | Purpose |
 /// |------|--------------|---------|---------|
 /// | 7467 | THOUGHTGATE_OUTBOUND_PORT | 7467 | Client requests → upstream (main proxy) |
-/// | 7468 | (reserved) | 7468 | Future inbound (dummy socket, not wired) |
+/// | 7468 | (fixed) | 7468 | Approval callbacks (reserved when no approval engine) |
 /// | 7469 | THOUGHTGATE_ADMIN_PORT | 7469 | Health checks, metrics, admin API |
 ///
 /// # Traceability
@@ -70,6 +74,11 @@ struct Config {
     /// If not specified, searches: THOUGHTGATE_CONFIG env, /etc/thoughtgate/config.yaml, ./config.yaml
     #[arg(long, env = "THOUGHTGATE_CONFIG")]
     config: Option<PathBuf>,
+
+    /// Override a proxy setting, e.g. `--set stream_read_timeout_secs=60` (repeatable).
+    /// Takes precedence over THOUGHTGATE_* environment variables and the config file.
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    overrides: Vec<String>,
 }
 
 /// Main entry point for the ThoughtGate proxy.
@@ -78,16 +87,55 @@ struct Config {
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Phase 1: Initialize observability
-    tracing_subscriber::fmt()
-        .json()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
-        )
-        .init();
+    let log_reload = init_tracing(LoggingConfig::from_env()?);
 
     let cli_config = Config::parse();
-    let proxy_config = ProxyConfig::from_env();
+    let cli_overrides = ProxyConfigLayer::from_overrides(&cli_config.overrides)?;
+
+    // Load YAML config first: its `proxy:` section is the base layer for
+    // proxy settings, overridden by env vars and then `--set` flags.
+    // Implements: REQ-CFG-001 Section 6.3 (Configuration Loading Interface)
+    let yaml_source: Option<(PathBuf, config::Config)> = if let Some(ref path) = cli_config.config {
+        let path_display = path.display().to_string();
+        info!(path = %path_display, "Loading configuration file");
+        let (config, result) = load_and_validate(path, Version::V0_2)?;
+        for warning in &result.warnings {
+            warn!(warning = %warning, "Configuration warning");
+        }
+        Some((path.clone(), config))
+    } else {
+        // Try to find config in default locations
+        match find_config_file(None) {
+            Ok(found_path) => {
+                let path_display = found_path.display().to_string();
+                info!(path = %path_display, "Found configuration file");
+                let (config, result) = load_and_validate(&found_path, Version::V0_2)?;
+                for warning in &result.warnings {
+                    warn!(warning = %warning, "Configuration warning");
+                }
+                Some((found_path, config))
+            }
+            Err(_) => {
+                debug!("No configuration file found, using passthrough mode");
+                None
+            }
+        }
+    };
+
+    let yaml_config = yaml_source.as_ref().map(|(_, config)| config.clone());
+    let proxy_config = config::load(yaml_config.as_ref(), &cli_overrides);
+
+    // Hot-reloadable settings (`runtime:` section), swapped in on SIGHUP or
+    // when the config file changes.
+    // Implements: REQ-CFG-001 Section 9.1 (Configuration Loading Flow)
+    let live_config = Arc::new(LiveConfig::new(
+        yaml_config
+            .as_ref()
+            .and_then(|c| c.runtime.clone())
+            .unwrap_or_default(),
+    ));
+    apply_log_settings(&log_reload, &live_config.load());
+    live_config.on_reload(move |settings| apply_log_settings(&log_reload, settings));
 
     // Phase 2: Initialize lifecycle manager
     // Implements: REQ-CORE-005/F-001
@@ -116,6 +164,53 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         metrics::init_metrics(&meter);
     }
 
+    // Initialize the audit trail (REQ-OBS-002). Failing to open the
+    // configured sink is fatal: running unaudited would be silent.
+    if let Some(audit_log) = thoughtgate::audit::AuditLog::from_env()
+        .map_err(|e| format!("Failed to open THOUGHTGATE_AUDIT_LOG: {e}"))?
+    {
+        thoughtgate::audit::init_audit(audit_log);
+        info!("Audit log enabled");
+    }
+
+    // Likewise for the log of undeliverable approval requests
+    if let Some(dead_letters) =
+        thoughtgate::governance::approval::dead_letter::DeadLetterLog::from_env()
+            .map_err(|e| format!("Failed to open THOUGHTGATE_APPROVAL_DEAD_LETTER_LOG: {e}"))?
+    {
+        thoughtgate::governance::approval::dead_letter::init_dead_letters(dead_letters);
+        info!("Approval dead-letter log enabled");
+    }
+
+    // Shared approval store, before the approval engine is built
+    if let Some(store) = thoughtgate::governance::approval::store::approval_store_from_env()
+        .await
+        .map_err(|e| format!("Failed to open THOUGHTGATE_APPROVAL_REDIS_URL: {e}"))?
+    {
+        thoughtgate::governance::approval::store::init_approval_store(store);
+        info!("Shared approval store enabled");
+    }
+
+    // Secret provider for adapter credentials, before any adapter is built
+    let secrets = thoughtgate::secrets::provider_from_env()
+        .await
+        .map_err(|e| format!("Failed to initialize secrets provider: {e}"))?;
+    info!(provider = ?secrets, "Secrets provider initialized");
+    thoughtgate::secrets::init_secrets(secrets);
+
+    // Startup self-test, before any port is bound
+    // Implements: REQ-CORE-005/F-001
+    if let Some(ref config) = yaml_config
+        && config.self_test.is_some()
+    {
+        let report = thoughtgate::startup::self_test(config).await?;
+        info!(
+            checks = report.checks.len(),
+            passed = report.passed(),
+            "Startup self-test complete"
+        );
+    }
+
     // Phase 3: Create unified shutdown token
     // Implements: REQ-CORE-005/F-004 (Unified Shutdown)
     let shutdown = CancellationToken::new();
@@ -128,10 +223,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tokio::spawn(async move {
         let admin_server = AdminServer::with_config(
             admin_lifecycle,
-            thoughtgate::admin::AdminServerConfig {
-                port: admin_port_val,
-                bind_addr: "0.0.0.0".to_string(),
-            },
+            thoughtgate::admin::AdminServerConfig::with_port(admin_port_val),
         );
         if let Err(e) = admin_server.run(admin_shutdown).await {
             error!(error = %e, "Admin server error");
@@ -139,25 +231,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     });
     info!(
         admin_port = admin_port_val,
-        "Admin server started (/health, /ready, /metrics)"
+        "Admin server started (/health, /ready, /healthz, /readyz, /metrics)"
     );
 
-    // Reserve inbound port (7468) - dummy socket, not wired to anything
-    // This reserves the port for future callback/webhook functionality.
-    // IMPORTANT: This listener must be kept alive (not dropped) to hold the port.
-    // The #[allow(unused)] silences the warning while keeping the socket open.
+    // Bind inbound port (7468) for approval callbacks. It is served once the
+    // approval engine exists (Phase 6); until then, or without an engine,
+    // the listener just holds the port.
     let inbound_port_val = inbound_port();
-    #[allow(unused)]
-    let inbound_listener = TcpListener::bind(format!("0.0.0.0:{}", inbound_port_val)).await?;
-    info!(
-        inbound_port = inbound_port_val,
-        "Inbound port reserved (not wired)"
-    );
+    let inbound_listener =
+        bind_listener(format!("0.0.0.0:{}", inbound_port_val), &proxy_config).await?;
 
     // Phase 5: Bind main listener (outbound port)
     let outbound_port_val = outbound_port();
     let addr = format!("{}:{}", cli_config.bind, outbound_port_val);
-    let listener = TcpListener::bind(&addr).await?;
+    let listener = bind_listener(&addr, &proxy_config).await?;
 
     info!(
         bind = %cli_config.bind,
@@ -168,52 +255,82 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         addr = %addr,
         tcp_nodelay = proxy_config.tcp_nodelay,
         tcp_keepalive_secs = proxy_config.tcp_keepalive_secs,
+        listen_backlog = proxy_config.listen_backlog,
         max_concurrent_streams = proxy_config.max_concurrent_streams,
         socket_buffer_size = proxy_config.socket_buffer_size,
         "ThoughtGate Proxy starting (Envoy-style 3-port model)"
     );
 
-    // Phase 6: Load YAML config and create governance components
+    // Phase 6: Create governance components
     // Implements: REQ-GOV-002 (Governance Pipeline)
-    let yaml_config: Option<config::Config> = if let Some(ref path) = cli_config.config {
-        let path_display = path.display().to_string();
-        info!(path = %path_display, "Loading configuration file");
-        let (config, result) = load_and_validate(path, Version::V0_2)?;
-        for warning in &result.warnings {
-            warn!(warning = %warning, "Configuration warning");
-        }
-        Some(config)
-    } else {
-        // Try to find config in default locations
-        match find_config_file(None) {
-            Ok(found_path) => {
-                let path_display = found_path.display().to_string();
-                info!(path = %path_display, "Found configuration file");
-                let (config, result) = load_and_validate(&found_path, Version::V0_2)?;
-                for warning in &result.warnings {
-                    warn!(warning = %warning, "Configuration warning");
-                }
-                Some(config)
-            }
-            Err(_) => {
-                debug!("No configuration file found, using passthrough mode");
-                None
-            }
-        }
-    };
-
     // Create MCP handler with governance if config exists
+    let mut catalog = None;
+    let mut classifiers = None;
+    let mut policy_engine = None;
+    let mut callback_engine = None;
+    let mut mcp_routes: Vec<Arc<McpHandler>> = Vec::new();
     let mcp_handler: Option<Arc<McpHandler>> = if let Some(ref config) = yaml_config {
-        // Create upstream client for MCP handler
-        let upstream_config = UpstreamConfig::from_env().map_err(|e| {
-            format!("MCP governance requires THOUGHTGATE_UPSTREAM environment variable: {e}")
-        })?;
-        let upstream = Arc::new(UpstreamClient::new(upstream_config)?);
+        // With several sources, each enabled source is routed to its own
+        // upstream at /mcp/v1/{server}; a single source keeps THOUGHTGATE_UPSTREAM
+        let routed = config.sources.len() > 1;
+        let mut route_upstreams = Vec::new();
+        let upstream: Arc<dyn UpstreamForwarder> = if routed {
+            // Approved calls are executed by one shared engine, so they
+            // cannot yet be routed back to the right upstream
+            if config.requires_approval_engine() {
+                return Err("approval rules are not supported with multiple sources".into());
+            }
+            let defaults = UpstreamConfig::defaults_from_env()?;
+            for source in config.sources.iter().filter(|s| s.is_enabled()) {
+                let upstream_config = UpstreamConfig::from_source(source, &defaults);
+                let upstream = source_upstream(source, upstream_config)?;
+                route_upstreams.push((source.id().to_string(), upstream));
+            }
+            match route_upstreams.first() {
+                Some((_, upstream)) => upstream.clone(),
+                None => return Err("no enabled sources to route MCP traffic to".into()),
+            }
+        } else {
+            let mut upstream_config = UpstreamConfig::from_env().map_err(|e| {
+                format!("MCP governance requires THOUGHTGATE_UPSTREAM environment variable: {e}")
+            })?;
+            match config.primary_source() {
+                Some(source) => {
+                    upstream_config.headers = source.headers().cloned();
+                    source_upstream(source, upstream_config)?
+                }
+                None => Arc::new(UpstreamClient::new(upstream_config)?),
+            }
+        };
 
         // Create governance components (TaskHandler, CedarEngine, ApprovalEngine)
         // IMPORTANT: The TaskHandler contains the shared TaskStore that ApprovalEngine uses
-        let (task_handler, cedar_engine, approval_engine) =
-            create_governance_components(upstream.clone(), Some(config), shutdown.clone())?;
+        let (task_handler, cedar_engine, approval_engine) = create_governance_components(
+            upstream.clone(),
+            Some(config),
+            Some(live_config.clone()),
+            shutdown.clone(),
+        )?;
+
+        // Keep the catalog handle for hot-reload
+        catalog = cedar_engine.catalog().cloned();
+        callback_engine = approval_engine.clone();
+        policy_engine = Some(cedar_engine.clone());
+
+        // Decision explanations (REQ-POL-001/§6.3) are operator-only
+        if thoughtgate::policy::explain::enabled_from_env() {
+            thoughtgate::policy::explain::init_explain(cedar_engine.clone());
+            info!("Policy decision explanations enabled (audit log and admin endpoint)");
+        }
+
+        // Content classifiers for Gate 3, with verdicts reused for
+        // identical requests (REQ-CORE-006)
+        let mut pipeline = ClassifierPipeline::from_config(&config.classifiers)
+            .map_err(|e| format!("Invalid classifier pattern: {e}"))?;
+        if let Some(cache) = ClassificationCache::from_env() {
+            pipeline = pipeline.with_cache(cache);
+        }
+        classifiers = Some(pipeline.clone());
 
         // Create MCP handler with full governance
         // Use the same TaskStore that ApprovalEngine uses for task coordination
@@ -224,29 +341,151 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             McpHandlerConfig::from_env(),
             Some(Arc::new(config.clone())),
             approval_engine,
-        );
+        )
+        .with_classifiers(pipeline);
 
         info!(
             requires_approval = config.requires_approval_engine(),
             rules_count = config.governance.rules.len(),
+            routes = route_upstreams.len(),
             "MCP governance enabled (4-gate model)"
         );
 
-        Some(Arc::new(handler))
+        // Upgrades and transports are governed as the first routed source
+        mcp_routes = route_upstreams
+            .into_iter()
+            .map(|(id, upstream)| Arc::new(handler.for_source(id, upstream)))
+            .collect();
+        Some(
+            mcp_routes
+                .first()
+                .cloned()
+                .unwrap_or_else(|| Arc::new(handler)),
+        )
     } else {
         info!("MCP governance disabled (no config file)");
         None
     };
 
+    // Serve approval callbacks (REQ-GOV-003/F-003) on the inbound port;
+    // without an approval engine the listener is kept only to hold the port
+    let _inbound_reservation = match callback_engine {
+        Some(engine) => {
+            let callback_shutdown = shutdown.clone();
+            tokio::spawn(async move {
+                let server = CallbackServer::new(engine);
+                if let Err(e) = server.run(inbound_listener, callback_shutdown).await {
+                    error!(error = %e, "Approval callback server error");
+                }
+            });
+            info!(
+                inbound_port = inbound_port_val,
+                path = CALLBACK_PATH,
+                "Approval callback server started"
+            );
+            None
+        }
+        None => {
+            info!(
+                inbound_port = inbound_port_val,
+                "Inbound port reserved (no approval engine)"
+            );
+            Some(inbound_listener)
+        }
+    };
+
     // Phase 7: Create proxy service
     let mut proxy_service =
         ProxyService::new_with_config(cli_config.upstream_url.clone(), proxy_config.clone())?;
 
+    // Per-source header rules (REQ-CFG-001 Section 7.2); a single source
+    // also owns THOUGHTGATE_UPSTREAM's traffic
+    if let Some(ref config) = yaml_config {
+        let single = config.sources.len() == 1;
+        for source in config.sources.iter().filter(|s| s.is_enabled()) {
+            let Some(headers) = source.headers() else {
+                continue;
+            };
+            let rules = Arc::new(HeaderRules::from_config(source.id(), headers)?);
+            let mut keys = vec![source.id().to_string(), source.url().to_string()];
+            if single && let Some(ref upstream_url) = cli_config.upstream_url {
+                keys.push(upstream_url.clone());
+            }
+            for key in keys {
+                let authority = key
+                    .parse::<http::Uri>()
+                    .ok()
+                    .and_then(|uri| uri.authority().map(|a| a.to_string()));
+                proxy_service =
+                    proxy_service.with_header_rules(authority.unwrap_or(key), rules.clone());
+            }
+            info!(source = source.id(), "Header rules enabled");
+        }
+    }
+
     // Wire MCP handler if governance is enabled
     if let Some(handler) = mcp_handler {
-        proxy_service = proxy_service.with_mcp_handler(handler);
+        proxy_service = proxy_service
+            .with_mcp_handler(handler)
+            .with_lifecycle(lifecycle.clone());
+        for route in mcp_routes {
+            info!(server = route.source_id(), "MCP route enabled");
+            proxy_service = proxy_service.with_mcp_route(route);
+        }
+
+        // Govern gRPC tool calls if a descriptor is provided (REQ-CORE-008)
+        if let Some(grpc) = thoughtgate::transport::grpc::GrpcTransport::from_env()? {
+            info!(methods = grpc.method_count(), "gRPC governance enabled");
+            proxy_service = proxy_service.with_transport(Arc::new(grpc));
+        }
     }
 
+    // Policy simulation endpoint, guarded by its own token (REQ-POL-001/§6.3)
+    if let Some(token) = thoughtgate::policy::explain::simulate_token_from_env() {
+        match policy_engine {
+            Some(engine) => {
+                info!(
+                    path = thoughtgate::proxy_service::POLICY_SIMULATE_PATH,
+                    "Policy simulation enabled"
+                );
+                proxy_service = proxy_service.with_policy_simulator(engine, &token);
+            }
+            None => warn!("THOUGHTGATE_POLICY_SIMULATE_TOKEN ignored: no policy engine"),
+        }
+    }
+
+    // Per-principal tool call quotas (REQ-GOV-001/F-009)
+    if let Some(config) = thoughtgate::governance::QuotaConfig::from_env() {
+        info!(
+            rate_per_second = config.rate_per_second,
+            burst = config.burst,
+            per_tool = config.per_tool,
+            "Tool call quotas enabled"
+        );
+        proxy_service =
+            proxy_service.with_quota(Arc::new(thoughtgate::governance::QuotaLimiter::new(config)));
+    }
+
+    // Recording of sampled MCP exchanges for offline analysis
+    if let Some(config) = thoughtgate::tap::TapConfig::from_env() {
+        let tap = thoughtgate::tap::Tap::from_config(&config)
+            .map_err(|e| format!("Failed to open THOUGHTGATE_TAP_LOG: {e}"))?;
+        info!(sample_rate = config.sample_rate, "Request tap enabled");
+        proxy_service = proxy_service.with_tap(Arc::new(tap));
+    }
+
+    // Terminate TLS on the outbound port if configured (REQ-POL-001/F-006.3)
+    let tls_acceptor = match InboundTlsConfig::from_env()? {
+        Some(tls) => {
+            info!(
+                client_auth = tls.client_ca_path.is_some(),
+                "Inbound TLS enabled"
+            );
+            Some(tls.acceptor()?)
+        }
+        None => None,
+    };
+
     let proxy_service = Arc::new(proxy_service);
     let service_stack = ServiceBuilder::new()
         .layer(LoggingLayer)
@@ -254,7 +493,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Setup signal handlers with unified shutdown token
     // Implements: REQ-CORE-005/F-004 (Signal Handling)
-    setup_signal_handlers(shutdown.clone(), lifecycle.clone());
+    let reload_trigger = Arc::new(Notify::new());
+    setup_signal_handlers(shutdown.clone(), lifecycle.clone(), reload_trigger.clone());
+
+    if let Some((path, config)) = yaml_source {
+        // A failed reload keeps the old policies but reports not ready
+        // Implements: REQ-CORE-005/F-003.3
+        let reload_lifecycle = lifecycle.clone();
+        let mut watcher =
+            ConfigWatcher::new(live_config.clone(), path, config).on_result(move |result| {
+                reload_lifecycle.record_policy_load(result.err().map(|e| e.to_string()))
+            });
+        if let Some(catalog) = catalog {
+            watcher = watcher.with_catalog(catalog);
+        }
+        if let Some(classifiers) = classifiers {
+            watcher = watcher.with_classifiers(classifiers);
+        }
+        tokio::spawn(watcher.run(reload_trigger, shutdown.clone()));
+    }
 
     let config_clone = proxy_config.clone();
 
@@ -327,9 +584,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         std::process::exit(1);
     }
 
+    // Liveness heartbeat
+    // Implements: REQ-CORE-005/F-002.2
+    lifecycle.spawn_heartbeat();
+
     // Mark as ready
     // Implements: REQ-CORE-005/F-001
     lifecycle.mark_config_loaded(); // Configuration loaded and validated
+    lifecycle.record_policy_load(None); // Policies loaded (startup fails otherwise)
     lifecycle.mark_approval_store_initialized(); // Approval store ready
     lifecycle.mark_ready();
 
@@ -373,18 +635,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                         // Configure socket with optimized options
                         // Implements: REQ-CORE-001 Section 3.2 (Network Optimization)
-                        if let Err(e) = configure_tcp_stream(&stream, &config_clone) {
+                        if let Err(e) = configure_stream(&stream, &config_clone) {
                             error!(error = %e, "Failed to configure socket");
                         }
 
                         let service_stack = service_stack.clone();
                         let conn_shutdown = shutdown.clone();
+                        let tls_acceptor = tls_acceptor.clone();
+                        let timeouts = ListenerTimeouts::from_config(&config_clone);
 
                         tokio::spawn(async move {
                             if let Err(e) = handle_connection(
                                 stream,
-                                peer_addr,
+                                tls_acceptor,
                                 service_stack,
+                                timeouts,
                                 conn_shutdown,
                             )
                             .await
@@ -427,6 +692,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Mark as stopped
     lifecycle.mark_stopped();
 
+    // Write out audit records still queued (REQ-OBS-002)
+    if let Err(e) = tokio::task::spawn_blocking(thoughtgate::audit::flush).await {
+        warn!(error = %e, "Failed to flush audit log");
+    }
+    // And dead-lettered approval requests (REQ-GOV-003/F-001)
+    if let Err(e) =
+        tokio::task::spawn_blocking(thoughtgate::governance::approval::dead_letter::flush).await
+    {
+        warn!(error = %e, "Failed to flush dead-letter log");
+    }
+
     // Exit with appropriate code
     // Implements: REQ-CORE-005/F-004.6
     match drain_result {
@@ -455,7 +731,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// - SIGINT (Ctrl+C): Begin graceful shutdown
 /// - SIGTERM: Begin graceful shutdown
 /// - SIGQUIT: Immediate shutdown (no drain)
-fn setup_signal_handlers(shutdown: CancellationToken, lifecycle: Arc<LifecycleManager>) {
+/// - SIGHUP: Reload runtime settings from the config file
+fn setup_signal_handlers(
+    shutdown: CancellationToken,
+    lifecycle: Arc<LifecycleManager>,
+    reload: Arc<Notify>,
+) {
     // SIGINT handler
     let shutdown_sigint = shutdown.clone();
     let lifecycle_sigint = lifecycle.clone();
@@ -515,136 +796,60 @@ fn setup_signal_handlers(shutdown: CancellationToken, lifecycle: Arc<LifecycleMa
         });
     }
 
-    // Prevent unused variable warning on non-Unix
-    #[cfg(not(unix))]
-    let _ = (shutdown, lifecycle);
-}
-
-/// Handle a single connection with HTTP protocol.
-///
-/// # Traceability
-/// - Implements: REQ-CORE-001 F-001 (TCP_NODELAY enforcement)
-/// - Implements: REQ-CORE-002 (Conditional Termination - CONNECT rejection)
-async fn handle_connection<S>(
-    mut stream: TcpStream,
-    _peer_addr: SocketAddr,
-    service: S,
-    shutdown: CancellationToken,
-) -> Result<(), ProxyError>
-where
-    S: tower::Service<
-            Request<Incoming>,
-            Response = Response<thoughtgate::proxy_service::UnifiedBody>,
-            Error = ProxyError,
-        > + Clone
-        + Send
-        + 'static,
-    S::Future: Send + 'static,
-{
-    // Peek to detect CONNECT method and reject it immediately
-    // PERF(latency): Zero-copy peek avoids buffering overhead
-    let mut peek_buf = [0u8; 7];
-    if let Ok(n) = stream.peek(&mut peek_buf).await
-        && n >= 7
-        && &peek_buf[..7] == b"CONNECT"
-    {
-        use tokio::io::AsyncWriteExt;
-
-        warn!("Rejected CONNECT request - ThoughtGate is a termination proxy");
-
-        let body = "405 Method Not Allowed\n\n\
-                 ThoughtGate is a termination proxy for AI governance.\n\
-                 It requires visibility into request headers and bodies.\n\n\
-                 Send plain HTTP requests to http://127.0.0.1:4141 instead.";
-        let content_length = body.len();
-        let response = format!(
-            "HTTP/1.1 405 Method Not Allowed\r\n\
-                 Content-Type: text/plain\r\n\
-                 Content-Length: {}\r\n\
-                 Connection: close\r\n\
-                 \r\n\
-                 {}",
-            content_length, body
-        );
-
-        let _ = stream.write_all(response.as_bytes()).await;
-        return Ok(());
-    }
-
-    let io = TokioIo::new(stream);
-
-    let svc_fn = hyper::service::service_fn(move |req| {
-        let mut svc = service.clone();
-        async move {
-            // Convert ProxyError to proper HTTP response with correct status codes
-            // Implements: REQ-CORE-001 F-002 (Fail-Fast Error Propagation)
-            // Implements: REQ-CORE-002 F-002 (Fail-Closed State)
-            let result: Result<_, std::convert::Infallible> = match svc.call(req).await {
-                Ok(response) => {
-                    // UnifiedBody is BoxBody<Bytes, ProxyError>. Convert to BoxBody<Bytes, Box<dyn Error>>
-                    // for hyper compatibility - hyper requires a boxed error type.
-                    Ok(response.map(|body| {
-                        body.map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-                            Box::new(std::io::Error::other(e.to_string()))
-                        })
-                        .boxed()
-                    }))
-                }
-                Err(e) => {
-                    error!(error = %e, "Service error");
-                    // Use to_response() to map error to proper HTTP status code
-                    // Full<Bytes> has Infallible error - convert using absurd pattern
-                    Ok(e.to_response()
-                        .map(|body| body.map_err(|e| match e {}).boxed()))
+    // SIGHUP handler (Unix only) - Reload runtime settings
+    // Implements: REQ-CFG-001 Section 9.1 (Configuration Loading Flow)
+    #[cfg(unix)]
+    tokio::spawn(async move {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()) {
+            Ok(mut sighup) => {
+                while sighup.recv().await.is_some() {
+                    info!("Received SIGHUP, reloading configuration");
+                    reload.notify_one();
                 }
-            };
-            result
+            }
+            Err(e) => {
+                error!(error = %e, "Failed to listen for SIGHUP");
+            }
         }
     });
 
-    let executor = hyper_util::rt::TokioExecutor::new();
-    let builder = auto::Builder::new(executor);
-    let conn = builder.serve_connection_with_upgrades(io, svc_fn);
-
-    tokio::pin!(conn);
+    // Prevent unused variable warning on non-Unix
+    #[cfg(not(unix))]
+    let _ = (shutdown, lifecycle, reload);
+}
 
-    tokio::select! {
-        result = &mut conn => {
-            if let Err(e) = result {
-                error!(error = %e, "Connection error");
-            }
-        }
-        _ = shutdown.cancelled() => {
-            info!("Shutdown signal received, gracefully closing connection");
-            conn.as_mut().graceful_shutdown();
-            let _ = tokio::time::timeout(Duration::from_secs(5), conn).await;
-        }
+/// Apply reloaded log level and sample rate to the tracing subscriber.
+fn apply_log_settings(handle: &LogReloadHandle, settings: &config::RuntimeSettings) {
+    if let Err(e) = handle.apply(settings.log_level.as_deref(), settings.log_sample_rate) {
+        warn!(error = %e, "Failed to apply log settings");
     }
-
-    Ok(())
 }
 
-/// Configure a TcpStream with optimized socket options.
+/// Build the upstream for a source: one client, or a failover group.
 ///
-/// # Traceability
-/// - Implements: REQ-CORE-001 Section 3.2 (Network Optimization)
-fn configure_tcp_stream(stream: &TcpStream, config: &ProxyConfig) -> std::io::Result<()> {
-    // Set TCP_NODELAY
-    stream.set_nodelay(config.tcp_nodelay)?;
-
-    // Convert to socket2::Socket for advanced options
-    let socket = socket2::SockRef::from(stream);
-
-    // Set TCP keepalive
-    let keepalive =
-        socket2::TcpKeepalive::new().with_time(Duration::from_secs(config.tcp_keepalive_secs));
-    socket.set_tcp_keepalive(&keepalive)?;
-
-    // Set socket buffer sizes
-    socket.set_recv_buffer_size(config.socket_buffer_size)?;
-    socket.set_send_buffer_size(config.socket_buffer_size)?;
-
-    Ok(())
+/// `upstream_config` is the primary's settings; secondaries reuse them with
+/// their own URL.
+fn source_upstream(
+    source: &config::Source,
+    upstream_config: UpstreamConfig,
+) -> Result<Arc<dyn UpstreamForwarder>, ThoughtGateError> {
+    let primary = Arc::new(UpstreamClient::new(upstream_config.clone())?);
+    match source.failover() {
+        Some(failover) => {
+            info!(
+                source = source.id(),
+                members = failover.upstreams.len() + 1,
+                strategy = ?failover.strategy,
+                "Upstream failover group enabled"
+            );
+            Ok(Arc::new(UpstreamGroup::from_failover(
+                primary,
+                &upstream_config,
+                failover,
+            )?))
+        }
+        None => Ok(primary),
+    }
 }
 
 /// Send a 503 Service Unavailable response when semaphore is exhausted.
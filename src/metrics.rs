@@ -9,11 +9,12 @@
 //! - Implements: REQ-CORE-001 NFR-001 (Observability)
 //! - Implements: REQ-CORE-002 NFR-001 (Observability)
 
+use arc_swap::ArcSwap;
 use opentelemetry::KeyValue;
-use opentelemetry::metrics::{Counter, Histogram, Meter};
+use opentelemetry::metrics::{Counter, Histogram, Meter, ObservableGauge, UpDownCounter};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicI64, Ordering};
-use std::time::Instant;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Green Path Metrics (REQ-CORE-001)
@@ -112,6 +113,8 @@ impl GreenPathMetrics {
 /// - `amber_inspector_duration_seconds`: Per-inspector duration histogram
 /// - `amber_path_inspections_total`: Counter by decision type
 /// - `amber_path_errors_total`: Counter by error type
+/// - `amber_path_buffer_budget_used_bytes`: Gauge of bytes reserved against
+///   the global buffer budget
 ///
 /// # Traceability
 /// - Implements: REQ-CORE-002 NFR-001 (Observability - Metrics)
@@ -127,8 +130,14 @@ pub struct AmberPathMetrics {
     pub inspections_total: Counter<u64>,
     /// Error counter
     pub errors_total: Counter<u64>,
+    /// Bodies spilled to disk
+    pub spills_total: Counter<u64>,
     /// Active buffered connections (using atomic for gauge-like behavior)
     pub buffers_active: Arc<AtomicI64>,
+    /// Bytes reserved against the global buffer budget
+    pub budget_used_bytes: Arc<AtomicI64>,
+    /// Exports `budget_used_bytes`
+    _budget_used_gauge: ObservableGauge<i64>,
 }
 
 impl AmberPathMetrics {
@@ -137,6 +146,8 @@ impl AmberPathMetrics {
     /// # Traceability
     /// - Implements: REQ-CORE-002 NFR-001 (Observability)
     pub fn new(meter: &Meter) -> Self {
+        let budget_used_bytes = Arc::new(AtomicI64::new(0));
+        let observed = budget_used_bytes.clone();
         Self {
             buffer_size_bytes: meter
                 .u64_histogram("amber_path_buffer_size_bytes")
@@ -158,7 +169,17 @@ impl AmberPathMetrics {
                 .u64_counter("amber_path_errors_total")
                 .with_description("Total number of Amber Path errors by type")
                 .build(),
+            spills_total: meter
+                .u64_counter("amber_path_spills_total")
+                .with_description("Total number of Amber Path buffers spilled to disk")
+                .build(),
             buffers_active: Arc::new(AtomicI64::new(0)),
+            budget_used_bytes,
+            _budget_used_gauge: meter
+                .i64_observable_gauge("amber_path_buffer_budget_used_bytes")
+                .with_description("Bytes reserved against the global Amber Path buffer budget")
+                .with_callback(move |gauge| gauge.observe(observed.load(Ordering::Relaxed), &[]))
+                .build(),
         }
     }
 
@@ -194,12 +215,17 @@ impl AmberPathMetrics {
     ///
     /// # Arguments
     ///
-    /// * `error_type` - One of: "timeout", "limit", "semaphore", "panic", "compressed", "error"
+    /// * `error_type` - One of: "timeout", "limit", "semaphore", "budget", "panic", "compressed", "error"
     pub fn record_error(&self, error_type: &str) {
         self.errors_total
             .add(1, &[KeyValue::new("type", error_type.to_string())]);
     }
 
+    /// Record a buffer spilled to disk.
+    pub fn record_spill(&self) {
+        self.spills_total.add(1, &[]);
+    }
+
     /// Increment active buffers.
     pub fn increment_active(&self) {
         self.buffers_active.fetch_add(1, Ordering::Relaxed);
@@ -214,6 +240,16 @@ impl AmberPathMetrics {
     pub fn active_count(&self) -> i64 {
         self.buffers_active.load(Ordering::Relaxed)
     }
+
+    /// Adjust the bytes reserved against the buffer budget.
+    pub fn add_budget_used(&self, bytes: i64) {
+        self.budget_used_bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Get the bytes reserved against the buffer budget.
+    pub fn budget_used(&self) -> i64 {
+        self.budget_used_bytes.load(Ordering::Relaxed)
+    }
 }
 
 /// Helper to track inspector timing.
@@ -310,6 +346,578 @@ impl Drop for AmberPathTimer {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Policy Metrics (REQ-POL-001)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Metrics for Cedar policy evaluation and loading.
+///
+/// # Metrics
+///
+/// - `thoughtgate_policy_reload_total`: Counter of reloads by `result`
+///   (`success` or `failure`)
+/// - `thoughtgate_policy_last_reload_timestamp`: Gauge of the Unix time the
+///   live policies were loaded, for staleness alerts
+/// - `thoughtgate_policy_count`: Gauge of policies in the live set
+///
+/// # Traceability
+/// - Implements: REQ-POL-001/F-001 (Policy Evaluation)
+/// - Implements: REQ-POL-001/F-005 (Hot-Reload)
+#[derive(Clone)]
+pub struct PolicyMetrics {
+    /// Requests permitted because the engine errored under fail-open
+    pub fail_open_total: Counter<u64>,
+    /// Policies quarantined after repeated evaluation failures, by policy
+    pub quarantined_total: Counter<u64>,
+    /// Requests decided by the allow/deny lists without Cedar, by decision
+    pub fast_path_total: Counter<u64>,
+    /// Policy reloads, by result
+    pub reload_total: Counter<u64>,
+    /// The live policy set, replaced whole so both gauges agree
+    loaded: Arc<ArcSwap<LoadedPolicies>>,
+    /// Exports `loaded.policy_count`
+    _policy_count_gauge: ObservableGauge<u64>,
+    /// Exports `loaded.loaded_at`
+    _last_reload_gauge: ObservableGauge<f64>,
+}
+
+/// The live policy set, as reported by [`PolicyMetrics`].
+#[derive(Debug, Default)]
+struct LoadedPolicies {
+    /// Number of policies
+    policy_count: u64,
+    /// Seconds since the Unix epoch when they were loaded
+    loaded_at: f64,
+}
+
+impl PolicyMetrics {
+    /// Create new metrics collector.
+    pub fn new(meter: &Meter) -> Self {
+        let loaded = Arc::new(ArcSwap::from_pointee(LoadedPolicies::default()));
+        let count = loaded.clone();
+        let loaded_at = loaded.clone();
+        Self {
+            fail_open_total: meter
+                .u64_counter("policy_fail_open_total")
+                .with_description("Requests permitted after a policy engine error (fail-open)")
+                .build(),
+            quarantined_total: meter
+                .u64_counter("policy_quarantined_total")
+                .with_description("Policies quarantined after repeated evaluation failures")
+                .build(),
+            fast_path_total: meter
+                .u64_counter("policy_fast_path_total")
+                .with_description("Requests decided by the policy allow/deny lists without Cedar")
+                .build(),
+            reload_total: meter
+                // Exported as `thoughtgate_policy_reload_total`
+                .u64_counter("thoughtgate_policy_reload")
+                .with_description("Policy reloads by result")
+                .build(),
+            loaded,
+            _policy_count_gauge: meter
+                .u64_observable_gauge("thoughtgate_policy_count")
+                .with_description("Policies in the live policy set")
+                .with_callback(move |gauge| gauge.observe(count.load().policy_count, &[]))
+                .build(),
+            _last_reload_gauge: meter
+                .f64_observable_gauge("thoughtgate_policy_last_reload_timestamp")
+                .with_description("Unix time the live policies were loaded")
+                .with_callback(move |gauge| gauge.observe(loaded_at.load().loaded_at, &[]))
+                .build(),
+        }
+    }
+
+    /// Record the policy set that just went live, at startup or on reload.
+    pub fn record_policies_loaded(&self, policy_count: usize, loaded_at: SystemTime) {
+        self.loaded.store(Arc::new(LoadedPolicies {
+            policy_count: policy_count as u64,
+            loaded_at: loaded_at
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs_f64(),
+        }));
+    }
+
+    /// Record a successful reload of `policy_count` policies.
+    pub fn record_reload_success(&self, policy_count: usize, loaded_at: SystemTime) {
+        self.record_policies_loaded(policy_count, loaded_at);
+        self.reload_total
+            .add(1, &[KeyValue::new("result", "success")]);
+    }
+
+    /// Record a failed reload; the previous policies stay live.
+    pub fn record_reload_failure(&self) {
+        self.reload_total
+            .add(1, &[KeyValue::new("result", "failure")]);
+    }
+
+    /// Record a request permitted by fail-open.
+    pub fn record_fail_open(&self) {
+        self.fail_open_total.add(1, &[]);
+    }
+
+    /// Record a policy being quarantined.
+    pub fn record_quarantine(&self, policy: &str) {
+        self.quarantined_total
+            .add(1, &[KeyValue::new("policy", policy.to_string())]);
+    }
+
+    /// Record a request decided by the allow/deny lists (`allow` or `deny`).
+    pub fn record_fast_path(&self, decision: &'static str) {
+        self.fast_path_total
+            .add(1, &[KeyValue::new("decision", decision)]);
+    }
+}
+
+/// Metrics for request limits enforced during inspection.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-004/EC-ERR-015 (JSON Limits)
+#[derive(Clone)]
+pub struct LimitMetrics {
+    /// Requests refused by a JSON limit, by reason code
+    pub json_limit_exceeded_total: Counter<u64>,
+    /// Streamed responses aborted for exceeding `max_stream_bytes`
+    pub stream_limit_exceeded_total: Counter<u64>,
+    /// Requests refused for too many or too large headers, by reason
+    pub header_limit_exceeded_total: Counter<u64>,
+}
+
+impl LimitMetrics {
+    /// Create new metrics collector.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            json_limit_exceeded_total: meter
+                .u64_counter("json_limit_exceeded_total")
+                .with_description("Requests refused for exceeding a JSON depth or size limit")
+                .build(),
+            stream_limit_exceeded_total: meter
+                .u64_counter("stream_limit_exceeded_total")
+                .with_description("Streamed responses aborted for exceeding the byte limit")
+                .build(),
+            header_limit_exceeded_total: meter
+                .u64_counter("header_limit_exceeded_total")
+                .with_description("Requests refused for exceeding a header count or size limit")
+                .build(),
+        }
+    }
+
+    /// Record a request refused by a JSON limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - One of: "json_depth_exceeded", "json_size_exceeded"
+    pub fn record_json_limit_exceeded(&self, reason: &str) {
+        self.json_limit_exceeded_total
+            .add(1, &[KeyValue::new("reason", reason.to_string())]);
+    }
+
+    /// Record a streamed response aborted by `max_stream_bytes`.
+    pub fn record_stream_limit_exceeded(&self) {
+        self.stream_limit_exceeded_total.add(1, &[]);
+    }
+
+    /// Record a request refused by a header limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - One of: "header_count", "header_bytes"
+    pub fn record_header_limit_exceeded(&self, reason: &str) {
+        self.header_limit_exceeded_total
+            .add(1, &[KeyValue::new("reason", reason.to_string())]);
+    }
+}
+
+/// Metrics for per-principal tool call quotas.
+///
+/// # Traceability
+/// - Implements: REQ-GOV-001/F-009 (Rate Limiting)
+#[derive(Clone)]
+pub struct QuotaMetrics {
+    /// Tool calls refused for exceeding a quota, by principal
+    pub quota_denied_total: Counter<u64>,
+}
+
+impl QuotaMetrics {
+    /// Create new metrics collector.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            quota_denied_total: meter
+                .u64_counter("quota_denied_total")
+                .with_description("Tool calls refused for exceeding the per-principal quota")
+                .build(),
+        }
+    }
+
+    /// Record a tool call refused by a quota.
+    pub fn record_denied(&self, principal: &str) {
+        self.quota_denied_total
+            .add(1, &[KeyValue::new("principal", principal.to_string())]);
+    }
+}
+
+/// Metrics for requests abandoned by the client.
+#[derive(Clone)]
+pub struct DisconnectMetrics {
+    /// Requests whose client disconnected before the response was ready
+    pub client_disconnects_total: Counter<u64>,
+    /// Blocking-mode approval waits cancelled by a client disconnect
+    pub approvals_abandoned_total: Counter<u64>,
+}
+
+impl DisconnectMetrics {
+    /// Create new metrics collector.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            client_disconnects_total: meter
+                .u64_counter("client_disconnects_total")
+                .with_description("Requests cancelled because the client disconnected")
+                .build(),
+            approvals_abandoned_total: meter
+                .u64_counter("approvals_abandoned_total")
+                .with_description("Blocking approval waits cancelled by a client disconnect")
+                .build(),
+        }
+    }
+
+    /// Record a request cancelled by a client disconnect.
+    pub fn record_disconnect(&self, method: &str) {
+        self.client_disconnects_total
+            .add(1, &[KeyValue::new("method", method.to_string())]);
+    }
+
+    /// Record an approval wait cancelled by a client disconnect.
+    pub fn record_abandoned_approval(&self, tool: &str) {
+        self.approvals_abandoned_total
+            .add(1, &[KeyValue::new("tool", tool.to_string())]);
+    }
+}
+
+/// Metrics for client connections closed by a listener timeout and
+/// HTTP/2 streams refused over the concurrency limit.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+#[derive(Clone)]
+pub struct ConnectionMetrics {
+    /// Connections closed by a timeout, by kind
+    pub timed_out_total: Counter<u64>,
+    /// HTTP/2 streams refused with REFUSED_STREAM
+    pub streams_refused_total: Counter<u64>,
+}
+
+impl ConnectionMetrics {
+    /// Create new metrics collector.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            timed_out_total: meter
+                .u64_counter("connections_timed_out_total")
+                .with_description("Client connections closed by a listener timeout")
+                .build(),
+            streams_refused_total: meter
+                .u64_counter("http2_streams_refused_total")
+                .with_description("HTTP/2 streams refused over the concurrent stream limit")
+                .build(),
+        }
+    }
+
+    /// Record a connection closed by a timeout.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - One of: "header_read", "idle", "request"
+    pub fn record_timeout(&self, kind: &str) {
+        self.timed_out_total
+            .add(1, &[KeyValue::new("kind", kind.to_string())]);
+    }
+
+    /// Record HTTP/2 streams refused over the concurrent stream limit.
+    pub fn record_streams_refused(&self, count: u64) {
+        self.streams_refused_total.add(count, &[]);
+    }
+}
+
+/// Metrics for approval delivery and backlog.
+///
+/// # Metrics
+///
+/// - `approvals_dead_lettered_total`: Counter of undeliverable approval
+///   requests by `adapter`
+/// - `approvals_pending`: Gauge of tasks awaiting a decision or execution
+///
+/// # Traceability
+/// - Implements: REQ-GOV-003/F-001 (Post Approval Request)
+/// - Implements: REQ-GOV-001/F-009.4 (Global capacity)
+#[derive(Clone)]
+pub struct ApprovalMetrics {
+    /// Approval requests that could not be delivered, by adapter
+    pub dead_lettered_total: Counter<u64>,
+    /// Pending approval tasks
+    pub pending: Arc<AtomicI64>,
+    /// Exports `pending`
+    _pending_gauge: ObservableGauge<i64>,
+}
+
+impl ApprovalMetrics {
+    /// Create new metrics collector.
+    pub fn new(meter: &Meter) -> Self {
+        let pending = Arc::new(AtomicI64::new(0));
+        let observed = pending.clone();
+        Self {
+            dead_lettered_total: meter
+                .u64_counter("approvals_dead_lettered_total")
+                .with_description("Approval requests that could not be delivered to approvers")
+                .build(),
+            pending,
+            _pending_gauge: meter
+                .i64_observable_gauge("approvals_pending")
+                .with_description("Approval tasks awaiting a decision or execution")
+                .with_callback(move |gauge| gauge.observe(observed.load(Ordering::Relaxed), &[]))
+                .build(),
+        }
+    }
+
+    /// Record the number of pending approval tasks.
+    pub fn record_pending(&self, pending: usize) {
+        self.pending.store(
+            i64::try_from(pending).unwrap_or(i64::MAX),
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Record an undeliverable approval request.
+    pub fn record_dead_letter(&self, adapter: &str) {
+        self.dead_lettered_total
+            .add(1, &[KeyValue::new("adapter", adapter.to_string())]);
+    }
+}
+
+/// Metrics for upstream connections.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-004/EC-ERR-005 (Upstream Connection Failure)
+#[derive(Clone)]
+pub struct UpstreamMetrics {
+    /// Failed TLS handshakes with an upstream, by upstream and reason code
+    pub tls_failures_total: Counter<u64>,
+}
+
+impl UpstreamMetrics {
+    /// Create new metrics collector.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            tls_failures_total: meter
+                .u64_counter("upstream_tls_failures_total")
+                .with_description("Failed TLS handshakes with an upstream")
+                .build(),
+        }
+    }
+
+    /// Record a failed TLS handshake.
+    ///
+    /// # Arguments
+    ///
+    /// * `upstream` - Upstream URL or authority
+    /// * `reason` - One of: "certificate_expired", "hostname_mismatch",
+    ///   "unknown_ca", "handshake_failed"
+    pub fn record_tls_failure(&self, upstream: &str, reason: &str) {
+        self.tls_failures_total.add(
+            1,
+            &[
+                KeyValue::new("upstream", upstream.to_string()),
+                KeyValue::new("reason", reason.to_string()),
+            ],
+        );
+    }
+}
+
+/// Metrics for request admission control.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 Section 3.2 (Concurrency Limit)
+#[derive(Clone)]
+pub struct AdmissionMetrics {
+    /// Requests currently admitted
+    pub requests_in_flight: UpDownCounter<i64>,
+    /// Requests shed because a concurrency limit was reached, by scope
+    pub requests_overloaded_total: Counter<u64>,
+}
+
+impl AdmissionMetrics {
+    /// Create new metrics collector.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            requests_in_flight: meter
+                .i64_up_down_counter("requests_in_flight")
+                .with_description("Requests admitted and not yet completed")
+                .build(),
+            requests_overloaded_total: meter
+                .u64_counter("requests_overloaded_total")
+                .with_description("Requests shed because a concurrency limit was reached")
+                .build(),
+        }
+    }
+
+    /// Adjust the number of requests in flight.
+    pub fn add_in_flight(&self, delta: i64) {
+        self.requests_in_flight.add(delta, &[]);
+    }
+
+    /// Record a request shed by a concurrency limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - One of: "global", "upstream"
+    pub fn record_overloaded(&self, scope: &str) {
+        self.requests_overloaded_total
+            .add(1, &[KeyValue::new("scope", scope.to_string())]);
+    }
+}
+
+/// Metrics for the request/response tap.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 NFR-001 (Observability)
+#[derive(Clone)]
+pub struct TapMetrics {
+    /// Sampled exchanges not recorded because the tap queue was full
+    pub dropped_total: Counter<u64>,
+}
+
+impl TapMetrics {
+    /// Create new metrics collector.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            dropped_total: meter
+                .u64_counter("tap_records_dropped_total")
+                .with_description("Sampled exchanges not recorded because the tap queue was full")
+                .build(),
+        }
+    }
+
+    /// Record an exchange dropped by the tap.
+    pub fn record_dropped(&self) {
+        self.dropped_total.add(1, &[]);
+    }
+}
+
+/// Metrics for content classification.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-006 (Content Classification)
+#[derive(Clone)]
+pub struct ClassificationMetrics {
+    /// Classification cache lookups, by result
+    pub cache_lookups_total: Counter<u64>,
+}
+
+impl ClassificationMetrics {
+    /// Create new metrics collector.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            cache_lookups_total: meter
+                .u64_counter("classification_cache_lookups_total")
+                .with_description("Classification cache lookups, by result (hit or miss)")
+                .build(),
+        }
+    }
+
+    /// Record a classification cache lookup.
+    pub fn record_cache_lookup(&self, hit: bool) {
+        let result = if hit { "hit" } else { "miss" };
+        self.cache_lookups_total
+            .add(1, &[KeyValue::new("result", result)]);
+    }
+}
+
+/// Per-request latency, split into governance overhead and upstream time.
+///
+/// Recorded from a [`crate::latency::LatencyBreakdown`] when the response
+/// head is ready:
+///
+/// - `thoughtgate_request_duration_seconds`: Histogram of total duration
+/// - `thoughtgate_governance_overhead_seconds`: Histogram of time spent on
+///   inspection, policy evaluation and approval waits
+/// - `thoughtgate_upstream_seconds`: Histogram of time spent waiting on
+///   upstreams
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 NFR-001 (Observability)
+#[derive(Clone)]
+pub struct LatencyMetrics {
+    /// Total duration until the response head
+    pub request_duration_seconds: Histogram<f64>,
+    /// Governance overhead
+    pub governance_overhead_seconds: Histogram<f64>,
+    /// Time waiting on upstreams
+    pub upstream_seconds: Histogram<f64>,
+}
+
+impl LatencyMetrics {
+    /// Create new metrics collector.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            request_duration_seconds: meter
+                .f64_histogram("thoughtgate_request_duration_seconds")
+                .with_description("Time until the response head is sent, in seconds")
+                .build(),
+            governance_overhead_seconds: meter
+                .f64_histogram("thoughtgate_governance_overhead_seconds")
+                .with_description(
+                    "Time spent on inspection, policy evaluation and approval waits, in seconds",
+                )
+                .build(),
+            upstream_seconds: meter
+                .f64_histogram("thoughtgate_upstream_seconds")
+                .with_description("Time spent waiting on upstreams, in seconds")
+                .build(),
+        }
+    }
+
+    /// Record one request's latency breakdown.
+    pub fn record(&self, total: Duration, governance: Duration, upstream: Duration) {
+        self.request_duration_seconds
+            .record(total.as_secs_f64(), &[]);
+        self.governance_overhead_seconds
+            .record(governance.as_secs_f64(), &[]);
+        self.upstream_seconds.record(upstream.as_secs_f64(), &[]);
+    }
+}
+
+/// Metrics for monitor mode.
+///
+/// - `thoughtgate_monitor_decisions_total`: Counter of requests forwarded
+///   that enforcement would have refused or held, by decision
+///   (`would_reject` or `would_approve`)
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 9 (Request Processing)
+#[derive(Clone)]
+pub struct MonitorMetrics {
+    /// Decisions recorded but not enforced, by decision
+    pub decisions_total: Counter<u64>,
+}
+
+impl MonitorMetrics {
+    /// Create new metrics collector.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            decisions_total: meter
+                // Exported as `thoughtgate_monitor_decisions_total`
+                .u64_counter("thoughtgate_monitor_decisions")
+                .with_description("Decisions recorded but not enforced in monitor mode")
+                .build(),
+        }
+    }
+
+    /// Record a decision that was not enforced.
+    pub fn record_decision(&self, decision: &'static str) {
+        self.decisions_total
+            .add(1, &[KeyValue::new("decision", decision)]);
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Global Metrics
 // ─────────────────────────────────────────────────────────────────────────────
@@ -339,12 +947,71 @@ static GREEN_METRICS: once_cell::sync::OnceCell<Arc<GreenPathMetrics>> =
 static AMBER_METRICS: once_cell::sync::OnceCell<Arc<AmberPathMetrics>> =
     once_cell::sync::OnceCell::new();
 
+/// Global policy metrics instance.
+static POLICY_METRICS: once_cell::sync::OnceCell<Arc<PolicyMetrics>> =
+    once_cell::sync::OnceCell::new();
+
+/// Global limit metrics instance.
+static LIMIT_METRICS: once_cell::sync::OnceCell<Arc<LimitMetrics>> =
+    once_cell::sync::OnceCell::new();
+
+/// Global quota metrics instance.
+static QUOTA_METRICS: once_cell::sync::OnceCell<Arc<QuotaMetrics>> =
+    once_cell::sync::OnceCell::new();
+
+/// Global client disconnect metrics instance.
+static DISCONNECT_METRICS: once_cell::sync::OnceCell<Arc<DisconnectMetrics>> =
+    once_cell::sync::OnceCell::new();
+
+/// Global connection timeout metrics instance.
+static CONNECTION_METRICS: once_cell::sync::OnceCell<Arc<ConnectionMetrics>> =
+    once_cell::sync::OnceCell::new();
+
+/// Global approval delivery metrics instance.
+static APPROVAL_METRICS: once_cell::sync::OnceCell<Arc<ApprovalMetrics>> =
+    once_cell::sync::OnceCell::new();
+
+/// Global upstream metrics instance.
+static UPSTREAM_METRICS: once_cell::sync::OnceCell<Arc<UpstreamMetrics>> =
+    once_cell::sync::OnceCell::new();
+
+/// Global admission control metrics instance.
+static ADMISSION_METRICS: once_cell::sync::OnceCell<Arc<AdmissionMetrics>> =
+    once_cell::sync::OnceCell::new();
+
+/// Global tap metrics instance.
+static TAP_METRICS: once_cell::sync::OnceCell<Arc<TapMetrics>> = once_cell::sync::OnceCell::new();
+
+/// Global classification metrics instance.
+static CLASSIFICATION_METRICS: once_cell::sync::OnceCell<Arc<ClassificationMetrics>> =
+    once_cell::sync::OnceCell::new();
+
+/// Global latency breakdown metrics instance.
+static LATENCY_METRICS: once_cell::sync::OnceCell<Arc<LatencyMetrics>> =
+    once_cell::sync::OnceCell::new();
+
+/// Global monitor mode metrics instance.
+static MONITOR_METRICS: once_cell::sync::OnceCell<Arc<MonitorMetrics>> =
+    once_cell::sync::OnceCell::new();
+
 /// Initialize global metrics.
 pub fn init_metrics(meter: &Meter) {
     let green_metrics = Arc::new(GreenPathMetrics::new(meter));
     let amber_metrics = Arc::new(AmberPathMetrics::new(meter));
     let _ = GREEN_METRICS.set(green_metrics);
     let _ = AMBER_METRICS.set(amber_metrics);
+    let _ = POLICY_METRICS.set(Arc::new(PolicyMetrics::new(meter)));
+    let _ = LIMIT_METRICS.set(Arc::new(LimitMetrics::new(meter)));
+    let _ = QUOTA_METRICS.set(Arc::new(QuotaMetrics::new(meter)));
+    let _ = DISCONNECT_METRICS.set(Arc::new(DisconnectMetrics::new(meter)));
+    let _ = CONNECTION_METRICS.set(Arc::new(ConnectionMetrics::new(meter)));
+    let _ = APPROVAL_METRICS.set(Arc::new(ApprovalMetrics::new(meter)));
+    let _ = UPSTREAM_METRICS.set(Arc::new(UpstreamMetrics::new(meter)));
+    let _ = ADMISSION_METRICS.set(Arc::new(AdmissionMetrics::new(meter)));
+    let _ = TAP_METRICS.set(Arc::new(TapMetrics::new(meter)));
+    let _ = CLASSIFICATION_METRICS.set(Arc::new(ClassificationMetrics::new(meter)));
+    let _ = LATENCY_METRICS.set(Arc::new(LatencyMetrics::new(meter)));
+    let _ = MONITOR_METRICS.set(Arc::new(MonitorMetrics::new(meter)));
 }
 
 /// Get global Green Path metrics instance.
@@ -360,6 +1027,159 @@ pub fn get_amber_metrics() -> Option<Arc<AmberPathMetrics>> {
     AMBER_METRICS.get().cloned()
 }
 
+/// Get global policy metrics instance.
+///
+/// # Traceability
+/// - Implements: REQ-POL-001/F-001 (Policy Evaluation)
+pub fn get_policy_metrics() -> Option<Arc<PolicyMetrics>> {
+    POLICY_METRICS.get().cloned()
+}
+
+/// Get global limit metrics instance.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-004/EC-ERR-015 (JSON Limits)
+pub fn get_limit_metrics() -> Option<Arc<LimitMetrics>> {
+    LIMIT_METRICS.get().cloned()
+}
+
+/// Get global quota metrics instance.
+///
+/// # Traceability
+/// - Implements: REQ-GOV-001/F-009 (Rate Limiting)
+pub fn get_quota_metrics() -> Option<Arc<QuotaMetrics>> {
+    QUOTA_METRICS.get().cloned()
+}
+
+/// Get global client disconnect metrics instance.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-005 (Operational Lifecycle)
+pub fn get_disconnect_metrics() -> Option<Arc<DisconnectMetrics>> {
+    DISCONNECT_METRICS.get().cloned()
+}
+
+/// Get global connection timeout metrics instance.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+pub fn get_connection_metrics() -> Option<Arc<ConnectionMetrics>> {
+    CONNECTION_METRICS.get().cloned()
+}
+
+/// Get global approval delivery metrics instance.
+///
+/// # Traceability
+/// - Implements: REQ-GOV-003/F-001 (Post Approval Request)
+pub fn get_approval_metrics() -> Option<Arc<ApprovalMetrics>> {
+    APPROVAL_METRICS.get().cloned()
+}
+
+/// Get global upstream metrics instance.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-004/EC-ERR-005 (Upstream Connection Failure)
+pub fn get_upstream_metrics() -> Option<Arc<UpstreamMetrics>> {
+    UPSTREAM_METRICS.get().cloned()
+}
+
+/// Get global admission control metrics instance.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 Section 3.2 (Concurrency Limit)
+pub fn get_admission_metrics() -> Option<Arc<AdmissionMetrics>> {
+    ADMISSION_METRICS.get().cloned()
+}
+
+/// Get global tap metrics instance.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 NFR-001 (Observability)
+pub fn get_tap_metrics() -> Option<Arc<TapMetrics>> {
+    TAP_METRICS.get().cloned()
+}
+
+/// Get global classification metrics instance.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-006 (Content Classification)
+pub fn get_classification_metrics() -> Option<Arc<ClassificationMetrics>> {
+    CLASSIFICATION_METRICS.get().cloned()
+}
+
+/// Get global latency breakdown metrics instance.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 NFR-001 (Observability)
+pub fn get_latency_metrics() -> Option<Arc<LatencyMetrics>> {
+    LATENCY_METRICS.get().cloned()
+}
+
+/// Get global monitor mode metrics instance.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 9 (Request Processing)
+pub fn get_monitor_metrics() -> Option<Arc<MonitorMetrics>> {
+    MONITOR_METRICS.get().cloned()
+}
+
+/// Test support: the global metrics, exported to a shared registry.
+#[cfg(test)]
+pub(crate) mod testing {
+    use opentelemetry::metrics::MeterProvider;
+    use opentelemetry_sdk::metrics::SdkMeterProvider;
+
+    static REGISTRY: once_cell::sync::Lazy<(prometheus::Registry, SdkMeterProvider)> =
+        once_cell::sync::Lazy::new(|| {
+            let registry = prometheus::Registry::new();
+            let exporter = opentelemetry_prometheus::exporter()
+                .with_registry(registry.clone())
+                .build()
+                .expect("Failed to build exporter");
+            let provider = SdkMeterProvider::builder().with_reader(exporter).build();
+            super::init_metrics(&provider.meter("thoughtgate"));
+            (registry, provider)
+        });
+
+    /// Install the global metrics (idempotent) and return their registry.
+    ///
+    /// Other tests record into the same metrics, so compare before and
+    /// after rather than asserting absolute values.
+    pub(crate) fn registry() -> &'static prometheus::Registry {
+        &REGISTRY.0
+    }
+
+    /// Sample count and sum of the histogram `name`.
+    pub(crate) fn histogram(name: &str) -> (u64, f64) {
+        registry()
+            .gather()
+            .iter()
+            .filter(|family| family.name() == name)
+            .flat_map(|family| family.get_metric())
+            .map(|metric| metric.get_histogram())
+            .fold((0, 0.0), |(count, sum), h| {
+                (count + h.get_sample_count(), sum + h.get_sample_sum())
+            })
+    }
+
+    /// Value of the counter `name` where `label` is `value`.
+    pub(crate) fn counter(name: &str, label: &str, value: &str) -> f64 {
+        registry()
+            .gather()
+            .iter()
+            .filter(|family| family.name() == name)
+            .flat_map(|family| family.get_metric())
+            .filter(|metric| {
+                metric
+                    .get_label()
+                    .iter()
+                    .any(|pair| pair.name() == label && pair.value() == value)
+            })
+            .map(|metric| metric.get_counter().value())
+            .sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -385,6 +1205,12 @@ mod tests {
         assert_eq!(metrics.active_count(), 1);
         metrics.decrement_active();
         assert_eq!(metrics.active_count(), 0);
+
+        // Budget usage tracking
+        metrics.add_budget_used(4096);
+        assert_eq!(metrics.budget_used(), 4096);
+        metrics.add_budget_used(-4096);
+        assert_eq!(metrics.budget_used(), 0);
     }
 
     #[test]
@@ -15,24 +15,31 @@
 //! The legacy `evaluate()` method returning `PolicyAction` is retained
 //! for backward compatibility but marked deprecated.
 
+use super::fast_path::{FastPath, FastPathVerdict};
+use super::loader::PolicyLink;
+use super::namespace::{PolicySets, scope_to_namespace};
+use super::quarantine::{PolicyQuarantine, QuarantineConfig, static_copy, without_quarantined};
 #[allow(deprecated)] // v0.1 types needed for backward compatibility
 use super::{
-    PolicyAction, PolicyError, PolicyRequest, PolicySource, PolicyStats, Resource, loader,
+    PolicyAction, PolicyError, PolicyRequest, PolicySource, PolicyStats, Principal, Resource,
+    loader,
     types::{
-        CedarContext, CedarDecision, CedarRequest, CedarResource, CedarStats, PolicyAnnotations,
-        PolicyInfo,
+        AttrType, AttrValue, CedarContext, CedarDecision, CedarRequest, CedarResource, CedarStats,
+        Explanation, MatchedCondition, PolicyAnnotations, PolicyInfo, TimeContext,
     },
 };
+use crate::config::{LiveCatalog, PolicyErrorMode, RejectCode};
 use arc_swap::ArcSwap;
 use cedar_policy::{
-    Authorizer, Context, Decision, Entities, EntityId, EntityTypeName, EntityUid, PolicySet,
-    Request, Schema,
+    AuthorizationError, Authorizer, Context, Decision, Effect, Entities, EntityId, EntityTypeName,
+    EntityUid, PolicyId, PolicySet, Request, Response, Schema, SchemaFragment, SlotId,
+    ValidationError,
 };
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 use tracing::{debug, error, info, warn};
 
 /// Cedar policy engine for ThoughtGate.
@@ -63,8 +70,17 @@ pub struct CedarEngine {
     /// Cedar authorizer
     authorizer: Authorizer,
 
-    /// Current policy set (atomic for hot-reload)
-    policies: ArcSwap<PolicySet>,
+    /// Current policy sets, global and per namespace (atomic for hot-reload)
+    policies: ArcSwap<PolicySets>,
+
+    /// The policy sets evaluated: `policies` less any quarantined ones
+    active: ArcSwap<PolicySets>,
+
+    /// Policies taken out of evaluation after repeated failures
+    quarantine: PolicyQuarantine,
+
+    /// Allow/deny lists checked before Cedar (atomic for hot-reload)
+    fast_path: ArcSwap<FastPath>,
 
     /// Cedar schema for validation
     schema: Schema,
@@ -75,6 +91,12 @@ pub struct CedarEngine {
     /// Policy source information
     source: Arc<ArcSwap<PolicySource>>,
 
+    /// Decision used when evaluation itself fails
+    on_policy_error: PolicyErrorMode,
+
+    /// Tool-metadata catalog used to enrich resources
+    catalog: Option<Arc<LiveCatalog>>,
+
     /// v0.2 statistics counters
     stats_v2: Arc<StatsV2>,
 
@@ -87,6 +109,7 @@ struct StatsV2 {
     evaluation_count: AtomicU64,
     permit_count: AtomicU64,
     forbid_count: AtomicU64,
+    fail_open_count: AtomicU64,
     total_eval_time_us: AtomicU64,
 }
 
@@ -107,39 +130,70 @@ impl CedarEngine {
     /// - Schema parsing fails
     /// - Policy parsing fails
     /// - Schema validation fails
+    /// - `THOUGHTGATE_POLICIES_SHA256` is set and the loaded policies are
+    ///   missing or do not match it
     pub fn new() -> Result<Self, PolicyError> {
+        Self::new_with_attributes(&BTreeMap::new())
+    }
+
+    /// Create a new Cedar engine whose schema also declares catalog attributes.
+    ///
+    /// Implements: REQ-POL-001/F-003 (Policy Loading)
+    ///
+    /// Each entry is added as an optional attribute of both `ToolCall` and
+    /// `McpMethod`, so policies must guard access with `has`:
+    /// `resource has risk && resource.risk == "low"`.
+    ///
+    /// # Errors
+    /// Same as [`CedarEngine::new`].
+    pub fn new_with_attributes(
+        attributes: &BTreeMap<String, AttrType>,
+    ) -> Result<Self, PolicyError> {
         info!("Initializing Cedar policy engine");
 
         // Load schema
         let schema_str = loader::load_schema();
-        let schema = Schema::from_str(&schema_str).map_err(|e| PolicyError::SchemaValidation {
-            details: format!("Failed to parse schema: {}", e),
-        })?;
+        let schema = Self::build_schema(&schema_str, attributes)?;
 
-        // Load policies
-        let (policy_str, source) = loader::load_policies();
-        let policies = Self::parse_policies(&policy_str, &schema)?;
+        // Load policies and template links
+        let (policy_str, source) = loader::load_policies()?;
+        let links = loader::load_policy_links()?;
+        let namespaces = loader::load_namespace_policies()?;
+        let policies = Self::parse_policy_sets(&policy_str, &links, &namespaces, &schema)?;
+        let fast_path = FastPath::load()?;
 
         // Parse annotations
         let annotations = Self::parse_annotations(&policies);
 
         info!(
             source = ?source,
-            policy_count = policies.policies().count(),
+            policy_count = policies.policy_count(),
+            namespaces = ?policies.namespace_counts(),
             annotated_count = annotations.len(),
             "Cedar engine initialized"
         );
 
+        if let Some(metrics) = crate::metrics::get_policy_metrics() {
+            metrics.record_policies_loaded(policies.policy_count(), SystemTime::now());
+        }
+
+        let policies = Arc::new(policies);
         Ok(Self {
             authorizer: Authorizer::new(),
-            policies: ArcSwap::new(Arc::new(policies)),
+            active: ArcSwap::new(policies.clone()),
+            policies: ArcSwap::new(policies),
+            quarantine: PolicyQuarantine::new(QuarantineConfig::from_env()),
+            fast_path: ArcSwap::new(Arc::new(fast_path)),
             schema,
             annotations: ArcSwap::new(Arc::new(annotations)),
             source: Arc::new(ArcSwap::new(Arc::new(source))),
+            on_policy_error: PolicyErrorMode::default(),
+            catalog: None,
             stats_v2: Arc::new(StatsV2 {
                 evaluation_count: AtomicU64::new(0),
                 permit_count: AtomicU64::new(0),
                 forbid_count: AtomicU64::new(0),
+                fail_open_count: AtomicU64::new(0),
                 total_eval_time_us: AtomicU64::new(0),
             }),
             stats: Arc::new(Stats {
@@ -150,6 +204,112 @@ impl CedarEngine {
         })
     }
 
+    /// Parse the schema, declaring catalog attributes on resource entities.
+    ///
+    /// Attributes already declared by the schema are left untouched.
+    fn build_schema(
+        schema_str: &str,
+        attributes: &BTreeMap<String, AttrType>,
+    ) -> Result<Schema, PolicyError> {
+        let parse_error = |e: &dyn std::fmt::Display| PolicyError::SchemaValidation {
+            details: format!("Failed to parse schema: {}", e),
+        };
+
+        if attributes.is_empty() {
+            return Schema::from_str(schema_str).map_err(|e| parse_error(&e));
+        }
+
+        let (fragment, _warnings) =
+            SchemaFragment::from_cedarschema_str(schema_str).map_err(|e| parse_error(&e))?;
+        let mut json = fragment.to_json_value().map_err(|e| parse_error(&e))?;
+
+        let namespaces = json
+            .as_object_mut()
+            .into_iter()
+            .flat_map(|ns| ns.values_mut());
+        for namespace in namespaces {
+            for entity in ["ToolCall", "McpMethod"] {
+                let Some(declared) = namespace
+                    .pointer_mut(&format!("/entityTypes/{entity}/shape/attributes"))
+                    .and_then(|a| a.as_object_mut())
+                else {
+                    continue;
+                };
+                for (key, ty) in attributes {
+                    declared.entry(key.clone()).or_insert_with(
+                        || serde_json::json!({ "type": ty.schema_name(), "required": false }),
+                    );
+                }
+            }
+        }
+
+        Schema::from_json_value(json).map_err(|e| parse_error(&e))
+    }
+
+    /// Set how engine errors are decided (default: fail-closed).
+    ///
+    /// Implements: REQ-POL-001/F-001 (Policy Evaluation)
+    #[must_use]
+    pub fn with_on_policy_error(mut self, mode: PolicyErrorMode) -> Self {
+        if mode == PolicyErrorMode::FailOpen {
+            warn!("Cedar engine configured to FAIL OPEN: engine errors will permit requests");
+        }
+        self.on_policy_error = mode;
+        self
+    }
+
+    /// Set when repeatedly failing policies are quarantined (default: from
+    /// the environment, see [`QuarantineConfig::from_env`]).
+    ///
+    /// Implements: REQ-POL-001/F-001 (Policy Evaluation)
+    #[must_use]
+    pub fn with_quarantine(mut self, config: QuarantineConfig) -> Self {
+        self.quarantine = PolicyQuarantine::new(config);
+        self.active.store(self.policies.load_full());
+        self
+    }
+
+    /// Decide listed `(server, tool)` pairs before Cedar (default: from the
+    /// environment, see [`FastPath::load`]).
+    ///
+    /// Implements: REQ-POL-001/F-001 (Policy Evaluation)
+    #[must_use]
+    pub fn with_fast_path(self, fast_path: FastPath) -> Self {
+        self.fast_path.store(Arc::new(fast_path));
+        self
+    }
+
+    /// Policies currently quarantined, by ID.
+    ///
+    /// Implements: REQ-POL-001/F-001 (Policy Evaluation)
+    pub fn quarantined_policies(&self) -> Vec<String> {
+        self.quarantine.quarantined()
+    }
+
+    /// Enrich resources from a tool-metadata catalog before evaluation.
+    ///
+    /// Build the engine with [`CedarEngine::new_with_attributes`] using the
+    /// catalog's [`LiveCatalog::declared_types`] so policies type-check.
+    #[must_use]
+    pub fn with_catalog(mut self, catalog: Arc<LiveCatalog>) -> Self {
+        self.catalog = Some(catalog);
+        self
+    }
+
+    /// The tool-metadata catalog, if configured.
+    pub fn catalog(&self) -> Option<&Arc<LiveCatalog>> {
+        self.catalog.as_ref()
+    }
+
+    /// Attach catalog attributes to a resource (no-op without a catalog).
+    ///
+    /// Implements: REQ-CFG-001/7.7 (Tool Metadata Catalog)
+    pub fn enrich(&self, resource: &mut CedarResource) {
+        if let Some(ref catalog) = self.catalog {
+            catalog.catalog().enrich(resource);
+        }
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     // v0.2 API (REQ-POL-001/F-001)
     // ═══════════════════════════════════════════════════════════════════════════
@@ -173,30 +333,31 @@ impl CedarEngine {
     ///
     /// - `CedarDecision::Permit` - Continue to Gate 4
     /// - `CedarDecision::Forbid` - Deny with -32003 PolicyDenied
+    ///
+    /// Requests matching the fast-path allow/deny lists are decided before
+    /// any of this, without touching Cedar (see [`super::fast_path`]).
     pub fn evaluate_v2(&self, request: &CedarRequest) -> CedarDecision {
+        if let Some(decision) = self.check_fast_path(request) {
+            return decision;
+        }
+
         let start = std::time::Instant::now();
         self.stats_v2
             .evaluation_count
             .fetch_add(1, Ordering::Relaxed);
 
-        let policies = self.policies.load();
+        let policies = self.active_policies();
+        let policies = policies.for_namespace(&request.principal.namespace);
 
         // Determine action based on resource type
-        let action_name = match &request.resource {
-            CedarResource::ToolCall { .. } => "tools/call",
-            CedarResource::McpMethod { .. } => "mcp/method",
-        };
+        let action_name = v2_action_name(&request.resource);
 
         // Build and evaluate Cedar request
         let cedar_request = match self.build_cedar_request_v2(request, action_name) {
             Ok(req) => req,
             Err(e) => {
                 error!(error = %e, "Failed to build Cedar request");
-                self.stats_v2.forbid_count.fetch_add(1, Ordering::Relaxed);
-                return CedarDecision::Forbid {
-                    reason: format!("Failed to build request: {}", e),
-                    policy_ids: vec![],
-                };
+                return self.engine_error(request, format!("Failed to build request: {}", e));
             }
         };
 
@@ -205,18 +366,14 @@ impl CedarEngine {
             Ok(e) => e,
             Err(e) => {
                 error!(error = %e, "Failed to build entities");
-                self.stats_v2.forbid_count.fetch_add(1, Ordering::Relaxed);
-                return CedarDecision::Forbid {
-                    reason: format!("Failed to build entities: {}", e),
-                    policy_ids: vec![],
-                };
+                return self.engine_error(request, format!("Failed to build entities: {}", e));
             }
         };
 
         // Evaluate
-        let response = self
-            .authorizer
-            .is_authorized(&cedar_request, &policies, &entities);
+        let Some(response) = self.authorize(&cedar_request, policies, &entities) else {
+            return self.engine_error(request, "Policy evaluation panicked".to_string());
+        };
 
         let elapsed = start.elapsed();
         self.stats_v2
@@ -249,8 +406,6 @@ impl CedarEngine {
                 }
             }
             Decision::Deny => {
-                self.stats_v2.forbid_count.fetch_add(1, Ordering::Relaxed);
-
                 // Extract forbidding policy IDs
                 let policy_ids: Vec<String> = response
                     .diagnostics()
@@ -265,14 +420,34 @@ impl CedarEngine {
                     .map(|e| e.to_string())
                     .collect();
 
-                let reason = if errors.is_empty() {
+                // Denied only because policies failed to evaluate: an engine
+                // error rather than an explicit forbid.
+                if policy_ids.is_empty() && !errors.is_empty() {
+                    return self.engine_error(
+                        request,
+                        format!("Policy evaluation errors: {}", errors.join("; ")),
+                    );
+                }
+
+                self.stats_v2.forbid_count.fetch_add(1, Ordering::Relaxed);
+
+                let (code, reason) = if errors.is_empty() {
                     if policy_ids.is_empty() {
-                        "No policy permits this action (default-deny)".to_string()
+                        (
+                            RejectCode::DefaultDeny,
+                            "No policy permits this action (default-deny)".to_string(),
+                        )
                     } else {
-                        format!("Forbidden by policy: {}", policy_ids.join(", "))
+                        (
+                            RejectCode::Forbidden,
+                            format!("Forbidden by policy: {}", policy_ids.join(", ")),
+                        )
                     }
                 } else {
-                    format!("Policy evaluation errors: {}", errors.join("; "))
+                    (
+                        RejectCode::PolicyError,
+                        format!("Policy evaluation errors: {}", errors.join("; ")),
+                    )
                 };
 
                 warn!(
@@ -284,7 +459,261 @@ impl CedarEngine {
                     "Cedar forbid"
                 );
 
-                CedarDecision::Forbid { reason, policy_ids }
+                CedarDecision::Forbid {
+                    code,
+                    reason,
+                    policy_ids,
+                }
+            }
+        }
+    }
+
+    /// Run one throwaway evaluation so the first real request is not slower.
+    ///
+    /// Implements: REQ-OBS-001 M-POL-001 (Policy Evaluation Performance)
+    ///
+    /// Policies are parsed and validated at load, but the first evaluation
+    /// still pays one-off costs (allocator growth, cold caches). Call this at
+    /// startup; [`Self::reload`] calls it after swapping in new policies.
+    /// Statistics are not updated and the decision is discarded.
+    ///
+    /// Returns how long the evaluation took.
+    pub fn warmup(&self) -> Duration {
+        let start = std::time::Instant::now();
+        let request = CedarRequest {
+            principal: Principal {
+                app_name: "warmup".to_string(),
+                namespace: "default".to_string(),
+                service_account: "default".to_string(),
+                roles: Vec::new(),
+            },
+            resource: CedarResource::ToolCall {
+                name: "warmup".to_string(),
+                server: "warmup".to_string(),
+                arguments: serde_json::json!({}),
+                attributes: BTreeMap::new(),
+            },
+            context: CedarContext {
+                policy_id: "warmup".to_string(),
+                source_id: "warmup".to_string(),
+                time: TimeContext::now(),
+            },
+        };
+
+        let action_name = v2_action_name(&request.resource);
+        let built = self
+            .build_cedar_request_v2(&request, action_name)
+            .and_then(|req| Ok((req, self.build_entities_v2(&request)?)));
+        match built {
+            Ok((cedar_request, entities)) => {
+                let policies = self.active_policies();
+                let policies = policies.for_namespace(&request.principal.namespace);
+                self.authorize(&cedar_request, policies, &entities);
+            }
+            Err(e) => warn!(error = %e, "Cedar warmup could not build a request"),
+        }
+
+        let elapsed = start.elapsed();
+        debug!(duration_us = elapsed.as_micros(), "Cedar engine warmed up");
+        elapsed
+    }
+
+    /// Explain a decision returned by [`Self::evaluate_v2`].
+    ///
+    /// Implements: REQ-POL-001/§6.3 (Decision Explanation)
+    ///
+    /// Conditions are looked up in the current policy set; a policy removed
+    /// by a reload since the evaluation is listed without one.
+    pub fn explain_v2(&self, request: &CedarRequest, decision: &CedarDecision) -> Explanation {
+        let policies = self.policies.load();
+        let policies = policies.for_namespace(&request.principal.namespace);
+        let ids = match decision {
+            CedarDecision::Permit {
+                determining_policies,
+            } => determining_policies,
+            CedarDecision::Forbid { policy_ids, .. } => policy_ids,
+        };
+        let mut explanation = Explanation::default();
+        explanation.determining_policy = record_reasons(
+            policies,
+            v2_action_name(&request.resource),
+            ids.iter().cloned(),
+            &mut explanation,
+        );
+        explanation
+    }
+
+    /// Name a policy the way its author would: by its `@id` annotation if
+    /// it has one, otherwise by its Cedar ID (`policy0`).
+    ///
+    /// `namespace` selects the policy set, as a request's principal does.
+    pub fn policy_display_name(&self, namespace: &str, id: &str) -> String {
+        let policies = self.policies.load();
+        policy_name(policies.for_namespace(namespace), &PolicyId::new(id))
+    }
+
+    /// The policy sets to evaluate, ending quarantines whose time is up.
+    fn active_policies(&self) -> Arc<PolicySets> {
+        let released = self.quarantine.release_expired();
+        if !released.is_empty() {
+            warn!(policies = ?released, "Policy quarantine ended, evaluating policies again");
+            self.rebuild_active();
+        }
+        self.active.load_full()
+    }
+
+    fn rebuild_active(&self) {
+        let policies = self.policies.load();
+        let quarantined = self.quarantine.quarantined();
+        self.active.store(Arc::new(
+            policies.map(|set| without_quarantined(set, &quarantined)),
+        ));
+    }
+
+    /// Run the authorizer, counting policy failures toward quarantine.
+    ///
+    /// Implements: REQ-POL-001/F-001 (Policy Evaluation)
+    ///
+    /// A policy that errors is skipped by Cedar and named in the
+    /// diagnostics. A panic names no policy, so the policies are evaluated
+    /// one by one to find the ones that cause it. Returns `None` if the
+    /// evaluation panicked.
+    fn authorize(
+        &self,
+        request: &Request,
+        policies: &PolicySet,
+        entities: &Entities,
+    ) -> Option<Response> {
+        let run = |set: &PolicySet| {
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                self.authorizer.is_authorized(request, set, entities)
+            }))
+        };
+
+        let failing: Vec<PolicyId> = match run(policies) {
+            Ok(response) => {
+                let failing: Vec<PolicyId> = response
+                    .diagnostics()
+                    .errors()
+                    .map(|e| match e {
+                        AuthorizationError::PolicyEvaluationError(e) => e.policy_id().clone(),
+                    })
+                    .collect();
+                self.record_failures(policies, &failing);
+                return Some(response);
+            }
+            Err(_) => policies
+                .policies()
+                .filter(|policy| {
+                    let mut single = PolicySet::new();
+                    static_copy(policy, true)
+                        .is_some_and(|copy| single.add(copy).is_ok() && run(&single).is_err())
+                })
+                .map(|policy| policy.id().clone())
+                .collect(),
+        };
+        error!(policies = ?failing, "Cedar evaluation panicked");
+        self.record_failures(policies, &failing);
+        None
+    }
+
+    /// Count failures of `failing`, quarantining those past the threshold.
+    fn record_failures(&self, policies: &PolicySet, failing: &[PolicyId]) {
+        let mut quarantined = false;
+        for id in failing {
+            if !self.quarantine.record_failure(id.as_ref()) {
+                continue;
+            }
+            quarantined = true;
+            let config = self.quarantine.config();
+            error!(
+                policy = %id,
+                name = %policy_name(policies, id),
+                failures = config.threshold,
+                window_secs = config.window.as_secs(),
+                quarantine_secs = config.duration.as_secs(),
+                "Policy QUARANTINED after repeated evaluation failures"
+            );
+            if let Some(metrics) = crate::metrics::get_policy_metrics() {
+                metrics.record_quarantine(id.as_ref());
+            }
+        }
+        if quarantined {
+            self.rebuild_active();
+        }
+    }
+
+    /// Decide a request from the fast-path lists, if it is listed.
+    ///
+    /// Implements: REQ-POL-001/F-001 (Policy Evaluation)
+    fn check_fast_path(&self, request: &CedarRequest) -> Option<CedarDecision> {
+        let verdict = self
+            .fast_path
+            .load()
+            .check(request.resource.server(), request.resource.name())?;
+        if let Some(metrics) = crate::metrics::get_policy_metrics() {
+            metrics.record_fast_path(verdict.as_str());
+        }
+
+        Some(match verdict {
+            FastPathVerdict::Allow => {
+                debug!(
+                    principal = %request.principal.app_name,
+                    server = %request.resource.server(),
+                    resource = %request.resource.name(),
+                    "Fast-path allow, Cedar skipped"
+                );
+                CedarDecision::Permit {
+                    determining_policies: Vec::new(),
+                }
+            }
+            FastPathVerdict::Deny => {
+                warn!(
+                    principal = %request.principal.app_name,
+                    server = %request.resource.server(),
+                    resource = %request.resource.name(),
+                    "Fast-path deny, Cedar skipped"
+                );
+                CedarDecision::Forbid {
+                    code: RejectCode::Denylist,
+                    reason: "Denied by the policy denylist".to_string(),
+                    policy_ids: Vec::new(),
+                }
+            }
+        })
+    }
+
+    /// Decide a request whose evaluation failed, per `on_policy_error`.
+    ///
+    /// Implements: REQ-POL-001/F-001 (Policy Evaluation)
+    fn engine_error(&self, request: &CedarRequest, reason: String) -> CedarDecision {
+        match self.on_policy_error {
+            PolicyErrorMode::FailClosed => {
+                self.stats_v2.forbid_count.fetch_add(1, Ordering::Relaxed);
+                CedarDecision::Forbid {
+                    code: RejectCode::PolicyError,
+                    reason,
+                    policy_ids: vec![],
+                }
+            }
+            PolicyErrorMode::FailOpen => {
+                self.stats_v2.permit_count.fetch_add(1, Ordering::Relaxed);
+                self.stats_v2
+                    .fail_open_count
+                    .fetch_add(1, Ordering::Relaxed);
+                if let Some(metrics) = crate::metrics::get_policy_metrics() {
+                    metrics.record_fail_open();
+                }
+                error!(
+                    principal = %request.principal.app_name,
+                    resource = %request.resource.name(),
+                    policy_id = %request.context.policy_id,
+                    reason = %reason,
+                    "Cedar engine error, FAILING OPEN: request permitted without a policy decision"
+                );
+                CedarDecision::Permit {
+                    determining_policies: vec![],
+                }
             }
         }
     }
@@ -457,6 +886,7 @@ impl CedarEngine {
                 name,
                 server,
                 arguments,
+                attributes,
             } => {
                 let mut attrs = HashMap::new();
                 attrs.insert(
@@ -471,10 +901,15 @@ impl CedarEngine {
                 // Convert JSON arguments to Cedar record
                 let arguments_expr = self.json_to_cedar_expr(arguments)?;
                 attrs.insert("arguments".to_string(), arguments_expr);
+                insert_catalog_attributes(&mut attrs, attributes);
 
                 ("ThoughtGate::ToolCall", name.clone(), attrs)
             }
-            CedarResource::McpMethod { method, server } => {
+            CedarResource::McpMethod {
+                method,
+                server,
+                attributes,
+            } => {
                 let mut attrs = HashMap::new();
                 attrs.insert(
                     "method".to_string(),
@@ -484,6 +919,7 @@ impl CedarEngine {
                     "server".to_string(),
                     RestrictedExpression::new_string(server.clone()),
                 );
+                insert_catalog_attributes(&mut attrs, attributes);
                 ("ThoughtGate::McpMethod", method.clone(), attrs)
             }
         };
@@ -613,11 +1049,8 @@ impl CedarEngine {
             evaluation_count: eval_count,
             permit_count: self.stats_v2.permit_count.load(Ordering::Relaxed),
             forbid_count: self.stats_v2.forbid_count.load(Ordering::Relaxed),
-            avg_eval_time_us: if eval_count > 0 {
-                total_time / eval_count
-            } else {
-                0
-            },
+            fail_open_count: self.stats_v2.fail_open_count.load(Ordering::Relaxed),
+            avg_eval_time_us: total_time.checked_div(eval_count).unwrap_or(0),
         }
     }
 
@@ -630,7 +1063,7 @@ impl CedarEngine {
 
         PolicyInfo {
             paths: vec![], // TODO: Track paths from loader
-            policy_count: policies.policies().count(),
+            policy_count: policies.policy_count(),
             last_reload: *self.stats.last_reload.load().as_ref(),
             annotated_policy_count: annotations.len(),
         }
@@ -639,10 +1072,10 @@ impl CedarEngine {
     /// Parse policy annotations at load time.
     ///
     /// Implements: REQ-POL-001/§8.0 (Using Policy Annotations for Workflow Routing)
-    fn parse_annotations(policies: &PolicySet) -> PolicyAnnotations {
+    fn parse_annotations(policies: &PolicySets) -> PolicyAnnotations {
         let mut annotations = PolicyAnnotations::new();
 
-        for policy in policies.policies() {
+        for policy in policies.sets().flat_map(PolicySet::policies) {
             // Check for @thoughtgate_approval annotation
             if let Some(workflow) = policy.annotation("thoughtgate_approval") {
                 let policy_id = policy.id().to_string();
@@ -678,13 +1111,15 @@ impl CedarEngine {
     pub fn evaluate(&self, request: &PolicyRequest) -> PolicyAction {
         self.stats.evaluation_count.fetch_add(1, Ordering::Relaxed);
 
-        let policies = self.policies.load();
+        let policies = self.active_policies();
+        let policies = policies.for_namespace(&request.principal.namespace);
 
         // v0.1: Check actions in priority order: Forward → Approve
-        let actions = ["Forward", "Approve"];
-
-        for action_name in &actions {
-            if self.is_action_permitted(request, action_name, &policies) {
+        for action_name in V01_ACTIONS {
+            let permitted = self
+                .authorize_action(request, action_name, policies)
+                .is_some_and(|response| response.decision() == Decision::Allow);
+            if permitted {
                 debug!(
                     principal = %request.principal.app_name,
                     resource = ?request.resource,
@@ -692,13 +1127,7 @@ impl CedarEngine {
                     "Policy permit"
                 );
 
-                return match *action_name {
-                    "Forward" => PolicyAction::Forward,
-                    "Approve" => PolicyAction::Approve {
-                        timeout: Duration::from_secs(300), // Default 5 minutes
-                    },
-                    _ => unreachable!(),
-                };
+                return v01_action(action_name);
             }
         }
 
@@ -709,38 +1138,129 @@ impl CedarEngine {
             "Policy denied - no permitted action"
         );
 
-        PolicyAction::Reject {
-            reason: "No policy permits this request".to_string(),
+        v01_reject()
+    }
+
+    /// Evaluate a policy request (v0.1 API) and explain the outcome.
+    ///
+    /// Implements: REQ-POL-001/§6.3 (Decision Explanation)
+    ///
+    /// Returns the same action as [`Self::evaluate`], with the policies that
+    /// were satisfied for each action checked. On a permit the determining
+    /// policy is a permit of the granted action; on a reject it is the first
+    /// forbid that applied, or `None` if nothing was permitted by default.
+    ///
+    /// The explanation exposes policy internals: never return it to
+    /// untrusted clients.
+    #[allow(deprecated)]
+    pub fn evaluate_explained(&self, request: &PolicyRequest) -> (PolicyAction, Explanation) {
+        self.stats.evaluation_count.fetch_add(1, Ordering::Relaxed);
+
+        let policies = self.active_policies();
+        let policies = policies.for_namespace(&request.principal.namespace);
+        let mut explanation = Explanation::default();
+        let mut first_forbid = None;
+
+        for action_name in V01_ACTIONS {
+            let Some(response) = self.authorize_action(request, action_name, policies) else {
+                continue;
+            };
+            let first = record_reasons(
+                policies,
+                action_name,
+                response.diagnostics().reason().map(PolicyId::to_string),
+                &mut explanation,
+            );
+            if response.decision() == Decision::Allow {
+                explanation.determining_policy = first;
+                return (v01_action(action_name), explanation);
+            }
+            first_forbid = first_forbid.or(first);
         }
+
+        explanation.determining_policy = first_forbid;
+        (v01_reject(), explanation)
     }
 
-    /// Check if a specific action is permitted (v0.1).
-    fn is_action_permitted(
+    /// Authorize a single action (v0.1).
+    ///
+    /// Returns `None` if the Cedar request could not be built, which is
+    /// treated as not permitted.
+    fn authorize_action(
         &self,
         request: &PolicyRequest,
         action_name: &str,
         policies: &PolicySet,
-    ) -> bool {
+    ) -> Option<cedar_policy::Response> {
         // Build Cedar request
         let cedar_request = match self.build_cedar_request(request, action_name) {
             Ok(req) => req,
             Err(e) => {
                 error!(error = %e, "Failed to build Cedar request");
-                return false;
+                return None;
+            }
+        };
+
+        // Note: For v0.1, only the resource entity is populated, and only
+        // with name/server and catalog attributes. Principal attributes
+        // (principal.namespace == "prod") are not available.
+        let entities = match Self::build_resource_entities(&cedar_request, &request.resource) {
+            Ok(entities) => entities,
+            Err(e) => {
+                error!(error = %e, "Failed to build resource entity");
+                return None;
             }
         };
+        self.authorize(&cedar_request, policies, &entities)
+    }
+
+    /// Build an entity store holding just the resource (v0.1).
+    fn build_resource_entities(
+        cedar_request: &Request,
+        resource: &Resource,
+    ) -> Result<Entities, PolicyError> {
+        use cedar_policy::{Entity, RestrictedExpression};
+
+        let Some(resource_uid) = cedar_request.resource() else {
+            return Ok(Entities::empty());
+        };
+
+        let (key, id, server, attributes) = match resource {
+            Resource::ToolCall {
+                name,
+                server,
+                attributes,
+            } => ("name", name, server, attributes),
+            Resource::McpMethod {
+                method,
+                server,
+                attributes,
+            } => ("method", method, server, attributes),
+        };
+
+        let mut attrs = HashMap::new();
+        attrs.insert(
+            key.to_string(),
+            RestrictedExpression::new_string(id.clone()),
+        );
+        attrs.insert(
+            "server".to_string(),
+            RestrictedExpression::new_string(server.clone()),
+        );
+        insert_catalog_attributes(&mut attrs, attributes);
 
-        // Evaluate with empty entities
-        // Note: For v0.1, we don't populate the entity store.
-        // This works for policies using entity UIDs (principal == ThoughtGate::App::"name")
-        // but not for attribute-based policies (principal.namespace == "prod").
-        // Full entity support will be added in a future version.
-        let entities = Entities::empty();
-        let response = self
-            .authorizer
-            .is_authorized(&cedar_request, policies, &entities);
+        let entity = Entity::new(
+            resource_uid.clone(),
+            attrs,
+            std::collections::HashSet::new(),
+        )
+        .map_err(|e| PolicyError::CedarError {
+            details: format!("Failed to create resource entity: {}", e),
+        })?;
 
-        response.decision() == Decision::Allow
+        Entities::from_entities([entity], None).map_err(|e| PolicyError::CedarError {
+            details: format!("Failed to create entities: {}", e),
+        })
     }
 
     /// Build Cedar request from PolicyRequest (v0.1).
@@ -830,56 +1350,195 @@ impl CedarEngine {
         })
     }
 
-    /// Parse policies and validate against schema.
+    /// Parse the global and namespace policies into [`PolicySets`].
+    ///
+    /// Implements: REQ-POL-001/F-003 (Policy Loading)
+    ///
+    /// Template links apply to the global policies only. Each namespace's
+    /// policies are validated on their own, so errors name the namespace.
+    fn parse_policy_sets(
+        policy_str: &str,
+        links: &[PolicyLink],
+        namespaces: &BTreeMap<String, String>,
+        schema: &Schema,
+    ) -> Result<PolicySets, PolicyError> {
+        let global = Self::parse_policies(policy_str, links, schema)?;
+        if namespaces.is_empty() {
+            return Ok(PolicySets::global_only(global));
+        }
+
+        let scoped = namespaces
+            .iter()
+            .map(|(namespace, text)| {
+                let policies = PolicySet::from_str(text).map_err(|e| PolicyError::ParseError {
+                    details: format!("namespace `{namespace}`: {e}"),
+                    line: None,
+                })?;
+                let policies = scope_to_namespace(namespace, &policies)?;
+                Ok((
+                    namespace.clone(),
+                    Self::validate_policies(policies, schema)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, PolicyError>>()?;
+        PolicySets::new(global, scoped)
+    }
+
+    /// Parse policies, link templates, and validate against schema.
     ///
+    /// Implements: REQ-POL-001/F-003 (Policy Loading)
     /// Implements: REQ-POL-001/F-004 (Schema Validation)
-    fn parse_policies(policy_str: &str, schema: &Schema) -> Result<PolicySet, PolicyError> {
+    ///
+    /// Linked policies are validated along with the rest, so a slot filled
+    /// with an entity the schema does not allow fails the load.
+    fn parse_policies(
+        policy_str: &str,
+        links: &[PolicyLink],
+        schema: &Schema,
+    ) -> Result<PolicySet, PolicyError> {
         // Parse policies
-        let policies = PolicySet::from_str(policy_str).map_err(|e| PolicyError::ParseError {
-            details: format!("{}", e),
-            line: None, // Cedar doesn't provide line numbers in error
-        })?;
+        let mut policies =
+            PolicySet::from_str(policy_str).map_err(|e| PolicyError::ParseError {
+                details: format!("{}", e),
+                line: None, // Cedar doesn't provide line numbers in error
+            })?;
+
+        for link in links {
+            Self::link_template(&mut policies, link)?;
+        }
+
+        Self::validate_policies(policies, schema)
+    }
 
-        // Validate against schema
+    /// Validate `policies` against the schema.
+    ///
+    /// Implements: REQ-POL-001/F-004 (Schema Validation)
+    fn validate_policies(policies: PolicySet, schema: &Schema) -> Result<PolicySet, PolicyError> {
         let validator = cedar_policy::Validator::new(schema.clone());
         let validation_result =
             validator.validate(&policies, cedar_policy::ValidationMode::default());
 
         if validation_result.validation_passed() {
-            Ok(policies)
-        } else {
-            let errors: Vec<String> = validation_result
-                .validation_errors()
-                .map(|e| format!("{}", e))
-                .collect();
+            return Ok(policies);
+        }
+
+        // An unknown type or action also leaves the policy with no
+        // applicable action; report only the root cause for such policies.
+        let unrecognized: HashSet<&PolicyId> = validation_result
+            .validation_errors()
+            .filter(|e| {
+                matches!(
+                    e,
+                    ValidationError::UnrecognizedEntityType(_)
+                        | ValidationError::UnrecognizedActionId(_)
+                )
+            })
+            .map(ValidationError::policy_id)
+            .collect();
+        let errors: Vec<String> = validation_result
+            .validation_errors()
+            .filter(|e| {
+                !(matches!(e, ValidationError::InvalidActionApplication(_))
+                    && unrecognized.contains(e.policy_id()))
+            })
+            .map(|e| {
+                let id = e.policy_id();
+                let message = e.to_string();
+                let message = message
+                    .strip_prefix(&format!("for policy `{id}`, "))
+                    .unwrap_or(&message);
+                format!("policy `{}`: {message}", policy_name(&policies, id))
+            })
+            .collect();
+
+        Err(PolicyError::SchemaValidation {
+            details: errors.join("; "),
+        })
+    }
+
+    /// Instantiate one template link into `policies`.
+    ///
+    /// Implements: REQ-POL-001/F-003 (Policy Loading)
+    fn link_template(policies: &mut PolicySet, link: &PolicyLink) -> Result<(), PolicyError> {
+        let link_error = |details: String| PolicyError::ParseError {
+            details: format!("policy link `{}`: {details}", link.id),
+            line: None,
+        };
 
-            Err(PolicyError::SchemaValidation {
-                details: errors.join("; "),
+        let template_id = policies
+            .templates()
+            .find(|t| {
+                t.annotation("id") == Some(link.template.as_str())
+                    || t.id().to_string() == link.template
             })
+            .map(|t| t.id().clone())
+            .ok_or_else(|| link_error(format!("no template `{}`", link.template)))?;
+
+        let mut slots = HashMap::new();
+        for (slot, value) in [
+            (SlotId::principal(), &link.principal),
+            (SlotId::resource(), &link.resource),
+        ] {
+            if let Some(value) = value {
+                let uid = EntityUid::from_str(value)
+                    .map_err(|e| link_error(format!("invalid entity `{value}`: {e}")))?;
+                slots.insert(slot, uid);
+            }
         }
+
+        policies
+            .link(template_id, PolicyId::new(&link.id), slots)
+            .map_err(|e| link_error(e.to_string()))
     }
 
     /// Reload policies from source.
     ///
     /// Implements: REQ-POL-001/F-005 (Hot-Reload)
     ///
-    /// On success, atomically swaps in new policies.
+    /// On success, atomically swaps in new policies and empties the
+    /// principal cache, so roles are mapped afresh.
     /// On failure, keeps old policies and returns error.
+    ///
+    /// Either way the outcome is counted in `thoughtgate_policy_reload_total`;
+    /// the policy count and reload time gauges change with the swap.
     pub fn reload(&self) -> Result<(), PolicyError> {
         info!("Reloading policies");
 
-        let (policy_str, source) = loader::load_policies();
-        let new_policies = Self::parse_policies(&policy_str, &self.schema)?;
+        let prepared = loader::load_policy_links().and_then(|links| {
+            let (policy_str, source) = loader::load_policies()?;
+            let namespaces = loader::load_namespace_policies()?;
+            let new_policies =
+                Self::parse_policy_sets(&policy_str, &links, &namespaces, &self.schema)?;
+            Ok((new_policies, FastPath::load()?, source))
+        });
+        let (new_policies, new_fast_path, source) = match prepared {
+            Ok(prepared) => prepared,
+            Err(e) => {
+                if let Some(metrics) = crate::metrics::get_policy_metrics() {
+                    metrics.record_reload_failure();
+                }
+                return Err(e);
+            }
+        };
         let new_annotations = Self::parse_annotations(&new_policies);
+        let policy_count = new_policies.policy_count();
 
         // Atomic swap
+        let now = SystemTime::now();
         self.policies.store(Arc::new(new_policies));
+        self.fast_path.store(Arc::new(new_fast_path));
         self.annotations.store(Arc::new(new_annotations));
         self.source.store(Arc::new(source));
+        super::principal::principal_cache().invalidate();
         self.stats.reload_count.fetch_add(1, Ordering::Relaxed);
-        self.stats
-            .last_reload
-            .store(Arc::new(Some(std::time::SystemTime::now())));
+        self.stats.last_reload.store(Arc::new(Some(now)));
+        if let Some(metrics) = crate::metrics::get_policy_metrics() {
+            metrics.record_reload_success(policy_count, now);
+        }
+        // New policies start with a clean record
+        self.quarantine.clear();
+        self.active.store(self.policies.load_full());
+        self.warmup();
 
         info!("Policies reloaded successfully");
         Ok(())
@@ -905,7 +1564,8 @@ impl CedarEngine {
         let policies = self.policies.load();
 
         PolicyStats {
-            policy_count: policies.policies().count(),
+            policy_count: policies.policy_count(),
+            namespace_policy_counts: policies.namespace_counts().clone(),
             last_reload: *self.stats.last_reload.load().as_ref(),
             reload_count: self.stats.reload_count.load(Ordering::Relaxed),
             evaluation_count: self.stats.evaluation_count.load(Ordering::Relaxed),
@@ -913,7 +1573,108 @@ impl CedarEngine {
     }
 }
 
-#[cfg(test)]
+/// How a policy is named in load errors: its `@id` annotation if it has
+/// one, otherwise Cedar's positional ID (`policy0` for the first policy in
+/// the file, and so on). A linked policy is named by its link ID, since it
+/// shares the template's annotations.
+fn policy_name(policies: &PolicySet, id: &PolicyId) -> String {
+    policies
+        .policy(id)
+        .filter(|policy| policy.template_id().is_none())
+        .and_then(|policy| policy.annotation("id"))
+        .map_or_else(|| id.to_string(), str::to_string)
+}
+
+/// Add catalog attributes to a resource entity's attribute map.
+///
+/// Built-in attributes (`name`, `server`, `arguments`, `method`) are never
+/// overwritten.
+fn insert_catalog_attributes(
+    attrs: &mut HashMap<String, cedar_policy::RestrictedExpression>,
+    attributes: &BTreeMap<String, AttrValue>,
+) {
+    use cedar_policy::RestrictedExpression;
+
+    for (key, value) in attributes {
+        let expr = match value {
+            AttrValue::Bool(b) => RestrictedExpression::new_bool(*b),
+            AttrValue::Long(n) => RestrictedExpression::new_long(*n),
+            AttrValue::String(s) => RestrictedExpression::new_string(s.clone()),
+        };
+        attrs.entry(key.clone()).or_insert(expr);
+    }
+}
+
+/// v0.1 actions, in priority order.
+const V01_ACTIONS: [&str; 2] = ["Forward", "Approve"];
+
+/// The v0.1 action granted by a permitted Cedar action.
+#[allow(deprecated)]
+fn v01_action(action_name: &str) -> PolicyAction {
+    match action_name {
+        "Forward" => PolicyAction::Forward,
+        _ => PolicyAction::Approve {
+            timeout: Duration::from_secs(300), // Default 5 minutes
+        },
+    }
+}
+
+/// The v0.1 action when no action is permitted.
+#[allow(deprecated)]
+fn v01_reject() -> PolicyAction {
+    PolicyAction::Reject {
+        reason: "No policy permits this request".to_string(),
+    }
+}
+
+/// The Cedar action evaluated for a v0.2 resource.
+fn v2_action_name(resource: &CedarResource) -> &'static str {
+    match resource {
+        CedarResource::ToolCall { .. } => "tools/call",
+        CedarResource::McpMethod { .. } => "mcp/method",
+    }
+}
+
+/// Add the policies satisfied for `action` to `explanation`.
+///
+/// Returns the first of them by ID, so the determining policy does not
+/// depend on Cedar's (unordered) diagnostics.
+fn record_reasons(
+    policies: &PolicySet,
+    action: &str,
+    reasons: impl Iterator<Item = String>,
+    explanation: &mut Explanation,
+) -> Option<String> {
+    let mut ids: Vec<String> = reasons.collect();
+    ids.sort();
+
+    for id in &ids {
+        if !explanation.contributing_policies.contains(id) {
+            explanation.contributing_policies.push(id.clone());
+        }
+        let Some(policy) = PolicyId::from_str(id)
+            .ok()
+            .and_then(|policy_id| policies.policy(&policy_id))
+        else {
+            continue;
+        };
+        explanation.matched_conditions.push(MatchedCondition {
+            policy_id: id.clone(),
+            action: action.to_string(),
+            effect: match policy.effect() {
+                Effect::Permit => "permit",
+                Effect::Forbid => "forbid",
+            }
+            .to_string(),
+            condition: policy.to_string().trim().to_string(),
+        });
+    }
+    explanation.contributing_policies.sort();
+
+    ids.into_iter().next()
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
     use crate::policy::{Principal, TimeContext};
@@ -932,6 +1693,7 @@ mod tests {
         Resource::ToolCall {
             name: name.to_string(),
             server: "test-server".to_string(),
+            attributes: Default::default(),
         }
     }
 
@@ -963,6 +1725,7 @@ mod tests {
                 name: "test_tool".to_string(),
                 server: "test-server".to_string(),
                 arguments: serde_json::json!({}),
+                attributes: Default::default(),
             },
             context: CedarContext {
                 policy_id: "test_policy".to_string(),
@@ -1003,6 +1766,7 @@ mod tests {
                 name: "test_tool".to_string(),
                 server: "test-server".to_string(),
                 arguments: serde_json::json!({}),
+                attributes: Default::default(),
             },
             context: CedarContext {
                 policy_id: "test_policy".to_string(),
@@ -1019,6 +1783,259 @@ mod tests {
         }
     }
 
+    /// Policy that fails at evaluation time (Long overflow) for any request
+    /// with a positive timestamp.
+    const OVERFLOWING_POLICY: &str = r#"
+        permit(principal, action == ThoughtGate::Action::"tools/call", resource)
+        when { context.time.timestamp + 9223372036854775807 > 0 };
+    "#;
+
+    fn overflow_request() -> CedarRequest {
+        CedarRequest {
+            principal: test_principal(),
+            resource: CedarResource::ToolCall {
+                name: "test_tool".to_string(),
+                server: "test-server".to_string(),
+                arguments: serde_json::json!({}),
+                attributes: Default::default(),
+            },
+            context: CedarContext {
+                policy_id: "test_policy".to_string(),
+                source_id: "test-server".to_string(),
+                time: TimeContext::from_timestamp(1),
+            },
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_v2_engine_error_fail_closed() {
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", OVERFLOWING_POLICY);
+        }
+
+        let engine = CedarEngine::new().expect("Failed to create engine");
+        let decision = engine.evaluate_v2(&overflow_request());
+
+        let CedarDecision::Forbid { reason, .. } = decision else {
+            panic!("expected Forbid, got {decision:?}");
+        };
+        assert!(reason.starts_with("Policy evaluation errors"), "{reason}");
+        let stats = engine.stats_v2();
+        assert_eq!(stats.forbid_count, 1);
+        assert_eq!(stats.fail_open_count, 0);
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_v2_engine_error_fail_open() {
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", OVERFLOWING_POLICY);
+        }
+
+        let engine = CedarEngine::new()
+            .expect("Failed to create engine")
+            .with_on_policy_error(PolicyErrorMode::FailOpen);
+        let decision = engine.evaluate_v2(&overflow_request());
+
+        assert!(decision.is_permit(), "expected Permit, got {decision:?}");
+        let stats = engine.stats_v2();
+        assert_eq!(stats.permit_count, 1);
+        assert_eq!(stats.fail_open_count, 1);
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_v2_fail_open_honors_explicit_forbid() {
+        let policy = format!(
+            r#"{OVERFLOWING_POLICY}
+            forbid(principal, action == ThoughtGate::Action::"tools/call", resource);
+            "#
+        );
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", policy);
+        }
+
+        let engine = CedarEngine::new()
+            .expect("Failed to create engine")
+            .with_on_policy_error(PolicyErrorMode::FailOpen);
+        let decision = engine.evaluate_v2(&overflow_request());
+
+        assert!(decision.is_forbid(), "expected Forbid, got {decision:?}");
+        assert_eq!(engine.stats_v2().fail_open_count, 0);
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
+
+    fn quarantine_after(threshold: u32) -> QuarantineConfig {
+        QuarantineConfig {
+            threshold,
+            window: Duration::from_secs(60),
+            duration: Duration::from_secs(300),
+        }
+    }
+
+    /// An always-erroring permit is quarantined while the other policies
+    /// keep deciding; requests only it could have permitted are denied.
+    #[test]
+    #[serial]
+    fn test_erroring_permit_quarantined() {
+        let policy = format!(
+            r#"{OVERFLOWING_POLICY}
+            permit(
+                principal,
+                action == ThoughtGate::Action::"tools/call",
+                resource == ThoughtGate::ToolCall::"safe_tool"
+            );
+            "#
+        );
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", policy);
+        }
+
+        let engine = CedarEngine::new()
+            .expect("Failed to create engine")
+            .with_quarantine(quarantine_after(3));
+        let bad = overflow_request();
+        let mut safe = overflow_request();
+        safe.resource = CedarResource::ToolCall {
+            name: "safe_tool".to_string(),
+            server: "test-server".to_string(),
+            arguments: serde_json::json!({}),
+            attributes: Default::default(),
+        };
+
+        // Both requests reach the erroring policy: three failures
+        for request in [&bad, &safe, &bad] {
+            assert!(engine.quarantined_policies().is_empty());
+            engine.evaluate_v2(request);
+        }
+        assert_eq!(engine.quarantined_policies(), vec!["policy0".to_string()]);
+
+        let CedarDecision::Forbid { reason, .. } = engine.evaluate_v2(&bad) else {
+            panic!("expected Forbid");
+        };
+        assert!(reason.contains("default-deny"), "{reason}");
+        assert!(engine.evaluate_v2(&safe).is_permit());
+
+        // A reload starts over
+        engine.reload().expect("reload failed");
+        assert!(engine.quarantined_policies().is_empty());
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
+
+    /// A quarantined forbid keeps denying everything in its scope, where
+    /// Cedar alone would skip it and let the permit through.
+    #[test]
+    #[serial]
+    fn test_erroring_forbid_quarantined_fails_safe() {
+        let policy = r#"
+            forbid(principal, action == ThoughtGate::Action::"tools/call", resource)
+            when { context.time.timestamp + 9223372036854775807 > 0 };
+            permit(principal, action, resource);
+        "#;
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", policy);
+        }
+
+        let engine = CedarEngine::new()
+            .expect("Failed to create engine")
+            .with_quarantine(quarantine_after(2));
+        let request = overflow_request();
+
+        engine.evaluate_v2(&request);
+        engine.evaluate_v2(&request);
+        assert_eq!(engine.quarantined_policies(), vec!["policy0".to_string()]);
+
+        let CedarDecision::Forbid { policy_ids, .. } = engine.evaluate_v2(&request) else {
+            panic!("expected Forbid");
+        };
+        assert_eq!(policy_ids, vec!["policy0".to_string()]);
+
+        // Outside the forbid's scope, the permit still applies
+        let mut method = overflow_request();
+        method.resource = CedarResource::McpMethod {
+            method: "resources/read".to_string(),
+            server: "test-server".to_string(),
+            attributes: Default::default(),
+        };
+        assert!(engine.evaluate_v2(&method).is_permit());
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
+
+    /// Listed pairs are decided without Cedar; everything else still goes
+    /// through it.
+    #[test]
+    #[serial]
+    fn test_fast_path_short_circuits_cedar() {
+        let policy = r#"
+            permit(principal, action, resource);
+            forbid(
+                principal,
+                action == ThoughtGate::Action::"tools/call",
+                resource == ThoughtGate::ToolCall::"safe_tool"
+            );
+        "#;
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", policy);
+        }
+
+        let fast_path = FastPath::parse(
+            "allow:\n  - server: test-server\n    name: safe_tool\ndeny:\n  - name: delete_*\n",
+        )
+        .unwrap();
+        let engine = CedarEngine::new()
+            .expect("Failed to create engine")
+            .with_fast_path(fast_path);
+        let request = |name: &str| {
+            let mut request = overflow_request();
+            request.resource = CedarResource::ToolCall {
+                name: name.to_string(),
+                server: "test-server".to_string(),
+                arguments: serde_json::json!({}),
+                attributes: Default::default(),
+            };
+            request
+        };
+
+        // Allowed despite the forbid: Cedar never saw it
+        assert!(engine.evaluate_v2(&request("safe_tool")).is_permit());
+        // Denied despite the permit
+        let CedarDecision::Forbid {
+            reason, policy_ids, ..
+        } = engine.evaluate_v2(&request("delete_repo"))
+        else {
+            panic!("expected Forbid");
+        };
+        assert!(reason.contains("denylist"), "{reason}");
+        assert!(policy_ids.is_empty());
+        assert_eq!(engine.stats_v2().evaluation_count, 0);
+
+        // Unlisted tools fall through to Cedar
+        assert!(engine.evaluate_v2(&request("other_tool")).is_permit());
+        assert_eq!(engine.stats_v2().evaluation_count, 1);
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
+
     #[test]
     #[serial]
     fn test_evaluate_v2_stats() {
@@ -1038,6 +2055,7 @@ mod tests {
                 name: "test_tool".to_string(),
                 server: "test-server".to_string(),
                 arguments: serde_json::json!({}),
+                attributes: Default::default(),
             },
             context: CedarContext {
                 policy_id: "test_policy".to_string(),
@@ -1238,6 +2256,129 @@ mod tests {
         }
     }
 
+    /// Explained permit: the permit of the granted action is determining.
+    #[test]
+    #[serial]
+    #[allow(deprecated)]
+    fn test_evaluate_explained_permit() {
+        let policy_str = r#"
+            permit(
+                principal,
+                action == ThoughtGate::Action::"Approve",
+                resource
+            );
+            permit(
+                principal == ThoughtGate::App::"test-app",
+                action == ThoughtGate::Action::"Forward",
+                resource == ThoughtGate::ToolCall::"test_tool"
+            );
+        "#;
+
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", policy_str);
+        }
+
+        let engine = CedarEngine::new().expect("Failed to create engine");
+        let request = PolicyRequest {
+            principal: test_principal(),
+            resource: test_tool_call("test_tool"),
+            context: None,
+        };
+
+        let (action, explanation) = engine.evaluate_explained(&request);
+        assert_eq!(action, engine.evaluate(&request));
+        assert!(matches!(action, PolicyAction::Forward));
+        assert_eq!(explanation.determining_policy.as_deref(), Some("policy1"));
+        assert_eq!(explanation.contributing_policies, vec!["policy1"]);
+        let matched = &explanation.matched_conditions[0];
+        assert_eq!(matched.action, "Forward");
+        assert_eq!(matched.effect, "permit");
+        assert!(
+            matched
+                .condition
+                .contains(r#"ThoughtGate::ToolCall::"test_tool""#)
+        );
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
+
+    /// Explained deny: the forbid is determining; default-deny has none.
+    #[test]
+    #[serial]
+    #[allow(deprecated)]
+    fn test_evaluate_explained_deny() {
+        let policy_str = r#"
+            permit(principal, action, resource);
+            forbid(
+                principal,
+                action,
+                resource == ThoughtGate::ToolCall::"delete_user"
+            );
+        "#;
+
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", policy_str);
+        }
+
+        let engine = CedarEngine::new().expect("Failed to create engine");
+        let request = PolicyRequest {
+            principal: test_principal(),
+            resource: test_tool_call("delete_user"),
+            context: None,
+        };
+
+        let (action, explanation) = engine.evaluate_explained(&request);
+        assert!(matches!(action, PolicyAction::Reject { .. }));
+        assert_eq!(explanation.determining_policy.as_deref(), Some("policy1"));
+        assert_eq!(explanation.contributing_policies, vec!["policy1"]);
+        // Checked for both Forward and Approve
+        let actions: Vec<_> = explanation
+            .matched_conditions
+            .iter()
+            .map(|m| (m.action.as_str(), m.effect.as_str()))
+            .collect();
+        assert_eq!(actions, vec![("Forward", "forbid"), ("Approve", "forbid")]);
+
+        // v0.2 decisions are explained the same way
+        let cedar_request = CedarRequest {
+            principal: test_principal(),
+            resource: CedarResource::ToolCall {
+                name: "delete_user".to_string(),
+                server: "test-server".to_string(),
+                arguments: serde_json::json!({}),
+                attributes: Default::default(),
+            },
+            context: CedarContext {
+                policy_id: "default".to_string(),
+                source_id: "test-server".to_string(),
+                time: crate::policy::TimeContext::now(),
+            },
+        };
+        let decision = engine.evaluate_v2(&cedar_request);
+        assert!(decision.is_forbid());
+        let explanation = engine.explain_v2(&cedar_request, &decision);
+        assert_eq!(explanation.determining_policy.as_deref(), Some("policy1"));
+        assert_eq!(explanation.matched_conditions[0].action, "tools/call");
+
+        // Nothing matches: denied by default, no determining policy
+        unsafe {
+            std::env::set_var(
+                "THOUGHTGATE_POLICIES",
+                r#"forbid(principal, action, resource == ThoughtGate::ToolCall::"other");"#,
+            );
+        }
+        engine.reload().expect("reload");
+        let (action, explanation) = engine.evaluate_explained(&request);
+        assert!(matches!(action, PolicyAction::Reject { .. }));
+        assert_eq!(explanation, Explanation::default());
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
+
     /// EC-POL-007: Invalid policy syntax → Keep old policies
     #[test]
     #[serial]
@@ -1350,4 +2491,520 @@ mod tests {
             std::env::remove_var("THOUGHTGATE_POLICIES");
         }
     }
+
+    /// Reloads are counted by result, and a success moves the reload time.
+    ///
+    /// Verifies: REQ-POL-001/F-005 (Hot-Reload)
+    #[test]
+    #[serial]
+    fn test_reload_metrics() {
+        let registry = crate::metrics::testing::registry();
+        // Sum of a metric's samples, optionally those with `result`
+        let sample = |name: &str, result: Option<&str>| -> f64 {
+            registry
+                .gather()
+                .iter()
+                .filter(|family| family.name() == name)
+                .flat_map(|family| family.get_metric())
+                .filter(|metric| {
+                    result.is_none_or(|result| {
+                        metric
+                            .get_label()
+                            .iter()
+                            .any(|l| l.name() == "result" && l.value() == result)
+                    })
+                })
+                .map(|metric| metric.get_counter().value() + metric.get_gauge().value())
+                .sum()
+        };
+
+        unsafe {
+            std::env::set_var(
+                "THOUGHTGATE_POLICIES",
+                "permit(principal, action, resource);",
+            );
+        }
+        let engine = CedarEngine::new().expect("Failed to create engine");
+        let successes = sample("thoughtgate_policy_reload_total", Some("success"));
+        let failures = sample("thoughtgate_policy_reload_total", Some("failure"));
+        let loaded_at = sample("thoughtgate_policy_last_reload_timestamp", None);
+        assert!(loaded_at > 0.0);
+
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(engine.reload().is_ok());
+        assert_eq!(
+            sample("thoughtgate_policy_reload_total", Some("success")),
+            successes + 1.0
+        );
+        assert_eq!(
+            sample("thoughtgate_policy_reload_total", Some("failure")),
+            failures
+        );
+        assert!(sample("thoughtgate_policy_last_reload_timestamp", None) > loaded_at);
+        assert!(sample("thoughtgate_policy_count", None) >= 1.0);
+
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", "invalid syntax {{{");
+        }
+        assert!(engine.reload().is_err());
+        assert_eq!(
+            sample("thoughtgate_policy_reload_total", Some("success")),
+            successes + 1.0
+        );
+        assert_eq!(
+            sample("thoughtgate_policy_reload_total", Some("failure")),
+            failures + 1.0
+        );
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
+
+    fn catalog_request(attributes: BTreeMap<String, AttrValue>) -> CedarRequest {
+        CedarRequest {
+            principal: test_principal(),
+            resource: CedarResource::ToolCall {
+                name: "transfer_funds".to_string(),
+                server: "test-server".to_string(),
+                arguments: serde_json::json!({}),
+                attributes,
+            },
+            context: CedarContext {
+                policy_id: "test_policy".to_string(),
+                source_id: "test-server".to_string(),
+                time: TimeContext::from_timestamp(0),
+            },
+        }
+    }
+
+    /// Catalog attributes gate decisions via `resource has` / `resource.<key>`.
+    #[test]
+    #[serial]
+    fn test_evaluate_v2_catalog_attributes() {
+        let policy = r#"
+            permit(
+                principal,
+                action == ThoughtGate::Action::"tools/call",
+                resource
+            ) when { resource has risk && resource.risk == "low" };
+
+            forbid(
+                principal,
+                action == ThoughtGate::Action::"tools/call",
+                resource
+            ) when { resource has pii && resource.pii };
+        "#;
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", policy);
+        }
+
+        let types = BTreeMap::from([
+            ("risk".to_string(), AttrType::String),
+            ("pii".to_string(), AttrType::Bool),
+        ]);
+        let engine = CedarEngine::new_with_attributes(&types).expect("Failed to create engine");
+
+        let low = BTreeMap::from([("risk".to_string(), AttrValue::String("low".to_string()))]);
+        assert!(
+            engine
+                .evaluate_v2(&catalog_request(low.clone()))
+                .is_permit()
+        );
+
+        let high = BTreeMap::from([("risk".to_string(), AttrValue::String("high".to_string()))]);
+        assert!(engine.evaluate_v2(&catalog_request(high)).is_forbid());
+
+        // No catalog entry: the `has` guard fails, so default-deny applies
+        assert!(
+            engine
+                .evaluate_v2(&catalog_request(BTreeMap::new()))
+                .is_forbid()
+        );
+
+        let mut low_pii = low;
+        low_pii.insert("pii".to_string(), AttrValue::Bool(true));
+        assert!(engine.evaluate_v2(&catalog_request(low_pii)).is_forbid());
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
+
+    /// Uncatalogued tools are enriched with the fail-safe default risk.
+    #[test]
+    #[serial]
+    fn test_enrich_from_catalog() {
+        let policy = r#"
+            permit(principal, action == ThoughtGate::Action::"tools/call", resource);
+            forbid(
+                principal,
+                action == ThoughtGate::Action::"tools/call",
+                resource
+            ) when { resource has risk && resource.risk == "high" };
+        "#;
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", policy);
+        }
+        let path = std::env::temp_dir().join(format!(
+            "thoughtgate_test_engine_catalog_{}.yaml",
+            std::process::id()
+        ));
+        std::fs::write(
+            &path,
+            "tools:\n  - match: transfer_funds\n    attributes:\n      risk: low\n",
+        )
+        .unwrap();
+
+        let catalog = Arc::new(LiveCatalog::load(path.clone()).unwrap());
+        let engine = CedarEngine::new_with_attributes(catalog.declared_types())
+            .expect("Failed to create engine")
+            .with_catalog(catalog);
+
+        let mut known = catalog_request(BTreeMap::new());
+        engine.enrich(&mut known.resource);
+        assert!(engine.evaluate_v2(&known).is_permit());
+
+        let mut unknown = catalog_request(BTreeMap::new());
+        unknown.resource = CedarResource::ToolCall {
+            name: "delete_user".to_string(),
+            server: "test-server".to_string(),
+            arguments: serde_json::json!({}),
+            attributes: BTreeMap::new(),
+        };
+        engine.enrich(&mut unknown.resource);
+        assert!(engine.evaluate_v2(&unknown).is_forbid());
+
+        let _ = std::fs::remove_file(&path);
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
+
+    /// A policy naming an undeclared action is rejected on reload with an
+    /// error naming the policy and the action; the previous set stays live.
+    ///
+    /// Verifies: REQ-POL-001/F-004 (Schema Validation)
+    #[test]
+    #[serial]
+    #[allow(deprecated)]
+    fn test_reload_rejects_undeclared_action() {
+        unsafe {
+            std::env::set_var(
+                "THOUGHTGATE_POLICIES",
+                "permit(principal, action, resource);",
+            );
+        }
+        let engine = CedarEngine::new().expect("Failed to create engine");
+
+        unsafe {
+            std::env::set_var(
+                "THOUGHTGATE_POLICIES",
+                r#"
+                permit(principal, action == ThoughtGate::Action::"Forward", resource);
+
+                @id("delete-guard")
+                permit(principal, action == ThoughtGate::Action::"tools/delete", resource);
+
+                permit(principal == ThoughtGate::Robot::"r2", action, resource);
+                "#,
+            );
+        }
+        let result = engine.reload();
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+
+        let Err(PolicyError::SchemaValidation { details }) = result else {
+            panic!("expected SchemaValidation, got {result:?}");
+        };
+        assert!(
+            details.contains(
+                r#"policy `delete-guard`: unrecognized action `ThoughtGate::Action::"tools/delete"`"#
+            ),
+            "{details}"
+        );
+        assert!(
+            details.contains("policy `policy2`: unrecognized entity type `ThoughtGate::Robot`"),
+            "{details}"
+        );
+        assert!(!details.contains("applicable action"), "{details}");
+        assert!(!details.contains("policy0"), "{details}");
+
+        let request = PolicyRequest {
+            principal: test_principal(),
+            resource: test_tool_call("test_tool"),
+            context: None,
+        };
+        assert!(matches!(engine.evaluate(&request), PolicyAction::Forward));
+    }
+
+    /// Warming up evaluates without touching the statistics.
+    ///
+    /// Verifies: REQ-OBS-001 M-POL-001 (Policy Evaluation Performance)
+    #[test]
+    #[serial]
+    fn test_warmup_leaves_stats_untouched() {
+        let engine = CedarEngine::new().expect("Failed to create engine");
+        engine.warmup();
+
+        let stats = engine.stats_v2();
+        assert_eq!(stats.evaluation_count, 0);
+        assert_eq!(stats.permit_count + stats.forbid_count, 0);
+    }
+
+    /// A template linked for two principals permits each only its own tool.
+    ///
+    /// Verifies: REQ-POL-001/F-003 (Policy Loading)
+    #[test]
+    #[serial]
+    fn test_template_linked_per_principal() {
+        unsafe {
+            std::env::set_var(
+                "THOUGHTGATE_POLICIES",
+                r#"
+                @id("own-tool")
+                permit(
+                    principal == ?principal,
+                    action == ThoughtGate::Action::"tools/call",
+                    resource == ?resource
+                );
+                "#,
+            );
+            std::env::set_var(
+                "THOUGHTGATE_POLICY_LINKS",
+                r#"
+- template: own-tool
+  id: own-tool-agent-a
+  principal: 'ThoughtGate::App::"agent-a"'
+  resource: 'ThoughtGate::ToolCall::"deploy_a"'
+- template: own-tool
+  id: own-tool-agent-b
+  principal: 'ThoughtGate::App::"agent-b"'
+  resource: 'ThoughtGate::ToolCall::"deploy_b"'
+"#,
+            );
+        }
+        let engine = CedarEngine::new();
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+            std::env::remove_var("THOUGHTGATE_POLICY_LINKS");
+        }
+        let engine = engine.expect("Failed to create engine");
+
+        let call = |app: &str, tool: &str| {
+            engine.evaluate_v2(&CedarRequest {
+                principal: Principal {
+                    app_name: app.to_string(),
+                    ..test_principal()
+                },
+                resource: CedarResource::ToolCall {
+                    name: tool.to_string(),
+                    server: "test-server".to_string(),
+                    arguments: serde_json::json!({}),
+                    attributes: Default::default(),
+                },
+                context: CedarContext {
+                    policy_id: "test_policy".to_string(),
+                    source_id: "test-server".to_string(),
+                    time: TimeContext::from_timestamp(0),
+                },
+            })
+        };
+
+        assert!(call("agent-a", "deploy_a").is_permit());
+        assert!(!call("agent-a", "deploy_b").is_permit());
+        assert!(call("agent-b", "deploy_b").is_permit());
+        assert!(!call("agent-b", "deploy_a").is_permit());
+        assert!(!call("test-app", "deploy_a").is_permit());
+    }
+
+    /// Namespace policies only apply to their own namespace, and a global
+    /// forbid holds in every namespace.
+    ///
+    /// Verifies: REQ-POL-001/F-003 (Policy Loading)
+    #[test]
+    #[serial]
+    fn test_namespace_policies_isolated() {
+        let dir =
+            std::env::temp_dir().join(format!("thoughtgate_ns_engine_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let permit = |tool: &str| {
+            format!(
+                r#"permit(principal, action == ThoughtGate::Action::"tools/call", resource) when {{ resource.name == "{tool}" }};"#
+            )
+        };
+        std::fs::write(
+            dir.join("tenant-a.cedar"),
+            format!("{}\n{}", permit("deploy_a"), permit("drop_database")),
+        )
+        .unwrap();
+        std::fs::write(dir.join("tenant-b.cedar"), permit("deploy_b")).unwrap();
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICY_NAMESPACE_DIR", &dir);
+            std::env::set_var(
+                "THOUGHTGATE_POLICIES",
+                r#"forbid(principal, action == ThoughtGate::Action::"tools/call", resource) when { resource.name == "drop_database" };"#,
+            );
+        }
+        let engine = CedarEngine::new();
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICY_NAMESPACE_DIR");
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+        let _ = std::fs::remove_dir_all(&dir);
+        let engine = engine.expect("Failed to create engine");
+
+        let call = |namespace: &str, tool: &str| {
+            engine.evaluate_v2(&CedarRequest {
+                principal: Principal {
+                    namespace: namespace.to_string(),
+                    ..test_principal()
+                },
+                resource: CedarResource::ToolCall {
+                    name: tool.to_string(),
+                    server: "test-server".to_string(),
+                    arguments: serde_json::json!({}),
+                    attributes: Default::default(),
+                },
+                context: CedarContext {
+                    policy_id: "test_policy".to_string(),
+                    source_id: "test-server".to_string(),
+                    time: TimeContext::from_timestamp(0),
+                },
+            })
+        };
+
+        assert!(call("tenant-a", "deploy_a").is_permit());
+        assert!(!call("tenant-a", "deploy_b").is_permit());
+        assert!(call("tenant-b", "deploy_b").is_permit());
+        assert!(!call("tenant-b", "deploy_a").is_permit());
+        assert!(!call("default", "deploy_a").is_permit());
+
+        // The tenant's permit does not override the global forbid
+        match call("tenant-a", "drop_database") {
+            CedarDecision::Forbid { policy_ids, .. } => assert_eq!(policy_ids, ["policy0"]),
+            other => panic!("expected forbid, got {other:?}"),
+        }
+
+        let stats = engine.stats();
+        assert_eq!(stats.policy_count, 4);
+        assert_eq!(
+            stats.namespace_policy_counts,
+            BTreeMap::from([("tenant-a".to_string(), 2), ("tenant-b".to_string(), 1)])
+        );
+    }
+
+    /// Bad template links fail the load with a message naming the link.
+    ///
+    /// Verifies: REQ-POL-001/F-003 (Policy Loading), REQ-POL-001/F-004
+    #[test]
+    fn test_template_link_errors() {
+        let schema = CedarEngine::build_schema(&loader::load_schema(), &BTreeMap::new()).unwrap();
+        let template = r#"
+            @id("own-tool")
+            permit(
+                principal == ?principal,
+                action == ThoughtGate::Action::"tools/call",
+                resource
+            );
+        "#;
+        let link = |template: &str, id: &str, principal: &str| PolicyLink {
+            template: template.to_string(),
+            id: id.to_string(),
+            principal: Some(principal.to_string()),
+            resource: None,
+        };
+        let agent = r#"ThoughtGate::App::"agent-a""#;
+
+        let parse_error =
+            |links: &[PolicyLink]| match CedarEngine::parse_policies(template, links, &schema) {
+                Err(PolicyError::ParseError { details, .. }) => details,
+                other => panic!("expected ParseError, got {other:?}"),
+            };
+        let details = parse_error(&[link("missing", "l1", agent)]);
+        assert!(
+            details.contains("policy link `l1`: no template `missing`"),
+            "{details}"
+        );
+        let details = parse_error(&[link("own-tool", "l1", "not an entity")]);
+        assert!(
+            details.contains("policy link `l1`: invalid entity"),
+            "{details}"
+        );
+        let details = parse_error(&[link("own-tool", "l1", agent), link("own-tool", "l1", agent)]);
+        assert!(details.contains("policy link `l1`"), "{details}");
+
+        match CedarEngine::parse_policies(
+            template,
+            &[link("own-tool", "robot", r#"ThoughtGate::Robot::"r2""#)],
+            &schema,
+        ) {
+            Err(PolicyError::SchemaValidation { details }) => {
+                assert!(details.contains("policy `robot`"), "{details}");
+                assert!(details.contains("ThoughtGate::Robot"), "{details}");
+            }
+            other => panic!("expected SchemaValidation, got {other:?}"),
+        }
+
+        assert!(
+            CedarEngine::parse_policies(template, &[link("own-tool", "l1", agent)], &schema)
+                .is_ok()
+        );
+    }
+
+    /// Declared catalog attributes are type-checked against policies.
+    #[test]
+    #[serial]
+    fn test_catalog_attributes_type_checked() {
+        let policy = r#"
+            permit(
+                principal,
+                action == ThoughtGate::Action::"tools/call",
+                resource
+            ) when { resource has risk && resource.risk > 1 };
+        "#;
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", policy);
+        }
+
+        let types = BTreeMap::from([("risk".to_string(), AttrType::String)]);
+        let result = CedarEngine::new_with_attributes(&types);
+        assert!(
+            matches!(result, Err(PolicyError::SchemaValidation { .. })),
+            "String attribute compared as Long must be rejected"
+        );
+
+        let types = BTreeMap::from([("risk".to_string(), AttrType::Long)]);
+        assert!(CedarEngine::new_with_attributes(&types).is_ok());
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
+
+    /// Catalog attributes never shadow built-in resource attributes.
+    #[test]
+    #[serial]
+    fn test_evaluate_v2_catalog_attributes_keep_builtins() {
+        let policy = r#"
+            permit(
+                principal,
+                action == ThoughtGate::Action::"tools/call",
+                resource
+            ) when { resource.name == "transfer_funds" };
+        "#;
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", policy);
+        }
+
+        let engine = CedarEngine::new().expect("Failed to create engine");
+        let spoofed =
+            BTreeMap::from([("name".to_string(), AttrValue::String("other".to_string()))]);
+        assert!(engine.evaluate_v2(&catalog_request(spoofed)).is_permit());
+
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+    }
 }
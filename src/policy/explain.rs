@@ -0,0 +1,161 @@
+//! Operator-only policy decision explanations.
+//!
+//! Implements: REQ-POL-001/§6.3 (Decision Explanation)
+//!
+//! Explanations name the policies that decided a request and quote their
+//! conditions, which is policy internals. They are therefore off by default
+//! and, when enabled with `THOUGHTGATE_EXPLAIN_DECISIONS=true`, only surface
+//! in two operator-facing places:
+//!
+//! - Gate 3 audit records carry an `explanation` field
+//! - The admin server answers `POST /debug/explain`
+//!
+//! Responses to proxy clients never include them. The separately
+//! token-guarded `POST /policy/simulate` proxy endpoint evaluates the same
+//! [`ExplainQuery`] with [`explain`].
+
+use super::engine::CedarEngine;
+use super::{ApprovalGrant, Explanation, PolicyContext, PolicyRequest, Principal, Resource};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Environment variable that enables explanations.
+pub const EXPLAIN_DECISIONS_ENV: &str = "THOUGHTGATE_EXPLAIN_DECISIONS";
+
+/// Whether `THOUGHTGATE_EXPLAIN_DECISIONS` is set to `true`.
+pub fn enabled_from_env() -> bool {
+    std::env::var(EXPLAIN_DECISIONS_ENV).as_deref() == Ok("true")
+}
+
+/// Environment variable holding the bearer token for `POST /policy/simulate`.
+///
+/// The endpoint is only served when this is set.
+pub const SIMULATE_TOKEN_ENV: &str = "THOUGHTGATE_POLICY_SIMULATE_TOKEN";
+
+/// The policy simulation token, if set and non-empty.
+pub fn simulate_token_from_env() -> Option<String> {
+    std::env::var(SIMULATE_TOKEN_ENV)
+        .ok()
+        .filter(|token| !token.is_empty())
+}
+
+/// Engine used to explain decisions, installed when explanations are on.
+static EXPLAIN_ENGINE: once_cell::sync::OnceCell<Arc<CedarEngine>> =
+    once_cell::sync::OnceCell::new();
+
+/// Enable explanations against `engine` (first call wins).
+pub fn init_explain(engine: Arc<CedarEngine>) {
+    let _ = EXPLAIN_ENGINE.set(engine);
+}
+
+/// Get the engine to explain decisions with, if explanations are on.
+pub fn get_explain_engine() -> Option<Arc<CedarEngine>> {
+    EXPLAIN_ENGINE.get().cloned()
+}
+
+/// Whether explanations are enabled.
+pub fn is_enabled() -> bool {
+    EXPLAIN_ENGINE.get().is_some()
+}
+
+/// A hypothetical request to explain, as posted to `POST /debug/explain`
+/// or `POST /policy/simulate`.
+///
+/// Exactly one of `tool` and `method` names the resource.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExplainQuery {
+    /// Application name of the principal.
+    pub principal: String,
+    /// Kubernetes namespace of the principal.
+    #[serde(default)]
+    pub namespace: String,
+    /// Kubernetes ServiceAccount of the principal.
+    #[serde(default)]
+    pub service_account: String,
+    /// Roles of the principal.
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// Tool name, for a `tools/call`.
+    #[serde(default)]
+    pub tool: Option<String>,
+    /// MCP method, for anything else.
+    #[serde(default)]
+    pub method: Option<String>,
+    /// Upstream server identifier.
+    #[serde(default = "default_server")]
+    pub server: String,
+    /// Approval grant, to evaluate as a post-approval re-check.
+    #[serde(default)]
+    pub approval_grant: Option<ApprovalGrant>,
+}
+
+/// Decision and explanation for an [`ExplainQuery`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExplainOutcome {
+    /// `forward`, `approve` or `reject`.
+    pub action: &'static str,
+    /// Why the policies reached that action.
+    pub explanation: Explanation,
+}
+
+/// Evaluate `query` against the live policies without forwarding anything.
+///
+/// # Errors
+///
+/// Returns a message if the query does not name exactly one resource.
+#[allow(deprecated)] // Explains the v0.1 PolicyAction
+pub fn explain(engine: &CedarEngine, query: &ExplainQuery) -> Result<ExplainOutcome, String> {
+    use super::PolicyAction;
+
+    let request = query.to_policy_request()?;
+    let (action, explanation) = engine.evaluate_explained(&request);
+    let action = match action {
+        PolicyAction::Forward => "forward",
+        PolicyAction::Approve { .. } => "approve",
+        PolicyAction::Reject { .. } => "reject",
+    };
+    Ok(ExplainOutcome {
+        action,
+        explanation,
+    })
+}
+
+fn default_server() -> String {
+    crate::transport::DEFAULT_SOURCE_ID.to_string()
+}
+
+impl ExplainQuery {
+    /// Build the policy request to evaluate.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message if not exactly one of `tool` and `method` is set.
+    pub fn to_policy_request(&self) -> Result<PolicyRequest, String> {
+        let resource = match (&self.tool, &self.method) {
+            (Some(name), None) => Resource::ToolCall {
+                name: name.clone(),
+                server: self.server.clone(),
+                attributes: Default::default(),
+            },
+            (None, Some(method)) => Resource::McpMethod {
+                method: method.clone(),
+                server: self.server.clone(),
+                attributes: Default::default(),
+            },
+            _ => return Err("exactly one of `tool` and `method` is required".to_string()),
+        };
+        Ok(PolicyRequest {
+            principal: Principal {
+                app_name: self.principal.clone(),
+                namespace: self.namespace.clone(),
+                service_account: self.service_account.clone(),
+                roles: self.roles.clone(),
+            },
+            resource,
+            context: self.approval_grant.clone().map(|grant| PolicyContext {
+                approval_grant: Some(grant),
+            }),
+        })
+    }
+}
@@ -0,0 +1,257 @@
+//! Static allow/deny lists checked before Cedar.
+//!
+//! Implements: REQ-POL-001/F-001 (Policy Evaluation)
+//!
+//! Some `(server, tool or method)` pairs are always allowed or always
+//! denied, whatever their arguments. Listing them here decides them without
+//! building a Cedar request:
+//!
+//! 1. A `deny` entry matches → Forbid, without evaluating Cedar
+//! 2. An `allow` entry matches → Permit, without evaluating Cedar
+//! 3. Nothing matches → Cedar evaluates the request as usual
+//!
+//! Deny entries are checked first, so a pair on both lists is denied.
+//!
+//! # Security
+//!
+//! An `allow` entry bypasses Cedar entirely: `forbid` policies, argument
+//! checks and catalog attributes are never consulted for requests it
+//! matches. Keep allow entries narrow (no bare `*` tool names) and prefer
+//! Cedar for anything that depends on who is calling or with what.
+//!
+//! # Configuration
+//!
+//! 1. YAML file at `$THOUGHTGATE_POLICY_FAST_PATH_FILE` (default:
+//!    `/etc/thoughtgate/policy-fast-path.yaml`)
+//! 2. Environment variable `$THOUGHTGATE_POLICY_FAST_PATH` (YAML)
+//! 3. No lists (every request goes to Cedar)
+//!
+//! ```yaml
+//! deny:
+//!   - name: "delete_*"
+//! allow:
+//!   - server: github
+//!     name: "get_*"
+//! ```
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use super::PolicyError;
+
+/// One list entry as written in YAML.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EntryConfig {
+    /// Server (source) ID glob (default: any server)
+    #[serde(default = "any")]
+    server: String,
+    /// Tool name or MCP method glob
+    name: String,
+}
+
+fn any() -> String {
+    "*".to_string()
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FastPathConfig {
+    #[serde(default)]
+    allow: Vec<EntryConfig>,
+    #[serde(default)]
+    deny: Vec<EntryConfig>,
+}
+
+/// A compiled list entry.
+#[derive(Debug, Clone)]
+struct Entry {
+    server: glob::Pattern,
+    name: glob::Pattern,
+}
+
+impl Entry {
+    fn compile(entry: EntryConfig) -> Result<Self, PolicyError> {
+        let pattern = |glob: &str| {
+            glob::Pattern::new(glob).map_err(|e| PolicyError::ParseError {
+                details: format!("Invalid fast-path pattern '{glob}': {e}"),
+                line: None,
+            })
+        };
+        Ok(Self {
+            server: pattern(&entry.server)?,
+            name: pattern(&entry.name)?,
+        })
+    }
+
+    fn matches(&self, server: &str, name: &str) -> bool {
+        self.server.matches(server) && self.name.matches(name)
+    }
+}
+
+/// Outcome of checking a request against the lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FastPathVerdict {
+    /// Matched an `allow` entry: permit without Cedar
+    Allow,
+    /// Matched a `deny` entry: forbid without Cedar
+    Deny,
+}
+
+impl FastPathVerdict {
+    /// Metric label for this verdict.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FastPathVerdict::Allow => "allow",
+            FastPathVerdict::Deny => "deny",
+        }
+    }
+}
+
+/// Allow and deny lists of `(server, name)` globs.
+///
+/// Implements: REQ-POL-001/F-001 (Policy Evaluation)
+#[derive(Debug, Clone, Default)]
+pub struct FastPath {
+    allow: Vec<Entry>,
+    deny: Vec<Entry>,
+}
+
+impl FastPath {
+    /// Load the lists from the file or environment variable.
+    ///
+    /// # Errors
+    /// Returns `PolicyError::ParseError` if the file cannot be read or the
+    /// lists are invalid.
+    pub fn load() -> Result<Self, PolicyError> {
+        let path = env::var("THOUGHTGATE_POLICY_FAST_PATH_FILE")
+            .unwrap_or_else(|_| "/etc/thoughtgate/policy-fast-path.yaml".to_string());
+
+        let yaml = if Path::new(&path).exists() {
+            info!(path = %path, "Loading policy fast-path lists from file");
+            fs::read_to_string(&path).map_err(|e| PolicyError::ParseError {
+                details: format!("Failed to read {path}: {e}"),
+                line: None,
+            })?
+        } else if let Ok(yaml) = env::var("THOUGHTGATE_POLICY_FAST_PATH") {
+            info!("Loading policy fast-path lists from environment variable");
+            yaml
+        } else {
+            return Ok(Self::default());
+        };
+
+        Self::parse(&yaml)
+    }
+
+    /// Parse lists from YAML.
+    ///
+    /// # Errors
+    /// Returns `PolicyError::ParseError` for malformed YAML or globs.
+    pub fn parse(yaml: &str) -> Result<Self, PolicyError> {
+        if yaml.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        let config: FastPathConfig =
+            serde_saphyr::from_str(yaml).map_err(|e| PolicyError::ParseError {
+                details: format!("Invalid fast-path lists: {e}"),
+                line: None,
+            })?;
+
+        let fast_path = Self {
+            allow: config
+                .allow
+                .into_iter()
+                .map(Entry::compile)
+                .collect::<Result<_, _>>()?,
+            deny: config
+                .deny
+                .into_iter()
+                .map(Entry::compile)
+                .collect::<Result<_, _>>()?,
+        };
+        for entry in &fast_path.allow {
+            if entry.name.as_str() == "*" {
+                warn!(
+                    server = %entry.server,
+                    "Fast-path allow entry matches every tool: Cedar is bypassed for this server"
+                );
+            }
+        }
+        Ok(fast_path)
+    }
+
+    /// Whether both lists are empty.
+    pub fn is_empty(&self) -> bool {
+        self.allow.is_empty() && self.deny.is_empty()
+    }
+
+    /// Check `server` and tool/method `name`; `None` means ask Cedar.
+    pub fn check(&self, server: &str, name: &str) -> Option<FastPathVerdict> {
+        if self.deny.iter().any(|e| e.matches(server, name)) {
+            Some(FastPathVerdict::Deny)
+        } else if self.allow.iter().any(|e| e.matches(server, name)) {
+            Some(FastPathVerdict::Allow)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    fn test_deny_wins_over_allow() {
+        let fast_path = FastPath::parse(
+            "allow:\n  - server: github\n    name: \"*_issue\"\ndeny:\n  - name: delete_*\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            fast_path.check("github", "get_issue"),
+            Some(FastPathVerdict::Allow)
+        );
+        assert_eq!(
+            fast_path.check("github", "delete_issue"),
+            Some(FastPathVerdict::Deny)
+        );
+        assert_eq!(
+            fast_path.check("jira", "delete_ticket"),
+            Some(FastPathVerdict::Deny)
+        );
+        // Allow entries are scoped to their server
+        assert_eq!(fast_path.check("jira", "get_issue"), None);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_lists() {
+        assert!(FastPath::parse("").unwrap().is_empty());
+        assert!(FastPath::parse("allow:\n  - name: \"[\"\n").is_err());
+        assert!(FastPath::parse("allow:\n  - tool: x\n").is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_load_from_env() {
+        unsafe {
+            env::set_var("THOUGHTGATE_POLICY_FAST_PATH_FILE", "/nonexistent");
+            env::set_var("THOUGHTGATE_POLICY_FAST_PATH", "deny:\n  - name: rm\n");
+        }
+        let fast_path = FastPath::load();
+        unsafe {
+            env::remove_var("THOUGHTGATE_POLICY_FAST_PATH_FILE");
+            env::remove_var("THOUGHTGATE_POLICY_FAST_PATH");
+        }
+
+        assert_eq!(
+            fast_path.unwrap().check("any", "rm"),
+            Some(FastPathVerdict::Deny)
+        );
+    }
+}
@@ -2,11 +2,14 @@
 //!
 //! Implements: REQ-POL-001/F-003 (Policy Loading)
 
-use super::PolicySource;
+use super::{PolicyError, PolicySource};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
 use std::env;
 use std::fs;
 use std::path::Path;
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 
 /// Load policies with priority order.
 ///
@@ -17,9 +20,36 @@ use tracing::{info, warn};
 /// 2. Environment variable `$THOUGHTGATE_POLICIES`
 /// 3. Embedded default policies
 ///
+/// If `$THOUGHTGATE_POLICIES_SHA256` is set, the policies loaded, from
+/// whichever source, must have that SHA-256 (hex, case-insensitive), and
+/// the embedded defaults are never used.
+///
 /// # Returns
 /// Tuple of (policy_text, source)
-pub fn load_policies() -> (String, PolicySource) {
+///
+/// # Errors
+/// Returns `PolicyError::ChecksumMismatch` if the loaded policies do not
+/// match `$THOUGHTGATE_POLICIES_SHA256`, and
+/// `PolicyError::PinnedPoliciesMissing` if it is set but neither a file nor
+/// the environment provides policies.
+pub fn load_policies() -> Result<(String, PolicySource), PolicyError> {
+    let (policies, source) = load_unpinned_policies();
+    if let Ok(expected) = env::var("THOUGHTGATE_POLICIES_SHA256") {
+        if matches!(source, PolicySource::Embedded) {
+            error!(
+                "THOUGHTGATE_POLICIES_SHA256 is set but no policies were provided, refusing to load"
+            );
+            return Err(PolicyError::PinnedPoliciesMissing {
+                expected: expected.trim().to_ascii_lowercase(),
+            });
+        }
+        verify_checksum(&policies, &expected)?;
+    }
+    Ok((policies, source))
+}
+
+/// Load policies by priority, without checking a pinned checksum.
+fn load_unpinned_policies() -> (String, PolicySource) {
     // 1. Try ConfigMap
     let config_path = env::var("THOUGHTGATE_POLICY_FILE")
         .unwrap_or_else(|_| "/etc/thoughtgate/policies.cedar".to_string());
@@ -62,6 +92,167 @@ pub fn load_policies() -> (String, PolicySource) {
     (embedded_default_policies(), PolicySource::Embedded)
 }
 
+/// Check that `policies` hash to `expected`, a hex SHA-256.
+///
+/// Implements: REQ-POL-001/F-003 (Policy Loading)
+fn verify_checksum(policies: &str, expected: &str) -> Result<(), PolicyError> {
+    let expected = expected.trim().to_ascii_lowercase();
+    let actual = hex::encode(Sha256::digest(policies.as_bytes()));
+    if actual != expected {
+        error!(
+            expected = %expected,
+            actual = %actual,
+            bytes = policies.len(),
+            "Policies do not match THOUGHTGATE_POLICIES_SHA256, refusing to load"
+        );
+        return Err(PolicyError::ChecksumMismatch { expected, actual });
+    }
+    Ok(())
+}
+
+/// Load namespace-scoped policies.
+///
+/// Implements: REQ-POL-001/F-003 (Policy Loading)
+///
+/// Reads every `<namespace>.cedar` file in
+/// `$THOUGHTGATE_POLICY_NAMESPACE_DIR` (default:
+/// `/etc/thoughtgate/namespaces`); see [`super::namespace`]. A missing
+/// directory means no namespace policies.
+///
+/// # Returns
+/// Policy text by namespace
+///
+/// # Errors
+/// Returns `PolicyError::ParseError` if the directory or a file cannot be
+/// read, or a file name is not a valid Kubernetes namespace.
+pub fn load_namespace_policies() -> Result<BTreeMap<String, String>, PolicyError> {
+    let dir = env::var("THOUGHTGATE_POLICY_NAMESPACE_DIR")
+        .unwrap_or_else(|_| "/etc/thoughtgate/namespaces".to_string());
+    let read_error = |path: &Path, e: std::io::Error| PolicyError::ParseError {
+        details: format!("Failed to read {}: {e}", path.display()),
+        line: None,
+    };
+
+    let dir = Path::new(&dir);
+    if !dir.is_dir() {
+        return Ok(BTreeMap::new());
+    }
+
+    let mut namespaces = BTreeMap::new();
+    for entry in fs::read_dir(dir).map_err(|e| read_error(dir, e))? {
+        let path = entry.map_err(|e| read_error(dir, e))?.path();
+        // ConfigMap mounts also hold hidden `..data` entries
+        let Some(namespace) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .filter(|name| !name.starts_with('.'))
+            .and_then(|name| name.strip_suffix(".cedar"))
+        else {
+            continue;
+        };
+        if !is_namespace_name(namespace) {
+            return Err(PolicyError::ParseError {
+                details: format!("{}: `{namespace}` is not a namespace name", path.display()),
+                line: None,
+            });
+        }
+        let policies = fs::read_to_string(&path).map_err(|e| read_error(&path, e))?;
+        namespaces.insert(namespace.to_string(), policies);
+    }
+
+    if !namespaces.is_empty() {
+        info!(
+            dir = %dir.display(),
+            namespaces = ?namespaces.keys().collect::<Vec<_>>(),
+            "Loading namespace policies"
+        );
+    }
+    Ok(namespaces)
+}
+
+/// Whether `name` is a valid Kubernetes namespace (an RFC 1123 label).
+fn is_namespace_name(name: &str) -> bool {
+    (1..=63).contains(&name.len())
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+}
+
+/// A template instantiated with concrete slot values.
+///
+/// Implements: REQ-POL-001/F-003 (Policy Loading)
+///
+/// ```yaml
+/// - template: own-tools               # @id of the template (or policyN)
+///   id: own-tools-agent-a             # ID of the linked policy
+///   principal: 'ThoughtGate::App::"agent-a"'
+///   resource: 'ThoughtGate::ToolCall::"deploy_agent_a"'
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyLink {
+    /// Template to instantiate: its `@id` annotation, or Cedar's
+    /// positional ID when it has none
+    pub template: String,
+    /// ID of the linked policy; must not clash with another policy
+    pub id: String,
+    /// Entity for the `?principal` slot, e.g. `ThoughtGate::App::"agent-a"`
+    #[serde(default)]
+    pub principal: Option<String>,
+    /// Entity for the `?resource` slot
+    #[serde(default)]
+    pub resource: Option<String>,
+}
+
+/// Load template links.
+///
+/// Implements: REQ-POL-001/F-003 (Policy Loading)
+///
+/// # Priority
+/// 1. YAML file at `$THOUGHTGATE_POLICY_LINKS_FILE` (default:
+///    `/etc/thoughtgate/policy-links.yaml`)
+/// 2. Environment variable `$THOUGHTGATE_POLICY_LINKS` (YAML)
+/// 3. No links
+///
+/// # Errors
+/// Returns `PolicyError::ParseError` if the links are not a valid YAML list
+/// of [`PolicyLink`]s.
+pub fn load_policy_links() -> Result<Vec<PolicyLink>, PolicyError> {
+    let links_path = env::var("THOUGHTGATE_POLICY_LINKS_FILE")
+        .unwrap_or_else(|_| "/etc/thoughtgate/policy-links.yaml".to_string());
+
+    let yaml = if Path::new(&links_path).exists() {
+        info!(path = %links_path, "Loading policy template links from file");
+        fs::read_to_string(&links_path).map_err(|e| PolicyError::ParseError {
+            details: format!("Failed to read {links_path}: {e}"),
+            line: None,
+        })?
+    } else if let Ok(yaml) = env::var("THOUGHTGATE_POLICY_LINKS") {
+        info!("Loading policy template links from environment variable");
+        yaml
+    } else {
+        return Ok(Vec::new());
+    };
+
+    parse_policy_links(&yaml)
+}
+
+/// Parse a YAML list of template links.
+///
+/// # Errors
+/// Returns `PolicyError::ParseError` for malformed YAML.
+pub fn parse_policy_links(yaml: &str) -> Result<Vec<PolicyLink>, PolicyError> {
+    if yaml.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    serde_saphyr::from_str(yaml).map_err(|e| PolicyError::ParseError {
+        details: format!("Invalid policy links: {e}"),
+        line: None,
+    })
+}
+
 /// Load Cedar schema.
 ///
 /// Implements: REQ-POL-001/F-004 (Schema Validation)
@@ -121,11 +312,42 @@ mod tests {
             env::remove_var("THOUGHTGATE_POLICIES");
         }
 
-        let (policies, source) = load_policies();
+        let (policies, source) = load_policies().unwrap();
         assert!(!policies.is_empty());
         assert!(matches!(source, PolicySource::Embedded));
     }
 
+    #[test]
+    #[serial]
+    fn test_load_policy_links_from_env() {
+        unsafe {
+            env::remove_var("THOUGHTGATE_POLICY_LINKS_FILE");
+            env::set_var(
+                "THOUGHTGATE_POLICY_LINKS",
+                "- template: own-tools\n  id: own-tools-a\n  principal: 'ThoughtGate::App::\"a\"'\n",
+            );
+        }
+        let links = load_policy_links();
+        unsafe {
+            env::remove_var("THOUGHTGATE_POLICY_LINKS");
+        }
+
+        assert_eq!(
+            links.unwrap(),
+            vec![PolicyLink {
+                template: "own-tools".to_string(),
+                id: "own-tools-a".to_string(),
+                principal: Some(r#"ThoughtGate::App::"a""#.to_string()),
+                resource: None,
+            }]
+        );
+        assert!(load_policy_links().unwrap().is_empty());
+        assert!(matches!(
+            parse_policy_links("- template: t\n  principle: x\n"),
+            Err(PolicyError::ParseError { .. })
+        ));
+    }
+
     #[test]
     #[serial]
     fn test_load_policies_from_env() {
@@ -136,7 +358,7 @@ mod tests {
             );
         }
 
-        let (policies, source) = load_policies();
+        let (policies, source) = load_policies().unwrap();
         assert_eq!(policies, "permit(principal, action, resource);");
         assert!(matches!(source, PolicySource::Environment { .. }));
 
@@ -145,6 +367,124 @@ mod tests {
         }
     }
 
+    #[test]
+    #[serial]
+    fn test_load_policies_env_checksum() {
+        let policy = "permit(principal, action, resource);";
+        unsafe {
+            env::remove_var("THOUGHTGATE_POLICY_FILE");
+            env::set_var("THOUGHTGATE_POLICIES", policy);
+            env::set_var(
+                "THOUGHTGATE_POLICIES_SHA256",
+                hex::encode(Sha256::digest(policy)).to_uppercase(),
+            );
+        }
+        let matching = load_policies();
+
+        // Truncated in transit
+        unsafe {
+            env::set_var("THOUGHTGATE_POLICIES", &policy[..policy.len() - 1]);
+        }
+        let mismatching = load_policies();
+        unsafe {
+            env::remove_var("THOUGHTGATE_POLICIES");
+            env::remove_var("THOUGHTGATE_POLICIES_SHA256");
+        }
+
+        let (policies, source) = matching.unwrap();
+        assert_eq!(policies, policy);
+        assert!(matches!(source, PolicySource::Environment { .. }));
+        match mismatching {
+            Err(PolicyError::ChecksumMismatch { expected, actual }) => {
+                assert_eq!(expected, hex::encode(Sha256::digest(policy)));
+                assert_ne!(actual, expected);
+            }
+            other => panic!("expected checksum mismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_load_policies_checksum_covers_every_source() {
+        let policy = "permit(principal, action, resource);";
+        let path = std::env::temp_dir().join(format!(
+            "thoughtgate_pinned_policies_{}.cedar",
+            std::process::id()
+        ));
+        fs::write(&path, "forbid(principal, action, resource);").unwrap();
+        unsafe {
+            env::set_var("THOUGHTGATE_POLICY_FILE", &path);
+            env::set_var("THOUGHTGATE_POLICIES", policy);
+            env::set_var(
+                "THOUGHTGATE_POLICIES_SHA256",
+                hex::encode(Sha256::digest(policy)),
+            );
+        }
+        // The file takes precedence and does not match the pin
+        let file = load_policies();
+
+        unsafe {
+            env::remove_var("THOUGHTGATE_POLICY_FILE");
+            env::remove_var("THOUGHTGATE_POLICIES");
+        }
+        // Nothing provided: no fallback to the embedded defaults
+        let missing = load_policies();
+        unsafe {
+            env::remove_var("THOUGHTGATE_POLICIES_SHA256");
+        }
+        let _ = fs::remove_file(&path);
+
+        assert!(
+            matches!(file, Err(PolicyError::ChecksumMismatch { .. })),
+            "{file:?}"
+        );
+        assert!(
+            matches!(missing, Err(PolicyError::PinnedPoliciesMissing { .. })),
+            "{missing:?}"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_load_namespace_policies() {
+        let dir = std::env::temp_dir().join(format!("thoughtgate_ns_{}", std::process::id()));
+        fs::create_dir_all(dir.join("..data")).unwrap();
+        fs::write(
+            dir.join("tenant-a.cedar"),
+            "permit(principal, action, resource);",
+        )
+        .unwrap();
+        fs::write(dir.join("README.md"), "not a policy").unwrap();
+        unsafe {
+            env::set_var("THOUGHTGATE_POLICY_NAMESPACE_DIR", &dir);
+        }
+
+        let loaded = load_namespace_policies();
+        fs::write(dir.join("Tenant_B.cedar"), "").unwrap();
+        let invalid = load_namespace_policies();
+        unsafe {
+            env::set_var(
+                "THOUGHTGATE_POLICY_NAMESPACE_DIR",
+                "/nonexistent/namespaces",
+            );
+        }
+        let missing = load_namespace_policies();
+        unsafe {
+            env::remove_var("THOUGHTGATE_POLICY_NAMESPACE_DIR");
+        }
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            loaded.unwrap(),
+            BTreeMap::from([(
+                "tenant-a".to_string(),
+                "permit(principal, action, resource);".to_string()
+            )])
+        );
+        assert!(matches!(invalid, Err(PolicyError::ParseError { .. })));
+        assert!(missing.unwrap().is_empty());
+    }
+
     #[test]
     fn test_load_schema_embedded() {
         let schema = load_schema();
@@ -191,7 +531,7 @@ mod tests {
             env::remove_var("THOUGHTGATE_POLICIES");
         }
 
-        let (policies, source) = load_policies();
+        let (policies, source) = load_policies().unwrap();
         assert_eq!(policies, config_content);
         assert!(matches!(source, PolicySource::ConfigMap { .. }));
 
@@ -215,7 +555,7 @@ mod tests {
             );
         }
 
-        let (policies, source) = load_policies();
+        let (policies, source) = load_policies().unwrap();
         assert_eq!(policies, "permit(principal, action, resource);");
         assert!(matches!(source, PolicySource::Environment { .. }));
 
@@ -234,7 +574,7 @@ mod tests {
             env::remove_var("THOUGHTGATE_POLICIES");
         }
 
-        let (policies, source) = load_policies();
+        let (policies, source) = load_policies().unwrap();
         assert!(!policies.is_empty());
         assert!(matches!(source, PolicySource::Embedded));
         // Verify it contains expected v0.1 default policies
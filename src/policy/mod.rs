@@ -17,6 +17,8 @@
 //! - [`CedarContext`] - Context with policy_id, source_id, time
 //! - [`CedarDecision`] - Output: Permit or Forbid
 //! - [`PolicyAnnotations`] - Cached `@thoughtgate_approval` annotations
+//! - [`Explanation`] - Which policies decided an evaluation (operators only)
+//! - [`testkit`] - Fixture-driven policy tests for CI
 //!
 //! # v0.1 Compatibility
 //!
@@ -24,16 +26,22 @@
 //! backward compatibility but deprecated. Use `CedarDecision` for v0.2.
 
 pub mod engine;
+pub mod explain;
+pub mod fast_path;
 pub mod loader;
+pub mod namespace;
 pub mod principal;
+pub mod quarantine;
+pub mod testkit;
 pub mod types;
 
 // Re-export v0.2 types
 pub use types::{
-    CedarContext, CedarDecision, CedarRequest, CedarResource, CedarStats, PolicyAnnotations,
-    PolicyInfo, TimeContext,
+    AttrType, AttrValue, CedarContext, CedarDecision, CedarRequest, CedarResource, CedarStats,
+    Explanation, MatchedCondition, PolicyAnnotations, PolicyInfo, TimeContext,
 };
 
+use std::collections::BTreeMap;
 use std::time::Duration;
 use thiserror::Error;
 
@@ -213,6 +221,8 @@ pub enum Resource {
         name: String,
         /// Upstream server identifier
         server: String,
+        /// Catalog attributes (empty if none)
+        attributes: BTreeMap<String, AttrValue>,
     },
 
     /// Generic MCP method (e.g., "resources/read")
@@ -221,6 +231,8 @@ pub enum Resource {
         method: String,
         /// Upstream server identifier
         server: String,
+        /// Catalog attributes (empty if none)
+        attributes: BTreeMap<String, AttrValue>,
     },
 }
 
@@ -236,7 +248,7 @@ pub struct PolicyContext {
 /// Approval grant from human/agent approver.
 ///
 /// Implements: REQ-POL-001/§6.1 (ApprovalGrant)
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
 pub struct ApprovalGrant {
     /// Task ID that was approved
     pub task_id: String,
@@ -283,6 +295,22 @@ pub enum PolicyError {
         details: String,
     },
 
+    /// Policy text does not match its expected checksum
+    #[error("Policy checksum mismatch: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        /// SHA-256 the policies were expected to have
+        expected: String,
+        /// SHA-256 of the policies actually loaded
+        actual: String,
+    },
+
+    /// Policies are pinned to a checksum but none were provided
+    #[error("Policies pinned to {expected} but none were provided")]
+    PinnedPoliciesMissing {
+        /// SHA-256 the policies were expected to have
+        expected: String,
+    },
+
     /// Cedar engine error
     #[error("Cedar engine error: {details}")]
     CedarError {
@@ -319,9 +347,12 @@ pub enum PolicySource {
 /// Implements: REQ-POL-001/§6.3 (PolicyStats)
 #[derive(Debug, Clone, Default)]
 pub struct PolicyStats {
-    /// Number of policies loaded
+    /// Number of policies loaded, global and namespace-scoped
     pub policy_count: usize,
 
+    /// Number of policies scoped to each namespace
+    pub namespace_policy_counts: BTreeMap<String, usize>,
+
     /// Last successful reload time
     pub last_reload: Option<std::time::SystemTime>,
 
@@ -423,12 +454,14 @@ mod tests {
         let tool = Resource::ToolCall {
             name: "delete_user".to_string(),
             server: "mcp-server".to_string(),
+            attributes: Default::default(),
         };
         assert!(matches!(tool, Resource::ToolCall { .. }));
 
         let method = Resource::McpMethod {
             method: "resources/read".to_string(),
             server: "mcp-server".to_string(),
+            attributes: Default::default(),
         };
         assert!(matches!(method, Resource::McpMethod { .. }));
     }
@@ -0,0 +1,201 @@
+//! Namespace-scoped policy sets.
+//!
+//! Implements: REQ-POL-001/F-003 (Policy Loading)
+//!
+//! In a multi-tenant deployment each Kubernetes namespace can have its own
+//! policies, which only ever apply to principals in that namespace. A
+//! request is evaluated against the global policies together with the
+//! policies of its principal's namespace, and nothing else, so one tenant
+//! cannot write a rule that affects another.
+//!
+//! Within that set Cedar's usual rules decide: any applicable `forbid`
+//! wins, otherwise any applicable `permit` allows, otherwise the request is
+//! denied. A global `forbid` therefore holds for every namespace, while a
+//! namespace `permit` only opens things up for its own principals.
+//!
+//! Namespace policies are read from `<namespace>.cedar` files in
+//! `$THOUGHTGATE_POLICY_NAMESPACE_DIR` (default:
+//! `/etc/thoughtgate/namespaces`). They may not contain templates, and
+//! their policy IDs are prefixed with the namespace (`tenant-a/policy0`) so
+//! they can be told apart in logs, explanations and quarantine.
+
+use std::collections::{BTreeMap, HashMap};
+
+use cedar_policy::{PolicyId, PolicySet};
+
+use super::PolicyError;
+
+/// The global policy set and, for each namespace with its own policies,
+/// the global set merged with them.
+///
+/// Implements: REQ-POL-001/F-003 (Policy Loading)
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PolicySets {
+    /// Policies that apply to every principal
+    global: PolicySet,
+    /// Global plus namespace policies, by namespace
+    merged: HashMap<String, PolicySet>,
+    /// Number of each namespace's own policies
+    counts: BTreeMap<String, usize>,
+}
+
+impl PolicySets {
+    /// Combine `global` with each namespace's policies, already scoped with
+    /// [`scope_to_namespace`].
+    ///
+    /// # Errors
+    /// Returns `PolicyError::ParseError` if a namespace policy ID clashes
+    /// with a global one.
+    pub(crate) fn new(
+        global: PolicySet,
+        namespaces: impl IntoIterator<Item = (String, PolicySet)>,
+    ) -> Result<Self, PolicyError> {
+        let mut merged = HashMap::new();
+        let mut counts = BTreeMap::new();
+        for (namespace, policies) in namespaces {
+            let mut set = global.clone();
+            set.merge(&policies, false)
+                .map_err(|e| PolicyError::ParseError {
+                    details: format!("namespace `{namespace}`: {e}"),
+                    line: None,
+                })?;
+            counts.insert(namespace.clone(), policies.policies().count());
+            merged.insert(namespace, set);
+        }
+        Ok(Self {
+            global,
+            merged,
+            counts,
+        })
+    }
+
+    /// Only global policies.
+    pub(crate) fn global_only(global: PolicySet) -> Self {
+        Self {
+            global,
+            ..Self::default()
+        }
+    }
+
+    /// The policies that apply to principals in `namespace`.
+    pub(crate) fn for_namespace(&self, namespace: &str) -> &PolicySet {
+        self.merged.get(namespace).unwrap_or(&self.global)
+    }
+
+    /// The global set followed by each merged namespace set.
+    pub(crate) fn sets(&self) -> impl Iterator<Item = &PolicySet> {
+        std::iter::once(&self.global).chain(self.merged.values())
+    }
+
+    /// Apply `f` to every set, keeping the namespace counts.
+    pub(crate) fn map(&self, f: impl Fn(&PolicySet) -> PolicySet) -> Self {
+        Self {
+            global: f(&self.global),
+            merged: self
+                .merged
+                .iter()
+                .map(|(namespace, set)| (namespace.clone(), f(set)))
+                .collect(),
+            counts: self.counts.clone(),
+        }
+    }
+
+    /// Number of policies loaded: global ones plus every namespace's own.
+    pub(crate) fn policy_count(&self) -> usize {
+        self.global.policies().count() + self.counts.values().sum::<usize>()
+    }
+
+    /// Number of each namespace's own policies.
+    pub(crate) fn namespace_counts(&self) -> &BTreeMap<String, usize> {
+        &self.counts
+    }
+}
+
+/// Give each policy in `policies` an ID prefixed with `namespace`.
+///
+/// Implements: REQ-POL-001/F-003 (Policy Loading)
+///
+/// # Errors
+/// Returns `PolicyError::ParseError` if `policies` contains templates,
+/// which could be linked for principals outside the namespace.
+pub(crate) fn scope_to_namespace(
+    namespace: &str,
+    policies: &PolicySet,
+) -> Result<PolicySet, PolicyError> {
+    let error = |details: String| PolicyError::ParseError {
+        details: format!("namespace `{namespace}`: {details}"),
+        line: None,
+    };
+    if policies.templates().next().is_some() {
+        return Err(error("templates are not supported".to_string()));
+    }
+
+    let mut scoped = PolicySet::new();
+    for policy in policies.policies() {
+        let id = PolicyId::new(format!("{namespace}/{}", policy.id()));
+        scoped
+            .add(policy.new_id(id))
+            .map_err(|e| error(e.to_string()))?;
+    }
+    Ok(scoped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_namespace_sets_do_not_cross() {
+        let global = PolicySet::from_str("forbid(principal, action, resource);").unwrap();
+        let tenant = |text: &str, namespace: &str| {
+            let set = PolicySet::from_str(text).unwrap();
+            (
+                namespace.to_string(),
+                scope_to_namespace(namespace, &set).unwrap(),
+            )
+        };
+        let sets = PolicySets::new(
+            global,
+            [
+                tenant("permit(principal, action, resource);", "tenant-a"),
+                tenant(
+                    "permit(principal, action, resource);\npermit(principal, action, resource);",
+                    "tenant-b",
+                ),
+            ],
+        )
+        .unwrap();
+
+        let ids = |namespace: &str| -> Vec<String> {
+            let mut ids: Vec<_> = sets
+                .for_namespace(namespace)
+                .policies()
+                .map(|p| p.id().to_string())
+                .collect();
+            ids.sort();
+            ids
+        };
+        assert_eq!(ids("tenant-a"), ["policy0", "tenant-a/policy0"]);
+        assert_eq!(
+            ids("tenant-b"),
+            ["policy0", "tenant-b/policy0", "tenant-b/policy1"]
+        );
+        assert_eq!(ids("tenant-c"), ["policy0"]);
+        assert_eq!(sets.policy_count(), 4);
+        assert_eq!(
+            sets.namespace_counts(),
+            &BTreeMap::from([("tenant-a".to_string(), 1), ("tenant-b".to_string(), 2)])
+        );
+    }
+
+    #[test]
+    fn test_scope_rejects_templates() {
+        let set =
+            PolicySet::from_str("permit(principal == ?principal, action, resource);").unwrap();
+        assert!(matches!(
+            scope_to_namespace("tenant-a", &set),
+            Err(PolicyError::ParseError { details, .. }) if details.contains("templates")
+        ));
+    }
+}
@@ -1,11 +1,192 @@
 //! Principal identity inference from Kubernetes environment.
 //!
+//! Callers that authenticate with a TLS client certificate are identified
+//! from the certificate instead (see [`from_client_cert`]).
+//!
+//! Derived principals are cached by their identity source (see
+//! [`PrincipalCache`]), so the pod identity and each client certificate are
+//! only parsed once until policies reload.
+//!
 //! Implements: REQ-POL-001/F-006 (Identity Inference)
 
 use super::{PolicyError, Principal};
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
 use std::env;
 use std::fs;
-use tracing::{info, warn};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::{debug, info, warn};
+
+/// Distinct identities cached before the cache is emptied.
+const CACHE_CAPACITY: usize = 1024;
+
+static PRINCIPAL_CACHE: Lazy<PrincipalCache> = Lazy::new(|| PrincipalCache::new(CACHE_CAPACITY));
+
+tokio::task_local! {
+    /// Principal authenticated by the client certificate of the current request.
+    static CLIENT_PRINCIPAL: Principal;
+}
+
+/// Run `fut` with `principal` as the identity of the calling client.
+///
+/// Implements: REQ-POL-001/F-006.3 (Client Certificate Identity)
+pub async fn with_client_principal<F: Future>(principal: Principal, fut: F) -> F::Output {
+    CLIENT_PRINCIPAL.scope(principal, fut).await
+}
+
+/// Principal for the request being processed by the current task.
+///
+/// Returns the client-certificate identity when the request arrived over
+/// mTLS, otherwise falls back to the pod identity from [`infer_principal`]
+/// (cached, see [`PrincipalCache::pod_principal`]).
+///
+/// Implements: REQ-POL-001/F-006.3 (Client Certificate Identity)
+///
+/// # Errors
+/// Returns `PolicyError::IdentityError` if no client identity is set and
+/// inference fails.
+pub fn request_principal() -> Result<Principal, PolicyError> {
+    match CLIENT_PRINCIPAL.try_with(Principal::clone) {
+        Ok(principal) => Ok(principal),
+        Err(_) => principal_cache().pod_principal(),
+    }
+}
+
+/// The process-wide principal cache.
+pub fn principal_cache() -> &'static PrincipalCache {
+    &PRINCIPAL_CACHE
+}
+
+/// Where a principal was derived from, and the key it is cached under.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum IdentitySource {
+    /// Dev mode, with its `THOUGHTGATE_DEV_*` overrides
+    Dev {
+        app_name: Option<String>,
+        namespace: Option<String>,
+    },
+    /// Kubernetes ServiceAccount mount of the pod named `hostname`
+    Kubernetes { hostname: String },
+    /// SHA-256 of a client certificate (DER)
+    Certificate([u8; 32]),
+}
+
+impl IdentitySource {
+    /// The source [`infer_principal`] would use, or `None` if there is none.
+    fn pod() -> Option<Self> {
+        if env::var("THOUGHTGATE_DEV_MODE").as_deref() == Ok("true") {
+            Some(IdentitySource::Dev {
+                app_name: env::var("THOUGHTGATE_DEV_PRINCIPAL").ok(),
+                namespace: env::var("THOUGHTGATE_DEV_NAMESPACE").ok(),
+            })
+        } else {
+            env::var("HOSTNAME")
+                .ok()
+                .map(|hostname| IdentitySource::Kubernetes { hostname })
+        }
+    }
+}
+
+/// Principals keyed by the identity they were derived from.
+///
+/// The pod identity is keyed by the environment it is read from and a
+/// client certificate by its hash, so a connection that presents another
+/// certificate, or a changed dev-mode override, is never served a stale
+/// principal. Derivation failures are not cached. [`invalidate`] empties
+/// the cache; policy reloads call it, since they change what roles map to.
+///
+/// [`invalidate`]: PrincipalCache::invalidate
+///
+/// Implements: REQ-POL-001/F-006 (Identity Inference)
+#[derive(Debug)]
+pub struct PrincipalCache {
+    principals: DashMap<IdentitySource, Principal>,
+    /// Bumped on every invalidation
+    generation: AtomicU64,
+    /// Entries held before the cache is emptied
+    capacity: usize,
+}
+
+impl PrincipalCache {
+    /// Create an empty cache holding up to `capacity` identities.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            principals: DashMap::new(),
+            generation: AtomicU64::new(0),
+            capacity,
+        }
+    }
+
+    /// The pod's own principal (see [`infer_principal`]).
+    ///
+    /// # Errors
+    /// Returns `PolicyError::IdentityError` if inference fails.
+    pub fn pod_principal(&self) -> Result<Principal, PolicyError> {
+        match IdentitySource::pod() {
+            Some(source) => self.get_or_derive(source, infer_principal),
+            None => infer_principal(),
+        }
+    }
+
+    /// The principal of a DER-encoded client certificate (see
+    /// [`from_client_cert`]).
+    ///
+    /// Implements: REQ-POL-001/F-006.3 (Client Certificate Identity)
+    ///
+    /// # Errors
+    /// Returns `PolicyError::IdentityError` if the certificate maps to no
+    /// principal.
+    pub fn client_principal(&self, cert: &[u8]) -> Result<Principal, PolicyError> {
+        let source = IdentitySource::Certificate(Sha256::digest(cert).into());
+        self.get_or_derive(source, || from_client_cert(cert))
+    }
+
+    /// Drop every cached principal.
+    pub fn invalidate(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        self.principals.clear();
+        debug!("Principal cache invalidated");
+    }
+
+    /// Number of cached principals.
+    pub fn len(&self) -> usize {
+        self.principals.len()
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The cached principal for `source`, or `derive`'s result, cached
+    /// unless the cache was invalidated while deriving.
+    fn get_or_derive(
+        &self,
+        source: IdentitySource,
+        derive: impl FnOnce() -> Result<Principal, PolicyError>,
+    ) -> Result<Principal, PolicyError> {
+        let generation = self.generation.load(Ordering::Acquire);
+        if let Some(principal) = self.principals.get(&source) {
+            return Ok(principal.clone());
+        }
+
+        let principal = derive()?;
+
+        if self.generation.load(Ordering::Acquire) == generation {
+            if self.principals.len() >= self.capacity {
+                self.principals.clear();
+            }
+            self.principals.insert(source.clone(), principal.clone());
+            // An invalidation that cleared before the insert must still win
+            if self.generation.load(Ordering::Acquire) != generation {
+                self.principals.remove(&source);
+            }
+        }
+        Ok(principal)
+    }
+}
 
 /// Infer principal identity from environment.
 ///
@@ -140,11 +321,391 @@ fn extract_sa_from_json(json: &str) -> Option<String> {
     None
 }
 
+/// Map a DER-encoded client certificate to a principal.
+///
+/// Implements: REQ-POL-001/F-006.3 (Client Certificate Identity)
+///
+/// # Mapping
+/// - SAN URI `spiffe://<trust-domain>/ns/<namespace>/sa/<sa>` → namespace
+///   and service_account (required)
+/// - Subject CN → app_name (defaults to the service account)
+/// - Subject OUs → roles
+///
+/// The certificate chain is verified during the TLS handshake; this only
+/// reads identity fields.
+///
+/// # Errors
+/// Returns `PolicyError::IdentityError` if the certificate is malformed or
+/// has no SPIFFE URI SAN in the form above.
+pub fn from_client_cert(cert: &[u8]) -> Result<Principal, PolicyError> {
+    let identity = read_cert_identity(cert).ok_or_else(|| PolicyError::IdentityError {
+        details: "Malformed client certificate".to_string(),
+    })?;
+
+    let (namespace, service_account) = identity
+        .uris
+        .iter()
+        .find_map(|uri| parse_spiffe_id(uri))
+        .ok_or_else(|| PolicyError::IdentityError {
+            details: "Client certificate has no SAN URI of the form \
+                      spiffe://<trust-domain>/ns/<namespace>/sa/<service-account>"
+                .to_string(),
+        })?;
+
+    Ok(Principal {
+        app_name: identity
+            .common_name
+            .unwrap_or_else(|| service_account.clone()),
+        namespace,
+        service_account,
+        roles: identity.organizational_units,
+    })
+}
+
+/// Split a SPIFFE ID into (namespace, service account).
+fn parse_spiffe_id(uri: &str) -> Option<(String, String)> {
+    let path = uri.strip_prefix("spiffe://")?;
+    match path.split('/').collect::<Vec<_>>()[..] {
+        [trust_domain, "ns", namespace, "sa", service_account]
+            if !trust_domain.is_empty() && !namespace.is_empty() && !service_account.is_empty() =>
+        {
+            Some((namespace.to_string(), service_account.to_string()))
+        }
+        _ => None,
+    }
+}
+
+/// Identity fields read from a certificate.
+#[derive(Debug, Default)]
+struct CertIdentity {
+    common_name: Option<String>,
+    organizational_units: Vec<String>,
+    uris: Vec<String>,
+}
+
+const DER_SEQUENCE: u8 = 0x30;
+const DER_OCTET_STRING: u8 = 0x04;
+const DER_OID: u8 = 0x06;
+/// `[0]` version and `[3]` extensions of TBSCertificate.
+const DER_VERSION: u8 = 0xA0;
+const DER_EXTENSIONS: u8 = 0xA3;
+/// `uniformResourceIdentifier [6] IA5String` in GeneralName.
+const DER_SAN_URI: u8 = 0x86;
+
+const OID_COMMON_NAME: &[u8] = &[0x55, 0x04, 0x03];
+const OID_ORGANIZATIONAL_UNIT: &[u8] = &[0x55, 0x04, 0x0B];
+const OID_SUBJECT_ALT_NAME: &[u8] = &[0x55, 0x1D, 0x11];
+
+/// Read one DER element from the front of `input`.
+///
+/// Returns `(tag, contents, rest)`, or `None` if the encoding is truncated.
+fn der_read(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = input.split_first()?;
+    let (&first, mut rest) = rest.split_first()?;
+    let len = if first < 0x80 {
+        usize::from(first)
+    } else {
+        let count = usize::from(first & 0x7F);
+        if count == 0 || count > 4 || rest.len() < count {
+            return None;
+        }
+        let (len_bytes, tail) = rest.split_at(count);
+        rest = tail;
+        len_bytes
+            .iter()
+            .fold(0usize, |len, &b| (len << 8) | usize::from(b))
+    };
+    if rest.len() < len {
+        return None;
+    }
+    let (contents, rest) = rest.split_at(len);
+    Some((tag, contents, rest))
+}
+
+/// Read the elements of a constructed value with the expected tag.
+fn der_children(input: &[u8], tag: u8) -> Option<Vec<(u8, &[u8])>> {
+    let (actual, mut contents, _) = der_read(input)?;
+    if actual != tag {
+        return None;
+    }
+    let mut children = Vec::new();
+    while !contents.is_empty() {
+        let (tag, value, rest) = der_read(contents)?;
+        children.push((tag, value));
+        contents = rest;
+    }
+    Some(children)
+}
+
+/// Read subject CN/OUs and SAN URIs from an X.509 certificate.
+fn read_cert_identity(der: &[u8]) -> Option<CertIdentity> {
+    // Certificate ::= SEQUENCE { tbsCertificate, signatureAlgorithm, signature }
+    let (tag, certificate, _) = der_read(der)?;
+    if tag != DER_SEQUENCE {
+        return None;
+    }
+    let (tag, tbs, _) = der_read(certificate)?;
+    if tag != DER_SEQUENCE {
+        return None;
+    }
+
+    // TBSCertificate ::= SEQUENCE { [0] version OPTIONAL, serialNumber,
+    //     signature, issuer, validity, subject, subjectPublicKeyInfo, ...,
+    //     [3] extensions OPTIONAL }
+    let mut fields = Vec::new();
+    let mut rest = tbs;
+    while !rest.is_empty() {
+        let (tag, value, tail) = der_read(rest)?;
+        let encoded = &rest[..rest.len() - tail.len()];
+        fields.push((tag, value, encoded));
+        rest = tail;
+    }
+    let skip = usize::from(fields.first()?.0 == DER_VERSION);
+    let (_, _, subject) = *fields.get(skip + 4)?;
+
+    let mut identity = CertIdentity::default();
+    // Name ::= SEQUENCE OF SET OF SEQUENCE { type OID, value ANY }
+    for (_, rdn) in der_children(subject, DER_SEQUENCE)? {
+        let mut attributes = rdn;
+        while !attributes.is_empty() {
+            let (_, attribute, tail) = der_read(attributes)?;
+            attributes = tail;
+            let (tag, oid, value) = der_read(attribute)?;
+            if tag != DER_OID {
+                return None;
+            }
+            let (_, value, _) = der_read(value)?;
+            let value = String::from_utf8_lossy(value).into_owned();
+            match oid {
+                OID_COMMON_NAME => identity.common_name = Some(value),
+                OID_ORGANIZATIONAL_UNIT => identity.organizational_units.push(value),
+                _ => {}
+            }
+        }
+    }
+
+    let Some(&(_, extensions, _)) = fields.iter().find(|(tag, _, _)| *tag == DER_EXTENSIONS) else {
+        return Some(identity);
+    };
+    // Extension ::= SEQUENCE { extnID OID, critical BOOLEAN DEFAULT FALSE,
+    //     extnValue OCTET STRING }
+    for (_, extension) in der_children(extensions, DER_SEQUENCE)? {
+        let (tag, oid, rest) = der_read(extension)?;
+        if tag != DER_OID || oid != OID_SUBJECT_ALT_NAME {
+            continue;
+        }
+        let (mut tag, mut value, mut rest) = der_read(rest)?;
+        if tag != DER_OCTET_STRING {
+            (tag, value, rest) = der_read(rest)?;
+        }
+        if tag != DER_OCTET_STRING || !rest.is_empty() {
+            return None;
+        }
+        for (tag, name) in der_children(value, DER_SEQUENCE)? {
+            if tag == DER_SAN_URI {
+                identity
+                    .uris
+                    .push(String::from_utf8_lossy(name).into_owned());
+            }
+        }
+    }
+    Some(identity)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use serial_test::serial;
 
+    /// Subject `O=acme, OU=billing, OU=oncall, CN=billing-agent` with SANs
+    /// `URI:spiffe://cluster.local/ns/payments/sa/billing-agent-sa` and
+    /// `DNS:billing-agent.payments.svc`.
+    const SPIFFE_CLIENT_CERT: &str = "\
+-----BEGIN CERTIFICATE-----
+MIICDzCCAbWgAwIBAgIUaMfaMCL5wZfm3qVHqtLeLtCs1kUwCgYIKoZIzj0EAwIw
+HjEcMBoGA1UEAwwTdGhvdWdodGdhdGUtdGVzdC1jYTAgFw0yNjEwMTcyMDI0MTJa
+GA8yMTI2MDkyMzIwMjQxMlowSjENMAsGA1UECgwEYWNtZTEQMA4GA1UECwwHYmls
+bGluZzEPMA0GA1UECwwGb25jYWxsMRYwFAYDVQQDDA1iaWxsaW5nLWFnZW50MFkw
+EwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAERs0uCUNCTltXMOTdqgifa3Z/uhNBaHrY
+UwE0MHsD91a6DuOqcANyfEfx3nV1T4ch30k2sVjGR+J8FOC9RSaYXqOBojCBnzBd
+BgNVHREEVjBUhjZzcGlmZmU6Ly9jbHVzdGVyLmxvY2FsL25zL3BheW1lbnRzL3Nh
+L2JpbGxpbmctYWdlbnQtc2GCGmJpbGxpbmctYWdlbnQucGF5bWVudHMuc3ZjMB0G
+A1UdDgQWBBTs8qncfKF3R8uWRkKuK26mARHuCzAfBgNVHSMEGDAWgBSL4eCbaGUs
+YK/CsoaA976gMbSYajAKBggqhkjOPQQDAgNIADBFAiEA5mpQ679+0XxnlKuCNfbC
+RPzw1JM00q5Yb7QH4Ri2NqsCIC5XaStshzRAy0dJa2XJ+HdDYE1DOehAK9YPRCN9
+QPSz
+-----END CERTIFICATE-----";
+
+    /// Subject `CN=no-spiffe` with only `DNS:no-spiffe.example.com`.
+    const DNS_ONLY_CLIENT_CERT: &str = "\
+-----BEGIN CERTIFICATE-----
+MIIBmTCCAUCgAwIBAgIUaMfaMCL5wZfm3qVHqtLeLtCs1kYwCgYIKoZIzj0EAwIw
+HjEcMBoGA1UEAwwTdGhvdWdodGdhdGUtdGVzdC1jYTAgFw0yNjEwMTcyMDI0MTJa
+GA8yMTI2MDkyMzIwMjQxMlowFDESMBAGA1UEAwwJbm8tc3BpZmZlMFkwEwYHKoZI
+zj0CAQYIKoZIzj0DAQcDQgAERs0uCUNCTltXMOTdqgifa3Z/uhNBaHrYUwE0MHsD
+91a6DuOqcANyfEfx3nV1T4ch30k2sVjGR+J8FOC9RSaYXqNkMGIwIAYDVR0RBBkw
+F4IVbm8tc3BpZmZlLmV4YW1wbGUuY29tMB0GA1UdDgQWBBTs8qncfKF3R8uWRkKu
+K26mARHuCzAfBgNVHSMEGDAWgBSL4eCbaGUsYK/CsoaA976gMbSYajAKBggqhkjO
+PQQDAgNHADBEAiAzV6crIwBgT3zCXAGyjOlCyvZlSk6d5jw3FRW7YpQRoAIgaNTp
+UMV0h3TYhFNXrunXX8JAD+27N/HCcFQtBNR13QQ=
+-----END CERTIFICATE-----";
+
+    fn cert_der(pem: &str) -> Vec<u8> {
+        use rustls::pki_types::{CertificateDer, pem::PemObject};
+        CertificateDer::from_pem_slice(pem.as_bytes())
+            .expect("test certificate is valid PEM")
+            .to_vec()
+    }
+
+    #[test]
+    fn test_from_client_cert_maps_spiffe_san() {
+        let principal = from_client_cert(&cert_der(SPIFFE_CLIENT_CERT)).unwrap();
+
+        assert_eq!(principal.app_name, "billing-agent");
+        assert_eq!(principal.namespace, "payments");
+        assert_eq!(principal.service_account, "billing-agent-sa");
+        assert_eq!(principal.roles, vec!["billing", "oncall"]);
+    }
+
+    #[test]
+    fn test_from_client_cert_without_spiffe_san_fails() {
+        let result = from_client_cert(&cert_der(DNS_ONLY_CLIENT_CERT));
+
+        assert!(
+            matches!(result, Err(PolicyError::IdentityError { ref details }) if details.contains("spiffe://")),
+            "{result:?}"
+        );
+        assert!(from_client_cert(b"not a certificate").is_err());
+    }
+
+    fn principal(app_name: &str) -> Principal {
+        Principal {
+            app_name: app_name.to_string(),
+            namespace: "ns".to_string(),
+            service_account: "sa".to_string(),
+            roles: vec![],
+        }
+    }
+
+    #[test]
+    fn test_principal_cache_hit() {
+        let cache = PrincipalCache::new(8);
+        let source = IdentitySource::Kubernetes {
+            hostname: "pod-a".to_string(),
+        };
+        let mut derived = 0;
+
+        for _ in 0..3 {
+            let result = cache.get_or_derive(source.clone(), || {
+                derived += 1;
+                Ok(principal("pod-a"))
+            });
+            assert_eq!(result.unwrap(), principal("pod-a"));
+        }
+        assert_eq!(derived, 1);
+
+        // Failures are not cached
+        let missing = IdentitySource::Kubernetes {
+            hostname: "pod-b".to_string(),
+        };
+        let failing = || {
+            Err(PolicyError::IdentityError {
+                details: "no mount".to_string(),
+            })
+        };
+        assert!(cache.get_or_derive(missing.clone(), failing).is_err());
+        assert!(cache.get_or_derive(missing, failing).is_err());
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_principal_cache_invalidated_on_reload() {
+        let cache = PrincipalCache::new(8);
+        let source = IdentitySource::Certificate([7; 32]);
+
+        cache
+            .get_or_derive(source.clone(), || Ok(principal("before")))
+            .unwrap();
+        cache.invalidate();
+        assert!(cache.is_empty());
+        let after = cache.get_or_derive(source.clone(), || Ok(principal("after")));
+        assert_eq!(after.unwrap(), principal("after"));
+
+        // A principal derived across an invalidation is returned, not cached
+        let raced = cache.get_or_derive(IdentitySource::Certificate([8; 32]), || {
+            cache.invalidate();
+            Ok(principal("raced"))
+        });
+        assert_eq!(raced.unwrap(), principal("raced"));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_principal_cache_identity_change() {
+        let cache = PrincipalCache::new(8);
+        let spiffe = cert_der(SPIFFE_CLIENT_CERT);
+
+        let first = cache.client_principal(&spiffe).unwrap();
+        assert_eq!(first.app_name, "billing-agent");
+        assert_eq!(cache.client_principal(&spiffe).unwrap(), first);
+
+        // Another certificate on the same connection is parsed on its own
+        // and fails closed instead of reusing the cached identity
+        assert!(
+            cache
+                .client_principal(&cert_der(DNS_ONLY_CLIENT_CERT))
+                .is_err()
+        );
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    #[serial]
+    fn test_pod_principal_follows_dev_overrides() {
+        let cache = PrincipalCache::new(8);
+        unsafe {
+            env::set_var("THOUGHTGATE_DEV_MODE", "true");
+            env::set_var("THOUGHTGATE_DEV_PRINCIPAL", "first-app");
+        }
+        assert_eq!(cache.pod_principal().unwrap().app_name, "first-app");
+
+        unsafe {
+            env::set_var("THOUGHTGATE_DEV_PRINCIPAL", "second-app");
+        }
+        assert_eq!(cache.pod_principal().unwrap().app_name, "second-app");
+        assert_eq!(cache.len(), 2);
+
+        unsafe {
+            env::remove_var("THOUGHTGATE_DEV_MODE");
+            env::remove_var("THOUGHTGATE_DEV_PRINCIPAL");
+        }
+    }
+
+    #[test]
+    fn test_parse_spiffe_id() {
+        assert_eq!(
+            parse_spiffe_id("spiffe://td/ns/prod/sa/api"),
+            Some(("prod".to_string(), "api".to_string()))
+        );
+        assert_eq!(parse_spiffe_id("spiffe://td/ns/prod"), None);
+        assert_eq!(parse_spiffe_id("spiffe://td/ns//sa/api"), None);
+        assert_eq!(parse_spiffe_id("https://td/ns/prod/sa/api"), None);
+    }
+
+    #[tokio::test]
+    async fn test_request_principal_prefers_client_identity() {
+        let client = Principal {
+            app_name: "client".to_string(),
+            namespace: "ns".to_string(),
+            service_account: "sa".to_string(),
+            roles: vec![],
+        };
+
+        let inside = with_client_principal(client.clone(), async { request_principal() }).await;
+
+        assert_eq!(inside.unwrap(), client);
+    }
+
     #[test]
     #[serial]
     fn test_dev_mode_principal() {
@@ -0,0 +1,336 @@
+//! Quarantine for policies that keep failing to evaluate.
+//!
+//! Implements: REQ-POL-001/F-001 (Policy Evaluation)
+//!
+//! A policy that errors (or panics) on every evaluation would otherwise
+//! turn every request it touches into an engine error. Once a policy fails
+//! `threshold` times within `window`, it is quarantined for `duration`:
+//! taken out of the evaluated set while the rest keep being evaluated.
+//!
+//! Taking a policy out must not open anything up:
+//! - A quarantined `permit` is removed, so what it allowed falls back to
+//!   the other policies (and default deny).
+//! - A quarantined `forbid` is replaced by an unconditional forbid with the
+//!   same scope, so every request it could have forbidden stays denied.
+//!
+//! # Configuration
+//!
+//! - `THOUGHTGATE_POLICY_QUARANTINE_THRESHOLD` (default: 5; 0 disables)
+//! - `THOUGHTGATE_POLICY_QUARANTINE_WINDOW_SECS` (default: 60)
+//! - `THOUGHTGATE_POLICY_QUARANTINE_SECS` (default: 300)
+
+use std::collections::VecDeque;
+use std::env;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use cedar_policy::{Effect, Policy, PolicyId, PolicySet};
+use dashmap::DashMap;
+use tracing::{error, warn};
+
+/// When a failing policy is quarantined, and for how long.
+///
+/// Implements: REQ-POL-001/F-001 (Policy Evaluation)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuarantineConfig {
+    /// Failures within `window` that quarantine a policy (0 disables)
+    pub threshold: u32,
+    /// Window the failures are counted in
+    pub window: Duration,
+    /// How long a policy stays quarantined
+    pub duration: Duration,
+}
+
+impl Default for QuarantineConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 5,
+            window: Duration::from_secs(60),
+            duration: Duration::from_secs(300),
+        }
+    }
+}
+
+impl QuarantineConfig {
+    /// Read the configuration from environment variables.
+    ///
+    /// Unparseable values are logged and the default is kept.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            threshold: env_setting("THOUGHTGATE_POLICY_QUARANTINE_THRESHOLD")
+                .unwrap_or(defaults.threshold),
+            window: env_setting("THOUGHTGATE_POLICY_QUARANTINE_WINDOW_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.window),
+            duration: env_setting("THOUGHTGATE_POLICY_QUARANTINE_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.duration),
+        }
+    }
+
+    /// Whether policies are quarantined at all.
+    pub fn is_enabled(&self) -> bool {
+        self.threshold > 0
+    }
+}
+
+fn env_setting<T: std::str::FromStr>(var: &str) -> Option<T> {
+    let value = env::var(var).ok()?;
+    match value.parse() {
+        Ok(parsed) => Some(parsed),
+        Err(_) => {
+            warn!(var = %var, value = %value, "Ignoring invalid policy quarantine setting");
+            None
+        }
+    }
+}
+
+/// Failure counts and currently quarantined policies.
+pub(crate) struct PolicyQuarantine {
+    config: QuarantineConfig,
+    /// Recent failure times per policy, oldest first
+    failures: DashMap<String, VecDeque<Instant>>,
+    /// Quarantined policies and when their quarantine ends
+    quarantined: DashMap<String, Instant>,
+    /// Number of quarantined policies, so evaluation can skip the map
+    /// when there are none
+    active: AtomicUsize,
+}
+
+impl PolicyQuarantine {
+    pub(crate) fn new(config: QuarantineConfig) -> Self {
+        Self {
+            config,
+            failures: DashMap::new(),
+            quarantined: DashMap::new(),
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn config(&self) -> &QuarantineConfig {
+        &self.config
+    }
+
+    /// Count a failed evaluation of `policy`.
+    ///
+    /// Returns `true` if this failure quarantines it.
+    pub(crate) fn record_failure(&self, policy: &str) -> bool {
+        if !self.config.is_enabled() {
+            return false;
+        }
+        let now = Instant::now();
+        if self.quarantined.contains_key(policy) {
+            return false;
+        }
+        let reached = {
+            let mut failures = self.failures.entry(policy.to_string()).or_default();
+            failures.push_back(now);
+            while failures
+                .front()
+                .is_some_and(|t| now.duration_since(*t) > self.config.window)
+            {
+                failures.pop_front();
+            }
+            failures.len() >= self.config.threshold as usize
+        };
+        if !reached {
+            return false;
+        }
+        self.failures.remove(policy);
+        // A concurrent failure may have quarantined it first
+        let first = self
+            .quarantined
+            .insert(policy.to_string(), now + self.config.duration)
+            .is_none();
+        self.active.store(self.quarantined.len(), Ordering::Relaxed);
+        first
+    }
+
+    /// End the quarantine of policies whose time is up, returning them.
+    pub(crate) fn release_expired(&self) -> Vec<String> {
+        if self.active.load(Ordering::Relaxed) == 0 {
+            return Vec::new();
+        }
+        let now = Instant::now();
+        let mut released = Vec::new();
+        self.quarantined.retain(|policy, until| {
+            let keep = *until > now;
+            if !keep {
+                released.push(policy.clone());
+            }
+            keep
+        });
+        self.active.store(self.quarantined.len(), Ordering::Relaxed);
+        released.sort();
+        released
+    }
+
+    /// Currently quarantined policies, sorted.
+    pub(crate) fn quarantined(&self) -> Vec<String> {
+        if self.active.load(Ordering::Relaxed) == 0 {
+            return Vec::new();
+        }
+        let mut policies: Vec<String> = self
+            .quarantined
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect();
+        policies.sort();
+        policies
+    }
+
+    /// Forget all failures and quarantines, e.g. after a reload.
+    pub(crate) fn clear(&self) {
+        self.failures.clear();
+        self.quarantined.clear();
+        self.active.store(0, Ordering::Relaxed);
+    }
+}
+
+/// `policies` with the `quarantined` ones taken out, per the module docs.
+pub(crate) fn without_quarantined(policies: &PolicySet, quarantined: &[String]) -> PolicySet {
+    let mut active = policies.clone();
+    for id in quarantined {
+        let id = PolicyId::new(id);
+        let Some(policy) = policies.policy(&id) else {
+            continue;
+        };
+        let scope = match policy.effect() {
+            Effect::Permit => None,
+            Effect::Forbid => match static_copy(policy, false) {
+                Some(scope) => Some(scope),
+                None => {
+                    // Keep the original rather than lose the forbid
+                    error!(policy = %id, "Failed to narrow quarantined forbid, keeping it");
+                    continue;
+                }
+            },
+        };
+        let removed = if policy.is_static() {
+            active.remove_static(id.clone()).map(drop)
+        } else {
+            active.unlink(id.clone()).map(drop)
+        };
+        if let Err(e) = removed {
+            error!(policy = %id, error = %e, "Failed to quarantine policy");
+            continue;
+        }
+        if let Some(scope) = scope
+            && let Err(e) = active.add(scope)
+        {
+            error!(policy = %id, error = %e, "Failed to narrow quarantined forbid");
+        }
+    }
+    active
+}
+
+/// `policy` as a static policy (links filled in), optionally without its
+/// `when`/`unless` conditions.
+pub(crate) fn static_copy(policy: &Policy, keep_conditions: bool) -> Option<Policy> {
+    let mut json = policy.to_json().ok()?;
+    if !keep_conditions {
+        json["conditions"] = serde_json::json!([]);
+    }
+    Policy::from_json(Some(policy.id().clone()), json).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::str::FromStr;
+
+    fn quarantine(threshold: u32, window: Duration, duration: Duration) -> PolicyQuarantine {
+        PolicyQuarantine::new(QuarantineConfig {
+            threshold,
+            window,
+            duration,
+        })
+    }
+
+    #[test]
+    fn test_quarantine_after_threshold_within_window() {
+        let q = quarantine(3, Duration::from_secs(60), Duration::from_secs(60));
+
+        assert!(!q.record_failure("p"));
+        assert!(!q.record_failure("p"));
+        assert!(!q.record_failure("other"));
+        assert!(q.record_failure("p"));
+        assert_eq!(q.quarantined(), vec!["p".to_string()]);
+
+        // Already quarantined: not quarantined again
+        assert!(!q.record_failure("p"));
+
+        q.clear();
+        assert!(q.quarantined().is_empty());
+    }
+
+    #[test]
+    fn test_failures_outside_window_do_not_count() {
+        let q = quarantine(2, Duration::from_millis(20), Duration::from_secs(60));
+
+        assert!(!q.record_failure("p"));
+        std::thread::sleep(Duration::from_millis(40));
+        assert!(!q.record_failure("p"));
+        assert!(q.quarantined().is_empty());
+    }
+
+    #[test]
+    fn test_quarantine_expires() {
+        let q = quarantine(1, Duration::from_secs(60), Duration::from_millis(20));
+
+        assert!(q.record_failure("p"));
+        assert!(q.release_expired().is_empty());
+        std::thread::sleep(Duration::from_millis(40));
+        assert_eq!(q.release_expired(), vec!["p".to_string()]);
+        assert!(q.quarantined().is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_config_from_env() {
+        unsafe {
+            env::set_var("THOUGHTGATE_POLICY_QUARANTINE_THRESHOLD", "0");
+            env::set_var("THOUGHTGATE_POLICY_QUARANTINE_SECS", "soon");
+        }
+        let config = QuarantineConfig::from_env();
+        unsafe {
+            env::remove_var("THOUGHTGATE_POLICY_QUARANTINE_THRESHOLD");
+            env::remove_var("THOUGHTGATE_POLICY_QUARANTINE_SECS");
+        }
+
+        assert!(!config.is_enabled());
+        assert_eq!(config.window, QuarantineConfig::default().window);
+        assert_eq!(config.duration, QuarantineConfig::default().duration);
+    }
+
+    #[test]
+    fn test_disabled_never_quarantines() {
+        let q = quarantine(0, Duration::from_secs(60), Duration::from_secs(60));
+        for _ in 0..10 {
+            assert!(!q.record_failure("p"));
+        }
+    }
+
+    #[test]
+    fn test_without_quarantined_narrows_forbid_and_drops_permit() {
+        let policies = PolicySet::from_str(
+            r#"
+            permit(principal, action, resource) when { context.a };
+            forbid(principal, action, resource) when { context.b };
+            permit(principal, action, resource);
+            "#,
+        )
+        .unwrap();
+
+        let active =
+            without_quarantined(&policies, &["policy0".to_string(), "policy1".to_string()]);
+
+        assert!(active.policy(&PolicyId::new("policy0")).is_none());
+        let forbid = active.policy(&PolicyId::new("policy1")).unwrap();
+        assert_eq!(forbid.effect(), Effect::Forbid);
+        assert!(!forbid.to_cedar().unwrap_or_default().contains("when"));
+        assert!(active.policy(&PolicyId::new("policy2")).is_some());
+    }
+}
@@ -0,0 +1,567 @@
+//! Fixture-driven policy tests.
+//!
+//! Implements: REQ-POL-001/F-001 (Policy Evaluation)
+//!
+//! Policy authors list requests and the decision they expect in a YAML
+//! fixture file (JSON works too), and [`run`] evaluates each case against a
+//! [`CedarEngine`] exactly as Gate 3 would. Every mismatch is reported with
+//! an [`Explanation`] of the policies that decided it, so a policy change
+//! shows up in review as the cases it breaks:
+//!
+//! ```yaml
+//! - name: support reads tickets
+//!   principal: { app: support-bot, roles: [support] }
+//!   resource: { tool: get_ticket, server: helpdesk, arguments: { id: 7 } }
+//!   expect: permit
+//!   determining_policy: support-read
+//! - name: refunds need finance approval
+//!   principal: { app: support-bot, roles: [support] }
+//!   resource: { tool: refund, arguments: { amount: 500 } }
+//!   context: { policy_id: refunds }
+//!   expect: permit
+//!   approval: finance
+//! - name: nobody deletes users
+//!   principal: { app: admin-bot, roles: [admin] }
+//!   resource: { tool: delete_user }
+//!   expect: forbid
+//! ```
+//!
+//! Cedar only permits or forbids. A case can also pin the policy that
+//! decided it (`determining_policy`, by `@id` or Cedar ID) and the
+//! `@thoughtgate_approval` workflow a permit selects (`approval`).
+//! Evaluation time defaults to the Unix epoch so results do not depend on
+//! when the tests run; set `context.timestamp` for time-based policies.
+//!
+//! The `policy_test` binary runs fixture files against the policies the
+//! proxy would load.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::engine::CedarEngine;
+use super::{
+    AttrType, AttrValue, CedarContext, CedarDecision, CedarRequest, CedarResource, Explanation,
+    Principal, TimeContext,
+};
+
+/// Errors loading a fixture file.
+#[derive(Debug, Error)]
+pub enum TestkitError {
+    /// The file could not be read.
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        /// Fixture file path
+        path: String,
+        /// Underlying error
+        source: std::io::Error,
+    },
+
+    /// The file is not a list of test cases.
+    #[error("Invalid policy test fixture: {details}")]
+    Parse {
+        /// Parser error
+        details: String,
+    },
+
+    /// A case does not describe a request that can be evaluated.
+    #[error("Policy test `{name}`: {details}")]
+    InvalidCase {
+        /// Case name
+        name: String,
+        /// What is wrong with it
+        details: String,
+    },
+}
+
+/// One request and the decision policies should reach for it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyTestCase {
+    /// Name shown in the report.
+    pub name: String,
+    /// Who makes the request.
+    pub principal: TestPrincipal,
+    /// What is requested.
+    pub resource: TestResource,
+    /// Context from the governance rule, and the evaluation time.
+    #[serde(default)]
+    pub context: TestContext,
+    /// Expected decision.
+    pub expect: Expectation,
+    /// Policy expected to decide the request, by `@id` or Cedar ID.
+    #[serde(default)]
+    pub determining_policy: Option<String>,
+    /// `@thoughtgate_approval` workflow a permit is expected to select.
+    #[serde(default)]
+    pub approval: Option<String>,
+}
+
+/// Principal of a [`PolicyTestCase`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestPrincipal {
+    /// Application name.
+    pub app: String,
+    /// Kubernetes namespace.
+    #[serde(default)]
+    pub namespace: String,
+    /// Kubernetes ServiceAccount.
+    #[serde(default)]
+    pub service_account: String,
+    /// Roles the application holds.
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+/// Resource of a [`PolicyTestCase`]: exactly one of `tool` and `method`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestResource {
+    /// Tool name, for a `tools/call`.
+    #[serde(default)]
+    pub tool: Option<String>,
+    /// MCP method, for anything else.
+    #[serde(default)]
+    pub method: Option<String>,
+    /// Upstream server identifier.
+    #[serde(default = "default_server")]
+    pub server: String,
+    /// Tool arguments.
+    #[serde(default)]
+    pub arguments: Option<serde_json::Value>,
+    /// Catalog attributes.
+    #[serde(default)]
+    pub attributes: BTreeMap<String, AttrValue>,
+}
+
+/// Context of a [`PolicyTestCase`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestContext {
+    /// `policy_id` of the governance rule that sent the request to Cedar.
+    #[serde(default)]
+    pub policy_id: String,
+    /// Source that matched (default: the resource's server).
+    #[serde(default)]
+    pub source_id: Option<String>,
+    /// Evaluation time as a Unix timestamp (default: 0).
+    #[serde(default)]
+    pub timestamp: i64,
+}
+
+/// A Cedar decision, as written in fixtures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Expectation {
+    /// The request is permitted.
+    Permit,
+    /// The request is forbidden.
+    Forbid,
+}
+
+impl fmt::Display for Expectation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Expectation::Permit => "permit",
+            Expectation::Forbid => "forbid",
+        })
+    }
+}
+
+/// Result of evaluating one [`PolicyTestCase`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CaseOutcome {
+    /// Case name.
+    pub name: String,
+    /// Decision the policies reached.
+    pub decision: Expectation,
+    /// Why the case failed, if it did.
+    pub failure: Option<String>,
+    /// Which policies decided the request.
+    pub explanation: Explanation,
+}
+
+impl CaseOutcome {
+    /// Whether the policies did what the case expected.
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Outcomes of a fixture run, in case order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TestReport {
+    /// One outcome per case.
+    pub outcomes: Vec<CaseOutcome>,
+}
+
+impl TestReport {
+    /// Whether every case passed.
+    pub fn passed(&self) -> bool {
+        self.outcomes.iter().all(CaseOutcome::passed)
+    }
+
+    /// The cases that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &CaseOutcome> {
+        self.outcomes.iter().filter(|outcome| !outcome.passed())
+    }
+}
+
+impl fmt::Display for TestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for outcome in self.failures() {
+            writeln!(
+                f,
+                "FAIL {}: {}",
+                outcome.name,
+                outcome.failure.as_deref().unwrap_or_default()
+            )?;
+            let explanation = &outcome.explanation;
+            match &explanation.determining_policy {
+                Some(policy) => writeln!(f, "  decided by {policy}")?,
+                None => writeln!(f, "  no Cedar policy matched")?,
+            }
+            for matched in &explanation.matched_conditions {
+                writeln!(f, "  {} {}:", matched.effect, matched.policy_id)?;
+                for line in matched.condition.lines() {
+                    writeln!(f, "    {line}")?;
+                }
+            }
+        }
+        let failed = self.failures().count();
+        write!(
+            f,
+            "{} passed, {failed} failed",
+            self.outcomes.len() - failed
+        )
+    }
+}
+
+fn default_server() -> String {
+    crate::transport::DEFAULT_SOURCE_ID.to_string()
+}
+
+impl PolicyTestCase {
+    /// Build the Cedar request this case describes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TestkitError::InvalidCase` if not exactly one of
+    /// `resource.tool` and `resource.method` is set, or a method has
+    /// arguments.
+    pub fn to_cedar_request(&self) -> Result<CedarRequest, TestkitError> {
+        let invalid = |details: &str| TestkitError::InvalidCase {
+            name: self.name.clone(),
+            details: details.to_string(),
+        };
+        let r = &self.resource;
+        let resource = match (&r.tool, &r.method) {
+            (Some(name), None) => CedarResource::ToolCall {
+                name: name.clone(),
+                server: r.server.clone(),
+                arguments: r.arguments.clone().unwrap_or_else(|| serde_json::json!({})),
+                attributes: r.attributes.clone(),
+            },
+            (None, Some(method)) if r.arguments.is_none() => CedarResource::McpMethod {
+                method: method.clone(),
+                server: r.server.clone(),
+                attributes: r.attributes.clone(),
+            },
+            (None, Some(_)) => return Err(invalid("only tool calls have `arguments`")),
+            _ => return Err(invalid("exactly one of `tool` and `method` is required")),
+        };
+        Ok(CedarRequest {
+            principal: Principal {
+                app_name: self.principal.app.clone(),
+                namespace: self.principal.namespace.clone(),
+                service_account: self.principal.service_account.clone(),
+                roles: self.principal.roles.clone(),
+            },
+            context: CedarContext {
+                policy_id: self.context.policy_id.clone(),
+                source_id: self
+                    .context
+                    .source_id
+                    .clone()
+                    .unwrap_or_else(|| r.server.clone()),
+                time: TimeContext::from_timestamp(self.context.timestamp),
+            },
+            resource,
+        })
+    }
+}
+
+/// Parse fixture text: a YAML or JSON list of cases.
+///
+/// # Errors
+///
+/// Returns `TestkitError::Parse` if the text is not a list of cases, and
+/// `TestkitError::InvalidCase` for a case that cannot be evaluated.
+pub fn parse_cases(text: &str) -> Result<Vec<PolicyTestCase>, TestkitError> {
+    let cases: Vec<PolicyTestCase> =
+        serde_saphyr::from_str(text).map_err(|e| TestkitError::Parse {
+            details: e.to_string(),
+        })?;
+    for case in &cases {
+        case.to_cedar_request()?;
+    }
+    Ok(cases)
+}
+
+/// Read and parse a fixture file.
+///
+/// # Errors
+///
+/// Returns `TestkitError::Io` if the file cannot be read, otherwise as
+/// [`parse_cases`].
+pub fn load_cases(path: &Path) -> Result<Vec<PolicyTestCase>, TestkitError> {
+    let text = std::fs::read_to_string(path).map_err(|source| TestkitError::Io {
+        path: path.display().to_string(),
+        source,
+    })?;
+    parse_cases(&text)
+}
+
+/// Catalog attribute types used by `cases`, for
+/// [`CedarEngine::new_with_attributes`].
+///
+/// Without them, policies that test catalog attributes fail schema
+/// validation. An attribute given different types keeps the first.
+pub fn attribute_types<'a>(
+    cases: impl IntoIterator<Item = &'a PolicyTestCase>,
+) -> BTreeMap<String, AttrType> {
+    let mut types = BTreeMap::new();
+    for case in cases {
+        for (key, value) in &case.resource.attributes {
+            types
+                .entry(key.clone())
+                .or_insert_with(|| value.attr_type());
+        }
+    }
+    types
+}
+
+/// Evaluate every case against `engine`.
+pub fn run(engine: &CedarEngine, cases: &[PolicyTestCase]) -> TestReport {
+    TestReport {
+        outcomes: cases.iter().map(|case| run_case(engine, case)).collect(),
+    }
+}
+
+/// Evaluate one case and compare the decision with its expectations.
+fn run_case(engine: &CedarEngine, case: &PolicyTestCase) -> CaseOutcome {
+    let request = match case.to_cedar_request() {
+        Ok(request) => request,
+        Err(e) => {
+            return CaseOutcome {
+                name: case.name.clone(),
+                decision: Expectation::Forbid,
+                failure: Some(e.to_string()),
+                explanation: Explanation::default(),
+            };
+        }
+    };
+
+    let cedar_decision = engine.evaluate_v2(&request);
+    let namespace = &request.principal.namespace;
+    let mut explanation = engine.explain_v2(&request, &cedar_decision);
+    let determining = explanation
+        .determining_policy
+        .as_deref()
+        .map(|id| (id.to_string(), engine.policy_display_name(namespace, id)));
+
+    let decision = match cedar_decision {
+        CedarDecision::Permit { .. } => Expectation::Permit,
+        CedarDecision::Forbid { .. } => Expectation::Forbid,
+    };
+    let workflow = match &cedar_decision {
+        CedarDecision::Permit {
+            determining_policies,
+        } => engine
+            .annotations()
+            .get_workflow(determining_policies)
+            .map(str::to_string),
+        CedarDecision::Forbid { .. } => None,
+    };
+
+    let failure = if decision != case.expect {
+        // A forbid no policy decided says why (default deny, engine error)
+        let reason = match &cedar_decision {
+            CedarDecision::Forbid { reason, .. } if determining.is_none() => {
+                format!(" ({reason})")
+            }
+            _ => String::new(),
+        };
+        Some(format!("expected {}, got {decision}{reason}", case.expect))
+    } else if let Some(expected) = &case.determining_policy
+        && !determining
+            .as_ref()
+            .is_some_and(|(id, name)| expected == id || expected == name)
+    {
+        Some(format!(
+            "expected determining policy `{expected}`, got {}",
+            determining
+                .as_ref()
+                .map_or_else(|| "none".to_string(), |(_, name)| format!("`{name}`")),
+        ))
+    } else if let Some(expected) = &case.approval
+        && workflow.as_ref() != Some(expected)
+    {
+        Some(format!(
+            "expected approval workflow `{expected}`, got {}",
+            workflow.map_or_else(|| "none".to_string(), |w| format!("`{w}`")),
+        ))
+    } else {
+        None
+    };
+
+    // Report policies by the names their authors gave them
+    if let Some((_, name)) = determining {
+        explanation.determining_policy = Some(name);
+    }
+    for matched in &mut explanation.matched_conditions {
+        matched.policy_id = engine.policy_display_name(namespace, &matched.policy_id);
+    }
+
+    CaseOutcome {
+        name: case.name.clone(),
+        decision,
+        failure,
+        explanation,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    const POLICIES: &str = r#"
+        @id("support-read")
+        permit(
+            principal in ThoughtGate::Role::"support",
+            action == ThoughtGate::Action::"tools/call",
+            resource
+        ) when { resource.name like "get_*" };
+
+        @id("refunds")
+        @thoughtgate_approval("finance")
+        permit(
+            principal in ThoughtGate::Role::"support",
+            action == ThoughtGate::Action::"tools/call",
+            resource
+        ) when {
+            context.policy_id == "refunds" &&
+            resource has risk && resource.risk == "low"
+        };
+
+        @id("no-deletes")
+        forbid(
+            principal,
+            action == ThoughtGate::Action::"tools/call",
+            resource
+        ) when { resource.name like "delete_*" };
+    "#;
+
+    const FIXTURE: &str = r#"
+- name: support reads tickets
+  principal: { app: support-bot, roles: [support] }
+  resource: { tool: get_ticket, server: helpdesk, arguments: { id: 7 } }
+  expect: permit
+  determining_policy: support-read
+- name: refunds need finance approval
+  principal: { app: support-bot, roles: [support] }
+  resource: { tool: refund, attributes: { risk: low } }
+  context: { policy_id: refunds }
+  expect: permit
+  approval: finance
+- name: support deletes users
+  principal: { app: support-bot, roles: [support] }
+  resource: { tool: delete_user }
+  expect: permit
+- name: guest reads tickets
+  principal: { app: guest }
+  resource: { tool: get_ticket }
+  expect: forbid
+- name: reads pinned to the wrong policy
+  principal: { app: support-bot, roles: [support] }
+  resource: { tool: get_ticket }
+  expect: permit
+  determining_policy: refunds
+"#;
+
+    fn engine_for(cases: &[PolicyTestCase]) -> CedarEngine {
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", POLICIES);
+        }
+        let engine = CedarEngine::new_with_attributes(&attribute_types(cases))
+            .expect("Failed to create engine");
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+        engine
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_reports_mismatches_with_explanations() {
+        let cases = parse_cases(FIXTURE).unwrap();
+        let report = run(&engine_for(&cases), &cases);
+
+        let failures: Vec<_> = report.failures().collect();
+        assert_eq!(failures.len(), 2, "{report}");
+        assert!(!report.passed());
+
+        let deletes = failures[0];
+        assert_eq!(deletes.name, "support deletes users");
+        assert_eq!(deletes.decision, Expectation::Forbid);
+        assert_eq!(
+            deletes.failure.as_deref(),
+            Some("expected permit, got forbid")
+        );
+        assert_eq!(
+            deletes.explanation.determining_policy.as_deref(),
+            Some("no-deletes")
+        );
+
+        assert_eq!(
+            failures[1].failure.as_deref(),
+            Some("expected determining policy `refunds`, got `support-read`")
+        );
+
+        let text = report.to_string();
+        assert!(text.contains("FAIL support deletes users"), "{text}");
+        assert!(text.contains("forbid no-deletes:"), "{text}");
+        assert!(text.ends_with("3 passed, 2 failed"), "{text}");
+    }
+
+    #[test]
+    fn test_parse_rejects_unusable_cases() {
+        let json = r#"[{"name": "json works", "principal": {"app": "a"},
+                        "resource": {"method": "resources/read"}, "expect": "forbid"}]"#;
+        assert_eq!(parse_cases(json).unwrap().len(), 1);
+
+        let both = r#"
+- name: ambiguous
+  principal: { app: a }
+  resource: { tool: t, method: m }
+  expect: permit
+"#;
+        assert!(matches!(
+            parse_cases(both),
+            Err(TestkitError::InvalidCase { name, .. }) if name == "ambiguous"
+        ));
+
+        let typo = r#"
+- name: typo
+  principal: { app: a }
+  resource: { tool: t }
+  expected: permit
+"#;
+        assert!(matches!(parse_cases(typo), Err(TestkitError::Parse { .. })));
+    }
+}
@@ -16,9 +16,12 @@
 //! | No time context | `context.time.*` for time-based rules |
 //! | No policy_id binding | `context.policy_id` from YAML rules |
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use super::Principal;
+use serde::{Deserialize, Serialize};
+
+use super::{PolicyRequest, Principal, Resource};
+use crate::config::RejectCode;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // v0.2 Request Types (REQ-POL-001 §6.1)
@@ -42,6 +45,43 @@ pub struct CedarRequest {
     pub context: CedarContext,
 }
 
+impl CedarRequest {
+    /// The request as content classifiers see it.
+    ///
+    /// Classifiers read the arguments from the body, so the resource
+    /// keeps only its name, server and catalog attributes.
+    ///
+    /// Implements: REQ-CORE-006 (Content Classification)
+    pub fn policy_view(&self) -> PolicyRequest {
+        let resource = match &self.resource {
+            CedarResource::ToolCall {
+                name,
+                server,
+                attributes,
+                ..
+            } => Resource::ToolCall {
+                name: name.clone(),
+                server: server.clone(),
+                attributes: attributes.clone(),
+            },
+            CedarResource::McpMethod {
+                method,
+                server,
+                attributes,
+            } => Resource::McpMethod {
+                method: method.clone(),
+                server: server.clone(),
+                attributes: attributes.clone(),
+            },
+        };
+        PolicyRequest {
+            principal: self.principal.clone(),
+            resource,
+            context: None,
+        }
+    }
+}
+
 /// v0.2 Resource with arguments for inspection.
 ///
 /// Implements: REQ-POL-001/§6.1 (Resource)
@@ -59,6 +99,9 @@ pub enum CedarResource {
         /// Tool arguments for inspection.
         /// Accessible in Cedar as `resource.arguments.*`.
         arguments: serde_json::Value,
+        /// Catalog attributes (e.g., `risk`), empty if none.
+        /// Accessible in Cedar as `resource.<key>`.
+        attributes: BTreeMap<String, AttrValue>,
     },
 
     /// Generic MCP method (non-tool requests).
@@ -67,6 +110,8 @@ pub enum CedarResource {
         method: String,
         /// Source ID from config.
         server: String,
+        /// Catalog attributes, empty if none.
+        attributes: BTreeMap<String, AttrValue>,
     },
 }
 
@@ -86,6 +131,82 @@ impl CedarResource {
             CedarResource::McpMethod { server, .. } => server,
         }
     }
+
+    /// Get the catalog attributes.
+    pub fn attributes(&self) -> &BTreeMap<String, AttrValue> {
+        match self {
+            CedarResource::ToolCall { attributes, .. } => attributes,
+            CedarResource::McpMethod { attributes, .. } => attributes,
+        }
+    }
+
+    /// Replace the catalog attributes.
+    pub fn set_attributes(&mut self, new: BTreeMap<String, AttrValue>) {
+        match self {
+            CedarResource::ToolCall { attributes, .. } => *attributes = new,
+            CedarResource::McpMethod { attributes, .. } => *attributes = new,
+        }
+    }
+}
+
+/// Value of a resource attribute from the tool-metadata catalog.
+///
+/// Implements: REQ-POL-001/§6.1 (Resource)
+///
+/// Deserialized untagged, so YAML `true`, `3` and `high` map to
+/// `Bool`, `Long` and `String` respectively.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AttrValue {
+    /// Cedar `Bool`.
+    Bool(bool),
+    /// Cedar `Long`.
+    Long(i64),
+    /// Cedar `String`.
+    String(String),
+}
+
+impl AttrValue {
+    /// Get the Cedar type of this value.
+    pub fn attr_type(&self) -> AttrType {
+        match self {
+            AttrValue::Bool(_) => AttrType::Bool,
+            AttrValue::Long(_) => AttrType::Long,
+            AttrValue::String(_) => AttrType::String,
+        }
+    }
+}
+
+/// Cedar type of a catalog attribute, used to declare it in the schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttrType {
+    /// `Bool`
+    Bool,
+    /// `Long`
+    Long,
+    /// `String`
+    String,
+}
+
+impl AttrType {
+    /// Type name as used in Cedar JSON schemas.
+    pub fn schema_name(&self) -> &'static str {
+        match self {
+            AttrType::Bool => "Boolean",
+            AttrType::Long => "Long",
+            AttrType::String => "String",
+        }
+    }
+}
+
+impl std::fmt::Display for AttrType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttrType::Bool => write!(f, "Bool"),
+            AttrType::Long => write!(f, "Long"),
+            AttrType::String => write!(f, "String"),
+        }
+    }
 }
 
 /// Context passed from YAML governance rules to Cedar.
@@ -222,7 +343,10 @@ pub enum CedarDecision {
     ///
     /// ThoughtGate denies immediately with -32003 PolicyDenied.
     Forbid {
-        /// Reason for denial (safe for logging).
+        /// Why the request was denied.
+        code: RejectCode,
+        /// Detailed reason for logs and the audit trail. Not returned to
+        /// the client unless `code` is opted in with `disclose_reasons`.
         reason: String,
         /// Policy IDs that caused the denial.
         policy_ids: Vec<String>,
@@ -253,6 +377,7 @@ impl CedarDecision {
     /// Create a default Forbid decision (no matching policy).
     pub fn default_forbid() -> Self {
         CedarDecision::Forbid {
+            code: RejectCode::DefaultDeny,
             reason: "No policy permits this action (default-deny)".to_string(),
             policy_ids: vec![],
         }
@@ -345,10 +470,55 @@ pub struct CedarStats {
     /// Number of Forbid decisions.
     pub forbid_count: u64,
 
+    /// Number of engine errors turned into a Permit by fail-open.
+    /// Also counted in `permit_count`.
+    pub fail_open_count: u64,
+
     /// Average evaluation time in microseconds.
     pub avg_eval_time_us: u64,
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// Decision Explanations (REQ-POL-001 §6.3)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Why a policy evaluation reached its outcome, for debugging.
+///
+/// Exposes policy internals (IDs and source), so it is only surfaced to
+/// operators - audit records and the admin debug endpoint - and never in
+/// responses to proxy clients.
+///
+/// Implements: REQ-POL-001/§6.3 (Decision Explanation)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Explanation {
+    /// IDs of every policy the request satisfied, sorted.
+    pub contributing_policies: Vec<String>,
+
+    /// The policy that decided the outcome: a permit of the granted action,
+    /// or a forbid on a denial (the first by ID if several apply). `None`
+    /// when nothing matched and the request was denied by default.
+    pub determining_policy: Option<String>,
+
+    /// The satisfied policies with the conditions they matched on.
+    pub matched_conditions: Vec<MatchedCondition>,
+}
+
+/// One satisfied policy in an [`Explanation`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchedCondition {
+    /// Policy ID.
+    pub policy_id: String,
+
+    /// Action it was satisfied for (e.g. `Forward` or `tools/call`).
+    pub action: String,
+
+    /// `permit` or `forbid`.
+    pub effect: String,
+
+    /// The policy's scope and conditions, as written.
+    pub condition: String,
+}
+
 /// Policy information for debugging/observability.
 ///
 /// Implements: REQ-POL-001/§6.3 (PolicyInfo)
@@ -385,6 +555,7 @@ mod tests {
                 "currency": "USD",
                 "destination": "account-123"
             }),
+            attributes: Default::default(),
         };
 
         assert_eq!(resource.name(), "transfer_funds");
@@ -396,6 +567,7 @@ mod tests {
         let resource = CedarResource::McpMethod {
             method: "resources/read".to_string(),
             server: "upstream".to_string(),
+            attributes: Default::default(),
         };
 
         assert_eq!(resource.name(), "resources/read");
@@ -453,6 +625,7 @@ mod tests {
     #[test]
     fn test_cedar_decision_forbid() {
         let decision = CedarDecision::Forbid {
+            code: RejectCode::Forbidden,
             reason: "Amount exceeds limit".to_string(),
             policy_ids: vec!["high_value_block".to_string()],
         };
@@ -525,6 +698,7 @@ mod tests {
                     "quantity": 100,
                     "action": "buy"
                 }),
+                attributes: Default::default(),
             },
             context: CedarContext {
                 policy_id: "trading_policy".to_string(),
@@ -10,7 +10,7 @@
 //! | Port | Name | Purpose |
 //! |------|------|---------|
 //! | 7467 | Outbound | Client requests → upstream (main proxy) |
-//! | 7468 | Inbound | Reserved for callbacks/webhooks (not wired in v0.2) |
+//! | 7468 | Inbound | Approval callbacks (`POST /approval/callback`) |
 //! | 7469 | Admin | Health checks, metrics, admin API |
 //!
 //! # Environment Variables
@@ -23,12 +23,10 @@
 /// Configurable via `THOUGHTGATE_OUTBOUND_PORT` environment variable.
 pub const DEFAULT_OUTBOUND_PORT: u16 = 7467;
 
-/// Default inbound port reserved for future callbacks/webhooks.
+/// Default inbound port for approval callbacks.
 ///
-/// In v0.2, this port is bound but not wired to any handlers.
-/// It will be used in future versions for:
-/// - Webhook callbacks from approval systems
-/// - Push notifications from upstream servers
+/// Serves `POST /approval/callback` when an approval engine is running;
+/// otherwise the port is bound but not wired to any handlers.
 pub const DEFAULT_INBOUND_PORT: u16 = 7468;
 
 /// Default admin port for health checks and metrics.
@@ -83,15 +81,13 @@ pub fn admin_port() -> u16 {
         .unwrap_or(DEFAULT_ADMIN_PORT)
 }
 
-/// Get the inbound port (reserved, not configurable in v0.2).
+/// Get the inbound port (not configurable in v0.2).
 ///
-/// This port is bound but not wired to handlers. It reserves the port
-/// for future use with callback/webhook functionality.
+/// Serves approval callbacks (see [`crate::callback`]).
 ///
 /// # Note
 ///
-/// In v0.2, this always returns the default value. Configuration will
-/// be added when the inbound functionality is implemented.
+/// In v0.2, this always returns the default value.
 pub fn inbound_port() -> u16 {
     DEFAULT_INBOUND_PORT
 }
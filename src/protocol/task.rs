@@ -47,6 +47,26 @@ impl Sep1686TaskId {
         Self(format!("{}{}", TASK_ID_PREFIX, body))
     }
 
+    /// Derives a task ID deterministically from `seed`.
+    ///
+    /// Implements: REQ-CORE-007/§6.5
+    ///
+    /// The body is drawn from the same alphabet and has the same length as
+    /// [`Sep1686TaskId::new`], so derived and random IDs are interchangeable.
+    /// The same seed always yields the same ID.
+    #[must_use]
+    pub fn derive(seed: &[u8]) -> Self {
+        use sha2::{Digest, Sha256};
+
+        let digest = Sha256::digest(seed);
+        let body: String = digest
+            .iter()
+            .take(TASK_ID_BODY_LENGTH)
+            .map(|byte| nanoid::alphabet::SAFE[usize::from(byte & 63)])
+            .collect();
+        Self(format!("{}{}", TASK_ID_PREFIX, body))
+    }
+
     /// Creates a task ID from a raw string without validation.
     ///
     /// Use this for upstream task IDs that don't have the `tg_` prefix.
@@ -420,6 +440,27 @@ mod tests {
         );
     }
 
+    /// Tests derived task IDs are stable and shaped like random ones.
+    ///
+    /// Verifies: REQ-CORE-007/§6.5
+    #[test]
+    fn test_task_id_derive() {
+        let id = Sep1686TaskId::derive(b"seed");
+        assert_eq!(id, Sep1686TaskId::derive(b"seed"));
+        assert_ne!(id, Sep1686TaskId::derive(b"other seed"));
+
+        assert!(id.is_thoughtgate_owned());
+        assert_eq!(
+            id.as_str().len(),
+            TASK_ID_PREFIX.len() + TASK_ID_BODY_LENGTH
+        );
+        assert!(
+            id.as_str()[TASK_ID_PREFIX.len()..]
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        );
+    }
+
     /// Tests task ID ownership detection.
     ///
     /// Verifies: REQ-CORE-007/F-006.3
@@ -17,7 +17,14 @@
 //! - Deferred: REQ-CORE-001 Section 3.2 (Network Optimization)
 //! - Deferred: REQ-CORE-002 Section 3.2 (Memory Management)
 
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::time::Duration;
+use tracing::warn;
+
+use crate::config::ConfigError;
+use crate::policy::PolicyDecision;
+use crate::proxy_body::SseBlockMode;
 
 /// Runtime configuration for the ThoughtGate proxy.
 ///
@@ -39,9 +46,22 @@ pub struct ProxyConfig {
     /// Enable TCP_NODELAY (Nagle's algorithm disabled)
     pub tcp_nodelay: bool,
 
-    /// TCP keepalive interval in seconds
+    /// Idle time in seconds before TCP keepalive probes start
     pub tcp_keepalive_secs: u64,
 
+    /// Time between TCP keepalive probes; `None` keeps the OS default.
+    /// Ignored on platforms without `TCP_KEEPINTVL`.
+    pub tcp_keepalive_interval: Option<Duration>,
+
+    /// Set SO_REUSEADDR on the listening socket, so a restarted proxy can
+    /// bind while old connections sit in TIME_WAIT. Ignored on Windows,
+    /// where the option lets another process take over the port.
+    pub tcp_reuseaddr: bool,
+
+    /// Accept backlog passed to `listen(2)`. The kernel may cap it (e.g.
+    /// `net.core.somaxconn` on Linux).
+    pub listen_backlog: u32,
+
     /// Per-chunk read timeout
     pub stream_read_timeout: Duration,
 
@@ -57,6 +77,94 @@ pub struct ProxyConfig {
     /// Socket buffer size (SO_RCVBUF / SO_SNDBUF)
     pub socket_buffer_size: usize,
 
+    /// Most bytes a streamed response may carry before it is aborted.
+    /// Counted as the body passes through, so nothing is buffered.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-004 (Slow-Read Protection)
+    pub max_stream_bytes: Option<u64>,
+
+    /// Overall deadline for a request, bounding inspection, any approval
+    /// wait, the upstream call and the streamed response together. A
+    /// shorter `grpc-timeout` or `X-Deadline` from the client takes
+    /// precedence.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+    pub request_deadline: Option<Duration>,
+
+    /// Most requests handled at once across all upstreams. Excess requests
+    /// are queued (see `admission_queue_depth`) or shed with 503.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 Section 3.2 (Concurrency Limit)
+    pub max_in_flight_requests: Option<usize>,
+
+    /// Most requests handled at once for any one upstream.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 Section 3.2 (Concurrency Limit)
+    pub max_in_flight_per_upstream: Option<usize>,
+
+    /// Requests that may wait for an in-flight limit before being shed.
+    /// Zero sheds immediately.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 Section 3.2 (Concurrency Limit)
+    pub admission_queue_depth: usize,
+
+    /// How long a queued request waits before being shed.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 Section 3.2 (Concurrency Limit)
+    pub admission_queue_wait: Duration,
+
+    /// How long a client has to send a request's headers, counted from the
+    /// connection opening or, between HTTP/1 requests, from the next
+    /// request's first byte. Slower connections are closed.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+    pub header_read_timeout: Option<Duration>,
+
+    /// How long a connection may sit with no request in flight before it
+    /// is closed.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+    pub idle_connection_timeout: Option<Duration>,
+
+    /// Longest the listener waits for a response to start once a request's
+    /// headers are in; the client then gets 408 and the connection is
+    /// closed. Unlike `request_deadline` it is not passed upstream, so it
+    /// must allow for blocking approval waits. Off by default.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+    pub response_start_timeout: Option<Duration>,
+
+    /// Most headers a request may carry; more are refused with 431
+    /// before any policy work.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 Section 3.2 (Header Limits)
+    pub max_request_headers: usize,
+
+    /// Most bytes of header names and values a request may carry; more
+    /// are refused with 431 before any policy work.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 Section 3.2 (Header Limits)
+    pub max_request_header_bytes: usize,
+
+    /// Most streams an HTTP/2 client may have open at once on one
+    /// connection, advertised in the server's SETTINGS. Streams over the
+    /// limit are refused with RST_STREAM(REFUSED_STREAM).
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 Section 3.2 (Network Optimization)
+    pub http2_max_concurrent_streams: u32,
+
     // NOTE: metrics_port removed - metrics are now served on the admin port (7469)
     // via the AdminServer module. See src/admin.rs and src/ports.rs.
 
@@ -84,12 +192,115 @@ pub struct ProxyConfig {
     /// - Implements: REQ-CORE-002 Section 3.2 (Memory Management)
     pub resp_buffer_max: usize,
 
+    /// Total bytes that may be reserved across all in-flight Amber Path
+    /// buffers. Each buffer reserves its `Content-Length`, or the applicable
+    /// per-request limit when the length is unknown.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-002 Section 3.2 (Memory Management)
+    pub buffer_budget: usize,
+
+    /// How long a new buffer waits for budget before 503. Zero rejects
+    /// immediately.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-002 Section 3.2 (Memory Management)
+    pub buffer_budget_wait: Duration,
+
     /// Total timeout for the entire Amber Path lifecycle (buffering + inspection).
     /// Operations exceeding this receive 408 Request Timeout.
     ///
     /// # Traceability
     /// - Implements: REQ-CORE-002 Section 3.2 (THOUGHTGATE_BUFFER_TIMEOUT_SECS)
     pub buffer_timeout: Duration,
+
+    /// Amber Path bodies larger than this spill to an unlinked temp file
+    /// instead of staying in memory. Unset keeps every buffer in memory.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-002 Section 3.2 (Memory Management)
+    pub buffer_spill_threshold: Option<usize>,
+
+    /// Directory for spilled buffers; the system temp directory if unset.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-002 Section 3.2 (Memory Management)
+    pub buffer_spill_dir: Option<PathBuf>,
+
+    /// Total bytes that spilled buffers may occupy on disk at once. A body
+    /// that would exceed it is rejected with 503 like the memory budget.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-002 Section 3.2 (Memory Management)
+    pub buffer_disk_budget: usize,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Path Overrides (REQ-CORE-001 / REQ-CORE-002)
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Send Green requests with a known length below this through Amber.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-002 Section 3.2 (Path Overrides)
+    pub force_amber_below_bytes: Option<u64>,
+
+    /// Stream Amber requests with a known length above this as Green.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 Section 3.2 (Path Overrides)
+    pub force_green_above_bytes: Option<u64>,
+
+    /// MCP methods streamed upstream as soon as the method is read, without
+    /// waiting for the rest of the body. Only methods that pass through
+    /// without governance qualify; anything else is buffered as usual.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 (Zero-Copy Peeking Strategy)
+    pub early_forward_methods: Vec<String>,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Client Signaling (REQ-CORE-001)
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Report the governance outcome in `ThoughtGate-Decision` and
+    /// `ThoughtGate-Inspected` trailers on streamed responses. Only sent to
+    /// clients that accept trailers.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-003 (Trailer Support)
+    pub governance_trailers: bool,
+
+    /// Send `ThoughtGate-Decision: reject` and `ThoughtGate-Reject-Code`
+    /// headers on MCP responses refused by a governance gate. The code
+    /// matches the audit record's `reject_code`; the reason is never sent.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-OBS-002 (Audit Trail)
+    pub reject_headers: bool,
+
+    /// How a streamed SSE response is ended when it is blocked partway
+    /// through: aborted, or closed after a `thoughtgate-blocked` event
+    /// giving the reason.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-002 F-003 (Response Governance)
+    pub sse_block_mode: SseBlockMode,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Response Compression (REQ-CORE-001)
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Gzip streamed responses on the fly for clients that accept it. Only
+    /// uncompressed responses of an allowed content type are compressed.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-001 (Zero-Copy Forwarding)
+    pub compress_responses: bool,
+
+    /// Responses with a smaller `Content-Length` are sent uncompressed.
+    /// Responses without one are compressed, as their size is unknown.
+    pub compress_min_bytes: u64,
+
+    /// Content types eligible for compression, e.g. `application/json`, or
+    /// `text/*` for every subtype.
+    pub compress_content_types: Vec<String>,
 }
 
 impl Default for ProxyConfig {
@@ -98,17 +309,50 @@ impl Default for ProxyConfig {
             // Green Path defaults
             tcp_nodelay: true,
             tcp_keepalive_secs: 60,
+            tcp_keepalive_interval: None,
+            tcp_reuseaddr: true,
+            listen_backlog: 1024,
             stream_read_timeout: Duration::from_secs(300),
             stream_write_timeout: Duration::from_secs(300),
             stream_total_timeout: Duration::from_secs(3600),
             max_concurrent_streams: 10000,
             socket_buffer_size: 262144, // 256 KB
+            max_stream_bytes: None,
+            request_deadline: None,
+            max_in_flight_requests: None,
+            max_in_flight_per_upstream: None,
+            admission_queue_depth: 0,
+            admission_queue_wait: Duration::from_millis(100),
+            header_read_timeout: Some(Duration::from_secs(30)),
+            idle_connection_timeout: Some(Duration::from_secs(300)),
+            response_start_timeout: None,
+            max_request_headers: 100,
+            max_request_header_bytes: 64 * 1024, // 64 KB
+            http2_max_concurrent_streams: 100,
 
             // Amber Path defaults (REQ-CORE-002 Section 3.2)
             max_concurrent_buffers: 100,
             req_buffer_max: 2 * 1024 * 1024,   // 2 MB
             resp_buffer_max: 10 * 1024 * 1024, // 10 MB
+            buffer_budget: 256 * 1024 * 1024,  // 256 MB
+            buffer_budget_wait: Duration::ZERO,
             buffer_timeout: Duration::from_secs(30),
+            buffer_spill_threshold: None,
+            buffer_spill_dir: None,
+            buffer_disk_budget: 1024 * 1024 * 1024, // 1 GB
+
+            // Path overrides are off unless configured
+            force_amber_below_bytes: None,
+            force_green_above_bytes: None,
+            early_forward_methods: Vec::new(),
+
+            governance_trailers: false,
+            reject_headers: false,
+            sse_block_mode: SseBlockMode::HardClose,
+
+            compress_responses: false,
+            compress_min_bytes: 1024,
+            compress_content_types: vec!["application/json".to_string(), "text/*".to_string()],
         }
     }
 }
@@ -116,15 +360,32 @@ impl Default for ProxyConfig {
 impl ProxyConfig {
     /// Load configuration from environment variables with defaults.
     ///
+    /// See [`crate::config::load`] to also apply the config file and CLI.
+    ///
     /// # Environment Variables (Green Path - REQ-CORE-001)
     ///
     /// - `THOUGHTGATE_TCP_NODELAY` (default: true)
     /// - `THOUGHTGATE_TCP_KEEPALIVE_SECS` (default: 60)
+    /// - `THOUGHTGATE_TCP_KEEPALIVE_INTERVAL_SECS` (default: OS default)
+    /// - `THOUGHTGATE_TCP_REUSEADDR` (default: true)
+    /// - `THOUGHTGATE_LISTEN_BACKLOG` (default: 1024)
     /// - `THOUGHTGATE_STREAM_READ_TIMEOUT_SECS` (default: 300)
     /// - `THOUGHTGATE_STREAM_WRITE_TIMEOUT_SECS` (default: 300)
     /// - `THOUGHTGATE_STREAM_TOTAL_TIMEOUT_SECS` (default: 3600)
     /// - `THOUGHTGATE_MAX_CONCURRENT_STREAMS` (default: 10000)
     /// - `THOUGHTGATE_SOCKET_BUFFER_SIZE` (default: 262144)
+    /// - `THOUGHTGATE_MAX_STREAM_BYTES` (default: unlimited)
+    /// - `THOUGHTGATE_REQUEST_DEADLINE_SECS` (default: none)
+    /// - `THOUGHTGATE_MAX_IN_FLIGHT_REQUESTS` (default: unlimited)
+    /// - `THOUGHTGATE_MAX_IN_FLIGHT_PER_UPSTREAM` (default: unlimited)
+    /// - `THOUGHTGATE_ADMISSION_QUEUE_DEPTH` (default: 0)
+    /// - `THOUGHTGATE_ADMISSION_QUEUE_WAIT_MS` (default: 100)
+    /// - `THOUGHTGATE_HEADER_READ_TIMEOUT_SECS` (default: 30, 0 disables)
+    /// - `THOUGHTGATE_IDLE_CONNECTION_TIMEOUT_SECS` (default: 300, 0 disables)
+    /// - `THOUGHTGATE_RESPONSE_START_TIMEOUT_SECS` (default: none, 0 disables)
+    /// - `THOUGHTGATE_MAX_REQUEST_HEADERS` (default: 100)
+    /// - `THOUGHTGATE_MAX_REQUEST_HEADER_BYTES` (default: 65536 = 64KB)
+    /// - `THOUGHTGATE_HTTP2_MAX_CONCURRENT_STREAMS` (default: 100)
     ///
     /// Note: THOUGHTGATE_METRICS_PORT is no longer used. Metrics are served on
     /// the admin port (default: 7469). See THOUGHTGATE_ADMIN_PORT.
@@ -134,79 +395,522 @@ impl ProxyConfig {
     /// - `THOUGHTGATE_MAX_CONCURRENT_BUFFERS` (default: 100)
     /// - `THOUGHTGATE_REQ_BUFFER_MAX` (default: 2097152 = 2MB)
     /// - `THOUGHTGATE_RESP_BUFFER_MAX` (default: 10485760 = 10MB)
+    /// - `THOUGHTGATE_BUFFER_BUDGET` (default: 268435456 = 256MB)
+    /// - `THOUGHTGATE_BUFFER_BUDGET_WAIT_SECS` (default: 0)
     /// - `THOUGHTGATE_BUFFER_TIMEOUT_SECS` (default: 30)
+    /// - `THOUGHTGATE_BUFFER_SPILL_THRESHOLD` (default: unset, never spill)
+    /// - `THOUGHTGATE_BUFFER_SPILL_DIR` (default: system temp directory)
+    /// - `THOUGHTGATE_BUFFER_DISK_BUDGET` (default: 1073741824 = 1GB)
     ///
     /// # Traceability
     /// - Implements: REQ-CORE-001 Section 3.2 (Config Loading)
     /// - Implements: REQ-CORE-002 Section 3.2 (Config Loading)
     pub fn from_env() -> Self {
-        let default = Self::default();
+        Self::from_layers(&[&ProxyConfigLayer::from_env()])
+    }
 
-        Self {
-            // Green Path configuration
-            tcp_nodelay: std::env::var("THOUGHTGATE_TCP_NODELAY")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(default.tcp_nodelay),
-
-            tcp_keepalive_secs: std::env::var("THOUGHTGATE_TCP_KEEPALIVE_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(default.tcp_keepalive_secs),
-
-            stream_read_timeout: std::env::var("THOUGHTGATE_STREAM_READ_TIMEOUT_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .map(Duration::from_secs)
-                .unwrap_or(default.stream_read_timeout),
+    /// Apply the size-based path overrides to a policy decision.
+    ///
+    /// Only Green and Amber can be overridden: a Red (reject) or Approval
+    /// decision always stands. A request without a known length (e.g.
+    /// chunked) keeps the path policy chose, since its size cannot be
+    /// judged before reading it. Both thresholds are exclusive.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 Section 3.2 (Path Overrides)
+    /// - Implements: REQ-CORE-002 Section 3.2 (Path Overrides)
+    pub fn select_path(
+        &self,
+        decision: PolicyDecision,
+        content_length: Option<u64>,
+    ) -> PolicyDecision {
+        let Some(len) = content_length else {
+            return decision;
+        };
+        match decision {
+            PolicyDecision::Green if self.force_amber_below_bytes.is_some_and(|max| len < max) => {
+                PolicyDecision::Amber
+            }
+            PolicyDecision::Amber if self.force_green_above_bytes.is_some_and(|min| len > min) => {
+                PolicyDecision::Green
+            }
+            other => other,
+        }
+    }
 
-            stream_write_timeout: std::env::var("THOUGHTGATE_STREAM_WRITE_TIMEOUT_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .map(Duration::from_secs)
-                .unwrap_or(default.stream_write_timeout),
+    /// Whether a response of `content_type` may be compressed.
+    ///
+    /// Parameters such as `charset` are ignored, and matching is
+    /// case-insensitive.
+    pub fn compresses_content_type(&self, content_type: &str) -> bool {
+        let media_type = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        self.compress_content_types.iter().any(|allowed| {
+            let allowed = allowed.trim().to_ascii_lowercase();
+            match allowed.strip_suffix("/*") {
+                Some(kind) => media_type
+                    .split_once('/')
+                    .is_some_and(|(media_kind, _)| media_kind == kind),
+                None => media_type == allowed,
+            }
+        })
+    }
+
+    /// Build configuration from layers ordered lowest to highest precedence.
+    ///
+    /// Each layer only overrides the settings it sets; anything left unset
+    /// by every layer keeps its built-in default.
+    pub fn from_layers(layers: &[&ProxyConfigLayer]) -> Self {
+        layers
+            .iter()
+            .fold(Self::default(), |config, layer| layer.apply(config))
+    }
+}
+
+/// A partial set of [`ProxyConfig`] overrides from one configuration source.
+///
+/// The same keys are used by every source: the `proxy:` section of the YAML
+/// config file, `THOUGHTGATE_<KEY>` environment variables, and `--set
+/// key=value` CLI flags.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 6.3 (Configuration Loading Interface)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProxyConfigLayer {
+    /// Overrides [`ProxyConfig::tcp_nodelay`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tcp_nodelay: Option<bool>,
+    /// Overrides [`ProxyConfig::tcp_keepalive_secs`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tcp_keepalive_secs: Option<u64>,
+    /// Overrides [`ProxyConfig::tcp_keepalive_interval`], in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tcp_keepalive_interval_secs: Option<u64>,
+    /// Overrides [`ProxyConfig::tcp_reuseaddr`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tcp_reuseaddr: Option<bool>,
+    /// Overrides [`ProxyConfig::listen_backlog`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub listen_backlog: Option<u32>,
+    /// Overrides [`ProxyConfig::stream_read_timeout`], in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream_read_timeout_secs: Option<u64>,
+    /// Overrides [`ProxyConfig::stream_write_timeout`], in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream_write_timeout_secs: Option<u64>,
+    /// Overrides [`ProxyConfig::stream_total_timeout`], in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream_total_timeout_secs: Option<u64>,
+    /// Overrides [`ProxyConfig::max_concurrent_streams`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_streams: Option<usize>,
+    /// Overrides [`ProxyConfig::socket_buffer_size`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub socket_buffer_size: Option<usize>,
+    /// Overrides [`ProxyConfig::max_stream_bytes`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_stream_bytes: Option<u64>,
+    /// Overrides [`ProxyConfig::request_deadline`], in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_deadline_secs: Option<u64>,
+    /// Overrides [`ProxyConfig::max_in_flight_requests`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_in_flight_requests: Option<usize>,
+    /// Overrides [`ProxyConfig::max_in_flight_per_upstream`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_in_flight_per_upstream: Option<usize>,
+    /// Overrides [`ProxyConfig::admission_queue_depth`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admission_queue_depth: Option<usize>,
+    /// Overrides [`ProxyConfig::admission_queue_wait`], in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admission_queue_wait_ms: Option<u64>,
+    /// Overrides [`ProxyConfig::header_read_timeout`], in seconds (0 disables).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header_read_timeout_secs: Option<u64>,
+    /// Overrides [`ProxyConfig::idle_connection_timeout`], in seconds (0 disables).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_connection_timeout_secs: Option<u64>,
+    /// Overrides [`ProxyConfig::response_start_timeout`], in seconds (0 disables).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_start_timeout_secs: Option<u64>,
+    /// Overrides [`ProxyConfig::max_request_headers`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_request_headers: Option<usize>,
+    /// Overrides [`ProxyConfig::max_request_header_bytes`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_request_header_bytes: Option<usize>,
+    /// Overrides [`ProxyConfig::http2_max_concurrent_streams`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http2_max_concurrent_streams: Option<u32>,
+    /// Overrides [`ProxyConfig::max_concurrent_buffers`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_buffers: Option<usize>,
+    /// Overrides [`ProxyConfig::req_buffer_max`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub req_buffer_max: Option<usize>,
+    /// Overrides [`ProxyConfig::resp_buffer_max`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resp_buffer_max: Option<usize>,
+    /// Overrides [`ProxyConfig::buffer_budget`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffer_budget: Option<usize>,
+    /// Overrides [`ProxyConfig::buffer_budget_wait`], in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffer_budget_wait_secs: Option<u64>,
+    /// Overrides [`ProxyConfig::buffer_timeout`], in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffer_timeout_secs: Option<u64>,
+    /// Overrides [`ProxyConfig::buffer_spill_threshold`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffer_spill_threshold: Option<usize>,
+    /// Overrides [`ProxyConfig::buffer_spill_dir`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffer_spill_dir: Option<PathBuf>,
+    /// Overrides [`ProxyConfig::buffer_disk_budget`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffer_disk_budget: Option<usize>,
+    /// Overrides [`ProxyConfig::force_amber_below_bytes`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub force_amber_below_bytes: Option<u64>,
+    /// Overrides [`ProxyConfig::force_green_above_bytes`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub force_green_above_bytes: Option<u64>,
+    /// Overrides [`ProxyConfig::early_forward_methods`] (comma-separated as a string).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub early_forward_methods: Option<Vec<String>>,
+    /// Overrides [`ProxyConfig::governance_trailers`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub governance_trailers: Option<bool>,
+    /// Overrides [`ProxyConfig::reject_headers`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reject_headers: Option<bool>,
+    /// Overrides [`ProxyConfig::sse_block_mode`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sse_block_mode: Option<SseBlockMode>,
+    /// Overrides [`ProxyConfig::compress_responses`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compress_responses: Option<bool>,
+    /// Overrides [`ProxyConfig::compress_min_bytes`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compress_min_bytes: Option<u64>,
+    /// Overrides [`ProxyConfig::compress_content_types`] (comma-separated as a string).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compress_content_types: Option<Vec<String>>,
+}
+
+impl ProxyConfigLayer {
+    /// Every key accepted by [`ProxyConfigLayer::set`].
+    pub const KEYS: &'static [&'static str] = &[
+        "tcp_nodelay",
+        "tcp_keepalive_secs",
+        "tcp_keepalive_interval_secs",
+        "tcp_reuseaddr",
+        "listen_backlog",
+        "stream_read_timeout_secs",
+        "stream_write_timeout_secs",
+        "stream_total_timeout_secs",
+        "max_concurrent_streams",
+        "socket_buffer_size",
+        "max_stream_bytes",
+        "request_deadline_secs",
+        "max_in_flight_requests",
+        "max_in_flight_per_upstream",
+        "admission_queue_depth",
+        "admission_queue_wait_ms",
+        "header_read_timeout_secs",
+        "idle_connection_timeout_secs",
+        "response_start_timeout_secs",
+        "max_request_headers",
+        "max_request_header_bytes",
+        "http2_max_concurrent_streams",
+        "max_concurrent_buffers",
+        "req_buffer_max",
+        "resp_buffer_max",
+        "buffer_budget",
+        "buffer_budget_wait_secs",
+        "buffer_timeout_secs",
+        "buffer_spill_threshold",
+        "buffer_spill_dir",
+        "buffer_disk_budget",
+        "force_amber_below_bytes",
+        "force_green_above_bytes",
+        "early_forward_methods",
+        "governance_trailers",
+        "reject_headers",
+        "sse_block_mode",
+        "compress_responses",
+        "compress_min_bytes",
+        "compress_content_types",
+    ];
+
+    /// Read overrides from `THOUGHTGATE_<KEY>` environment variables.
+    ///
+    /// Unparseable values are logged and ignored.
+    pub fn from_env() -> Self {
+        let mut layer = Self::default();
+        for key in Self::KEYS {
+            let var = format!("THOUGHTGATE_{}", key.to_ascii_uppercase());
+            if let Ok(value) = std::env::var(&var) {
+                if let Err(e) = layer.set(key, &value) {
+                    warn!(var = %var, error = %e, "Ignoring invalid environment override");
+                }
+            }
+        }
+        layer
+    }
 
-            stream_total_timeout: std::env::var("THOUGHTGATE_STREAM_TOTAL_TIMEOUT_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
+    /// Parse `key=value` overrides, as given to the `--set` CLI flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidSetting` for a malformed override, an
+    /// unknown key, or a value that does not parse.
+    pub fn from_overrides<S: AsRef<str>>(overrides: &[S]) -> Result<Self, ConfigError> {
+        let mut layer = Self::default();
+        for entry in overrides {
+            let entry = entry.as_ref();
+            let Some((key, value)) = entry.split_once('=') else {
+                return Err(ConfigError::InvalidSetting {
+                    key: entry.to_string(),
+                    message: "expected KEY=VALUE".to_string(),
+                });
+            };
+            layer.set(key.trim(), value.trim())?;
+        }
+        Ok(layer)
+    }
+
+    /// Set a single override from its string form.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidSetting` if `key` is unknown or `value`
+    /// does not parse.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), ConfigError> {
+        match key {
+            "tcp_nodelay" => self.tcp_nodelay = Some(parse_setting(key, value)?),
+            "tcp_keepalive_secs" => self.tcp_keepalive_secs = Some(parse_setting(key, value)?),
+            "tcp_keepalive_interval_secs" => {
+                self.tcp_keepalive_interval_secs = Some(parse_setting(key, value)?)
+            }
+            "tcp_reuseaddr" => self.tcp_reuseaddr = Some(parse_setting(key, value)?),
+            "listen_backlog" => self.listen_backlog = Some(parse_setting(key, value)?),
+            "stream_read_timeout_secs" => {
+                self.stream_read_timeout_secs = Some(parse_setting(key, value)?)
+            }
+            "stream_write_timeout_secs" => {
+                self.stream_write_timeout_secs = Some(parse_setting(key, value)?)
+            }
+            "stream_total_timeout_secs" => {
+                self.stream_total_timeout_secs = Some(parse_setting(key, value)?)
+            }
+            "max_concurrent_streams" => {
+                self.max_concurrent_streams = Some(parse_setting(key, value)?)
+            }
+            "socket_buffer_size" => self.socket_buffer_size = Some(parse_setting(key, value)?),
+            "max_stream_bytes" => self.max_stream_bytes = Some(parse_setting(key, value)?),
+            "request_deadline_secs" => {
+                self.request_deadline_secs = Some(parse_setting(key, value)?)
+            }
+            "max_in_flight_requests" => {
+                self.max_in_flight_requests = Some(parse_setting(key, value)?)
+            }
+            "max_in_flight_per_upstream" => {
+                self.max_in_flight_per_upstream = Some(parse_setting(key, value)?)
+            }
+            "admission_queue_depth" => {
+                self.admission_queue_depth = Some(parse_setting(key, value)?)
+            }
+            "admission_queue_wait_ms" => {
+                self.admission_queue_wait_ms = Some(parse_setting(key, value)?)
+            }
+            "header_read_timeout_secs" => {
+                self.header_read_timeout_secs = Some(parse_setting(key, value)?)
+            }
+            "idle_connection_timeout_secs" => {
+                self.idle_connection_timeout_secs = Some(parse_setting(key, value)?)
+            }
+            "response_start_timeout_secs" => {
+                self.response_start_timeout_secs = Some(parse_setting(key, value)?)
+            }
+            "max_request_headers" => self.max_request_headers = Some(parse_setting(key, value)?),
+            "max_request_header_bytes" => {
+                self.max_request_header_bytes = Some(parse_setting(key, value)?)
+            }
+            "http2_max_concurrent_streams" => {
+                self.http2_max_concurrent_streams = Some(parse_setting(key, value)?)
+            }
+            "max_concurrent_buffers" => {
+                self.max_concurrent_buffers = Some(parse_setting(key, value)?)
+            }
+            "req_buffer_max" => self.req_buffer_max = Some(parse_setting(key, value)?),
+            "resp_buffer_max" => self.resp_buffer_max = Some(parse_setting(key, value)?),
+            "buffer_budget" => self.buffer_budget = Some(parse_setting(key, value)?),
+            "buffer_budget_wait_secs" => {
+                self.buffer_budget_wait_secs = Some(parse_setting(key, value)?)
+            }
+            "buffer_timeout_secs" => self.buffer_timeout_secs = Some(parse_setting(key, value)?),
+            "buffer_spill_threshold" => {
+                self.buffer_spill_threshold = Some(parse_setting(key, value)?)
+            }
+            "buffer_spill_dir" => self.buffer_spill_dir = Some(parse_setting(key, value)?),
+            "buffer_disk_budget" => self.buffer_disk_budget = Some(parse_setting(key, value)?),
+            "force_amber_below_bytes" => {
+                self.force_amber_below_bytes = Some(parse_setting(key, value)?)
+            }
+            "force_green_above_bytes" => {
+                self.force_green_above_bytes = Some(parse_setting(key, value)?)
+            }
+            "early_forward_methods" => self.early_forward_methods = Some(parse_list(value)),
+            "governance_trailers" => self.governance_trailers = Some(parse_setting(key, value)?),
+            "reject_headers" => self.reject_headers = Some(parse_setting(key, value)?),
+            "sse_block_mode" => self.sse_block_mode = Some(parse_setting(key, value)?),
+            "compress_responses" => self.compress_responses = Some(parse_setting(key, value)?),
+            "compress_min_bytes" => self.compress_min_bytes = Some(parse_setting(key, value)?),
+            "compress_content_types" => self.compress_content_types = Some(parse_list(value)),
+            _ => {
+                return Err(ConfigError::InvalidSetting {
+                    key: key.to_string(),
+                    message: "unknown setting".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply the settings present in this layer on top of `base`.
+    pub fn apply(&self, base: ProxyConfig) -> ProxyConfig {
+        ProxyConfig {
+            tcp_nodelay: self.tcp_nodelay.unwrap_or(base.tcp_nodelay),
+            tcp_keepalive_secs: self.tcp_keepalive_secs.unwrap_or(base.tcp_keepalive_secs),
+            tcp_keepalive_interval: self
+                .tcp_keepalive_interval_secs
+                .map(Duration::from_secs)
+                .or(base.tcp_keepalive_interval),
+            tcp_reuseaddr: self.tcp_reuseaddr.unwrap_or(base.tcp_reuseaddr),
+            listen_backlog: self.listen_backlog.unwrap_or(base.listen_backlog),
+            stream_read_timeout: self
+                .stream_read_timeout_secs
+                .map(Duration::from_secs)
+                .unwrap_or(base.stream_read_timeout),
+            stream_write_timeout: self
+                .stream_write_timeout_secs
+                .map(Duration::from_secs)
+                .unwrap_or(base.stream_write_timeout),
+            stream_total_timeout: self
+                .stream_total_timeout_secs
                 .map(Duration::from_secs)
-                .unwrap_or(default.stream_total_timeout),
-
-            max_concurrent_streams: std::env::var("THOUGHTGATE_MAX_CONCURRENT_STREAMS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(default.max_concurrent_streams),
-
-            socket_buffer_size: std::env::var("THOUGHTGATE_SOCKET_BUFFER_SIZE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(default.socket_buffer_size),
-
-            // Amber Path configuration
-            max_concurrent_buffers: std::env::var("THOUGHTGATE_MAX_CONCURRENT_BUFFERS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(default.max_concurrent_buffers),
-
-            req_buffer_max: std::env::var("THOUGHTGATE_REQ_BUFFER_MAX")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(default.req_buffer_max),
-
-            resp_buffer_max: std::env::var("THOUGHTGATE_RESP_BUFFER_MAX")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(default.resp_buffer_max),
-
-            buffer_timeout: std::env::var("THOUGHTGATE_BUFFER_TIMEOUT_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
+                .unwrap_or(base.stream_total_timeout),
+            max_concurrent_streams: self
+                .max_concurrent_streams
+                .unwrap_or(base.max_concurrent_streams),
+            socket_buffer_size: self.socket_buffer_size.unwrap_or(base.socket_buffer_size),
+            max_stream_bytes: self.max_stream_bytes.or(base.max_stream_bytes),
+            request_deadline: self
+                .request_deadline_secs
                 .map(Duration::from_secs)
-                .unwrap_or(default.buffer_timeout),
+                .or(base.request_deadline),
+            max_in_flight_requests: self.max_in_flight_requests.or(base.max_in_flight_requests),
+            max_in_flight_per_upstream: self
+                .max_in_flight_per_upstream
+                .or(base.max_in_flight_per_upstream),
+            admission_queue_depth: self
+                .admission_queue_depth
+                .unwrap_or(base.admission_queue_depth),
+            admission_queue_wait: self
+                .admission_queue_wait_ms
+                .map(Duration::from_millis)
+                .unwrap_or(base.admission_queue_wait),
+            header_read_timeout: self
+                .header_read_timeout_secs
+                .map_or(base.header_read_timeout, optional_secs),
+            idle_connection_timeout: self
+                .idle_connection_timeout_secs
+                .map_or(base.idle_connection_timeout, optional_secs),
+            response_start_timeout: self
+                .response_start_timeout_secs
+                .map_or(base.response_start_timeout, optional_secs),
+            max_request_headers: self.max_request_headers.unwrap_or(base.max_request_headers),
+            max_request_header_bytes: self
+                .max_request_header_bytes
+                .unwrap_or(base.max_request_header_bytes),
+            http2_max_concurrent_streams: self
+                .http2_max_concurrent_streams
+                .unwrap_or(base.http2_max_concurrent_streams),
+            max_concurrent_buffers: self
+                .max_concurrent_buffers
+                .unwrap_or(base.max_concurrent_buffers),
+            req_buffer_max: self.req_buffer_max.unwrap_or(base.req_buffer_max),
+            resp_buffer_max: self.resp_buffer_max.unwrap_or(base.resp_buffer_max),
+            buffer_budget: self.buffer_budget.unwrap_or(base.buffer_budget),
+            buffer_budget_wait: self
+                .buffer_budget_wait_secs
+                .map(Duration::from_secs)
+                .unwrap_or(base.buffer_budget_wait),
+            buffer_timeout: self
+                .buffer_timeout_secs
+                .map(Duration::from_secs)
+                .unwrap_or(base.buffer_timeout),
+            buffer_spill_threshold: self.buffer_spill_threshold.or(base.buffer_spill_threshold),
+            buffer_spill_dir: self.buffer_spill_dir.clone().or(base.buffer_spill_dir),
+            buffer_disk_budget: self.buffer_disk_budget.unwrap_or(base.buffer_disk_budget),
+            force_amber_below_bytes: self
+                .force_amber_below_bytes
+                .or(base.force_amber_below_bytes),
+            force_green_above_bytes: self
+                .force_green_above_bytes
+                .or(base.force_green_above_bytes),
+            early_forward_methods: self
+                .early_forward_methods
+                .clone()
+                .unwrap_or(base.early_forward_methods),
+            governance_trailers: self.governance_trailers.unwrap_or(base.governance_trailers),
+            reject_headers: self.reject_headers.unwrap_or(base.reject_headers),
+            sse_block_mode: self.sse_block_mode.unwrap_or(base.sse_block_mode),
+            compress_responses: self.compress_responses.unwrap_or(base.compress_responses),
+            compress_min_bytes: self.compress_min_bytes.unwrap_or(base.compress_min_bytes),
+            compress_content_types: self
+                .compress_content_types
+                .clone()
+                .unwrap_or(base.compress_content_types),
         }
     }
 }
 
+/// Seconds as an optional timeout, where zero turns it off.
+fn optional_secs(secs: u64) -> Option<Duration> {
+    (secs > 0).then(|| Duration::from_secs(secs))
+}
+
+fn parse_setting<T>(key: &str, value: &str) -> Result<T, ConfigError>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    value
+        .parse()
+        .map_err(|e: T::Err| ConfigError::InvalidSetting {
+            key: key.to_string(),
+            message: format!("'{value}': {e}"),
+        })
+}
+
+/// A comma-separated list, ignoring blank entries.
+fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(String::from)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,6 +929,8 @@ mod tests {
         assert_eq!(config.max_concurrent_buffers, 100);
         assert_eq!(config.req_buffer_max, 2 * 1024 * 1024); // 2 MB
         assert_eq!(config.resp_buffer_max, 10 * 1024 * 1024); // 10 MB
+        assert_eq!(config.buffer_budget, 256 * 1024 * 1024); // 256 MB
+        assert_eq!(config.buffer_budget_wait, Duration::ZERO);
         assert_eq!(config.buffer_timeout, Duration::from_secs(30));
     }
 
@@ -274,4 +980,263 @@ mod tests {
             std::env::remove_var("THOUGHTGATE_BUFFER_TIMEOUT_SECS");
         }
     }
+
+    #[test]
+    fn test_layer_partial_override_keeps_other_settings() {
+        let layer = ProxyConfigLayer {
+            stream_read_timeout_secs: Some(60),
+            ..Default::default()
+        };
+        let config = ProxyConfig::from_layers(&[&layer]);
+        let default = ProxyConfig::default();
+
+        assert_eq!(config.stream_read_timeout, Duration::from_secs(60));
+        assert_eq!(config.stream_write_timeout, default.stream_write_timeout);
+        assert_eq!(config.stream_total_timeout, default.stream_total_timeout);
+        assert_eq!(config.buffer_timeout, default.buffer_timeout);
+    }
+
+    #[test]
+    fn test_layer_precedence() {
+        let file = ProxyConfigLayer {
+            stream_read_timeout_secs: Some(10),
+            stream_write_timeout_secs: Some(10),
+            max_concurrent_streams: Some(10),
+            ..Default::default()
+        };
+        let env = ProxyConfigLayer {
+            stream_write_timeout_secs: Some(20),
+            max_concurrent_streams: Some(20),
+            ..Default::default()
+        };
+        let cli = ProxyConfigLayer::from_overrides(&["max_concurrent_streams=30"]).unwrap();
+
+        let config = ProxyConfig::from_layers(&[&file, &env, &cli]);
+
+        assert_eq!(config.stream_read_timeout, Duration::from_secs(10)); // file
+        assert_eq!(config.stream_write_timeout, Duration::from_secs(20)); // env
+        assert_eq!(config.max_concurrent_streams, 30); // cli
+        assert_eq!(config.socket_buffer_size, 262144); // default
+    }
+
+    #[test]
+    fn test_layer_from_file_section() {
+        let yaml = "tcp_nodelay: false\nbuffer_timeout_secs: 5\n";
+        let layer: ProxyConfigLayer = serde_saphyr::from_str(yaml).unwrap();
+        let config = ProxyConfig::from_layers(&[&layer]);
+        assert!(!config.tcp_nodelay);
+        assert_eq!(config.buffer_timeout, Duration::from_secs(5));
+
+        assert!(serde_saphyr::from_str::<ProxyConfigLayer>("tcp_nodelya: false\n").is_err());
+    }
+
+    #[test]
+    fn test_layer_overrides_rejects_bad_input() {
+        for bad in [
+            "max_concurrent_streams",
+            "unknown_key=1",
+            "tcp_nodelay=maybe",
+        ] {
+            let err = ProxyConfigLayer::from_overrides(&[bad]).unwrap_err();
+            assert!(
+                matches!(err, ConfigError::InvalidSetting { .. }),
+                "{bad}: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_select_path_thresholds() {
+        let config = ProxyConfigLayer::from_overrides(&[
+            "force_amber_below_bytes=1024",
+            "force_green_above_bytes=65536",
+        ])
+        .unwrap()
+        .apply(ProxyConfig::default());
+        let reject = || PolicyDecision::Red {
+            reason: "denied".to_string(),
+        };
+
+        // Thresholds are exclusive
+        for (len, expected) in [
+            (0, PolicyDecision::Amber),
+            (1023, PolicyDecision::Amber),
+            (1024, PolicyDecision::Green),
+        ] {
+            assert_eq!(
+                config.select_path(PolicyDecision::Green, Some(len)),
+                expected
+            );
+        }
+        for (len, expected) in [
+            (65536, PolicyDecision::Amber),
+            (65537, PolicyDecision::Green),
+        ] {
+            assert_eq!(
+                config.select_path(PolicyDecision::Amber, Some(len)),
+                expected
+            );
+        }
+
+        // Unknown length (chunked) keeps the policy path
+        assert_eq!(
+            config.select_path(PolicyDecision::Green, None),
+            PolicyDecision::Green
+        );
+        assert_eq!(
+            config.select_path(PolicyDecision::Amber, None),
+            PolicyDecision::Amber
+        );
+
+        // Reject and approval always win
+        assert_eq!(config.select_path(reject(), Some(10)), reject());
+        let approval = PolicyDecision::Approval {
+            timeout: Duration::from_secs(60),
+        };
+        assert_eq!(config.select_path(approval.clone(), Some(10)), approval);
+        assert_eq!(config.select_path(reject(), Some(1 << 20)), reject());
+
+        // No overrides configured: policy path unchanged
+        let plain = ProxyConfig::default();
+        assert_eq!(
+            plain.select_path(PolicyDecision::Green, Some(1)),
+            PolicyDecision::Green
+        );
+    }
+
+    #[test]
+    fn test_max_stream_bytes_setting() {
+        assert_eq!(ProxyConfig::default().max_stream_bytes, None);
+
+        let config = ProxyConfigLayer::from_overrides(&["max_stream_bytes=1048576"])
+            .unwrap()
+            .apply(ProxyConfig::default());
+        assert_eq!(config.max_stream_bytes, Some(1_048_576));
+
+        assert!(ProxyConfigLayer::from_overrides(&["max_stream_bytes=-1"]).is_err());
+    }
+
+    #[test]
+    fn test_request_deadline_setting() {
+        assert_eq!(ProxyConfig::default().request_deadline, None);
+
+        let config = ProxyConfigLayer::from_overrides(&["request_deadline_secs=30"])
+            .unwrap()
+            .apply(ProxyConfig::default());
+        assert_eq!(config.request_deadline, Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_listener_timeout_settings() {
+        let default = ProxyConfig::default();
+        assert_eq!(default.header_read_timeout, Some(Duration::from_secs(30)));
+        assert_eq!(
+            default.idle_connection_timeout,
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(default.response_start_timeout, None);
+
+        let config = ProxyConfigLayer::from_overrides(&[
+            "header_read_timeout_secs=5",
+            "idle_connection_timeout_secs=0",
+            "response_start_timeout_secs=900",
+        ])
+        .unwrap()
+        .apply(default);
+        assert_eq!(config.header_read_timeout, Some(Duration::from_secs(5)));
+        assert_eq!(config.idle_connection_timeout, None);
+        assert_eq!(
+            config.response_start_timeout,
+            Some(Duration::from_secs(900))
+        );
+    }
+
+    #[test]
+    fn test_admission_settings() {
+        let default = ProxyConfig::default();
+        assert_eq!(default.max_in_flight_requests, None);
+        assert_eq!(default.admission_queue_depth, 0);
+
+        let config = ProxyConfigLayer::from_overrides(&[
+            "max_in_flight_requests=200",
+            "max_in_flight_per_upstream=50",
+            "admission_queue_depth=10",
+            "admission_queue_wait_ms=250",
+        ])
+        .unwrap()
+        .apply(default);
+        assert_eq!(config.max_in_flight_requests, Some(200));
+        assert_eq!(config.max_in_flight_per_upstream, Some(50));
+        assert_eq!(config.admission_queue_depth, 10);
+        assert_eq!(config.admission_queue_wait, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_sse_block_mode_setting() {
+        assert_eq!(
+            ProxyConfig::default().sse_block_mode,
+            SseBlockMode::HardClose
+        );
+
+        let layer = ProxyConfigLayer::from_overrides(&["sse_block_mode=terminal_event"]).unwrap();
+        assert_eq!(
+            layer.apply(ProxyConfig::default()).sse_block_mode,
+            SseBlockMode::TerminalEvent
+        );
+        let file: ProxyConfigLayer =
+            serde_saphyr::from_str("sse_block_mode: hard_close\n").unwrap();
+        assert_eq!(file.sse_block_mode, Some(SseBlockMode::HardClose));
+        assert!(ProxyConfigLayer::from_overrides(&["sse_block_mode=polite"]).is_err());
+    }
+
+    #[test]
+    fn test_early_forward_methods_setting() {
+        assert!(ProxyConfig::default().early_forward_methods.is_empty());
+
+        let layer =
+            ProxyConfigLayer::from_overrides(&["early_forward_methods=ping, notifications/log,"])
+                .unwrap();
+        assert_eq!(
+            layer.apply(ProxyConfig::default()).early_forward_methods,
+            ["ping", "notifications/log"]
+        );
+        let file: ProxyConfigLayer =
+            serde_saphyr::from_str("early_forward_methods: [ping]\n").unwrap();
+        assert_eq!(file.early_forward_methods, Some(vec!["ping".to_string()]));
+    }
+
+    #[test]
+    fn test_compression_settings() {
+        let config = ProxyConfig::default();
+        assert!(!config.compress_responses);
+        assert!(config.compresses_content_type("application/json; charset=utf-8"));
+        assert!(config.compresses_content_type("Text/Event-Stream"));
+        assert!(!config.compresses_content_type("image/png"));
+        assert!(!config.compresses_content_type("application/jsonl"));
+
+        let layer = ProxyConfigLayer::from_overrides(&[
+            "compress_responses=true",
+            "compress_min_bytes=0",
+            "compress_content_types=application/x-ndjson, image/*",
+        ])
+        .unwrap();
+        let config = layer.apply(ProxyConfig::default());
+        assert!(config.compress_responses);
+        assert_eq!(config.compress_min_bytes, 0);
+        assert!(config.compresses_content_type("image/svg+xml"));
+        assert!(config.compresses_content_type("application/x-ndjson"));
+        assert!(!config.compresses_content_type("application/json"));
+    }
+
+    #[test]
+    fn test_layer_from_env_uses_prefixed_keys() {
+        unsafe {
+            std::env::set_var("THOUGHTGATE_STREAM_TOTAL_TIMEOUT_SECS", "120");
+        }
+        let layer = ProxyConfigLayer::from_env();
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_STREAM_TOTAL_TIMEOUT_SECS");
+        }
+        assert_eq!(layer.stream_total_timeout_secs, Some(120));
+    }
 }
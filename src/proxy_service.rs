@@ -9,6 +9,14 @@
 //!   - Buffered for inspection and policy evaluation
 //!   - Routed through McpHandler for Cedar policy + task handling
 //!
+//! - **Upgrade Requests** (`Connection: upgrade`, e.g. WebSocket):
+//!   - Authorized by McpHandler on the handshake path before upgrading
+//!   - Relayed as an opaque byte stream with idle and total timeouts
+//!
+//! - **Governed Transports** (e.g. gRPC, see [`Transport`]):
+//!   - Buffered only until the call can be identified
+//!   - Authorized by McpHandler, then streamed upstream with framing intact
+//!
 //! - **HTTP Traffic** (everything else):
 //!   - Zero-copy streaming passthrough to upstream
 //!   - No inspection or buffering overhead
@@ -33,23 +41,48 @@
 //! - Implements: REQ-CORE-001 (Zero-Copy Peeking Strategy)
 //! - Implements: REQ-CORE-003/F-002 (MCP Traffic Detection)
 
-use crate::error::{ProxyError, ProxyResult};
+use crate::admission::{Admission, AdmissionControl};
+use crate::connection::PeerAddr;
+use crate::deadline::{self, Deadline};
+use crate::error::{ProxyError, ProxyResult, ThoughtGateError};
+use crate::governance::approval::signature::constant_time_eq;
+use crate::governance::{QuotaLimiter, TaskId, ToolCallResult, with_request_headers};
+use crate::header_rules::HeaderRules;
+use crate::inspector::{PeekOutcome, ResponsePolicy, peek_for_classification};
+use crate::latency::{self, LatencyBreakdown};
+use crate::lifecycle::LifecycleManager;
+use crate::policy::PolicyDecision;
+use crate::policy::engine::CedarEngine;
+use crate::policy::explain::{ExplainQuery, explain};
+use crate::policy::principal::{principal_cache, request_principal, with_client_principal};
+use crate::proxy_body::{GzipBody, ProxyBody, SseBlockMode, SseBody};
 use crate::proxy_config::ProxyConfig;
-use crate::traffic::{TrafficType, discriminate_traffic};
+use crate::tap::Tap;
+use crate::timeout::{TimeoutBody, TimeoutConfig};
+use crate::traffic::{TrafficType, discriminate_traffic, mcp_server_id};
+use crate::transport::jsonrpc::{JsonRpcId, JsonRpcResponse, request_id};
+use crate::transport::router::McpRouter;
 use crate::transport::server::McpHandler;
-use bytes::Bytes;
+use crate::transport::tls::ClientCertificate;
+use crate::transport::upstream::{audit_upstream_tls_failure, record_tls_failure};
+use crate::transport::wire::Transport;
+use bytes::{Bytes, BytesMut};
 use futures_util::StreamExt;
 use http::Uri;
-use http_body_util::{BodyExt, BodyStream, Full, StreamBody};
+use http_body_util::{BodyExt, BodyStream, Empty, Full, Limited, StreamBody};
+use hyper::body::Frame;
 use hyper::body::Incoming;
 use hyper::{Request, Response, StatusCode, header};
 use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
 use hyper_util::client::legacy::{Client, connect::HttpConnector};
-use hyper_util::rt::TokioExecutor;
+use hyper_util::rt::{TokioExecutor, TokioIo};
 use socket2::{Domain, Protocol, Socket, TcpKeepalive, Type};
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::time::{Instant, sleep, sleep_until, timeout};
 use tower::Service;
 use tracing::{debug, error, info, warn};
 
@@ -63,6 +96,28 @@ type ClientBody =
 /// Both are boxed for a unified return type.
 pub type UnifiedBody = http_body_util::combinators::BoxBody<Bytes, ProxyError>;
 
+/// Buffer size for each direction of an upgraded connection relay.
+const RELAY_BUFFER_SIZE: usize = 16 * 1024;
+
+/// Path of the policy simulation endpoint.
+pub const POLICY_SIMULATE_PATH: &str = "/policy/simulate";
+
+/// Largest accepted policy simulation request body.
+const POLICY_SIMULATE_MAX_BODY: usize = 64 * 1024;
+
+/// `Retry-After` for MCP requests that arrive before policies are loaded.
+const POLICY_LOAD_RETRY_AFTER_SECS: u64 = 1;
+
+/// Trailer carrying the governance action taken on the request. Also sent
+/// as a header on refusals when `reject_headers` is set.
+pub const DECISION_TRAILER: &str = "thoughtgate-decision";
+
+/// Header carrying the reject code of a refused request.
+pub const REJECT_CODE_HEADER: &str = "thoughtgate-reject-code";
+
+/// Trailer reporting whether the request was inspected before forwarding.
+pub const INSPECTED_TRAILER: &str = "thoughtgate-inspected";
+
 /// Main proxy service that handles HTTP and HTTPS requests with full TLS support.
 ///
 /// This service implements:
@@ -84,6 +139,28 @@ pub struct ProxyService {
     config: ProxyConfig,
     /// MCP request handler (optional - for MCP traffic routing)
     mcp_handler: Option<Arc<McpHandler>>,
+    /// Per-server MCP handlers, selected by `/mcp/v1/{server}`
+    mcp_routes: HashMap<String, Arc<McpHandler>>,
+    /// HTTP/2-only client for transports that require it (e.g. gRPC).
+    h2_client: Client<HttpsConnector<HttpConnector>, ClientBody>,
+    /// Non-JSON-RPC transports governed through `mcp_handler`.
+    transports: Vec<Arc<dyn Transport>>,
+    /// Policy engine and bearer token for `POST /policy/simulate`.
+    policy_simulator: Option<(Arc<CedarEngine>, Arc<str>)>,
+    /// Per-principal tool call quota, checked before MCP requests are handled.
+    quota: Option<Arc<QuotaLimiter>>,
+    /// Limits on requests in flight, shared by every clone of the service.
+    admission: Option<Arc<AdmissionControl>>,
+    /// Policy applied to each event of streamed SSE responses.
+    sse_policy: Option<Arc<ResponsePolicy>>,
+    /// Header mutation rules by upstream (see [`Self::with_header_rules`]).
+    header_rules: HashMap<String, Arc<HeaderRules>>,
+    /// Rules for upstreams without their own.
+    default_header_rules: Arc<HeaderRules>,
+    /// Lifecycle consulted for whether policies have loaded yet.
+    lifecycle: Option<Arc<LifecycleManager>>,
+    /// Recorder of sampled MCP exchanges.
+    tap: Option<Arc<Tap>>,
 }
 
 impl Clone for ProxyService {
@@ -93,6 +170,17 @@ impl Clone for ProxyService {
             upstream_url: self.upstream_url.clone(),
             config: self.config.clone(),
             mcp_handler: self.mcp_handler.clone(),
+            mcp_routes: self.mcp_routes.clone(),
+            h2_client: self.h2_client.clone(),
+            transports: self.transports.clone(),
+            policy_simulator: self.policy_simulator.clone(),
+            quota: self.quota.clone(),
+            admission: self.admission.clone(),
+            sse_policy: self.sse_policy.clone(),
+            header_rules: self.header_rules.clone(),
+            default_header_rules: self.default_header_rules.clone(),
+            lifecycle: self.lifecycle.clone(),
+            tap: self.tap.clone(),
         }
     }
 }
@@ -137,6 +225,14 @@ impl ProxyService {
             .enable_http2()
             .wrap_connector(http_connector);
 
+        // Plaintext upstreams only speak HTTP/2 with prior knowledge (h2c)
+        let h2_client = Client::builder(TokioExecutor::new())
+            .http2_only(true)
+            .http2_keep_alive_while_idle(true)
+            .pool_max_idle_per_host(32)
+            .pool_idle_timeout(std::time::Duration::from_secs(90))
+            .build(https_connector.clone());
+
         let client = Client::builder(TokioExecutor::new())
             .http1_preserve_header_case(true)
             .http1_title_case_headers(true)
@@ -146,11 +242,24 @@ impl ProxyService {
             .pool_idle_timeout(std::time::Duration::from_secs(90))
             .build(https_connector);
 
+        let admission = AdmissionControl::from_config(&config).map(Arc::new);
+
         Ok(Self {
             client,
             upstream_url,
             config,
             mcp_handler: None,
+            mcp_routes: HashMap::new(),
+            h2_client,
+            transports: Vec::new(),
+            policy_simulator: None,
+            quota: None,
+            admission,
+            sse_policy: None,
+            header_rules: HashMap::new(),
+            default_header_rules: Arc::new(HeaderRules::default()),
+            lifecycle: None,
+            tap: None,
         })
     }
 
@@ -166,9 +275,169 @@ impl ProxyService {
         self
     }
 
+    /// Route MCP traffic for one server to its own handler.
+    ///
+    /// The handler is keyed by its source ID and serves
+    /// `POST /mcp/v1/{server}`. Once any route is added, MCP requests that do
+    /// not name a server are rejected instead of falling back to the default
+    /// handler, which then only governs upgrades and transports.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+    pub fn with_mcp_route(mut self, handler: Arc<McpHandler>) -> Self {
+        self.mcp_routes
+            .insert(handler.source_id().to_string(), handler);
+        self
+    }
+
+    /// Govern requests carried by `transport` (e.g. gRPC).
+    ///
+    /// Matching requests are authorized by the MCP handler, so this has no
+    /// effect without one; they are then passed through as plain HTTP.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-008 (Transport Abstraction)
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transports.push(transport);
+        self
+    }
+
+    /// Serve `POST /policy/simulate` for callers presenting `token`.
+    ///
+    /// Operators can post a hypothetical principal and resource and get back
+    /// the decision `engine` would make, with its explanation. Nothing is
+    /// forwarded. Without this, the path is proxied like any other.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-POL-001/§6.3 (Decision Explanation)
+    pub fn with_policy_simulator(mut self, engine: Arc<CedarEngine>, token: &str) -> Self {
+        self.policy_simulator = Some((engine, Arc::from(token)));
+        self
+    }
+
+    /// Limit how fast each principal may call tools.
+    ///
+    /// MCP `tools/call` requests over quota are refused with `RateLimited`
+    /// (HTTP 429) before reaching the handler, so they are neither
+    /// evaluated nor forwarded.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-GOV-001/F-009 (Rate Limiting)
+    pub fn with_quota(mut self, quota: Arc<QuotaLimiter>) -> Self {
+        self.quota = Some(quota);
+        self
+    }
+
+    /// Record a sample of MCP exchanges with `tap`.
+    ///
+    /// Requests answered by the MCP handler are queued for recording with
+    /// their responses; see [`crate::tap`] for what is recorded and how.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 NFR-001 (Observability)
+    pub fn with_tap(mut self, tap: Arc<Tap>) -> Self {
+        self.tap = Some(tap);
+        self
+    }
+
+    /// Govern streamed `text/event-stream` responses event by event.
+    ///
+    /// Each event is checked against `policy` as it completes, without
+    /// buffering the stream. An event the policy blocks ends the stream as
+    /// `sse_block_mode` says: aborted, or with a `thoughtgate-blocked`
+    /// event giving the reason.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-002 F-003 (Response Governance)
+    pub fn with_sse_policy(mut self, policy: Arc<ResponsePolicy>) -> Self {
+        self.sse_policy = Some(policy);
+        self
+    }
+
+    /// Set the header mutation rules for traffic to `upstream`.
+    ///
+    /// `upstream` is a routed MCP server's ID or an upstream authority
+    /// (`host:port`), as for `max_in_flight_per_upstream`. Upstreams
+    /// without rules only get the `X-Forwarded-For` and `Via` headers.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+    pub fn with_header_rules(
+        mut self,
+        upstream: impl Into<String>,
+        rules: Arc<HeaderRules>,
+    ) -> Self {
+        self.header_rules.insert(upstream.into(), rules);
+        self
+    }
+
+    /// Refuse MCP requests until `lifecycle` reports policies loaded.
+    ///
+    /// Until the first policy load completes there is nothing to evaluate
+    /// requests against, so they fail closed with `ServiceUnavailable`
+    /// (HTTP 503) and a `Retry-After` header rather than being judged by
+    /// an empty policy set. Once loaded, a failed reload does not bring
+    /// this back: the previous policies stay in force.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-005/F-001 (Startup Sequencing)
+    pub fn with_lifecycle(mut self, lifecycle: Arc<LifecycleManager>) -> Self {
+        self.lifecycle = Some(lifecycle);
+        self
+    }
+
+    /// Header mutation rules for the upstream a request is sent to.
+    fn header_rules_for<B>(&self, req: &Request<B>) -> Arc<HeaderRules> {
+        if self.header_rules.is_empty() {
+            return self.default_header_rules.clone();
+        }
+        self.header_rules
+            .get(&self.upstream_key(req))
+            .unwrap_or(&self.default_header_rules)
+            .clone()
+    }
+
     /// Check if this proxy service has MCP handling enabled.
     pub fn has_mcp_handler(&self) -> bool {
-        self.mcp_handler.is_some()
+        self.mcp_handler.is_some() || !self.mcp_routes.is_empty()
+    }
+
+    /// Select the MCP handler for a request path.
+    ///
+    /// `/mcp/v1/{server}` selects that server's route. Unrouted paths use
+    /// the default handler, but only when no routes are configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ThoughtGateError::InvalidRequest` if the named server has no
+    /// route, or if routes are configured and the path names no server.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+    pub fn route_mcp(&self, path: &str) -> Result<&Arc<McpHandler>, ThoughtGateError> {
+        match mcp_server_id(path) {
+            Some(server) => self
+                .mcp_routes
+                .get(server)
+                .or_else(|| {
+                    self.mcp_handler
+                        .as_ref()
+                        .filter(|h| self.mcp_routes.is_empty() && h.source_id() == server)
+                })
+                .ok_or_else(|| ThoughtGateError::InvalidRequest {
+                    details: format!("No upstream route for server '{server}'"),
+                }),
+            None if self.mcp_routes.is_empty() => {
+                self.mcp_handler
+                    .as_ref()
+                    .ok_or_else(|| ThoughtGateError::InvalidRequest {
+                        details: "No MCP handler configured".to_string(),
+                    })
+            }
+            None => Err(ThoughtGateError::InvalidRequest {
+                details: "Request must name a server: POST /mcp/v1/{server}".to_string(),
+            }),
+        }
     }
 
     /// Get a reference to the proxy configuration.
@@ -176,31 +445,180 @@ impl ProxyService {
         &self.config
     }
 
+    /// Choose the Green or Amber path for a request given its policy decision.
+    ///
+    /// See [`ProxyConfig::select_path`] for how the size overrides apply.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-002 Section 3.2 (Path Overrides)
+    pub fn select_path<B>(&self, req: &Request<B>, decision: PolicyDecision) -> PolicyDecision {
+        self.config
+            .select_path(decision, request_content_length(req))
+    }
+
     /// Handle an incoming request, discriminating between MCP and HTTP traffic.
     ///
     /// This is the main entry point for all traffic. It:
-    /// 1. Discriminates traffic type (MCP vs HTTP)
-    /// 2. Routes MCP traffic through McpHandler (if configured)
-    /// 3. Routes HTTP traffic through zero-copy streaming
+    /// 1. Routes protocol upgrades through the upgrade relay
+    /// 2. Discriminates traffic type (MCP vs HTTP)
+    /// 3. Routes MCP traffic through McpHandler (if configured)
+    /// 4. Routes HTTP traffic through zero-copy streaming
+    ///
+    /// A request whose client disconnects first is logged and counted in
+    /// `client_disconnects_total`. A request with a deadline (from its
+    /// headers or `request_deadline`) is bounded by it as a whole. With
+    /// in-flight limits configured, a request is only handled once
+    /// admitted, and counts against the limits until its response body
+    /// ends.
+    ///
+    /// # Errors
     ///
+    /// - `HeadersTooLarge` (431) - Too many or too large request headers
+    /// - `Overloaded` (503) - Too many requests in flight
     /// # Traceability
     /// - Implements: REQ-CORE-003/F-002 (MCP Traffic Detection)
     /// - Implements: REQ-CORE-001 (Zero-Copy Peeking Strategy)
+    /// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
+    /// - Implements: REQ-CORE-005 (Operational Lifecycle)
+    /// - Implements: REQ-CORE-001 Section 3.2 (Concurrency Limit)
+    /// - Implements: REQ-CORE-001 Section 3.2 (Header Limits)
     pub async fn handle_request(
         &self,
         req: Request<Incoming>,
     ) -> ProxyResult<Response<UnifiedBody>> {
+        check_header_limits(&req, &self.config)?;
+        let breakdown = LatencyBreakdown::start();
+        let guard = DisconnectGuard::new(&req);
+        let admission = match &self.admission {
+            Some(admission) => match admission.admit(&self.upstream_key(&req)).await {
+                Ok(admitted) => Some(admitted),
+                Err(e) => {
+                    guard.completed();
+                    return Err(e);
+                }
+            },
+            None => None,
+        };
+        let dispatch = Box::pin(async {
+            match Deadline::for_request(req.headers(), self.config.request_deadline) {
+                Some(deadline) => self.dispatch_with_deadline(req, deadline).await,
+                None => self.dispatch_request(req).await,
+            }
+        });
+        let result = latency::with_breakdown(breakdown.clone(), dispatch).await;
+        guard.completed();
+        if let Some(metrics) = crate::metrics::get_latency_metrics() {
+            breakdown.record(&metrics);
+        }
+        match admission {
+            Some(admitted) => result.map(|response| hold_admission(response, admitted)),
+            None => result,
+        }
+    }
+
+    /// The upstream a request counts against for `max_in_flight_per_upstream`:
+    /// its routed MCP server, or else the authority it will be sent to.
+    fn upstream_key<B>(&self, req: &Request<B>) -> String {
+        if let Some(server) = mcp_server_id(req.uri().path())
+            && self.mcp_routes.contains_key(server)
+        {
+            return server.to_string();
+        }
+        self.extract_target_uri(req)
+            .ok()
+            .and_then(|uri| uri.authority().map(|a| a.to_string()))
+            .unwrap_or_default()
+    }
+
+    /// Dispatch a request that must be answered by `deadline`.
+    ///
+    /// The deadline is visible to every phase through
+    /// [`deadline::current`], so waits such as a blocking approval can end
+    /// themselves cleanly; whatever phase is still running when it passes
+    /// is cancelled.
+    ///
+    /// # Errors
+    ///
+    /// - `DeadlineExceeded` (504) - The deadline passed before a response
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+    async fn dispatch_with_deadline(
+        &self,
+        req: Request<Incoming>,
+        deadline: Deadline,
+    ) -> ProxyResult<Response<UnifiedBody>> {
+        let method = req.method().clone();
+        let uri = req.uri().clone();
+        let bounded = tokio::time::timeout_at(deadline.instant(), self.dispatch_request(req));
+        match deadline::with_deadline(deadline, bounded).await {
+            Ok(result) => result,
+            Err(_) => {
+                warn!(
+                    method = %method,
+                    uri = %uri,
+                    budget_ms = deadline.budget().as_millis() as u64,
+                    "Request deadline exceeded"
+                );
+                Err(ProxyError::DeadlineExceeded(deadline.budget()))
+            }
+        }
+    }
+
+    /// Route a request to the handler for its kind of traffic.
+    async fn dispatch_request(&self, req: Request<Incoming>) -> ProxyResult<Response<UnifiedBody>> {
+        if let Some((engine, token)) = &self.policy_simulator
+            && req.uri().path() == POLICY_SIMULATE_PATH
+        {
+            return handle_policy_simulate(req, engine, token).await;
+        }
+
+        if is_upgrade_request(&req) {
+            return self.handle_upgrade_request(req).await;
+        }
+
+        let req = match self.mcp_handler {
+            Some(ref mcp_handler) if !self.transports.is_empty() => {
+                let (head, body) = req.into_parts();
+                if let Some(transport) = self.transports.iter().find(|t| t.matches(&head)) {
+                    return self
+                        .handle_transport_request(
+                            head,
+                            body,
+                            transport.clone(),
+                            mcp_handler.clone(),
+                        )
+                        .await;
+                }
+                Request::from_parts(head, body)
+            }
+            _ => req,
+        };
+
         let traffic_type = discriminate_traffic(&req);
 
         match traffic_type {
             TrafficType::Mcp => {
-                if let Some(ref mcp_handler) = self.mcp_handler {
+                if self.has_mcp_handler() {
+                    let mcp_handler = match self.route_mcp(req.uri().path()) {
+                        Ok(handler) => handler.clone(),
+                        Err(e) => {
+                            warn!(uri = %req.uri(), error = %e, "Unrouted MCP request");
+                            return unrouted_response(&e);
+                        }
+                    };
                     debug!(
                         method = %req.method(),
                         uri = %req.uri(),
+                        server = mcp_handler.source_id(),
                         "MCP traffic detected, routing to McpHandler"
                     );
-                    self.handle_mcp_request(req, mcp_handler.clone()).await
+                    // The upstream client applies the request rules
+                    let rules = self.header_rules_for(&req);
+                    let version = req.version();
+                    let mut response = self.handle_mcp_request(req, mcp_handler).await?;
+                    rules.apply_response(response.headers_mut(), version);
+                    Ok(response)
                 } else {
                     // No MCP handler configured, fall through to HTTP passthrough
                     debug!(
@@ -217,23 +635,83 @@ impl ProxyService {
 
     /// Handle MCP traffic by buffering the body and passing to McpHandler.
     ///
+    /// A request for one of `early_forward_methods` is instead streamed
+    /// upstream once its method has been read (see [`Self::forward_early`]).
+    ///
     /// # Traceability
     /// - Implements: REQ-CORE-003 (MCP Transport & Routing)
+    /// - Implements: REQ-CORE-001 (Zero-Copy Peeking Strategy)
     async fn handle_mcp_request(
         &self,
         req: Request<Incoming>,
         mcp_handler: Arc<McpHandler>,
     ) -> ProxyResult<Response<UnifiedBody>> {
-        // Buffer the request body
-        let (_parts, body) = req.into_parts();
+        if let Some(lifecycle) = &self.lifecycle
+            && !lifecycle.policies_initialized()
+        {
+            warn!("MCP request received before policies loaded, refusing");
+            let error = ThoughtGateError::ServiceUnavailable {
+                reason: "Policies are still loading".to_string(),
+            };
+            let mut response = error_response(StatusCode::SERVICE_UNAVAILABLE, &error, None)?;
+            response.headers_mut().insert(
+                header::RETRY_AFTER,
+                header::HeaderValue::from(POLICY_LOAD_RETRY_AFTER_SECS),
+            );
+            return Ok(response);
+        }
+
+        let mut tapped = self.tap.as_ref().and_then(|tap| tap.capture(&req));
+
+        // Only a single upstream can be reached without the handler
+        let early_target =
+            if self.config.early_forward_methods.is_empty() || !self.mcp_routes.is_empty() {
+                None
+            } else {
+                self.extract_target_uri(&req).ok()
+            };
+
+        // Headers as the upstream would see them, kept for replay after approval
+        let rules = self.header_rules_for(&req);
+        let client = req.extensions().get::<PeerAddr>().map(|peer| peer.0.ip());
+        let (parts, mut body) = req.into_parts();
+        let signal_version = self.governance_trailers_for(&parts);
+        let mut headers = parts.headers;
+        strip_connection_headers(&mut headers);
+        rules.apply_request(&mut headers, client, parts.version);
 
         // Check body size limit before collecting
         let max_body_size = mcp_handler.max_body_size();
 
-        // Collect body with size limit check
+        let mut prefix = BytesMut::new();
+        if let Some(target_uri) = early_target {
+            let method;
+            (prefix, method) = peek_mcp_method(&mut body, max_body_size).await?;
+            if let Some(method) = method
+                && McpRouter::is_pass_through(&method)
+                && self.config.early_forward_methods.contains(&method)
+            {
+                let mut head = Request::new(());
+                *head.method_mut() = parts.method;
+                *head.uri_mut() = target_uri;
+                *head.version_mut() = parts.version;
+                *head.headers_mut() = headers;
+                let rest = Limited::new(body, max_body_size.saturating_sub(prefix.len()));
+                return self
+                    .forward_early(&method, head, prefix.freeze(), rest, signal_version)
+                    .await;
+            }
+        }
+
+        // Buffer the rest of the request body, then check the size limit
         let body_bytes = match body.collect().await {
             Ok(collected) => {
-                let bytes = collected.to_bytes();
+                let bytes = if prefix.is_empty() {
+                    collected.to_bytes()
+                } else {
+                    prefix.extend_from_slice(&collected.to_bytes());
+                    prefix.freeze()
+                };
                 if bytes.len() > max_body_size {
                     warn!(
                         size = bytes.len(),
@@ -263,176 +741,628 @@ impl ProxyService {
         };
 
         debug!(size = body_bytes.len(), "Collected MCP request body");
+        if let Some(capture) = tapped.as_mut() {
+            capture.request_body(body_bytes.clone());
+        }
+
+        if let Some(quota) = &self.quota
+            && let Some((error, id)) = latency::governance(check_quota(quota, &body_bytes)).await
+        {
+            return error_response(StatusCode::TOO_MANY_REQUESTS, &error, id);
+        }
 
-        // Handle the MCP request - returns (StatusCode, Bytes) directly
-        // This avoids double-buffering (Simplification #5)
-        let (status, response_bytes) = mcp_handler.handle(body_bytes).await;
+        if let Some(response) =
+            latency::governance(self.replay_task_result(&body_bytes, &mcp_handler)).await
+        {
+            return response;
+        }
+
+        // Handle the MCP request - returns the body as Bytes directly
+        // This avoids double-buffering (Simplification #5). Governance and
+        // the upstream call both happen inside the handler.
+        let reply = latency::governance(with_request_headers(
+            headers,
+            mcp_handler.handle_reply(body_bytes),
+        ))
+        .await;
+        let response_bytes = reply.body;
 
         // Build unified response directly from bytes
         // Full<Bytes> has Infallible error - convert using absurd pattern
-        Response::builder()
-            .status(status)
-            .header(header::CONTENT_TYPE, "application/json")
-            .body(Full::new(response_bytes).map_err(|e| match e {}).boxed())
-            .map_err(|e| ProxyError::Connection(e.to_string()))
+        let mut builder = Response::builder()
+            .status(reply.status)
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(secs) = reply.retry_after {
+            builder = builder.header(header::RETRY_AFTER, secs);
+        }
+        if self.config.reject_headers
+            && let Some(code) = reply.reject_code
+        {
+            builder = builder
+                .header(DECISION_TRAILER, "reject")
+                .header(REJECT_CODE_HEADER, code);
+        }
+        let response = builder
+            .body(
+                Full::new(response_bytes.clone())
+                    .map_err(|e| match e {})
+                    .boxed(),
+            )
+            .map_err(|e| ProxyError::Connection(e.to_string()))?;
+        if let Some(capture) = tapped {
+            capture.finish(response.status(), response.headers(), response_bytes);
+        }
+        Ok(response)
     }
 
-    /// Handle an incoming HTTP request with zero-copy streaming.
+    /// Stream a pass-through MCP request upstream before its body has arrived.
+    ///
+    /// `head` carries the upstream URI and headers. `prefix` is what was read
+    /// to classify the request; `rest` follows as it arrives, limited to what the
+    /// MCP body size limit leaves. The request goes to the proxy's upstream,
+    /// like other streamed traffic.
     ///
     /// # Traceability
+    /// - Implements: REQ-CORE-001 (Zero-Copy Peeking Strategy)
     /// - Implements: REQ-CORE-001 F-001 (Zero-Copy using bytes::Bytes and BodyStream)
-    /// - Implements: REQ-CORE-001 F-002 (Fail-Fast Error Propagation)
-    /// - Implements: REQ-CORE-001 F-003 (Transparency - preserve Content-Length/Transfer-Encoding)
-    /// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
-    pub async fn handle_http_request(
+    async fn forward_early(
         &self,
-        req: Request<Incoming>,
+        mcp_method: &str,
+        head: Request<()>,
+        prefix: Bytes,
+        rest: Limited<Incoming>,
+        signal_version: Option<http::Version>,
     ) -> ProxyResult<Response<UnifiedBody>> {
-        // Extract target URI from request
-        let target_uri = self.extract_target_uri(&req)?;
+        let target_uri = head.uri().clone();
+        debug!(
+            mcp_method,
+            target = %target_uri,
+            buffered = prefix.len(),
+            "Streaming MCP request before body is received"
+        );
 
-        // Check for protocol upgrade (REQ-CORE-001 F-004)
-        let is_upgrade = is_upgrade_request(&req);
-        let upgrade_protocol = if is_upgrade {
-            get_upgrade_protocol(&req)
-        } else {
-            None
+        let prefix_frame = (!prefix.is_empty()).then(|| Ok(Frame::data(prefix)));
+        let rest = BodyStream::new(rest).map(|result| {
+            result.map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
+                Box::new(std::io::Error::other(format!("Body stream error: {}", e)))
+            })
+        });
+        let body_stream = futures_util::stream::iter(prefix_frame).chain(rest);
+        let boxed_body: ClientBody = BodyExt::boxed(StreamBody::new(body_stream));
+
+        let upstream_req = head.map(|()| boxed_body);
+        let method = upstream_req.method().clone();
+        let mut upstream_res = latency::upstream(self.client.request(upstream_req))
+            .await
+            .map_err(|e| map_hyper_error(e, &method, &target_uri))?;
+        strip_connection_headers(upstream_res.headers_mut());
+
+        let response = stream_response(upstream_res, &self.config, self.sse_policy.as_ref(), false);
+        Ok(match signal_version {
+            Some(version) => with_governance_trailers(response, version, "forward", false),
+            None => response,
+        })
+    }
+
+    /// Answer `tasks/result` for an approved task by replaying its request.
+    ///
+    /// Returns `None` for any other request, which is left to the handler.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-GOV-002/F-006 (Execution of approved requests)
+    async fn replay_task_result(
+        &self,
+        body: &[u8],
+        mcp_handler: &McpHandler,
+    ) -> Option<ProxyResult<Response<UnifiedBody>>> {
+        let engine = mcp_handler.approval_engine()?;
+        let value: serde_json::Value = serde_json::from_slice(body).ok()?;
+        if value.get("method")?.as_str()? != "tasks/result" {
+            return None;
+        }
+        let params: crate::protocol::TasksResultRequest =
+            serde_json::from_value(value.get("params")?.clone()).ok()?;
+        if !engine.is_replayable(&params.task_id) {
+            return None;
+        }
+        let id = value
+            .get("id")
+            .and_then(|id| serde_json::from_value::<JsonRpcId>(id.clone()).ok());
+
+        Some(match self.replay(&params.task_id).await {
+            Ok(result) => serde_json::to_value(result)
+                .map_err(|e| ProxyError::Connection(e.to_string()))
+                .and_then(|result| {
+                    let body = serde_json::to_vec(&JsonRpcResponse::success(id, result))
+                        .map_err(|e| ProxyError::Connection(e.to_string()))?;
+                    Response::builder()
+                        .status(StatusCode::OK)
+                        .header(header::CONTENT_TYPE, "application/json")
+                        .body(Full::new(Bytes::from(body)).map_err(|e| match e {}).boxed())
+                        .map_err(|e| ProxyError::Connection(e.to_string()))
+                }),
+            Err(error) => {
+                let status = crate::error::status::status_for_jsonrpc_code(
+                    error.to_jsonrpc_error("").code.into(),
+                );
+                error_response(status, &error, id)
+            }
+        })
+    }
+
+    /// Replay an approved task's stored request to the upstream.
+    ///
+    /// The request is sent as it was received, the same JSON-RPC message
+    /// and headers, through the source's upstream client. Before it is
+    /// sent the approval must still be valid and the live policy must
+    /// still permit the call with the approval grant in its context; a
+    /// task whose policy has since changed to deny fails with
+    /// `PolicyDenied` and is never replayed. The outcome completes or
+    /// fails the task.
+    ///
+    /// A task that is not approved, or whose request did not come through
+    /// the proxy, is answered as `tasks/result` would be.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `tasks/result`, or `ServiceUnavailable`
+    /// if no approval engine is configured.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-GOV-002/F-004 (Policy re-evaluation)
+    /// - Implements: REQ-GOV-002/F-006 (Execution of approved requests)
+    pub async fn replay(&self, task_id: &TaskId) -> Result<ToolCallResult, ThoughtGateError> {
+        let Some((handler, engine)) = self
+            .mcp_handler
+            .as_ref()
+            .and_then(|handler| Some((handler, handler.approval_engine()?)))
+        else {
+            return Err(ThoughtGateError::ServiceUnavailable {
+                reason: "Approval engine not configured".to_string(),
+            });
+        };
+        if !engine.is_replayable(task_id) {
+            return engine.execute_on_result(task_id).await;
+        }
+
+        let claim = engine.begin_replay(task_id, handler.cedar_engine())?;
+        let timeout = engine.config().execution_timeout;
+        let send = handler.upstream().replay(claim.request());
+        let outcome = match tokio::time::timeout(timeout, send).await {
+            Ok(outcome) => outcome,
+            Err(_) => Err(ThoughtGateError::UpstreamTimeout {
+                url: handler.source_id().to_string(),
+                timeout_secs: timeout.as_secs(),
+            }),
         };
+        engine.finish_replay(claim, outcome)
+    }
 
-        if is_upgrade {
-            info!(
-                method = %req.method(),
-                uri = %req.uri(),
-                target = %target_uri,
-                upgrade_protocol = ?upgrade_protocol,
-                "Proxying upgrade request"
-            );
-        } else {
-            info!(
-                method = %req.method(),
-                uri = %req.uri(),
-                target = %target_uri,
-                "Proxying request"
-            );
+    /// Handle a request on a governed [`Transport`] (e.g. gRPC).
+    ///
+    /// The body is read only until the transport can identify the call,
+    /// capped at the MCP body size limit. A refused call is answered in the
+    /// transport's own error format without contacting upstream. A permitted
+    /// call is streamed upstream as the buffered prefix followed by the rest
+    /// of the body, so framing and trailers pass through unchanged.
+    ///
+    /// A call that needs approval in blocking mode is held here, with the
+    /// rest of its body unread, until the decision. Once approved it is
+    /// streamed like a permitted call, however large the body, and the
+    /// upstream's response status is recorded on the task.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-008/F-002 (Transport Call Governance)
+    /// - Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    async fn handle_transport_request(
+        &self,
+        head: http::request::Parts,
+        mut body: Incoming,
+        transport: Arc<dyn Transport>,
+        mcp_handler: Arc<McpHandler>,
+    ) -> ProxyResult<Response<UnifiedBody>> {
+        let max_body_size = mcp_handler.max_body_size();
+        let mut prefix = BytesMut::new();
+        let mut trailers = None;
+        loop {
+            let needed = transport.needed(&head, &prefix);
+            if needed == 0 {
+                break;
+            }
+            if prefix.len().saturating_add(needed) > max_body_size {
+                let error = ThoughtGateError::InvalidParams {
+                    details: format!(
+                        "{} message exceeds maximum size of {max_body_size} bytes",
+                        transport.name()
+                    ),
+                };
+                return Ok(transport_rejection(transport.as_ref(), &error));
+            }
+            match body.frame().await {
+                Some(Ok(frame)) => match frame.into_data() {
+                    Ok(data) => prefix.extend_from_slice(&data),
+                    Err(frame) => {
+                        trailers = frame.into_trailers().ok();
+                        break;
+                    }
+                },
+                Some(Err(e)) => {
+                    error!(error = %e, transport = transport.name(), "Failed to read request body");
+                    return Err(ProxyError::Connection(format!(
+                        "Failed to read request body: {}",
+                        e
+                    )));
+                }
+                // Body ended early: identify() reports what is missing
+                None => break,
+            }
         }
+        let prefix = prefix.freeze();
+
+        let decision = match transport.identify(&head, &prefix) {
+            Ok(call) => {
+                debug!(
+                    transport = transport.name(),
+                    method = %call.method,
+                    resource = %call.resource,
+                    "Transport call identified"
+                );
+                latency::governance(mcp_handler.authorize_call(&call)).await
+            }
+            Err(e) => Err(e),
+        };
+        let approval = match decision {
+            Ok(approval) => approval,
+            Err(e) => {
+                warn!(
+                    transport = transport.name(),
+                    path = %head.uri.path(),
+                    error = %e,
+                    "Transport call refused"
+                );
+                return Ok(transport_rejection(transport.as_ref(), &e));
+            }
+        };
 
-        // Split request into parts and body
-        let (parts, incoming_body) = req.into_parts();
+        let signal_version = self.governance_trailers_for(&head);
+        let head = Request::from_parts(head, ());
+        let target_uri = self.extract_target_uri(&head)?;
+        let (parts, ()) = head.into_parts();
+        info!(
+            transport = transport.name(),
+            uri = %parts.uri,
+            target = %target_uri,
+            "Proxying transport call"
+        );
 
-        // Build upstream request
+        let version = if transport.requires_http2() {
+            http::Version::HTTP_2
+        } else {
+            parts.version
+        };
         let mut upstream_req = Request::builder()
-            .method(parts.method.clone())
+            .method(parts.method)
             .uri(&target_uri)
-            .version(parts.version);
-
-        // Copy headers (excluding hop-by-hop headers)
+            .version(version);
         let headers = upstream_req.headers_mut().ok_or_else(|| {
             error!("Failed to get mutable headers from request builder");
             ProxyError::Connection("Request builder in invalid state".to_string())
         })?;
-        for (name_opt, value) in parts.headers {
-            if let Some(name) = name_opt
-                && !is_hop_by_hop_header(name.as_str())
-            {
-                headers.insert(name, value);
+        for (name, value) in &parts.headers {
+            if !is_hop_by_hop_header(name.as_str()) {
+                headers.append(name, value.clone());
             }
         }
+        transport.prepare_upstream(headers);
 
-        // Convert Incoming body to zero-copy streaming body
-        let body_stream = BodyStream::new(incoming_body);
-        let mapped_stream = body_stream.map(|result| {
+        // Buffered prefix, then the rest of the body (empty if it already
+        // ended), then any trailers read while buffering
+        let prefix_frame = (!prefix.is_empty()).then(|| Ok(Frame::data(prefix)));
+        let trailer_frame = trailers.map(|t| Ok(Frame::trailers(t)));
+        let rest = BodyStream::new(body).map(|result| {
             result.map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
                 Box::new(std::io::Error::other(format!("Body stream error: {}", e)))
             })
         });
-
-        let stream_body = StreamBody::new(mapped_stream);
-        let boxed_body: ClientBody = BodyExt::boxed(stream_body);
+        let body_stream = futures_util::stream::iter(prefix_frame)
+            .chain(rest)
+            .chain(futures_util::stream::iter(trailer_frame));
+        let boxed_body: ClientBody = BodyExt::boxed(StreamBody::new(body_stream));
 
         let upstream_req = upstream_req.body(boxed_body).map_err(|e| {
             error!(error = %e, "Failed to build upstream request");
             ProxyError::Connection(format!("Failed to build request: {}", e))
         })?;
-
-        // Send request and stream response (zero-copy, no buffering)
-        // Map hyper errors to appropriate ProxyError variants (REQ-CORE-001 F-002)
-        let upstream_res = self
-            .client
-            .request(upstream_req)
+        let client = if transport.requires_http2() {
+            &self.h2_client
+        } else {
+            &self.client
+        };
+        let method = upstream_req.method().clone();
+        let upstream_res = latency::upstream(client.request(upstream_req))
             .await
-            .map_err(map_hyper_error)?;
+            .map_err(|e| map_hyper_error(e, &method, &target_uri));
+        if let Some(claim) = approval {
+            claim.finish(match &upstream_res {
+                Ok(res) => Ok(res.status()),
+                Err(e) => Err(ThoughtGateError::UpstreamConnectionFailed {
+                    url: target_uri.to_string(),
+                    reason: e.to_string(),
+                }),
+            });
+        }
 
-        // TODO(REQ-CORE-001 F-005): KNOWN LIMITATION - Timeout Wrapping
-        //
-        // Current State: TimeoutBody and ProxyBody wrappers exist but are not applied
-        // to the Green Path response bodies.
-        //
-        // Why: The current architecture returns `Response<Incoming>` directly from the
-        // client. Wrapping the body would change the return type to
-        // `Response<ProxyBody<TimeoutBody<Incoming>>>`, which would require:
-        // 1. Type erasure via BoxBody (adds allocation overhead)
-        // 2. Changes to Service trait implementation
-        // 3. Updates to all call sites in main.rs
-        //
-        // Impact: The proxy is vulnerable to slow-read attacks on the Green Path.
-        // Chunks can be delayed indefinitely without triggering timeouts.
-        //
-        // Remediation Path:
-        // 1. Change return type to use BoxBody for type erasure
-        // 2. Apply TimeoutBody wrapper with config from ProxyConfig
-        // 3. Apply ProxyBody wrapper for metrics and cancellation
-        // 4. Update Service trait and main.rs to handle boxed bodies
-        //
-        // Note: The Amber Path (BufferedForwarder) already has timeout protection
-        // via tokio::time::timeout wrapping the entire buffering operation.
+        let response =
+            stream_response(upstream_res?, &self.config, self.sse_policy.as_ref(), false);
+        Ok(match signal_version {
+            Some(version) => with_governance_trailers(response, version, "forward", true),
+            None => response,
+        })
+    }
 
-        // Log if upgrade was successful (REQ-CORE-001 F-004)
-        if is_upgrade && is_upgrade_response(&upstream_res) {
-            info!(
-                status = %upstream_res.status(),
-                upgrade_protocol = ?upgrade_protocol,
-                "Protocol upgrade successful - relying on hyper's internal handling"
-            );
+    /// Whether the response to this request should carry the governance
+    /// trailers, returning the client's HTTP version if so.
+    ///
+    /// Trailers are only sent when enabled and the client can receive them:
+    /// over HTTP/2, or over HTTP/1.1 when it announced `TE: trailers`. HEAD
+    /// responses have no body to end with trailers.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-003 (Trailer Support)
+    fn governance_trailers_for(&self, parts: &http::request::Parts) -> Option<http::Version> {
+        // Matches hyper's HTTP/1 server, which only sends trailers for an
+        // exact `TE: trailers`
+        let accepted = parts.version >= http::Version::HTTP_2
+            || parts
+                .headers
+                .get(header::TE)
+                .is_some_and(|te| te == "trailers");
+        (self.config.governance_trailers && accepted && parts.method != http::Method::HEAD)
+            .then_some(parts.version)
+    }
+
+    /// Whether the streamed response to this request may be gzipped.
+    ///
+    /// Requires `compress_responses` and a client that accepts gzip. HEAD
+    /// responses have no body to compress. Whether the response itself
+    /// qualifies is decided once it arrives (see [`compressible`]).
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-001 (Zero-Copy Forwarding)
+    fn compression_for(&self, parts: &http::request::Parts) -> bool {
+        self.config.compress_responses
+            && parts.method != http::Method::HEAD
+            && accepts_gzip(&parts.headers)
+    }
+
+    /// Handle an incoming HTTP request with zero-copy streaming.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-001 (Zero-Copy using bytes::Bytes and BodyStream)
+    /// - Implements: REQ-CORE-001 F-002 (Fail-Fast Error Propagation)
+    /// - Implements: REQ-CORE-001 F-003 (Transparency - preserve Content-Length/Transfer-Encoding)
+    pub async fn handle_http_request(
+        &self,
+        req: Request<Incoming>,
+    ) -> ProxyResult<Response<UnifiedBody>> {
+        // Extract target URI from request
+        let target_uri = self.extract_target_uri(&req)?;
+
+        info!(
+            method = %req.method(),
+            uri = %req.uri(),
+            target = %target_uri,
+            "Proxying request"
+        );
+
+        let rules = self.header_rules_for(&req);
+        let client = req.extensions().get::<PeerAddr>().map(|peer| peer.0.ip());
+
+        // Split request into parts and body
+        let (mut parts, incoming_body) = req.into_parts();
+        let signal_version = self.governance_trailers_for(&parts);
+        let gzip = self.compression_for(&parts);
+
+        // Build upstream request
+        let mut upstream_req = Request::builder()
+            .method(parts.method.clone())
+            .uri(&target_uri)
+            .version(parts.version);
+
+        // Copy headers, minus connection-specific ones, then apply the
+        // upstream's header rules
+        let headers = upstream_req.headers_mut().ok_or_else(|| {
+            error!("Failed to get mutable headers from request builder");
+            ProxyError::Connection("Request builder in invalid state".to_string())
+        })?;
+        *headers = std::mem::take(&mut parts.headers);
+        strip_connection_headers(headers);
+        rules.apply_request(headers, client, parts.version);
 
-            // TODO(REQ-CORE-001 F-004): KNOWN LIMITATION - Upgrade Handling
-            //
-            // Current State: We detect upgrades and preserve necessary headers, but rely on
-            // hyper's internal upgrade handling rather than explicitly using hyper::upgrade::on()
-            // and tokio::io::copy_bidirectional().
-            //
-            // Why: The current architecture uses hyper_util::client::legacy::Client which abstracts
-            // away the underlying connection. To properly implement "opaque TCP pipe" semantics per
-            // REQ-CORE-001 F-004, we would need to:
-            //
-            // 1. Use hyper::upgrade::on() on both client request and upstream response
-            // 2. Extract the underlying IO from both connections
-            // 3. Use tokio::io::copy_bidirectional() for the relay
-            //
-            // This requires architectural changes:
-            // - Replace Client API with manual connection pooling
-            // - Track raw TcpStreams for both client and upstream
-            // - Implement custom upgrade detection and handshake coordination
-            //
-            // Impact: For most upgrade scenarios (WebSocket, HTTP/2), hyper's internal handling
-            // is sufficient. However, for strict "opaque TCP pipe" semantics and full control
-            // over the bidirectional relay, explicit handling is preferred.
-            //
-            // Next Steps:
-            // - Implement custom connection pool that exposes raw IO
-            // - Add explicit upgrade handler in handle_connection()
-            // - Add integration test that verifies bidirectional data flow
-        }
-
-        // Convert the Incoming body to UnifiedBody for type unification
-        let (parts, body) = upstream_res.into_parts();
-        let body_stream = BodyStream::new(body);
+        // Convert Incoming body to zero-copy streaming body
+        let body_stream = BodyStream::new(incoming_body);
         let mapped_stream = body_stream.map(|result| {
-            result.map_err(|e| ProxyError::Connection(format!("Body stream error: {}", e)))
+            result.map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
+                Box::new(std::io::Error::other(format!("Body stream error: {}", e)))
+            })
         });
+
         let stream_body = StreamBody::new(mapped_stream);
-        let boxed_body: UnifiedBody = BodyExt::boxed(stream_body);
+        let boxed_body: ClientBody = BodyExt::boxed(stream_body);
+
+        let upstream_req = upstream_req.body(boxed_body).map_err(|e| {
+            error!(error = %e, "Failed to build upstream request");
+            ProxyError::Connection(format!("Failed to build request: {}", e))
+        })?;
+
+        // Send request and stream response (zero-copy, no buffering)
+        // Map hyper errors to appropriate ProxyError variants (REQ-CORE-001 F-002)
+        let method = upstream_req.method().clone();
+        let mut upstream_res = latency::upstream(self.client.request(upstream_req))
+            .await
+            .map_err(|e| map_hyper_error(e, &method, &target_uri))?;
+        let version = upstream_res.version();
+        strip_connection_headers(upstream_res.headers_mut());
+        rules.apply_response(upstream_res.headers_mut(), version);
+
+        // TODO(REQ-CORE-001 F-005): KNOWN LIMITATION - Timeout Wrapping
+        //
+        // Current State: The TimeoutBody wrapper is only applied to Green Path
+        // response bodies of requests with a deadline (ProxyBody always is, for
+        // `max_stream_bytes`).
+        //
+        // Why: The current architecture returns `Response<Incoming>` directly from the
+        // client. Wrapping the body would change the return type to
+        // `Response<ProxyBody<TimeoutBody<Incoming>>>`, which would require:
+        // 1. Type erasure via BoxBody (adds allocation overhead)
+        // 2. Changes to Service trait implementation
+        // 3. Updates to all call sites in main.rs
+        //
+        // Impact: Without a deadline, the proxy is vulnerable to slow-read attacks
+        // on the Green Path. Chunks can be delayed indefinitely without
+        // triggering timeouts.
+        //
+        // Remediation Path:
+        // 1. Change return type to use BoxBody for type erasure
+        // 2. Apply TimeoutBody wrapper with config from ProxyConfig
+        // 3. Wire ProxyBody's cancellation token to client disconnect
+        // 4. Update Service trait and main.rs to handle boxed bodies
+        //
+        // Note: The Amber Path (BufferedForwarder) already has timeout protection
+        // via tokio::time::timeout wrapping the entire buffering operation.
+
+        let response = stream_response(upstream_res, &self.config, self.sse_policy.as_ref(), gzip);
+        Ok(match signal_version {
+            Some(version) => with_governance_trailers(response, version, "forward", false),
+            None => response,
+        })
+    }
+
+    /// Handle a protocol upgrade request (e.g. WebSocket).
+    ///
+    /// The handshake is authorized by the MCP handler (if configured) before
+    /// anything is sent upstream, so a refused request is never upgraded.
+    /// The handshake is then forwarded; if upstream answers
+    /// `101 Switching Protocols`, both connections are upgraded and relayed
+    /// as an opaque byte stream with the configured stream timeouts. Any
+    /// other upstream response is returned as-is.
+    ///
+    /// # Errors
+    ///
+    /// - `Rejected` (403) - The upgrade was refused by policy
+    /// - Upstream connection errors, as for `handle_http_request`
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
+    /// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+    async fn handle_upgrade_request(
+        &self,
+        mut req: Request<Incoming>,
+    ) -> ProxyResult<Response<UnifiedBody>> {
+        let protocol = get_upgrade_protocol(&req).unwrap_or_default();
+
+        if let Some(ref mcp_handler) = self.mcp_handler {
+            latency::governance(async {
+                mcp_handler.authorize_upgrade(req.uri().path(), &protocol)
+            })
+            .await
+            .map_err(|e| ProxyError::Rejected(e.to_string(), StatusCode::FORBIDDEN))?;
+        }
+
+        let target_uri = self.extract_target_uri(&req)?;
+        info!(
+            method = %req.method(),
+            uri = %req.uri(),
+            target = %target_uri,
+            upgrade_protocol = %protocol,
+            "Proxying upgrade request"
+        );
+
+        // Must be taken before the request is consumed
+        let client_upgrade = hyper::upgrade::on(&mut req);
+
+        // Upgrades are HTTP/1.1 only; the handshake carries no body
+        let mut upstream_req = Request::builder()
+            .method(req.method().clone())
+            .uri(&target_uri)
+            .version(http::Version::HTTP_11);
+        let headers = upstream_req.headers_mut().ok_or_else(|| {
+            error!("Failed to get mutable headers from request builder");
+            ProxyError::Connection("Request builder in invalid state".to_string())
+        })?;
+        for (name, value) in req.headers() {
+            if !is_hop_by_hop_header(name.as_str()) {
+                headers.append(name, value.clone());
+            }
+        }
+        let body: ClientBody = Empty::new().map_err(|e| match e {}).boxed();
+        let upstream_req = upstream_req.body(body).map_err(|e| {
+            error!(error = %e, "Failed to build upstream upgrade request");
+            ProxyError::Connection(format!("Failed to build request: {}", e))
+        })?;
+
+        let method = upstream_req.method().clone();
+        let mut upstream_res = latency::upstream(self.client.request(upstream_req))
+            .await
+            .map_err(|e| map_hyper_error(e, &method, &target_uri))?;
+
+        if !is_upgrade_response(&upstream_res) {
+            debug!(
+                status = %upstream_res.status(),
+                upgrade_protocol = %protocol,
+                "Upstream declined upgrade"
+            );
+            return Ok(stream_response(
+                upstream_res,
+                &self.config,
+                self.sse_policy.as_ref(),
+                false,
+            ));
+        }
+
+        info!(upgrade_protocol = %protocol, "Protocol upgrade successful");
+
+        let upstream_upgrade = hyper::upgrade::on(&mut upstream_res);
+        let config = self.config.clone();
+        tokio::spawn(async move {
+            let (client_io, upstream_io) = match tokio::try_join!(client_upgrade, upstream_upgrade)
+            {
+                Ok(io) => io,
+                Err(e) => {
+                    warn!(error = %e, "Upgrade handshake failed");
+                    return;
+                }
+            };
+            match relay_upgraded(TokioIo::new(client_io), TokioIo::new(upstream_io), &config).await
+            {
+                Ok((to_upstream, to_client)) => debug!(
+                    upgrade_protocol = %protocol,
+                    to_upstream,
+                    to_client,
+                    "Upgraded connection closed"
+                ),
+                Err(e) => warn!(
+                    upgrade_protocol = %protocol,
+                    error = %e,
+                    "Upgraded connection terminated"
+                ),
+            }
+        });
 
-        Ok(Response::from_parts(parts, boxed_body))
+        let (mut parts, _) = upstream_res.into_parts();
+        let hop_by_hop: Vec<_> = parts
+            .headers
+            .keys()
+            .filter(|name| is_hop_by_hop_header(name.as_str()))
+            .cloned()
+            .collect();
+        for name in hop_by_hop {
+            parts.headers.remove(name);
+        }
+        Ok(Response::from_parts(
+            parts,
+            Empty::new().map_err(|e| match e {}).boxed(),
+        ))
     }
 
     /// Extract target URI from request.
@@ -512,118 +1442,885 @@ impl Service<Request<Incoming>> for ProxyService {
 
     fn call(&mut self, req: Request<Incoming>) -> Self::Future {
         let service = self.clone();
-        Box::pin(async move { service.handle_request(req).await })
+        let client_cert = req.extensions().get::<ClientCertificate>().cloned();
+        Box::pin(async move {
+            let Some(ClientCertificate(cert)) = client_cert else {
+                return service.handle_request(req).await;
+            };
+            // A verified certificate that maps to no identity fails closed
+            // rather than falling back to the proxy's own principal.
+            match principal_cache().client_principal(&cert) {
+                Ok(principal) => {
+                    with_client_principal(principal, service.handle_request(req)).await
+                }
+                Err(e) => {
+                    warn!(error = %e, "Rejected request: unusable client certificate");
+                    Err(ProxyError::Rejected(e.to_string(), StatusCode::FORBIDDEN))
+                }
+            }
+        })
     }
 }
 
-/// Check if a header is a hop-by-hop header that shouldn't be forwarded.
+/// Keep `admission` until the response body has been sent or dropped.
 ///
-/// Note: `transfer-encoding` and `upgrade` are NOT filtered per REQ-CORE-001 F-003 and F-004.
-/// As a transparent streaming proxy, we must preserve chunked encoding information.
-/// Upgrade headers are preserved to enable WebSocket and HTTP/2 upgrades.
+/// # Traceability
+/// - Implements: REQ-CORE-001 Section 3.2 (Concurrency Limit)
+fn hold_admission(response: Response<UnifiedBody>, admission: Admission) -> Response<UnifiedBody> {
+    response.map(|body| {
+        body.map_frame(move |frame| {
+            // Owned by the body, so released when it is dropped
+            let _ = &admission;
+            frame
+        })
+        .boxed()
+    })
+}
+
+/// Notices a request whose client disconnected before its response was
+/// ready.
+///
+/// Hyper drops the service future when the client's connection closes.
+/// Everything the request was waiting on goes with it: the upstream request
+/// future (closing that connection) and, through the approval flow's own
+/// guard, any blocking approval wait. This guard only makes the
+/// cancellation visible in logs and metrics.
 ///
 /// # Traceability
-/// - Implements: REQ-CORE-001 F-003 (Transparency - preserve Transfer-Encoding)
-/// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
-#[cfg(feature = "fuzzing")]
-pub fn is_hop_by_hop_header(name: &str) -> bool {
-    is_hop_by_hop_header_impl(name)
+/// - Implements: REQ-CORE-005 (Operational Lifecycle)
+struct DisconnectGuard {
+    method: http::Method,
+    uri: Uri,
+    started: Instant,
+    completed: bool,
 }
 
-#[cfg(not(feature = "fuzzing"))]
-fn is_hop_by_hop_header(name: &str) -> bool {
-    is_hop_by_hop_header_impl(name)
+impl DisconnectGuard {
+    fn new<B>(req: &Request<B>) -> Self {
+        Self {
+            method: req.method().clone(),
+            uri: req.uri().clone(),
+            started: Instant::now(),
+            completed: false,
+        }
+    }
+
+    /// The response is ready; nothing was cancelled.
+    fn completed(mut self) {
+        self.completed = true;
+    }
 }
 
-fn is_hop_by_hop_header_impl(name: &str) -> bool {
-    matches!(
-        name.to_lowercase().as_str(),
-        "keep-alive" | "proxy-authenticate" | "proxy-authorization" | "te" | "trailers" // NOTE: "connection", "upgrade", and "transfer-encoding" intentionally NOT filtered
-                                                                                        // to support WebSocket upgrades (F-004) and transparent streaming (F-003)
-    )
+impl Drop for DisconnectGuard {
+    fn drop(&mut self) {
+        if self.completed {
+            return;
+        }
+        info!(
+            method = %self.method,
+            uri = %self.uri,
+            elapsed_ms = self.started.elapsed().as_millis() as u64,
+            "Client disconnected, request cancelled"
+        );
+        if let Some(metrics) = crate::metrics::get_disconnect_metrics() {
+            metrics.record_disconnect(self.method.as_str());
+        }
+    }
 }
 
-/// Check if a request is attempting a protocol upgrade.
+/// Convert a transport's refusal into a `UnifiedBody` response.
+fn transport_rejection(
+    transport: &dyn Transport,
+    error: &ThoughtGateError,
+) -> Response<UnifiedBody> {
+    transport
+        .reject(error)
+        .map(|body| Full::new(body).map_err(|e| match e {}).boxed())
+}
+
+/// Convert an upstream response into a streaming `UnifiedBody` response.
+///
+/// With `max_stream_bytes`, the stream is aborted with `PayloadTooLarge`
+/// once the body passes that many bytes. When the request has a deadline,
+/// the stream is also bounded by the stream timeouts and whatever is left
+/// of the deadline.
+///
+/// An SSE response is governed event by event (see [`SseBody`]) when there
+/// is an `sse_policy`, or when `sse_block_mode` asks for a terminal event
+/// so a stream cut off by `max_stream_bytes` still ends cleanly.
+///
+/// With `gzip`, a [`compressible`] response is gzipped as it streams (see
+/// [`GzipBody`]); `max_stream_bytes` still counts the uncompressed bytes.
 ///
 /// # Traceability
-/// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
-pub fn is_upgrade_request<B>(req: &Request<B>) -> bool {
-    req.headers()
-        .get("connection")
+/// - Implements: REQ-CORE-001 F-001 (Zero-Copy Forwarding)
+/// - Implements: REQ-CORE-001 F-004 (Slow-Read Protection)
+/// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+/// - Implements: REQ-CORE-002 F-003 (Response Governance)
+fn stream_response(
+    upstream_res: Response<Incoming>,
+    config: &ProxyConfig,
+    sse_policy: Option<&Arc<ResponsePolicy>>,
+    gzip: bool,
+) -> Response<UnifiedBody> {
+    let (mut parts, body) = upstream_res.into_parts();
+    let gzip = gzip && compressible(parts.status, &parts.headers, config);
+    if gzip {
+        mark_gzipped(&mut parts.headers);
+    }
+    let mut body = ProxyBody::new(body, tokio_util::sync::CancellationToken::new());
+    if let Some(limit) = config.max_stream_bytes {
+        body = body.with_max_bytes(limit);
+    }
+    let governed = is_event_stream(&parts.headers)
+        && (sse_policy.is_some() || config.sse_block_mode == SseBlockMode::TerminalEvent);
+    let boxed_body = if governed {
+        // Redaction and the terminal event change the length
+        parts.headers.remove(header::CONTENT_LENGTH);
+        let mut sse = SseBody::new(body, config.sse_block_mode, config.resp_buffer_max);
+        if let Some(policy) = sse_policy {
+            sse = sse.with_policy(policy.clone());
+        }
+        encode_stream(sse, gzip, config)
+    } else {
+        encode_stream(body, gzip, config)
+    };
+
+    Response::from_parts(parts, boxed_body)
+}
+
+/// Whether a response is a `text/event-stream`.
+fn is_event_stream(headers: &http::HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
         .and_then(|v| v.to_str().ok())
-        .map(|v| v.to_lowercase().contains("upgrade"))
-        .unwrap_or(false)
-        && req.headers().contains_key("upgrade")
+        .is_some_and(|ct| ct.trim_start().starts_with("text/event-stream"))
 }
 
-/// Get the upgrade protocol from request headers.
+/// Whether a client accepts gzip, per its `Accept-Encoding`.
 ///
-/// # Traceability
-/// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
-pub fn get_upgrade_protocol<B>(req: &Request<B>) -> Option<String> {
-    req.headers()
-        .get("upgrade")
-        .and_then(|v| v.to_str().ok())
-        .map(|v| v.to_lowercase())
+/// `gzip` (or `x-gzip`) must be listed, or `*` without `gzip` being
+/// excluded, with a non-zero `q` value.
+fn accepts_gzip(headers: &http::HeaderMap) -> bool {
+    let mut wildcard = false;
+    let codings = headers
+        .get_all(header::ACCEPT_ENCODING)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','));
+    for coding in codings {
+        let mut params = coding.split(';');
+        let name = params.next().unwrap_or_default().trim();
+        let accepted = params
+            .filter_map(|param| param.trim().split_once('='))
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case("q"))
+            .is_none_or(|(_, q)| q.trim().parse::<f32>().is_ok_and(|q| q > 0.0));
+        if name.eq_ignore_ascii_case("gzip") || name.eq_ignore_ascii_case("x-gzip") {
+            return accepted;
+        }
+        if name == "*" {
+            wildcard = accepted;
+        }
+    }
+    wildcard
 }
 
-/// Check if a response indicates a successful protocol upgrade.
+/// Whether a response may be gzipped on its way to the client.
+///
+/// Responses already encoded by upstream (any `Content-Encoding`), partial
+/// content, `Cache-Control: no-transform`, content types outside
+/// `compress_content_types`, and bodies known to be under
+/// `compress_min_bytes` are sent as they are.
 ///
 /// # Traceability
-/// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
-pub fn is_upgrade_response<B>(res: &Response<B>) -> bool {
-    res.status() == hyper::StatusCode::SWITCHING_PROTOCOLS
+/// - Implements: REQ-CORE-001 F-001 (Zero-Copy Forwarding)
+fn compressible(status: StatusCode, headers: &http::HeaderMap, config: &ProxyConfig) -> bool {
+    if status.is_informational()
+        || status == StatusCode::NO_CONTENT
+        || status == StatusCode::NOT_MODIFIED
+        || status == StatusCode::PARTIAL_CONTENT
+        || headers.contains_key(header::CONTENT_ENCODING)
+        || headers.contains_key(header::CONTENT_RANGE)
+    {
+        return false;
+    }
+    let no_transform = headers
+        .get_all(header::CACHE_CONTROL)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|directive| directive.trim().eq_ignore_ascii_case("no-transform"));
+    let allowed_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| config.compresses_content_type(ct));
+    let too_small = headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|len| len.trim().parse::<u64>().ok())
+        .is_some_and(|len| len < config.compress_min_bytes);
+    !no_transform && allowed_type && !too_small
 }
 
-/// Extract host from URI.
-#[allow(dead_code)]
-fn extract_host(uri: &Uri) -> ProxyResult<String> {
-    uri.host()
-        .ok_or_else(|| ProxyError::InvalidUri("URI missing host".to_string()))
-        .map(|s| s.to_string())
+/// Rewrite a response's headers for a gzipped body.
+///
+/// The length is no longer known up front, caches must key on
+/// `Accept-Encoding`, and a strong `ETag` no longer matches the bytes sent,
+/// so it is weakened.
+fn mark_gzipped(headers: &mut http::HeaderMap) {
+    headers.remove(header::CONTENT_LENGTH);
+    headers.insert(
+        header::CONTENT_ENCODING,
+        http::HeaderValue::from_static("gzip"),
+    );
+    let varies = headers
+        .get_all(header::VARY)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|name| {
+            let name = name.trim();
+            name == "*" || name.eq_ignore_ascii_case("accept-encoding")
+        });
+    if !varies {
+        headers.append(
+            header::VARY,
+            http::HeaderValue::from_static("accept-encoding"),
+        );
+    }
+    if let Some(etag) = headers.get(header::ETAG)
+        && !etag.as_bytes().starts_with(b"W/")
+        && let Ok(weak) = http::HeaderValue::from_bytes(&[b"W/", etag.as_bytes()].concat())
+    {
+        headers.insert(header::ETAG, weak);
+    }
 }
 
-/// Extract port from URI, defaulting to 80 for HTTP or 443 for HTTPS.
-#[allow(dead_code)]
-fn extract_port(uri: &Uri) -> ProxyResult<u16> {
-    if let Some(port) = uri.port_u16() {
-        Ok(port)
+/// Box a streamed body, gzipped if `gzip`, and bounded by the request's
+/// deadline if it has one.
+fn encode_stream<B>(body: B, gzip: bool, config: &ProxyConfig) -> UnifiedBody
+where
+    B: http_body::Body<Data = Bytes, Error = Box<dyn std::error::Error + Send + Sync>>
+        + Unpin
+        + Send
+        + Sync
+        + 'static,
+{
+    if gzip {
+        bound_stream(GzipBody::new(body), config)
     } else {
-        match uri.scheme_str() {
-            Some("https") => Ok(443),
-            Some("http") => Ok(80),
-            _ => Err(ProxyError::InvalidUri(
-                "Cannot determine port from URI".to_string(),
-            )),
+        bound_stream(body, config)
+    }
+}
+
+/// Box a streamed body, bounded by the request's deadline if it has one.
+fn bound_stream<B>(body: B, config: &ProxyConfig) -> UnifiedBody
+where
+    B: http_body::Body<Data = Bytes, Error = Box<dyn std::error::Error + Send + Sync>>
+        + Unpin
+        + Send
+        + Sync
+        + 'static,
+{
+    match deadline::current() {
+        Some(deadline) => {
+            let timeouts =
+                TimeoutConfig::new(config.stream_read_timeout, config.stream_total_timeout)
+                    .with_deadline(deadline.instant());
+            unify_stream(TimeoutBody::new(body, timeouts))
         }
+        None => unify_stream(body),
     }
 }
 
-/// Configure socket with optimized options for zero-copy streaming.
+/// Box a streamed body, recovering the `ProxyError`s it raised.
+fn unify_stream<B>(body: B) -> UnifiedBody
+where
+    B: http_body::Body<Data = Bytes, Error = Box<dyn std::error::Error + Send + Sync>>
+        + Send
+        + Sync
+        + 'static,
+{
+    let body_stream = BodyStream::new(body);
+    let mapped_stream = body_stream.map(|result| {
+        result.map_err(|e| match e.downcast::<ProxyError>() {
+            Ok(e) => *e,
+            Err(e) => ProxyError::Connection(format!("Body stream error: {}", e)),
+        })
+    });
+    BodyExt::boxed(StreamBody::new(mapped_stream))
+}
+
+/// End a streamed response with the governance trailers.
 ///
-/// # Traceability
-/// - Implements: REQ-CORE-001 Section 3.2 (Network Optimization)
+/// The trailer names are announced (lowercase) in the `Trailer` header, as
+/// HTTP/1.1 servers only send announced trailers. On HTTP/1.x `Content-Length` is
+/// dropped so the response goes out chunked, the only framing that can
+/// carry trailers. The body itself is still streamed frame by frame.
 ///
-/// # Socket Options
-/// - TCP_NODELAY: Disable Nagle's algorithm for low-latency
-/// - SO_KEEPALIVE: Keep connections alive during idle periods
-/// - SO_RCVBUF / SO_SNDBUF: Set buffer sizes for throughput
-pub fn configure_socket(socket: &Socket, config: &ProxyConfig) -> ProxyResult<()> {
-    // Set TCP_NODELAY (disable Nagle's algorithm)
-    socket
-        .set_tcp_nodelay(config.tcp_nodelay)
-        .map_err(|e| ProxyError::Connection(format!("Failed to set TCP_NODELAY: {}", e)))?;
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-003 (Trailer Support)
+fn with_governance_trailers(
+    response: Response<UnifiedBody>,
+    client_version: http::Version,
+    decision: &'static str,
+    inspected: bool,
+) -> Response<UnifiedBody> {
+    let status = response.status();
+    if status.is_informational()
+        || status == StatusCode::NO_CONTENT
+        || status == StatusCode::NOT_MODIFIED
+    {
+        return response;
+    }
 
-    // Set TCP keepalive
-    let keepalive = TcpKeepalive::new().with_time(Duration::from_secs(config.tcp_keepalive_secs));
-    socket
-        .set_tcp_keepalive(&keepalive)
-        .map_err(|e| ProxyError::Connection(format!("Failed to set SO_KEEPALIVE: {}", e)))?;
+    let (mut parts, body) = response.into_parts();
+    if client_version < http::Version::HTTP_2 {
+        parts.headers.remove(header::CONTENT_LENGTH);
+    }
+    parts.headers.append(
+        header::TRAILER,
+        header::HeaderValue::from_static("thoughtgate-decision, thoughtgate-inspected"),
+    );
+
+    let mut trailers = http::HeaderMap::new();
+    trailers.insert(DECISION_TRAILER, header::HeaderValue::from_static(decision));
+    trailers.insert(
+        INSPECTED_TRAILER,
+        header::HeaderValue::from_static(if inspected { "true" } else { "false" }),
+    );
+    let body = ProxyBody::new(body, tokio_util::sync::CancellationToken::new())
+        .with_trailers(trailers)
+        .map_err(|e| ProxyError::Connection(format!("Body stream error: {}", e)));
+
+    Response::from_parts(parts, BodyExt::boxed(body))
+}
 
-    // Set socket buffer sizes
-    socket
-        .set_recv_buffer_size(config.socket_buffer_size)
-        .map_err(|e| ProxyError::Connection(format!("Failed to set SO_RCVBUF: {}", e)))?;
+/// Relay bytes between two upgraded connections until either side closes.
+///
+/// Data is forwarded as soon as it arrives in either direction. The relay
+/// fails with `ProxyError::Timeout` if neither side sends anything for
+/// `stream_read_timeout`, a write stalls for `stream_write_timeout`, or the
+/// connection outlives `stream_total_timeout`. When one side closes, the
+/// other is shut down.
+///
+/// Returns the bytes sent (to upstream, to client).
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
+/// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+async fn relay_upgraded<C, U>(
+    mut client: C,
+    mut upstream: U,
+    config: &ProxyConfig,
+) -> ProxyResult<(u64, u64)>
+where
+    C: AsyncRead + AsyncWrite + Unpin,
+    U: AsyncRead + AsyncWrite + Unpin,
+{
+    let deadline = Instant::now() + config.stream_total_timeout;
+    let mut client_buf = vec![0u8; RELAY_BUFFER_SIZE];
+    let mut upstream_buf = vec![0u8; RELAY_BUFFER_SIZE];
+    let (mut to_upstream, mut to_client) = (0u64, 0u64);
+
+    loop {
+        tokio::select! {
+            read = client.read(&mut client_buf) => {
+                let n = read?;
+                if n == 0 {
+                    let _ = upstream.shutdown().await;
+                    break;
+                }
+                relay_write(&mut upstream, &client_buf[..n], config.stream_write_timeout).await?;
+                to_upstream += n as u64;
+            }
+            read = upstream.read(&mut upstream_buf) => {
+                let n = read?;
+                if n == 0 {
+                    let _ = client.shutdown().await;
+                    break;
+                }
+                relay_write(&mut client, &upstream_buf[..n], config.stream_write_timeout).await?;
+                to_client += n as u64;
+            }
+            _ = sleep(config.stream_read_timeout) => {
+                return Err(ProxyError::Timeout(format!(
+                    "Upgraded connection idle for {:?}",
+                    config.stream_read_timeout
+                )));
+            }
+            _ = sleep_until(deadline) => {
+                return Err(ProxyError::Timeout(format!(
+                    "Upgraded connection exceeded {:?}",
+                    config.stream_total_timeout
+                )));
+            }
+        }
+    }
+
+    Ok((to_upstream, to_client))
+}
+
+/// Write a relayed chunk, failing if the peer stops reading.
+async fn relay_write<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    data: &[u8],
+    write_timeout: Duration,
+) -> ProxyResult<()> {
+    timeout(write_timeout, writer.write_all(data))
+        .await
+        .map_err(|_| {
+            ProxyError::Timeout(format!(
+                "Upgraded connection write stalled for {write_timeout:?}"
+            ))
+        })?
+        .map_err(ProxyError::from)
+}
+
+/// Read an MCP request body until its method is known to be the only one.
+///
+/// Reads to the end of the request object, so a body that repeats
+/// `method` is never forwarded early. Returns the bytes read and the
+/// method, or `None` for the method if the body is a batch, is not
+/// JSON-RPC, repeats `method`, or ends or passes `max_bytes` before the
+/// object does. The caller owns the rest of `body`.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 (Zero-Copy Peeking Strategy)
+async fn peek_mcp_method(
+    body: &mut Incoming,
+    max_bytes: usize,
+) -> ProxyResult<(BytesMut, Option<String>)> {
+    let mut prefix = BytesMut::new();
+    loop {
+        match peek_for_classification(&prefix) {
+            PeekOutcome::Classified { method, .. } => {
+                // A batch's first method says nothing about the others
+                let batch = prefix.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'[');
+                return Ok((prefix, (!batch).then_some(method)));
+            }
+            PeekOutcome::Unclassifiable { .. } => return Ok((prefix, None)),
+            PeekOutcome::NeedMore if prefix.len() >= max_bytes => return Ok((prefix, None)),
+            PeekOutcome::NeedMore => {}
+        }
+        match body.frame().await {
+            Some(Ok(frame)) => {
+                if let Ok(data) = frame.into_data() {
+                    prefix.extend_from_slice(&data);
+                }
+            }
+            Some(Err(e)) => {
+                error!(error = %e, "Failed to read MCP request body");
+                return Err(ProxyError::Connection(format!(
+                    "Failed to read request body: {}",
+                    e
+                )));
+            }
+            None => return Ok((prefix, None)),
+        }
+    }
+}
+
+/// Answer an MCP request that names no routable server.
+///
+/// The body is a JSON-RPC error; the status is 404 so clients and load
+/// balancers can tell a missing route from a governance decision.
+fn unrouted_response(error: &ThoughtGateError) -> ProxyResult<Response<UnifiedBody>> {
+    error_response(StatusCode::NOT_FOUND, error, None)
+}
+
+/// Answer an MCP request with a JSON-RPC error, without involving a handler.
+///
+/// A `Retry-After` header is added for errors that carry one.
+fn error_response(
+    status: StatusCode,
+    error: &ThoughtGateError,
+    id: Option<JsonRpcId>,
+) -> ProxyResult<Response<UnifiedBody>> {
+    let correlation_id = uuid::Uuid::new_v4().to_string();
+    let response = JsonRpcResponse::error(id, error.to_jsonrpc_error(&correlation_id));
+    let body = serde_json::to_vec(&response).map_err(|e| ProxyError::Connection(e.to_string()))?;
+    let mut builder = Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, "application/json");
+    if let Some(secs) = error.retry_after() {
+        builder = builder.header(header::RETRY_AFTER, secs);
+    }
+    builder
+        .body(Full::new(Bytes::from(body)).map_err(|e| match e {}).boxed())
+        .map_err(|e| ProxyError::Connection(e.to_string()))
+}
+
+/// Take a quota token for every `tools/call` in an MCP request body.
+///
+/// Handles single and batch requests. Returns the `RateLimited` error, and
+/// the ID of a single request, once any call is over quota; bodies that do
+/// not parse are left for the handler to reject.
+///
+/// # Traceability
+/// - Implements: REQ-GOV-001/F-009 (Rate Limiting)
+async fn check_quota(
+    quota: &QuotaLimiter,
+    body: &[u8],
+) -> Option<(ThoughtGateError, Option<JsonRpcId>)> {
+    let value: serde_json::Value = serde_json::from_slice(body).ok()?;
+    let (calls, single) = match &value {
+        serde_json::Value::Array(batch) => (batch.as_slice(), false),
+        single => (std::slice::from_ref(single), true),
+    };
+    let tools: Vec<&str> = calls
+        .iter()
+        .filter(|call| call.get("method").and_then(|m| m.as_str()) == Some("tools/call"))
+        .filter_map(|call| call.get("params")?.get("name")?.as_str())
+        .collect();
+    if tools.is_empty() {
+        return None;
+    }
+    // Without an identity the handler refuses the request anyway
+    let principal = request_principal().ok()?.app_name;
+
+    for tool in tools {
+        if let Err(wait) = quota.check(&principal, tool).await {
+            warn!(principal = %principal, tool, "Tool call quota exceeded");
+            if let Some(metrics) = crate::metrics::get_quota_metrics() {
+                metrics.record_denied(&principal);
+            }
+            let id = single.then(|| request_id(&value)).flatten();
+            let error = ThoughtGateError::RateLimited {
+                retry_after_ms: Some(wait.as_millis().max(1) as u64),
+            };
+            return Some((error, id));
+        }
+    }
+    None
+}
+
+/// Evaluate a hypothetical request posted to [`POLICY_SIMULATE_PATH`].
+///
+/// The caller must send `Authorization: Bearer <token>`; the token is
+/// compared in constant time. The body is an [`ExplainQuery`] and the
+/// response the decision with its explanation, as JSON.
+///
+/// # Traceability
+/// - Implements: REQ-POL-001/§6.3 (Decision Explanation)
+async fn handle_policy_simulate(
+    req: Request<Incoming>,
+    engine: &CedarEngine,
+    token: &str,
+) -> ProxyResult<Response<UnifiedBody>> {
+    let authorized = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|presented| constant_time_eq(presented.as_bytes(), token.as_bytes()));
+    if !authorized {
+        warn!("Policy simulation refused: missing or invalid token");
+        return simulate_error(StatusCode::UNAUTHORIZED, "missing or invalid bearer token");
+    }
+    if req.method() != http::Method::POST {
+        return simulate_error(StatusCode::METHOD_NOT_ALLOWED, "use POST");
+    }
+
+    let body = match http_body_util::Limited::new(req.into_body(), POLICY_SIMULATE_MAX_BODY)
+        .collect()
+        .await
+    {
+        Ok(collected) => collected.to_bytes(),
+        Err(e) if e.is::<http_body_util::LengthLimitError>() => {
+            return simulate_error(StatusCode::PAYLOAD_TOO_LARGE, "request body too large");
+        }
+        Err(e) => {
+            return simulate_error(
+                StatusCode::BAD_REQUEST,
+                &format!("failed to read body: {e}"),
+            );
+        }
+    };
+    let outcome = serde_json::from_slice::<ExplainQuery>(&body)
+        .map_err(|e| e.to_string())
+        .and_then(|query| explain(engine, &query));
+    match outcome {
+        Ok(outcome) => {
+            info!(
+                action = outcome.action,
+                determining = ?outcome.explanation.determining_policy,
+                "Policy simulation"
+            );
+            simulate_json(StatusCode::OK, &serde_json::json!(outcome))
+        }
+        Err(message) => simulate_error(StatusCode::BAD_REQUEST, &message),
+    }
+}
+
+/// JSON error response for the policy simulation endpoint.
+fn simulate_error(status: StatusCode, message: &str) -> ProxyResult<Response<UnifiedBody>> {
+    simulate_json(status, &serde_json::json!({ "error": message }))
+}
+
+fn simulate_json(
+    status: StatusCode,
+    value: &serde_json::Value,
+) -> ProxyResult<Response<UnifiedBody>> {
+    let mut builder = Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, "application/json");
+    if status == StatusCode::UNAUTHORIZED {
+        builder = builder.header(header::WWW_AUTHENTICATE, "Bearer");
+    }
+    builder
+        .body(
+            Full::new(Bytes::from(value.to_string()))
+                .map_err(|e| match e {})
+                .boxed(),
+        )
+        .map_err(|e| ProxyError::Connection(e.to_string()))
+}
+
+/// Check if a header is a hop-by-hop header that shouldn't be forwarded.
+///
+/// Note: `transfer-encoding` and `upgrade` are NOT filtered per REQ-CORE-001 F-003 and F-004.
+/// As a transparent streaming proxy, we must preserve chunked encoding information.
+/// Upgrade headers are preserved to enable WebSocket and HTTP/2 upgrades.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-003 (Transparency - preserve Transfer-Encoding)
+/// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
+#[cfg(feature = "fuzzing")]
+pub fn is_hop_by_hop_header(name: &str) -> bool {
+    is_hop_by_hop_header_impl(name)
+}
+
+#[cfg(not(feature = "fuzzing"))]
+fn is_hop_by_hop_header(name: &str) -> bool {
+    is_hop_by_hop_header_impl(name)
+}
+
+/// Remove connection-specific headers: `Connection`, the headers it names,
+/// `Upgrade`, and the hop-by-hop set.
+///
+/// For messages that are not being upgraded. `Transfer-Encoding` is kept,
+/// as in [`is_hop_by_hop_header`].
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-003 (Transparency - preserve Transfer-Encoding)
+pub fn strip_connection_headers(headers: &mut http::HeaderMap) {
+    let named: Vec<_> = headers
+        .get_all(header::CONNECTION)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .filter_map(|token| http::HeaderName::from_bytes(token.trim().as_bytes()).ok())
+        .filter(|name| name != header::TRANSFER_ENCODING)
+        .collect();
+    for name in named {
+        headers.remove(name);
+    }
+    headers.remove(header::CONNECTION);
+    headers.remove(header::UPGRADE);
+    headers.remove("proxy-connection");
+    let hop_by_hop: Vec<_> = headers
+        .keys()
+        .filter(|name| is_hop_by_hop_header(name.as_str()))
+        .cloned()
+        .collect();
+    for name in hop_by_hop {
+        headers.remove(name);
+    }
+}
+
+fn is_hop_by_hop_header_impl(name: &str) -> bool {
+    matches!(
+        name.to_lowercase().as_str(),
+        "keep-alive" | "proxy-authenticate" | "proxy-authorization" | "te" | "trailers" // NOTE: "connection", "upgrade", and "transfer-encoding" intentionally NOT filtered
+                                                                                        // to support WebSocket upgrades (F-004) and transparent streaming (F-003)
+    )
+}
+
+/// Refuse a request whose headers exceed `max_request_headers` or
+/// `max_request_header_bytes`.
+///
+/// Enforced on the parsed header map, so the limits hold for HTTP/1 and
+/// HTTP/2 alike whatever hyper's own parser limits are. Header bytes count
+/// names and values.
+///
+/// # Errors
+///
+/// - `HeadersTooLarge` (431) - A limit was exceeded
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 Section 3.2 (Header Limits)
+pub fn check_header_limits<B>(req: &Request<B>, config: &ProxyConfig) -> ProxyResult<()> {
+    let headers = req.headers();
+    let (reason, detail) = if headers.len() > config.max_request_headers {
+        (
+            "header_count",
+            format!(
+                "{} headers exceeds limit of {}",
+                headers.len(),
+                config.max_request_headers
+            ),
+        )
+    } else {
+        let bytes: usize = headers
+            .iter()
+            .map(|(name, value)| name.as_str().len() + value.len())
+            .sum();
+        if bytes <= config.max_request_header_bytes {
+            return Ok(());
+        }
+        (
+            "header_bytes",
+            format!(
+                "{bytes} header bytes exceeds limit of {}",
+                config.max_request_header_bytes
+            ),
+        )
+    };
+
+    warn!(method = %req.method(), uri = %req.uri(), reason, "{detail}");
+    if let Some(metrics) = crate::metrics::get_limit_metrics() {
+        metrics.record_header_limit_exceeded(reason);
+    }
+    Err(ProxyError::HeadersTooLarge(detail))
+}
+
+/// Declared body length of a request, if it is known up front.
+///
+/// Returns `None` for chunked requests (any `Transfer-Encoding`), and when
+/// `Content-Length` is missing or malformed.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-002 Section 3.2 (Path Overrides)
+pub fn request_content_length<B>(req: &Request<B>) -> Option<u64> {
+    if req.headers().contains_key(header::TRANSFER_ENCODING) {
+        return None;
+    }
+    req.headers()
+        .get(header::CONTENT_LENGTH)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Check if a request is attempting a protocol upgrade.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
+pub fn is_upgrade_request<B>(req: &Request<B>) -> bool {
+    req.headers()
+        .get("connection")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_lowercase().contains("upgrade"))
+        .unwrap_or(false)
+        && req.headers().contains_key("upgrade")
+}
+
+/// Get the upgrade protocol from request headers.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
+pub fn get_upgrade_protocol<B>(req: &Request<B>) -> Option<String> {
+    req.headers()
+        .get("upgrade")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_lowercase())
+}
+
+/// Check if a response indicates a successful protocol upgrade.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
+pub fn is_upgrade_response<B>(res: &Response<B>) -> bool {
+    res.status() == hyper::StatusCode::SWITCHING_PROTOCOLS
+}
+
+/// Number of bytes peeked from a new connection to detect CONNECT.
+pub const CONNECT_PEEK_LEN: usize = 7;
+
+/// Check whether bytes peeked from a new connection start a CONNECT request.
+///
+/// Only the first [`CONNECT_PEEK_LEN`] bytes are examined; a short peek is
+/// never treated as CONNECT.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-002 (Conditional Termination - CONNECT rejection)
+pub fn is_connect_preface(peeked: &[u8]) -> bool {
+    peeked.len() >= CONNECT_PEEK_LEN && &peeked[..CONNECT_PEEK_LEN] == b"CONNECT"
+}
+
+/// Raw HTTP/1.1 405 response written when a CONNECT request is rejected.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-002 (Conditional Termination - CONNECT rejection)
+pub fn connect_rejection_response() -> String {
+    let body = "405 Method Not Allowed\n\n\
+             ThoughtGate is a termination proxy for AI governance.\n\
+             It requires visibility into request headers and bodies.\n\n\
+             Send plain HTTP requests to http://127.0.0.1:4141 instead.";
+    format!(
+        "HTTP/1.1 405 Method Not Allowed\r\n\
+             Content-Type: text/plain\r\n\
+             Content-Length: {}\r\n\
+             Connection: close\r\n\
+             \r\n\
+             {}",
+        body.len(),
+        body
+    )
+}
+
+/// Extract host from URI.
+#[allow(dead_code)]
+fn extract_host(uri: &Uri) -> ProxyResult<String> {
+    uri.host()
+        .ok_or_else(|| ProxyError::InvalidUri("URI missing host".to_string()))
+        .map(|s| s.to_string())
+}
+
+/// Extract port from URI, defaulting to 80 for HTTP or 443 for HTTPS.
+#[allow(dead_code)]
+fn extract_port(uri: &Uri) -> ProxyResult<u16> {
+    if let Some(port) = uri.port_u16() {
+        Ok(port)
+    } else {
+        match uri.scheme_str() {
+            Some("https") => Ok(443),
+            Some("http") => Ok(80),
+            _ => Err(ProxyError::InvalidUri(
+                "Cannot determine port from URI".to_string(),
+            )),
+        }
+    }
+}
+
+/// Configure socket with optimized options for zero-copy streaming.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-001 Section 3.2 (Network Optimization)
+///
+/// # Socket Options
+/// - TCP_NODELAY: Disable Nagle's algorithm for low-latency
+/// - SO_KEEPALIVE: Keep connections alive during idle periods
+/// - SO_RCVBUF / SO_SNDBUF: Set buffer sizes for throughput
+pub fn configure_socket(socket: &Socket, config: &ProxyConfig) -> ProxyResult<()> {
+    // Set TCP_NODELAY (disable Nagle's algorithm)
+    socket
+        .set_tcp_nodelay(config.tcp_nodelay)
+        .map_err(|e| ProxyError::Connection(format!("Failed to set TCP_NODELAY: {}", e)))?;
+
+    // Set TCP keepalive
+    let keepalive = TcpKeepalive::new().with_time(Duration::from_secs(config.tcp_keepalive_secs));
+    socket
+        .set_tcp_keepalive(&keepalive)
+        .map_err(|e| ProxyError::Connection(format!("Failed to set SO_KEEPALIVE: {}", e)))?;
+
+    // Set socket buffer sizes
+    socket
+        .set_recv_buffer_size(config.socket_buffer_size)
+        .map_err(|e| ProxyError::Connection(format!("Failed to set SO_RCVBUF: {}", e)))?;
 
     socket
         .set_send_buffer_size(config.socket_buffer_size)
@@ -657,12 +2354,27 @@ pub fn create_configured_socket(addr: &SocketAddr, config: &ProxyConfig) -> Prox
 /// - Implements: REQ-CORE-001 F-002 (Fail-Fast Error Propagation)
 ///
 /// # Error Mapping
+/// - Failed TLS handshake -> `ProxyError::Tls` (502), counted and audited
 /// - Connection refused -> `ProxyError::ConnectionRefused` (502)
 /// - Timeout -> `ProxyError::Timeout` (504)
 /// - Other errors -> `ProxyError::Connection` (502)
-fn map_hyper_error(e: hyper_util::client::legacy::Error) -> ProxyError {
+fn map_hyper_error(
+    e: hyper_util::client::legacy::Error,
+    method: &http::Method,
+    target: &Uri,
+) -> ProxyError {
     use tracing::warn;
 
+    if let Some(failure) = crate::error::tls::classify(&e) {
+        let upstream = match (target.scheme_str(), target.authority()) {
+            (Some(scheme), Some(authority)) => format!("{scheme}://{authority}"),
+            _ => target.to_string(),
+        };
+        record_tls_failure(&upstream, failure);
+        audit_upstream_tls_failure(&upstream, failure, method.as_str(), target.path());
+        return ProxyError::Tls { upstream, failure };
+    }
+
     let error_msg = e.to_string().to_lowercase();
 
     // Check for connection refused
@@ -698,6 +2410,7 @@ fn map_hyper_error(e: hyper_util::client::legacy::Error) -> ProxyError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_harness::{serve, serve_as};
     use crate::traffic::{TrafficType, discriminate_traffic};
     use http::{HeaderMap, Method, Version};
 
@@ -794,6 +2507,27 @@ mod tests {
         assert!(!is_hop_by_hop_header("Upgrade"));
     }
 
+    /// Test CONNECT detection on peeked bytes
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-002 (Conditional Termination - CONNECT rejection)
+    #[test]
+    fn test_connect_preface_detection() {
+        assert!(is_connect_preface(b"CONNECT"));
+        assert!(is_connect_preface(b"CONNECT example.com:443 HTTP/1.1\r\n"));
+
+        // Short peeks and other methods are not CONNECT
+        assert!(!is_connect_preface(b""));
+        assert!(!is_connect_preface(b"CONNEC"));
+        assert!(!is_connect_preface(b"connect"));
+        assert!(!is_connect_preface(b"POST /mcp/v1 HTTP/1.1"));
+
+        let response = connect_rejection_response();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        assert!(head.starts_with("HTTP/1.1 405"));
+        assert!(head.contains(&format!("Content-Length: {}", body.len())));
+    }
+
     /// Snapshot test: Verify request transformation logic
     #[test]
     fn test_integrity_snapshot() {
@@ -1032,6 +2766,50 @@ mod tests {
         assert!(!service.has_mcp_handler());
     }
 
+    /// Test size overrides use Content-Length and leave chunked requests alone.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-002 Section 3.2 (Path Overrides)
+    #[test]
+    fn test_select_path_uses_content_length() {
+        let config = ProxyConfig {
+            force_amber_below_bytes: Some(4096),
+            ..ProxyConfig::default()
+        };
+        let service = ProxyService::new_with_config(None, config).unwrap();
+        let request = |headers: &[(&str, &str)]| {
+            headers
+                .iter()
+                .fold(Request::builder().method(Method::POST), |b, (k, v)| {
+                    b.header(*k, *v)
+                })
+                .body(())
+                .unwrap()
+        };
+
+        let small = request(&[("content-length", "4095")]);
+        assert_eq!(request_content_length(&small), Some(4095));
+        assert_eq!(
+            service.select_path(&small, PolicyDecision::Green),
+            PolicyDecision::Amber
+        );
+
+        // Chunked, missing, or malformed lengths keep the policy path
+        for headers in [
+            &[("transfer-encoding", "chunked")][..],
+            &[("transfer-encoding", "chunked"), ("content-length", "10")][..],
+            &[][..],
+            &[("content-length", "ten")][..],
+        ] {
+            let req = request(headers);
+            assert_eq!(request_content_length(&req), None, "{headers:?}");
+            assert_eq!(
+                service.select_path(&req, PolicyDecision::Green),
+                PolicyDecision::Green
+            );
+        }
+    }
+
     /// Test MCP traffic with content-type charset is still detected.
     ///
     /// # Traceability
@@ -1065,7 +2843,7 @@ mod tests {
         use std::sync::Arc;
 
         /// Mock upstream that returns a simple JSON-RPC response.
-        struct MockUpstream;
+        pub(super) struct MockUpstream;
 
         #[async_trait::async_trait]
         impl UpstreamForwarder for MockUpstream {
@@ -1239,4 +3017,2031 @@ mod tests {
             assert_eq!(default_handler.max_body_size(), 1024 * 1024);
         }
     }
+
+    // =========================================================================
+    // MCP Routing Tests (route_mcp)
+    // =========================================================================
+
+    mod routing_tests {
+        use super::*;
+        use crate::governance::TaskStore;
+        use crate::policy::engine::CedarEngine;
+        use crate::transport::jsonrpc::McpRequest;
+        use crate::transport::server::McpHandlerConfig;
+        use crate::transport::upstream::UpstreamForwarder;
+
+        /// Upstream that tags each response with its server name.
+        struct TaggedUpstream(&'static str);
+
+        #[async_trait::async_trait]
+        impl UpstreamForwarder for TaggedUpstream {
+            async fn forward(
+                &self,
+                request: &McpRequest,
+            ) -> Result<JsonRpcResponse, ThoughtGateError> {
+                Ok(JsonRpcResponse::success(
+                    request.id.clone(),
+                    serde_json::json!({"server": self.0}),
+                ))
+            }
+
+            async fn forward_batch(
+                &self,
+                requests: &[McpRequest],
+            ) -> Result<Vec<JsonRpcResponse>, ThoughtGateError> {
+                let mut responses = Vec::new();
+                for request in requests.iter().filter(|r| !r.is_notification()) {
+                    responses.push(self.forward(request).await?);
+                }
+                Ok(responses)
+            }
+        }
+
+        fn base_handler() -> McpHandler {
+            McpHandler::new(
+                Arc::new(TaggedUpstream("default")),
+                Arc::new(CedarEngine::new().expect("Failed to create Cedar engine")),
+                Arc::new(TaskStore::with_defaults()),
+                McpHandlerConfig::default(),
+            )
+        }
+
+        /// Proxy with a route per server name.
+        fn routed_service(servers: &[&'static str]) -> ProxyService {
+            let base = base_handler();
+            let mut service = ProxyService::new_with_config(None, ProxyConfig::default())
+                .unwrap()
+                .with_mcp_handler(Arc::new(base.clone()));
+            for server in servers {
+                let route = base.for_source(*server, Arc::new(TaggedUpstream(server)));
+                service = service.with_mcp_route(Arc::new(route));
+            }
+            service
+        }
+
+        async fn post_mcp(proxy: SocketAddr, path: &str) -> (StatusCode, serde_json::Value) {
+            let client = Client::builder(TokioExecutor::new()).build_http::<Full<Bytes>>();
+            let req = Request::builder()
+                .method(Method::POST)
+                .uri(format!("http://{proxy}{path}"))
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Full::new(Bytes::from_static(
+                    br#"{"jsonrpc":"2.0","id":1,"method":"test"}"#,
+                )))
+                .unwrap();
+            let res = client.request(req).await.unwrap();
+            let status = res.status();
+            let body = res.into_body().collect().await.unwrap().to_bytes();
+            (status, serde_json::from_slice(&body).unwrap())
+        }
+
+        /// Test each named server is forwarded to its own upstream.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+        #[tokio::test]
+        async fn test_routed_requests_reach_their_upstream() {
+            let proxy = serve(routed_service(&["billing", "search"])).await;
+
+            for server in ["billing", "search"] {
+                let (status, body) = post_mcp(proxy, &format!("/mcp/v1/{server}")).await;
+                assert_eq!(status, StatusCode::OK);
+                assert_eq!(body["result"]["server"], server);
+            }
+        }
+
+        /// Test unknown servers and unnamed requests are rejected, not defaulted.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+        #[tokio::test]
+        async fn test_unrouted_requests_rejected() {
+            let proxy = serve(routed_service(&["billing", "search"])).await;
+
+            let (status, body) = post_mcp(proxy, "/mcp/v1/payroll").await;
+            assert_eq!(status, StatusCode::NOT_FOUND);
+            assert_eq!(body["error"]["code"], -32600);
+            assert!(
+                body["error"]["message"]
+                    .as_str()
+                    .unwrap()
+                    .contains("No upstream route for server 'payroll'")
+            );
+
+            // With routes configured, the default handler is never used for MCP
+            for path in ["/mcp/v1", "/"] {
+                let (status, body) = post_mcp(proxy, path).await;
+                assert_eq!(status, StatusCode::NOT_FOUND);
+                assert_ne!(body["result"]["server"], "default");
+            }
+        }
+
+        /// Test route selection with one, several, and no routes configured.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+        #[test]
+        fn test_route_selection() {
+            // No routes: the default handler serves its own source ID only
+            let single = routed_service(&[]);
+            for path in ["/", "/mcp/v1", "/mcp/v1/upstream"] {
+                assert_eq!(single.route_mcp(path).unwrap().source_id(), "upstream");
+            }
+            assert!(single.route_mcp("/mcp/v1/billing").is_err());
+
+            let multi = routed_service(&["billing", "search", "tickets"]);
+            for server in ["billing", "search", "tickets"] {
+                let path = format!("/mcp/v1/{server}");
+                assert_eq!(multi.route_mcp(&path).unwrap().source_id(), server);
+            }
+            assert!(multi.route_mcp("/mcp/v1/upstream").is_err());
+            assert!(multi.route_mcp("/mcp/v1").is_err());
+        }
+    }
+
+    // =========================================================================
+    // Protocol Upgrade Tests (handle_upgrade_request)
+    // =========================================================================
+
+    mod upgrade_tests {
+        use super::mcp_request_tests::MockUpstream;
+        use super::*;
+        use crate::config::Config;
+        use crate::governance::TaskStore;
+        use crate::policy::engine::CedarEngine;
+        use crate::transport::server::McpHandlerConfig;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::net::{TcpListener, TcpStream};
+
+        const GOVERNANCE_YAML: &str = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://127.0.0.1:1
+governance:
+  defaults:
+    action: forward
+  rules:
+    - match: "/ws/private*"
+      action: deny
+"#;
+
+        /// Start a mock WebSocket server that accepts any handshake and then
+        /// echoes bytes. Returns its address and a count of handshakes seen.
+        async fn spawn_ws_echo() -> (SocketAddr, Arc<AtomicUsize>) {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            let handshakes = Arc::new(AtomicUsize::new(0));
+            let seen = handshakes.clone();
+
+            tokio::spawn(async move {
+                while let Ok((mut socket, _)) = listener.accept().await {
+                    let seen = seen.clone();
+                    tokio::spawn(async move {
+                        read_head(&mut socket).await;
+                        seen.fetch_add(1, Ordering::SeqCst);
+                        socket
+                            .write_all(
+                                b"HTTP/1.1 101 Switching Protocols\r\n\
+                                  Upgrade: websocket\r\n\
+                                  Connection: Upgrade\r\n\
+                                  Sec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo=\r\n\r\n",
+                            )
+                            .await
+                            .unwrap();
+                        let mut buf = [0u8; 1024];
+                        while let Ok(n) = socket.read(&mut buf).await {
+                            if n == 0 || socket.write_all(&buf[..n]).await.is_err() {
+                                break;
+                            }
+                        }
+                    });
+                }
+            });
+            (addr, handshakes)
+        }
+
+        /// Serve a proxy in front of `upstream`, governed by `GOVERNANCE_YAML`.
+        async fn spawn_proxy(upstream: SocketAddr) -> SocketAddr {
+            let yaml: Config = serde_saphyr::from_str(GOVERNANCE_YAML).unwrap();
+            let handler = McpHandler::with_governance(
+                Arc::new(MockUpstream),
+                Arc::new(CedarEngine::new().expect("Failed to create Cedar engine")),
+                Arc::new(TaskStore::with_defaults()),
+                McpHandlerConfig::default(),
+                Some(Arc::new(yaml)),
+                None,
+            );
+            let service = ProxyService::new_with_config(
+                Some(format!("http://{upstream}")),
+                ProxyConfig::default(),
+            )
+            .unwrap()
+            .with_mcp_handler(Arc::new(handler));
+
+            serve(service).await
+        }
+
+        /// Read an HTTP head (through the blank line) byte by byte.
+        async fn read_head(stream: &mut TcpStream) -> String {
+            let mut head = Vec::new();
+            let mut byte = [0u8; 1];
+            while !head.ends_with(b"\r\n\r\n") {
+                if stream.read(&mut byte).await.unwrap() == 0 {
+                    break;
+                }
+                head.push(byte[0]);
+            }
+            String::from_utf8_lossy(&head).into_owned()
+        }
+
+        async fn handshake(proxy: SocketAddr, path: &str) -> (TcpStream, String) {
+            let mut stream = TcpStream::connect(proxy).await.unwrap();
+            let request = format!(
+                "GET {path} HTTP/1.1\r\n\
+                 Host: {proxy}\r\n\
+                 Connection: Upgrade\r\n\
+                 Upgrade: websocket\r\n\
+                 Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+                 Sec-WebSocket-Version: 13\r\n\r\n"
+            );
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let head = read_head(&mut stream).await;
+            (stream, head)
+        }
+
+        /// Test a permitted WebSocket upgrade is relayed in both directions.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
+        #[tokio::test]
+        async fn test_websocket_upgrade_relayed() {
+            let (upstream, handshakes) = spawn_ws_echo().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let (mut stream, head) = handshake(proxy, "/ws/public").await;
+            assert!(head.starts_with("HTTP/1.1 101"), "unexpected head: {head}");
+            assert!(head.to_lowercase().contains("sec-websocket-accept"));
+            assert_eq!(handshakes.load(Ordering::SeqCst), 1);
+
+            // A masked text frame; the relay is opaque so any bytes work
+            let frame = [
+                0x81,
+                0x85,
+                1,
+                2,
+                3,
+                4,
+                b'h' ^ 1,
+                b'e' ^ 2,
+                b'l' ^ 3,
+                b'l' ^ 4,
+                b'o' ^ 1,
+            ];
+            for _ in 0..3 {
+                stream.write_all(&frame).await.unwrap();
+                let mut echo = [0u8; 11];
+                tokio::time::timeout(Duration::from_secs(5), stream.read_exact(&mut echo))
+                    .await
+                    .expect("echo timed out")
+                    .unwrap();
+                assert_eq!(echo, frame);
+            }
+        }
+
+        /// Test a denied WebSocket upgrade is refused before upstream sees it.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
+        #[tokio::test]
+        async fn test_websocket_upgrade_denied_before_upgrade() {
+            let (upstream, handshakes) = spawn_ws_echo().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let (_stream, head) = handshake(proxy, "/ws/private/admin").await;
+            assert!(head.starts_with("HTTP/1.1 403"), "unexpected head: {head}");
+            assert_eq!(handshakes.load(Ordering::SeqCst), 0);
+        }
+
+        /// Test an idle upgraded connection is closed by the read timeout.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
+        #[tokio::test]
+        async fn test_relay_idle_timeout() {
+            let (client, _client_peer) = tokio::io::duplex(64);
+            let (upstream, _upstream_peer) = tokio::io::duplex(64);
+            let config = ProxyConfig {
+                stream_read_timeout: Duration::from_millis(50),
+                ..ProxyConfig::default()
+            };
+
+            let result = relay_upgraded(client, upstream, &config).await;
+            assert!(matches!(result, Err(ProxyError::Timeout(_))));
+        }
+    }
+
+    // =========================================================================
+    // gRPC Transport Tests (handle_transport_request)
+    // =========================================================================
+
+    mod grpc_tests {
+        use super::mcp_request_tests::MockUpstream;
+        use super::*;
+        use crate::config::{ApprovalMode, Config};
+        use crate::governance::approval::mock::MockAdapter;
+        use crate::governance::{
+            ApprovalDecision, ApprovalEngine, ApprovalEngineConfig, TaskStatus, TaskStore,
+        };
+        use crate::policy::Principal;
+        use crate::policy::engine::CedarEngine;
+        use crate::transport::grpc::GrpcTransport;
+        use crate::transport::grpc::test_support::{call_tool_frame, descriptor_set};
+        use crate::transport::server::McpHandlerConfig;
+        use http::HeaderMap;
+        use hyper_util::server::conn::auto;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::net::TcpListener;
+
+        const GOVERNANCE_YAML: &str = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://127.0.0.1:1
+governance:
+  defaults:
+    action: forward
+  rules:
+    - match: "delete_*"
+      action: deny
+    - match: "upload_*"
+      action: approve
+"#;
+
+        /// Start a mock h2c gRPC server that echoes the request body and
+        /// ends with `grpc-status: 0`. Returns its address and call count.
+        async fn spawn_grpc_echo() -> (SocketAddr, Arc<AtomicUsize>) {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            let calls = Arc::new(AtomicUsize::new(0));
+            let seen = calls.clone();
+
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    let seen = seen.clone();
+                    tokio::spawn(async move {
+                        let svc_fn = hyper::service::service_fn(move |req: Request<Incoming>| {
+                            let seen = seen.clone();
+                            async move {
+                                seen.fetch_add(1, Ordering::SeqCst);
+                                assert_eq!(req.headers()["te"], "trailers");
+                                let echoed = req.into_body().collect().await.unwrap().to_bytes();
+                                let mut trailers = HeaderMap::new();
+                                trailers.insert("grpc-status", "0".parse().unwrap());
+                                let frames = futures_util::stream::iter([
+                                    Ok::<_, std::convert::Infallible>(Frame::data(echoed)),
+                                    Ok(Frame::trailers(trailers)),
+                                ]);
+                                let res = Response::builder()
+                                    .header(header::CONTENT_TYPE, "application/grpc")
+                                    .body(StreamBody::new(frames))
+                                    .unwrap();
+                                Ok::<_, std::convert::Infallible>(res)
+                            }
+                        });
+                        let _ = auto::Builder::new(TokioExecutor::new())
+                            .serve_connection(TokioIo::new(stream), svc_fn)
+                            .await;
+                    });
+                }
+            });
+            (addr, calls)
+        }
+
+        /// Start a mock h2c gRPC server that counts request body bytes as
+        /// they arrive and ends with `grpc-status: 0` once the body is done.
+        async fn spawn_grpc_sink() -> (SocketAddr, Arc<AtomicUsize>) {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            let received = Arc::new(AtomicUsize::new(0));
+            let seen = received.clone();
+
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    let seen = seen.clone();
+                    tokio::spawn(async move {
+                        let svc_fn = hyper::service::service_fn(move |req: Request<Incoming>| {
+                            let seen = seen.clone();
+                            async move {
+                                let mut body = req.into_body();
+                                while let Some(frame) = body.frame().await {
+                                    if let Ok(data) = frame.unwrap().into_data() {
+                                        seen.fetch_add(data.len(), Ordering::SeqCst);
+                                    }
+                                }
+                                let mut trailers = HeaderMap::new();
+                                trailers.insert("grpc-status", "0".parse().unwrap());
+                                let frames = futures_util::stream::iter([Ok::<
+                                    Frame<Bytes>,
+                                    std::convert::Infallible,
+                                >(
+                                    Frame::trailers(trailers),
+                                )]);
+                                let res = Response::builder()
+                                    .header(header::CONTENT_TYPE, "application/grpc")
+                                    .body(StreamBody::new(frames))
+                                    .unwrap();
+                                Ok::<_, std::convert::Infallible>(res)
+                            }
+                        });
+                        let _ = auto::Builder::new(TokioExecutor::new())
+                            .serve_connection(TokioIo::new(stream), svc_fn)
+                            .await;
+                    });
+                }
+            });
+            (addr, received)
+        }
+
+        /// Serve a gRPC-governing proxy in front of `upstream`.
+        async fn spawn_proxy(upstream: SocketAddr) -> SocketAddr {
+            let yaml: Config = serde_saphyr::from_str(GOVERNANCE_YAML).unwrap();
+            let handler = McpHandler::with_governance(
+                Arc::new(MockUpstream),
+                Arc::new(CedarEngine::new().expect("Failed to create Cedar engine")),
+                Arc::new(TaskStore::with_defaults()),
+                McpHandlerConfig::default(),
+                Some(Arc::new(yaml)),
+                None,
+            );
+            spawn_proxy_with(upstream, handler).await
+        }
+
+        /// Serve a gRPC-governing proxy with `handler` in front of `upstream`.
+        async fn spawn_proxy_with(upstream: SocketAddr, handler: McpHandler) -> SocketAddr {
+            let grpc = GrpcTransport::from_descriptor_set(&descriptor_set()).unwrap();
+            let service = ProxyService::new_with_config(
+                Some(format!("http://{upstream}")),
+                ProxyConfig::default(),
+            )
+            .unwrap()
+            .with_mcp_handler(Arc::new(handler))
+            .with_transport(Arc::new(grpc));
+
+            let client = Principal {
+                app_name: "uploader".to_string(),
+                namespace: "default".to_string(),
+                service_account: "default".to_string(),
+                roles: vec![],
+            };
+            serve_as(service, client).await
+        }
+
+        /// Make a unary gRPC call over h2c; returns (headers, body, trailers).
+        async fn grpc_call(
+            proxy: SocketAddr,
+            path: &str,
+            body: Vec<u8>,
+        ) -> (HeaderMap, Bytes, Option<HeaderMap>) {
+            let client = Client::builder(TokioExecutor::new())
+                .http2_only(true)
+                .build_http::<Full<Bytes>>();
+            let req = Request::builder()
+                .method(Method::POST)
+                .uri(format!("http://{proxy}{path}"))
+                .header(header::CONTENT_TYPE, "application/grpc")
+                .header("te", "trailers")
+                .body(Full::new(Bytes::from(body)))
+                .unwrap();
+
+            let res = tokio::time::timeout(Duration::from_secs(5), client.request(req))
+                .await
+                .expect("gRPC call timed out")
+                .unwrap();
+            assert_eq!(res.status(), StatusCode::OK);
+            let (parts, body) = res.into_parts();
+            let collected = body.collect().await.unwrap();
+            let trailers = collected.trailers().cloned();
+            (parts.headers, collected.to_bytes(), trailers)
+        }
+
+        /// Test a permitted tool call is forwarded with framing and trailers intact.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-008/F-002 (Transport Call Governance)
+        #[tokio::test]
+        async fn test_grpc_tool_call_forwarded() {
+            let (upstream, calls) = spawn_grpc_echo().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let frame = call_tool_frame("get_user");
+            let (_, body, trailers) = grpc_call(proxy, "/mcp.Tools/CallTool", frame.clone()).await;
+
+            assert_eq!(calls.load(Ordering::SeqCst), 1);
+            assert_eq!(body.as_ref(), frame.as_slice());
+            assert_eq!(trailers.expect("trailers")["grpc-status"], "0");
+        }
+
+        /// Test a denied tool call gets PERMISSION_DENIED without reaching upstream.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-008/F-004 (gRPC Error Mapping)
+        #[tokio::test]
+        async fn test_grpc_tool_call_denied() {
+            let (upstream, calls) = spawn_grpc_echo().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let (headers, body, _) =
+                grpc_call(proxy, "/mcp.Tools/CallTool", call_tool_frame("delete_user")).await;
+
+            assert_eq!(calls.load(Ordering::SeqCst), 0);
+            assert!(body.is_empty());
+            assert_eq!(headers["grpc-status"], "7");
+            assert!(
+                headers["grpc-message"]
+                    .to_str()
+                    .unwrap()
+                    .contains("delete_user")
+            );
+        }
+
+        /// Test a method without a tool field is governed by method name.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-008/F-003 (gRPC Transport)
+        #[tokio::test]
+        async fn test_grpc_non_tool_method_forwarded() {
+            let (upstream, calls) = spawn_grpc_echo().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let frame = vec![0, 0, 0, 0, 0];
+            let (_, body, trailers) = grpc_call(proxy, "/mcp.Tools/Ping", frame.clone()).await;
+
+            assert_eq!(calls.load(Ordering::SeqCst), 1);
+            assert_eq!(body.as_ref(), frame.as_slice());
+            assert_eq!(trailers.expect("trailers")["grpc-status"], "0");
+        }
+
+        /// Test a streamed call held for blocking approval is streamed to
+        /// upstream once approved, without buffering the body.
+        ///
+        /// The body is larger than the MCP body size limit, and each chunk
+        /// reaches the upstream before the next one is sent.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+        #[tokio::test]
+        async fn test_grpc_streamed_call_approved_then_streamed() {
+            const CHUNK: usize = 256 * 1024;
+            const CHUNKS: usize = 8;
+
+            let (upstream, received) = spawn_grpc_sink().await;
+            let task_store = Arc::new(TaskStore::with_defaults());
+            let engine = ApprovalEngine::new(
+                task_store.clone(),
+                Arc::new(MockAdapter::new(Duration::from_secs(3600), true)),
+                Arc::new(MockUpstream),
+                ApprovalEngineConfig {
+                    mode: ApprovalMode::Blocking,
+                    ..Default::default()
+                },
+                tokio_util::sync::CancellationToken::new(),
+            )
+            .unwrap();
+            let yaml: Config = serde_saphyr::from_str(GOVERNANCE_YAML).unwrap();
+            let handler = McpHandler::with_governance(
+                Arc::new(MockUpstream),
+                Arc::new(CedarEngine::new().expect("Failed to create Cedar engine")),
+                task_store.clone(),
+                McpHandlerConfig::default(),
+                Some(Arc::new(yaml)),
+                Some(Arc::new(engine)),
+            );
+            let max_body_size = handler.max_body_size();
+            assert!(CHUNK * CHUNKS > max_body_size);
+            let proxy = spawn_proxy_with(upstream, handler).await;
+
+            let (tx, rx) = tokio::sync::mpsc::channel::<Bytes>(1);
+            let frames = Box::pin(futures_util::stream::unfold(rx, |mut rx| async move {
+                let data = rx.recv().await?;
+                Some((Ok::<_, std::convert::Infallible>(Frame::data(data)), rx))
+            }));
+            let client = Client::builder(TokioExecutor::new())
+                .http2_only(true)
+                .build_http();
+            let req = Request::builder()
+                .method(Method::POST)
+                .uri(format!("http://{proxy}/mcp.Tools/CallTool"))
+                .header(header::CONTENT_TYPE, "application/grpc")
+                .header("te", "trailers")
+                .body(StreamBody::new(frames))
+                .unwrap();
+            let call = tokio::spawn(client.request(req));
+
+            let first = Bytes::from(call_tool_frame("upload_blob"));
+            let mut sent = first.len();
+            tx.send(first).await.unwrap();
+
+            let principal = crate::governance::Principal::new("uploader");
+            let task_id = loop {
+                if let Some(task) = task_store.list_for_principal(&principal, 0, 1).pop() {
+                    break task.id;
+                }
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            };
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            assert_eq!(received.load(Ordering::SeqCst), 0, "sent before approval");
+
+            task_store
+                .record_approval(
+                    &task_id,
+                    ApprovalDecision::Approved,
+                    "reviewer".to_string(),
+                    Duration::from_secs(60),
+                )
+                .unwrap();
+
+            let wait_for = |bytes: usize| {
+                let received = received.clone();
+                async move {
+                    tokio::time::timeout(Duration::from_secs(5), async {
+                        while received.load(Ordering::SeqCst) < bytes {
+                            tokio::time::sleep(Duration::from_millis(2)).await;
+                        }
+                    })
+                    .await
+                    .expect("upstream did not receive streamed bytes");
+                }
+            };
+            wait_for(sent).await;
+            for _ in 0..CHUNKS {
+                tx.send(Bytes::from(vec![0u8; CHUNK])).await.unwrap();
+                sent += CHUNK;
+                wait_for(sent).await;
+            }
+            drop(tx);
+
+            let res = tokio::time::timeout(Duration::from_secs(5), call)
+                .await
+                .expect("call timed out")
+                .unwrap()
+                .unwrap();
+            assert_eq!(res.status(), StatusCode::OK);
+            let trailers = res.into_body().collect().await.unwrap().trailers().cloned();
+            assert_eq!(trailers.expect("trailers")["grpc-status"], "0");
+            assert_eq!(received.load(Ordering::SeqCst), sent);
+            assert_eq!(
+                task_store.get(&task_id).unwrap().status,
+                TaskStatus::Completed
+            );
+        }
+    }
+
+    mod trailer_tests {
+        use super::*;
+        use hyper_util::server::conn::auto;
+        use tokio::net::TcpListener;
+
+        /// Start a mock HTTP/1.1 upstream answering every request with a
+        /// fixed-length body.
+        async fn spawn_upstream() -> SocketAddr {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(async move {
+                        let svc_fn = hyper::service::service_fn(|_req: Request<Incoming>| async {
+                            Ok::<_, std::convert::Infallible>(Response::new(Full::new(
+                                Bytes::from_static(b"streamed"),
+                            )))
+                        });
+                        let _ = auto::Builder::new(TokioExecutor::new())
+                            .serve_connection(TokioIo::new(stream), svc_fn)
+                            .await;
+                    });
+                }
+            });
+            addr
+        }
+
+        /// Serve a proxy with governance trailers enabled in front of `upstream`.
+        async fn spawn_proxy(upstream: SocketAddr) -> SocketAddr {
+            let config = ProxyConfig {
+                governance_trailers: true,
+                ..ProxyConfig::default()
+            };
+            let service =
+                ProxyService::new_with_config(Some(format!("http://{upstream}")), config).unwrap();
+
+            serve(service).await
+        }
+
+        /// GET through the proxy over HTTP/1.1; returns (headers, body, trailers).
+        async fn get(
+            proxy: SocketAddr,
+            te: Option<&str>,
+        ) -> (http::HeaderMap, Bytes, Option<http::HeaderMap>) {
+            let client = Client::builder(TokioExecutor::new()).build_http::<Empty<Bytes>>();
+            let mut req = Request::builder().uri(format!("http://{proxy}/data"));
+            if let Some(te) = te {
+                req = req.header(header::TE, te);
+            }
+            let res = tokio::time::timeout(
+                Duration::from_secs(5),
+                client.request(req.body(Empty::new()).unwrap()),
+            )
+            .await
+            .expect("request timed out")
+            .unwrap();
+            assert_eq!(res.status(), StatusCode::OK);
+            let (parts, body) = res.into_parts();
+            let collected = body.collect().await.unwrap();
+            let trailers = collected.trailers().cloned();
+            (parts.headers, collected.to_bytes(), trailers)
+        }
+
+        /// Test a streamed Green Path response ends with the governance trailers.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 F-003 (Trailer Support)
+        #[tokio::test]
+        async fn test_governance_trailers_on_streamed_response() {
+            let proxy = spawn_proxy(spawn_upstream().await).await;
+
+            let (headers, body, trailers) = get(proxy, Some("trailers")).await;
+
+            assert_eq!(body.as_ref(), b"streamed");
+            assert!(headers.get(header::CONTENT_LENGTH).is_none());
+            assert_eq!(
+                headers[header::TRAILER],
+                "thoughtgate-decision, thoughtgate-inspected"
+            );
+            let trailers = trailers.expect("governance trailers");
+            assert_eq!(trailers[DECISION_TRAILER], "forward");
+            assert_eq!(trailers[INSPECTED_TRAILER], "false");
+        }
+
+        /// Test no trailers are sent to an HTTP/1.1 client that did not ask.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 F-003 (Trailer Support)
+        #[tokio::test]
+        async fn test_governance_trailers_require_te() {
+            let proxy = spawn_proxy(spawn_upstream().await).await;
+
+            let (headers, body, trailers) = get(proxy, None).await;
+
+            assert_eq!(body.as_ref(), b"streamed");
+            assert_eq!(headers[header::CONTENT_LENGTH], "8");
+            assert!(headers.get(header::TRAILER).is_none());
+            assert!(trailers.is_none());
+        }
+    }
+
+    mod compression_tests {
+        use super::*;
+        use hyper_util::server::conn::auto;
+        use std::io::Read;
+        use tokio::net::TcpListener;
+
+        /// A JSON document large enough to be compressed.
+        fn large_json() -> String {
+            format!(
+                r#"{{"items":[{}]}}"#,
+                vec![r#"{"name":"item"}"#; 200].join(",")
+            )
+        }
+
+        /// Start a mock upstream whose response depends on the path:
+        /// `/json` (large JSON), `/small` (tiny JSON), `/image` (large PNG)
+        /// and `/encoded` (large JSON already encoded by upstream).
+        async fn spawn_upstream() -> SocketAddr {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(async move {
+                        let svc_fn =
+                            hyper::service::service_fn(|req: Request<Incoming>| async move {
+                                let res = Response::builder().header(header::ETAG, "\"v1\"");
+                                let res = match req.uri().path() {
+                                    "/small" => res
+                                        .header(header::CONTENT_TYPE, "application/json")
+                                        .body(Full::new(Bytes::from_static(b"{}"))),
+                                    "/image" => res
+                                        .header(header::CONTENT_TYPE, "image/png")
+                                        .body(Full::new(Bytes::from(large_json()))),
+                                    "/encoded" => res
+                                        .header(header::CONTENT_TYPE, "application/json")
+                                        .header(header::CONTENT_ENCODING, "br")
+                                        .body(Full::new(Bytes::from(large_json()))),
+                                    _ => res
+                                        .header(header::CONTENT_TYPE, "application/json")
+                                        .body(Full::new(Bytes::from(large_json()))),
+                                };
+                                Ok::<_, std::convert::Infallible>(res.unwrap())
+                            });
+                        let _ = auto::Builder::new(TokioExecutor::new())
+                            .serve_connection(TokioIo::new(stream), svc_fn)
+                            .await;
+                    });
+                }
+            });
+            addr
+        }
+
+        /// Serve a proxy in front of `upstream`, compressing if `enabled`.
+        async fn spawn_proxy(upstream: SocketAddr, enabled: bool) -> SocketAddr {
+            let config = ProxyConfig {
+                compress_responses: enabled,
+                ..ProxyConfig::default()
+            };
+            let service =
+                ProxyService::new_with_config(Some(format!("http://{upstream}")), config).unwrap();
+
+            serve(service).await
+        }
+
+        /// GET `path` through the proxy; returns (headers, raw body).
+        async fn get(
+            proxy: SocketAddr,
+            path: &str,
+            accept_encoding: Option<&str>,
+        ) -> (http::HeaderMap, Bytes) {
+            let client = Client::builder(TokioExecutor::new()).build_http::<Empty<Bytes>>();
+            let mut req = Request::builder().uri(format!("http://{proxy}{path}"));
+            if let Some(accept_encoding) = accept_encoding {
+                req = req.header(header::ACCEPT_ENCODING, accept_encoding);
+            }
+            let res = tokio::time::timeout(
+                Duration::from_secs(5),
+                client.request(req.body(Empty::new()).unwrap()),
+            )
+            .await
+            .expect("request timed out")
+            .unwrap();
+            assert_eq!(res.status(), StatusCode::OK);
+            let (parts, body) = res.into_parts();
+            (parts.headers, body.collect().await.unwrap().to_bytes())
+        }
+
+        /// Test an eligible response is gzipped and decompresses to the original.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 F-001 (Zero-Copy Forwarding)
+        #[tokio::test]
+        async fn test_response_gzipped() {
+            let proxy = spawn_proxy(spawn_upstream().await, true).await;
+
+            let (headers, body) = get(proxy, "/json", Some("br;q=1.0, gzip;q=0.8")).await;
+
+            assert_eq!(headers[header::CONTENT_ENCODING], "gzip");
+            assert_eq!(headers[header::VARY], "accept-encoding");
+            assert_eq!(headers[header::ETAG], "W/\"v1\"");
+            assert!(headers.get(header::CONTENT_LENGTH).is_none());
+            assert!(body.len() < large_json().len());
+            let mut decoded = String::new();
+            flate2::read::GzDecoder::new(body.as_ref())
+                .read_to_string(&mut decoded)
+                .unwrap();
+            assert_eq!(decoded, large_json());
+        }
+
+        /// Test responses are passed through untouched when compression
+        /// doesn't apply.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 F-001 (Zero-Copy Forwarding)
+        #[tokio::test]
+        async fn test_response_not_gzipped() {
+            let upstream = spawn_upstream().await;
+            let proxy = spawn_proxy(upstream, true).await;
+
+            for (path, accept_encoding, encoding) in [
+                ("/json", None, None),
+                ("/json", Some("gzip;q=0, *"), None),
+                ("/small", Some("gzip"), None),
+                ("/image", Some("gzip"), None),
+                ("/encoded", Some("gzip"), Some("br")),
+            ] {
+                let (headers, body) = get(proxy, path, accept_encoding).await;
+                assert_eq!(
+                    headers
+                        .get(header::CONTENT_ENCODING)
+                        .map(|v| v.to_str().unwrap()),
+                    encoding,
+                    "{path} {accept_encoding:?}"
+                );
+                assert_eq!(headers[header::ETAG], "\"v1\"");
+                assert_eq!(
+                    headers[header::CONTENT_LENGTH],
+                    body.len().to_string().as_str()
+                );
+            }
+
+            // Off unless enabled
+            let proxy = spawn_proxy(upstream, false).await;
+            let (headers, body) = get(proxy, "/json", Some("gzip")).await;
+            assert!(headers.get(header::CONTENT_ENCODING).is_none());
+            assert_eq!(body, large_json());
+        }
+
+        #[test]
+        fn test_accepts_gzip() {
+            let accepts = |value: &str| {
+                let mut headers = http::HeaderMap::new();
+                headers.insert(header::ACCEPT_ENCODING, value.parse().unwrap());
+                accepts_gzip(&headers)
+            };
+            assert!(accepts("gzip"));
+            assert!(accepts("deflate, GZIP;q=0.5"));
+            assert!(accepts("x-gzip"));
+            assert!(accepts("*"));
+            assert!(!accepts("br, deflate"));
+            assert!(!accepts("gzip;q=0"));
+            assert!(!accepts("gzip; q=0.0, *"));
+            assert!(!accepts("*;q=0"));
+            assert!(!accepts_gzip(&http::HeaderMap::new()));
+        }
+
+        #[test]
+        fn test_compressible() {
+            let config = ProxyConfig::default();
+            let headers = |pairs: &[(header::HeaderName, &str)]| {
+                let mut headers = http::HeaderMap::new();
+                for (name, value) in pairs {
+                    headers.insert(name, value.parse().unwrap());
+                }
+                headers
+            };
+            let json = || (header::CONTENT_TYPE, "application/json");
+
+            assert!(compressible(StatusCode::OK, &headers(&[json()]), &config));
+            assert!(compressible(
+                StatusCode::OK,
+                &headers(&[(header::CONTENT_TYPE, "text/event-stream")]),
+                &config
+            ));
+            assert!(!compressible(StatusCode::OK, &headers(&[]), &config));
+            assert!(!compressible(
+                StatusCode::NO_CONTENT,
+                &headers(&[json()]),
+                &config
+            ));
+            assert!(!compressible(
+                StatusCode::PARTIAL_CONTENT,
+                &headers(&[json()]),
+                &config
+            ));
+            assert!(!compressible(
+                StatusCode::OK,
+                &headers(&[json(), (header::CONTENT_ENCODING, "gzip")]),
+                &config
+            ));
+            assert!(!compressible(
+                StatusCode::OK,
+                &headers(&[json(), (header::CACHE_CONTROL, "max-age=60, no-transform")]),
+                &config
+            ));
+            assert!(!compressible(
+                StatusCode::OK,
+                &headers(&[json(), (header::CONTENT_LENGTH, "1023")]),
+                &config
+            ));
+            assert!(compressible(
+                StatusCode::OK,
+                &headers(&[json(), (header::CONTENT_LENGTH, "1024")]),
+                &config
+            ));
+        }
+    }
+
+    mod chunked_tests {
+        use super::*;
+        use hyper_util::server::conn::auto;
+        use std::convert::Infallible;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::{TcpListener, TcpStream};
+        use tokio::sync::mpsc;
+
+        /// Upstream reporting each complete request body and its trailers,
+        /// then answering with a fixed body.
+        async fn spawn_capture_upstream() -> (
+            SocketAddr,
+            mpsc::UnboundedReceiver<(Bytes, Option<http::HeaderMap>)>,
+        ) {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            let (tx, rx) = mpsc::unbounded_channel();
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    let tx = tx.clone();
+                    tokio::spawn(async move {
+                        let svc_fn = hyper::service::service_fn(move |req: Request<Incoming>| {
+                            let tx = tx.clone();
+                            async move {
+                                // A body cut short is not reported
+                                if let Ok(collected) = req.into_body().collect().await {
+                                    let trailers = collected.trailers().cloned();
+                                    let _ = tx.send((collected.to_bytes(), trailers));
+                                }
+                                Ok::<_, Infallible>(Response::new(Full::new(Bytes::from_static(
+                                    b"ok",
+                                ))))
+                            }
+                        });
+                        let _ = auto::Builder::new(TokioExecutor::new())
+                            .serve_connection(TokioIo::new(stream), svc_fn)
+                            .await;
+                    });
+                }
+            });
+            (addr, rx)
+        }
+
+        /// Upstream answering one request with `response`, written one
+        /// piece at a time so the client reads each piece separately.
+        async fn spawn_raw_upstream(response: &'static [&'static [u8]]) -> SocketAddr {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            tokio::spawn(async move {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut head = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !head.ends_with(b"\r\n\r\n") {
+                    let n = stream.read(&mut buf).await.unwrap();
+                    assert!(n > 0, "request head cut short");
+                    head.extend_from_slice(&buf[..n]);
+                }
+                write_pieces(&mut stream, response).await;
+            });
+            addr
+        }
+
+        /// Serve a proxy in front of `upstream`.
+        async fn spawn_proxy(upstream: SocketAddr) -> SocketAddr {
+            let service = ProxyService::new_with_config(
+                Some(format!("http://{upstream}")),
+                ProxyConfig::default(),
+            )
+            .unwrap();
+
+            serve(service).await
+        }
+
+        /// Write each piece with a pause in between, so none are coalesced.
+        async fn write_pieces(stream: &mut TcpStream, pieces: &[&[u8]]) {
+            stream.set_nodelay(true).unwrap();
+            for piece in pieces {
+                stream.write_all(piece).await.unwrap();
+                stream.flush().await.unwrap();
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        }
+
+        /// Send a raw request to `proxy` piece by piece; returns the raw response.
+        async fn send_raw(proxy: SocketAddr, pieces: &[&[u8]]) -> String {
+            let mut stream = TcpStream::connect(proxy).await.unwrap();
+            write_pieces(&mut stream, pieces).await;
+            let mut response = Vec::new();
+            timeout(Duration::from_secs(5), stream.read_to_end(&mut response))
+                .await
+                .expect("response timed out")
+                .unwrap();
+            String::from_utf8(response).unwrap()
+        }
+
+        /// Test a chunked request with chunk extensions and trailers, its
+        /// terminator split across reads, reaches upstream byte for byte.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 F-003 (Transparency - preserve Content-Length/Transfer-Encoding)
+        #[tokio::test]
+        async fn test_chunked_request_extensions_trailers_split_terminator() {
+            let (upstream, mut received) = spawn_capture_upstream().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let response = send_raw(
+                proxy,
+                &[
+                    b"POST /upload HTTP/1.1\r\nHost: upstream\r\n\
+                      Transfer-Encoding: chunked\r\nTrailer: x-checksum\r\n\
+                      Connection: close\r\n\r\n",
+                    b"5;name=value\r\nhello\r\n",
+                    b"6;flag;q=\"a b\"\r\n wor",
+                    b"ld\r\n",
+                    b"0\r",
+                    b"\nx-checksum: abc\r\n\r",
+                    b"\n",
+                ],
+            )
+            .await;
+
+            assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+            assert!(response.ends_with("ok"), "{response}");
+            let (body, trailers) = timeout(Duration::from_secs(5), received.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(body.as_ref(), b"hello world");
+            assert_eq!(trailers.expect("request trailers")["x-checksum"], "abc");
+        }
+
+        /// Test a chunked request with bare LF line endings is refused
+        /// rather than reinterpreted: upstream never sees a complete body.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 F-002 (Fail-Fast Error Propagation)
+        #[tokio::test]
+        async fn test_chunked_request_lf_only_rejected() {
+            let (upstream, mut received) = spawn_capture_upstream().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let response = send_raw(
+                proxy,
+                &[
+                    b"POST /upload HTTP/1.1\r\nHost: upstream\r\n\
+                      Transfer-Encoding: chunked\r\nConnection: close\r\n\r\n",
+                    b"5\nhello\n0\n\n",
+                ],
+            )
+            .await;
+
+            assert!(!response.starts_with("HTTP/1.1 200"), "{response}");
+            let forwarded = timeout(Duration::from_millis(200), received.recv()).await;
+            assert!(
+                !matches!(forwarded, Ok(Some(_))),
+                "malformed body forwarded"
+            );
+        }
+
+        /// Test a chunked response with chunk extensions and trailers, its
+        /// terminator split across reads, reaches the client byte for byte.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 F-003 (Trailer Support)
+        #[tokio::test]
+        async fn test_chunked_response_extensions_trailers_split_terminator() {
+            let upstream = spawn_raw_upstream(&[
+                b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nTrailer: x-checksum\r\n\r\n",
+                b"8;ext=1\r\nstreame",
+                b"d\r\n",
+                b"1\r\n!\r\n0;last\r",
+                b"\nx-checksum: abc\r",
+                b"\n\r\n",
+            ])
+            .await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let client = Client::builder(TokioExecutor::new()).build_http::<Empty<Bytes>>();
+            let req = Request::builder()
+                .uri(format!("http://{proxy}/data"))
+                .header(header::TE, "trailers")
+                .body(Empty::new())
+                .unwrap();
+            let res = timeout(Duration::from_secs(5), client.request(req))
+                .await
+                .expect("request timed out")
+                .unwrap();
+            assert_eq!(res.status(), StatusCode::OK);
+            let collected = timeout(Duration::from_secs(5), res.into_body().collect())
+                .await
+                .expect("body timed out")
+                .unwrap();
+            let trailers = collected.trailers().cloned();
+            assert_eq!(collected.to_bytes().as_ref(), b"streamed!");
+            assert_eq!(trailers.expect("response trailers")["x-checksum"], "abc");
+        }
+    }
+
+    mod header_limit_tests {
+        use super::*;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::{TcpListener, TcpStream};
+
+        /// Start an upstream that counts the requests reaching it.
+        async fn spawn_upstream() -> (SocketAddr, Arc<AtomicUsize>) {
+            let hits = Arc::new(AtomicUsize::new(0));
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            let counter = hits.clone();
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    let counter = counter.clone();
+                    tokio::spawn(async move {
+                        let svc_fn = hyper::service::service_fn(move |_req: Request<Incoming>| {
+                            counter.fetch_add(1, Ordering::SeqCst);
+                            async {
+                                Ok::<_, std::convert::Infallible>(Response::new(Full::new(
+                                    Bytes::from_static(b"ok"),
+                                )))
+                            }
+                        });
+                        let _ = hyper_util::server::conn::auto::Builder::new(TokioExecutor::new())
+                            .serve_connection(TokioIo::new(stream), svc_fn)
+                            .await;
+                    });
+                }
+            });
+            (addr, hits)
+        }
+
+        /// Serve a proxy with small header limits in front of `upstream`.
+        async fn spawn_proxy(upstream: SocketAddr) -> SocketAddr {
+            let config = ProxyConfig {
+                max_request_headers: 10,
+                max_request_header_bytes: 1024,
+                ..ProxyConfig::default()
+            };
+            let service =
+                ProxyService::new_with_config(Some(format!("http://{upstream}")), config).unwrap();
+
+            serve(service).await
+        }
+
+        /// Send a raw HTTP/1.1 GET with `extra` headers; returns the status line.
+        async fn send(proxy: SocketAddr, extra: &str) -> String {
+            let mut stream = TcpStream::connect(proxy).await.unwrap();
+            let request =
+                format!("GET /data HTTP/1.1\r\nHost: x\r\nConnection: close\r\n{extra}\r\n");
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = Vec::new();
+            tokio::time::timeout(Duration::from_secs(5), stream.read_to_end(&mut response))
+                .await
+                .expect("response timed out")
+                .unwrap();
+            let response = String::from_utf8_lossy(&response).into_owned();
+            response.lines().next().unwrap_or_default().to_string()
+        }
+
+        /// Test too many headers are refused with 431 before reaching upstream.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 Section 3.2 (Header Limits)
+        #[tokio::test]
+        async fn test_too_many_headers_rejected() {
+            let (upstream, hits) = spawn_upstream().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let extra: String = (0..20).map(|i| format!("X-Pad-{i}: v\r\n")).collect();
+            let status = send(proxy, &extra).await;
+
+            assert_eq!(status, "HTTP/1.1 431 Request Header Fields Too Large");
+            assert_eq!(hits.load(Ordering::SeqCst), 0);
+        }
+
+        /// Test oversized header values are refused with 431 before reaching upstream.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 Section 3.2 (Header Limits)
+        #[tokio::test]
+        async fn test_oversized_headers_rejected() {
+            let (upstream, hits) = spawn_upstream().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let extra = format!("X-Big: {}\r\n", "a".repeat(2048));
+            let status = send(proxy, &extra).await;
+
+            assert_eq!(status, "HTTP/1.1 431 Request Header Fields Too Large");
+            assert_eq!(hits.load(Ordering::SeqCst), 0);
+        }
+
+        /// Test requests within the limits are forwarded.
+        #[tokio::test]
+        async fn test_headers_within_limits_forwarded() {
+            let (upstream, hits) = spawn_upstream().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let status = send(proxy, "X-Small: v\r\n").await;
+
+            assert_eq!(status, "HTTP/1.1 200 OK");
+            assert_eq!(hits.load(Ordering::SeqCst), 1);
+        }
+    }
+
+    mod header_rule_tests {
+        use super::*;
+        use crate::config::SourceHeaders;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::{TcpListener, TcpStream};
+
+        /// Start an upstream that echoes the request headers it receives.
+        async fn spawn_echo_upstream() -> SocketAddr {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(async move {
+                        let svc_fn =
+                            hyper::service::service_fn(|req: Request<Incoming>| async move {
+                                let echoed: String = req
+                                    .headers()
+                                    .iter()
+                                    .map(|(name, value)| {
+                                        format!("{name}: {}\n", value.to_str().unwrap_or_default())
+                                    })
+                                    .collect();
+                                Response::builder()
+                                    .header("server", "internal/1.2")
+                                    .header("cache-control", "max-age=60")
+                                    .header("keep-alive", "timeout=5")
+                                    .body(Full::new(Bytes::from(echoed)))
+                            });
+                        let _ = hyper_util::server::conn::auto::Builder::new(TokioExecutor::new())
+                            .serve_connection(TokioIo::new(stream), svc_fn)
+                            .await;
+                    });
+                }
+            });
+            addr
+        }
+
+        /// Serve a proxy in front of `upstream` with header rules for it.
+        async fn spawn_proxy(upstream: SocketAddr) -> SocketAddr {
+            let config: SourceHeaders = serde_saphyr::from_str(
+                r#"
+request:
+  remove: [x-internal-auth]
+  set: { authorization: "Bearer upstream" }
+  add: { x-tenant: billing }
+response:
+  remove: [server]
+  set: { cache-control: no-store }
+  add: { x-served-by: thoughtgate }
+"#,
+            )
+            .unwrap();
+            let rules = Arc::new(HeaderRules::from_config("billing", &config).unwrap());
+            let service = ProxyService::new_with_config(
+                Some(format!("http://{upstream}")),
+                ProxyConfig::default(),
+            )
+            .unwrap()
+            .with_header_rules(upstream.to_string(), rules);
+
+            serve(service).await
+        }
+
+        /// Send a raw HTTP/1.1 GET; returns the lowercased response head and body.
+        async fn send(proxy: SocketAddr, extra: &str) -> (String, String) {
+            let mut stream = TcpStream::connect(proxy).await.unwrap();
+            let request = format!("GET /data HTTP/1.1\r\nHost: x\r\n{extra}\r\n");
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = Vec::new();
+            tokio::time::timeout(Duration::from_secs(5), stream.read_to_end(&mut response))
+                .await
+                .expect("response timed out")
+                .unwrap();
+            let response = String::from_utf8_lossy(&response).to_lowercase();
+            let (head, body) = response.split_once("\r\n\r\n").unwrap();
+            (head.to_string(), body.to_string())
+        }
+
+        /// Test request headers are stripped, overridden and added on the way upstream.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+        #[tokio::test]
+        async fn test_request_header_rules() {
+            let upstream = spawn_echo_upstream().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let (_, received) = send(
+                proxy,
+                "X-Internal-Auth: secret\r\nAuthorization: Bearer client\r\n\
+                 X-Tenant: acme\r\nX-Hop: 1\r\nConnection: close, x-hop\r\n",
+            )
+            .await;
+
+            assert!(!received.contains("x-internal-auth"), "{received}");
+            assert!(received.contains("authorization: bearer upstream\n"));
+            assert!(!received.contains("bearer client"));
+            assert!(received.contains("x-tenant: acme\nx-tenant: billing\n"));
+            assert!(received.contains("x-forwarded-for: 127.0.0.1\n"));
+            assert!(received.contains("via: 1.1 thoughtgate\n"));
+            // Connection-specific headers never reach upstream
+            assert!(!received.contains("x-hop"), "{received}");
+        }
+
+        /// Test response headers are stripped, overridden and added on the way back.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+        #[tokio::test]
+        async fn test_response_header_rules() {
+            let upstream = spawn_echo_upstream().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let (head, _) = send(proxy, "Connection: close\r\n").await;
+
+            assert!(head.starts_with("http/1.1 200"), "{head}");
+            assert!(!head.contains("server: internal"), "{head}");
+            assert!(head.contains("cache-control: no-store"));
+            assert!(!head.contains("max-age=60"));
+            assert!(head.contains("x-served-by: thoughtgate"));
+            assert!(head.contains("via: 1.1 thoughtgate"));
+            assert!(!head.contains("keep-alive"), "{head}");
+        }
+    }
+
+    mod policy_load_tests {
+        use super::mcp_request_tests::MockUpstream;
+        use super::*;
+        use crate::governance::TaskStore;
+        use crate::lifecycle::LifecycleConfig;
+        use crate::transport::server::McpHandlerConfig;
+
+        /// Serve an MCP proxy that waits on `lifecycle` for its policies.
+        async fn spawn_proxy(lifecycle: Arc<LifecycleManager>) -> SocketAddr {
+            let handler = Arc::new(McpHandler::new(
+                Arc::new(MockUpstream),
+                Arc::new(CedarEngine::new().expect("Failed to create Cedar engine")),
+                Arc::new(TaskStore::with_defaults()),
+                McpHandlerConfig::default(),
+            ));
+            let service = ProxyService::new_with_config(None, ProxyConfig::default())
+                .unwrap()
+                .with_mcp_handler(handler)
+                .with_lifecycle(lifecycle);
+
+            serve(service).await
+        }
+
+        /// Test MCP requests fail closed with 503 until policies load.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-005/F-001 (Startup Sequencing)
+        #[tokio::test]
+        async fn test_mcp_request_refused_until_policies_load() {
+            let lifecycle = Arc::new(LifecycleManager::new(LifecycleConfig::default()));
+            let proxy = spawn_proxy(lifecycle.clone()).await;
+            let client = reqwest::Client::new();
+            let send = || {
+                client
+                    .post(format!("http://{proxy}/mcp/v1"))
+                    .header("content-type", "application/json")
+                    .body(r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#)
+                    .send()
+            };
+
+            let response = send().await.unwrap();
+            assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+            assert_eq!(response.headers()[header::RETRY_AFTER], "1");
+            let body: serde_json::Value = response.json().await.unwrap();
+            assert_eq!(body["error"]["code"], -32013);
+            assert!(!lifecycle.readiness_checks().policy_loaded);
+
+            lifecycle.record_policy_load(None);
+
+            let response = send().await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body: serde_json::Value = response.json().await.unwrap();
+            assert_eq!(body["id"], 1);
+            assert!(body.get("error").is_none(), "{body}");
+        }
+    }
+
+    mod early_forward_tests {
+        use super::mcp_request_tests::MockUpstream;
+        use super::*;
+        use crate::governance::TaskStore;
+        use crate::transport::server::McpHandlerConfig;
+        use hyper_util::server::conn::auto;
+        use std::convert::Infallible;
+        use tokio::net::TcpListener;
+        use tokio::sync::mpsc;
+
+        type TestBody = http_body_util::combinators::BoxBody<Bytes, Infallible>;
+
+        /// Start of a request whose method is read before its params.
+        const HEAD: &[u8] =
+            br#"{"jsonrpc":"2.0","id":1,"method":"custom/upload","params":{"data":""#;
+        const TAIL: &[u8] = br#"abcdef"}}"#;
+
+        /// Upstream reporting the size of the first body frame it receives,
+        /// then answering with the total size once the body ends.
+        async fn spawn_upstream() -> (SocketAddr, mpsc::UnboundedReceiver<usize>) {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            let (first_tx, first_rx) = mpsc::unbounded_channel();
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    let first_tx = first_tx.clone();
+                    tokio::spawn(async move {
+                        let svc_fn = hyper::service::service_fn(move |req: Request<Incoming>| {
+                            let first_tx = first_tx.clone();
+                            async move {
+                                let mut body = req.into_body();
+                                let mut total = 0;
+                                while let Some(frame) = body.frame().await {
+                                    if let Ok(data) = frame.unwrap().into_data() {
+                                        if total == 0 {
+                                            let _ = first_tx.send(data.len());
+                                        }
+                                        total += data.len();
+                                    }
+                                }
+                                let reply = serde_json::json!({
+                                    "jsonrpc": "2.0",
+                                    "id": 1,
+                                    "result": {"bytes": total}
+                                });
+                                Ok::<_, Infallible>(Response::new(Full::new(Bytes::from(
+                                    reply.to_string(),
+                                ))))
+                            }
+                        });
+                        let _ = auto::Builder::new(TokioExecutor::new())
+                            .serve_connection(TokioIo::new(stream), svc_fn)
+                            .await;
+                    });
+                }
+            });
+            (addr, first_rx)
+        }
+
+        /// Serve a proxy in front of `upstream` that streams `custom/upload`.
+        async fn spawn_proxy(upstream: SocketAddr) -> SocketAddr {
+            let handler = Arc::new(McpHandler::new(
+                Arc::new(MockUpstream),
+                Arc::new(CedarEngine::new().expect("Failed to create Cedar engine")),
+                Arc::new(TaskStore::with_defaults()),
+                McpHandlerConfig::default(),
+            ));
+            let config = ProxyConfig {
+                early_forward_methods: vec!["custom/upload".to_string()],
+                ..ProxyConfig::default()
+            };
+            let service = ProxyService::new_with_config(Some(format!("http://{upstream}")), config)
+                .unwrap()
+                .with_mcp_handler(handler);
+
+            serve(service).await
+        }
+
+        /// POST an MCP request whose body is sent as chunks arrive on the
+        /// returned sender; the handle resolves to the response.
+        fn post_streaming(
+            proxy: SocketAddr,
+        ) -> (
+            mpsc::UnboundedSender<Bytes>,
+            tokio::task::JoinHandle<serde_json::Value>,
+        ) {
+            let (tx, rx) = mpsc::unbounded_channel::<Bytes>();
+            let chunks = futures_util::stream::unfold(rx, |mut rx| async move {
+                let chunk = rx.recv().await?;
+                Some((Ok::<_, Infallible>(Frame::data(chunk)), rx))
+            });
+            let body: TestBody = BodyExt::boxed(StreamBody::new(chunks));
+            let req = Request::builder()
+                .method(http::Method::POST)
+                .uri(format!("http://{proxy}/mcp/v1"))
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(body)
+                .unwrap();
+            let response = tokio::spawn(async move {
+                let client = Client::builder(TokioExecutor::new()).build_http::<TestBody>();
+                let res = client.request(req).await.unwrap();
+                assert_eq!(res.status(), StatusCode::OK);
+                let body = res.into_body().collect().await.unwrap().to_bytes();
+                serde_json::from_slice(&body).unwrap()
+            });
+            (tx, response)
+        }
+
+        /// Test a listed pass-through method reaches upstream once its
+        /// request object has been read, before the client has finished
+        /// sending its body.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 (Zero-Copy Peeking Strategy)
+        #[tokio::test]
+        async fn test_body_forwarded_before_fully_received() {
+            let (upstream, mut first_bytes) = spawn_upstream().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let (tx, response) = post_streaming(proxy);
+            tx.send(Bytes::from_static(HEAD)).unwrap();
+            // A later member could still repeat the method
+            assert!(
+                timeout(Duration::from_millis(200), first_bytes.recv())
+                    .await
+                    .is_err()
+            );
+
+            tx.send(Bytes::from_static(TAIL)).unwrap();
+            let seen = timeout(Duration::from_secs(5), first_bytes.recv())
+                .await
+                .expect("upstream should receive the body before it is complete")
+                .unwrap();
+            assert!(seen > 0 && seen <= HEAD.len() + TAIL.len());
+
+            drop(tx);
+            let body = response.await.unwrap();
+            assert_eq!(body["result"]["bytes"], HEAD.len() + TAIL.len());
+        }
+
+        /// Test requests classification cannot settle on the method alone
+        /// are buffered and handled as usual.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 (Zero-Copy Peeking Strategy)
+        #[tokio::test]
+        async fn test_body_buffered_when_method_not_enough() {
+            let (upstream, mut first_bytes) = spawn_upstream().await;
+            let proxy = spawn_proxy(upstream).await;
+
+            let unlisted = [
+                br#"{"jsonrpc":"2.0","id":1,"method":"custom/other","params":{"data":""#.to_vec(),
+                // Only the first method of a batch could be peeked
+                [b"[", HEAD].concat(),
+                // The handler reads the last method, not the listed one
+                br#"{"jsonrpc":"2.0","method":"custom/upload","id":1,"method":"custom/other","params":{"data":""#.to_vec(),
+            ];
+            for head in unlisted {
+                let (tx, response) = post_streaming(proxy);
+                tx.send(Bytes::from(head.clone())).unwrap();
+                assert!(
+                    timeout(Duration::from_millis(200), first_bytes.recv())
+                        .await
+                        .is_err(),
+                    "{}",
+                    String::from_utf8_lossy(&head)
+                );
+
+                tx.send(Bytes::from_static(TAIL)).unwrap();
+                if head.starts_with(b"[") {
+                    tx.send(Bytes::from_static(b"]")).unwrap();
+                }
+                drop(tx);
+                let body = response.await.unwrap();
+                let body = body.get(0).unwrap_or(&body);
+                assert_eq!(body["result"]["mock"], "response", "{body}");
+            }
+        }
+    }
+
+    mod tap_tests {
+        use super::mcp_request_tests::MockUpstream;
+        use super::*;
+        use crate::governance::TaskStore;
+        use crate::tap::{TapBody, TapConfig, TapRecord};
+        use crate::transport::server::McpHandlerConfig;
+
+        /// Serve an MCP proxy recording every exchange to `path`.
+        async fn spawn_proxy(path: &std::path::Path) -> SocketAddr {
+            let handler = Arc::new(McpHandler::new(
+                Arc::new(MockUpstream),
+                Arc::new(CedarEngine::new().expect("Failed to create Cedar engine")),
+                Arc::new(TaskStore::with_defaults()),
+                McpHandlerConfig::default(),
+            ));
+            let tap = Tap::from_config(&TapConfig::new(path.to_string_lossy())).unwrap();
+            let service = ProxyService::new_with_config(None, ProxyConfig::default())
+                .unwrap()
+                .with_mcp_handler(handler)
+                .with_tap(Arc::new(tap));
+
+            serve(service).await
+        }
+
+        /// Test a recorded pair matches the request sent and the response
+        /// received, with credentials redacted.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-001 NFR-001 (Observability)
+        #[tokio::test]
+        async fn test_recorded_pair_matches_traffic() {
+            let path = std::env::temp_dir()
+                .join(format!("thoughtgate-tap-{}.ndjson", uuid::Uuid::new_v4()));
+            let proxy = spawn_proxy(&path).await;
+
+            let sent = serde_json::json!({"jsonrpc": "2.0", "id": 7, "method": "tools/list"});
+            let client = Client::builder(TokioExecutor::new()).build_http::<Full<Bytes>>();
+            let req = Request::builder()
+                .method(http::Method::POST)
+                .uri(format!("http://{proxy}/mcp/v1"))
+                .header(header::CONTENT_TYPE, "application/json")
+                .header(header::AUTHORIZATION, "Bearer agent-secret")
+                .body(Full::new(Bytes::from(sent.to_string())))
+                .unwrap();
+            let res = client.request(req).await.unwrap();
+            let status = res.status();
+            let received: serde_json::Value =
+                serde_json::from_slice(&res.into_body().collect().await.unwrap().to_bytes())
+                    .unwrap();
+
+            let mut contents = String::new();
+            for _ in 0..500 {
+                contents = std::fs::read_to_string(&path).unwrap_or_default();
+                if contents.ends_with('\n') {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            let _ = std::fs::remove_file(&path);
+            let record: TapRecord = serde_json::from_str(contents.trim_end()).unwrap();
+
+            assert_eq!(record.request.method, "POST");
+            assert_eq!(record.request.uri, "/mcp/v1");
+            assert_eq!(record.request.body, TapBody::Json(sent));
+            let header = |name: &str| {
+                record
+                    .request
+                    .headers
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, v)| v.as_str())
+            };
+            assert_eq!(header("content-type"), Some("application/json"));
+            assert_eq!(header("authorization"), Some("[REDACTED]"));
+            assert!(!contents.contains("agent-secret"));
+
+            assert_eq!(record.response.status, status.as_u16());
+            assert_eq!(record.response.body, TapBody::Json(received));
+        }
+    }
+
+    mod quota_tests {
+        use super::*;
+        use crate::governance::QuotaConfig;
+        use crate::policy::Principal;
+
+        fn principal(app_name: &str) -> Principal {
+            Principal {
+                app_name: app_name.to_string(),
+                namespace: "default".to_string(),
+                service_account: "default".to_string(),
+                roles: vec![],
+            }
+        }
+
+        fn call(id: i64, tool: &str) -> serde_json::Value {
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "method": "tools/call",
+                "params": {"name": tool, "arguments": {}}
+            })
+        }
+
+        /// Only tool calls consume quota, per principal, and refusals carry
+        /// the request ID and a retry hint.
+        ///
+        /// Verifies: REQ-GOV-001/F-009 (Rate Limiting)
+        #[tokio::test]
+        async fn test_check_quota_refuses_tool_calls_over_quota() {
+            let quota = QuotaLimiter::new(QuotaConfig::new(1.0));
+            let list = serde_json::json!({"jsonrpc": "2.0", "id": 9, "method": "tools/list"});
+            let body = |v: &serde_json::Value| serde_json::to_vec(v).unwrap();
+
+            with_client_principal(principal("agent-a"), async {
+                assert!(check_quota(&quota, &body(&call(1, "read"))).await.is_none());
+                assert!(check_quota(&quota, &body(&list)).await.is_none());
+
+                let (error, id) = check_quota(&quota, &body(&call(2, "read"))).await.unwrap();
+                assert_eq!(id, Some(JsonRpcId::Number(2)));
+                assert_eq!(error.to_jsonrpc_code(), -32009);
+                assert_eq!(error.retry_after(), Some(1));
+
+                // An explicit null id is echoed, not dropped
+                let mut null_id = call(4, "read");
+                null_id["id"] = serde_json::Value::Null;
+                let (_, id) = check_quota(&quota, &body(&null_id)).await.unwrap();
+                assert_eq!(id, Some(JsonRpcId::Null));
+
+                let batch = serde_json::json!([call(3, "read")]);
+                let (_, id) = check_quota(&quota, &body(&batch)).await.unwrap();
+                assert_eq!(id, None);
+            })
+            .await;
+
+            with_client_principal(principal("agent-b"), async {
+                assert!(check_quota(&quota, &body(&call(1, "read"))).await.is_none());
+            })
+            .await;
+        }
+    }
+
+    mod simulate_tests {
+        use super::*;
+        use serial_test::serial;
+
+        const POLICIES: &str = r#"
+            permit(
+                principal == ThoughtGate::App::"trusted",
+                action == ThoughtGate::Action::"Forward",
+                resource
+            );
+            permit(principal, action == ThoughtGate::Action::"Approve", resource);
+            forbid(principal, action, resource == ThoughtGate::ToolCall::"delete_user");
+        "#;
+
+        /// Serve a proxy with policy simulation in front of an unreachable
+        /// upstream, so any forwarded request would fail.
+        async fn spawn_proxy() -> SocketAddr {
+            unsafe {
+                std::env::set_var("THOUGHTGATE_POLICIES", POLICIES);
+            }
+            let engine = CedarEngine::new().expect("Failed to create engine");
+            unsafe {
+                std::env::remove_var("THOUGHTGATE_POLICIES");
+            }
+            let service = ProxyService::new_with_config(
+                Some("http://127.0.0.1:1".to_string()),
+                ProxyConfig::default(),
+            )
+            .unwrap()
+            .with_policy_simulator(Arc::new(engine), "s3cret");
+
+            serve(service).await
+        }
+
+        /// POST a simulation; returns the status and JSON body.
+        async fn simulate(
+            proxy: SocketAddr,
+            token: Option<&str>,
+            body: serde_json::Value,
+        ) -> (StatusCode, serde_json::Value) {
+            let client = Client::builder(TokioExecutor::new()).build_http::<Full<Bytes>>();
+            let mut req = Request::builder()
+                .method(http::Method::POST)
+                .uri(format!("http://{proxy}{POLICY_SIMULATE_PATH}"))
+                .header(header::CONTENT_TYPE, "application/json");
+            if let Some(token) = token {
+                req = req.header(header::AUTHORIZATION, format!("Bearer {token}"));
+            }
+            let req = req.body(Full::new(Bytes::from(body.to_string()))).unwrap();
+            let res = tokio::time::timeout(Duration::from_secs(5), client.request(req))
+                .await
+                .expect("simulation timed out")
+                .unwrap();
+            let status = res.status();
+            let body = res.into_body().collect().await.unwrap().to_bytes();
+            (status, serde_json::from_slice(&body).unwrap())
+        }
+
+        /// Test simulated requests get the loaded policy's decision.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-POL-001/§6.3 (Decision Explanation)
+        #[tokio::test]
+        #[serial]
+        async fn test_policy_simulate_decisions() {
+            let proxy = spawn_proxy().await;
+
+            for (principal, tool, action, determining) in [
+                ("trusted", "read_file", "forward", "policy0"),
+                ("other", "read_file", "approve", "policy1"),
+                ("trusted", "delete_user", "reject", "policy2"),
+            ] {
+                let (status, body) = simulate(
+                    proxy,
+                    Some("s3cret"),
+                    serde_json::json!({ "principal": principal, "tool": tool }),
+                )
+                .await;
+                assert_eq!(status, StatusCode::OK, "{principal}/{tool}: {body}");
+                assert_eq!(body["action"], action, "{principal}/{tool}");
+                assert_eq!(
+                    body["explanation"]["determining_policy"], determining,
+                    "{principal}/{tool}"
+                );
+            }
+
+            let (status, body) = simulate(
+                proxy,
+                Some("s3cret"),
+                serde_json::json!({ "principal": "trusted" }),
+            )
+            .await;
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+            assert!(body["error"].as_str().unwrap().contains("tool"));
+        }
+
+        /// Test simulation requires the configured token.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-POL-001/§6.3 (Decision Explanation)
+        #[tokio::test]
+        #[serial]
+        async fn test_policy_simulate_requires_token() {
+            let proxy = spawn_proxy().await;
+            let query = serde_json::json!({ "principal": "trusted", "tool": "read_file" });
+
+            for token in [None, Some("wrong")] {
+                let (status, body) = simulate(proxy, token, query.clone()).await;
+                assert_eq!(status, StatusCode::UNAUTHORIZED);
+                assert!(body.get("explanation").is_none());
+            }
+        }
+    }
+
+    mod admin_separation_tests {
+        use super::*;
+        use crate::admin::{AdminServer, AdminServerConfig};
+        use crate::lifecycle::{LifecycleConfig, LifecycleManager};
+        use hyper_util::server::conn::auto;
+        use tokio::net::TcpListener;
+        use tokio_util::sync::CancellationToken;
+
+        async fn get(addr: SocketAddr, path: &str, token: Option<&str>) -> StatusCode {
+            let client = Client::builder(TokioExecutor::new()).build_http::<Full<Bytes>>();
+            let mut req = Request::builder().uri(format!("http://{addr}{path}"));
+            if let Some(token) = token {
+                req = req.header(header::AUTHORIZATION, format!("Bearer {token}"));
+            }
+            let res = client
+                .request(req.body(Full::new(Bytes::new())).unwrap())
+                .await
+                .unwrap();
+            res.status()
+        }
+
+        /// Test metrics are served on the token-protected admin port, while
+        /// the traffic port forwards the same paths upstream.
+        ///
+        /// # Traceability
+        /// - Implements: REQ-CORE-005/§5.1 (Admin Server)
+        #[tokio::test]
+        async fn test_metrics_only_on_admin_port() {
+            let shutdown = CancellationToken::new();
+            let admin = AdminServer::with_config(
+                Arc::new(LifecycleManager::new(LifecycleConfig::default())),
+                AdminServerConfig {
+                    port: 0,
+                    bind_addr: "127.0.0.1".to_string(),
+                    token: Some("scrape-secret".to_string()),
+                },
+            );
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let admin_addr = listener.local_addr().unwrap();
+            tokio::spawn(admin.serve(listener, shutdown.clone()));
+
+            // Anything reaching the upstream was forwarded, not served
+            let upstream = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let upstream_addr = upstream.local_addr().unwrap();
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = upstream.accept().await {
+                    tokio::spawn(async move {
+                        let svc_fn = hyper::service::service_fn(|_req: Request<Incoming>| async {
+                            let mut res = Response::new(Full::new(Bytes::new()));
+                            *res.status_mut() = StatusCode::IM_A_TEAPOT;
+                            Ok::<_, std::convert::Infallible>(res)
+                        });
+                        let _ = auto::Builder::new(TokioExecutor::new())
+                            .serve_connection(TokioIo::new(stream), svc_fn)
+                            .await;
+                    });
+                }
+            });
+            let service = ProxyService::new_with_config(
+                Some(format!("http://{upstream_addr}")),
+                ProxyConfig::default(),
+            )
+            .unwrap();
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let traffic_addr = listener.local_addr().unwrap();
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    let service = service.clone();
+                    tokio::spawn(async move {
+                        let svc_fn = hyper::service::service_fn(move |req| {
+                            let service = service.clone();
+                            async move { service.handle_request(req).await }
+                        });
+                        let _ = auto::Builder::new(TokioExecutor::new())
+                            .serve_connection(TokioIo::new(stream), svc_fn)
+                            .await;
+                    });
+                }
+            });
+
+            assert_eq!(
+                get(admin_addr, "/metrics", Some("scrape-secret")).await,
+                StatusCode::OK
+            );
+            assert_eq!(
+                get(admin_addr, "/metrics", None).await,
+                StatusCode::UNAUTHORIZED
+            );
+            assert_eq!(
+                get(admin_addr, "/metrics", Some("wrong")).await,
+                StatusCode::UNAUTHORIZED
+            );
+            assert_eq!(get(admin_addr, "/health", None).await, StatusCode::OK);
+
+            for path in ["/metrics", "/health", "/debug/explain"] {
+                assert_eq!(
+                    get(traffic_addr, path, Some("scrape-secret")).await,
+                    StatusCode::IM_A_TEAPOT,
+                    "{path} served on the traffic port"
+                );
+            }
+            shutdown.cancel();
+        }
+    }
 }
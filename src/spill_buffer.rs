@@ -0,0 +1,482 @@
+//! Disk spill for large Amber Path buffers.
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-002 Section 3.2 (Memory Management)
+//!
+//! With `buffer_spill_threshold` set, a body stays in memory until it grows
+//! past the threshold. It is then moved to a temp file that is unlinked as
+//! soon as it is created, so no other process can open it by name and
+//! nothing is left behind if the proxy dies. The rest of the body is
+//! appended to the file, and the forwarded body is read back from it in
+//! chunks.
+//!
+//! Spilled bodies reserve their expected size (their `Content-Length`, or
+//! the per-body limit) against `buffer_disk_budget` when the spill starts.
+//! A body that cannot fit is rejected with
+//! `ProxyError::BufferBudgetExhausted`, like the memory budget.
+//!
+//! Inspectors see the payload as one slice, so the forwarder reads a
+//! spilled body back into memory while its inspector chain runs. Receiving
+//! and forwarding, which wait on the network, hold at most the threshold.
+
+use std::io::{self, SeekFrom};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bytes::{Bytes, BytesMut};
+use futures_util::stream::{self, BoxStream, StreamExt};
+use http::HeaderMap;
+use http_body::{Body, Frame};
+use http_body_util::{BodyExt, StreamBody};
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tracing::{debug, error, warn};
+
+use crate::error::{ProxyError, ProxyResult};
+use crate::metrics::get_amber_metrics;
+use crate::proxy_config::ProxyConfig;
+
+/// Bytes read from a spill file per forwarded data frame.
+const READ_CHUNK: usize = 64 * 1024;
+
+/// A buffered body, streamed as one data frame (or file chunks) and then
+/// its trailers.
+pub type BufferedBody = StreamBody<BoxStream<'static, Result<Frame<Bytes>, io::Error>>>;
+
+/// Bytes that spilled buffers may occupy on disk at once.
+///
+/// Unlike the memory [`BufferBudget`](crate::buffered_forwarder::BufferBudget),
+/// a reservation that does not fit fails immediately: waiting would hold
+/// the memory the spill was meant to release.
+#[derive(Debug, Clone)]
+pub struct DiskBudget {
+    used: Arc<AtomicUsize>,
+    total: usize,
+}
+
+/// Bytes reserved against a [`DiskBudget`], released on drop.
+#[derive(Debug)]
+pub struct DiskReservation {
+    used: Arc<AtomicUsize>,
+    bytes: usize,
+}
+
+impl DiskBudget {
+    /// Create a budget of `total` bytes.
+    pub fn new(total: usize) -> Self {
+        Self {
+            used: Arc::new(AtomicUsize::new(0)),
+            total,
+        }
+    }
+
+    /// Total budget in bytes.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Bytes currently reserved.
+    pub fn in_use(&self) -> usize {
+        self.used.load(Ordering::Acquire)
+    }
+
+    /// Reserve `bytes`, or `None` if they do not fit.
+    pub fn reserve(&self, bytes: usize) -> Option<DiskReservation> {
+        let mut reservation = DiskReservation {
+            used: self.used.clone(),
+            bytes: 0,
+        };
+        reservation.grow(bytes, self.total).then_some(reservation)
+    }
+}
+
+impl DiskReservation {
+    /// Bytes held by this reservation.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Reserve `more` bytes in addition, if the budget has room.
+    fn grow(&mut self, more: usize, total: usize) -> bool {
+        let reserved = self
+            .used
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                used.checked_add(more).filter(|&next| next <= total)
+            })
+            .is_ok();
+        if reserved {
+            self.bytes += more;
+        }
+        reserved
+    }
+}
+
+impl Drop for DiskReservation {
+    fn drop(&mut self) {
+        self.used.fetch_sub(self.bytes, Ordering::AcqRel);
+    }
+}
+
+/// When and where Amber Path buffers spill to disk.
+///
+/// Clones share the disk budget.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-002 Section 3.2 (Memory Management)
+#[derive(Debug, Clone)]
+pub struct SpillConfig {
+    /// Bodies larger than this spill
+    threshold: usize,
+    /// Directory for spill files (system temp directory if unset)
+    dir: Option<PathBuf>,
+    /// Disk space shared by all spilled bodies
+    disk: DiskBudget,
+}
+
+impl SpillConfig {
+    /// Spill bodies larger than `threshold` into `dir`, using at most
+    /// `disk_budget` bytes of disk in total.
+    pub fn new(threshold: usize, dir: Option<PathBuf>, disk_budget: usize) -> Self {
+        Self {
+            threshold,
+            dir,
+            disk: DiskBudget::new(disk_budget),
+        }
+    }
+
+    /// Take the spill settings from the proxy configuration, or `None` if
+    /// `buffer_spill_threshold` is unset.
+    pub fn from_config(config: &ProxyConfig) -> Option<Self> {
+        config.buffer_spill_threshold.map(|threshold| {
+            Self::new(
+                threshold,
+                config.buffer_spill_dir.clone(),
+                config.buffer_disk_budget,
+            )
+        })
+    }
+
+    /// Bodies larger than this spill to disk.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The disk budget spilled bodies reserve against.
+    pub fn disk(&self) -> &DiskBudget {
+        &self.disk
+    }
+
+    /// Create an unlinked spill file with `expected` bytes of disk reserved.
+    fn create(&self, expected: usize) -> ProxyResult<SpillFile> {
+        let Some(reservation) = self.disk.reserve(expected) else {
+            warn!(
+                bytes = expected,
+                in_use = self.disk.in_use(),
+                budget = self.disk.total(),
+                "Amber Path disk budget exhausted, rejecting"
+            );
+            return Err(ProxyError::BufferBudgetExhausted);
+        };
+
+        // The file is unlinked before this returns (deleted on close on Windows)
+        let file = match &self.dir {
+            Some(dir) => tempfile::tempfile_in(dir),
+            None => tempfile::tempfile(),
+        }
+        .inspect_err(|e| error!(error = %e, "Failed to create Amber Path spill file"))?;
+
+        debug!(bytes = expected, "Spilling Amber Path buffer to disk");
+        if let Some(m) = get_amber_metrics() {
+            m.record_spill();
+        }
+        Ok(SpillFile {
+            file: File::from_std(file),
+            len: 0,
+            disk_total: self.disk.total(),
+            reservation,
+        })
+    }
+}
+
+/// A body spilled to an unlinked temp file.
+#[derive(Debug)]
+pub struct SpillFile {
+    file: File,
+    len: usize,
+    /// Total of the disk budget, for growing the reservation
+    disk_total: usize,
+    reservation: DiskReservation,
+}
+
+impl SpillFile {
+    /// Append `data`, growing the disk reservation if the body outgrows it.
+    async fn append(&mut self, data: &[u8]) -> ProxyResult<()> {
+        let needed = (self.len + data.len()).saturating_sub(self.reservation.bytes());
+        if needed > 0 && !self.reservation.grow(needed, self.disk_total) {
+            warn!(
+                bytes = self.len + data.len(),
+                "Amber Path disk budget exhausted, rejecting"
+            );
+            return Err(ProxyError::BufferBudgetExhausted);
+        }
+        self.file.write_all(data).await?;
+        self.len += data.len();
+        Ok(())
+    }
+
+    /// Flush what was written and rewind to the start.
+    async fn rewind(&mut self) -> io::Result<()> {
+        self.file.flush().await?;
+        self.file.seek(SeekFrom::Start(0)).await?;
+        Ok(())
+    }
+}
+
+/// A body held in memory, or spilled to an unlinked temp file.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-002 Section 3.2 (Memory Management)
+#[derive(Debug)]
+pub enum Buffered {
+    /// The whole body in memory
+    Memory(Bytes),
+    /// The whole body on disk
+    Disk(SpillFile),
+}
+
+impl Buffered {
+    /// Body length in bytes.
+    pub fn len(&self) -> usize {
+        match self {
+            Buffered::Memory(bytes) => bytes.len(),
+            Buffered::Disk(file) => file.len,
+        }
+    }
+
+    /// Whether the body is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the body was spilled to disk.
+    pub fn is_spilled(&self) -> bool {
+        matches!(self, Buffered::Disk(_))
+    }
+
+    /// The whole body in memory, reading a spilled body back from disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the spill file cannot be read.
+    pub async fn to_bytes(&mut self) -> io::Result<Bytes> {
+        match self {
+            Buffered::Memory(bytes) => Ok(bytes.clone()),
+            Buffered::Disk(spill) => {
+                let mut bytes = Vec::with_capacity(spill.len);
+                spill.file.read_to_end(&mut bytes).await?;
+                spill.file.seek(SeekFrom::Start(0)).await?;
+                Ok(Bytes::from(bytes))
+            }
+        }
+    }
+
+    /// Stream the body, then `trailers`.
+    ///
+    /// A spilled body is read from disk in chunks as it is forwarded, and
+    /// its disk reservation is released once the stream is dropped.
+    pub fn into_body(self, trailers: Option<HeaderMap>) -> BufferedBody {
+        let trailers = stream::iter(trailers.map(|t| Ok(Frame::trailers(t))));
+        let data = match self {
+            Buffered::Memory(bytes) => stream::iter([Ok(Frame::data(bytes))]).boxed(),
+            Buffered::Disk(spill) => stream::unfold(Some(spill), |spill| async move {
+                let mut spill = spill?;
+                let mut chunk = BytesMut::with_capacity(READ_CHUNK);
+                match spill.file.read_buf(&mut chunk).await {
+                    Ok(0) => None,
+                    Ok(_) => Some((Ok(Frame::data(chunk.freeze())), Some(spill))),
+                    Err(e) => Some((Err(e), None)),
+                }
+            })
+            .boxed(),
+        };
+        StreamBody::new(data.chain(trailers).boxed())
+    }
+}
+
+/// Buffer `body` up to `limit` bytes, spilling to disk past the threshold.
+///
+/// `expected` is the size to reserve on disk if the body spills: its
+/// `Content-Length`, or `limit` when the length is unknown.
+///
+/// # Errors
+///
+/// - `PayloadTooLarge` - The body exceeds `limit`
+/// - `BufferBudgetExhausted` - The body does not fit in the disk budget
+/// - `Client` - Reading the body failed
+/// - `Io` - Writing the spill file failed
+///
+/// # Traceability
+/// - Implements: REQ-CORE-002 F-001 (Safe Buffering)
+/// - Implements: REQ-CORE-002 F-005 (Trailer Preservation)
+pub async fn buffer_body<B>(
+    mut body: B,
+    limit: usize,
+    expected: usize,
+    spill: Option<&SpillConfig>,
+) -> ProxyResult<(Buffered, Option<HeaderMap>)>
+where
+    B: Body<Data = Bytes> + Unpin,
+    B::Error: std::fmt::Display,
+{
+    let mut memory = BytesMut::new();
+    let mut file: Option<SpillFile> = None;
+    let mut len = 0;
+    let mut trailers = None;
+
+    while let Some(frame) = body.frame().await {
+        let frame = frame.map_err(|e| {
+            error!(error = %e, "Failed to buffer body");
+            ProxyError::Client(e.to_string())
+        })?;
+        let data = match frame.into_data() {
+            Ok(data) => data,
+            Err(frame) => {
+                if let Ok(t) = frame.into_trailers() {
+                    trailers = Some(t);
+                }
+                continue;
+            }
+        };
+
+        len += data.len();
+        if len > limit {
+            warn!(limit = limit, "Payload exceeded buffer limit");
+            return Err(ProxyError::PayloadTooLarge(len, limit));
+        }
+
+        match (&mut file, spill) {
+            (Some(file), _) => file.append(&data).await?,
+            (None, Some(spill)) if len > spill.threshold => {
+                let mut spilled = spill.create(expected.max(len))?;
+                spilled.append(&memory).await?;
+                spilled.append(&data).await?;
+                memory = BytesMut::new();
+                file = Some(spilled);
+            }
+            (None, _) => memory.extend_from_slice(&data),
+        }
+    }
+
+    let buffered = match file {
+        Some(mut file) => {
+            file.rewind().await?;
+            Buffered::Disk(file)
+        }
+        None => Buffered::Memory(memory.freeze()),
+    };
+    Ok((buffered, trailers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::Full;
+
+    /// A body delivered in `chunks` data frames, then `trailers`.
+    fn chunked(chunks: &[&'static [u8]], trailers: Option<HeaderMap>) -> BufferedBody {
+        let data = chunks
+            .iter()
+            .map(|chunk| Ok(Frame::data(Bytes::from_static(chunk))));
+        let trailers = trailers.map(|t| Ok(Frame::trailers(t)));
+        StreamBody::new(stream::iter(data.chain(trailers).collect::<Vec<_>>()).boxed())
+    }
+
+    /// A fresh, empty directory for spill files.
+    fn spill_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("thoughtgate-spill-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&dir).unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_spills_past_threshold() {
+        let dir = spill_dir();
+        let spill = SpillConfig::new(16, Some(dir.clone()), 1024);
+
+        // Small bodies stay in memory
+        let (small, _) = buffer_body(Full::new(Bytes::from_static(b"tiny")), 512, 4, Some(&spill))
+            .await
+            .unwrap();
+        assert!(!small.is_spilled());
+        assert_eq!(spill.disk().in_use(), 0);
+
+        let mut trailers = HeaderMap::new();
+        trailers.insert("x-checksum", "abc".parse().unwrap());
+        let chunks: [&[u8]; 3] = [b"first chunk ", b"crosses the threshold ", b"and more"];
+        let (mut buffered, got_trailers) =
+            buffer_body(chunked(&chunks, Some(trailers)), 512, 512, Some(&spill))
+                .await
+                .unwrap();
+
+        let original = chunks.concat();
+        assert!(buffered.is_spilled());
+        assert_eq!(buffered.len(), original.len());
+        assert_eq!(spill.disk().in_use(), 512);
+        // Unlinked as soon as it was created
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+
+        assert_eq!(buffered.to_bytes().await.unwrap(), original);
+        let collected = BodyExt::collect(buffered.into_body(got_trailers))
+            .await
+            .unwrap();
+        assert_eq!(collected.trailers().unwrap()["x-checksum"], "abc");
+        assert_eq!(collected.to_bytes(), original);
+        assert_eq!(spill.disk().in_use(), 0);
+
+        std::fs::remove_dir(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_disk_budget_is_a_hard_cap() {
+        let spill = SpillConfig::new(8, None, 100);
+        let body = || chunked(&[b"0123456789", b"0123456789"], None);
+
+        // The expected size does not fit
+        assert!(matches!(
+            buffer_body(body(), 512, 200, Some(&spill)).await,
+            Err(ProxyError::BufferBudgetExhausted)
+        ));
+
+        // One body fits; a second cannot while the first holds its space
+        let (held, _) = buffer_body(body(), 512, 60, Some(&spill)).await.unwrap();
+        assert!(held.is_spilled());
+        assert!(matches!(
+            buffer_body(body(), 512, 60, Some(&spill)).await,
+            Err(ProxyError::BufferBudgetExhausted)
+        ));
+        drop(held);
+
+        // A body larger than its expected size grows its reservation
+        let (grown, _) = buffer_body(body(), 512, 12, Some(&spill)).await.unwrap();
+        assert_eq!(spill.disk().in_use(), 20);
+        drop(grown);
+        assert_eq!(spill.disk().in_use(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_limit_applies_with_spill() {
+        let spill = SpillConfig::new(4, None, 1024);
+        assert!(matches!(
+            buffer_body(
+                chunked(&[b"0123456789".as_slice(); 3], None),
+                25,
+                25,
+                Some(&spill)
+            )
+            .await,
+            Err(ProxyError::PayloadTooLarge(30, 25))
+        ));
+        assert_eq!(spill.disk().in_use(), 0);
+    }
+}
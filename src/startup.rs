@@ -0,0 +1,602 @@
+//! Startup self-test.
+//!
+//! Implements: REQ-CORE-005/F-001 (Startup Sequencing)
+//!
+//! With a `self_test:` section in the config file, the binary runs
+//! [`self_test`] before binding any port, so a misconfiguration shows up
+//! as a failed start rather than as the first refused request:
+//!
+//! | Check | Passes when |
+//! |-------|-------------|
+//! | `policies` | The Cedar policy set loads, with catalog attributes |
+//! | `samples` | Every case in the `samples` fixture gets its expected decision |
+//! | `slack` | Slack accepts the bot token (`auth.test`) |
+//! | `upstream:<source>` | The source's upstream accepts a TCP connection |
+//!
+//! Each check can be turned off. The Slack check is skipped when approvals
+//! do not go to Slack. A failed check stops startup unless `on_failure` is
+//! `warn`, in which case it is logged at error level.
+//!
+//! ```yaml
+//! self_test:
+//!   samples: /etc/thoughtgate/policy-tests.yaml
+//!   slack: false
+//!   on_failure: warn
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use async_trait::async_trait;
+use thiserror::Error;
+use tracing::{error, info};
+
+use crate::config::{
+    ApprovalDestination, Config, LiveCatalog, SelfTestConfig, SelfTestFailureMode,
+};
+use crate::governance::{SlackAdapter, SlackConfig};
+use crate::policy::engine::CedarEngine;
+use crate::policy::testkit;
+use crate::transport::{UpstreamClient, UpstreamConfig};
+
+/// Errors from [`self_test`].
+#[derive(Debug, Error)]
+pub enum SelfTestError {
+    /// A check failed and `on_failure` is `fail`.
+    #[error("Startup self-test failed: {}", summary(.report))]
+    Failed {
+        /// Every check that ran, including the failures
+        report: SelfTestReport,
+    },
+}
+
+fn summary(report: &SelfTestReport) -> String {
+    report
+        .failures()
+        .map(|check| format!("{}: {}", check.name, check.detail))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// How a self-test check ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// The check passed.
+    Passed,
+    /// The check failed.
+    Failed,
+    /// The check was turned off or does not apply.
+    Skipped,
+}
+
+/// Result of one self-test check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    /// Check name (`policies`, `samples`, `slack` or `upstream:<source>`).
+    pub name: String,
+    /// How the check ended.
+    pub status: CheckStatus,
+    /// What was checked, why it failed, or why it was skipped.
+    pub detail: String,
+}
+
+/// Results of a self-test run, in the order the checks ran.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelfTestReport {
+    /// One result per check.
+    pub checks: Vec<CheckResult>,
+}
+
+impl SelfTestReport {
+    /// Whether no check failed.
+    pub fn passed(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// The checks that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &CheckResult> {
+        self.checks
+            .iter()
+            .filter(|check| check.status == CheckStatus::Failed)
+    }
+
+    /// The result of the check called `name`.
+    pub fn check(&self, name: &str) -> Option<&CheckResult> {
+        self.checks.iter().find(|check| check.name == name)
+    }
+
+    fn record(&mut self, name: impl Into<String>, result: Result<String, String>) {
+        let (status, detail) = match result {
+            Ok(detail) => (CheckStatus::Passed, detail),
+            Err(detail) => (CheckStatus::Failed, detail),
+        };
+        self.push(name, status, detail);
+    }
+
+    fn skip(&mut self, name: impl Into<String>, reason: &str) {
+        self.push(name, CheckStatus::Skipped, reason.to_string());
+    }
+
+    fn push(&mut self, name: impl Into<String>, status: CheckStatus, detail: String) {
+        self.checks.push(CheckResult {
+            name: name.into(),
+            status,
+            detail,
+        });
+    }
+}
+
+impl fmt::Display for SelfTestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            let status = match check.status {
+                CheckStatus::Passed => "PASS",
+                CheckStatus::Failed => "FAIL",
+                CheckStatus::Skipped => "SKIP",
+            };
+            writeln!(f, "{status} {}: {}", check.name, check.detail)?;
+        }
+        Ok(())
+    }
+}
+
+/// The network side of the self-test, so it can be replaced in tests.
+#[async_trait]
+pub trait SelfTestProbes: Send + Sync {
+    /// Verify the Slack bot token, returning who it authenticates as.
+    async fn slack(&self) -> Result<String, String>;
+
+    /// Verify that `url` accepts connections.
+    async fn upstream(&self, url: &str) -> Result<(), String>;
+}
+
+/// Probes that talk to Slack and the upstreams.
+///
+/// Slack credentials come from the environment, as for the approval
+/// adapter (see [`SlackConfig::from_env`]).
+pub struct NetworkProbes;
+
+#[async_trait]
+impl SelfTestProbes for NetworkProbes {
+    async fn slack(&self) -> Result<String, String> {
+        let config = SlackConfig::from_env().map_err(|e| e.to_string())?;
+        let adapter = SlackAdapter::new(config).map_err(|e| e.to_string())?;
+        adapter.auth_test().await.map_err(|e| e.to_string())
+    }
+
+    async fn upstream(&self, url: &str) -> Result<(), String> {
+        let client =
+            UpstreamClient::new(UpstreamConfig::with_base_url(url)).map_err(|e| e.to_string())?;
+        client.health_check().await.map_err(|e| e.to_string())
+    }
+}
+
+/// Run the checks in `config`'s `self_test:` section against the real
+/// policies, Slack and upstreams.
+///
+/// Without a `self_test:` section nothing runs and the report is empty.
+///
+/// # Errors
+///
+/// Returns `SelfTestError::Failed` if a check fails and `on_failure` is
+/// `fail`.
+pub async fn self_test(config: &Config) -> Result<SelfTestReport, SelfTestError> {
+    self_test_with(config, &NetworkProbes).await
+}
+
+/// [`self_test`] with the network checks made through `probes`.
+///
+/// # Errors
+///
+/// As [`self_test`].
+pub async fn self_test_with(
+    config: &Config,
+    probes: &dyn SelfTestProbes,
+) -> Result<SelfTestReport, SelfTestError> {
+    let mut report = SelfTestReport::default();
+    let Some(settings) = config.self_test.as_ref() else {
+        return Ok(report);
+    };
+
+    check_policies(config, settings, &mut report);
+    check_slack(config, settings, probes, &mut report).await;
+    check_upstreams(config, settings, probes, &mut report).await;
+
+    for check in &report.checks {
+        match check.status {
+            CheckStatus::Failed => {
+                error!(check = %check.name, reason = %check.detail, "Self-test check failed")
+            }
+            CheckStatus::Passed => {
+                info!(check = %check.name, detail = %check.detail, "Self-test check passed")
+            }
+            CheckStatus::Skipped => {
+                info!(check = %check.name, reason = %check.detail, "Self-test check skipped")
+            }
+        }
+    }
+
+    if report.passed() || settings.on_failure == SelfTestFailureMode::Warn {
+        Ok(report)
+    } else {
+        Err(SelfTestError::Failed { report })
+    }
+}
+
+/// Load the policy set and run the sample requests through it.
+fn check_policies(config: &Config, settings: &SelfTestConfig, report: &mut SelfTestReport) {
+    if !settings.policies && settings.samples.is_none() {
+        report.skip("policies", "disabled");
+        return;
+    }
+
+    let cases = match &settings.samples {
+        Some(path) => match testkit::load_cases(path) {
+            Ok(cases) => Some(cases),
+            Err(e) => {
+                report.record("samples", Err(e.to_string()));
+                None
+            }
+        },
+        None => None,
+    };
+
+    // Declare catalog attributes as the proxy does, plus any the samples use
+    let mut attributes = BTreeMap::new();
+    if let Some(path) = &config.catalog {
+        match LiveCatalog::load(path.clone()) {
+            Ok(catalog) => attributes.extend(catalog.declared_types().clone()),
+            Err(e) => {
+                report.record("policies", Err(format!("Failed to load tool catalog: {e}")));
+                return;
+            }
+        }
+    }
+    for (key, attr_type) in testkit::attribute_types(cases.iter().flatten()) {
+        attributes.entry(key).or_insert(attr_type);
+    }
+
+    let engine = match CedarEngine::new_with_attributes(&attributes) {
+        Ok(engine) => engine,
+        Err(e) => {
+            report.record("policies", Err(e.to_string()));
+            return;
+        }
+    };
+    if settings.policies {
+        report.record("policies", Ok("policy set loaded".to_string()));
+    } else {
+        report.skip("policies", "disabled");
+    }
+
+    let Some(cases) = cases else {
+        if settings.samples.is_none() {
+            report.skip("samples", "no samples configured");
+        }
+        return;
+    };
+    let results = testkit::run(&engine, &cases);
+    let failed = results.failures().count();
+    report.record(
+        "samples",
+        if failed == 0 {
+            Ok(format!("{} cases passed", cases.len()))
+        } else {
+            Err(format!(
+                "{failed} of {} cases failed: {}",
+                cases.len(),
+                results
+                    .failures()
+                    .map(|outcome| outcome.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        },
+    );
+}
+
+/// Verify the Slack token, if approvals are posted to Slack.
+async fn check_slack(
+    config: &Config,
+    settings: &SelfTestConfig,
+    probes: &dyn SelfTestProbes,
+    report: &mut SelfTestReport,
+) {
+    if !settings.slack {
+        report.skip("slack", "disabled");
+    } else if !uses_slack(config) {
+        report.skip("slack", "approvals do not use Slack");
+    } else {
+        let result = probes
+            .slack()
+            .await
+            .map(|identity| format!("authenticated as {identity}"));
+        report.record("slack", result);
+    }
+}
+
+/// Whether approvals go to Slack, choosing the adapter as
+/// [`crate::transport::create_governance_components`] does.
+fn uses_slack(config: &Config) -> bool {
+    if !config.requires_approval_engine() {
+        return false;
+    }
+    let selected = std::env::var("THOUGHTGATE_APPROVAL_ADAPTER").ok();
+    let default_destination = config.get_workflow("default").map(|w| &w.destination);
+    !matches!(
+        (selected.as_deref(), default_destination),
+        (Some("mock" | "webhook"), _) | (None, Some(ApprovalDestination::Webhook { .. }))
+    )
+}
+
+/// Verify every enabled source's upstream accepts connections.
+async fn check_upstreams(
+    config: &Config,
+    settings: &SelfTestConfig,
+    probes: &dyn SelfTestProbes,
+    report: &mut SelfTestReport,
+) {
+    if !settings.upstream {
+        report.skip("upstream", "disabled");
+        return;
+    }
+    for source in config.sources.iter().filter(|s| s.is_enabled()) {
+        let result = probes
+            .upstream(source.url())
+            .await
+            .map(|()| format!("{} reachable", source.url()));
+        report.record(format!("upstream:{}", source.id()), result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::sync::Mutex;
+
+    const POLICIES: &str = r#"
+        @id("reads")
+        permit(
+            principal,
+            action == ThoughtGate::Action::"tools/call",
+            resource
+        ) when { resource.name like "get_*" };
+    "#;
+
+    /// Probes that answer from canned results and record what was asked.
+    struct FakeProbes {
+        slack: Result<String, String>,
+        unreachable: Vec<String>,
+        probed: Mutex<Vec<String>>,
+    }
+
+    impl FakeProbes {
+        fn healthy() -> Self {
+            Self {
+                slack: Ok("thoughtgate@acme".to_string()),
+                unreachable: Vec::new(),
+                probed: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SelfTestProbes for FakeProbes {
+        async fn slack(&self) -> Result<String, String> {
+            self.probed.lock().unwrap().push("slack".to_string());
+            self.slack.clone()
+        }
+
+        async fn upstream(&self, url: &str) -> Result<(), String> {
+            self.probed.lock().unwrap().push(url.to_string());
+            if self.unreachable.iter().any(|u| u == url) {
+                Err("connection refused".to_string())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn config(self_test: &str, action: &str) -> Config {
+        let yaml = format!(
+            r##"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://localhost:8080
+governance:
+  defaults:
+    action: {action}
+approval:
+  default:
+    destination:
+      type: slack
+      channel: "#approvals"
+self_test:
+{self_test}
+"##
+        );
+        serde_saphyr::from_str(&yaml).expect("valid config")
+    }
+
+    fn samples_file(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "thoughtgate_self_test_{name}_{}.yaml",
+            std::process::id()
+        ));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    async fn run_with_policies(
+        config: &Config,
+        probes: &FakeProbes,
+    ) -> Result<SelfTestReport, SelfTestError> {
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", POLICIES);
+        }
+        let result = self_test_with(config, probes).await;
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+        result
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_self_test_passes_with_samples() {
+        let samples = samples_file(
+            "pass",
+            r#"
+- name: reads are allowed
+  principal: { app: agent }
+  resource: { tool: get_ticket }
+  expect: permit
+  determining_policy: reads
+- name: deletes are not
+  principal: { app: agent }
+  resource: { tool: delete_ticket }
+  expect: forbid
+"#,
+        );
+        let config = config(&format!("  samples: {}", samples.display()), "forward");
+        let probes = FakeProbes::healthy();
+
+        let report = run_with_policies(&config, &probes).await.unwrap();
+        std::fs::remove_file(&samples).ok();
+
+        assert!(report.passed(), "{report}");
+        assert_eq!(
+            report.check("policies").unwrap().status,
+            CheckStatus::Passed
+        );
+        let samples = report.check("samples").unwrap();
+        assert_eq!(samples.status, CheckStatus::Passed);
+        assert_eq!(samples.detail, "2 cases passed");
+        // Slack is skipped: nothing needs approval
+        assert_eq!(report.check("slack").unwrap().status, CheckStatus::Skipped);
+        assert_eq!(
+            report.check("upstream:upstream").unwrap().status,
+            CheckStatus::Passed
+        );
+        assert_eq!(
+            *probes.probed.lock().unwrap(),
+            vec!["http://localhost:8080".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_self_test_fails_on_sample_mismatch() {
+        let samples = samples_file(
+            "fail",
+            r#"
+- name: deletes are allowed
+  principal: { app: agent }
+  resource: { tool: delete_ticket }
+  expect: permit
+"#,
+        );
+        let config = config(&format!("  samples: {}", samples.display()), "forward");
+
+        let result = run_with_policies(&config, &FakeProbes::healthy()).await;
+        std::fs::remove_file(&samples).ok();
+
+        let Err(SelfTestError::Failed { report }) = result else {
+            panic!("expected the self-test to fail");
+        };
+        let samples = report.check("samples").unwrap();
+        assert_eq!(samples.status, CheckStatus::Failed);
+        assert_eq!(samples.detail, "1 of 1 cases failed: deletes are allowed");
+        assert_eq!(
+            report.check("policies").unwrap().status,
+            CheckStatus::Passed
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_self_test_reports_invalid_policies() {
+        let config = config("  upstream: false", "forward");
+
+        unsafe {
+            std::env::set_var("THOUGHTGATE_POLICIES", "permit(principal, action");
+        }
+        let result = self_test_with(&config, &FakeProbes::healthy()).await;
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+
+        let Err(e) = result else {
+            panic!("expected the self-test to fail");
+        };
+        let SelfTestError::Failed { report } = &e;
+        assert_eq!(
+            report.check("policies").unwrap().status,
+            CheckStatus::Failed
+        );
+        assert!(
+            e.to_string()
+                .starts_with("Startup self-test failed: policies:")
+        );
+        assert_eq!(
+            report.check("upstream").unwrap().status,
+            CheckStatus::Skipped
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_self_test_warn_mode_reports_network_failures() {
+        let config = config("  on_failure: warn", "approve");
+        let probes = FakeProbes {
+            slack: Err("Invalid or missing token".to_string()),
+            unreachable: vec!["http://localhost:8080".to_string()],
+            probed: Mutex::new(Vec::new()),
+        };
+
+        let report = run_with_policies(&config, &probes).await.unwrap();
+
+        assert!(!report.passed());
+        let failed: Vec<_> = report.failures().map(|c| c.name.as_str()).collect();
+        assert_eq!(failed, vec!["slack", "upstream:upstream"]);
+        assert_eq!(
+            report.check("slack").unwrap().detail,
+            "Invalid or missing token"
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_self_test_checks_are_toggleable() {
+        let config = config(
+            "  policies: false\n  slack: false\n  upstream: false",
+            "approve",
+        );
+        let probes = FakeProbes::healthy();
+
+        let report = self_test_with(&config, &probes).await.unwrap();
+
+        assert!(
+            report
+                .checks
+                .iter()
+                .all(|check| check.status == CheckStatus::Skipped),
+            "{report}"
+        );
+        assert!(probes.probed.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_self_test_without_section_runs_nothing() {
+        let mut config = config("  slack: true", "forward");
+        config.self_test = None;
+
+        let report = self_test_with(&config, &FakeProbes::healthy())
+            .await
+            .unwrap();
+        assert!(report.checks.is_empty());
+    }
+}
@@ -0,0 +1,542 @@
+//! Recording of MCP request/response pairs for offline analysis.
+//!
+//! When enabled, a sample of the MCP exchanges the proxy answers is written
+//! as newline-delimited JSON [`TapRecord`]s: the request method, URI,
+//! headers and body, and the response status, headers and body. Records
+//! help with debugging and with building policy test corpora; the request
+//! half of each can be sent to the proxy again as recorded.
+//!
+//! Only exchanges whose bodies the proxy buffers are recorded. Streamed
+//! traffic (HTTP passthrough and `early_forward_methods`) is not.
+//!
+//! # Redaction
+//!
+//! Records are redacted like the logs: sensitive headers such as
+//! `Authorization` are replaced with `[REDACTED]`, and bodies go through
+//! the log [`Redactor`], so `THOUGHTGATE_LOG_REDACT_FIELDS` and
+//! `THOUGHTGATE_LOG_REDACT_PATTERNS` apply here too.
+//!
+//! # Hot Path
+//!
+//! Requests only queue the exchange on a bounded channel; redaction,
+//! serialization and writing happen on a dedicated thread. An exchange
+//! that finds the queue full is dropped and counted in
+//! `tap_records_dropped_total`.
+//!
+//! # Configuration
+//!
+//! - `THOUGHTGATE_TAP_LOG`: `stdout`, or a file path opened in append
+//!   mode. Unset disables the tap.
+//! - `THOUGHTGATE_TAP_SAMPLE_RATE`: fraction of exchanges recorded,
+//!   0.0-1.0 (default 1.0). Sampling follows the request ID, as for logs.
+//! - `THOUGHTGATE_TAP_QUEUE_SIZE`: exchanges waiting to be written before
+//!   new ones are dropped (default 1024).
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-001 NFR-001 (Observability)
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http::{HeaderMap, Method, Request, StatusCode, Uri};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tracing::{debug, error};
+
+use crate::logging_layer::{Redactor, Sampler, current_request_id, is_sensitive_header};
+
+/// Version of the [`TapRecord`] schema.
+pub const TAP_SCHEMA_VERSION: u32 = 1;
+
+/// Default for `THOUGHTGATE_TAP_QUEUE_SIZE`.
+const DEFAULT_QUEUE_SIZE: usize = 1024;
+
+/// Replacement for sensitive header values, as in the logs.
+const REDACTED_HEADER: &str = "[REDACTED]";
+
+/// Where and how much the tap records.
+///
+/// Implements: REQ-CORE-001 NFR-001 (Observability)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TapConfig {
+    /// `stdout`, or the path of the file records are appended to.
+    pub sink: String,
+    /// Fraction of exchanges recorded (0.0-1.0).
+    pub sample_rate: f64,
+    /// Exchanges waiting to be written before new ones are dropped.
+    pub queue_size: usize,
+}
+
+impl TapConfig {
+    /// Record every exchange to `sink`.
+    pub fn new(sink: impl Into<String>) -> Self {
+        Self {
+            sink: sink.into(),
+            sample_rate: 1.0,
+            queue_size: DEFAULT_QUEUE_SIZE,
+        }
+    }
+
+    /// Load from the environment; `None` unless `THOUGHTGATE_TAP_LOG` is set.
+    pub fn from_env() -> Option<Self> {
+        fn var<T: std::str::FromStr>(name: &str) -> Option<T> {
+            std::env::var(name).ok().and_then(|v| v.parse().ok())
+        }
+
+        let mut config = Self::new(
+            std::env::var("THOUGHTGATE_TAP_LOG")
+                .ok()
+                .filter(|s| !s.is_empty())?,
+        );
+        if let Some(rate) = var("THOUGHTGATE_TAP_SAMPLE_RATE") {
+            config.sample_rate = rate;
+        }
+        if let Some(size) = var::<usize>("THOUGHTGATE_TAP_QUEUE_SIZE").filter(|s| *s > 0) {
+            config.queue_size = size;
+        }
+        Some(config)
+    }
+}
+
+/// One recorded exchange (one line of the tap log).
+///
+/// Implements: REQ-CORE-001 NFR-001 (Observability)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TapRecord {
+    /// Schema version ([`TAP_SCHEMA_VERSION`]).
+    pub version: u32,
+    /// When the request was received.
+    pub timestamp: DateTime<Utc>,
+    /// Correlation ID of the request.
+    pub request_id: String,
+    /// Time from receiving the request to having the response.
+    pub duration_ms: u64,
+    /// The request as received.
+    pub request: TapRequest,
+    /// The response as sent.
+    pub response: TapResponse,
+}
+
+/// Request half of a [`TapRecord`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TapRequest {
+    /// HTTP method.
+    pub method: String,
+    /// Request URI as received.
+    pub uri: String,
+    /// Headers in arrival order; values that are not UTF-8 are left out.
+    pub headers: Vec<(String, String)>,
+    /// Request body.
+    pub body: TapBody,
+}
+
+/// Response half of a [`TapRecord`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TapResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Headers in order; values that are not UTF-8 are left out.
+    pub headers: Vec<(String, String)>,
+    /// Response body.
+    pub body: TapBody,
+}
+
+/// A recorded body.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TapBody {
+    /// A JSON body, as a document.
+    Json(serde_json::Value),
+    /// Any other body, as (lossy UTF-8) text.
+    Text(String),
+}
+
+/// An exchange waiting to be written, before redaction.
+struct Exchange {
+    timestamp: DateTime<Utc>,
+    request_id: String,
+    duration: Duration,
+    method: Method,
+    uri: Uri,
+    request_headers: HeaderMap,
+    request_body: Bytes,
+    status: StatusCode,
+    response_headers: HeaderMap,
+    response_body: Bytes,
+}
+
+impl Exchange {
+    fn into_record(self, redactor: &Redactor) -> TapRecord {
+        TapRecord {
+            version: TAP_SCHEMA_VERSION,
+            timestamp: self.timestamp,
+            request_id: self.request_id,
+            duration_ms: self.duration.as_millis() as u64,
+            request: TapRequest {
+                method: self.method.to_string(),
+                uri: self.uri.to_string(),
+                headers: record_headers(&self.request_headers, redactor),
+                body: record_body(&self.request_body, redactor),
+            },
+            response: TapResponse {
+                status: self.status.as_u16(),
+                headers: record_headers(&self.response_headers, redactor),
+                body: record_body(&self.response_body, redactor),
+            },
+        }
+    }
+}
+
+fn record_headers(headers: &HeaderMap, redactor: &Redactor) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = if is_sensitive_header(name.as_str()) {
+                REDACTED_HEADER.to_string()
+            } else {
+                redactor.redact(value.to_str().ok()?).into_owned()
+            };
+            Some((name.as_str().to_string(), value))
+        })
+        .collect()
+}
+
+fn record_body(body: &Bytes, redactor: &Redactor) -> TapBody {
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut json) => {
+            redactor.redact_json(&mut json);
+            TapBody::Json(json)
+        }
+        Err(_) => TapBody::Text(redactor.redact(&String::from_utf8_lossy(body)).into_owned()),
+    }
+}
+
+/// Records sampled exchanges without holding up the requests.
+///
+/// Implements: REQ-CORE-001 NFR-001 (Observability)
+pub struct Tap {
+    queue: mpsc::Sender<Exchange>,
+    sampler: Sampler,
+    dropped: AtomicU64,
+}
+
+impl std::fmt::Debug for Tap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tap").finish_non_exhaustive()
+    }
+}
+
+impl Tap {
+    /// Open the sink named by `config` and start writing to it, redacting
+    /// with the log redactor.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the file cannot be opened, a configured
+    /// redaction pattern is invalid, or the writer thread cannot start.
+    pub fn from_config(config: &TapConfig) -> std::io::Result<Self> {
+        let redactor = Redactor::from_env().map_err(std::io::Error::other)?;
+        match config.sink.as_str() {
+            "stdout" => Self::new(config, std::io::stdout(), redactor),
+            path => {
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(Path::new(path))?;
+                Self::new(config, file, redactor)
+            }
+        }
+    }
+
+    /// Write records to `writer` from a dedicated thread.
+    ///
+    /// The thread exits once the tap is dropped and its queue drained.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the writer thread cannot start.
+    pub fn new(
+        config: &TapConfig,
+        mut writer: impl Write + Send + 'static,
+        redactor: Redactor,
+    ) -> std::io::Result<Self> {
+        let (queue, mut exchanges) = mpsc::channel::<Exchange>(config.queue_size.max(1));
+        std::thread::Builder::new()
+            .name("thoughtgate-tap".to_string())
+            .spawn(move || {
+                while let Some(exchange) = exchanges.blocking_recv() {
+                    let record = exchange.into_record(&redactor);
+                    let mut line = match serde_json::to_vec(&record) {
+                        Ok(line) => line,
+                        Err(e) => {
+                            error!(error = %e, "Failed to serialize tap record");
+                            continue;
+                        }
+                    };
+                    line.push(b'\n');
+                    if let Err(e) = writer.write_all(&line).and_then(|()| writer.flush()) {
+                        error!(error = %e, "Failed to write tap record");
+                    }
+                }
+            })?;
+        Ok(Self {
+            queue,
+            sampler: Sampler::new(config.sample_rate),
+            dropped: AtomicU64::new(0),
+        })
+    }
+
+    /// Start recording `req`, if its request is sampled.
+    ///
+    /// The body is added with [`TapCapture::request_body`] once read.
+    pub fn capture<B>(self: &Arc<Self>, req: &Request<B>) -> Option<TapCapture> {
+        let request_id = current_request_id()
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        if !self.sampler.is_sampled(&request_id) {
+            return None;
+        }
+        Some(TapCapture {
+            tap: self.clone(),
+            timestamp: Utc::now(),
+            started: Instant::now(),
+            request_id,
+            method: req.method().clone(),
+            uri: req.uri().clone(),
+            headers: req.headers().clone(),
+            body: Bytes::new(),
+        })
+    }
+
+    /// Exchanges dropped because the queue was full.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    fn send(&self, exchange: Exchange) {
+        match self.queue.try_send(exchange) {
+            Ok(()) => {}
+            Err(TrySendError::Full(exchange) | TrySendError::Closed(exchange)) => {
+                // Closed only if the writer thread died; either way it is lost
+                debug!(request_id = %exchange.request_id, "Tap exchange dropped");
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                if let Some(metrics) = crate::metrics::get_tap_metrics() {
+                    metrics.record_dropped();
+                }
+            }
+        }
+    }
+}
+
+/// A request being recorded, finished with its response.
+///
+/// Implements: REQ-CORE-001 NFR-001 (Observability)
+pub struct TapCapture {
+    tap: Arc<Tap>,
+    timestamp: DateTime<Utc>,
+    started: Instant,
+    request_id: String,
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl TapCapture {
+    /// Set the request body.
+    pub fn request_body(&mut self, body: Bytes) {
+        self.body = body;
+    }
+
+    /// Queue the exchange with its response for writing.
+    pub fn finish(self, status: StatusCode, headers: &HeaderMap, body: Bytes) {
+        let exchange = Exchange {
+            timestamp: self.timestamp,
+            request_id: self.request_id,
+            duration: self.started.elapsed(),
+            method: self.method,
+            uri: self.uri,
+            request_headers: self.headers,
+            request_body: self.body,
+            status,
+            response_headers: headers.clone(),
+            response_body: body,
+        };
+        self.tap.send(exchange);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::sync::mpsc as std_mpsc;
+
+    /// Writer appending to a shared buffer.
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Captured {
+        /// Wait up to a few seconds for `count` records to be written.
+        fn records(&self, count: usize) -> Vec<TapRecord> {
+            for _ in 0..500 {
+                let text = String::from_utf8(self.0.lock().unwrap().clone()).unwrap();
+                let records: Vec<TapRecord> = text
+                    .lines()
+                    .map(|line| serde_json::from_str(line).unwrap())
+                    .collect();
+                if records.len() >= count {
+                    return records;
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            panic!("expected {count} tap records");
+        }
+    }
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Writer that reports each write and blocks until released.
+    struct Gated {
+        out: Captured,
+        entered: std_mpsc::Sender<()>,
+        release: std_mpsc::Receiver<()>,
+    }
+
+    impl Write for Gated {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let _ = self.entered.send(());
+            let _ = self.release.recv();
+            self.out.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn request() -> Request<()> {
+        Request::builder()
+            .method(Method::POST)
+            .uri("/mcp/v1")
+            .header("content-type", "application/json")
+            .header("authorization", "Bearer agent-token")
+            .body(())
+            .unwrap()
+    }
+
+    fn exchange(tap: &Arc<Tap>, id: u64) {
+        let mut capture = tap.capture(&request()).unwrap();
+        capture.request_body(Bytes::from(format!(
+            r#"{{"jsonrpc":"2.0","id":{id},"method":"tools/list"}}"#
+        )));
+        capture.finish(StatusCode::OK, &HeaderMap::new(), Bytes::from("{}"));
+    }
+
+    #[test]
+    fn test_record_redacts_headers_and_body() {
+        let out = Captured::default();
+        let redactor = Redactor::new(["password"], [r"sk-[a-z0-9]+"]).unwrap();
+        let tap = Arc::new(Tap::new(&TapConfig::new("test"), out.clone(), redactor).unwrap());
+
+        let mut capture = tap.capture(&request()).unwrap();
+        capture.request_body(Bytes::from_static(
+            br#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"login","arguments":{"password":"hunter2","key":"sk-abc1"}}}"#,
+        ));
+        let mut headers = HeaderMap::new();
+        headers.insert("content-type", "text/plain".parse().unwrap());
+        capture.finish(
+            StatusCode::BAD_GATEWAY,
+            &headers,
+            Bytes::from_static(b"upstream said sk-zz9"),
+        );
+
+        let record = out.records(1).remove(0);
+        assert_eq!(record.version, TAP_SCHEMA_VERSION);
+        assert_eq!(record.request.method, "POST");
+        assert_eq!(record.request.uri, "/mcp/v1");
+        assert_eq!(
+            record.request.headers,
+            [
+                ("content-type".to_string(), "application/json".to_string()),
+                ("authorization".to_string(), REDACTED_HEADER.to_string()),
+            ]
+        );
+        let TapBody::Json(body) = record.request.body else {
+            panic!("JSON request body recorded as text");
+        };
+        assert_eq!(body["params"]["arguments"]["password"], "***");
+        assert_eq!(body["params"]["arguments"]["key"], "***");
+        assert_eq!(body["params"]["name"], "login");
+        assert_eq!(record.response.status, 502);
+        assert_eq!(
+            record.response.body,
+            TapBody::Text("upstream said ***".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sampling_skips_requests() {
+        let config = TapConfig {
+            sample_rate: 0.0,
+            ..TapConfig::new("test")
+        };
+        let tap = Arc::new(Tap::new(&config, Captured::default(), Redactor::disabled()).unwrap());
+        assert!(tap.capture(&request()).is_none());
+    }
+
+    #[test]
+    fn test_full_queue_drops_and_counts() {
+        let out = Captured::default();
+        let (entered_tx, entered) = std_mpsc::channel();
+        let (release, release_rx) = std_mpsc::channel();
+        let writer = Gated {
+            out: out.clone(),
+            entered: entered_tx,
+            release: release_rx,
+        };
+        let config = TapConfig {
+            queue_size: 1,
+            ..TapConfig::new("test")
+        };
+        let tap = Arc::new(Tap::new(&config, writer, Redactor::disabled()).unwrap());
+
+        // The first exchange is being written, the second waits in the queue
+        exchange(&tap, 1);
+        entered.recv_timeout(Duration::from_secs(5)).unwrap();
+        exchange(&tap, 2);
+        assert_eq!(tap.dropped(), 0);
+
+        exchange(&tap, 3);
+        exchange(&tap, 4);
+        assert_eq!(tap.dropped(), 2);
+
+        for _ in 0..2 {
+            release.send(()).unwrap();
+        }
+        let ids: Vec<_> = out
+            .records(2)
+            .into_iter()
+            .map(|record| match record.request.body {
+                TapBody::Json(body) => body["id"].clone(),
+                TapBody::Text(text) => panic!("unexpected text body {text}"),
+            })
+            .collect();
+        assert_eq!(ids, [1, 2]);
+    }
+}
@@ -0,0 +1,1073 @@
+//! In-process harness for driving requests through the whole proxy.
+//!
+//! Wires a [`ProxyService`] with the full governance stack to a fake
+//! upstream and a fake Slack, so a test can send one request and assert
+//! which path it took, what reached upstream and what came back, without
+//! standing up anything outside the test.
+//!
+//! The path is inferred from what the fakes saw:
+//!
+//! | Path | Evidence |
+//! |------|----------|
+//! | Approval | A message was posted to the fake Slack |
+//! | Red | Nothing reached upstream and nothing was posted |
+//! | Amber | Upstream saw the buffered, governed call on [`GOVERNED_PREFIX`] |
+//! | Green | Upstream saw the request streamed on its original path |
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-001 (Green Path)
+//! - Implements: REQ-CORE-002 (Amber Path)
+//! - Implements: REQ-CORE-003 (MCP Transport & Routing)
+//! - Implements: REQ-GOV-003 (Approval Integration)
+
+use crate::config::Config;
+use crate::connection::PeerAddr;
+use crate::governance::approval::{
+    AdapterError, ApprovalAdapter, ApprovalReference, ApprovalRequest, PollResult,
+};
+use crate::governance::engine::{ApprovalEngine, ApprovalEngineConfig};
+use crate::governance::{ApprovalDecision, TaskStore};
+use crate::policy::Principal;
+use crate::policy::engine::CedarEngine;
+use crate::policy::principal::with_client_principal;
+use crate::proxy_config::ProxyConfig;
+use crate::proxy_service::ProxyService;
+use crate::transport::server::{McpHandler, McpHandlerConfig};
+use crate::transport::upstream::{UpstreamClient, UpstreamConfig, UpstreamForwarder};
+use async_trait::async_trait;
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use hyper::{HeaderMap, Method, Request, Response, StatusCode};
+use hyper_util::client::legacy::Client;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+/// Path prefix on the fake upstream that governed MCP calls are sent to.
+///
+/// Passthrough requests keep their own path, so this tells the two apart.
+pub(crate) const GOVERNED_PREFIX: &str = "/governed";
+
+/// Workflow that `approve` rules use.
+const WORKFLOW: &str = "harness";
+
+/// The path a request took through the proxy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Path {
+    /// Streamed to upstream unmodified
+    Green,
+    /// Buffered, governed and forwarded
+    Amber,
+    /// Held for approval in Slack
+    Approval,
+    /// Refused without contacting upstream
+    Red,
+}
+
+/// A request as received by the fake upstream.
+#[derive(Debug, Clone)]
+pub(crate) struct UpstreamRequest {
+    pub method: Method,
+    pub path: String,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+impl UpstreamRequest {
+    /// The body as JSON, for governed calls.
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).expect("upstream body is not JSON")
+    }
+}
+
+/// The result of sending one request through the harness.
+#[derive(Debug)]
+pub(crate) struct Exchange {
+    /// Path the request took
+    pub path: Path,
+    /// Response status
+    pub status: StatusCode,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Response body
+    pub body: Bytes,
+    /// Requests upstream received while handling this one
+    pub upstream: Vec<UpstreamRequest>,
+    /// Tools posted to Slack for approval while handling this one
+    pub approvals: Vec<String>,
+}
+
+impl Exchange {
+    /// The response body as JSON.
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).expect("response body is not JSON")
+    }
+}
+
+/// Builder for a [`Harness`].
+pub(crate) struct HarnessBuilder {
+    rules: Vec<(String, &'static str)>,
+    upstream_body: Bytes,
+    tool_result: serde_json::Value,
+    upstream_delay: Duration,
+    blocking_approvals: bool,
+    request_deadline: Option<Duration>,
+    max_in_flight: Option<usize>,
+    reject_headers: bool,
+}
+
+impl HarnessBuilder {
+    /// Add a governance rule: tools matching `pattern` get `action`
+    /// (`forward`, `deny` or `approve`). Unmatched tools are forwarded.
+    pub fn rule(mut self, pattern: &str, action: &'static str) -> Self {
+        self.rules.push((pattern.to_string(), action));
+        self
+    }
+
+    /// Body upstream returns for passthrough requests.
+    pub fn upstream_body(mut self, body: impl Into<Bytes>) -> Self {
+        self.upstream_body = body.into();
+        self
+    }
+
+    /// `result` upstream returns for governed JSON-RPC calls.
+    pub fn tool_result(mut self, result: serde_json::Value) -> Self {
+        self.tool_result = result;
+        self
+    }
+
+    /// How long upstream takes to answer each request.
+    pub fn upstream_delay(mut self, delay: Duration) -> Self {
+        self.upstream_delay = delay;
+        self
+    }
+
+    /// Hold approval-gated calls open until the decision (blocking mode)
+    /// instead of returning a task.
+    pub fn blocking_approvals(mut self) -> Self {
+        self.blocking_approvals = true;
+        self
+    }
+
+    /// Default deadline for requests that do not set their own.
+    pub fn request_deadline(mut self, deadline: Duration) -> Self {
+        self.request_deadline = Some(deadline);
+        self
+    }
+
+    /// Most requests the proxy handles at once; the rest are shed.
+    pub fn max_in_flight(mut self, max: usize) -> Self {
+        self.max_in_flight = Some(max);
+        self
+    }
+
+    /// Tag refused requests with reject decision headers.
+    pub fn reject_headers(mut self) -> Self {
+        self.reject_headers = true;
+        self
+    }
+
+    /// Start the fake upstream and the proxy in front of it.
+    pub async fn start(self) -> Harness {
+        let upstream =
+            FakeUpstream::spawn(self.upstream_body, self.tool_result, self.upstream_delay).await;
+        let slack = Arc::new(FakeSlack::default());
+        let shutdown = CancellationToken::new();
+
+        let governed_url = format!("http://{}{GOVERNED_PREFIX}", upstream.addr);
+        let forwarder: Arc<dyn UpstreamForwarder> = Arc::new(
+            UpstreamClient::new(UpstreamConfig::with_base_url(governed_url.clone()))
+                .expect("Failed to create upstream client"),
+        );
+        let config: Config = serde_saphyr::from_str(&config_yaml(
+            &governed_url,
+            &self.rules,
+            self.blocking_approvals,
+        ))
+        .expect("Invalid harness config");
+
+        let task_store = Arc::new(TaskStore::with_defaults());
+        let approvals = ApprovalEngine::new(
+            task_store.clone(),
+            slack.clone(),
+            forwarder.clone(),
+            ApprovalEngineConfig::default(),
+            shutdown.clone(),
+        )
+        .expect("Failed to create approval engine");
+        approvals.spawn_background_tasks();
+
+        let cedar = Arc::new(CedarEngine::new().expect("Failed to create Cedar engine"));
+        let handler = McpHandler::with_governance(
+            forwarder,
+            cedar.clone(),
+            task_store.clone(),
+            McpHandlerConfig::default(),
+            Some(Arc::new(config)),
+            Some(Arc::new(approvals)),
+        );
+        let service = ProxyService::new_with_config(
+            Some(format!("http://{}", upstream.addr)),
+            ProxyConfig {
+                request_deadline: self.request_deadline,
+                max_in_flight_requests: self.max_in_flight,
+                reject_headers: self.reject_headers,
+                ..ProxyConfig::default()
+            },
+        )
+        .expect("Failed to create proxy service")
+        .with_mcp_handler(Arc::new(handler));
+
+        let principal = Principal {
+            app_name: "harness-agent".to_string(),
+            namespace: "default".to_string(),
+            service_account: "default".to_string(),
+            roles: Vec::new(),
+        };
+        let proxy = serve_as(service, principal).await;
+
+        Harness {
+            proxy,
+            upstream,
+            slack,
+            task_store,
+            cedar,
+            shutdown,
+        }
+    }
+}
+
+/// A proxy wired to a fake upstream and a fake Slack.
+///
+/// Background tasks stop when the harness is dropped.
+pub(crate) struct Harness {
+    proxy: SocketAddr,
+    upstream: FakeUpstream,
+    slack: Arc<FakeSlack>,
+    task_store: Arc<TaskStore>,
+    cedar: Arc<CedarEngine>,
+    shutdown: CancellationToken,
+}
+
+impl Harness {
+    /// Start configuring a harness.
+    pub fn builder() -> HarnessBuilder {
+        HarnessBuilder {
+            rules: Vec::new(),
+            upstream_body: Bytes::from_static(b"upstream ok"),
+            tool_result: serde_json::json!({
+                "content": [{"type": "text", "text": "ok"}]
+            }),
+            upstream_delay: Duration::ZERO,
+            blocking_approvals: false,
+            request_deadline: None,
+            max_in_flight: None,
+            reject_headers: false,
+        }
+    }
+
+    /// Send `request` to the proxy; only its path and query are used from
+    /// the URI.
+    pub async fn send(&self, request: Request<Full<Bytes>>) -> Exchange {
+        let seen_upstream = self.upstream.requests.lock().unwrap().len();
+        let seen_approvals = self.slack.posted.lock().unwrap().len();
+
+        let client = Client::builder(TokioExecutor::new()).build_http::<Full<Bytes>>();
+        let response = client
+            .request(self.to_proxy(request))
+            .await
+            .expect("proxy request failed");
+        let (parts, body) = response.into_parts();
+        let body = body.collect().await.unwrap().to_bytes();
+
+        let upstream = self.upstream.requests.lock().unwrap()[seen_upstream..].to_vec();
+        let approvals = self.slack.posted.lock().unwrap()[seen_approvals..].to_vec();
+        let path = if !approvals.is_empty() {
+            Path::Approval
+        } else if upstream.is_empty() {
+            Path::Red
+        } else if upstream.iter().any(|r| r.path.starts_with(GOVERNED_PREFIX)) {
+            Path::Amber
+        } else {
+            Path::Green
+        };
+
+        Exchange {
+            path,
+            status: parts.status,
+            headers: parts.headers,
+            body,
+            upstream,
+            approvals,
+        }
+    }
+
+    /// Send `request`, then disconnect if no response has arrived `after`
+    /// it was sent.
+    ///
+    /// Panics if the response arrives first.
+    pub async fn send_and_disconnect(&self, request: Request<Full<Bytes>>, after: Duration) {
+        let client = Client::builder(TokioExecutor::new()).build_http::<Full<Bytes>>();
+        let response = tokio::time::timeout(after, client.request(self.to_proxy(request))).await;
+        assert!(response.is_err(), "response arrived before disconnecting");
+    }
+
+    /// Requests upstream stopped handling because the proxy went away.
+    pub fn upstream_cancelled(&self) -> usize {
+        self.upstream.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Tasks still waiting for an approval decision.
+    pub fn pending_approvals(&self) -> usize {
+        self.task_store.pending_count()
+    }
+
+    /// Approval requests withdrawn from Slack.
+    pub fn withdrawn_approvals(&self) -> usize {
+        self.slack.withdrawn.load(Ordering::SeqCst)
+    }
+
+    /// Approve the pending task `task_id`, as if an approver had.
+    pub fn approve(&self, task_id: &str) {
+        let task_id = task_id.parse().expect("invalid task ID");
+        self.task_store
+            .record_approval(
+                &task_id,
+                ApprovalDecision::Approved,
+                "harness-approver".to_string(),
+                Duration::from_secs(60),
+            )
+            .expect("Failed to approve task");
+    }
+
+    /// Reload Cedar policies from the environment.
+    pub fn reload_policies(&self) {
+        self.cedar.reload().expect("Failed to reload policies");
+    }
+
+    /// Point `request` at the proxy, keeping its path and query.
+    fn to_proxy(&self, request: Request<Full<Bytes>>) -> Request<Full<Bytes>> {
+        let (mut parts, body) = request.into_parts();
+        let path_and_query = parts
+            .uri
+            .path_and_query()
+            .map_or("/", |p| p.as_str())
+            .to_string();
+        parts.uri = format!("http://{}{path_and_query}", self.proxy)
+            .parse()
+            .unwrap();
+        Request::from_parts(parts, body)
+    }
+
+    /// Send an MCP `tools/call` for `tool` with `arguments`.
+    pub async fn call_tool(&self, tool: &str, arguments: serde_json::Value) -> Exchange {
+        self.post_mcp(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {"name": tool, "arguments": arguments}
+        }))
+        .await
+    }
+
+    /// Send an MCP `tools/call` as a task (SEP-1686), as tools needing
+    /// approval require.
+    pub async fn call_tool_as_task(&self, tool: &str, arguments: serde_json::Value) -> Exchange {
+        self.post_mcp(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {"name": tool, "arguments": arguments, "task": {"ttl": 600_000}}
+        }))
+        .await
+    }
+
+    /// Send a JSON-RPC `body` to the MCP endpoint.
+    pub async fn post_mcp(&self, body: serde_json::Value) -> Exchange {
+        self.send(mcp_request(&body)).await
+    }
+}
+
+impl Drop for Harness {
+    fn drop(&mut self) {
+        self.shutdown.cancel();
+    }
+}
+
+/// A POST of JSON-RPC `body` to the MCP endpoint.
+pub(crate) fn mcp_request(body: &serde_json::Value) -> Request<Full<Bytes>> {
+    Request::builder()
+        .method(Method::POST)
+        .uri("/mcp/v1")
+        .header("content-type", "application/json")
+        .body(Full::new(Bytes::from(serde_json::to_vec(body).unwrap())))
+        .unwrap()
+}
+
+/// Governance config forwarding to `url`, with `rules` in order.
+fn config_yaml(url: &str, rules: &[(String, &'static str)], blocking: bool) -> String {
+    let mut yaml = format!(
+        "schema: 1\n\
+         sources:\n  - id: upstream\n    kind: mcp\n    url: {url}\n\
+         governance:\n  defaults:\n    action: forward\n  rules:\n"
+    );
+    if rules.is_empty() {
+        yaml.push_str("    []\n");
+    }
+    for (pattern, action) in rules {
+        yaml.push_str(&format!(
+            "    - match: \"{pattern}\"\n      action: {action}\n"
+        ));
+        if *action == "approve" {
+            yaml.push_str(&format!("      approval: {WORKFLOW}\n"));
+        }
+    }
+    yaml.push_str(&format!(
+        "approval:\n  {WORKFLOW}:\n    destination:\n      type: slack\n      channel: '#harness'\n    timeout: 5m\n    on_timeout: deny\n"
+    ));
+    if blocking {
+        yaml.push_str("    mode: blocking\n");
+    }
+    yaml
+}
+
+/// Serve `service` on a local port, with upgrades, answering handler
+/// errors with their error response.
+pub(crate) async fn serve(service: ProxyService) -> SocketAddr {
+    listen(service, None).await
+}
+
+/// [`serve`], identifying every client as `principal`.
+pub(crate) async fn serve_as(service: ProxyService, principal: Principal) -> SocketAddr {
+    listen(service, Some(principal)).await
+}
+
+/// Accept connections for `service` until the test's runtime stops.
+async fn listen(service: ProxyService, principal: Option<Principal>) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((stream, peer)) = listener.accept().await {
+            let service = service.clone();
+            let principal = principal.clone();
+            tokio::spawn(async move {
+                let svc_fn =
+                    hyper::service::service_fn(move |mut req: Request<hyper::body::Incoming>| {
+                        // As the real listener does, for X-Forwarded-For
+                        req.extensions_mut().insert(PeerAddr(peer));
+                        let service = service.clone();
+                        let principal = principal.clone();
+                        async move {
+                            let handled = match principal {
+                                Some(principal) => {
+                                    with_client_principal(principal, service.handle_request(req))
+                                        .await
+                                }
+                                None => service.handle_request(req).await,
+                            };
+                            let res = match handled {
+                                Ok(res) => res,
+                                Err(e) => e
+                                    .to_response()
+                                    .map(|body| body.map_err(|e| match e {}).boxed()),
+                            };
+                            Ok::<_, Infallible>(res)
+                        }
+                    });
+                let _ = auto::Builder::new(TokioExecutor::new())
+                    .serve_connection_with_upgrades(TokioIo::new(stream), svc_fn)
+                    .await;
+            });
+        }
+    });
+    addr
+}
+
+/// An HTTP upstream that records every request.
+///
+/// Governed calls (under [`GOVERNED_PREFIX`]) get a JSON-RPC success echoing
+/// the request ID; anything else gets the configured body. A request whose
+/// connection closes while upstream is still delaying counts as cancelled.
+struct FakeUpstream {
+    addr: SocketAddr,
+    requests: Arc<Mutex<Vec<UpstreamRequest>>>,
+    cancelled: Arc<AtomicUsize>,
+}
+
+impl FakeUpstream {
+    async fn spawn(body: Bytes, tool_result: serde_json::Value, delay: Duration) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        let cancelled = Arc::new(AtomicUsize::new(0));
+        let cancellations = cancelled.clone();
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let recorded = recorded.clone();
+                let cancellations = cancellations.clone();
+                let body = body.clone();
+                let tool_result = tool_result.clone();
+                tokio::spawn(async move {
+                    let svc_fn =
+                        hyper::service::service_fn(move |req: Request<hyper::body::Incoming>| {
+                            let recorded = recorded.clone();
+                            let cancellations = cancellations.clone();
+                            let body = body.clone();
+                            let tool_result = tool_result.clone();
+                            async move {
+                                let (parts, incoming) = req.into_parts();
+                                let received = incoming.collect().await?.to_bytes();
+                                let delaying = CountIfDropped(Some(cancellations));
+                                tokio::time::sleep(delay).await;
+                                delaying.disarm();
+                                let request = UpstreamRequest {
+                                    method: parts.method,
+                                    path: parts.uri.path().to_string(),
+                                    headers: parts.headers,
+                                    body: received,
+                                };
+                                let reply = if request.path.starts_with(GOVERNED_PREFIX) {
+                                    let id = request.json()["id"].clone();
+                                    Bytes::from(
+                                        serde_json::to_vec(&serde_json::json!({
+                                            "jsonrpc": "2.0",
+                                            "id": id,
+                                            "result": tool_result,
+                                        }))
+                                        .unwrap(),
+                                    )
+                                } else {
+                                    body
+                                };
+                                recorded.lock().unwrap().push(request);
+                                Ok::<_, hyper::Error>(Response::new(Full::new(reply)))
+                            }
+                        });
+                    let _ = auto::Builder::new(TokioExecutor::new())
+                        .serve_connection(TokioIo::new(stream), svc_fn)
+                        .await;
+                });
+            }
+        });
+
+        Self {
+            addr,
+            requests,
+            cancelled,
+        }
+    }
+}
+
+/// Increments its counter if dropped before being disarmed.
+struct CountIfDropped(Option<Arc<AtomicUsize>>);
+
+impl CountIfDropped {
+    fn disarm(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for CountIfDropped {
+    fn drop(&mut self) {
+        if let Some(count) = self.0.take() {
+            count.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+/// An approval adapter standing in for Slack.
+///
+/// Records the tool of every approval posted and counts withdrawn ones.
+/// Nobody answers, so approvals stay pending.
+#[derive(Default)]
+struct FakeSlack {
+    posted: Mutex<Vec<String>>,
+    withdrawn: AtomicUsize,
+}
+
+#[async_trait]
+impl ApprovalAdapter for FakeSlack {
+    async fn post_approval_request(
+        &self,
+        request: &ApprovalRequest,
+    ) -> Result<ApprovalReference, AdapterError> {
+        self.posted.lock().unwrap().push(request.tool_name.clone());
+        Ok(ApprovalReference {
+            task_id: request.task_id.clone(),
+            external_id: format!("harness-{}", request.task_id),
+            channel: "#harness".to_string(),
+            posted_at: chrono::Utc::now(),
+            next_poll_at: std::time::Instant::now(),
+            poll_count: 0,
+        })
+    }
+
+    async fn poll_for_decision(
+        &self,
+        _reference: &ApprovalReference,
+    ) -> Result<Option<PollResult>, AdapterError> {
+        Ok(None)
+    }
+
+    async fn cancel_approval(&self, _reference: &ApprovalReference) -> Result<(), AdapterError> {
+        self.withdrawn.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "harness"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    /// A plain HTTP request is streamed to upstream unchanged.
+    ///
+    /// Verifies: REQ-CORE-001 (Green Path)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_green_path() {
+        let harness = Harness::builder().upstream_body("hello").start().await;
+
+        let request = Request::builder()
+            .uri("/v1/models?limit=1")
+            .header("x-client", "harness")
+            .body(Full::new(Bytes::new()))
+            .unwrap();
+        let exchange = harness.send(request).await;
+
+        assert_eq!(exchange.path, Path::Green);
+        assert_eq!(exchange.status, StatusCode::OK);
+        assert_eq!(exchange.body, "hello");
+        assert_eq!(exchange.upstream.len(), 1);
+        assert_eq!(exchange.upstream[0].method, Method::GET);
+        assert_eq!(exchange.upstream[0].path, "/v1/models");
+        assert_eq!(exchange.upstream[0].headers["x-client"], "harness");
+    }
+
+    /// A permitted tool call is buffered, governed and forwarded.
+    ///
+    /// Verifies: REQ-CORE-002 (Amber Path), REQ-CORE-003 (MCP Transport)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_amber_path() {
+        let harness = Harness::builder()
+            .tool_result(serde_json::json!({"content": [{"type": "text", "text": "42"}]}))
+            .start()
+            .await;
+
+        let exchange = harness
+            .call_tool("read_file", serde_json::json!({"path": "/tmp/a"}))
+            .await;
+
+        assert_eq!(exchange.path, Path::Amber);
+        assert_eq!(exchange.status, StatusCode::OK);
+        assert_eq!(exchange.headers["content-type"], "application/json");
+        let forwarded = exchange.upstream[0].json();
+        assert_eq!(forwarded["method"], "tools/call");
+        assert_eq!(forwarded["params"]["name"], "read_file");
+        assert_eq!(forwarded["params"]["arguments"]["path"], "/tmp/a");
+        assert_eq!(exchange.json()["result"]["content"][0]["text"], "42");
+    }
+
+    /// A tool call needing approval is posted to Slack and not forwarded.
+    ///
+    /// Verifies: REQ-GOV-003 (Approval Integration)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_approval_path() {
+        let harness = Harness::builder().rule("deploy_*", "approve").start().await;
+
+        let exchange = harness
+            .call_tool_as_task("deploy_prod", serde_json::json!({}))
+            .await;
+
+        assert_eq!(exchange.path, Path::Approval);
+        assert_eq!(exchange.approvals, vec!["deploy_prod".to_string()]);
+        assert!(exchange.upstream.is_empty());
+        assert!(exchange.json()["result"]["taskId"].is_string());
+    }
+
+    /// Send `deploy_prod` with a custom header and approve its task.
+    async fn approved_deploy(harness: &Harness) -> String {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/mcp/v1")
+            .header("content-type", "application/json")
+            .header("x-agent-trace", "trace-42")
+            .body(Full::new(Bytes::from(
+                serde_json::to_vec(&serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "method": "tools/call",
+                    "params": {
+                        "name": "deploy_prod",
+                        "arguments": {"version": "1.2.3"},
+                        "task": {"ttl": 600_000}
+                    }
+                }))
+                .unwrap(),
+            )))
+            .unwrap();
+        let exchange = harness.send(request).await;
+        assert_eq!(exchange.path, Path::Approval);
+        let task_id = exchange.json()["result"]["taskId"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        harness.approve(&task_id);
+        task_id
+    }
+
+    fn task_result(task_id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tasks/result",
+            "params": {"taskId": task_id}
+        })
+    }
+
+    /// An approved task's request is replayed with its body and headers.
+    ///
+    /// Verifies: REQ-GOV-002/F-006 (Execution of approved requests)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_approved_task_replayed() {
+        let harness = Harness::builder().rule("deploy_*", "approve").start().await;
+        let task_id = approved_deploy(&harness).await;
+
+        let exchange = harness.post_mcp(task_result(&task_id)).await;
+
+        assert_eq!(exchange.status, StatusCode::OK);
+        assert_eq!(exchange.json()["id"], 2);
+        assert_eq!(
+            exchange.json()["result"]["content"]["content"][0]["text"],
+            "ok"
+        );
+        assert_eq!(exchange.upstream.len(), 1);
+        let replayed = &exchange.upstream[0];
+        assert_eq!(replayed.path, format!("{GOVERNED_PREFIX}/mcp/v1"));
+        assert_eq!(replayed.headers["x-agent-trace"], "trace-42");
+        let body = replayed.json();
+        assert_eq!(body["id"], 1);
+        assert_eq!(body["params"]["name"], "deploy_prod");
+        assert_eq!(body["params"]["arguments"]["version"], "1.2.3");
+
+        // Replayed once: the result is cached from then on
+        let again = harness.post_mcp(task_result(&task_id)).await;
+        assert_eq!(again.status, StatusCode::OK);
+        assert!(again.upstream.is_empty());
+    }
+
+    /// An approved task is not replayed once policy no longer permits it.
+    ///
+    /// Verifies: REQ-GOV-002/F-004 (Policy re-evaluation)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_replay_refused_after_policy_denies() {
+        let harness = Harness::builder().rule("deploy_*", "approve").start().await;
+        let task_id = approved_deploy(&harness).await;
+
+        // Only the v0.2 actions remain permitted: the approval grant no longer
+        // satisfies any policy
+        unsafe {
+            std::env::set_var(
+                "THOUGHTGATE_POLICIES",
+                r#"permit(principal, action == ThoughtGate::Action::"tools/call", resource);"#,
+            );
+        }
+        harness.reload_policies();
+        let exchange = harness.post_mcp(task_result(&task_id)).await;
+        unsafe { std::env::remove_var("THOUGHTGATE_POLICIES") };
+
+        assert!(exchange.upstream.is_empty());
+        assert_eq!(
+            exchange.json()["error"]["code"],
+            -32003,
+            "{}",
+            exchange.json()
+        );
+        let status = harness
+            .task_store
+            .get(&task_id.parse().unwrap())
+            .unwrap()
+            .status;
+        assert_eq!(status, crate::governance::TaskStatus::Failed);
+    }
+
+    /// A governed call's latency is recorded as governance overhead and
+    /// upstream time, which never add up to more than the total.
+    ///
+    /// Verifies: REQ-CORE-001 NFR-001 (Observability)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_latency_breakdown() {
+        use crate::metrics::testing::histogram;
+        let sample = || {
+            [
+                histogram("thoughtgate_request_duration_seconds"),
+                histogram("thoughtgate_governance_overhead_seconds"),
+                histogram("thoughtgate_upstream_seconds"),
+            ]
+        };
+        let harness = Harness::builder()
+            .upstream_delay(Duration::from_millis(50))
+            .start()
+            .await;
+
+        let before = sample();
+        let exchange = harness.call_tool("read_user", serde_json::json!({})).await;
+        let after = sample();
+
+        assert_eq!(exchange.path, Path::Amber);
+        let [total, governance, upstream] =
+            [0, 1, 2].map(|i| (after[i].0 - before[i].0, after[i].1 - before[i].1));
+        assert!(total.0 >= 1 && governance.0 >= 1 && upstream.0 >= 1);
+        assert!(upstream.1 >= 0.05, "upstream time {}", upstream.1);
+        assert!(governance.1 > 0.0);
+        assert!(
+            governance.1 + upstream.1 <= total.1,
+            "governance {} + upstream {} > total {}",
+            governance.1,
+            upstream.1,
+            total.1
+        );
+    }
+
+    /// A denied tool call is refused without contacting upstream.
+    ///
+    /// Verifies: REQ-CORE-003 (4-Gate Decision Flow)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_red_path() {
+        let harness = Harness::builder().rule("delete_*", "deny").start().await;
+
+        let exchange = harness
+            .call_tool("delete_user", serde_json::json!({}))
+            .await;
+
+        assert_eq!(exchange.path, Path::Red);
+        assert!(exchange.upstream.is_empty());
+        assert!(exchange.json()["error"]["code"].is_i64());
+    }
+
+    /// A refused call carries the reject decision headers, and they name
+    /// the gate without leaking the matched rule.
+    ///
+    /// Verifies: REQ-CORE-004 (Error Handling)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_reject_headers() {
+        let harness = Harness::builder()
+            .rule("delete_*", "deny")
+            .reject_headers()
+            .start()
+            .await;
+
+        let exchange = harness
+            .call_tool("delete_user", serde_json::json!({}))
+            .await;
+
+        assert_eq!(exchange.path, Path::Red);
+        assert_eq!(exchange.headers["thoughtgate-decision"], "reject");
+        assert_eq!(
+            exchange.headers["thoughtgate-reject-code"],
+            "governance_rule_denied"
+        );
+        for value in exchange.headers.values() {
+            assert!(!value.to_str().unwrap_or_default().contains("delete_*"));
+        }
+
+        // Forwarded calls are not tagged
+        let allowed = harness.call_tool("read_user", serde_json::json!({})).await;
+        assert!(allowed.headers.get("thoughtgate-reject-code").is_none());
+    }
+
+    /// Without `reject_headers`, refusals carry no decision headers.
+    ///
+    /// Verifies: REQ-CORE-004 (Error Handling)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_reject_headers_off_by_default() {
+        let harness = Harness::builder().rule("delete_*", "deny").start().await;
+
+        let exchange = harness
+            .call_tool("delete_user", serde_json::json!({}))
+            .await;
+
+        assert_eq!(exchange.path, Path::Red);
+        assert!(exchange.headers.get("thoughtgate-decision").is_none());
+        assert!(exchange.headers.get("thoughtgate-reject-code").is_none());
+    }
+
+    /// Wait up to two seconds for `done`.
+    async fn eventually(done: impl Fn() -> bool) -> bool {
+        for _ in 0..40 {
+            if done() {
+                return true;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        done()
+    }
+
+    /// A client that disconnects mid-request cancels the upstream request.
+    ///
+    /// Verifies: REQ-CORE-005 (Operational Lifecycle)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_disconnect_cancels_upstream() {
+        let harness = Harness::builder()
+            .upstream_delay(Duration::from_secs(30))
+            .start()
+            .await;
+
+        let request = Request::builder()
+            .uri("/slow")
+            .body(Full::new(Bytes::new()))
+            .unwrap();
+        harness
+            .send_and_disconnect(request, Duration::from_millis(300))
+            .await;
+
+        assert!(eventually(|| harness.upstream_cancelled() == 1).await);
+    }
+
+    /// A client that disconnects during a blocking approval abandons the
+    /// task and withdraws the approval request.
+    ///
+    /// Verifies: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_disconnect_abandons_blocking_approval() {
+        let harness = Harness::builder()
+            .rule("deploy_*", "approve")
+            .blocking_approvals()
+            .start()
+            .await;
+
+        let request = mcp_request(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {"name": "deploy_prod", "arguments": {}, "task": {"ttl": 600_000}}
+        }));
+        harness
+            .send_and_disconnect(request, Duration::from_millis(300))
+            .await;
+
+        assert!(eventually(|| harness.withdrawn_approvals() == 1).await);
+        assert_eq!(harness.pending_approvals(), 0);
+        assert_eq!(harness.upstream_cancelled(), 0);
+    }
+
+    /// A short `X-Deadline` ends a blocking approval wait long before the
+    /// configured default would, abandoning the task.
+    ///
+    /// Verifies: REQ-CORE-001 F-005 (Timeout Handling)
+    /// Verifies: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_inbound_deadline_preempts_approval_wait() {
+        let harness = Harness::builder()
+            .rule("deploy_*", "approve")
+            .blocking_approvals()
+            .request_deadline(Duration::from_secs(30))
+            .start()
+            .await;
+
+        let mut request = mcp_request(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {"name": "deploy_prod", "arguments": {}, "task": {"ttl": 600_000}}
+        }));
+        request
+            .headers_mut()
+            .insert("x-deadline", "300ms".parse().unwrap());
+        let started = std::time::Instant::now();
+        let exchange = harness.send(request).await;
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(exchange.path, Path::Approval);
+        let error = &exchange.json()["error"];
+        assert_eq!(error["code"], -32001);
+        assert_eq!(error["data"]["error_type"], "deadline_exceeded");
+        assert!(eventually(|| harness.withdrawn_approvals() == 1).await);
+        assert_eq!(harness.pending_approvals(), 0);
+        assert!(exchange.upstream.is_empty());
+    }
+
+    /// The configured default deadline bounds a request without one.
+    ///
+    /// Verifies: REQ-CORE-001 F-005 (Timeout Handling)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_default_deadline_bounds_upstream() {
+        let harness = Harness::builder()
+            .upstream_delay(Duration::from_secs(30))
+            .request_deadline(Duration::from_secs(1))
+            .start()
+            .await;
+
+        let request = Request::builder()
+            .uri("/slow")
+            .body(Full::new(Bytes::new()))
+            .unwrap();
+        let exchange = harness.send(request).await;
+
+        assert_eq!(exchange.status, StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(exchange.json()["error"]["code"], -32001);
+        assert!(eventually(|| harness.upstream_cancelled() == 1).await);
+    }
+
+    /// Requests beyond the in-flight limit are shed with 503 and
+    /// `Retry-After`, while the admitted ones complete normally.
+    ///
+    /// Verifies: REQ-CORE-001 Section 3.2 (Concurrency Limit)
+    #[tokio::test]
+    #[serial]
+    async fn test_harness_sheds_requests_over_in_flight_limit() {
+        let harness = Harness::builder()
+            .upstream_delay(Duration::from_millis(500))
+            .max_in_flight(2)
+            .start()
+            .await;
+        let slow = || {
+            Request::builder()
+                .uri("/slow")
+                .body(Full::new(Bytes::new()))
+                .unwrap()
+        };
+
+        let exchanges = futures_util::future::join_all((0..5).map(|_| harness.send(slow()))).await;
+
+        let (served, shed): (Vec<_>, Vec<_>) =
+            exchanges.iter().partition(|e| e.status == StatusCode::OK);
+        assert_eq!(served.len(), 2);
+        assert_eq!(shed.len(), 3);
+        for exchange in shed {
+            assert_eq!(exchange.status, StatusCode::SERVICE_UNAVAILABLE);
+            assert_eq!(exchange.headers[hyper::header::RETRY_AFTER], "1");
+            assert_eq!(exchange.json()["error"]["code"], -32013);
+        }
+        for exchange in served {
+            assert_eq!(exchange.body, "upstream ok");
+        }
+
+        // Permits come back once the admitted responses are done
+        assert_eq!(harness.send(slow()).await.status, StatusCode::OK);
+    }
+}
@@ -3,13 +3,14 @@
 //! # Traceability
 //! - Implements: REQ-CORE-001 F-005 (Timeout Handling)
 
+use crate::clock::{Clock, Sleep, SystemClock};
 use bytes::Bytes;
 use http_body::{Body, Frame};
-use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::time::Duration;
-use tokio::time::{Sleep, sleep};
+use tokio::time::Instant;
 
 /// Timeout configuration for streaming bodies.
 ///
@@ -21,6 +22,9 @@ pub struct TimeoutConfig {
     pub chunk_timeout: Duration,
     /// Total timeout for the entire stream
     pub total_timeout: Duration,
+    /// The request's overall deadline, which ends the stream if it falls
+    /// before `total_timeout` runs out
+    pub deadline: Option<Instant>,
 }
 
 impl TimeoutConfig {
@@ -29,8 +33,16 @@ impl TimeoutConfig {
         Self {
             chunk_timeout,
             total_timeout,
+            deadline: None,
         }
     }
+
+    /// Bound the stream by the request's deadline as well, so it only gets
+    /// the budget the earlier phases left.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
 }
 
 /// Wrapper that adds timeout enforcement to a body stream.
@@ -38,15 +50,24 @@ impl TimeoutConfig {
 /// This wrapper ensures that:
 /// - Each chunk read/write completes within `chunk_timeout`
 /// - The total stream duration doesn't exceed `total_timeout`
+/// - The stream ends by the request's deadline, if one is set
 ///
 /// # Traceability
 /// - Implements: REQ-CORE-001 F-005 (Timeout Handling)
 pub struct TimeoutBody<B> {
     inner: B,
     config: TimeoutConfig,
-    chunk_timeout: Pin<Box<Sleep>>,
-    total_timeout: Pin<Box<Sleep>>,
-    started: bool,
+    clock: Arc<dyn Clock>,
+    /// Both timers, started on first poll
+    timers: Option<Timers>,
+    /// Whether the deadline, rather than `total_timeout`, ends the stream
+    deadline_bound: bool,
+}
+
+/// The running chunk and total timeouts of a [`TimeoutBody`].
+struct Timers {
+    chunk: Sleep,
+    total: Sleep,
 }
 
 impl<B> TimeoutBody<B> {
@@ -54,13 +75,19 @@ impl<B> TimeoutBody<B> {
     pub fn new(inner: B, config: TimeoutConfig) -> Self {
         Self {
             inner,
-            config: config.clone(),
-            chunk_timeout: Box::pin(sleep(config.chunk_timeout)),
-            total_timeout: Box::pin(sleep(config.total_timeout)),
-            started: false,
+            config,
+            clock: SystemClock::shared(),
+            timers: None,
+            deadline_bound: false,
         }
     }
 
+    /// Time the stream on `clock` instead of the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Get a reference to the timeout configuration.
     pub fn config(&self) -> &TimeoutConfig {
         &self.config
@@ -82,16 +109,32 @@ where
         let this = &mut *self;
 
         // Start total timeout and chunk timeout on first poll
-        if !this.started {
-            this.started = true;
-            let total_deadline = tokio::time::Instant::now() + this.config.total_timeout;
-            this.total_timeout.as_mut().reset(total_deadline);
-            let chunk_deadline = tokio::time::Instant::now() + this.config.chunk_timeout;
-            this.chunk_timeout.as_mut().reset(chunk_deadline);
-        }
+        let timers = this.timers.get_or_insert_with(|| {
+            let mut total = this.config.total_timeout;
+            if let Some(deadline) = this.config.deadline {
+                let left = deadline
+                    .into_std()
+                    .saturating_duration_since(this.clock.instant());
+                if left < total {
+                    total = left;
+                    this.deadline_bound = true;
+                }
+            }
+            Timers {
+                chunk: this.clock.sleep(this.config.chunk_timeout),
+                total: this.clock.sleep(total),
+            }
+        });
 
         // Check total timeout first
-        if this.total_timeout.as_mut().poll(cx).is_ready() {
+        if timers.total.as_mut().poll(cx).is_ready() {
+            if this.deadline_bound {
+                return Poll::Ready(Some(Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "Request deadline exceeded",
+                )
+                .into())));
+            }
             let timeout_duration = this.config.total_timeout;
             return Poll::Ready(Some(Err(std::io::Error::new(
                 std::io::ErrorKind::TimedOut,
@@ -101,7 +144,7 @@ where
         }
 
         // Check chunk timeout BEFORE polling inner body
-        if this.chunk_timeout.as_mut().poll(cx).is_ready() {
+        if timers.chunk.as_mut().poll(cx).is_ready() {
             let timeout_duration = this.config.chunk_timeout;
             return Poll::Ready(Some(Err(std::io::Error::new(
                 std::io::ErrorKind::TimedOut,
@@ -114,8 +157,7 @@ where
         match Pin::new(&mut this.inner).poll_frame(cx) {
             Poll::Ready(result) => {
                 // Reset chunk timeout for next chunk
-                let chunk_deadline = tokio::time::Instant::now() + this.config.chunk_timeout;
-                this.chunk_timeout.as_mut().reset(chunk_deadline);
+                timers.chunk = this.clock.sleep(this.config.chunk_timeout);
                 Poll::Ready(result.map(|r| r.map_err(|e| e.into())))
             }
             Poll::Pending => {
@@ -287,4 +329,88 @@ mod tests {
             err_msg
         );
     }
+
+    /// A body that never yields a frame.
+    struct PendingBody;
+
+    impl Body for PendingBody {
+        type Data = Bytes;
+        type Error = std::io::Error;
+
+        fn poll_frame(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+            Poll::Pending
+        }
+    }
+
+    /// A body that yields a chunk whenever one is sent to it.
+    struct ChannelBody(tokio::sync::mpsc::UnboundedReceiver<Bytes>);
+
+    impl Body for ChannelBody {
+        type Data = Bytes;
+        type Error = std::io::Error;
+
+        fn poll_frame(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+            self.0
+                .poll_recv(cx)
+                .map(|chunk| chunk.map(|data| Ok(Frame::data(data))))
+        }
+    }
+
+    /// Each chunk restarts the chunk timeout; the total timeout still ends
+    /// a stream that keeps trickling.
+    #[tokio::test]
+    async fn test_timeouts_follow_clock() {
+        use crate::clock::MockClock;
+
+        let clock = MockClock::new();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let config = TimeoutConfig::new(Duration::from_secs(1), Duration::from_secs(3));
+        let mut body =
+            TimeoutBody::new(ChannelBody(rx), config).with_clock(Arc::new(clock.clone()));
+
+        assert!(futures::poll!(body.frame()).is_pending());
+        for _ in 0..3 {
+            clock.advance(Duration::from_millis(900));
+            tx.send(Bytes::from("chunk")).unwrap();
+            body.frame().await.unwrap().unwrap();
+            assert!(futures::poll!(body.frame()).is_pending());
+        }
+
+        // 2.7s in: the chunk timeout has not run out, the total timeout has
+        clock.advance(Duration::from_millis(300));
+        let err = body.frame().await.unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "Total stream timeout exceeded (3s)");
+
+        // A stalled stream ends after the chunk timeout
+        let config = TimeoutConfig::new(Duration::from_secs(1), Duration::from_secs(3));
+        let mut body = TimeoutBody::new(PendingBody, config).with_clock(Arc::new(clock.clone()));
+        assert!(futures::poll!(body.frame()).is_pending());
+        clock.advance(Duration::from_millis(999));
+        assert!(futures::poll!(body.frame()).is_pending());
+        clock.advance(Duration::from_millis(1));
+        let err = body.frame().await.unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "Chunk timeout exceeded (1s)");
+    }
+
+    /// A deadline earlier than the total timeout ends the stream.
+    #[tokio::test]
+    async fn test_deadline_bounds_total_timeout() {
+        let config = TimeoutConfig::new(Duration::from_secs(5), Duration::from_secs(60))
+            .with_deadline(Instant::now() + Duration::from_millis(100));
+        let started = Instant::now();
+
+        let err = TimeoutBody::new(PendingBody, config)
+            .collect()
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "Request deadline exceeded");
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
 }
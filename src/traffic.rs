@@ -58,7 +58,7 @@ pub enum TrafficType {
 /// Traffic is classified as MCP if ALL of the following are true:
 /// 1. HTTP method is POST
 /// 2. Content-Type header contains "application/json"
-/// 3. Path is "/mcp/v1" or "/"
+/// 3. Path is "/mcp/v1", "/mcp/v1/{server}" or "/"
 ///
 /// Everything else is classified as HTTP for zero-copy passthrough.
 ///
@@ -125,8 +125,9 @@ pub fn discriminate_traffic<B>(req: &Request<B>) -> TrafficType {
     // Check 3: Path must be MCP endpoint
     // Standard: /mcp/v1
     // Also accept "/" for simple single-upstream deployments
+    // Routed: /mcp/v1/{server}
     let path = req.uri().path();
-    if path == "/mcp/v1" || path == "/" {
+    if path == "/mcp/v1" || path == "/" || mcp_server_id(path).is_some() {
         return TrafficType::Mcp;
     }
 
@@ -134,6 +135,17 @@ pub fn discriminate_traffic<B>(req: &Request<B>) -> TrafficType {
     TrafficType::Http
 }
 
+/// Extract the server ID from a routed MCP path (`/mcp/v1/{server}`).
+///
+/// Returns `None` for unrouted paths, an empty ID, or nested segments.
+///
+/// # Traceability
+/// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+pub fn mcp_server_id(path: &str) -> Option<&str> {
+    path.strip_prefix("/mcp/v1/")
+        .filter(|id| !id.is_empty() && !id.contains('/'))
+}
+
 /// Check if a request is an MCP request.
 ///
 /// Convenience wrapper around `discriminate_traffic`.
@@ -190,6 +202,23 @@ mod tests {
         assert_eq!(discriminate_traffic(&req), TrafficType::Mcp);
     }
 
+    /// Test: POST /mcp/v1/{server} → MCP, routed to that server
+    #[test]
+    fn test_mcp_server_endpoint() {
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/mcp/v1/billing")
+            .header("content-type", "application/json")
+            .body(())
+            .unwrap();
+
+        assert_eq!(discriminate_traffic(&req), TrafficType::Mcp);
+        assert_eq!(mcp_server_id("/mcp/v1/billing"), Some("billing"));
+        assert_eq!(mcp_server_id("/mcp/v1"), None);
+        assert_eq!(mcp_server_id("/mcp/v1/"), None);
+        assert_eq!(mcp_server_id("/mcp/v1/billing/tools"), None);
+    }
+
     /// Test: POST /mcp/v1 with charset → MCP (Content-Type with params)
     #[test]
     fn test_mcp_content_type_with_charset() {
@@ -0,0 +1,526 @@
+//! gRPC transport: governs MCP tools exposed as gRPC methods.
+//!
+//! # Overview
+//!
+//! gRPC requests are recognized by `content-type: application/grpc`. The
+//! method comes from the `:path` (`/package.Service/Method`), and a
+//! `FileDescriptorSet` (as written by `protoc --descriptor_set_out`) says
+//! which field of the method's input message holds the tool name: a string
+//! field named `tool` or, failing that, `name`.
+//!
+//! - Methods with a tool field are governed as `tools/call` on the tool
+//!   named in the first request message.
+//! - Other methods (including ones missing from the descriptor) are
+//!   governed as `grpc/<package.Service/Method>` on the resource
+//!   `package.Service/Method`, without reading the body.
+//!
+//! Only the tool field is decoded; Cedar sees empty `resource.arguments`.
+//! Compressed messages cannot be inspected and are refused for methods
+//! with a tool field.
+//!
+//! Refusals are Trailers-Only responses carrying `grpc-status` and
+//! `grpc-message` (e.g. `PERMISSION_DENIED` for gate denials).
+//!
+//! # Configuration
+//!
+//! - `THOUGHTGATE_GRPC_DESCRIPTOR`: path to a binary `FileDescriptorSet`.
+//!   Unset disables gRPC governance (gRPC traffic is passed through).
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-008/F-003 (gRPC Transport)
+
+use crate::error::ThoughtGateError;
+use crate::transport::wire::{GovernedCall, Transport};
+use bytes::Bytes;
+use http::header::{CONTENT_TYPE, TE};
+use http::request::Parts;
+use http::{HeaderMap, HeaderValue, Method, Response, StatusCode};
+use protobuf::Message;
+use protobuf::descriptor::field_descriptor_proto::Type;
+use protobuf::descriptor::{DescriptorProto, FileDescriptorSet};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Length of the gRPC message prefix (compressed flag + 4-byte length).
+pub const FRAME_HEADER_LEN: usize = 5;
+
+/// Input message fields that name the tool, in order of preference.
+const TOOL_FIELD_NAMES: [&str; 2] = ["tool", "name"];
+
+/// gRPC status codes used by ThoughtGate.
+///
+/// Implements: REQ-CORE-008/F-004 (gRPC Error Mapping)
+pub mod status {
+    /// Malformed or uninspectable request.
+    pub const INVALID_ARGUMENT: u16 = 3;
+    /// Upstream or approval deadline exceeded.
+    pub const DEADLINE_EXCEEDED: u16 = 4;
+    /// Refused by a governance gate.
+    pub const PERMISSION_DENIED: u16 = 7;
+    /// Rate limit exceeded.
+    pub const RESOURCE_EXHAUSTED: u16 = 8;
+    /// Unexpected failure.
+    pub const INTERNAL: u16 = 13;
+    /// Governance or upstream not available.
+    pub const UNAVAILABLE: u16 = 14;
+}
+
+/// Errors loading a gRPC descriptor set.
+#[derive(Debug, Error)]
+pub enum GrpcError {
+    /// The descriptor file could not be read.
+    #[error("Failed to read gRPC descriptor '{path}': {source}")]
+    Io {
+        /// Descriptor path
+        path: PathBuf,
+        /// Underlying error
+        source: std::io::Error,
+    },
+
+    /// The descriptor is not a valid `FileDescriptorSet`.
+    #[error("Invalid gRPC descriptor: {0}")]
+    Descriptor(#[from] protobuf::Error),
+}
+
+/// gRPC [`Transport`] driven by a `FileDescriptorSet`.
+///
+/// Implements: REQ-CORE-008/F-003 (gRPC Transport)
+#[derive(Debug, Clone, Default)]
+pub struct GrpcTransport {
+    /// Tool field number of each method's input message, by `:path`.
+    methods: HashMap<String, Option<u32>>,
+}
+
+impl GrpcTransport {
+    /// Build from the bytes of a binary `FileDescriptorSet`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GrpcError::Descriptor` if the bytes do not parse.
+    pub fn from_descriptor_set(bytes: &[u8]) -> Result<Self, GrpcError> {
+        let set = FileDescriptorSet::parse_from_bytes(bytes)?;
+
+        // Fully-qualified message name (".pkg.Outer.Inner") → tool field
+        let mut messages = HashMap::new();
+        for file in &set.file {
+            let scope = match file.package() {
+                "" => String::new(),
+                package => format!(".{package}"),
+            };
+            for message in &file.message_type {
+                index_message(&scope, message, &mut messages);
+            }
+        }
+
+        let mut methods = HashMap::new();
+        for file in &set.file {
+            for service in &file.service {
+                let service_name = qualify(file.package(), service.name());
+                for method in &service.method {
+                    let tool_field = messages.get(method.input_type()).copied().flatten();
+                    methods.insert(format!("/{service_name}/{}", method.name()), tool_field);
+                }
+            }
+        }
+        Ok(Self { methods })
+    }
+
+    /// Load a binary `FileDescriptorSet` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GrpcError` if the file cannot be read or parsed.
+    pub fn load(path: &Path) -> Result<Self, GrpcError> {
+        let bytes = std::fs::read(path).map_err(|source| GrpcError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_descriptor_set(&bytes)
+    }
+
+    /// Load the descriptor named by `THOUGHTGATE_GRPC_DESCRIPTOR`, if set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GrpcError` if the descriptor cannot be read or parsed.
+    pub fn from_env() -> Result<Option<Self>, GrpcError> {
+        match std::env::var("THOUGHTGATE_GRPC_DESCRIPTOR") {
+            Ok(path) if !path.is_empty() => Self::load(Path::new(&path)).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Number of methods in the descriptor.
+    pub fn method_count(&self) -> usize {
+        self.methods.len()
+    }
+
+    /// Tool field number for the method at `path`, if it has one.
+    fn tool_field(&self, path: &str) -> Option<u32> {
+        self.methods.get(path).copied().flatten()
+    }
+}
+
+impl Transport for GrpcTransport {
+    fn name(&self) -> &'static str {
+        "grpc"
+    }
+
+    fn matches(&self, head: &Parts) -> bool {
+        head.method == Method::POST
+            && head
+                .headers
+                .get(CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|ct| {
+                    ct.starts_with("application/grpc") && !ct.starts_with("application/grpc-web")
+                })
+    }
+
+    fn needed(&self, head: &Parts, prefix: &[u8]) -> usize {
+        if self.tool_field(head.uri.path()).is_none() {
+            return 0;
+        }
+        match frame_len(prefix) {
+            Some(len) => (FRAME_HEADER_LEN + len).saturating_sub(prefix.len()),
+            None => FRAME_HEADER_LEN - prefix.len(),
+        }
+    }
+
+    fn identify(&self, head: &Parts, prefix: &[u8]) -> Result<GovernedCall, ThoughtGateError> {
+        let path = head.uri.path();
+        let method_name = path
+            .strip_prefix('/')
+            .filter(|name| name.split('/').count() == 2)
+            .ok_or_else(|| invalid(format!("'{path}' is not a gRPC method path")))?;
+
+        let Some(field) = self.tool_field(path) else {
+            return Ok(GovernedCall {
+                method: format!("grpc/{method_name}"),
+                resource: method_name.to_string(),
+                arguments: serde_json::Value::Null,
+            });
+        };
+
+        let len = frame_len(prefix).ok_or_else(|| invalid("truncated gRPC message".into()))?;
+        let message = prefix
+            .get(FRAME_HEADER_LEN..FRAME_HEADER_LEN + len)
+            .ok_or_else(|| invalid("truncated gRPC message".into()))?;
+        if prefix[0] != 0 {
+            return Err(invalid(
+                "compressed gRPC messages cannot be inspected".into(),
+            ));
+        }
+        let tool = string_field(message, field)
+            .ok_or_else(|| invalid("malformed gRPC message".into()))?
+            .ok_or_else(|| invalid(format!("{method_name} request has no tool name")))?;
+
+        Ok(GovernedCall {
+            method: "tools/call".to_string(),
+            resource: tool,
+            arguments: serde_json::json!({}),
+        })
+    }
+
+    fn reject(&self, error: &ThoughtGateError) -> Response<Bytes> {
+        let mut response = Response::new(Bytes::new());
+        *response.status_mut() = StatusCode::OK;
+        let headers = response.headers_mut();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/grpc"));
+        headers.insert("grpc-status", HeaderValue::from(grpc_status(error)));
+        if let Ok(message) = HeaderValue::from_str(&percent_encode(&error.to_string())) {
+            headers.insert("grpc-message", message);
+        }
+        response
+    }
+
+    fn requires_http2(&self) -> bool {
+        true
+    }
+
+    fn prepare_upstream(&self, headers: &mut HeaderMap) {
+        // Stripped as hop-by-hop, but gRPC servers require it
+        headers.insert(TE, HeaderValue::from_static("trailers"));
+    }
+}
+
+/// gRPC status code for a ThoughtGate error.
+///
+/// Implements: REQ-CORE-008/F-004 (gRPC Error Mapping)
+pub fn grpc_status(error: &ThoughtGateError) -> u16 {
+    match error {
+        ThoughtGateError::ToolNotExposed { .. }
+        | ThoughtGateError::GovernanceRuleDenied { .. }
+        | ThoughtGateError::PolicyDenied { .. }
+        | ThoughtGateError::ApprovalRejected { .. } => status::PERMISSION_DENIED,
+        ThoughtGateError::ParseError { .. }
+        | ThoughtGateError::InvalidRequest { .. }
+        | ThoughtGateError::InvalidParams { .. } => status::INVALID_ARGUMENT,
+        ThoughtGateError::UpstreamTimeout { .. }
+        | ThoughtGateError::ApprovalTimeout { .. }
+        | ThoughtGateError::DeadlineExceeded { .. } => status::DEADLINE_EXCEEDED,
+        ThoughtGateError::RateLimited { .. } => status::RESOURCE_EXHAUSTED,
+        ThoughtGateError::UpstreamConnectionFailed { .. }
+        | ThoughtGateError::UpstreamTlsFailed { .. }
+        | ThoughtGateError::ServiceUnavailable { .. } => status::UNAVAILABLE,
+        _ => status::INTERNAL,
+    }
+}
+
+fn invalid(details: String) -> ThoughtGateError {
+    ThoughtGateError::InvalidParams { details }
+}
+
+/// `"{scope}.{name}"`, or `name` alone when the scope is empty.
+fn qualify(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_string()
+    } else {
+        format!("{scope}.{name}")
+    }
+}
+
+/// Record the tool field of `message` and its nested messages.
+fn index_message(scope: &str, message: &DescriptorProto, out: &mut HashMap<String, Option<u32>>) {
+    let full_name = format!("{scope}.{}", message.name());
+    let tool_field = TOOL_FIELD_NAMES.iter().find_map(|wanted| {
+        message
+            .field
+            .iter()
+            .find(|f| f.name() == *wanted && f.type_() == Type::TYPE_STRING)
+            .and_then(|f| u32::try_from(f.number()).ok())
+    });
+    for nested in &message.nested_type {
+        index_message(&full_name, nested, out);
+    }
+    out.insert(full_name, tool_field);
+}
+
+/// Message length from a gRPC frame header, if the header is complete.
+fn frame_len(prefix: &[u8]) -> Option<usize> {
+    let header: [u8; 4] = prefix.get(1..FRAME_HEADER_LEN)?.try_into().ok()?;
+    usize::try_from(u32::from_be_bytes(header)).ok()
+}
+
+/// Decode a base-128 varint at `*pos`.
+fn read_varint(buf: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *buf.get(*pos)?;
+        *pos += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Last value of string field `number` in a protobuf message.
+///
+/// Returns `None` if the message is malformed, `Some(None)` if the field is
+/// absent.
+fn string_field(message: &[u8], number: u32) -> Option<Option<String>> {
+    let mut pos = 0;
+    let mut found = None;
+    while pos < message.len() {
+        let key = read_varint(message, &mut pos)?;
+        let field = u32::try_from(key >> 3).ok()?;
+        match key & 0x7 {
+            0 => {
+                read_varint(message, &mut pos)?;
+            }
+            1 => pos = pos.checked_add(8)?,
+            2 => {
+                let len = usize::try_from(read_varint(message, &mut pos)?).ok()?;
+                let end = pos.checked_add(len)?;
+                let value = message.get(pos..end)?;
+                if field == number {
+                    found = Some(String::from_utf8(value.to_vec()).ok()?);
+                }
+                pos = end;
+            }
+            5 => pos = pos.checked_add(4)?,
+            // Groups are deprecated and never carry the tool name
+            _ => return None,
+        }
+    }
+    (pos == message.len()).then_some(found)
+}
+
+/// Percent-encode a `grpc-message` value per the gRPC HTTP/2 spec.
+fn percent_encode(message: &str) -> String {
+    let mut encoded = String::with_capacity(message.len());
+    for byte in message.bytes() {
+        if (0x20..=0x7e).contains(&byte) && byte != b'%' {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use protobuf::Message;
+    use protobuf::descriptor::field_descriptor_proto::Type;
+    use protobuf::descriptor::{
+        DescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet,
+        MethodDescriptorProto, ServiceDescriptorProto,
+    };
+
+    /// Descriptor for `package mcp; service Tools { rpc CallTool(CallToolRequest)
+    /// returns (CallToolResponse); rpc Ping(PingRequest) returns (PingRequest); }`
+    /// where `CallToolRequest { string name = 1; bytes arguments = 2; }`.
+    pub(crate) fn descriptor_set() -> Vec<u8> {
+        let mut name = FieldDescriptorProto::new();
+        name.set_name("name".into());
+        name.set_number(1);
+        name.set_type(Type::TYPE_STRING);
+        let mut arguments = FieldDescriptorProto::new();
+        arguments.set_name("arguments".into());
+        arguments.set_number(2);
+        arguments.set_type(Type::TYPE_BYTES);
+
+        let mut request = DescriptorProto::new();
+        request.set_name("CallToolRequest".into());
+        request.field = vec![name, arguments];
+        let mut response = DescriptorProto::new();
+        response.set_name("CallToolResponse".into());
+        let mut ping = DescriptorProto::new();
+        ping.set_name("PingRequest".into());
+
+        let method = |name: &str, input: &str, output: &str| {
+            let mut m = MethodDescriptorProto::new();
+            m.set_name(name.into());
+            m.set_input_type(input.into());
+            m.set_output_type(output.into());
+            m
+        };
+        let mut service = ServiceDescriptorProto::new();
+        service.set_name("Tools".into());
+        service.method = vec![
+            method("CallTool", ".mcp.CallToolRequest", ".mcp.CallToolResponse"),
+            method("Ping", ".mcp.PingRequest", ".mcp.PingRequest"),
+        ];
+
+        let mut file = FileDescriptorProto::new();
+        file.set_name("mcp.proto".into());
+        file.set_package("mcp".into());
+        file.message_type = vec![request, response, ping];
+        file.service = vec![service];
+
+        let mut set = FileDescriptorSet::new();
+        set.file = vec![file];
+        set.write_to_bytes().unwrap()
+    }
+
+    /// A length-prefixed `CallToolRequest { name: tool, arguments: b"{}" }`.
+    pub(crate) fn call_tool_frame(tool: &str) -> Vec<u8> {
+        let mut message = vec![0x0a, u8::try_from(tool.len()).unwrap()];
+        message.extend_from_slice(tool.as_bytes());
+        message.extend_from_slice(&[0x12, 2, b'{', b'}']);
+
+        let mut frame = vec![0];
+        frame.extend_from_slice(&u32::try_from(message.len()).unwrap().to_be_bytes());
+        frame.extend_from_slice(&message);
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_support::{call_tool_frame, descriptor_set};
+    use super::*;
+
+    fn transport() -> GrpcTransport {
+        GrpcTransport::from_descriptor_set(&descriptor_set()).unwrap()
+    }
+
+    fn head(path: &str, content_type: &str) -> Parts {
+        http::Request::builder()
+            .method(Method::POST)
+            .uri(path)
+            .header(CONTENT_TYPE, content_type)
+            .body(())
+            .unwrap()
+            .into_parts()
+            .0
+    }
+
+    #[test]
+    fn test_matches_grpc_but_not_grpc_web() {
+        let grpc = transport();
+        assert!(grpc.matches(&head("/mcp.Tools/CallTool", "application/grpc")));
+        assert!(grpc.matches(&head("/mcp.Tools/CallTool", "application/grpc+proto")));
+        assert!(!grpc.matches(&head("/mcp.Tools/CallTool", "application/grpc-web")));
+        assert!(!grpc.matches(&head("/mcp/v1", "application/json")));
+    }
+
+    #[test]
+    fn test_identify_tool_from_first_message() {
+        let grpc = transport();
+        let head = head("/mcp.Tools/CallTool", "application/grpc");
+        let frame = call_tool_frame("delete_user");
+
+        assert_eq!(grpc.needed(&head, &[]), FRAME_HEADER_LEN);
+        assert_eq!(grpc.needed(&head, &frame[..7]), frame.len() - 7);
+        assert_eq!(grpc.needed(&head, &frame), 0);
+
+        let call = grpc.identify(&head, &frame).unwrap();
+        assert_eq!(call.method, "tools/call");
+        assert_eq!(call.resource, "delete_user");
+    }
+
+    #[test]
+    fn test_identify_method_without_tool_field() {
+        let grpc = transport();
+        let head = head("/mcp.Tools/Ping", "application/grpc");
+        assert_eq!(grpc.needed(&head, &[]), 0);
+
+        let call = grpc.identify(&head, &[]).unwrap();
+        assert_eq!(call.method, "grpc/mcp.Tools/Ping");
+        assert_eq!(call.resource, "mcp.Tools/Ping");
+    }
+
+    #[test]
+    fn test_identify_rejects_uninspectable_messages() {
+        let grpc = transport();
+        let head = head("/mcp.Tools/CallTool", "application/grpc");
+        let mut frame = call_tool_frame("delete_user");
+
+        let truncated = grpc.identify(&head, &frame[..frame.len() - 1]);
+        assert!(matches!(
+            truncated,
+            Err(ThoughtGateError::InvalidParams { .. })
+        ));
+
+        frame[0] = 1;
+        let compressed = grpc.identify(&head, &frame).unwrap_err();
+        assert_eq!(grpc_status(&compressed), status::INVALID_ARGUMENT);
+    }
+
+    #[test]
+    fn test_reject_is_trailers_only_permission_denied() {
+        let response = transport().reject(&ThoughtGateError::GovernanceRuleDenied {
+            tool: "delete_user".to_string(),
+            rule: None,
+        });
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.body().is_empty());
+        assert_eq!(response.headers()["content-type"], "application/grpc");
+        assert_eq!(response.headers()["grpc-status"], "7");
+        assert_eq!(
+            response.headers()["grpc-message"],
+            "Tool 'delete_user' is denied by governance rules"
+        );
+    }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("50% off\n"), "50%25 off%0A");
+        assert_eq!(percent_encode("café"), "caf%C3%A9");
+    }
+}
@@ -550,15 +550,7 @@ pub fn parse_jsonrpc(bytes: &[u8]) -> Result<ParsedRequests, ThoughtGateError> {
             let mut items = Vec::with_capacity(arr.len());
             for item in arr {
                 // Try to extract ID before parsing (for error responses)
-                let id = item
-                    .as_object()
-                    .and_then(|obj| obj.get("id"))
-                    .and_then(|v| match v {
-                        Value::Number(n) => n.as_i64().map(JsonRpcId::Number),
-                        Value::String(s) => Some(JsonRpcId::String(s.clone())),
-                        Value::Null => Some(JsonRpcId::Null),
-                        _ => None,
-                    });
+                let id = request_id(&item);
 
                 match parse_single_request(item) {
                     Ok(request) => items.push(BatchItem::Valid(request)),
@@ -580,6 +572,19 @@ pub fn parse_jsonrpc(bytes: &[u8]) -> Result<ParsedRequests, ThoughtGateError> {
     }
 }
 
+/// The `id` of a raw JSON-RPC request, typed as the client sent it.
+///
+/// Implements: REQ-CORE-003/F-001.4 (Preserve ID type)
+///
+/// For answering a request that failed validation: an explicit `"id": null`
+/// is `Some(JsonRpcId::Null)`, while a missing `id`, a non-object request,
+/// or an id that is not a valid JSON-RPC id is `None`, and the error
+/// response then carries `"id": null`.
+pub fn request_id(request: &Value) -> Option<JsonRpcId> {
+    let id = request.as_object()?.get("id")?;
+    JsonRpcId::deserialize(id).ok()
+}
+
 /// Parse a single JSON-RPC 2.0 request from a JSON value.
 ///
 /// Implements: REQ-CORE-003/F-001 (Parse JSON-RPC 2.0)
@@ -981,6 +986,28 @@ mod tests {
         }
     }
 
+    /// Verifies: REQ-CORE-003/F-001.4 (ID of a request that failed validation)
+    #[test]
+    fn test_request_id_preserves_type() {
+        let id_of = |json: &str| request_id(&serde_json::from_str(json).unwrap());
+
+        assert_eq!(id_of(r#"{"id":7}"#), Some(JsonRpcId::Number(7)));
+        assert_eq!(
+            id_of(r#"{"id":9007199254740993}"#),
+            Some(JsonRpcId::Number(9_007_199_254_740_993))
+        );
+        assert_eq!(
+            id_of(r#"{"id":"7"}"#),
+            Some(JsonRpcId::String("7".to_string()))
+        );
+        assert_eq!(id_of(r#"{"id":null}"#), Some(JsonRpcId::Null));
+        assert_eq!(id_of(r#"{"method":"test"}"#), None);
+        // Not valid ids: the response uses null
+        assert_eq!(id_of(r#"{"id":1.5}"#), None);
+        assert_eq!(id_of(r#"{"id":[1]}"#), None);
+        assert_eq!(id_of(r#"[{"id":1}]"#), None);
+    }
+
     #[test]
     fn test_float_id_rejected() {
         let json = br#"{"jsonrpc":"2.0","id":1.5,"method":"test"}"#;
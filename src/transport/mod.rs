@@ -23,10 +23,14 @@
 //! # Traceability
 //! - Implements: REQ-CORE-003 (MCP Transport & Routing)
 
+pub mod grpc;
 pub mod jsonrpc;
 pub mod router;
 pub mod server;
+pub mod tls;
 pub mod upstream;
+pub mod upstream_group;
+pub mod wire;
 
 // Re-export core types
 pub use jsonrpc::{
@@ -34,7 +38,9 @@ pub use jsonrpc::{
 };
 pub use router::{McpRouter, RouteTarget, TaskMethod};
 pub use server::{
-    McpHandler, McpHandlerConfig, McpServer, McpServerConfig, McpState,
+    DEFAULT_SOURCE_ID, McpHandler, McpHandlerConfig, McpServer, McpServerConfig, McpState,
     create_governance_components,
 };
 pub use upstream::{UpstreamClient, UpstreamConfig, UpstreamForwarder};
+pub use upstream_group::{CircuitBreaker, UpstreamGroup};
+pub use wire::{GovernedCall, Transport};
@@ -162,10 +162,7 @@ impl McpRouter {
         }
 
         // Methods subject to policy evaluation
-        if method.starts_with("tools/")
-            || method.starts_with("resources/")
-            || method.starts_with("prompts/")
-        {
+        if Self::is_policy_method(method) {
             return RouteTarget::PolicyEvaluation { request };
         }
 
@@ -173,6 +170,25 @@ impl McpRouter {
         RouteTarget::PassThrough { request }
     }
 
+    /// Whether `method` is passed through to upstream untouched.
+    ///
+    /// Implements: REQ-CORE-003/F-002
+    ///
+    /// Such methods are routed on their name alone, so they can be forwarded
+    /// before the rest of the request has been read.
+    pub fn is_pass_through(method: &str) -> bool {
+        method != "initialize"
+            && Self::parse_task_method(method).is_none()
+            && !Self::is_policy_method(method)
+    }
+
+    /// Whether `method` is subject to policy evaluation.
+    fn is_policy_method(method: &str) -> bool {
+        method.starts_with("tools/")
+            || method.starts_with("resources/")
+            || method.starts_with("prompts/")
+    }
+
     /// Parse a task method string into TaskMethod enum.
     ///
     /// # Arguments
@@ -350,6 +366,16 @@ mod tests {
         assert!(matches!(target, RouteTarget::PassThrough { .. }));
     }
 
+    #[test]
+    fn test_is_pass_through() {
+        for method in ["ping", "notifications/progress", "custom/method"] {
+            assert!(McpRouter::is_pass_through(method), "{method}");
+        }
+        for method in ["initialize", "tasks/result", "tools/call", "resources/read"] {
+            assert!(!McpRouter::is_pass_through(method), "{method}");
+        }
+    }
+
     #[test]
     fn test_task_method_as_str() {
         assert_eq!(TaskMethod::Get.as_str(), "tasks/get");
@@ -22,12 +22,15 @@
 //! 6. Execute policy evaluation (Cedar) or task handling
 //! 7. Return JSON-RPC response(s)
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use arc_swap::ArcSwap;
+
 use axum::{
     Router,
     extract::{DefaultBodyLimit, State},
-    http::{StatusCode, header},
+    http::{HeaderValue, StatusCode, header},
     response::{IntoResponse, Response},
     routing::post,
 };
@@ -35,16 +38,28 @@ use bytes::Bytes;
 use tokio::sync::Semaphore;
 use tracing::{debug, error, info, warn};
 
-use crate::config::{Action, Config, MatchResult};
+use crate::audit::{self, AuditDecision, AuditGate, AuditRecord};
+use crate::config::{
+    Action, ApprovalDestination, ApprovalMode, Config, DEFAULT_REJECT_MESSAGE, Enforcement,
+    HumanWorkflow, LiveCatalog, LiveConfig, MatchResult, MultiCallMode, RejectCode,
+};
 use crate::error::ThoughtGateError;
 use crate::governance::task::JsonRpcId as GovernanceJsonRpcId;
 use crate::governance::{
-    ApprovalAdapter, ApprovalEngine, ApprovalEngineConfig, Principal, SlackAdapter, TaskHandler,
-    TaskStore, ToolCallRequest,
+    AdapterError, ApprovalAdapter, ApprovalEngine, ApprovalEngineConfig, ApprovalEngineError,
+    ApprovalStartResult, CanonicalRequest, Principal, SlackAdapter, StreamClaim, TaskHandler,
+    TaskId, TaskStore, ToolCallRequest, WebhookAdapter, WebhookConfig,
+};
+use crate::inspector::{
+    ClassificationOutcome, ClassifierPipeline, JsonLimits, MultiCallShape, RequestShape,
+    ToolCallEntry, Verdict,
 };
 use crate::policy::engine::CedarEngine;
-use crate::policy::principal::infer_principal;
-use crate::policy::{CedarContext, CedarDecision, CedarRequest, CedarResource, TimeContext};
+use crate::policy::explain;
+use crate::policy::principal::request_principal;
+use crate::policy::{
+    CedarContext, CedarDecision, CedarRequest, CedarResource, Explanation, TimeContext,
+};
 use crate::protocol::{
     CapabilityCache, TasksCancelRequest, TasksGetRequest, TasksListRequest, TasksResultRequest,
     extract_upstream_sse_support, extract_upstream_task_support, inject_task_capability,
@@ -52,12 +67,16 @@ use crate::protocol::{
 };
 use crate::transport::jsonrpc::{
     JsonRpcId, JsonRpcResponse, McpRequest, ParsedRequests, PromptDefinition, ResourceDefinition,
-    TaskSupport, ToolDefinition, ToolExecution, parse_jsonrpc,
+    TaskSupport, ToolDefinition, ToolExecution, parse_jsonrpc, request_id,
 };
 use crate::transport::router::{McpRouter, RouteTarget, TaskMethod};
 use crate::transport::upstream::{UpstreamClient, UpstreamConfig, UpstreamForwarder};
+use crate::transport::wire::GovernedCall;
 use tokio_util::sync::CancellationToken;
 
+/// Source ID used when no routing table is configured.
+pub const DEFAULT_SOURCE_ID: &str = "upstream";
+
 /// Configuration for the MCP server.
 ///
 /// Implements: REQ-CORE-003/§5.3 (Configuration)
@@ -69,6 +88,8 @@ pub struct McpServerConfig {
     pub max_body_size: usize,
     /// Maximum concurrent requests
     pub max_concurrent_requests: usize,
+    /// JSON depth and size limits
+    pub json_limits: JsonLimits,
     /// Upstream client configuration
     pub upstream: UpstreamConfig,
 }
@@ -79,6 +100,7 @@ impl Default for McpServerConfig {
             listen_addr: "0.0.0.0:8080".to_string(),
             max_body_size: 1024 * 1024, // 1MB
             max_concurrent_requests: 10000,
+            json_limits: JsonLimits::default(),
             upstream: UpstreamConfig::default(),
         }
     }
@@ -95,7 +117,7 @@ impl McpServerConfig {
     /// - `THOUGHTGATE_MAX_REQUEST_BODY_BYTES` (default: 1048576): Max body size
     /// - `THOUGHTGATE_MAX_CONCURRENT_REQUESTS` (default: 10000): Max concurrent requests
     ///
-    /// Plus all upstream configuration variables (see `UpstreamConfig::from_env`).
+    /// Plus the JSON limits (see `JsonLimits::from_env`) and all upstream configuration variables (see `UpstreamConfig::from_env`).
     ///
     /// # Errors
     ///
@@ -118,6 +140,7 @@ impl McpServerConfig {
             listen_addr,
             max_body_size,
             max_concurrent_requests,
+            json_limits: JsonLimits::from_env(),
             upstream: UpstreamConfig::from_env()?,
         })
     }
@@ -144,8 +167,14 @@ pub struct McpState {
     pub semaphore: Arc<Semaphore>,
     /// Maximum body size in bytes
     pub max_body_size: usize,
+    /// JSON depth and size limits
+    pub json_limits: JsonLimits,
     /// Capability cache for upstream detection (REQ-CORE-007)
     pub capability_cache: Arc<CapabilityCache>,
+    /// Source ID of the upstream this state forwards to
+    pub source_id: String,
+    /// Content classifiers run on requests Cedar permits (Gate 3)
+    pub classifiers: Arc<ArcSwap<ClassifierPipeline>>,
 }
 
 /// Configuration for the MCP handler.
@@ -157,6 +186,8 @@ pub struct McpHandlerConfig {
     pub max_body_size: usize,
     /// Maximum concurrent requests
     pub max_concurrent_requests: usize,
+    /// JSON depth and size limits
+    pub json_limits: JsonLimits,
 }
 
 impl Default for McpHandlerConfig {
@@ -164,6 +195,7 @@ impl Default for McpHandlerConfig {
         Self {
             max_body_size: 1024 * 1024, // 1MB
             max_concurrent_requests: 10000,
+            json_limits: JsonLimits::default(),
         }
     }
 }
@@ -175,6 +207,8 @@ impl McpHandlerConfig {
     ///
     /// - `THOUGHTGATE_MAX_REQUEST_BODY_BYTES` (default: 1048576): Max body size
     /// - `THOUGHTGATE_MAX_CONCURRENT_REQUESTS` (default: 10000): Max concurrent requests
+    ///
+    /// Plus the JSON limits (see `JsonLimits::from_env`).
     pub fn from_env() -> Self {
         let max_body_size: usize = std::env::var("THOUGHTGATE_MAX_REQUEST_BODY_BYTES")
             .ok()
@@ -189,6 +223,35 @@ impl McpHandlerConfig {
         Self {
             max_body_size,
             max_concurrent_requests,
+            json_limits: JsonLimits::from_env(),
+        }
+    }
+}
+
+/// Response to a buffered MCP request body.
+///
+/// Implements: REQ-OBS-002 (Audit Trail)
+#[derive(Debug, Clone)]
+pub struct McpReply {
+    /// HTTP status
+    pub status: StatusCode,
+    /// JSON-RPC response body
+    pub body: Bytes,
+    /// Reject code when a single request was refused by a governance gate
+    /// (see [`ThoughtGateError::reject_code`]). Batches never carry one.
+    pub reject_code: Option<&'static str>,
+    /// `Retry-After` hint in whole seconds when a single request failed
+    /// with one (see [`ThoughtGateError::retry_after`]).
+    pub retry_after: Option<u64>,
+}
+
+impl From<(StatusCode, Bytes)> for McpReply {
+    fn from((status, body): (StatusCode, Bytes)) -> Self {
+        Self {
+            status,
+            body,
+            reject_code: None,
+            retry_after: None,
         }
     }
 }
@@ -252,7 +315,10 @@ impl McpHandler {
             approval_engine: None,
             semaphore,
             max_body_size: config.max_body_size,
+            json_limits: config.json_limits,
             capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Default::default(),
         });
 
         Self { state }
@@ -278,7 +344,10 @@ impl McpHandler {
             approval_engine: None,
             semaphore,
             max_body_size: config.max_body_size,
+            json_limits: config.json_limits,
             capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Default::default(),
         });
 
         Self { state }
@@ -325,17 +394,124 @@ impl McpHandler {
             approval_engine,
             semaphore,
             max_body_size: handler_config.max_body_size,
+            json_limits: handler_config.json_limits,
             capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Default::default(),
         });
 
         Self { state }
     }
 
+    /// Derive a handler that forwards to another source.
+    ///
+    /// The new handler shares governance (policies, tasks, approvals and the
+    /// concurrency limit) with this one, but has its own upstream and
+    /// capability cache, and reports `source_id` as the resource server.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+    #[must_use]
+    pub fn for_source(
+        &self,
+        source_id: impl Into<String>,
+        upstream: Arc<dyn UpstreamForwarder>,
+    ) -> Self {
+        let state = &self.state;
+        Self {
+            state: Arc::new(McpState {
+                upstream,
+                router: state.router.clone(),
+                task_handler: state.task_handler.clone(),
+                cedar_engine: state.cedar_engine.clone(),
+                config: state.config.clone(),
+                approval_engine: state.approval_engine.clone(),
+                semaphore: state.semaphore.clone(),
+                max_body_size: state.max_body_size,
+                json_limits: state.json_limits,
+                capability_cache: Arc::new(CapabilityCache::new()),
+                source_id: source_id.into(),
+                classifiers: state.classifiers.clone(),
+            }),
+        }
+    }
+
+    /// Run `classifiers` on requests Cedar permits (Gate 3).
+    ///
+    /// Handlers derived with [`for_source`](Self::for_source) share them.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-006 (Content Classification)
+    #[must_use]
+    pub fn with_classifiers(self, classifiers: ClassifierPipeline) -> Self {
+        self.state.classifiers.store(Arc::new(classifiers));
+        self
+    }
+
+    /// Get the source ID this handler forwards to.
+    pub fn source_id(&self) -> &str {
+        &self.state.source_id
+    }
+
     /// Get the maximum body size.
     pub fn max_body_size(&self) -> usize {
         self.state.max_body_size
     }
 
+    /// Get the approval engine, if Gate 4 is enabled.
+    pub fn approval_engine(&self) -> Option<&Arc<ApprovalEngine>> {
+        self.state.approval_engine.as_ref()
+    }
+
+    /// Get the Cedar policy engine (Gate 3).
+    pub fn cedar_engine(&self) -> &Arc<CedarEngine> {
+        &self.state.cedar_engine
+    }
+
+    /// Get the upstream this handler forwards to.
+    pub fn upstream(&self) -> &Arc<dyn UpstreamForwarder> {
+        &self.state.upstream
+    }
+
+    /// Authorize a protocol upgrade (e.g. WebSocket) before it is relayed.
+    ///
+    /// The request path is the governed resource name. See
+    /// [`authorize_upgrade`] for how each gate applies.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gate error that refused the upgrade.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
+    pub fn authorize_upgrade(&self, path: &str, protocol: &str) -> Result<(), ThoughtGateError> {
+        authorize_upgrade(&self.state, path, protocol)
+    }
+
+    /// Authorize a call carried by a non-JSON-RPC [`Transport`].
+    ///
+    /// Such calls are decided before the rest of their body is read: see
+    /// [`authorize_direct`] for how each gate applies. A call that needs
+    /// approval waits for the decision in blocking mode, with its body left
+    /// unread, and the claim on the approved task is returned so the
+    /// outcome of sending it can be recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gate error, or approval decision, that refused the call.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-008/F-002 (Transport Call Governance)
+    /// - Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+    ///
+    /// [`Transport`]: crate::transport::wire::Transport
+    pub async fn authorize_call(
+        &self,
+        call: &GovernedCall,
+    ) -> Result<Option<StreamClaim>, ThoughtGateError> {
+        authorize_call(&self.state, call).await
+    }
+
     /// Handle a buffered MCP request body.
     ///
     /// This is the main entry point for processing MCP requests. It:
@@ -356,6 +532,13 @@ impl McpHandler {
     /// # Traceability
     /// - Implements: REQ-CORE-003/§10 (Request Handler Pattern)
     pub async fn handle(&self, body: Bytes) -> (StatusCode, Bytes) {
+        let reply = self.handle_reply(body).await;
+        (reply.status, reply.body)
+    }
+
+    /// [`handle`](Self::handle), also returning the reject code of a
+    /// refused request.
+    pub async fn handle_reply(&self, body: Bytes) -> McpReply {
         handle_mcp_body_bytes(&self.state, body).await
     }
 
@@ -363,8 +546,7 @@ impl McpHandler {
     ///
     /// This is used by McpServer for backwards compatibility with Axum.
     pub async fn handle_response(&self, body: Bytes) -> Response {
-        let (status, bytes) = self.handle(body).await;
-        (status, [(header::CONTENT_TYPE, "application/json")], bytes).into_response()
+        json_response(self.handle_reply(body).await)
     }
 
     /// Get a reference to the internal state (for testing).
@@ -406,6 +588,7 @@ pub struct McpServer {
 ///
 /// * `upstream` - Upstream forwarder for approved requests
 /// * `config` - Optional YAML config (enables Gate 1, 2, 4)
+/// * `live` - Optional hot-reloadable settings (task rate limits, default TTL)
 /// * `shutdown` - Cancellation token for graceful shutdown
 ///
 /// # Returns
@@ -415,19 +598,44 @@ pub struct McpServer {
 pub fn create_governance_components(
     upstream: Arc<dyn UpstreamForwarder>,
     config: Option<&Config>,
+    live: Option<Arc<LiveConfig>>,
     shutdown: CancellationToken,
 ) -> Result<(TaskHandler, Arc<CedarEngine>, Option<Arc<ApprovalEngine>>), ThoughtGateError> {
     // Create task store and handler for SEP-1686 task methods
-    let task_store = Arc::new(TaskStore::with_defaults());
+    let task_store = match live {
+        Some(live) => TaskStore::with_defaults().with_live_config(live),
+        None => TaskStore::with_defaults(),
+    };
+    let task_store = Arc::new(task_store);
     let task_handler = TaskHandler::new(task_store.clone());
 
     // Create Cedar policy engine (Gate 3)
-    let cedar_engine =
-        Arc::new(
-            CedarEngine::new().map_err(|e| ThoughtGateError::ServiceUnavailable {
-                reason: format!("Failed to create Cedar engine: {}", e),
-            })?,
-        );
+    let on_policy_error = config
+        .map(|c| c.governance.defaults.on_policy_error)
+        .unwrap_or_default();
+    let catalog = match config.and_then(|c| c.catalog.clone()) {
+        Some(path) => Some(Arc::new(LiveCatalog::load(path).map_err(|e| {
+            ThoughtGateError::ServiceUnavailable {
+                reason: format!("Failed to load tool catalog: {}", e),
+            }
+        })?)),
+        None => None,
+    };
+    // Catalog attributes must be declared in the schema before policies load
+    let attribute_types = catalog
+        .as_ref()
+        .map(|c| c.declared_types().clone())
+        .unwrap_or_default();
+    let mut cedar_engine = CedarEngine::new_with_attributes(&attribute_types)
+        .map_err(|e| ThoughtGateError::ServiceUnavailable {
+            reason: format!("Failed to create Cedar engine: {}", e),
+        })?
+        .with_on_policy_error(on_policy_error);
+    if let Some(catalog) = catalog {
+        cedar_engine = cedar_engine.with_catalog(catalog);
+    }
+    cedar_engine.warmup();
+    let cedar_engine = Arc::new(cedar_engine);
 
     // Create ApprovalEngine only if config uses approval rules (Gate 4)
     // This avoids requiring Slack credentials when approvals are not used
@@ -438,28 +646,37 @@ pub fn create_governance_components(
     let approval_engine = if needs_approval {
         info!("Approval rules detected, initializing ApprovalEngine");
 
-        // Create approval adapter based on environment
-        let adapter: Arc<dyn ApprovalAdapter> =
-            match std::env::var("THOUGHTGATE_APPROVAL_ADAPTER").as_deref() {
-                Ok("mock") => {
-                    // Use mock adapter for testing
-                    Arc::new(crate::governance::approval::mock::MockAdapter::from_env())
-                }
-                _ => {
-                    // Default to Slack adapter
-                    let slack_config = crate::governance::SlackConfig::from_env().map_err(|e| {
-                        ThoughtGateError::ServiceUnavailable {
-                            reason: format!("Failed to create Slack config: {}", e),
-                        }
-                    })?;
-                    let slack_adapter = SlackAdapter::new(slack_config).map_err(|e| {
-                        ThoughtGateError::ServiceUnavailable {
-                            reason: format!("Failed to create Slack adapter: {}", e),
-                        }
-                    })?;
-                    Arc::new(slack_adapter)
-                }
-            };
+        // Create approval adapter: THOUGHTGATE_APPROVAL_ADAPTER wins, then
+        // the destination of the "default" approval workflow, then Slack
+        let selected = std::env::var("THOUGHTGATE_APPROVAL_ADAPTER").ok();
+        let default_destination = config
+            .and_then(|c| c.get_workflow("default"))
+            .map(|w| &w.destination);
+        let adapter: Arc<dyn ApprovalAdapter> = match (selected.as_deref(), default_destination) {
+            (Some("mock"), _) => {
+                // Use mock adapter for testing
+                Arc::new(crate::governance::approval::mock::MockAdapter::from_env())
+            }
+            (Some("webhook"), _) => webhook_adapter(WebhookConfig::from_env())?,
+            (None, Some(destination @ ApprovalDestination::Webhook { .. })) => {
+                webhook_adapter(WebhookConfig::from_destination(destination))?
+            }
+            _ => {
+                // Default to Slack adapter
+                let slack_config = crate::governance::SlackConfig::from_env().map_err(|e| {
+                    ThoughtGateError::ServiceUnavailable {
+                        reason: format!("Failed to create Slack config: {}", e),
+                    }
+                })?;
+                let slack_adapter = SlackAdapter::new(slack_config).map_err(|e| {
+                    ThoughtGateError::ServiceUnavailable {
+                        reason: format!("Failed to create Slack adapter: {}", e),
+                    }
+                })?;
+                Arc::new(slack_adapter)
+            }
+        };
+        info!(adapter = adapter.name(), "Approval adapter selected");
 
         let engine_config = ApprovalEngineConfig::from_env();
 
@@ -484,6 +701,24 @@ pub fn create_governance_components(
     Ok((task_handler, cedar_engine, approval_engine))
 }
 
+/// Build the webhook approval adapter from a loaded configuration.
+///
+/// Implements: REQ-GOV-003/F-001
+fn webhook_adapter(
+    config: Result<WebhookConfig, AdapterError>,
+) -> Result<Arc<dyn ApprovalAdapter>, ThoughtGateError> {
+    let adapter =
+        config
+            .and_then(WebhookAdapter::new)
+            .map_err(|e| ThoughtGateError::ServiceUnavailable {
+                reason: format!(
+                    "Failed to create webhook adapter (THOUGHTGATE_APPROVAL_WEBHOOK_URL and \
+                 THOUGHTGATE_APPROVAL_WEBHOOK_SECRET are required): {e}"
+                ),
+            })?;
+    Ok(Arc::new(adapter))
+}
+
 impl McpServer {
     /// Create a new MCP server.
     ///
@@ -501,7 +736,7 @@ impl McpServer {
         let semaphore = Arc::new(Semaphore::new(config.max_concurrent_requests));
         let shutdown = CancellationToken::new();
         let (task_handler, cedar_engine, approval_engine) =
-            create_governance_components(upstream.clone(), None, shutdown.clone())?;
+            create_governance_components(upstream.clone(), None, None, shutdown.clone())?;
 
         let state = Arc::new(McpState {
             upstream,
@@ -512,7 +747,10 @@ impl McpServer {
             approval_engine,
             semaphore,
             max_body_size: config.max_body_size,
+            json_limits: config.json_limits,
             capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Default::default(),
         });
 
         Ok(Self {
@@ -539,7 +777,7 @@ impl McpServer {
         let semaphore = Arc::new(Semaphore::new(config.max_concurrent_requests));
         let shutdown = CancellationToken::new();
         let (task_handler, cedar_engine, approval_engine) =
-            create_governance_components(upstream.clone(), None, shutdown.clone())?;
+            create_governance_components(upstream.clone(), None, None, shutdown.clone())?;
 
         let state = Arc::new(McpState {
             upstream,
@@ -550,7 +788,10 @@ impl McpServer {
             approval_engine,
             semaphore,
             max_body_size: config.max_body_size,
+            json_limits: config.json_limits,
             capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Default::default(),
         });
 
         Ok(Self {
@@ -587,8 +828,14 @@ impl McpServer {
     ) -> Result<Self, ThoughtGateError> {
         let upstream = Arc::new(UpstreamClient::new(server_config.upstream.clone())?);
         let semaphore = Arc::new(Semaphore::new(server_config.max_concurrent_requests));
-        let (task_handler, cedar_engine, approval_engine) =
-            create_governance_components(upstream.clone(), Some(&yaml_config), shutdown.clone())?;
+        let (task_handler, cedar_engine, approval_engine) = create_governance_components(
+            upstream.clone(),
+            Some(&yaml_config),
+            Some(Arc::new(LiveConfig::new(
+                yaml_config.runtime.clone().unwrap_or_default(),
+            ))),
+            shutdown.clone(),
+        )?;
 
         let state = Arc::new(McpState {
             upstream,
@@ -599,7 +846,10 @@ impl McpServer {
             approval_engine,
             semaphore,
             max_body_size: server_config.max_body_size,
+            json_limits: server_config.json_limits,
             capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Default::default(),
         });
 
         Ok(Self {
@@ -671,17 +921,42 @@ impl McpServer {
 ///
 /// Implements: REQ-CORE-003/§10 (Request Handler Pattern)
 async fn handle_mcp_request(State(state): State<Arc<McpState>>, body: Bytes) -> Response {
-    let (status, bytes) = handle_mcp_body_bytes(&state, body).await;
-    (status, [(header::CONTENT_TYPE, "application/json")], bytes).into_response()
+    json_response(handle_mcp_body_bytes(&state, body).await)
 }
 
-/// Handle a buffered MCP request body, returning (StatusCode, Bytes).
+/// Build a JSON response, adding `Retry-After` when the reply carries a hint.
+///
+/// Implements: REQ-CORE-004/F-003.4 (Retry Guidance)
+fn json_response(reply: McpReply) -> Response {
+    match reply.retry_after {
+        Some(secs) => (
+            reply.status,
+            [
+                (
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static("application/json"),
+                ),
+                (header::RETRY_AFTER, HeaderValue::from(secs)),
+            ],
+            reply.body,
+        )
+            .into_response(),
+        None => (
+            reply.status,
+            [(header::CONTENT_TYPE, "application/json")],
+            reply.body,
+        )
+            .into_response(),
+    }
+}
+
+/// Handle a buffered MCP request body, returning an [`McpReply`].
 ///
 /// This is the core MCP processing logic, used by both:
 /// - `McpHandler::handle()` (direct invocation from ProxyService)
 /// - `handle_mcp_request()` (Axum handler for standalone server)
 ///
-/// Returns `(StatusCode, Bytes)` to avoid double-buffering when ProxyService
+/// Returns the body as `Bytes` to avoid double-buffering when ProxyService
 /// converts to UnifiedBody.
 ///
 /// # Request Flow
@@ -694,7 +969,7 @@ async fn handle_mcp_request(State(state): State<Arc<McpState>>, body: Bytes) ->
 ///
 /// # Traceability
 /// - Implements: REQ-CORE-003/§10 (Request Handler Pattern)
-async fn handle_mcp_body_bytes(state: &McpState, body: Bytes) -> (StatusCode, Bytes) {
+async fn handle_mcp_body_bytes(state: &McpState, body: Bytes) -> McpReply {
     // Check body size limit (generate unique correlation ID per REQ-CORE-004)
     if body.len() > state.max_body_size {
         let correlation_id = uuid::Uuid::new_v4().to_string();
@@ -704,7 +979,18 @@ async fn handle_mcp_body_bytes(state: &McpState, body: Bytes) -> (StatusCode, By
                 state.max_body_size
             ),
         };
-        return error_bytes(None, &error, &correlation_id);
+        return error_bytes(None, &error, &correlation_id).into();
+    }
+
+    // Bound depth and size before the parser builds the body (EC-ERR-015)
+    if let Err(exceeded) = state.json_limits.check(&body) {
+        let correlation_id = uuid::Uuid::new_v4().to_string();
+        warn!(
+            correlation_id = %correlation_id,
+            reason = exceeded.reason_code(),
+            "{exceeded}"
+        );
+        return error_bytes(None, &exceeded.into(), &correlation_id).into();
     }
 
     // Try to acquire semaphore permit (EC-MCP-011)
@@ -730,7 +1016,7 @@ async fn handle_mcp_body_bytes(state: &McpState, body: Bytes) -> (StatusCode, By
                         br#"{"jsonrpc":"2.0","id":null,"error":{"code":-32013,"message":"Service temporarily unavailable"}}"#,
                     )
                 });
-            return (StatusCode::SERVICE_UNAVAILABLE, bytes);
+            return (StatusCode::SERVICE_UNAVAILABLE, bytes).into();
         }
     };
 
@@ -739,13 +1025,18 @@ async fn handle_mcp_body_bytes(state: &McpState, body: Bytes) -> (StatusCode, By
         Ok(p) => p,
         Err(e) => {
             let correlation_id = uuid::Uuid::new_v4().to_string();
-            return error_bytes(None, &e, &correlation_id);
+            // A single request that parsed as JSON but failed validation
+            // still has its id echoed (F-001.4)
+            let id = serde_json::from_slice::<serde_json::Value>(&body)
+                .ok()
+                .and_then(|value| request_id(&value));
+            return error_bytes(id, &e, &correlation_id).into();
         }
     };
 
     match parsed {
         ParsedRequests::Single(request) => handle_single_request_bytes(state, request).await,
-        ParsedRequests::Batch(requests) => handle_batch_request_bytes(state, requests).await,
+        ParsedRequests::Batch(requests) => handle_batch_request_bytes(state, requests).await.into(),
     }
 }
 
@@ -764,7 +1055,7 @@ async fn handle_mcp_body_bytes(state: &McpState, body: Bytes) -> (StatusCode, By
 /// - `prompts/get` → params.name (prompt name)
 ///
 /// Returns `None` for list methods or unsupported methods.
-fn extract_governable_name(request: &McpRequest) -> Option<String> {
+pub(crate) fn extract_governable_name(request: &McpRequest) -> Option<String> {
     let params = request.params.as_ref()?;
 
     match request.method.as_str() {
@@ -812,11 +1103,96 @@ fn extract_tool_arguments(request: &McpRequest) -> serde_json::Value {
         .unwrap_or(serde_json::json!({}))
 }
 
+/// Build the Cedar request for a governable MCP request.
+///
+/// Implements: REQ-POL-001/§6.1 (Policy Evaluation Request)
+///
+/// Returns the governed resource name (tool name or URI) alongside the
+/// request. `tools/call` carries its arguments for inspection; the other
+/// governable methods are evaluated as `McpMethod`.
+///
+/// # Errors
+///
+/// Returns `ThoughtGateError::InvalidParams` if the method's required
+/// identifier (`name` or `uri`) is missing.
+#[cfg(feature = "fuzzing")]
+pub fn build_cedar_request(
+    request: &McpRequest,
+    principal: crate::policy::Principal,
+    policy_id: &str,
+    source_id: &str,
+) -> Result<(String, CedarRequest), ThoughtGateError> {
+    build_cedar_request_impl(request, principal, policy_id, source_id)
+}
+
+#[cfg(not(feature = "fuzzing"))]
+fn build_cedar_request(
+    request: &McpRequest,
+    principal: crate::policy::Principal,
+    policy_id: &str,
+    source_id: &str,
+) -> Result<(String, CedarRequest), ThoughtGateError> {
+    build_cedar_request_impl(request, principal, policy_id, source_id)
+}
+
+fn build_cedar_request_impl(
+    request: &McpRequest,
+    principal: crate::policy::Principal,
+    policy_id: &str,
+    source_id: &str,
+) -> Result<(String, CedarRequest), ThoughtGateError> {
+    // Extract resource name for all governable methods
+    let Some(resource_name) = extract_governable_name(request) else {
+        // Governable method without required identifier is invalid params
+        let field = match request.method.as_str() {
+            "tools/call" | "prompts/get" => "name",
+            "resources/read" | "resources/subscribe" => "uri",
+            _ => "identifier",
+        };
+        return Err(ThoughtGateError::InvalidParams {
+            details: format!(
+                "Missing required field '{}' in {} params",
+                field, request.method
+            ),
+        });
+    };
+
+    // Build Cedar resource based on method type
+    let resource = if request.method == "tools/call" {
+        // tools/call includes arguments for fine-grained policy checks
+        CedarResource::ToolCall {
+            name: resource_name.clone(),
+            server: source_id.to_string(),
+            arguments: extract_tool_arguments(request),
+            attributes: BTreeMap::new(),
+        }
+    } else {
+        // resources/read, resources/subscribe, prompts/get use McpMethod
+        CedarResource::McpMethod {
+            method: request.method.clone(),
+            server: source_id.to_string(),
+            attributes: BTreeMap::new(),
+        }
+    };
+
+    let cedar_request = CedarRequest {
+        principal,
+        resource,
+        context: CedarContext {
+            policy_id: policy_id.to_string(),
+            source_id: source_id.to_string(),
+            time: TimeContext::now(),
+        },
+    };
+    Ok((resource_name, cedar_request))
+}
+
 /// Get source ID for the request.
 ///
-/// v0.2: Single upstream, hardcoded to "upstream".
-fn get_source_id(_state: &McpState) -> &'static str {
-    "upstream"
+/// Each routed handler is bound to one source, so this is the source the
+/// request will be forwarded to.
+fn get_source_id(state: &McpState) -> &str {
+    &state.source_id
 }
 
 // ============================================================================
@@ -946,6 +1322,12 @@ async fn handle_list_method(
         None => return Ok(response),
     };
 
+    // In monitor mode nothing is hidden or marked as needing a task:
+    // every call is forwarded
+    if is_monitoring(state) {
+        return Ok(response);
+    }
+
     let source_id = get_source_id(state);
 
     // Gate 1: Filter by visibility (ExposeConfig) for all list methods
@@ -1179,8 +1561,8 @@ fn validate_task_metadata(
 ///
 /// # Returns
 ///
-/// (StatusCode, Bytes) tuple with JSON-RPC result or error.
-async fn handle_single_request_bytes(state: &McpState, request: McpRequest) -> (StatusCode, Bytes) {
+/// The JSON-RPC result or error, with the reject code of a refusal.
+async fn handle_single_request_bytes(state: &McpState, request: McpRequest) -> McpReply {
     let correlation_id = request.correlation_id.to_string();
     let id = request.id.clone();
     let is_notification = request.is_notification();
@@ -1237,20 +1619,37 @@ async fn handle_single_request_bytes(state: &McpState, request: McpRequest) -> (
 
     // Handle notification - no response (empty body with 204)
     if is_notification {
-        if let Err(e) = result {
-            error!(
-                correlation_id = %correlation_id,
-                error = %e,
-                "Notification processing failed"
-            );
-        }
-        return (StatusCode::NO_CONTENT, Bytes::new());
+        drop_notification_result(&correlation_id, result);
+        return (StatusCode::NO_CONTENT, Bytes::new()).into();
     }
 
     // Return response
     match result {
-        Ok(response) => json_bytes(&response),
-        Err(e) => error_bytes(id, &e, &correlation_id),
+        Ok(response) => json_bytes(&response).into(),
+        Err(e) => McpReply {
+            reject_code: e.reject_code(),
+            retry_after: e.retry_after(),
+            ..error_bytes(id, &e, &correlation_id).into()
+        },
+    }
+}
+
+/// Discard the outcome of a notification, which gets no response.
+///
+/// Implements: REQ-CORE-003/F-001.3 (Notification Detection)
+///
+/// A refused notification is simply dropped: the gate that refused it has
+/// already written the audit record, and there is no ID to answer with.
+fn drop_notification_result(
+    correlation_id: &str,
+    result: Result<JsonRpcResponse, ThoughtGateError>,
+) {
+    if let Err(e) = result {
+        info!(
+            correlation_id = %correlation_id,
+            error = %e,
+            "Notification dropped"
+        );
     }
 }
 
@@ -1347,9 +1746,9 @@ async fn handle_task_method(
                     details: format!("Invalid tasks/list params: {}", e),
                 })?;
 
-            // Infer principal from environment (same as other task operations)
+            // Client identity or inferred principal (same as other task operations)
             let policy_principal =
-                infer_principal().map_err(|e| ThoughtGateError::ServiceUnavailable {
+                request_principal().map_err(|e| ThoughtGateError::ServiceUnavailable {
                     reason: format!("Failed to infer principal: {}", e),
                 })?;
             let principal = Principal::new(&policy_principal.app_name);
@@ -1423,12 +1822,13 @@ fn task_error_to_thoughtgate(error: crate::governance::TaskError) -> ThoughtGate
             ),
         },
         TaskError::RateLimited { retry_after, .. } => ThoughtGateError::RateLimited {
-            retry_after_secs: Some(retry_after.as_secs()),
+            retry_after_ms: Some(u64::try_from(retry_after.as_millis()).unwrap_or(u64::MAX)),
         },
         TaskError::ResultNotReady { task_id } => ThoughtGateError::TaskResultNotReady {
             task_id: task_id.to_string(),
+            retry_after_ms: None,
         },
-        TaskError::CapacityExceeded => ThoughtGateError::ServiceUnavailable {
+        TaskError::CapacityExceeded { .. } => ThoughtGateError::ServiceUnavailable {
             reason: "Task capacity exceeded".to_string(),
         },
         TaskError::Internal { details } => ThoughtGateError::ServiceUnavailable {
@@ -1472,6 +1872,10 @@ async fn route_through_gates(
             reason: "Configuration not loaded".to_string(),
         })?;
 
+    if let Some((body, calls)) = multi_calls(&request) {
+        return route_multi_call(state, request, body, calls).await;
+    }
+
     // Extract the governable resource name (tool name, resource URI, or prompt name)
     // Implements: REQ-CORE-003/F-002 (Method Routing)
     let resource_name = match extract_governable_name(&request) {
@@ -1521,10 +1925,22 @@ async fn route_through_gates(
                 source = %source_id,
                 "Gate 1: Resource not exposed"
             );
-            return Err(ThoughtGateError::ToolNotExposed {
-                tool: resource_name,
+            let error = ThoughtGateError::ToolNotExposed {
+                tool: resource_name.clone(),
                 source_id: source_id.to_string(),
-            });
+            };
+            let refusal = Refusal {
+                method: &request.method,
+                resource: &resource_name,
+                gate: AuditGate::Visibility,
+                rule: None,
+                reason: Some("not exposed".to_string()),
+                explanation: None,
+            };
+            return match enforce_refusal(state, refusal, error) {
+                Some(error) => Err(error),
+                None => state.upstream.forward(&request).await,
+            };
         }
         debug!(resource = %resource_name, method = %request.method, "Gate 1 passed: resource is visible");
     } else {
@@ -1544,6 +1960,7 @@ async fn route_through_gates(
     // Implements: REQ-CFG-001 Section 9.2 (Rule Matching)
 
     let match_result = config.governance.evaluate(&resource_name, source_id);
+    crate::logging_layer::record_decision(match_result.action);
 
     info!(
         resource = %resource_name,
@@ -1558,13 +1975,22 @@ async fn route_through_gates(
     // SEP-1686: Task Metadata Validation
     // ========================================================================
     // Validate that client sent params.task for actions that require it
-    // This is checked AFTER Gate 2 because we need to know the action first
-    validate_task_metadata(
-        &request,
-        &match_result.action,
-        &resource_name,
-        state.capability_cache.upstream_supports_tasks(),
-    )?;
+    // This is checked AFTER Gate 2 because we need to know the action first.
+    // Notifications get no response, so there is no task to augment. In
+    // monitor mode every request is forwarded, so none needs a task.
+    if !request.is_notification() {
+        let action = if is_monitoring(state) {
+            Action::Forward
+        } else {
+            match_result.action
+        };
+        validate_task_metadata(
+            &request,
+            &action,
+            &resource_name,
+            state.capability_cache.upstream_supports_tasks(),
+        )?;
+    }
 
     // ========================================================================
     // Route by Gate 2 Action
@@ -1574,16 +2000,40 @@ async fn route_through_gates(
         Action::Forward => {
             // Skip all policy checks, forward directly
             debug!(resource = %resource_name, "Gate 2: Forwarding directly to upstream");
+            audit_decision(
+                &request.method,
+                &resource_name,
+                AuditDecision::Forward,
+                AuditGate::Governance,
+                match_result.matched_rule.clone(),
+                None,
+            );
             state.upstream.forward(&request).await
         }
 
         Action::Deny => {
             // Immediate rejection
             warn!(resource = %resource_name, "Gate 2: Request denied by governance rule");
-            Err(ThoughtGateError::GovernanceRuleDenied {
-                tool: resource_name,
+            let error = ThoughtGateError::GovernanceRuleDenied {
+                tool: resource_name.clone(),
+                rule: match_result.matched_rule.clone(),
+            };
+            let refusal = Refusal {
+                method: &request.method,
+                resource: &resource_name,
+                gate: AuditGate::Governance,
                 rule: match_result.matched_rule,
-            })
+                reason: None,
+                explanation: None,
+            };
+            let Some(error) = enforce_refusal(state, refusal, error) else {
+                return state.upstream.forward(&request).await;
+            };
+            if let Some(result) = match_result.synthetic_response {
+                debug!(resource = %resource_name, "Gate 2: Returning synthetic response");
+                return Ok(JsonRpcResponse::success(request.id, result));
+            }
+            Err(error)
         }
 
         Action::Approve => {
@@ -1600,107 +2050,793 @@ async fn route_through_gates(
     }
 }
 
-/// Start an approval workflow (Gate 4).
+/// Decide whether a protocol upgrade may proceed.
 ///
-/// Implements: REQ-GOV-002/F-001, F-002 (Task creation and approval posting)
+/// Implements: REQ-CORE-001 F-004 (Protocol Upgrade Handling)
 ///
-/// Creates a task, posts the approval request, and returns a task ID response.
-/// The agent will poll for the result using tasks/get and tasks/result.
-async fn start_approval_flow(
-    state: &McpState,
-    request: McpRequest,
-    tool_name: &str,
-    match_result: &MatchResult,
-) -> Result<JsonRpcResponse, ThoughtGateError> {
-    let approval_engine =
-        state
-            .approval_engine
-            .as_ref()
-            .ok_or_else(|| ThoughtGateError::ServiceUnavailable {
-                reason: "Approval engine not configured".to_string(),
-            })?;
-
-    // Infer principal from environment
-    let policy_principal = infer_principal().map_err(|e| ThoughtGateError::ServiceUnavailable {
-        reason: format!("Failed to infer principal: {}", e),
-    })?;
-
-    // Create ToolCallRequest for the approval engine
-    // Convert transport JsonRpcId to governance JsonRpcId
-    let mcp_request_id = match request.id.clone() {
-        Some(JsonRpcId::Number(n)) => GovernanceJsonRpcId::Number(n),
-        Some(JsonRpcId::String(s)) => GovernanceJsonRpcId::String(s),
-        Some(JsonRpcId::Null) | None => GovernanceJsonRpcId::Null,
+/// Once upgraded, the connection is an opaque byte stream, so the decision is
+/// made once on the handshake, with the request path as the resource name
+/// and Cedar evaluating the `mcp/method` action on `upgrade/<protocol>`.
+/// See [`authorize_direct`] for how each gate applies.
+fn authorize_upgrade(state: &McpState, path: &str, protocol: &str) -> Result<(), ThoughtGateError> {
+    let method = format!("upgrade/{protocol}");
+    let resource = CedarResource::McpMethod {
+        method: method.clone(),
+        server: get_source_id(state).to_string(),
+        attributes: BTreeMap::new(),
     };
+    match authorize_direct(state, &method, path, resource)? {
+        None => Ok(()),
+        Some(match_result) => gate2_refusal(state, &method, path, match_result).map_or(Ok(()), Err),
+    }
+}
 
-    let tool_request = ToolCallRequest {
-        method: request.method.clone(),
-        name: tool_name.to_string(),
-        arguments: extract_tool_arguments(&request),
-        mcp_request_id,
+/// Decide whether a call on a non-JSON-RPC transport may proceed.
+///
+/// Implements: REQ-CORE-008/F-002 (Transport Call Governance)
+///
+/// `tools/call` calls are evaluated as Cedar `ToolCall` resources; any
+/// other method as an `McpMethod`. See [`authorize_direct`].
+///
+/// A call that needs approval can only wait for it in blocking mode: the
+/// body is still with the client, so there is nothing to replay later.
+/// The call is held, unread, until the decision; an approved call is
+/// returned as a [`StreamClaim`] and the caller streams it upstream.
+///
+/// Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+async fn authorize_call(
+    state: &McpState,
+    call: &GovernedCall,
+) -> Result<Option<StreamClaim>, ThoughtGateError> {
+    let server = get_source_id(state).to_string();
+    let resource = if call.method == "tools/call" {
+        CedarResource::ToolCall {
+            name: call.resource.clone(),
+            server,
+            arguments: call.arguments.clone(),
+            attributes: BTreeMap::new(),
+        }
+    } else {
+        CedarResource::McpMethod {
+            method: call.method.clone(),
+            server,
+            attributes: BTreeMap::new(),
+        }
     };
-
-    // Create Principal for governance
-    let principal = Principal::new(&policy_principal.app_name);
-
-    // Look up workflow-specific timeout from config
-    let workflow_timeout = match_result
-        .approval_workflow
+    let Some(match_result) = authorize_direct(state, &call.method, &call.resource, resource)?
+    else {
+        return Ok(None);
+    };
+    let Some(engine) = state
+        .approval_engine
         .as_ref()
-        .and_then(|workflow_name| {
-            state
-                .config
-                .as_ref()
-                .and_then(|c| c.get_workflow(workflow_name))
-                .map(|w| w.timeout_or_default())
-        });
-
-    // Start the approval workflow with workflow-specific timeout
-    let result = approval_engine
-        .start_approval(tool_request, principal, workflow_timeout)
-        .await
-        .map_err(|e| ThoughtGateError::ServiceUnavailable {
-            reason: format!("Failed to start approval: {}", e),
-        })?;
+        .filter(|engine| approval_mode(state, engine, &match_result) == ApprovalMode::Blocking)
+    else {
+        return gate2_refusal(state, &call.method, &call.resource, match_result)
+            .map_or(Ok(None), Err);
+    };
 
+    let tool_request = ToolCallRequest {
+        method: call.method.clone(),
+        name: call.resource.clone(),
+        arguments: call.arguments.clone(),
+        mcp_request_id: GovernanceJsonRpcId::Null,
+    };
+    let result = begin_approval(state, tool_request, &call.resource, &match_result).await?;
     info!(
         task_id = %result.task_id,
-        tool = %tool_name,
+        tool = %call.resource,
         workflow = ?match_result.approval_workflow,
-        timeout_secs = ?workflow_timeout.map(|d| d.as_secs()),
-        "Gate 4: Approval workflow started"
+        "Gate 4: Holding streamed call for approval"
     );
-
-    // Return SEP-1686 task response
-    Ok(JsonRpcResponse::task_created(
-        request.id.clone(),
-        result.task_id.to_string(),
-        result.status.to_string(),
-        result.poll_interval,
-    ))
+    let claim = engine.claim_stream(&result.task_id, &state.cedar_engine);
+    let claim = wait_blocking(engine, &result.task_id, &call.resource, claim).await?;
+    Ok(Some(claim))
 }
 
-/// Evaluate a request against Cedar policy engine (Gate 3).
-///
-/// Implements: REQ-POL-001/F-001 (Policy Evaluation)
-///
-/// Evaluates Cedar policy for governable MCP methods:
-/// - `tools/call` → uses CedarResource::ToolCall with name and arguments
-/// - `resources/read`, `resources/subscribe`, `prompts/get` → uses CedarResource::McpMethod
-///
-/// List methods (`tools/list`, `resources/list`, `prompts/list`) are forwarded to
-/// upstream without Cedar evaluation since they don't reference a specific resource.
-/// Response filtering for list methods is a v0.3+ enhancement.
-///
-/// Cedar decisions:
-/// - Permit → forward to upstream (or to Gate 4 if approval workflow specified)
-/// - Forbid → return PolicyDenied error
-///
-/// # Arguments
+/// Run a request decided before its body is read through Gates 1-3.
 ///
-/// * `state` - Application state
-/// * `request` - The MCP request
-/// * `match_result` - Optional Gate 2 result (provides policy_id and approval_workflow)
+/// - Gate 1: `resource_name` must be exposed by the source
+/// - Gate 2: `forward` permits, `deny` refuses, and `approve` returns the
+///   match so the caller can decide whether it is able to wait for a human
+///   decision
+/// - Gate 3: `policy` (or no YAML config) evaluates Cedar on `resource`
+fn authorize_direct(
+    state: &McpState,
+    method: &str,
+    resource_name: &str,
+    mut resource: CedarResource,
+) -> Result<Option<MatchResult>, ThoughtGateError> {
+    let source_id = get_source_id(state);
+    let mut policy_id = "default".to_string();
+
+    if let Some(config) = state.config.as_ref() {
+        if let Some(source) = config.get_source(source_id)
+            && !source.expose().is_visible(resource_name)
+        {
+            warn!(resource = %resource_name, method = %method, "Gate 1: Resource not exposed");
+            let error = ThoughtGateError::ToolNotExposed {
+                tool: resource_name.to_string(),
+                source_id: source_id.to_string(),
+            };
+            let refusal = Refusal {
+                method,
+                resource: resource_name,
+                gate: AuditGate::Visibility,
+                rule: None,
+                reason: Some("not exposed".to_string()),
+                explanation: None,
+            };
+            return enforce_refusal(state, refusal, error).map_or(Ok(None), Err);
+        }
+
+        let match_result = config.governance.evaluate(resource_name, source_id);
+        crate::logging_layer::record_decision(match_result.action);
+        match match_result.action {
+            Action::Forward => {
+                debug!(resource = %resource_name, method = %method, "Gate 2: Forwarded");
+                audit_decision(
+                    method,
+                    resource_name,
+                    AuditDecision::Forward,
+                    AuditGate::Governance,
+                    match_result.matched_rule,
+                    None,
+                );
+                return Ok(None);
+            }
+            Action::Approve if is_monitoring(state) => {
+                monitor_approval(method, resource_name, match_result.matched_rule);
+                return Ok(None);
+            }
+            Action::Approve => return Ok(Some(match_result)),
+            Action::Deny => {
+                return gate2_refusal(state, method, resource_name, match_result)
+                    .map_or(Ok(None), Err);
+            }
+            Action::Policy => {
+                if let Some(id) = match_result.policy_id {
+                    policy_id = id;
+                }
+            }
+        }
+    }
+
+    let principal = request_principal().map_err(|e| ThoughtGateError::ServiceUnavailable {
+        reason: format!("Failed to infer principal: {}", e),
+    })?;
+    state.cedar_engine.enrich(&mut resource);
+    let cedar_request = CedarRequest {
+        principal,
+        resource,
+        context: CedarContext {
+            policy_id: policy_id.clone(),
+            source_id: source_id.to_string(),
+            time: TimeContext::now(),
+        },
+    };
+
+    let decision = state.cedar_engine.evaluate_v2(&cedar_request);
+    // Operator-only: explanations go to the audit trail, never the client
+    let explanation = (audit::is_enabled() && explain::is_enabled())
+        .then(|| state.cedar_engine.explain_v2(&cedar_request, &decision));
+    match decision {
+        CedarDecision::Permit { .. } => {
+            audit_decision_explained(
+                method,
+                resource_name,
+                AuditDecision::Forward,
+                AuditGate::Policy,
+                Some(policy_id),
+                None,
+                explanation,
+            );
+            Ok(None)
+        }
+        CedarDecision::Forbid { code, reason, .. } => {
+            warn!(
+                resource = %resource_name,
+                method = %method,
+                policy_id = %policy_id,
+                code = code.as_str(),
+                reason = %reason,
+                "Gate 3: Cedar forbid"
+            );
+            let error = ThoughtGateError::PolicyDenied {
+                tool: resource_name.to_string(),
+                policy_id: Some(policy_id.clone()),
+                client_message: reject_message(state, code, &reason),
+                internal_reason: Some(reason.clone()),
+                code: Some(code),
+            };
+            let refusal = Refusal {
+                method,
+                resource: resource_name,
+                gate: AuditGate::Policy,
+                rule: Some(policy_id),
+                reason: Some(reason),
+                explanation,
+            };
+            enforce_refusal(state, refusal, error).map_or(Ok(None), Err)
+        }
+    }
+}
+
+/// Refuse a request decided before its body is read at Gate 2: denied, or
+/// sent for approval when its caller cannot wait for the decision.
+///
+/// Returns `None` in monitor mode (see [`enforce_refusal`]).
+fn gate2_refusal(
+    state: &McpState,
+    method: &str,
+    resource_name: &str,
+    match_result: MatchResult,
+) -> Option<ThoughtGateError> {
+    warn!(
+        resource = %resource_name,
+        method = %method,
+        action = %match_result.action,
+        "Gate 2: Refused"
+    );
+    let error = ThoughtGateError::GovernanceRuleDenied {
+        tool: resource_name.to_string(),
+        rule: match_result.matched_rule.clone(),
+    };
+    let refusal = Refusal {
+        method,
+        resource: resource_name,
+        gate: AuditGate::Governance,
+        rule: match_result.matched_rule,
+        reason: None,
+        explanation: None,
+    };
+    enforce_refusal(state, refusal, error)
+}
+
+/// The JSON-RPC body and calls of a `tools/call` carrying several calls,
+/// or `None` for any other request.
+///
+/// Implements: REQ-CORE-003/F-007 (Multi-Call Requests)
+fn multi_calls(request: &McpRequest) -> Option<(serde_json::Value, Vec<ToolCallEntry>)> {
+    if request.method != "tools/call" {
+        return None;
+    }
+    let body = serde_json::to_value(request.to_jsonrpc_request()).ok()?;
+    let calls = MultiCallShape.calls(&body)?;
+    Some((body, calls))
+}
+
+/// Govern a `tools/call` carrying several calls, one call at a time.
+///
+/// Implements: REQ-CORE-003/F-007 (Multi-Call Requests)
+///
+/// Each call runs through Gates 1-3 like a request decided before its
+/// body is read (see [`authorize_direct`]). A call that needs approval is
+/// refused: one request cannot wait on a human decision per call. When
+/// only some calls are permitted, `governance.defaults.multi_call` decides
+/// whether the request is refused with the first refusal or forwarded with
+/// the permitted calls alone.
+async fn route_multi_call(
+    state: &McpState,
+    mut request: McpRequest,
+    mut body: serde_json::Value,
+    calls: Vec<ToolCallEntry>,
+) -> Result<JsonRpcResponse, ThoughtGateError> {
+    let source_id = get_source_id(state);
+    let mut refusal = None;
+    let keep: Vec<bool> = calls
+        .into_iter()
+        .map(|call| {
+            let resource = CedarResource::ToolCall {
+                name: call.name.clone(),
+                server: source_id.to_string(),
+                arguments: call.arguments,
+                attributes: BTreeMap::new(),
+            };
+            let error = match authorize_direct(state, &request.method, &call.name, resource) {
+                Ok(None) => return true,
+                Ok(Some(match_result)) => {
+                    match gate2_refusal(state, &request.method, &call.name, match_result) {
+                        Some(error) => error,
+                        None => return true,
+                    }
+                }
+                Err(error) => error,
+            };
+            refusal.get_or_insert(error);
+            false
+        })
+        .collect();
+
+    let Some(error) = refusal else {
+        return state.upstream.forward(&request).await;
+    };
+    let mode = state
+        .config
+        .as_ref()
+        .map(|config| config.governance.defaults.multi_call)
+        .unwrap_or_default();
+    let permitted = keep.iter().filter(|&&k| k).count();
+    if mode == MultiCallMode::RejectAny || permitted == 0 {
+        return Err(error);
+    }
+
+    info!(
+        permitted = permitted,
+        refused = keep.len() - permitted,
+        "Forwarding the permitted calls of a multi-call request"
+    );
+    MultiCallShape.retain(&mut body, &keep);
+    request.params = body.get_mut("params").map(serde_json::Value::take);
+    state.upstream.forward(&request).await
+}
+
+/// What the client is told about a Cedar denial.
+///
+/// Implements: REQ-CORE-004/NFR-002 (Security - No Data Leaks)
+///
+/// The detailed `reason` is only returned for codes listed in
+/// `governance.defaults.disclose_reasons`; it always goes to the logs and
+/// audit trail.
+fn reject_message(state: &McpState, code: RejectCode, reason: &str) -> String {
+    state.config.as_ref().map_or_else(
+        || DEFAULT_REJECT_MESSAGE.to_string(),
+        |config| config.governance.defaults.client_message(code, reason),
+    )
+}
+
+/// Write an audit record for a final Gate 1-3 decision.
+///
+/// Implements: REQ-OBS-002 (Audit Trail)
+///
+/// Gate 4 outcomes are recorded by the task store when the approval is
+/// decided.
+fn audit_decision(
+    method: &str,
+    resource: &str,
+    decision: AuditDecision,
+    gate: AuditGate,
+    rule: Option<String>,
+    reason: Option<String>,
+) {
+    audit_decision_explained(method, resource, decision, gate, rule, reason, None);
+}
+
+/// [`audit_decision`], with the Cedar explanation when one was produced.
+fn audit_decision_explained(
+    method: &str,
+    resource: &str,
+    decision: AuditDecision,
+    gate: AuditGate,
+    rule: Option<String>,
+    reason: Option<String>,
+    explanation: Option<Explanation>,
+) {
+    if !audit::is_enabled() {
+        return;
+    }
+    write_audit(
+        AuditRecord::new(method, resource, decision, gate)
+            .with_rule(rule)
+            .with_reason(reason)
+            .with_explanation(explanation),
+    );
+}
+
+/// A gate's refusal of a request, as audited.
+struct Refusal<'a> {
+    method: &'a str,
+    resource: &'a str,
+    gate: AuditGate,
+    rule: Option<String>,
+    reason: Option<String>,
+    explanation: Option<Explanation>,
+}
+
+/// Write an audit record for a refusal, tagged with the reject code of the
+/// `error` the client is sent (or, in monitor mode, would have been).
+///
+/// Implements: REQ-OBS-002 (Audit Trail)
+fn audit_refusal(refusal: Refusal<'_>, decision: AuditDecision, error: &ThoughtGateError) {
+    if !audit::is_enabled() {
+        return;
+    }
+    write_audit(
+        AuditRecord::new(refusal.method, refusal.resource, decision, refusal.gate)
+            .with_rule(refusal.rule)
+            .with_reason(refusal.reason)
+            .with_explanation(refusal.explanation)
+            .with_reject_code(error.reject_code()),
+    );
+}
+
+/// Whether gate decisions are only recorded (`enforcement: monitor`).
+///
+/// Implements: REQ-CFG-001 Section 9 (Request Processing)
+fn is_monitoring(state: &McpState) -> bool {
+    state
+        .config
+        .as_ref()
+        .is_some_and(|config| config.enforcement == Enforcement::Monitor)
+}
+
+/// Enforce a Gate 1-3 refusal: audit it and return `error` for the client.
+///
+/// In monitor mode the refusal is audited as `would_reject` instead, and
+/// `None` is returned: the caller forwards the request.
+fn enforce_refusal(
+    state: &McpState,
+    refusal: Refusal<'_>,
+    error: ThoughtGateError,
+) -> Option<ThoughtGateError> {
+    if !is_monitoring(state) {
+        audit_refusal(refusal, AuditDecision::Deny, &error);
+        return Some(error);
+    }
+    info!(
+        resource = %refusal.resource,
+        method = %refusal.method,
+        gate = ?refusal.gate,
+        "Monitor mode: forwarding a request that would be refused"
+    );
+    if let Some(metrics) = crate::metrics::get_monitor_metrics() {
+        metrics.record_decision("would_reject");
+    }
+    audit_refusal(refusal, AuditDecision::WouldReject, &error);
+    None
+}
+
+/// Record a request that would have been held for approval, in monitor
+/// mode. No approval is requested; the caller forwards the request.
+fn monitor_approval(method: &str, resource: &str, rule: Option<String>) {
+    info!(
+        resource = %resource,
+        method = %method,
+        "Monitor mode: forwarding a request that would need approval"
+    );
+    if let Some(metrics) = crate::metrics::get_monitor_metrics() {
+        metrics.record_decision("would_approve");
+    }
+    audit_decision(
+        method,
+        resource,
+        AuditDecision::WouldApprove,
+        AuditGate::Approval,
+        rule,
+        None,
+    );
+}
+
+/// Write `record` on behalf of the current request's principal.
+fn write_audit(mut record: AuditRecord) {
+    if let Ok(principal) = request_principal() {
+        record = record.with_principal(principal.app_name);
+    }
+    audit::record(record);
+}
+
+/// Start an approval workflow (Gate 4).
+///
+/// Implements: REQ-GOV-002/F-001, F-002 (Task creation and approval posting)
+///
+/// Creates a task and posts the approval request. In async mode (default)
+/// returns a task ID response and the agent polls with tasks/get and
+/// tasks/result. In blocking mode the connection is held until the decision
+/// and the replayed result is returned directly.
+///
+/// The mode comes from the workflow's `mode`, falling back to the engine's
+/// `THOUGHTGATE_APPROVAL_MODE`.
+async fn start_approval_flow(
+    state: &McpState,
+    request: McpRequest,
+    tool_name: &str,
+    match_result: &MatchResult,
+) -> Result<JsonRpcResponse, ThoughtGateError> {
+    if is_monitoring(state) {
+        monitor_approval(
+            &request.method,
+            tool_name,
+            match_result.matched_rule.clone(),
+        );
+        return state.upstream.forward(&request).await;
+    }
+
+    // A notification has no ID to return a task or result for, and its
+    // sender is not waiting for one
+    if request.is_notification() {
+        warn!(
+            resource = %tool_name,
+            method = %request.method,
+            "Gate 4: Notification cannot await approval"
+        );
+        let error = ThoughtGateError::GovernanceRuleDenied {
+            tool: tool_name.to_string(),
+            rule: match_result.matched_rule.clone(),
+        };
+        let refusal = Refusal {
+            method: &request.method,
+            resource: tool_name,
+            gate: AuditGate::Approval,
+            rule: match_result.matched_rule.clone(),
+            reason: Some("notification cannot await approval".to_string()),
+            explanation: None,
+        };
+        audit_refusal(refusal, AuditDecision::Deny, &error);
+        return Err(error);
+    }
+
+    // Convert transport JsonRpcId to governance JsonRpcId
+    let mcp_request_id = match request.id.clone() {
+        Some(JsonRpcId::Number(n)) => GovernanceJsonRpcId::Number(n),
+        Some(JsonRpcId::String(s)) => GovernanceJsonRpcId::String(s),
+        Some(JsonRpcId::Null) | None => GovernanceJsonRpcId::Null,
+    };
+
+    let tool_request = ToolCallRequest {
+        method: request.method.clone(),
+        name: tool_name.to_string(),
+        arguments: extract_tool_arguments(&request),
+        mcp_request_id,
+    };
+
+    let result = begin_approval(state, tool_request, tool_name, match_result).await?;
+    let Some(approval_engine) = state.approval_engine.as_ref() else {
+        return Err(ThoughtGateError::ServiceUnavailable {
+            reason: "Approval engine not configured".to_string(),
+        });
+    };
+
+    // Keep the request as received for replay once approved (REQ-GOV-002/F-006)
+    if let Some(canonical) = CanonicalRequest::capture(&request)
+        && let Err(e) = approval_engine
+            .task_store()
+            .set_canonical_request(&result.task_id, canonical)
+    {
+        warn!(task_id = %result.task_id, error = %e, "Failed to store request for replay");
+    }
+
+    let mode = approval_mode(state, approval_engine, match_result);
+    info!(
+        task_id = %result.task_id,
+        tool = %tool_name,
+        workflow = ?match_result.approval_workflow,
+        mode = ?mode,
+        "Gate 4: Approval workflow started"
+    );
+
+    if mode == ApprovalMode::Blocking {
+        let wait = approval_engine.await_result(&result.task_id);
+        let tool_result = wait_blocking(approval_engine, &result.task_id, tool_name, wait).await?;
+        return Ok(JsonRpcResponse::success(
+            request.id.clone(),
+            serde_json::to_value(tool_result).map_err(|e| {
+                ThoughtGateError::ServiceUnavailable {
+                    reason: format!("Failed to serialize approval result: {}", e),
+                }
+            })?,
+        ));
+    }
+
+    // Return SEP-1686 task response
+    Ok(JsonRpcResponse::task_created(
+        request.id.clone(),
+        result.task_id.to_string(),
+        result.status.to_string(),
+        result.poll_interval,
+    ))
+}
+
+/// Create the approval task for `tool_request` and post it to approvers.
+///
+/// Implements: REQ-GOV-002/F-001, F-002 (Task creation and approval posting)
+///
+/// The timeout comes from the matched workflow and per-tool overrides,
+/// falling back to the engine's.
+async fn begin_approval(
+    state: &McpState,
+    tool_request: ToolCallRequest,
+    tool_name: &str,
+    match_result: &MatchResult,
+) -> Result<ApprovalStartResult, ThoughtGateError> {
+    let approval_engine =
+        state
+            .approval_engine
+            .as_ref()
+            .ok_or_else(|| ThoughtGateError::ServiceUnavailable {
+                reason: "Approval engine not configured".to_string(),
+            })?;
+
+    // Client identity, falling back to the environment
+    let policy_principal =
+        request_principal().map_err(|e| ThoughtGateError::ServiceUnavailable {
+            reason: format!("Failed to infer principal: {}", e),
+        })?;
+
+    // Create Principal for governance
+    let principal = Principal::new(&policy_principal.app_name);
+
+    // Look up workflow-specific timeout from config
+    let workflow = approval_workflow(state, match_result);
+    // Overrides name the configured source, also when it is the only one
+    let workflow_timeout = state.config.as_ref().and_then(|config| {
+        let server = match config.sources.as_slice() {
+            [source] => source.id(),
+            _ => state.source_id.as_str(),
+        };
+        config.approval_timeout(workflow, server, tool_name)
+    });
+    debug!(
+        tool = %tool_name,
+        timeout_secs = ?workflow_timeout.map(|d| d.as_secs()),
+        "Gate 4: Starting approval"
+    );
+
+    // Start the approval workflow with workflow-specific timeout
+    approval_engine
+        .start_approval(tool_request, principal, workflow_timeout, workflow)
+        .await
+        .map_err(|e| match e {
+            ApprovalEngineError::Undeliverable { task_id, .. } => {
+                ThoughtGateError::ApprovalUndeliverable {
+                    tool: tool_name.to_string(),
+                    task_id: task_id.to_string(),
+                }
+            }
+            ApprovalEngineError::BacklogFull { retry_after } => {
+                ThoughtGateError::ApprovalBacklogFull {
+                    tool: tool_name.to_string(),
+                    retry_after_ms: u64::try_from(retry_after.as_millis()).unwrap_or(u64::MAX),
+                }
+            }
+            e => ThoughtGateError::ServiceUnavailable {
+                reason: format!("Failed to start approval: {}", e),
+            },
+        })
+}
+
+/// The workflow named by a Gate 2 match, if configured.
+fn approval_workflow<'a>(
+    state: &'a McpState,
+    match_result: &MatchResult,
+) -> Option<&'a HumanWorkflow> {
+    match_result
+        .approval_workflow
+        .as_ref()
+        .and_then(|workflow_name| {
+            state
+                .config
+                .as_ref()
+                .and_then(|c| c.get_workflow(workflow_name))
+        })
+}
+
+/// The workflow's `mode`, falling back to the engine's.
+fn approval_mode(
+    state: &McpState,
+    engine: &ApprovalEngine,
+    match_result: &MatchResult,
+) -> ApprovalMode {
+    approval_workflow(state, match_result)
+        .and_then(|w| w.mode)
+        .unwrap_or(engine.config().mode)
+}
+
+/// Wait on `wait` for a blocking-mode approval.
+///
+/// Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+///
+/// The wait ends by the request's deadline, if it has one. The task is
+/// abandoned if the deadline passes or the client disconnects first.
+async fn wait_blocking<T>(
+    engine: &Arc<ApprovalEngine>,
+    task_id: &TaskId,
+    tool: &str,
+    wait: impl Future<Output = Result<T, ThoughtGateError>>,
+) -> Result<T, ThoughtGateError> {
+    let guard = AbandonOnDisconnect {
+        engine: engine.clone(),
+        task_id: Some(task_id.clone()),
+        tool: tool.to_string(),
+    };
+    let outcome = match crate::deadline::current() {
+        Some(deadline) => match tokio::time::timeout_at(deadline.instant(), wait).await {
+            Ok(outcome) => outcome,
+            Err(_) => {
+                guard.deadline_passed();
+                return Err(ThoughtGateError::DeadlineExceeded {
+                    budget_ms: deadline.budget().as_millis() as u64,
+                });
+            }
+        },
+        None => wait.await,
+    };
+    guard.completed();
+    outcome
+}
+
+/// Abandons a blocking-mode approval if the wait is dropped before the
+/// decision arrives, which happens when the client disconnects, or when
+/// the request's deadline passes first.
+///
+/// Implements: REQ-GOV-002/F-002.3 (Blocking approval mode)
+struct AbandonOnDisconnect {
+    engine: Arc<ApprovalEngine>,
+    task_id: Option<TaskId>,
+    tool: String,
+}
+
+impl AbandonOnDisconnect {
+    /// The wait finished; keep the task.
+    fn completed(mut self) {
+        self.task_id = None;
+    }
+
+    /// The request's deadline passed before the decision; the client is
+    /// still there, so this is not counted as a disconnect.
+    fn deadline_passed(mut self) {
+        let Some(task_id) = self.task_id.take() else {
+            return;
+        };
+        warn!(
+            task_id = %task_id,
+            tool = %self.tool,
+            "Request deadline passed during blocking approval, abandoning task"
+        );
+        spawn_abandon(self.engine.clone(), task_id);
+    }
+}
+
+impl Drop for AbandonOnDisconnect {
+    fn drop(&mut self) {
+        let Some(task_id) = self.task_id.take() else {
+            return;
+        };
+        warn!(
+            task_id = %task_id,
+            tool = %self.tool,
+            "Client disconnected during blocking approval, abandoning task"
+        );
+        if let Some(metrics) = crate::metrics::get_disconnect_metrics() {
+            metrics.record_abandoned_approval(&self.tool);
+        }
+        spawn_abandon(self.engine.clone(), task_id);
+    }
+}
+
+/// Abandon `task_id` in the background, so it completes even though the
+/// request waiting on it is going away.
+fn spawn_abandon(engine: Arc<ApprovalEngine>, task_id: TaskId) {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) => {
+            handle.spawn(async move { engine.abandon(&task_id).await });
+        }
+        Err(_) => {
+            let _ = engine.task_store().abandon(&task_id);
+        }
+    }
+}
+
+/// Evaluate a request against Cedar policy engine (Gate 3).
+///
+/// Implements: REQ-POL-001/F-001 (Policy Evaluation)
+///
+/// Evaluates Cedar policy for governable MCP methods:
+/// - `tools/call` → uses CedarResource::ToolCall with name and arguments
+/// - `resources/read`, `resources/subscribe`, `prompts/get` → uses CedarResource::McpMethod
+///
+/// List methods (`tools/list`, `resources/list`, `prompts/list`) are forwarded to
+/// upstream without Cedar evaluation since they don't reference a specific resource.
+/// Response filtering for list methods is a v0.3+ enhancement.
+///
+/// Cedar decisions:
+/// - Permit → forward to upstream (or to Gate 4 if approval workflow specified)
+/// - Forbid → return PolicyDenied error
+///
+/// # Arguments
+///
+/// * `state` - Application state
+/// * `request` - The MCP request
+/// * `match_result` - Optional Gate 2 result (provides policy_id and approval_workflow)
 async fn evaluate_with_cedar(
     state: &McpState,
     request: McpRequest,
@@ -1716,65 +2852,55 @@ async fn evaluate_with_cedar(
         return state.upstream.forward(&request).await;
     }
 
-    // Extract resource name for all governable methods
-    let resource_name = match extract_governable_name(&request) {
-        Some(name) => name,
-        None => {
-            // Governable method without required identifier is invalid params
-            let field = match request.method.as_str() {
-                "tools/call" | "prompts/get" => "name",
-                "resources/read" | "resources/subscribe" => "uri",
-                _ => "identifier",
-            };
-            return Err(ThoughtGateError::InvalidParams {
-                details: format!(
-                    "Missing required field '{}' in {} params",
-                    field, request.method
-                ),
-            });
-        }
-    };
+    if let Some((body, calls)) = multi_calls(&request) {
+        return route_multi_call(state, request, body, calls).await;
+    }
 
-    // Infer principal from environment
-    let policy_principal = infer_principal().map_err(|e| ThoughtGateError::ServiceUnavailable {
-        reason: format!("Failed to infer principal: {}", e),
-    })?;
+    // Client identity, falling back to the environment
+    let policy_principal =
+        request_principal().map_err(|e| ThoughtGateError::ServiceUnavailable {
+            reason: format!("Failed to infer principal: {}", e),
+        })?;
 
     // Get policy_id and source_id from Gate 2 result, or use defaults
     let policy_id = match_result
         .and_then(|m| m.policy_id.clone())
         .unwrap_or_else(|| "default".to_string());
-    let source_id = get_source_id(state).to_string();
 
-    // Build Cedar resource based on method type
-    let cedar_resource = if request.method == "tools/call" {
-        // tools/call includes arguments for fine-grained policy checks
-        CedarResource::ToolCall {
-            name: resource_name.clone(),
-            server: source_id.clone(),
-            arguments: extract_tool_arguments(&request),
-        }
-    } else {
-        // resources/read, resources/subscribe, prompts/get use McpMethod
-        CedarResource::McpMethod {
-            method: request.method.clone(),
-            server: source_id.clone(),
-        }
-    };
+    let (resource_name, mut cedar_request) =
+        build_cedar_request(&request, policy_principal, &policy_id, get_source_id(state))?;
 
-    // Build Cedar request
-    let cedar_request = CedarRequest {
-        principal: policy_principal,
-        resource: cedar_resource,
-        context: CedarContext {
-            policy_id: policy_id.clone(),
-            source_id,
-            time: TimeContext::now(),
-        },
-    };
+    // Attach catalog attributes so policies can use `resource.<key>`
+    state.cedar_engine.enrich(&mut cedar_request.resource);
 
     // Evaluate Cedar policy
-    match state.cedar_engine.evaluate_v2(&cedar_request) {
+    let decision = state.cedar_engine.evaluate_v2(&cedar_request);
+    // Operator-only: explanations go to the audit trail, never the client
+    let explanation = (audit::is_enabled() && explain::is_enabled())
+        .then(|| state.cedar_engine.explain_v2(&cedar_request, &decision));
+
+    // Content classifiers can only make a permit stricter
+    let mut escalated = false;
+    let decision = match decision {
+        CedarDecision::Permit { .. } => {
+            let verdict = classify_permitted(state, &request, &cedar_request).await;
+            match verdict.outcome {
+                ClassificationOutcome::Clean => decision,
+                ClassificationOutcome::Approve { .. } => {
+                    escalated = true;
+                    decision
+                }
+                ClassificationOutcome::Reject { reason } => CedarDecision::Forbid {
+                    code: RejectCode::Classifier,
+                    reason: format!("{}: {}", verdict.classifier, reason),
+                    policy_ids: Vec::new(),
+                },
+            }
+        }
+        forbid => forbid,
+    };
+
+    match decision {
         CedarDecision::Permit { .. } => {
             // For action: policy, Cedar permit → Gate 4 (approval workflow)
             // Per REQ-CORE-003 Section 8: policy action always goes to Gate 4 after permit
@@ -1794,6 +2920,23 @@ async fn evaluate_with_cedar(
                 return start_approval_flow(state, request, &resource_name, match_result).await;
             }
 
+            // A classifier wants a human to look first
+            if escalated {
+                debug!(
+                    resource = %resource_name,
+                    method = %request.method,
+                    "Gate 3: Classifier escalation → Gate 4 approval"
+                );
+                let match_result = MatchResult {
+                    action: Action::Approve,
+                    policy_id: Some(policy_id),
+                    approval_workflow: None,
+                    matched_rule: None,
+                    synthetic_response: None,
+                };
+                return start_approval_flow(state, request, &resource_name, &match_result).await;
+            }
+
             // Legacy mode (no Gate 2 result): Cedar permit → forward to upstream
             debug!(
                 resource = %resource_name,
@@ -1801,26 +2944,87 @@ async fn evaluate_with_cedar(
                 policy_id = %policy_id,
                 "Gate 3: Cedar permit (legacy mode) - forwarding to upstream"
             );
+            audit_decision_explained(
+                &request.method,
+                &resource_name,
+                AuditDecision::Forward,
+                AuditGate::Policy,
+                Some(policy_id),
+                None,
+                explanation,
+            );
             state.upstream.forward(&request).await
         }
-        CedarDecision::Forbid { reason, .. } => {
+        CedarDecision::Forbid { code, reason, .. } => {
             // Cedar forbid → return PolicyDenied error
             warn!(
                 resource = %resource_name,
                 method = %request.method,
                 policy_id = %policy_id,
+                code = code.as_str(),
                 reason = %reason,
                 "Gate 3: Cedar forbid - denying request"
             );
-            Err(ThoughtGateError::PolicyDenied {
-                tool: resource_name,
-                policy_id: Some(policy_id),
+            let error = ThoughtGateError::PolicyDenied {
+                tool: resource_name.clone(),
+                policy_id: Some(policy_id.clone()),
+                client_message: reject_message(state, code, &reason),
+                internal_reason: Some(reason.clone()),
+                code: Some(code),
+            };
+            let refusal = Refusal {
+                method: &request.method,
+                resource: &resource_name,
+                gate: AuditGate::Policy,
+                rule: Some(policy_id),
                 reason: Some(reason),
-            })
+                explanation,
+            };
+            let Some(error) = enforce_refusal(state, refusal, error) else {
+                return state.upstream.forward(&request).await;
+            };
+            if let Some(result) = match_result.and_then(|m| m.synthetic_response.clone()) {
+                debug!(resource = %resource_name, "Gate 3: Returning synthetic response");
+                return Ok(JsonRpcResponse::success(request.id, result));
+            }
+            Err(error)
         }
     }
 }
 
+/// Run the content classifiers on a request Cedar permitted.
+///
+/// Classifiers see the request as JSON-RPC, re-serialized from the parsed
+/// request. A request that cannot be serialized is rejected, so a
+/// classifier is never skipped.
+///
+/// # Traceability
+/// - Implements: REQ-CORE-006 (Content Classification)
+async fn classify_permitted(
+    state: &McpState,
+    request: &McpRequest,
+    cedar_request: &CedarRequest,
+) -> Verdict {
+    let classifiers = state.classifiers.load_full();
+    if classifiers.is_empty() {
+        return Verdict::clean();
+    }
+    let body = match serde_json::to_vec(&request.to_jsonrpc_request()) {
+        Ok(body) => body,
+        Err(e) => {
+            return Verdict {
+                classifier: "none",
+                outcome: ClassificationOutcome::Reject {
+                    reason: format!("request could not be classified: {e}"),
+                },
+            };
+        }
+    };
+    classifiers
+        .classify(&cedar_request.policy_view(), &body)
+        .await
+}
+
 /// Handle a batch JSON-RPC request, returning (StatusCode, Bytes).
 ///
 /// Implements: REQ-CORE-003/F-007 (Batch Request Handling)
@@ -1898,13 +3102,7 @@ async fn handle_batch_request_bytes(
 
                 // F-007.4: Notifications don't produce response entries
                 if is_notification {
-                    if let Err(e) = result {
-                        error!(
-                            correlation_id = %correlation_id,
-                            error = %e,
-                            "Notification in batch failed"
-                        );
-                    }
+                    drop_notification_result(&correlation_id, result);
                     continue;
                 }
 
@@ -2027,7 +3225,10 @@ mod tests {
             approval_engine: None,
             semaphore: Arc::new(Semaphore::new(100)),
             max_body_size: 1024 * 1024,
+            json_limits: JsonLimits::default(),
             capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Default::default(),
         })
     }
 
@@ -2198,7 +3399,10 @@ mod tests {
             approval_engine: None,
             semaphore: Arc::new(Semaphore::new(0)), // No permits available
             max_body_size: 1024 * 1024,
+            json_limits: JsonLimits::default(),
             capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Default::default(),
         });
 
         let router = Router::new()
@@ -2236,31 +3440,94 @@ mod tests {
             config: None,
             approval_engine: None,
             semaphore: Arc::new(Semaphore::new(100)),
-            max_body_size: 10, // Very small limit
+            max_body_size: 10, // Very small limit
+            json_limits: JsonLimits::default(),
+            capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Default::default(),
+        });
+
+        // Router with DefaultBodyLimit disabled - we check size manually and return JSON-RPC error
+        let router = Router::new()
+            .route("/mcp/v1", post(handle_mcp_request))
+            .layer(DefaultBodyLimit::disable())
+            .with_state(state);
+
+        let body = r#"{"jsonrpc":"2.0","id":1,"method":"test"}"#; // Exceeds 10 bytes
+        let request = Request::builder()
+            .method("POST")
+            .uri("/mcp/v1")
+            .header("Content-Type", "application/json")
+            .body(Body::from(body))
+            .expect("should build request");
+
+        let response = router.oneshot(request).await.expect("should get response");
+        // EC-MCP-004: Returns JSON-RPC error, not HTTP 413
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_body(response).await;
+        assert!(body.contains("-32600")); // InvalidRequest error code
+        assert!(body.contains("exceeds maximum size"));
+    }
+
+    /// Verifies: EC-ERR-015 (JSON depth and size limits)
+    #[tokio::test]
+    async fn test_json_limits_reject_before_parse() {
+        let task_store = Arc::new(TaskStore::with_defaults());
+        let task_handler = TaskHandler::new(task_store);
+        let cedar_engine = Arc::new(CedarEngine::new().expect("Failed to create Cedar engine"));
+
+        let state = Arc::new(McpState {
+            upstream: Arc::new(MockUpstream),
+            router: McpRouter::new(),
+            task_handler,
+            cedar_engine,
+            config: None,
+            approval_engine: None,
+            semaphore: Arc::new(Semaphore::new(100)),
+            max_body_size: 1024 * 1024,
+            json_limits: JsonLimits {
+                max_depth: 8,
+                max_bytes: 512,
+            },
             capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Default::default(),
         });
-
-        // Router with DefaultBodyLimit disabled - we check size manually and return JSON-RPC error
         let router = Router::new()
             .route("/mcp/v1", post(handle_mcp_request))
-            .layer(DefaultBodyLimit::disable())
             .with_state(state);
 
-        let body = r#"{"jsonrpc":"2.0","id":1,"method":"test"}"#; // Exceeds 10 bytes
-        let request = Request::builder()
-            .method("POST")
-            .uri("/mcp/v1")
-            .header("Content-Type", "application/json")
-            .body(Body::from(body))
-            .expect("should build request");
-
-        let response = router.oneshot(request).await.expect("should get response");
-        // EC-MCP-004: Returns JSON-RPC error, not HTTP 413
-        assert_eq!(response.status(), StatusCode::OK);
+        let nested = format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{{"name":"t","arguments":{}1{}}}}}}}"#,
+            "[".repeat(100),
+            "]".repeat(100)
+        );
+        let oversized = format!(
+            r#"{{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{{"name":"t","arguments":{{"blob":"{}"}}}}}}"#,
+            "x".repeat(1000)
+        );
 
-        let body = response_body(response).await;
-        assert!(body.contains("-32600")); // InvalidRequest error code
-        assert!(body.contains("exceeds maximum size"));
+        for (body, reason) in [
+            (nested, "json_depth_exceeded"),
+            (oversized, "json_size_exceeded"),
+        ] {
+            let request = Request::builder()
+                .method("POST")
+                .uri("/mcp/v1")
+                .header("Content-Type", "application/json")
+                .body(Body::from(body))
+                .expect("should build request");
+
+            let response = router
+                .clone()
+                .oneshot(request)
+                .await
+                .expect("should get response");
+            let body = response_body(response).await;
+            assert!(body.contains("-32010"), "{body}");
+            assert!(body.contains(reason), "{body}");
+        }
     }
 
     /// Verifies: EC-MCP-013 (Integer ID preserved)
@@ -2571,7 +3838,10 @@ mod tests {
             approval_engine: None,
             semaphore: Arc::new(Semaphore::new(100)),
             max_body_size: 1024 * 1024,
+            json_limits: JsonLimits::default(),
             capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Default::default(),
         })
     }
 
@@ -2824,7 +4094,10 @@ mod tests {
             approval_engine: None,
             semaphore: Arc::new(Semaphore::new(100)),
             max_body_size: 1024 * 1024,
+            json_limits: JsonLimits::default(),
             capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Default::default(),
         })
     }
 
@@ -3006,4 +4279,760 @@ mod tests {
         // ID should be preserved
         assert_eq!(parsed["id"], "init-123");
     }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Retry-After hints (REQ-CORE-004/F-003.4)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[tokio::test]
+    async fn test_rate_limited_response_has_retry_after() {
+        let error = ThoughtGateError::RateLimited {
+            retry_after_ms: Some(1_500),
+        };
+        let (status, bytes) = error_bytes(None, &error, "test-correlation");
+
+        let parsed: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(parsed["error"]["data"]["retry_after_ms"], 1_500);
+
+        let response = json_response(McpReply {
+            retry_after: error.retry_after(),
+            ..(status, bytes).into()
+        });
+        // Rounded up so the client never retries before a slot frees
+        assert_eq!(response.headers()[header::RETRY_AFTER], "2");
+    }
+
+    #[tokio::test]
+    async fn test_pending_result_retry_after_within_timeout() {
+        let request = ToolCallRequest {
+            method: "tools/call".to_string(),
+            name: "delete_user".to_string(),
+            arguments: serde_json::json!({}),
+            mcp_request_id: GovernanceJsonRpcId::Number(1),
+        };
+        let task = TaskStore::with_defaults()
+            .create(
+                request.clone(),
+                request,
+                Principal::new("test-app"),
+                Some(std::time::Duration::from_secs(120)),
+                crate::governance::TimeoutAction::default(),
+            )
+            .unwrap();
+        let error = ThoughtGateError::TaskResultNotReady {
+            task_id: task.id.to_string(),
+            retry_after_ms: Some(u64::try_from(task.remaining_ttl().as_millis()).unwrap()),
+        };
+
+        let (status, bytes) = error_bytes(None, &error, "test-correlation");
+        let response = json_response(McpReply {
+            retry_after: error.retry_after(),
+            ..(status, bytes).into()
+        });
+        let secs: u64 = response.headers()[header::RETRY_AFTER]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!((1..=120).contains(&secs), "retry after {secs}s");
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_header_absent_without_hint() {
+        let state = create_test_state();
+        let reply = handle_mcp_body_bytes(&state, Bytes::from_static(b"{not json")).await;
+        assert!(reply.retry_after.is_none());
+        assert!(
+            json_response(reply)
+                .headers()
+                .get(header::RETRY_AFTER)
+                .is_none()
+        );
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Audit trail (REQ-OBS-002)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    fn create_test_state_with_rules(yaml: &str) -> Arc<McpState> {
+        create_test_state_with_rules_and_upstream(yaml, Arc::new(MockUpstream))
+    }
+
+    fn create_test_state_with_rules_and_upstream(
+        yaml: &str,
+        upstream: Arc<dyn UpstreamForwarder>,
+    ) -> Arc<McpState> {
+        let config: Config = serde_saphyr::from_str(yaml).expect("should parse config");
+        let task_store = Arc::new(TaskStore::with_defaults());
+        let classifiers =
+            ClassifierPipeline::from_config(&config.classifiers).expect("valid classifiers");
+
+        Arc::new(McpState {
+            upstream,
+            router: McpRouter::new(),
+            task_handler: TaskHandler::new(task_store),
+            cedar_engine: Arc::new(CedarEngine::new().expect("Failed to create Cedar engine")),
+            config: Some(Arc::new(config)),
+            approval_engine: None,
+            semaphore: Arc::new(Semaphore::new(100)),
+            max_body_size: 1024 * 1024,
+            json_limits: JsonLimits::default(),
+            capability_cache: Arc::new(CapabilityCache::new()),
+            source_id: DEFAULT_SOURCE_ID.to_string(),
+            classifiers: Arc::new(ArcSwap::from_pointee(classifiers)),
+        })
+    }
+
+    async fn call_tool(state: Arc<McpState>, tool: &str) -> serde_json::Value {
+        let router = Router::new()
+            .route("/mcp/v1", post(handle_mcp_request))
+            .with_state(state);
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {"name": tool, "arguments": {}}
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/mcp/v1")
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string()))
+            .expect("should build request");
+
+        let response = router.oneshot(request).await.expect("should get response");
+        serde_json::from_str(&response_body(response).await).expect("should parse response")
+    }
+
+    const AUDIT_RULES: &str = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://mcp-server:8080
+governance:
+  defaults:
+    action: forward
+  rules:
+    - match: "audit_reject_*"
+      action: deny
+"#;
+
+    /// Verifies: REQ-OBS-002 (forwarded request is audited)
+    #[tokio::test]
+    async fn test_audit_records_forward() {
+        crate::audit::testing::install();
+        let state = create_test_state_with_rules(AUDIT_RULES);
+
+        let parsed = call_tool(state, "audit_forward_tool").await;
+        assert!(parsed["result"].is_object());
+
+        let records = crate::audit::testing::records_for("audit_forward_tool");
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.version, crate::audit::AUDIT_SCHEMA_VERSION);
+        assert_eq!(record.method, "tools/call");
+        assert_eq!(record.decision, AuditDecision::Forward);
+        assert_eq!(record.gate, AuditGate::Governance);
+        assert!(record.approver.is_none());
+    }
+
+    /// Verifies: REQ-OBS-002 (refused request is audited with its rule)
+    #[tokio::test]
+    async fn test_audit_records_reject() {
+        crate::audit::testing::install();
+        let state = create_test_state_with_rules(AUDIT_RULES);
+
+        let parsed = call_tool(state, "audit_reject_tool").await;
+        assert!(parsed["error"].is_object());
+
+        let records = crate::audit::testing::records_for("audit_reject_tool");
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.decision, AuditDecision::Deny);
+        assert_eq!(record.gate, AuditGate::Governance);
+        assert_eq!(record.rule.as_deref(), Some("audit_reject_*"));
+        assert_eq!(
+            record.reject_code.as_deref(),
+            Some("governance_rule_denied")
+        );
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Notifications (REQ-CORE-003/F-001.3)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Records the resource of each forwarded request.
+    #[derive(Default)]
+    struct RecordingUpstream(std::sync::Mutex<Vec<String>>);
+
+    #[async_trait::async_trait]
+    impl UpstreamForwarder for RecordingUpstream {
+        async fn forward(&self, request: &McpRequest) -> Result<JsonRpcResponse, ThoughtGateError> {
+            let resource = extract_governable_name(request).unwrap_or(request.method.clone());
+            self.0.lock().unwrap().push(resource);
+            Ok(JsonRpcResponse::success(
+                request.id.clone(),
+                serde_json::json!({"mock": "response"}),
+            ))
+        }
+
+        async fn forward_batch(
+            &self,
+            _requests: &[McpRequest],
+        ) -> Result<Vec<JsonRpcResponse>, ThoughtGateError> {
+            unreachable!("batch items are forwarded one by one")
+        }
+    }
+
+    const NOTIFICATION_RULES: &str = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://mcp-server:8080
+governance:
+  defaults:
+    action: forward
+  rules:
+    - match: "notify_deny_*"
+      action: deny
+    - match: "notify_approve_*"
+      action: approve
+"#;
+
+    fn tool_call(id: Option<u64>, tool: &str) -> serde_json::Value {
+        let mut call = serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "tools/call",
+            "params": {"name": tool, "arguments": {}}
+        });
+        if let Some(id) = id {
+            call["id"] = id.into();
+        }
+        call
+    }
+
+    async fn post_mcp(state: Arc<McpState>, body: serde_json::Value) -> (StatusCode, String) {
+        let router = Router::new()
+            .route("/mcp/v1", post(handle_mcp_request))
+            .with_state(state);
+        let request = Request::builder()
+            .method("POST")
+            .uri("/mcp/v1")
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string()))
+            .expect("should build request");
+        let response = router.oneshot(request).await.expect("should get response");
+        let status = response.status();
+        (status, response_body(response).await)
+    }
+
+    /// Verifies: REQ-CORE-003/F-001.3 (forwarded notification gets no response)
+    #[tokio::test]
+    async fn test_notification_forwarded() {
+        crate::audit::testing::install();
+        let upstream = Arc::new(RecordingUpstream::default());
+        let state = create_test_state_with_rules_and_upstream(NOTIFICATION_RULES, upstream.clone());
+
+        let (status, body) = post_mcp(state, tool_call(None, "notify_forward_tool")).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(body.is_empty());
+        assert_eq!(*upstream.0.lock().unwrap(), ["notify_forward_tool"]);
+
+        let records = crate::audit::testing::records_for("notify_forward_tool");
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].decision, AuditDecision::Forward);
+    }
+
+    /// Verifies: REQ-CORE-003/F-001.3, REQ-OBS-002 (refused notification is
+    /// dropped and audited)
+    #[tokio::test]
+    async fn test_notification_denied_only_audited() {
+        crate::audit::testing::install();
+        let upstream = Arc::new(RecordingUpstream::default());
+        let state = create_test_state_with_rules_and_upstream(NOTIFICATION_RULES, upstream.clone());
+
+        for (tool, gate) in [
+            ("notify_deny_tool", AuditGate::Governance),
+            ("notify_approve_tool", AuditGate::Approval),
+        ] {
+            let (status, body) = post_mcp(state.clone(), tool_call(None, tool)).await;
+            assert_eq!(status, StatusCode::NO_CONTENT, "{tool}");
+            assert!(body.is_empty(), "{tool}: {body}");
+
+            let records = crate::audit::testing::records_for(tool);
+            assert_eq!(records.len(), 1, "{tool}");
+            assert_eq!(records[0].decision, AuditDecision::Deny);
+            assert_eq!(records[0].gate, gate);
+        }
+        assert!(upstream.0.lock().unwrap().is_empty());
+        assert_eq!(state.task_handler.store().total_count(), 0);
+    }
+
+    /// Verifies: REQ-CORE-003/F-007.4 (notifications in a batch)
+    #[tokio::test]
+    async fn test_batch_mixing_notifications_and_requests() {
+        let upstream = Arc::new(RecordingUpstream::default());
+        let state = create_test_state_with_rules_and_upstream(NOTIFICATION_RULES, upstream.clone());
+
+        let batch = serde_json::json!([
+            tool_call(Some(1), "notify_batch_a"),
+            tool_call(None, "notify_deny_batch_b"),
+            tool_call(None, "notify_batch_c"),
+            tool_call(Some(2), "notify_deny_batch_d"),
+        ]);
+        let (status, body) = post_mcp(state, batch).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let responses: Vec<serde_json::Value> = serde_json::from_str(&body).unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0]["id"], 1);
+        assert!(responses[0]["result"].is_object());
+        assert_eq!(responses[1]["id"], 2);
+        assert!(responses[1]["error"].is_object());
+        assert_eq!(
+            *upstream.0.lock().unwrap(),
+            ["notify_batch_a", "notify_batch_c"]
+        );
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Request IDs (REQ-CORE-003/F-001.4)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    const ID_RULES: &str = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://mcp-server:8080
+governance:
+  defaults:
+    action: deny
+"#;
+
+    /// Verifies: REQ-CORE-003/F-001.4 (each id type is echoed, uncoerced, in
+    /// a rejection)
+    #[tokio::test]
+    async fn test_rejection_echoes_request_id() {
+        let state = create_test_state_with_rules(ID_RULES);
+
+        for (id, echoed) in [
+            (serde_json::json!(7), r#""id":7"#),
+            (
+                serde_json::json!(9_007_199_254_740_993_i64),
+                r#""id":9007199254740993"#,
+            ),
+            (serde_json::json!(-1), r#""id":-1"#),
+            (serde_json::json!("7"), r#""id":"7""#),
+            (serde_json::json!(""), r#""id":"""#),
+            (serde_json::Value::Null, r#""id":null"#),
+        ] {
+            let mut call = tool_call(None, "delete_everything");
+            call["id"] = id.clone();
+            let (status, body) = post_mcp(state.clone(), call).await;
+            assert_eq!(status, StatusCode::OK, "{id}");
+
+            let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+            assert!(parsed["error"].is_object(), "{body}");
+            assert_eq!(parsed["id"], id);
+            assert!(body.contains(echoed), "{echoed} not in {body}");
+        }
+
+        // No id: a notification, so nothing is sent back
+        let (status, body) = post_mcp(state, tool_call(None, "delete_everything")).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(body.is_empty());
+    }
+
+    /// Verifies: REQ-CORE-003/F-001.4 (an invalid request still has its id
+    /// echoed; an invalid id is reported as null)
+    #[tokio::test]
+    async fn test_invalid_request_echoes_request_id() {
+        let state = create_test_state_with_rules(ID_RULES);
+
+        for (id, echoed) in [
+            (serde_json::json!(7), serde_json::json!(7)),
+            (serde_json::json!("7"), serde_json::json!("7")),
+            (serde_json::Value::Null, serde_json::Value::Null),
+            (serde_json::json!(1.5), serde_json::Value::Null),
+            (serde_json::json!({"n": 1}), serde_json::Value::Null),
+        ] {
+            // No method
+            let call = serde_json::json!({"jsonrpc": "2.0", "id": id});
+            let (_, body) = post_mcp(state.clone(), call).await;
+
+            let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+            assert_eq!(parsed["error"]["code"], -32600, "{body}");
+            assert_eq!(parsed.get("id"), Some(&echoed), "{body}");
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Monitor mode (REQ-CFG-001 Section 9)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    const MONITOR_RULES: &str = r#"
+schema: 1
+enforcement: monitor
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://mcp-server:8080
+governance:
+  defaults:
+    action: forward
+  rules:
+    - match: "monitor_deny_*"
+      action: deny
+    - match: "monitor_approve_*"
+      action: approve
+"#;
+
+    /// Verifies: REQ-CFG-001 Section 9, REQ-OBS-002 (monitor mode forwards a
+    /// would-be-rejected request and audits it)
+    #[tokio::test]
+    async fn test_monitor_mode_forwards_would_be_rejected() {
+        crate::audit::testing::install();
+        let counted = || {
+            crate::metrics::testing::counter(
+                "thoughtgate_monitor_decisions_total",
+                "decision",
+                "would_reject",
+            )
+        };
+        let before = counted();
+        let upstream = Arc::new(RecordingUpstream::default());
+        let state = create_test_state_with_rules_and_upstream(MONITOR_RULES, upstream.clone());
+
+        let (status, body) = post_mcp(state, tool_call(Some(1), "monitor_deny_tool")).await;
+        assert_eq!(status, StatusCode::OK);
+        let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(parsed["result"]["mock"], "response", "{body}");
+        assert_eq!(*upstream.0.lock().unwrap(), ["monitor_deny_tool"]);
+
+        let records = crate::audit::testing::records_for("monitor_deny_tool");
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.decision, AuditDecision::WouldReject);
+        assert_eq!(record.gate, AuditGate::Governance);
+        assert_eq!(record.rule.as_deref(), Some("monitor_deny_*"));
+        assert_eq!(
+            record.reject_code.as_deref(),
+            Some("governance_rule_denied")
+        );
+        assert!(counted() >= before + 1.0);
+    }
+
+    /// Verifies: REQ-CFG-001 Section 9 (monitor mode forwards a request that
+    /// needs approval without requesting it)
+    #[tokio::test]
+    async fn test_monitor_mode_forwards_without_approval() {
+        crate::audit::testing::install();
+        let upstream = Arc::new(RecordingUpstream::default());
+        let state = create_test_state_with_rules_and_upstream(MONITOR_RULES, upstream.clone());
+
+        // No params.task: nothing is held, so none is needed
+        let (_, body) = post_mcp(state.clone(), tool_call(Some(1), "monitor_approve_tool")).await;
+        let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(parsed["result"]["mock"], "response", "{body}");
+        assert_eq!(*upstream.0.lock().unwrap(), ["monitor_approve_tool"]);
+        assert_eq!(state.task_handler.store().total_count(), 0);
+
+        let records = crate::audit::testing::records_for("monitor_approve_tool");
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].decision, AuditDecision::WouldApprove);
+        assert_eq!(records[0].gate, AuditGate::Approval);
+        assert_eq!(records[0].rule.as_deref(), Some("monitor_approve_*"));
+    }
+
+    /// Verifies: REQ-CFG-001 Section 9 (decisions are enforced by default)
+    #[tokio::test]
+    async fn test_enforce_mode_refuses() {
+        let upstream = Arc::new(RecordingUpstream::default());
+        let yaml = MONITOR_RULES.replace("enforcement: monitor", "enforcement: enforce");
+        let state = create_test_state_with_rules_and_upstream(&yaml, upstream.clone());
+
+        let (_, body) = post_mcp(state, tool_call(Some(1), "monitor_deny_enforced")).await;
+        let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert!(parsed["error"].is_object(), "{body}");
+        assert!(upstream.0.lock().unwrap().is_empty());
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Deny reason disclosure (REQ-CORE-004/NFR-002)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    const POLICY_RULES: &str = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://mcp-server:8080
+governance:
+  defaults:
+    action: policy
+"#;
+
+    /// Send a task-augmented `tools/call` that no Cedar policy permits.
+    async fn call_default_denied_tool(yaml: &str) -> serde_json::Value {
+        unsafe {
+            std::env::set_var("THOUGHTGATE_DEV_MODE", "true");
+            std::env::set_var(
+                "THOUGHTGATE_POLICIES",
+                r#"permit(principal == ThoughtGate::App::"other-app", action, resource);"#,
+            );
+        }
+        let state = create_test_state_with_rules(yaml);
+
+        let router = Router::new()
+            .route("/mcp/v1", post(handle_mcp_request))
+            .with_state(state);
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {"name": "delete_user", "arguments": {}, "task": {"ttl": 600_000}}
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/mcp/v1")
+            .header("Content-Type", "application/json")
+            .body(Body::from(body.to_string()))
+            .expect("should build request");
+
+        let response = router.oneshot(request).await.expect("should get response");
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_DEV_MODE");
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+        serde_json::from_str(&response_body(response).await).expect("should parse response")
+    }
+
+    /// Verifies: REQ-CORE-004/NFR-002 (Cedar deny reason not returned to client)
+    #[tokio::test]
+    #[serial]
+    async fn test_policy_denied_hides_internal_reason() {
+        let parsed = call_default_denied_tool(POLICY_RULES).await;
+
+        assert_eq!(parsed["error"]["code"], -32003);
+        assert_eq!(
+            parsed["error"]["data"]["details"], DEFAULT_REJECT_MESSAGE,
+            "{parsed}"
+        );
+        let body = parsed.to_string();
+        assert!(!body.contains("default-deny"), "{body}");
+        assert!(!body.contains("No policy permits"), "{body}");
+    }
+
+    /// Verifies: REQ-CORE-004/NFR-002 (operator opts a reject code into disclosure)
+    #[tokio::test]
+    #[serial]
+    async fn test_policy_denied_discloses_allowed_code() {
+        let yaml = POLICY_RULES.replace(
+            "    action: policy\n",
+            "    action: policy\n    disclose_reasons: [default_deny]\n",
+        );
+        let parsed = call_default_denied_tool(&yaml).await;
+
+        assert_eq!(parsed["error"]["code"], -32003);
+        assert_eq!(
+            parsed["error"]["data"]["details"],
+            "No policy permits this action (default-deny)"
+        );
+
+        // Other codes stay hidden
+        let yaml = POLICY_RULES.replace(
+            "    action: policy\n",
+            "    action: policy\n    disclose_reasons: [forbidden, denylist]\n",
+        );
+        let parsed = call_default_denied_tool(&yaml).await;
+        assert_eq!(parsed["error"]["data"]["details"], DEFAULT_REJECT_MESSAGE);
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Synthetic responses for denied requests
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Verifies: REQ-CFG-001 Section 7.4 (deny rule answers with its synthetic result)
+    #[tokio::test]
+    async fn test_deny_rule_returns_synthetic_response() {
+        let yaml = r#"
+schema: 1
+sources:
+  - id: upstream
+    kind: mcp
+    url: http://mcp-server:8080
+governance:
+  defaults:
+    action: forward
+  rules:
+    - match: "search"
+      action: deny
+      synthetic_response:
+        content: []
+        structuredContent: {results: []}
+    - match: "delete_*"
+      action: deny
+"#;
+        let state = create_test_state_with_rules(yaml);
+
+        let parsed = call_tool(state.clone(), "search").await;
+        assert_eq!(parsed["id"], 1);
+        assert!(parsed.get("error").is_none(), "{parsed}");
+        assert_eq!(
+            parsed["result"],
+            serde_json::json!({"content": [], "structuredContent": {"results": []}})
+        );
+
+        // Rules without one still return an error
+        let parsed = call_tool(state, "delete_user").await;
+        assert!(parsed["error"].is_object(), "{parsed}");
+    }
+
+    /// Verifies: REQ-CFG-001 Section 7.4 (Cedar forbid answers with the rule's synthetic result)
+    #[tokio::test]
+    #[serial]
+    async fn test_policy_forbid_returns_synthetic_response() {
+        let yaml = format!(
+            "{POLICY_RULES}  rules:\n    - match: delete_user\n      action: policy\n      \
+             policy_id: default\n      synthetic_response: {{content: [{{type: text, text: skipped}}]}}\n"
+        );
+        let parsed = call_default_denied_tool(&yaml).await;
+
+        assert!(parsed.get("error").is_none(), "{parsed}");
+        assert_eq!(parsed["result"]["content"][0]["text"], "skipped");
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Content classifiers (REQ-CORE-006)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Verifies: REQ-CORE-006 (classifier refuses a call Cedar permits)
+    #[tokio::test]
+    #[serial]
+    async fn test_classifier_rejects_permitted_call() {
+        let yaml = format!(
+            "{POLICY_RULES}    disclose_reasons: [classifier]\nclassifiers:\n  - type: regex\n    \
+             action: reject\n    patterns: [secret]\n"
+        );
+        unsafe {
+            std::env::set_var("THOUGHTGATE_DEV_MODE", "true");
+            std::env::set_var(
+                "THOUGHTGATE_POLICIES",
+                "permit(principal, action, resource);",
+            );
+        }
+        let state = create_test_state_with_rules(&yaml);
+        let call = |tool: &str| {
+            let mut call = tool_call(Some(1), tool);
+            call["params"]["task"] = serde_json::json!({"ttl": 600_000});
+            let state = state.clone();
+            async move {
+                let (_, body) = post_mcp(state, call).await;
+                serde_json::from_str::<serde_json::Value>(&body).expect("should parse response")
+            }
+        };
+
+        let rejected = call("read_secret").await;
+        let other = call("read_public").await;
+        unsafe {
+            std::env::remove_var("THOUGHTGATE_DEV_MODE");
+            std::env::remove_var("THOUGHTGATE_POLICIES");
+        }
+
+        assert_eq!(rejected["error"]["code"], -32003, "{rejected}");
+        assert_eq!(
+            rejected["error"]["data"]["details"],
+            "regex: body matches pattern 'secret'"
+        );
+        // Clean calls continue to Gate 4 as before
+        assert_ne!(other["error"]["code"], -32003, "{other}");
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Multi-call requests (REQ-CORE-003/F-007)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Answers each request with the params it was forwarded with.
+    struct EchoUpstream;
+
+    #[async_trait::async_trait]
+    impl UpstreamForwarder for EchoUpstream {
+        async fn forward(&self, request: &McpRequest) -> Result<JsonRpcResponse, ThoughtGateError> {
+            Ok(JsonRpcResponse::success(
+                request.id.clone(),
+                request.params.clone().unwrap_or_default(),
+            ))
+        }
+
+        async fn forward_batch(
+            &self,
+            _requests: &[McpRequest],
+        ) -> Result<Vec<JsonRpcResponse>, ThoughtGateError> {
+            unreachable!("batch items are forwarded one by one")
+        }
+    }
+
+    fn multi_call_rules(mode: &str) -> String {
+        format!(
+            "schema: 1\nsources:\n  - id: upstream\n    kind: mcp\n    url: http://mcp-server:8080\n\
+             governance:\n  defaults:\n    action: forward\n    multi_call: {mode}\n  rules:\n\
+             \x20   - match: \"delete_*\"\n      action: deny\n"
+        )
+    }
+
+    async fn post_multi_call(state: Arc<McpState>, names: &[&str]) -> serde_json::Value {
+        let calls: Vec<_> = names
+            .iter()
+            .map(|name| serde_json::json!({"name": name, "arguments": {"path": "/tmp"}}))
+            .collect();
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {"calls": calls}
+        });
+        let (_, body) = post_mcp(state, body).await;
+        serde_json::from_str(&body).expect("should parse response")
+    }
+
+    /// Verifies: REQ-CORE-003/F-007 (any refused call rejects the request)
+    #[tokio::test]
+    async fn test_multi_call_reject_any() {
+        let state = create_test_state_with_rules_and_upstream(
+            &multi_call_rules("reject_any"),
+            Arc::new(EchoUpstream),
+        );
+
+        let permitted = post_multi_call(state.clone(), &["read_file", "write_file"]).await;
+        assert_eq!(
+            permitted["result"]["calls"].as_array().map(Vec::len),
+            Some(2)
+        );
+
+        let mixed = post_multi_call(state, &["read_file", "delete_file"]).await;
+        assert_eq!(mixed["error"]["code"], -32014, "{mixed}");
+    }
+
+    /// Verifies: REQ-CORE-003/F-007 (split forwards only permitted calls)
+    #[tokio::test]
+    async fn test_multi_call_split() {
+        let state = create_test_state_with_rules_and_upstream(
+            &multi_call_rules("split"),
+            Arc::new(EchoUpstream),
+        );
+
+        let mixed =
+            post_multi_call(state.clone(), &["delete_file", "read_file", "delete_dir"]).await;
+        let calls = mixed["result"]["calls"].as_array().expect("should forward");
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0]["name"], "read_file");
+        assert_eq!(calls[0]["arguments"]["path"], "/tmp");
+
+        // Nothing left to forward
+        let refused = post_multi_call(state, &["delete_file", "delete_dir"]).await;
+        assert_eq!(refused["error"]["code"], -32014, "{refused}");
+    }
 }
@@ -15,22 +15,34 @@
 //!
 //! Errors are classified into ThoughtGateError variants:
 //! - Timeout errors → UpstreamTimeout (-32001)
+//! - Failed TLS handshakes → UpstreamTlsFailed (-32000), counted in
+//!   `upstream_tls_failures_total` and written to the audit trail
 //! - Connection errors → UpstreamConnectionFailed (-32000)
 //! - Other errors → UpstreamError (-32002)
 //!
 //! # Security
 //!
 //! - TLS certificate verification is enabled by default
+//! - A source's `tls.pin_ca` trusts only its `ca_file`, not the system roots
 //! - TLS 1.2+ enforced by rustls (no TLS 1.0/1.1 support, preventing downgrade attacks)
 //! - No automatic retry (prevents duplicate side effects)
 
+use std::sync::Arc;
 use std::time::Duration;
 
+use http::header::{CONTENT_TYPE, HeaderMap, HeaderValue};
 use reqwest::Client;
 use tracing::{debug, error, warn};
 
-use crate::error::ThoughtGateError;
-use crate::transport::jsonrpc::{JsonRpcResponse, McpRequest};
+use crate::audit::{self, AuditDecision, AuditGate, AuditRecord};
+use crate::config::{Source, SourceHeaders, SourceTls};
+use crate::error::{ThoughtGateError, TlsFailure};
+use crate::governance::CanonicalRequest;
+use crate::header_rules::HeaderRules;
+use crate::latency;
+use crate::logging_layer::{REQUEST_ID_HEADER, current_request_id};
+use crate::transport::jsonrpc::{JsonRpcResponse, McpRequest, ParsedRequests, parse_jsonrpc};
+use crate::transport::server::extract_governable_name;
 
 /// Configuration for the upstream client.
 ///
@@ -47,6 +59,10 @@ pub struct UpstreamConfig {
     pub pool_max_idle_per_host: usize,
     /// Idle connection timeout
     pub pool_idle_timeout: Duration,
+    /// Extra CA roots and client identity for this upstream
+    pub tls: Option<SourceTls>,
+    /// Header mutation rules for requests to this upstream
+    pub headers: Option<SourceHeaders>,
 }
 
 impl Default for UpstreamConfig {
@@ -57,6 +73,8 @@ impl Default for UpstreamConfig {
             connect_timeout: Duration::from_secs(5),
             pool_max_idle_per_host: 32,
             pool_idle_timeout: Duration::from_secs(90),
+            tls: None,
+            headers: None,
         }
     }
 }
@@ -84,6 +102,21 @@ impl UpstreamConfig {
                 details: "THOUGHTGATE_UPSTREAM environment variable is required".to_string(),
             })?;
 
+        Ok(Self {
+            base_url,
+            ..Self::defaults_from_env()?
+        })
+    }
+
+    /// Load the timeout settings from environment variables, without a base URL.
+    ///
+    /// Used as the defaults for configured sources (see [`Self::from_source`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ThoughtGateError::InvalidParams` if either timeout variable is
+    /// set but not a valid u64.
+    pub fn defaults_from_env() -> Result<Self, ThoughtGateError> {
         let timeout_secs: u64 = match std::env::var("THOUGHTGATE_REQUEST_TIMEOUT_SECS") {
             Ok(val) => val.parse().map_err(|_| ThoughtGateError::InvalidParams {
                 details: format!(
@@ -107,13 +140,29 @@ impl UpstreamConfig {
         };
 
         Ok(Self {
-            base_url,
             timeout: Duration::from_secs(timeout_secs),
             connect_timeout: Duration::from_secs(connect_timeout_secs),
             ..Default::default()
         })
     }
 
+    /// Build the config for a configured source.
+    ///
+    /// Settings the source does not override are taken from `defaults`.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CFG-001 Section 7.2 (Source Configuration)
+    pub fn from_source(source: &Source, defaults: &UpstreamConfig) -> Self {
+        Self {
+            base_url: source.url().to_string(),
+            timeout: source.timeout().unwrap_or(defaults.timeout),
+            connect_timeout: source.connect_timeout().unwrap_or(defaults.connect_timeout),
+            tls: source.tls().cloned(),
+            headers: source.headers().cloned(),
+            ..defaults.clone()
+        }
+    }
+
     /// Create a new config with the specified base URL.
     ///
     /// Uses default values for all other settings.
@@ -127,6 +176,50 @@ impl UpstreamConfig {
     }
 }
 
+/// Add a source's CA roots and client identity to a client builder.
+///
+/// # Errors
+///
+/// Returns `ThoughtGateError::InternalError` if a file cannot be read or
+/// does not contain valid PEM.
+fn apply_tls(
+    mut builder: reqwest::ClientBuilder,
+    tls: &SourceTls,
+) -> Result<reqwest::ClientBuilder, ThoughtGateError> {
+    let read = |path: &std::path::Path| {
+        std::fs::read(path).map_err(|e| ThoughtGateError::InternalError {
+            correlation_id: format!(
+                "upstream-client-config-error: cannot read '{}': {}",
+                path.display(),
+                e
+            ),
+        })
+    };
+    let invalid = |what: &str, e: reqwest::Error| ThoughtGateError::InternalError {
+        correlation_id: format!("upstream-client-config-error: invalid {}: {}", what, e),
+    };
+
+    if let Some(ref ca_file) = tls.ca_file {
+        let certs = reqwest::Certificate::from_pem_bundle(&read(ca_file)?)
+            .map_err(|e| invalid("ca_file", e))?;
+        for cert in certs {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+    if tls.pin_ca {
+        builder = builder.tls_built_in_root_certs(false);
+    }
+    if let (Some(cert), Some(key)) = (&tls.client_cert, &tls.client_key) {
+        // reqwest expects the key and certificate chain in one PEM buffer
+        let mut pem = read(key)?;
+        pem.push(b'\n');
+        pem.extend(read(cert)?);
+        let identity = reqwest::Identity::from_pem(&pem).map_err(|e| invalid("client_cert", e))?;
+        builder = builder.identity(identity);
+    }
+    Ok(builder)
+}
+
 /// Upstream MCP client.
 ///
 /// Implements: REQ-CORE-003/F-004 (Upstream Forwarding)
@@ -140,6 +233,7 @@ impl UpstreamConfig {
 pub struct UpstreamClient {
     client: Client,
     config: UpstreamConfig,
+    header_rules: Arc<HeaderRules>,
 }
 
 impl UpstreamClient {
@@ -174,18 +268,36 @@ impl UpstreamClient {
             });
         }
 
-        let client = Client::builder()
+        let mut builder = Client::builder()
             .timeout(config.timeout)
             .connect_timeout(config.connect_timeout)
             .pool_max_idle_per_host(config.pool_max_idle_per_host)
             .pool_idle_timeout(config.pool_idle_timeout)
-            .tcp_nodelay(true)
+            .tcp_nodelay(true);
+        if let Some(ref tls) = config.tls {
+            builder = apply_tls(builder, tls)?;
+        }
+
+        let client = builder
             .build()
             .map_err(|e| ThoughtGateError::InternalError {
                 correlation_id: format!("upstream-client-build-error: {}", e),
             })?;
 
-        Ok(Self { client, config })
+        let header_rules = match &config.headers {
+            Some(headers) => HeaderRules::from_config(&config.base_url, headers).map_err(|e| {
+                ThoughtGateError::InternalError {
+                    correlation_id: format!("upstream-client-config-error: {}", e),
+                }
+            })?,
+            None => HeaderRules::default(),
+        };
+
+        Ok(Self {
+            client,
+            config,
+            header_rules: Arc::new(header_rules),
+        })
     }
 
     /// Perform a health check to verify upstream connectivity.
@@ -278,10 +390,11 @@ impl UpstreamClient {
     ///
     /// # Note on Header Forwarding
     ///
-    /// Currently only sets `Content-Type: application/json`. Header forwarding
-    /// (F-004.2) is not implemented as it requires security review - forwarding
-    /// Authorization headers to upstream could leak credentials. For MCP traffic,
-    /// the JSON-RPC body contains all necessary context.
+    /// Sets `Content-Type: application/json` and the `x-request-id`
+    /// correlation header, then applies the source's request header rules.
+    /// Client headers are not forwarded (F-004.2) - forwarding Authorization
+    /// headers to upstream could leak credentials; use `headers.request.set`
+    /// or `principal_header` to pass what the upstream needs.
     pub async fn forward(&self, request: &McpRequest) -> Result<JsonRpcResponse, ThoughtGateError> {
         let url = format!("{}/mcp/v1", self.config.base_url.trim_end_matches('/'));
         let correlation_id = request.correlation_id.to_string();
@@ -296,23 +409,76 @@ impl UpstreamClient {
         // Build JSON-RPC request
         let jsonrpc_request = request.to_jsonrpc_request();
 
-        let response = self
+        latency::upstream(self.send(request, self.request_builder(&url).json(&jsonrpc_request)))
+            .await
+    }
+
+    /// Send a stored request again, as it was received.
+    ///
+    /// Implements: REQ-GOV-002/F-006 (Execution of approved requests)
+    ///
+    /// Unlike [`Self::forward`], the stored headers are sent as they are:
+    /// they were recorded after the source's header rules were applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidRequest` if the stored body is not a single JSON-RPC
+    /// request, otherwise the same errors as [`Self::forward`].
+    pub async fn replay(
+        &self,
+        stored: &CanonicalRequest,
+    ) -> Result<JsonRpcResponse, ThoughtGateError> {
+        let ParsedRequests::Single(request) = parse_jsonrpc(stored.body.as_bytes())? else {
+            return Err(ThoughtGateError::InvalidRequest {
+                details: "Stored request is a batch".to_string(),
+            });
+        };
+        let url = format!("{}/mcp/v1", self.config.base_url.trim_end_matches('/'));
+
+        debug!(
+            correlation_id = %request.correlation_id,
+            method = %request.method,
+            url = %url,
+            "Replaying request to upstream"
+        );
+
+        let mut headers = stored.header_map();
+        headers
+            .entry(CONTENT_TYPE)
+            .or_insert(HeaderValue::from_static("application/json"));
+        let builder = self
             .client
             .post(&url)
-            .header("Content-Type", "application/json")
-            .json(&jsonrpc_request)
+            .headers(headers)
+            .body(stored.body.clone());
+        latency::upstream(self.send(&request, builder)).await
+    }
+
+    /// Send one JSON-RPC request and read its response.
+    async fn send(
+        &self,
+        request: &McpRequest,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<JsonRpcResponse, ThoughtGateError> {
+        let correlation_id = request.correlation_id.to_string();
+        let response = builder
             .send()
             .await
-            .map_err(|e| self.classify_error(e, &correlation_id))?;
+            .map_err(|e| self.classify_error(e, &correlation_id))
+            .inspect_err(|e| audit_tls_failure(e, std::slice::from_ref(request)))?;
 
         // Check HTTP status
         let status = response.status();
 
-        // Handle 204 No Content (notification response from upstream)
-        if status == reqwest::StatusCode::NO_CONTENT {
+        // Handle 204 No Content, or any success for a notification: MCP
+        // servers acknowledge notifications with 202 Accepted and no body
+        if status == reqwest::StatusCode::NO_CONTENT
+            || (request.is_notification() && status.is_success())
+        {
             debug!(
                 correlation_id = %correlation_id,
-                "Upstream returned 204 No Content (notification acknowledged)"
+                status = %status,
+                "Upstream acknowledged notification"
             );
             // Return a synthetic success response for internal processing.
             // Note: For notifications, request.id is None per JSON-RPC 2.0 spec.
@@ -394,22 +560,22 @@ impl UpstreamClient {
         // Build batch of JSON-RPC requests
         let jsonrpc_requests: Vec<_> = requests.iter().map(|r| r.to_jsonrpc_request()).collect();
 
-        let response = self
-            .client
-            .post(&url)
-            .header("Content-Type", "application/json")
-            .json(&jsonrpc_requests)
-            .send()
+        let response = latency::upstream(self.request_builder(&url).json(&jsonrpc_requests).send())
             .await
-            .map_err(|e| self.classify_error(e, "batch"))?;
+            .map_err(|e| self.classify_error(e, "batch"))
+            .inspect_err(|e| audit_tls_failure(e, requests))?;
 
         let status = response.status();
 
-        // Handle 204 No Content (all requests were notifications)
-        if status == reqwest::StatusCode::NO_CONTENT {
+        // Handle 204 No Content, or any success when all requests were
+        // notifications, which expect no response body
+        if status == reqwest::StatusCode::NO_CONTENT
+            || (status.is_success() && requests.iter().all(McpRequest::is_notification))
+        {
             debug!(
                 batch_size = requests.len(),
-                "Upstream returned 204 No Content (all notifications acknowledged)"
+                status = %status,
+                "Upstream acknowledged notifications"
             );
             // Return empty response array - no responses expected for notification-only batches
             return Ok(Vec::new());
@@ -422,14 +588,12 @@ impl UpstreamClient {
             });
         }
 
-        let body: Vec<JsonRpcResponse> =
-            response
-                .json()
-                .await
-                .map_err(|e| ThoughtGateError::UpstreamError {
-                    code: -32002,
-                    message: format!("Failed to parse upstream batch response: {}", e),
-                })?;
+        let body: Vec<JsonRpcResponse> = latency::upstream(response.json()).await.map_err(|e| {
+            ThoughtGateError::UpstreamError {
+                code: -32002,
+                message: format!("Failed to parse upstream batch response: {}", e),
+            }
+        })?;
 
         debug!(
             response_count = body.len(),
@@ -439,6 +603,25 @@ impl UpstreamClient {
         Ok(body)
     }
 
+    /// Build a POST request to upstream with the standard MCP headers.
+    ///
+    /// Propagates the inbound request's correlation ID (see
+    /// [`crate::logging_layer`]) so upstream logs can be joined with ours,
+    /// then applies the source's request header rules.
+    fn request_builder(&self, url: &str) -> reqwest::RequestBuilder {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        if let Some(value) =
+            current_request_id().and_then(|id| HeaderValue::from_str(id.as_ref()).ok())
+        {
+            headers.insert(REQUEST_ID_HEADER, value);
+        }
+        self.header_rules
+            .apply_request(&mut headers, None, http::Version::HTTP_11);
+
+        self.client.post(url).headers(headers)
+    }
+
     /// Classify a reqwest error into ThoughtGateError.
     ///
     /// Implements: REQ-CORE-003/§6.5 (Error handling)
@@ -462,6 +645,12 @@ impl UpstreamClient {
                 url: self.config.base_url.clone(),
                 timeout_secs: self.config.timeout.as_secs(),
             }
+        } else if let Some(failure) = crate::error::tls::classify(&error) {
+            record_tls_failure(&self.config.base_url, failure);
+            ThoughtGateError::UpstreamTlsFailed {
+                url: self.config.base_url.clone(),
+                failure,
+            }
         } else if error.is_connect() {
             warn!(
                 correlation_id = %correlation_id,
@@ -486,6 +675,54 @@ impl UpstreamClient {
     }
 }
 
+/// Log and count a failed TLS handshake with an upstream.
+///
+/// Implements: REQ-CORE-004/EC-ERR-005 (Upstream Connection Failure)
+pub(crate) fn record_tls_failure(upstream: &str, failure: TlsFailure) {
+    warn!(upstream = %upstream, reason = %failure, "Upstream TLS handshake failed");
+    if let Some(metrics) = crate::metrics::get_upstream_metrics() {
+        metrics.record_tls_failure(upstream, failure.as_str());
+    }
+}
+
+/// Write an audit record for a call that was not forwarded because the
+/// TLS handshake with `upstream` failed.
+///
+/// Implements: REQ-OBS-002 (Audit Trail)
+pub(crate) fn audit_upstream_tls_failure(
+    upstream: &str,
+    failure: TlsFailure,
+    method: &str,
+    resource: &str,
+) {
+    if !audit::is_enabled() {
+        return;
+    }
+    let mut record = AuditRecord::new(
+        method,
+        resource,
+        AuditDecision::UpstreamTlsFailure,
+        AuditGate::Upstream,
+    )
+    .with_upstream(upstream)
+    .with_reason(Some(failure.to_string()));
+    if let Ok(principal) = crate::policy::principal::request_principal() {
+        record = record.with_principal(principal.app_name);
+    }
+    audit::record(record);
+}
+
+/// Audit each request of a call that failed with `UpstreamTlsFailed`.
+fn audit_tls_failure(error: &ThoughtGateError, requests: &[McpRequest]) {
+    let ThoughtGateError::UpstreamTlsFailed { url, failure } = error else {
+        return;
+    };
+    for request in requests {
+        let resource = extract_governable_name(request).unwrap_or_default();
+        audit_upstream_tls_failure(url, *failure, &request.method, &resource);
+    }
+}
+
 /// Trait for upstream client (enables mocking in tests).
 ///
 /// This trait abstracts the upstream forwarding behavior, allowing tests
@@ -506,6 +743,21 @@ pub trait UpstreamForwarder: Send + Sync {
         &self,
         requests: &[McpRequest],
     ) -> Result<Vec<JsonRpcResponse>, ThoughtGateError>;
+
+    /// Send a stored request again after approval.
+    ///
+    /// Implements: REQ-GOV-002/F-006 (Execution of approved requests)
+    ///
+    /// The default forwards the stored JSON-RPC message without its
+    /// headers.
+    async fn replay(&self, stored: &CanonicalRequest) -> Result<JsonRpcResponse, ThoughtGateError> {
+        match parse_jsonrpc(stored.body.as_bytes())? {
+            ParsedRequests::Single(request) => self.forward(&request).await,
+            ParsedRequests::Batch(_) => Err(ThoughtGateError::InvalidRequest {
+                details: "Stored request is a batch".to_string(),
+            }),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -520,6 +772,10 @@ impl UpstreamForwarder for UpstreamClient {
     ) -> Result<Vec<JsonRpcResponse>, ThoughtGateError> {
         self.forward_batch(requests).await
     }
+
+    async fn replay(&self, stored: &CanonicalRequest) -> Result<JsonRpcResponse, ThoughtGateError> {
+        self.replay(stored).await
+    }
 }
 
 #[cfg(test)]
@@ -600,6 +856,221 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_config_from_source() {
+        let source: Source = serde_saphyr::from_str(
+            r#"
+id: billing
+kind: mcp
+url: https://billing:8443
+timeout: 10s
+tls:
+  ca_file: /nonexistent/billing-ca.pem
+"#,
+        )
+        .unwrap();
+        let defaults = UpstreamConfig {
+            connect_timeout: Duration::from_secs(2),
+            ..Default::default()
+        };
+
+        let config = UpstreamConfig::from_source(&source, &defaults);
+        assert_eq!(config.base_url, "https://billing:8443");
+        assert_eq!(config.timeout, Duration::from_secs(10));
+        assert_eq!(config.connect_timeout, Duration::from_secs(2));
+
+        // An unreadable CA bundle fails client construction, not the first call
+        let Err(ThoughtGateError::InternalError { correlation_id }) = UpstreamClient::new(config)
+        else {
+            panic!("expected InternalError");
+        };
+        assert!(correlation_id.contains("billing-ca.pem"));
+    }
+
+    /// Serve JSON-RPC over TLS with the test server certificate
+    /// (`DNS:localhost`, signed by the test CA). Returns the port.
+    async fn tls_upstream() -> u16 {
+        use crate::transport::tls::fixtures::acceptor;
+        use hyper_util::rt::{TokioExecutor, TokioIo};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let acceptor = acceptor();
+        tokio::spawn(async move {
+            while let Ok((tcp, _)) = listener.accept().await {
+                let acceptor = acceptor.clone();
+                tokio::spawn(async move {
+                    // Rejected handshakes end here
+                    let Ok(tls) = acceptor.accept(tcp).await else {
+                        return;
+                    };
+                    let service = hyper::service::service_fn(|_req| async {
+                        Ok::<_, std::convert::Infallible>(hyper::Response::new(
+                            http_body_util::Full::new(bytes::Bytes::from_static(
+                                br#"{"jsonrpc":"2.0","id":1,"result":{}}"#,
+                            )),
+                        ))
+                    });
+                    let _ = hyper_util::server::conn::auto::Builder::new(TokioExecutor::new())
+                        .serve_connection(TokioIo::new(tls), service)
+                        .await;
+                });
+            }
+        });
+        port
+    }
+
+    fn ping() -> McpRequest {
+        McpRequest {
+            id: Some(crate::transport::jsonrpc::JsonRpcId::Number(1)),
+            method: "ping".to_string(),
+            params: None,
+            task_metadata: None,
+            received_at: std::time::Instant::now(),
+            correlation_id: uuid::Uuid::new_v4(),
+        }
+    }
+
+    fn tls_client(url: String, tls: Option<SourceTls>) -> UpstreamClient {
+        UpstreamClient::new(UpstreamConfig {
+            tls,
+            ..UpstreamConfig::with_base_url(url)
+        })
+        .unwrap()
+    }
+
+    fn trust_test_ca(pin_ca: bool) -> Option<SourceTls> {
+        use crate::transport::tls::fixtures::{CA_PEM, write_fixture};
+        Some(SourceTls {
+            ca_file: Some(write_fixture("upstream_ca", CA_PEM)),
+            pin_ca,
+            ..Default::default()
+        })
+    }
+
+    /// Verifies: REQ-CORE-004/EC-ERR-005
+    #[tokio::test]
+    async fn test_tls_upstream_trusted_ca() {
+        let port = tls_upstream().await;
+        let url = format!("https://localhost:{port}");
+
+        for pin_ca in [false, true] {
+            let client = tls_client(url.clone(), trust_test_ca(pin_ca));
+            let response = client.forward(&ping()).await;
+            assert!(response.is_ok(), "pin_ca={pin_ca}: {response:?}");
+        }
+    }
+
+    /// Verifies: REQ-CORE-004/EC-ERR-005
+    #[tokio::test]
+    async fn test_tls_upstream_untrusted_ca() {
+        let port = tls_upstream().await;
+        let url = format!("https://localhost:{port}");
+
+        let client = tls_client(url.clone(), None);
+        let error = client.forward(&ping()).await.unwrap_err();
+        assert_eq!(
+            error,
+            ThoughtGateError::UpstreamTlsFailed {
+                url,
+                failure: TlsFailure::UnknownCa,
+            }
+        );
+        assert_eq!(error.error_type_name(), "upstream_tls_failed");
+    }
+
+    /// Verifies: REQ-CORE-004/EC-ERR-005
+    #[tokio::test]
+    async fn test_tls_upstream_hostname_mismatch() {
+        let port = tls_upstream().await;
+
+        // Trusted CA, but the certificate only names `localhost`
+        let client = tls_client(format!("https://127.0.0.1:{port}"), trust_test_ca(false));
+        let error = client.forward_batch(&[ping()]).await.unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ThoughtGateError::UpstreamTlsFailed {
+                    failure: TlsFailure::HostnameMismatch,
+                    ..
+                }
+            ),
+            "{error:?}"
+        );
+    }
+
+    /// Verifies: REQ-CFG-001 Section 7.2 (Source Configuration)
+    #[tokio::test]
+    async fn test_request_header_rules_applied() {
+        use crate::policy::Principal;
+        use crate::policy::principal::with_client_principal;
+        use wiremock::matchers::{header, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("authorization", "Bearer upstream"))
+            .and(header("x-thoughtgate-principal", "billing-agent"))
+            .and(header("via", "1.1 thoughtgate"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"jsonrpc":"2.0","id":1,"result":{}}"#),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let headers = serde_saphyr::from_str(
+            "request:\n  set: { authorization: \"Bearer upstream\" }\n\
+             principal_header: x-thoughtgate-principal\n",
+        )
+        .unwrap();
+        let client = UpstreamClient::new(UpstreamConfig {
+            headers: Some(headers),
+            ..UpstreamConfig::with_base_url(server.uri())
+        })
+        .unwrap();
+        let principal = Principal {
+            app_name: "billing-agent".to_string(),
+            namespace: "prod".to_string(),
+            service_account: "billing".to_string(),
+            roles: vec![],
+        };
+
+        let response = with_client_principal(principal, client.forward(&ping())).await;
+        assert!(response.is_ok(), "{response:?}");
+    }
+
+    /// Notifications are acknowledged without a response body.
+    ///
+    /// Verifies: REQ-CORE-003/F-001.3 (Notification Detection)
+    #[tokio::test]
+    async fn test_notification_accepted_without_body() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(3)
+            .mount(&server)
+            .await;
+        let client = UpstreamClient::new(UpstreamConfig::with_base_url(server.uri())).unwrap();
+        let notification = McpRequest {
+            id: None,
+            method: "notifications/progress".to_string(),
+            ..ping()
+        };
+
+        let response = client.forward(&notification).await;
+        assert!(response.is_ok(), "{response:?}");
+        let responses = client.forward_batch(&[notification]).await.unwrap();
+        assert!(responses.is_empty());
+
+        // A request still needs a response body
+        assert!(client.forward(&ping()).await.is_err());
+    }
+
     #[test]
     #[serial]
     fn test_config_from_env_missing_upstream() {
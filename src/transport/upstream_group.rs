@@ -0,0 +1,509 @@
+//! Upstream groups with failover and weighted selection.
+//!
+//! Implements: REQ-CORE-003/F-004 (Upstream Forwarding)
+//!
+//! A group maps one logical server to several upstream instances. Each
+//! member has a circuit breaker that opens after consecutive connect
+//! failures or timeouts and skips the member until its cooldown passes.
+//! After the cooldown a single probe request is let through: success closes
+//! the circuit, failure reopens it.
+//!
+//! # Failover
+//!
+//! Only connection failures, including failed TLS handshakes, move a
+//! request on to the next member, since the upstream never saw it. Timeouts and upstream errors are returned as-is:
+//! the call may already have had side effects.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, AtomicU64, Ordering};
+use std::time::Duration;
+
+use tokio::time::Instant;
+use tracing::warn;
+
+use crate::config::{FailoverConfig, FailoverStrategy};
+use crate::error::ThoughtGateError;
+use crate::governance::CanonicalRequest;
+use crate::transport::jsonrpc::{JsonRpcResponse, McpRequest};
+use crate::transport::upstream::{UpstreamClient, UpstreamConfig, UpstreamForwarder};
+
+/// Consecutive failures that open a circuit by default.
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 3;
+
+/// How long an open circuit skips its member by default.
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Per-member circuit breaker.
+///
+/// Implements: REQ-CORE-003/F-004 (Upstream Forwarding)
+///
+/// State is held in atomics, so requests never wait on each other. The
+/// fields are updated independently; a race can at worst let one extra
+/// request through or open the circuit one failure late.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    /// Reference point for `opened_at`.
+    epoch: Instant,
+    /// Consecutive failures since the last success.
+    failures: AtomicU32,
+    /// When the circuit last opened, in nanoseconds since `epoch` plus
+    /// one; [`CLOSED`] while closed.
+    opened_at: AtomicU64,
+    /// A half-open probe is in flight.
+    probing: AtomicBool,
+}
+
+/// `opened_at` of a closed circuit.
+const CLOSED: u64 = 0;
+
+impl CircuitBreaker {
+    /// Create a closed breaker.
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            epoch: Instant::now(),
+            failures: AtomicU32::new(0),
+            opened_at: AtomicU64::new(CLOSED),
+            probing: AtomicBool::new(false),
+        }
+    }
+
+    /// Whether the member could take a request now, without claiming it.
+    pub fn is_available(&self) -> bool {
+        match self.opened_at.load(Ordering::Acquire) {
+            CLOSED => true,
+            opened => !self.probing.load(Ordering::Acquire) && self.cooled_down(opened),
+        }
+    }
+
+    /// Claim permission to send a request.
+    ///
+    /// A closed circuit always allows. An open circuit allows one probe once
+    /// its cooldown has passed.
+    pub fn allow(&self) -> bool {
+        match self.opened_at.load(Ordering::Acquire) {
+            CLOSED => true,
+            opened if self.cooled_down(opened) => self
+                .probing
+                .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok(),
+            _ => false,
+        }
+    }
+
+    /// Record a request that reached the upstream.
+    pub fn record_success(&self) {
+        self.failures.store(0, Ordering::Release);
+        self.opened_at.store(CLOSED, Ordering::Release);
+        self.probing.store(false, Ordering::Release);
+    }
+
+    /// Record a connect failure or timeout.
+    pub fn record_failure(&self) {
+        let failures = self
+            .failures
+            .fetch_add(1, Ordering::AcqRel)
+            .saturating_add(1);
+        let probed = self.probing.swap(false, Ordering::AcqRel);
+        if probed || failures >= self.failure_threshold {
+            self.opened_at.store(self.now(), Ordering::Release);
+        }
+    }
+
+    /// Whether the circuit is currently open.
+    pub fn is_open(&self) -> bool {
+        self.opened_at.load(Ordering::Acquire) != CLOSED
+    }
+
+    /// The current time as an `opened_at` value.
+    fn now(&self) -> u64 {
+        let since_epoch = u64::try_from(self.epoch.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        since_epoch.saturating_add(1)
+    }
+
+    /// Whether the cooldown has passed since the circuit opened at `opened`.
+    fn cooled_down(&self, opened: u64) -> bool {
+        Duration::from_nanos(self.now().saturating_sub(opened)) >= self.cooldown
+    }
+}
+
+/// One upstream instance in a group.
+struct Member {
+    url: String,
+    weight: u32,
+    forwarder: Arc<dyn UpstreamForwarder>,
+    breaker: CircuitBreaker,
+}
+
+/// A logical server served by several upstream instances.
+///
+/// Implements: REQ-CORE-003/F-004 (Upstream Forwarding)
+///
+/// Members are tried in order, starting from the one picked by the
+/// strategy, skipping members whose circuit is open.
+pub struct UpstreamGroup {
+    strategy: FailoverStrategy,
+    failure_threshold: u32,
+    cooldown: Duration,
+    members: Vec<Member>,
+    /// Smooth weighted round-robin state, one entry per member.
+    current_weights: Vec<AtomicI64>,
+}
+
+impl UpstreamGroup {
+    /// Create an empty group.
+    pub fn new(strategy: FailoverStrategy, failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            strategy,
+            failure_threshold,
+            cooldown,
+            members: Vec::new(),
+            current_weights: Vec::new(),
+        }
+    }
+
+    /// Add a member. Members added first are preferred on failover.
+    pub fn with_member(
+        mut self,
+        url: impl Into<String>,
+        weight: u32,
+        forwarder: Arc<dyn UpstreamForwarder>,
+    ) -> Self {
+        self.members.push(Member {
+            url: url.into(),
+            weight,
+            forwarder,
+            breaker: CircuitBreaker::new(self.failure_threshold, self.cooldown),
+        });
+        self.current_weights.push(AtomicI64::new(0));
+        self
+    }
+
+    /// Build a group from a source's failover settings.
+    ///
+    /// `primary` serves the source's own URL; each secondary gets a client
+    /// built from `config` with its URL swapped in.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ThoughtGateError::InternalError` if a secondary client
+    /// cannot be built.
+    pub fn from_failover(
+        primary: Arc<dyn UpstreamForwarder>,
+        config: &UpstreamConfig,
+        failover: &FailoverConfig,
+    ) -> Result<Self, ThoughtGateError> {
+        let mut group = Self::new(
+            failover.strategy,
+            failover
+                .failure_threshold
+                .unwrap_or(DEFAULT_FAILURE_THRESHOLD),
+            failover.cooldown.unwrap_or(DEFAULT_COOLDOWN),
+        )
+        .with_member(config.base_url.clone(), failover.weight, primary);
+        for upstream in &failover.upstreams {
+            let client = UpstreamClient::new(UpstreamConfig {
+                base_url: upstream.url.clone(),
+                ..config.clone()
+            })?;
+            group = group.with_member(upstream.url.clone(), upstream.weight, Arc::new(client));
+        }
+        Ok(group)
+    }
+
+    /// Whether the member at `index` has an open circuit.
+    pub fn is_open(&self, index: usize) -> bool {
+        self.members.get(index).is_some_and(|m| m.breaker.is_open())
+    }
+
+    /// Member indices in the order they should be tried.
+    fn order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.members.len()).collect();
+        if self.strategy == FailoverStrategy::Weighted {
+            if let Some(first) = self.pick_weighted() {
+                order.retain(|&i| i != first);
+                order.insert(0, first);
+            }
+        }
+        order
+    }
+
+    /// Pick an available member by smooth weighted round-robin.
+    ///
+    /// Concurrent picks may interleave their updates, but every pick adds
+    /// and subtracts the same total, so long-run shares follow the weights.
+    fn pick_weighted(&self) -> Option<usize> {
+        let mut total = 0i64;
+        let mut best: Option<(usize, i64)> = None;
+        for (i, member) in self.members.iter().enumerate() {
+            if !member.breaker.is_available() {
+                continue;
+            }
+            let weight = i64::from(member.weight);
+            let current = self.current_weights[i].fetch_add(weight, Ordering::AcqRel) + weight;
+            total += weight;
+            if best.is_none_or(|(_, b)| current > b) {
+                best = Some((i, current));
+            }
+        }
+        let (b, _) = best?;
+        self.current_weights[b].fetch_sub(total, Ordering::AcqRel);
+        Some(b)
+    }
+
+    /// Send through the first member that accepts the connection.
+    async fn dispatch<'a, T, F>(&'a self, send: F) -> Result<T, ThoughtGateError>
+    where
+        F: Fn(
+            &'a dyn UpstreamForwarder,
+        ) -> futures_util::future::BoxFuture<'a, Result<T, ThoughtGateError>>,
+    {
+        let mut last_error = None;
+        for index in self.order() {
+            let member = &self.members[index];
+            if !member.breaker.allow() {
+                continue;
+            }
+            match send(member.forwarder.as_ref()).await {
+                Err(
+                    e @ (ThoughtGateError::UpstreamConnectionFailed { .. }
+                    | ThoughtGateError::UpstreamTlsFailed { .. }),
+                ) => {
+                    member.breaker.record_failure();
+                    warn!(upstream = %member.url, "Upstream unreachable, failing over");
+                    last_error = Some(e);
+                }
+                Err(e @ ThoughtGateError::UpstreamTimeout { .. }) => {
+                    member.breaker.record_failure();
+                    return Err(e);
+                }
+                result => {
+                    member.breaker.record_success();
+                    return result;
+                }
+            }
+        }
+        Err(
+            last_error.unwrap_or_else(|| ThoughtGateError::UpstreamConnectionFailed {
+                url: self
+                    .members
+                    .first()
+                    .map(|m| m.url.clone())
+                    .unwrap_or_default(),
+                reason: "all upstreams in the group have open circuits".to_string(),
+            }),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl UpstreamForwarder for UpstreamGroup {
+    async fn forward(&self, request: &McpRequest) -> Result<JsonRpcResponse, ThoughtGateError> {
+        self.dispatch(|upstream| upstream.forward(request)).await
+    }
+
+    async fn forward_batch(
+        &self,
+        requests: &[McpRequest],
+    ) -> Result<Vec<JsonRpcResponse>, ThoughtGateError> {
+        self.dispatch(|upstream| upstream.forward_batch(requests))
+            .await
+    }
+
+    async fn replay(&self, stored: &CanonicalRequest) -> Result<JsonRpcResponse, ThoughtGateError> {
+        self.dispatch(|upstream| upstream.replay(stored)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::jsonrpc::JsonRpcId;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    /// Upstream that tags responses with its name and can be taken down.
+    struct Instance {
+        name: &'static str,
+        down: AtomicBool,
+        timeout: AtomicBool,
+        calls: AtomicUsize,
+    }
+
+    impl Instance {
+        fn new(name: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                down: AtomicBool::new(false),
+                timeout: AtomicBool::new(false),
+                calls: AtomicUsize::new(0),
+            })
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl UpstreamForwarder for Instance {
+        async fn forward(&self, request: &McpRequest) -> Result<JsonRpcResponse, ThoughtGateError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.down.load(Ordering::SeqCst) {
+                return Err(ThoughtGateError::UpstreamConnectionFailed {
+                    url: self.name.to_string(),
+                    reason: "connection refused".to_string(),
+                });
+            }
+            if self.timeout.load(Ordering::SeqCst) {
+                return Err(ThoughtGateError::UpstreamTimeout {
+                    url: self.name.to_string(),
+                    timeout_secs: 30,
+                });
+            }
+            Ok(JsonRpcResponse::success(
+                request.id.clone(),
+                serde_json::json!({"instance": self.name}),
+            ))
+        }
+
+        async fn forward_batch(
+            &self,
+            requests: &[McpRequest],
+        ) -> Result<Vec<JsonRpcResponse>, ThoughtGateError> {
+            let mut responses = Vec::new();
+            for request in requests {
+                responses.push(self.forward(request).await?);
+            }
+            Ok(responses)
+        }
+    }
+
+    fn request() -> McpRequest {
+        McpRequest {
+            id: Some(JsonRpcId::Number(1)),
+            method: "tools/call".to_string(),
+            params: None,
+            task_metadata: None,
+            received_at: std::time::Instant::now(),
+            correlation_id: uuid::Uuid::new_v4(),
+        }
+    }
+
+    async fn served_by(group: &UpstreamGroup) -> String {
+        let response = group.forward(&request()).await.unwrap();
+        response.result.unwrap()["instance"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    fn group(
+        strategy: FailoverStrategy,
+        cooldown: Duration,
+        members: &[(&Arc<Instance>, u32)],
+    ) -> UpstreamGroup {
+        members.iter().fold(
+            UpstreamGroup::new(strategy, 1, cooldown),
+            |group, (instance, weight)| {
+                group.with_member(instance.name, *weight, Arc::clone(instance) as _)
+            },
+        )
+    }
+
+    /// Test traffic moves to the secondary while the primary is down, then back.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-003/F-004 (Upstream Forwarding)
+    #[tokio::test]
+    async fn test_failover_and_recovery() {
+        let primary = Instance::new("primary");
+        let secondary = Instance::new("secondary");
+        let cooldown = Duration::from_millis(50);
+        let group = group(
+            FailoverStrategy::Priority,
+            cooldown,
+            &[(&primary, 1), (&secondary, 1)],
+        );
+
+        assert_eq!(served_by(&group).await, "primary");
+
+        // Primary goes down: the request fails over and the circuit opens
+        primary.down.store(true, Ordering::SeqCst);
+        assert_eq!(served_by(&group).await, "secondary");
+        assert!(group.is_open(0));
+
+        // While open, the primary is not even tried
+        let attempts = primary.calls();
+        assert_eq!(served_by(&group).await, "secondary");
+        assert_eq!(primary.calls(), attempts);
+
+        // Primary recovers: after the cooldown a probe closes the circuit
+        primary.down.store(false, Ordering::SeqCst);
+        tokio::time::sleep(cooldown * 2).await;
+        assert_eq!(served_by(&group).await, "primary");
+        assert!(!group.is_open(0));
+    }
+
+    /// Test a timeout is returned rather than retried on another member.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-003/F-004 (Upstream Forwarding)
+    #[tokio::test]
+    async fn test_timeout_does_not_fail_over() {
+        let primary = Instance::new("primary");
+        let secondary = Instance::new("secondary");
+        let group = group(
+            FailoverStrategy::Priority,
+            DEFAULT_COOLDOWN,
+            &[(&primary, 1), (&secondary, 1)],
+        );
+
+        primary.timeout.store(true, Ordering::SeqCst);
+        let result = group.forward(&request()).await;
+        assert!(matches!(
+            result,
+            Err(ThoughtGateError::UpstreamTimeout { .. })
+        ));
+        assert_eq!(secondary.calls(), 0);
+
+        // Every member down and open: fail without contacting anyone
+        secondary.down.store(true, Ordering::SeqCst);
+        let _ = group.forward(&request()).await;
+        let result = group.forward(&request()).await;
+        assert!(matches!(
+            result,
+            Err(ThoughtGateError::UpstreamConnectionFailed { .. })
+        ));
+    }
+
+    /// Test weighted groups split traffic by weight and skip open members.
+    ///
+    /// # Traceability
+    /// - Implements: REQ-CORE-003/F-004 (Upstream Forwarding)
+    #[tokio::test]
+    async fn test_weighted_distribution() {
+        let a = Instance::new("a");
+        let b = Instance::new("b");
+        let group = group(
+            FailoverStrategy::Weighted,
+            DEFAULT_COOLDOWN,
+            &[(&a, 3), (&b, 1)],
+        );
+
+        for _ in 0..8 {
+            served_by(&group).await;
+        }
+        assert_eq!((a.calls(), b.calls()), (6, 2));
+
+        // The heavier member goes down: everything shifts to the other
+        a.down.store(true, Ordering::SeqCst);
+        for _ in 0..4 {
+            assert_eq!(served_by(&group).await, "b");
+        }
+        assert!(group.is_open(0));
+        assert_eq!(a.calls(), 7);
+    }
+}
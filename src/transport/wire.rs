@@ -0,0 +1,83 @@
+//! Seam for governing MCP carried over non-JSON-RPC wire protocols.
+//!
+//! # Overview
+//!
+//! A [`Transport`] teaches `ProxyService` to recognize one wire protocol
+//! (e.g. gRPC), identify the governed call from the request head and the
+//! start of its body, and refuse a call in the protocol's own error format.
+//! Permitted calls are forwarded with the body bytes untouched, so framing
+//! is preserved and streaming calls keep streaming after the first message.
+//! A call that needs approval in blocking mode waits at the first message,
+//! with the rest of its body unread, and then streams the same way.
+//!
+//! ```text
+//! Request ──► Transport::matches()
+//!                 │
+//!                 ▼
+//!   read body until Transport::needed() == 0
+//!                 │
+//!                 ▼
+//!   Transport::identify() ──► McpHandler::authorize_call()
+//!                 │                       │
+//!     permit, or approved after        refuse
+//!     a blocking-mode wait                │
+//!                 │                       │
+//!                 ▼                       ▼
+//!   forward buffered prefix +     Transport::reject()
+//!   rest of the body stream
+//! ```
+//!
+//! # Traceability
+//! - Implements: REQ-CORE-008 (Transport Abstraction)
+
+use crate::error::ThoughtGateError;
+use bytes::Bytes;
+use http::request::Parts;
+use http::{HeaderMap, Response};
+
+/// A call identified on a transport, ready for governance.
+///
+/// Implements: REQ-CORE-008/F-001 (Call Identification)
+#[derive(Debug, Clone, PartialEq)]
+pub struct GovernedCall {
+    /// MCP method (`tools/call` for tool invocations).
+    pub method: String,
+    /// Governed resource name matched by Gates 1 and 2 (e.g. the tool name).
+    pub resource: String,
+    /// Arguments exposed to Cedar as `resource.arguments` (`tools/call` only).
+    pub arguments: serde_json::Value,
+}
+
+/// A wire protocol that `ProxyService` can govern.
+///
+/// Implements: REQ-CORE-008 (Transport Abstraction)
+pub trait Transport: Send + Sync {
+    /// Short name for logs (e.g. `grpc`).
+    fn name(&self) -> &'static str;
+
+    /// Whether this transport carries the request with this head.
+    fn matches(&self, head: &Parts) -> bool;
+
+    /// Body bytes still needed before [`identify`](Self::identify) can run,
+    /// given the `prefix` read so far. Zero means enough.
+    fn needed(&self, head: &Parts, prefix: &[u8]) -> usize;
+
+    /// Identify the governed call from the head and the body prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidParams` if the call cannot be identified (e.g. the
+    /// body ended early or is malformed).
+    fn identify(&self, head: &Parts, prefix: &[u8]) -> Result<GovernedCall, ThoughtGateError>;
+
+    /// Build the response refusing a call with `error`.
+    fn reject(&self, error: &ThoughtGateError) -> Response<Bytes>;
+
+    /// Whether upstream must be reached over HTTP/2.
+    fn requires_http2(&self) -> bool {
+        false
+    }
+
+    /// Adjust headers sent upstream after hop-by-hop headers are removed.
+    fn prepare_upstream(&self, _headers: &mut HeaderMap) {}
+}
@@ -400,6 +400,8 @@ async fn test_mock_adapter_instant_approval() {
         expires_at: Utc::now() + chrono::Duration::minutes(5),
         created_at: Utc::now(),
         correlation_id: "test-123".to_string(),
+        escalation: None,
+        min_approvals: 1,
     };
 
     // Post request
@@ -439,6 +441,8 @@ async fn test_mock_adapter_delayed_approval() {
         expires_at: Utc::now() + chrono::Duration::minutes(5),
         created_at: Utc::now(),
         correlation_id: "test-456".to_string(),
+        escalation: None,
+        min_approvals: 1,
     };
 
     let reference = adapter.post_approval_request(&request).await.unwrap();
@@ -663,7 +667,7 @@ fn test_config_with_approval_parsing() {
         config
             .approval
             .as_ref()
-            .is_some_and(|a| a.contains_key("default"))
+            .is_some_and(|a| a.workflows.contains_key("default"))
     );
 
     unsafe {